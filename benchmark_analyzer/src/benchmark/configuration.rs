@@ -0,0 +1,29 @@
+//!
+//! The benchmark LLVM options configuration.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The benchmark LLVM options configuration.
+///
+/// Tags the benchmarks collected with extra LLVM options, so the results of several
+/// configurations of a sweep can be told apart.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Configuration {
+    /// The configuration name.
+    pub name: String,
+    /// The extra LLVM options.
+    pub llvm_options: Vec<String>,
+}
+
+impl Configuration {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, llvm_options: Vec<String>) -> Self {
+        Self { name, llvm_options }
+    }
+}
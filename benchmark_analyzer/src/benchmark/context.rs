@@ -0,0 +1,38 @@
+//!
+//! The benchmark run context.
+//!
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The benchmark run context.
+///
+/// Describes the environment the benchmark has been collected in, so the results
+/// of different runs can be attributed without post-processing.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Context {
+    /// The machine name.
+    pub machine: String,
+    /// The toolchain identifier.
+    pub toolchain: String,
+    /// The LLVM framework commit hash.
+    pub llvm_commit: String,
+}
+
+impl TryFrom<PathBuf> for Context {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path()).map_err(|error| {
+            anyhow::anyhow!("Benchmark context file {:?} reading: {}", path, error)
+        })?;
+        let json: Self = serde_json::from_str(text.as_str()).map_err(|error| {
+            anyhow::anyhow!("Benchmark context file {:?} parsing: {}", path, error)
+        })?;
+        Ok(json)
+    }
+}
@@ -0,0 +1,22 @@
+//!
+//! The benchmark delta classification.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The benchmark delta classification.
+///
+/// All metrics are costs, so a decrease is an improvement.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Classification {
+    /// The candidate value is greater than the reference one.
+    Regression,
+    /// The candidate value is less than the reference one.
+    Improvement,
+    /// The values are equal.
+    Unchanged,
+}
@@ -0,0 +1,52 @@
+//!
+//! The benchmark metric delta.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::benchmark::diff::classification::Classification;
+
+///
+/// The benchmark metric delta.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Delta {
+    /// The reference value.
+    pub reference: u64,
+    /// The candidate value.
+    pub candidate: u64,
+    /// The absolute delta.
+    pub absolute: i64,
+    /// The delta in percent of the reference value, `None` if the reference value is zero.
+    pub percent: Option<f64>,
+    /// The delta classification.
+    pub classification: Classification,
+}
+
+impl Delta {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(reference: u64, candidate: u64) -> Self {
+        let absolute = candidate as i64 - reference as i64;
+        let percent = if reference == 0 {
+            None
+        } else {
+            Some((absolute as f64) * 100.0 / (reference as f64))
+        };
+        let classification = match candidate.cmp(&reference) {
+            std::cmp::Ordering::Greater => Classification::Regression,
+            std::cmp::Ordering::Less => Classification::Improvement,
+            std::cmp::Ordering::Equal => Classification::Unchanged,
+        };
+
+        Self {
+            reference,
+            candidate,
+            absolute,
+            percent,
+            classification,
+        }
+    }
+}
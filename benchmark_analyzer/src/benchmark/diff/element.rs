@@ -0,0 +1,125 @@
+//!
+//! The benchmark element diff.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::benchmark::diff::delta::Delta;
+use crate::benchmark::group::element::Element;
+
+///
+/// The benchmark element diff.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElementDiff {
+    /// The contract size delta, `Some` for contracts deploys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<Delta>,
+    /// The contract runtime code size delta, `Some` for EraVM and REVM contracts deploys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_size: Option<Delta>,
+    /// The contract CBOR metadata length delta, `Some` for REVM contracts deploys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_size: Option<Delta>,
+    /// The cycles delta, `Some` if recorded in both elements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<Delta>,
+    /// The ergs delta, `Some` if recorded in both elements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ergs: Option<Delta>,
+    /// The EVM gas delta, `Some` if recorded in both elements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<Delta>,
+    /// The published pubdata bytes delta, `Some` for EraVM executions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubdata: Option<Delta>,
+}
+
+impl ElementDiff {
+    ///
+    /// Compares the `reference` and `candidate` elements.
+    ///
+    pub fn new(reference: &Element, candidate: &Element) -> Self {
+        Self {
+            size: Self::optional(reference.size, candidate.size),
+            runtime_size: Self::optional(reference.runtime_size, candidate.runtime_size),
+            metadata_size: Self::optional(reference.metadata_size, candidate.metadata_size),
+            cycles: Self::optional(reference.cycles, candidate.cycles),
+            ergs: reference
+                .ergs
+                .zip(candidate.ergs)
+                .map(|(reference, candidate)| Delta::new(reference, candidate)),
+            gas: reference
+                .gas
+                .zip(candidate.gas)
+                .map(|(reference, candidate)| Delta::new(reference, candidate)),
+            pubdata: Self::optional(reference.pubdata, candidate.pubdata),
+        }
+    }
+
+    ///
+    /// Compares the optional metric measured in both elements.
+    ///
+    fn optional(reference: Option<usize>, candidate: Option<usize>) -> Option<Delta> {
+        match (reference, candidate) {
+            (Some(reference), Some(candidate)) => {
+                Some(Delta::new(reference as u64, candidate as u64))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::benchmark::group::element::selector::Selector;
+    use crate::benchmark::group::element::Element;
+    use crate::benchmark::metadata::Metadata;
+
+    use super::ElementDiff;
+
+    ///
+    /// Creates an element with the optional mandatory metrics.
+    ///
+    fn element(cycles: Option<usize>, ergs: Option<u64>, gas: Option<u64>) -> Element {
+        Element {
+            metadata: Metadata {
+                selector: Selector {
+                    path: "tests/test.sol".to_owned(),
+                    case: None,
+                    input: None,
+                },
+                mode: None,
+                version: None,
+                group: "EVMInterpreter".to_owned(),
+                target: None,
+            },
+            size: None,
+            runtime_size: None,
+            metadata_size: None,
+            instructions: None,
+            function_sizes: None,
+            cycles,
+            ergs,
+            gas,
+            stack_depth: None,
+            pubdata: None,
+            cold_accesses: None,
+            warm_accesses: None,
+        }
+    }
+
+    #[test]
+    fn excluded_metrics() {
+        let diff = ElementDiff::new(
+            &element(Some(10), None, Some(30)),
+            &element(None, None, Some(33)),
+        );
+
+        assert!(diff.cycles.is_none());
+        assert!(diff.ergs.is_none());
+        let gas = diff.gas.expect("Always exists");
+        assert_eq!((gas.reference, gas.candidate, gas.absolute), (30, 33, 3));
+    }
+}
@@ -0,0 +1,82 @@
+//!
+//! The benchmark group diff.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::benchmark::diff::delta::Delta;
+use crate::benchmark::diff::element::ElementDiff;
+use crate::benchmark::group::Group;
+
+///
+/// The benchmark group diff.
+///
+/// Only the elements present in both groups are compared.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupDiff {
+    /// The total contract size delta.
+    pub size: Delta,
+    /// The total runtime code size delta, `Some` if any element has the runtime size measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_size: Option<Delta>,
+    /// The total CBOR metadata length delta, `Some` if any element has the metadata measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_size: Option<Delta>,
+    /// The total cycles delta.
+    pub cycles: Delta,
+    /// The total ergs delta.
+    pub ergs: Delta,
+    /// The total EVM gas delta.
+    pub gas: Delta,
+    /// The total published pubdata bytes delta, `Some` if any element has the pubdata measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubdata: Option<Delta>,
+    /// The element diffs.
+    pub elements: BTreeMap<String, ElementDiff>,
+}
+
+impl GroupDiff {
+    ///
+    /// Compares the `reference` and `candidate` groups.
+    ///
+    pub fn new(reference: &Group, candidate: &Group) -> Self {
+        let mut elements = BTreeMap::new();
+        for (path, reference, candidate) in Group::common_elements(reference, candidate) {
+            elements.insert(path.to_owned(), ElementDiff::new(reference, candidate));
+        }
+
+        let total = |delta: fn(&ElementDiff) -> Option<&Delta>| {
+            let (reference, candidate) = elements.values().filter_map(delta).fold(
+                (0, 0),
+                |(reference, candidate), delta| {
+                    (reference + delta.reference, candidate + delta.candidate)
+                },
+            );
+            Delta::new(reference, candidate)
+        };
+
+        Self {
+            size: total(|element| element.size.as_ref()),
+            runtime_size: elements
+                .values()
+                .any(|element| element.runtime_size.is_some())
+                .then(|| total(|element| element.runtime_size.as_ref())),
+            metadata_size: elements
+                .values()
+                .any(|element| element.metadata_size.is_some())
+                .then(|| total(|element| element.metadata_size.as_ref())),
+            cycles: total(|element| element.cycles.as_ref()),
+            ergs: total(|element| element.ergs.as_ref()),
+            gas: total(|element| element.gas.as_ref()),
+            pubdata: elements
+                .values()
+                .any(|element| element.pubdata.is_some())
+                .then(|| total(|element| element.pubdata.as_ref())),
+            elements,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//!
+//! The machine-readable benchmark diff.
+//!
+
+pub mod classification;
+pub mod delta;
+pub mod element;
+pub mod group;
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::benchmark::Benchmark;
+
+use self::group::GroupDiff;
+
+///
+/// The machine-readable benchmark diff.
+///
+/// Contains the per-group totals and per-element deltas, so CI tools can build their own
+/// reports and gates instead of parsing the text tables.
+///
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Diff {
+    /// The group diffs, for the groups present in both benchmarks.
+    pub groups: BTreeMap<String, GroupDiff>,
+}
+
+impl Diff {
+    ///
+    /// Compares the `reference` and `candidate` benchmarks.
+    ///
+    pub fn new(reference: &Benchmark, candidate: &Benchmark) -> Self {
+        let groups = reference
+            .groups
+            .iter()
+            .filter_map(|(group_name, reference_group)| {
+                let candidate_group = candidate.groups.get(group_name)?;
+                Some((
+                    group_name.to_owned(),
+                    GroupDiff::new(reference_group, candidate_group),
+                ))
+            })
+            .collect();
+
+        Self { groups }
+    }
+}
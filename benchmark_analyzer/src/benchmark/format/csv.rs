@@ -12,7 +12,8 @@ use crate::benchmark::metadata::Metadata;
 
 ///
 /// Serialize the benchmark to CSV in the following format:
-/// "group_name", "element_name", "size_str", "cycles", "ergs", "gas"
+/// "group_name", "element_name", "size_str", "runtime_size_str", "instructions_str", "cycles", "ergs", "gas", "stack_depth",
+/// "cold_accesses", "warm_accesses", "pubdata", "metadata_size_str"
 ///
 #[derive(Default)]
 pub struct Csv;
@@ -23,7 +24,7 @@ impl IBenchmarkSerializer for Csv {
     fn serialize_to_string(&self, benchmark: &Benchmark) -> Result<String, Self::Err> {
         let mut result = String::with_capacity(estimate_csv_size(benchmark));
         result.push_str(
-            r#""group", "mode", "version", "path", "case", "input", "size", "cycles", "ergs", "gas""#,
+            r#""group", "mode", "version", "path", "case", "input", "size", "runtime_size", "instructions", "cycles", "ergs", "gas", "stack_depth", "cold_accesses", "warm_accesses", "pubdata", "metadata_size""#,
         );
         result.push('\n');
         for (group_name, group) in &benchmark.groups {
@@ -34,21 +35,40 @@ impl IBenchmarkSerializer for Csv {
                         mode,
                         version,
                         group: _,
+                        target: _,
                     },
                 size,
+                runtime_size,
+                metadata_size,
+                instructions,
+                function_sizes: _,
                 cycles,
                 ergs,
                 gas,
+                stack_depth,
+                pubdata,
+                cold_accesses,
+                warm_accesses,
             } in group.elements.values()
             {
                 let size_str = size.map(|s| s.to_string()).unwrap_or_default();
+                let cycles_str = cycles.map(|s| s.to_string()).unwrap_or_default();
+                let ergs_str = ergs.map(|s| s.to_string()).unwrap_or_default();
+                let gas_str = gas.map(|s| s.to_string()).unwrap_or_default();
+                let runtime_size_str = runtime_size.map(|s| s.to_string()).unwrap_or_default();
+                let metadata_size_str = metadata_size.map(|s| s.to_string()).unwrap_or_default();
+                let instructions_str = instructions.map(|s| s.to_string()).unwrap_or_default();
+                let stack_depth_str = stack_depth.map(|s| s.to_string()).unwrap_or_default();
+                let pubdata_str = pubdata.map(|s| s.to_string()).unwrap_or_default();
+                let cold_accesses_str = cold_accesses.map(|s| s.to_string()).unwrap_or_default();
+                let warm_accesses_str = warm_accesses.map(|s| s.to_string()).unwrap_or_default();
                 let mode = mode.as_deref().unwrap_or_default();
                 let input = input.clone().map(|s| s.to_string()).unwrap_or_default();
                 let case = case.as_deref().unwrap_or_default();
                 let version = version.as_deref().unwrap_or_default();
                 writeln!(
                     &mut result,
-                    r#""{group_name}", "{mode}", "{version}", "{path}", "{case}", "{input}", {size_str}, {cycles}, {ergs}, {gas}"#,
+                    r#""{group_name}", "{mode}", "{version}", "{path}", "{case}", "{input}", {size_str}, {runtime_size_str}, {instructions_str}, {cycles_str}, {ergs_str}, {gas_str}, {stack_depth_str}, {cold_accesses_str}, {warm_accesses_str}, {pubdata_str}, {metadata_size_str}"#,
                 )?;
             }
         }
@@ -57,7 +77,7 @@ impl IBenchmarkSerializer for Csv {
 }
 
 fn estimate_csv_line_length() -> usize {
-    let number_fields = 4;
+    let number_fields = 8;
     let number_field_estimated_max_length = 15;
     let group_name_estimated_max = 10;
     let test_name_estimated_max = 300;
@@ -69,3 +89,60 @@ fn estimate_csv_line_length() -> usize {
 fn estimate_csv_size(benchmark: &Benchmark) -> usize {
     (benchmark.groups.len() + 1) * estimate_csv_line_length()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::benchmark::format::IBenchmarkSerializer;
+    use crate::benchmark::group::element::selector::Selector;
+    use crate::benchmark::group::element::Element;
+    use crate::benchmark::group::metric::Metric;
+    use crate::benchmark::group::Group;
+    use crate::benchmark::metadata::Metadata;
+    use crate::benchmark::Benchmark;
+
+    use super::Csv;
+
+    #[test]
+    fn excluded_metrics() {
+        let mut group = Group::with_metrics(Some(BTreeSet::from([Metric::Gas])));
+        group.insert(
+            "test".to_owned(),
+            Element {
+                metadata: Metadata {
+                    selector: Selector {
+                        path: "tests/test.sol".to_owned(),
+                        case: None,
+                        input: None,
+                    },
+                    mode: None,
+                    version: None,
+                    group: "EVMInterpreter".to_owned(),
+                    target: None,
+                },
+                size: None,
+                runtime_size: None,
+                metadata_size: None,
+                instructions: None,
+                function_sizes: None,
+                cycles: Some(10),
+                ergs: Some(20),
+                gas: Some(30),
+                stack_depth: None,
+                pubdata: None,
+                cold_accesses: None,
+                warm_accesses: None,
+            },
+        );
+        let mut benchmark = Benchmark::default();
+        benchmark.groups.insert("EVMInterpreter".to_owned(), group);
+
+        let csv = Csv.serialize_to_string(&benchmark).expect("Always valid");
+
+        assert_eq!(
+            csv.lines().nth(1),
+            Some(r#""EVMInterpreter", "", "", "tests/test.sol", "", "", , , , , , 30, , , , , "#)
+        );
+    }
+}
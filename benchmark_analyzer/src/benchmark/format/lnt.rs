@@ -0,0 +1,126 @@
+//!
+//! Serializing benchmark data to the LLVM LNT report format.
+//!
+
+use super::Benchmark;
+use super::IBenchmarkSerializer;
+
+///
+/// Serialize the benchmark data to the LNT report format, version 2.
+///
+/// Every benchmark element becomes an LNT test named `<group>/<element>`, with the metrics
+/// recorded for its group.
+///
+#[derive(Default)]
+pub struct Lnt;
+
+impl Lnt {
+    /// The LNT report format version.
+    pub const FORMAT_VERSION: &'static str = "2";
+
+    /// The LNT machine name, if the benchmark context is not provided.
+    pub const MACHINE_NAME: &'static str = "era-compiler-tester";
+}
+
+impl IBenchmarkSerializer for Lnt {
+    type Err = serde_json::error::Error;
+
+    fn serialize_to_string(&self, benchmark: &Benchmark) -> Result<String, Self::Err> {
+        let mut tests = Vec::with_capacity(benchmark.groups.len());
+        for (group_name, group) in benchmark.groups.iter() {
+            for (element_name, element) in group.elements.iter() {
+                let mut test = serde_json::json!({
+                    "name": format!("{group_name}/{}", element_name.trim()),
+                });
+                if let Some(cycles) = element.cycles {
+                    test["cycles"] = serde_json::Value::from(cycles);
+                }
+                if let Some(ergs) = element.ergs {
+                    test["ergs"] = serde_json::Value::from(ergs);
+                }
+                if let Some(gas) = element.gas {
+                    test["gas"] = serde_json::Value::from(gas);
+                }
+                if let Some(size) = element.size {
+                    test["size"] = serde_json::Value::from(size);
+                }
+                if let Some(runtime_size) = element.runtime_size {
+                    test["runtime_size"] = serde_json::Value::from(runtime_size);
+                }
+                if let Some(metadata_size) = element.metadata_size {
+                    test["metadata_size"] = serde_json::Value::from(metadata_size);
+                }
+                if let Some(instructions) = element.instructions {
+                    test["instructions"] = serde_json::Value::from(instructions);
+                }
+                if let Some(stack_depth) = element.stack_depth {
+                    test["stack_depth"] = serde_json::Value::from(stack_depth);
+                }
+                if let Some(pubdata) = element.pubdata {
+                    test["pubdata"] = serde_json::Value::from(pubdata);
+                }
+                if let Some(cold_accesses) = element.cold_accesses {
+                    test["cold_accesses"] = serde_json::Value::from(cold_accesses);
+                }
+                if let Some(warm_accesses) = element.warm_accesses {
+                    test["warm_accesses"] = serde_json::Value::from(warm_accesses);
+                }
+                tests.push(test);
+            }
+        }
+
+        let mut run = serde_json::json!({});
+        if benchmark.incomplete {
+            run["incomplete"] = serde_json::Value::from(true);
+        }
+        let mut machine_name = Self::MACHINE_NAME;
+        if let Some(context) = benchmark.context.as_ref() {
+            machine_name = context.machine.as_str();
+            run["toolchain"] = serde_json::Value::from(context.toolchain.as_str());
+            run["llvm_commit"] = serde_json::Value::from(context.llvm_commit.as_str());
+        }
+        if let Some(configuration) = benchmark.configuration.as_ref() {
+            run["configuration"] = serde_json::Value::from(configuration.name.as_str());
+            run["llvm_options"] = serde_json::Value::from(configuration.llvm_options.join(" "));
+        }
+
+        let report = serde_json::json!({
+            "format_version": Self::FORMAT_VERSION,
+            "machine": {
+                "name": machine_name,
+            },
+            "run": run,
+            "tests": tests,
+        });
+        serde_json::to_string(&report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::benchmark::format::IBenchmarkSerializer;
+    use crate::benchmark::Benchmark;
+
+    use super::Lnt;
+
+    #[test]
+    fn incomplete() {
+        for (incomplete, expected) in [
+            (true, serde_json::Value::Bool(true)),
+            (false, serde_json::Value::Null),
+        ] {
+            let benchmark = Benchmark {
+                incomplete,
+                ..Benchmark::default()
+            };
+            let report: serde_json::Value = serde_json::from_str(
+                Lnt.serialize_to_string(&benchmark)
+                    .expect("Always valid")
+                    .as_str(),
+            )
+            .expect("Always valid");
+
+            assert_eq!(report["run"]["incomplete"], expected, "{incomplete}");
+        }
+    }
+}
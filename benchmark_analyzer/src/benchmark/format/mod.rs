@@ -4,6 +4,7 @@
 
 pub mod csv;
 pub mod json;
+pub mod lnt;
 
 use crate::benchmark::Benchmark;
 
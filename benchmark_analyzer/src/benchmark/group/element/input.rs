@@ -32,6 +32,16 @@ pub enum Input {
         /// Index in the array of inputs.
         input_index: usize,
     },
+    /// The state test transaction.
+    StateTransition {
+        /// Index in the array of inputs.
+        input_index: usize,
+    },
+    /// The immutables check.
+    Immutables {
+        /// Index in the array of inputs.
+        input_index: usize,
+    },
 }
 
 impl std::fmt::Display for Input {
@@ -49,6 +59,12 @@ impl std::fmt::Display for Input {
             Input::Balance { input_index } => {
                 f.write_fmt(format_args!("#balance_check:{input_index}"))
             }
+            Input::StateTransition { input_index } => {
+                f.write_fmt(format_args!("#state_transition:{input_index}"))
+            }
+            Input::Immutables { input_index } => {
+                f.write_fmt(format_args!("#immutables_check:{input_index}"))
+            }
         }
     }
 }
@@ -5,9 +5,13 @@
 pub mod input;
 pub mod selector;
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::benchmark::group::metric::Metric;
 use crate::benchmark::metadata::Metadata;
 
 ///
@@ -19,31 +23,81 @@ pub struct Element {
     pub metadata: Metadata,
     /// The contract size, `Some` for contracts deploys.
     pub size: Option<usize>,
-    /// The number of cycles.
-    pub cycles: usize,
-    /// The amount of ergs.
-    pub ergs: u64,
-    /// The amount of EVM gas.
-    pub gas: u64,
+    /// The contract runtime code size, `Some` for EraVM and REVM contracts deploys.
+    #[serde(default)]
+    pub runtime_size: Option<usize>,
+    /// The CBOR metadata length at the end of the runtime code, `Some` for REVM contracts deploys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_size: Option<usize>,
+    /// The contract size in instructions, `Some` for EraVM contracts deploys.
+    #[serde(default)]
+    pub instructions: Option<usize>,
+    /// The contract code size of each function, `Some` for EraVM contracts deploys with assembly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_sizes: Option<BTreeMap<String, usize>>,
+    /// The number of cycles, `None` if not recorded for the group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<usize>,
+    /// The amount of ergs, `None` if not recorded for the group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ergs: Option<u64>,
+    /// The amount of EVM gas, `None` if not recorded for the group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<u64>,
+    /// The maximum call stack depth, `Some` for EraVM executions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_depth: Option<usize>,
+    /// The estimated number of published pubdata bytes, `Some` for EraVM executions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pubdata: Option<usize>,
+    /// The number of cold EIP-2929 storage slot and account accesses, `Some` for REVM calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cold_accesses: Option<usize>,
+    /// The number of warm EIP-2929 storage slot and account accesses, `Some` for REVM calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warm_accesses: Option<usize>,
 }
 
 impl Element {
     ///
-    /// A shortcut constructor.
+    /// Clears the metrics not contained in `metrics`.
     ///
-    pub fn new(
-        metadata: Metadata,
-        size: Option<usize>,
-        cycles: usize,
-        ergs: u64,
-        gas: u64,
-    ) -> Self {
-        Self {
-            metadata,
-            size,
-            cycles,
-            ergs,
-            gas,
+    pub fn retain_metrics(&mut self, metrics: &BTreeSet<Metric>) {
+        if !metrics.contains(&Metric::Size) {
+            self.size = None;
+        }
+        if !metrics.contains(&Metric::RuntimeSize) {
+            self.runtime_size = None;
+        }
+        if !metrics.contains(&Metric::MetadataSize) {
+            self.metadata_size = None;
+        }
+        if !metrics.contains(&Metric::Instructions) {
+            self.instructions = None;
+        }
+        if !metrics.contains(&Metric::FunctionSizes) {
+            self.function_sizes = None;
+        }
+        if !metrics.contains(&Metric::Cycles) {
+            self.cycles = None;
+        }
+        if !metrics.contains(&Metric::Ergs) {
+            self.ergs = None;
+        }
+        if !metrics.contains(&Metric::Gas) {
+            self.gas = None;
+        }
+        if !metrics.contains(&Metric::StackDepth) {
+            self.stack_depth = None;
+        }
+        if !metrics.contains(&Metric::Pubdata) {
+            self.pubdata = None;
+        }
+        if !metrics.contains(&Metric::ColdAccesses) {
+            self.cold_accesses = None;
+        }
+        if !metrics.contains(&Metric::WarmAccesses) {
+            self.warm_accesses = None;
         }
     }
 }
@@ -0,0 +1,57 @@
+//!
+//! The benchmark metric.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The benchmark metric.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Metric {
+    /// The contract size.
+    Size,
+    /// The contract runtime code size.
+    RuntimeSize,
+    /// The contract CBOR metadata length.
+    MetadataSize,
+    /// The contract size in instructions.
+    Instructions,
+    /// The contract code size of each function.
+    FunctionSizes,
+    /// The number of cycles.
+    Cycles,
+    /// The amount of ergs.
+    Ergs,
+    /// The amount of EVM gas.
+    Gas,
+    /// The maximum call stack depth.
+    StackDepth,
+    /// The estimated number of published pubdata bytes.
+    Pubdata,
+    /// The number of cold storage slot and account accesses.
+    ColdAccesses,
+    /// The number of warm storage slot and account accesses.
+    WarmAccesses,
+}
+
+impl std::fmt::Display for Metric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Size => write!(f, "size"),
+            Self::RuntimeSize => write!(f, "runtime_size"),
+            Self::MetadataSize => write!(f, "metadata_size"),
+            Self::Instructions => write!(f, "instructions"),
+            Self::FunctionSizes => write!(f, "function_sizes"),
+            Self::Cycles => write!(f, "cycles"),
+            Self::Ergs => write!(f, "ergs"),
+            Self::Gas => write!(f, "gas"),
+            Self::StackDepth => write!(f, "stack_depth"),
+            Self::Pubdata => write!(f, "pubdata"),
+            Self::ColdAccesses => write!(f, "cold_accesses"),
+            Self::WarmAccesses => write!(f, "warm_accesses"),
+        }
+    }
+}
@@ -3,9 +3,11 @@
 //!
 
 pub mod element;
+pub mod metric;
 pub mod results;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -13,6 +15,7 @@ use serde::Serialize;
 use crate::benchmark::Benchmark;
 
 use self::element::Element;
+use self::metric::Metric;
 use self::results::Results;
 
 ///
@@ -22,9 +25,57 @@ use self::results::Results;
 pub struct Group {
     /// The group elements.
     pub elements: BTreeMap<String, Element>,
+    /// The metrics recorded for the group, all if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<BTreeSet<Metric>>,
 }
 
 impl Group {
+    /// The EVM interpreter test, whose deployers are not compared.
+    const INTERPRETER_TEST_PATH: &'static str = "tests/solidity/complex/interpreter/test.json";
+
+    ///
+    /// A shortcut constructor for the group recording only the specified `metrics`.
+    ///
+    pub fn with_metrics(metrics: Option<BTreeSet<Metric>>) -> Self {
+        Self {
+            elements: BTreeMap::new(),
+            metrics,
+        }
+    }
+
+    ///
+    /// Inserts the `element`, clearing the metrics not recorded for the group.
+    ///
+    pub fn insert(&mut self, key: String, mut element: Element) {
+        if let Some(metrics) = self.metrics.as_ref() {
+            element.retain_metrics(metrics);
+        }
+        self.elements.insert(key, element);
+    }
+
+    ///
+    /// Returns the elements present in both `reference` and `candidate`, which are compared.
+    ///
+    /// The deployers of the EVM interpreter test are skipped, as they only deploy the contracts
+    /// measured by the other inputs.
+    ///
+    pub fn common_elements<'a>(
+        reference: &'a Self,
+        candidate: &'a Self,
+    ) -> impl Iterator<Item = (&'a String, &'a Element, &'a Element)> {
+        reference
+            .elements
+            .iter()
+            .filter(|(path, _)| {
+                !(path.contains(Self::INTERPRETER_TEST_PATH) && path.contains("#deployer"))
+            })
+            .filter_map(|(path, reference)| {
+                let candidate = candidate.elements.get(path.as_str())?;
+                Some((path, reference, candidate))
+            })
+    }
+
     ///
     /// Compares two benchmark groups.
     ///
@@ -63,67 +114,95 @@ impl Group {
         let mut gas_total_reference: u64 = 0;
         let mut gas_total_candidate: u64 = 0;
 
-        for (path, reference) in reference.elements.iter() {
-            if path.contains("tests/solidity/complex/interpreter/test.json")
-                && path.contains("#deployer")
+        let mut pubdata_total_reference: Option<u64> = None;
+        let mut pubdata_total_candidate: u64 = 0;
+
+        let mut runtime_size_total_reference: Option<u64> = None;
+        let mut runtime_size_total_candidate: u64 = 0;
+
+        let mut metadata_size_total_reference: Option<u64> = None;
+        let mut metadata_size_total_candidate: u64 = 0;
+
+        for (path, reference, candidate) in Self::common_elements(reference, candidate) {
+            if let (Some(reference_cycles), Some(candidate_cycles)) =
+                (reference.cycles, candidate.cycles)
             {
-                continue;
+                cycles_total_reference += reference_cycles as u64;
+                cycles_total_candidate += candidate_cycles as u64;
+                let cycles_factor = (candidate_cycles as f64) / (reference_cycles as f64);
+                if cycles_factor > 1.0 {
+                    cycles_negatives.push((cycles_factor, path.as_str()));
+                }
+                if cycles_factor < 1.0 {
+                    cycles_positives.push((cycles_factor, path.as_str()));
+                }
+                if cycles_factor < cycles_min {
+                    cycles_min = cycles_factor;
+                }
+                if cycles_factor > cycles_max {
+                    cycles_max = cycles_factor;
+                }
+                cycles_factors.push(cycles_factor);
             }
-            let candidate = match candidate.elements.get(path.as_str()) {
-                Some(candidate) => candidate,
-                None => continue,
-            };
 
-            cycles_total_reference += reference.cycles as u64;
-            cycles_total_candidate += candidate.cycles as u64;
-            let cycles_factor = (candidate.cycles as f64) / (reference.cycles as f64);
-            if cycles_factor > 1.0 {
-                cycles_negatives.push((cycles_factor, path.as_str()));
-            }
-            if cycles_factor < 1.0 {
-                cycles_positives.push((cycles_factor, path.as_str()));
-            }
-            if cycles_factor < cycles_min {
-                cycles_min = cycles_factor;
-            }
-            if cycles_factor > cycles_max {
-                cycles_max = cycles_factor;
+            if let (Some(reference_ergs), Some(candidate_ergs)) = (reference.ergs, candidate.ergs) {
+                ergs_total_reference += reference_ergs;
+                ergs_total_candidate += candidate_ergs;
+                let ergs_factor = (candidate_ergs as f64) / (reference_ergs as f64);
+                if ergs_factor > 1.0 {
+                    ergs_negatives.push((ergs_factor, path.as_str()));
+                }
+                if ergs_factor < 1.0 {
+                    ergs_positives.push((ergs_factor, path.as_str()));
+                }
+                if ergs_factor < ergs_min {
+                    ergs_min = ergs_factor;
+                }
+                if ergs_factor > ergs_max {
+                    ergs_max = ergs_factor;
+                }
+                ergs_factors.push(ergs_factor);
             }
-            cycles_factors.push(cycles_factor);
 
-            ergs_total_reference += reference.ergs;
-            ergs_total_candidate += candidate.ergs;
-            let ergs_factor = (candidate.ergs as f64) / (reference.ergs as f64);
-            if ergs_factor > 1.0 {
-                ergs_negatives.push((ergs_factor, path.as_str()));
+            if let (Some(reference_gas), Some(candidate_gas)) = (reference.gas, candidate.gas) {
+                gas_total_reference += reference_gas;
+                gas_total_candidate += candidate_gas;
+                let gas_factor = (candidate_gas as f64) / (reference_gas as f64);
+                if gas_factor > 1.0 {
+                    gas_negatives.push((gas_factor, path.as_str()));
+                }
+                if gas_factor < 1.0 {
+                    gas_positives.push((gas_factor, path.as_str()));
+                }
+                if gas_factor < gas_min {
+                    gas_min = gas_factor;
+                }
+                if gas_factor > gas_max {
+                    gas_max = gas_factor;
+                }
+                gas_factors.push(gas_factor);
             }
-            if ergs_factor < 1.0 {
-                ergs_positives.push((ergs_factor, path.as_str()));
-            }
-            if ergs_factor < ergs_min {
-                ergs_min = ergs_factor;
-            }
-            if ergs_factor > ergs_max {
-                ergs_max = ergs_factor;
-            }
-            ergs_factors.push(ergs_factor);
 
-            gas_total_reference += reference.gas;
-            gas_total_candidate += candidate.gas;
-            let gas_factor = (candidate.gas as f64) / (reference.gas as f64);
-            if gas_factor > 1.0 {
-                gas_negatives.push((gas_factor, path.as_str()));
-            }
-            if gas_factor < 1.0 {
-                gas_positives.push((gas_factor, path.as_str()));
+            if let (Some(reference_pubdata), Some(candidate_pubdata)) =
+                (reference.pubdata, candidate.pubdata)
+            {
+                *pubdata_total_reference.get_or_insert(0) += reference_pubdata as u64;
+                pubdata_total_candidate += candidate_pubdata as u64;
             }
-            if gas_factor < gas_min {
-                gas_min = gas_factor;
+
+            if let (Some(reference_runtime_size), Some(candidate_runtime_size)) =
+                (reference.runtime_size, candidate.runtime_size)
+            {
+                *runtime_size_total_reference.get_or_insert(0) += reference_runtime_size as u64;
+                runtime_size_total_candidate += candidate_runtime_size as u64;
             }
-            if gas_factor > gas_max {
-                gas_max = gas_factor;
+
+            if let (Some(reference_metadata_size), Some(candidate_metadata_size)) =
+                (reference.metadata_size, candidate.metadata_size)
+            {
+                *metadata_size_total_reference.get_or_insert(0) += reference_metadata_size as u64;
+                metadata_size_total_candidate += candidate_metadata_size as u64;
             }
-            gas_factors.push(gas_factor);
 
             let reference_size = match reference.size {
                 Some(size) => size,
@@ -159,7 +238,7 @@ impl Group {
 
         let gas_total = (gas_total_candidate as f64) / (gas_total_reference as f64);
 
-        Results::new(
+        let mut results = Results::new(
             size_min,
             size_max,
             size_total,
@@ -180,7 +259,23 @@ impl Group {
             gas_total,
             gas_negatives,
             gas_positives,
-        )
+        );
+        if let Some(pubdata_total_reference) = pubdata_total_reference {
+            results.set_pubdata_total(
+                (pubdata_total_candidate as f64) / (pubdata_total_reference as f64),
+            );
+        }
+        if let Some(runtime_size_total_reference) = runtime_size_total_reference {
+            results.set_runtime_size_total(
+                (runtime_size_total_candidate as f64) / (runtime_size_total_reference as f64),
+            );
+        }
+        if let Some(metadata_size_total_reference) = metadata_size_total_reference {
+            results.set_metadata_size_total(
+                (metadata_size_total_candidate as f64) / (metadata_size_total_reference as f64),
+            );
+        }
+        results
     }
 
     ///
@@ -201,11 +296,137 @@ impl Group {
                 .try_into()
                 .expect("Always valid");
 
-            let ergs_difference = full.ergs - template.ergs;
-            let gas_difference = full.gas - template.gas;
+            let ergs_difference = full.ergs.unwrap_or_default() - template.ergs.unwrap_or_default();
+            let gas_difference = full.gas.unwrap_or_default() - template.gas.unwrap_or_default();
             let ergs_gas_ratio = (ergs_difference as f64) / (gas_difference as f64);
             results.push((evm_opcode.to_owned(), ergs_gas_ratio));
         }
         results
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::benchmark::group::element::selector::Selector;
+    use crate::benchmark::group::element::Element;
+    use crate::benchmark::group::metric::Metric;
+    use crate::benchmark::metadata::Metadata;
+
+    use super::Group;
+
+    ///
+    /// Creates an element with all mandatory metrics and the size.
+    ///
+    fn element(cycles: usize, ergs: u64, gas: u64) -> Element {
+        Element {
+            metadata: Metadata {
+                selector: Selector {
+                    path: "tests/test.sol".to_owned(),
+                    case: None,
+                    input: None,
+                },
+                mode: None,
+                version: None,
+                group: "EVMInterpreter".to_owned(),
+                target: None,
+            },
+            size: Some(100),
+            runtime_size: None,
+            metadata_size: None,
+            instructions: None,
+            function_sizes: None,
+            cycles: Some(cycles),
+            ergs: Some(ergs),
+            gas: Some(gas),
+            stack_depth: None,
+            pubdata: None,
+            cold_accesses: None,
+            warm_accesses: None,
+        }
+    }
+
+    #[test]
+    fn common_elements() {
+        let mut reference = Group::with_metrics(None);
+        let mut candidate = Group::with_metrics(None);
+        for path in [
+            "tests/solidity/simple/default.sol",
+            "tests/solidity/complex/interpreter/test.json[#deployer:Test]",
+            "tests/solidity/complex/interpreter/test.json[add]",
+        ] {
+            reference.insert(path.to_owned(), element(10, 20, 30));
+            candidate.insert(path.to_owned(), element(10, 20, 30));
+        }
+        reference.insert("tests/reference_only.sol".to_owned(), element(10, 20, 30));
+
+        let paths: Vec<&str> = Group::common_elements(&reference, &candidate)
+            .map(|(path, _, _)| path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "tests/solidity/complex/interpreter/test.json[add]",
+                "tests/solidity/simple/default.sol",
+            ]
+        );
+    }
+
+    #[test]
+    fn insert() {
+        let mut group = Group::with_metrics(Some(BTreeSet::from([Metric::Gas])));
+
+        group.insert("test".to_owned(), element(10, 20, 30));
+
+        let element = group.elements.get("test").expect("Always exists");
+        assert_eq!(element.cycles, None);
+        assert_eq!(element.ergs, None);
+        assert_eq!(element.gas, Some(30));
+        assert_eq!(element.size, None);
+        let json = serde_json::to_value(element).expect("Always valid");
+        for (field, is_present) in [("cycles", false), ("ergs", false), ("gas", true)] {
+            assert_eq!(json.get(field).is_some(), is_present, "{field}");
+        }
+    }
+
+    #[test]
+    fn insert_all() {
+        let mut group = Group::with_metrics(None);
+
+        group.insert("test".to_owned(), element(10, 20, 30));
+
+        let element = group.elements.get("test").expect("Always exists");
+        assert_eq!(
+            (element.cycles, element.ergs, element.gas, element.size),
+            (Some(10), Some(20), Some(30), Some(100))
+        );
+    }
+
+    #[test]
+    fn deserialize_recorded() {
+        let json = r#"{
+            "metadata": { "selector": { "path": "tests/test.sol", "case": null, "input": null }, "mode": null, "version": null, "group": "" },
+            "size": null,
+            "cycles": 10,
+            "ergs": 20,
+            "gas": 30
+        }"#;
+        let element: Element = serde_json::from_str(json).expect("Always valid");
+        assert_eq!(
+            (element.cycles, element.ergs, element.gas),
+            (Some(10), Some(20), Some(30))
+        );
+
+        let json = r#"{
+            "metadata": { "selector": { "path": "tests/test.sol", "case": null, "input": null }, "mode": null, "version": null, "group": "" },
+            "size": null,
+            "gas": 30
+        }"#;
+        let element: Element = serde_json::from_str(json).expect("Always valid");
+        assert_eq!(
+            (element.cycles, element.ergs, element.gas),
+            (None, None, Some(30))
+        );
+    }
+}
@@ -54,6 +54,14 @@ pub struct Results<'a> {
     /// The gas positive result test names.
     pub gas_positives: Vec<(f64, &'a str)>,
 
+    /// The pubdata total decrease result, `Some` if the pubdata is measured.
+    pub pubdata_total: Option<f64>,
+
+    /// The runtime code size total decrease result, `Some` if the runtime size is measured.
+    pub runtime_size_total: Option<f64>,
+    /// The CBOR metadata length total decrease result, `Some` if the metadata is measured.
+    pub metadata_size_total: Option<f64>,
+
     /// The EVM interpreter reference ratios.
     pub evm_interpreter_reference_ratios: Option<Vec<(String, f64)>>,
     /// The EVM interpreter candidate ratios.
@@ -115,11 +123,37 @@ impl<'a> Results<'a> {
             gas_negatives,
             gas_positives,
 
+            pubdata_total: None,
+
+            runtime_size_total: None,
+            metadata_size_total: None,
+
             evm_interpreter_reference_ratios: None,
             evm_interpreter_candidate_ratios: None,
         }
     }
 
+    ///
+    /// Sets the pubdata total decrease result.
+    ///
+    pub fn set_pubdata_total(&mut self, pubdata_total: f64) {
+        self.pubdata_total = Some(pubdata_total);
+    }
+
+    ///
+    /// Sets the runtime code size total decrease result.
+    ///
+    pub fn set_runtime_size_total(&mut self, runtime_size_total: f64) {
+        self.runtime_size_total = Some(runtime_size_total);
+    }
+
+    ///
+    /// Sets the CBOR metadata length total decrease result.
+    ///
+    pub fn set_metadata_size_total(&mut self, metadata_size_total: f64) {
+        self.metadata_size_total = Some(metadata_size_total);
+    }
+
     ///
     /// Sets the EVM interpreter ratios.
     ///
@@ -314,6 +348,22 @@ impl<'a> Results<'a> {
             "Total".bright_white(),
             Self::format_f64(self.size_total)
         )?;
+        if let Some(runtime_size_total) = self.runtime_size_total {
+            writeln!(
+                w,
+                "║ {:33} {:07} ║",
+                "Runtime total".bright_white(),
+                Self::format_f64(runtime_size_total)
+            )?;
+        }
+        if let Some(metadata_size_total) = self.metadata_size_total {
+            writeln!(
+                w,
+                "║ {:33} {:07} ║",
+                "Metadata total".bright_white(),
+                Self::format_f64(metadata_size_total)
+            )?;
+        }
 
         writeln!(
             w,
@@ -393,6 +443,22 @@ impl<'a> Results<'a> {
             Self::format_f64(self.gas_total)
         )?;
 
+        if let Some(pubdata_total) = self.pubdata_total {
+            writeln!(
+                w,
+                "╠═╡ {} ╞{}╡ {} ╞═╣",
+                "Pubdata (-%)".bright_white(),
+                "═".repeat(cmp::max(21 - group_name.len(), 0)),
+                group_name.bright_white()
+            )?;
+            writeln!(
+                w,
+                "║ {:33} {:07} ║",
+                "Total".bright_white(),
+                Self::format_f64(pubdata_total)
+            )?;
+        }
+
         if let (Some(gas_reference_ratios), Some(gas_candidate_ratios)) = (
             self.evm_interpreter_reference_ratios.as_deref(),
             self.evm_interpreter_candidate_ratios.as_deref(),
@@ -26,4 +26,7 @@ pub struct Metadata {
     pub version: Option<Mode>,
     /// Test group
     pub group: String,
+    /// Target architecture, if several targets are benchmarked in a single run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
 }
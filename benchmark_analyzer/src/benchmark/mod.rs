@@ -2,9 +2,13 @@
 //! The benchmark representation.
 //!
 
+pub mod configuration;
+pub mod context;
+pub mod diff;
 pub mod format;
 pub mod group;
 pub mod metadata;
+pub mod size_attribution;
 
 use std::collections::BTreeMap;
 use std::path::PathBuf;
@@ -13,6 +17,8 @@ use format::IBenchmarkSerializer;
 use serde::Deserialize;
 use serde::Serialize;
 
+use self::configuration::Configuration;
+use self::context::Context;
 use self::group::results::Results;
 use self::group::Group;
 
@@ -23,6 +29,15 @@ use self::group::Group;
 pub struct Benchmark {
     /// The benchmark groups.
     pub groups: BTreeMap<String, Group>,
+    /// Whether the benchmark has been collected from an interrupted run.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub incomplete: bool,
+    /// The run context, if provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<Context>,
+    /// The LLVM options configuration, if the benchmark is a part of a sweep.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<Configuration>,
 }
 
 impl Benchmark {
@@ -32,6 +47,12 @@ impl Benchmark {
     /// The EVM interpreter cycles group identifier.
     pub const EVM_INTERPRETER_GROUP_NAME_CYCLES: &'static str = "EVMInterpreter M3B3";
 
+    /// The EraVM system contracts group identifier.
+    pub const SYSTEM_CONTRACTS_GROUP_NAME: &'static str = "SystemContracts";
+
+    /// The real-world protocol macro-benchmarks group identifier.
+    pub const REAL_WORLD_GROUP_NAME: &'static str = "RealWorld";
+
     /// The EVM opcodes to test.
     pub const EVM_OPCODES: [&'static str; 135] = [
         "ADD",
@@ -208,11 +229,11 @@ impl Benchmark {
     /// Writes the benchmark results to a file using a provided serializer.
     ///
     pub fn write_to_file(
-        self,
+        &self,
         path: PathBuf,
         serializer: impl IBenchmarkSerializer,
     ) -> anyhow::Result<()> {
-        let contents = serializer.serialize_to_string(&self).expect("Always valid");
+        let contents = serializer.serialize_to_string(self).expect("Always valid");
         std::fs::write(path.as_path(), contents)
             .map_err(|error| anyhow::anyhow!("Benchmark file {path:?} reading: {error}"))?;
         Ok(())
@@ -0,0 +1,118 @@
+//!
+//! The benchmark code size attribution to functions.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::benchmark::Benchmark;
+
+///
+/// The benchmark code size attribution to functions.
+///
+/// Only the groups with all elements are considered, so that each contract is counted once per mode.
+///
+#[derive(Debug, Default)]
+pub struct SizeAttribution {
+    /// The reference and candidate function sizes, keyed by the group, element, and function.
+    pub sizes: BTreeMap<(String, String, String), (usize, usize)>,
+}
+
+impl SizeAttribution {
+    ///
+    /// Attributes the size deltas between the `reference` and `candidate` to functions.
+    ///
+    pub fn new(reference: &Benchmark, candidate: &Benchmark) -> Self {
+        let mut sizes: BTreeMap<(String, String, String), (usize, usize)> = BTreeMap::new();
+
+        for (index, benchmark) in [reference, candidate].into_iter().enumerate() {
+            for (group_name, group) in benchmark.groups.iter() {
+                if !group_name.starts_with(crate::BENCHMARK_ALL_GROUP_NAME) {
+                    continue;
+                }
+
+                for (element_name, element) in group.elements.iter() {
+                    let function_sizes = match element.function_sizes.as_ref() {
+                        Some(function_sizes) => function_sizes,
+                        None => continue,
+                    };
+                    let element_name = element_name
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    for (function, size) in function_sizes.iter() {
+                        let entry = sizes
+                            .entry((
+                                group_name.to_owned(),
+                                element_name.clone(),
+                                function.to_owned(),
+                            ))
+                            .or_default();
+                        if index == 0 {
+                            entry.0 = *size;
+                        } else {
+                            entry.1 = *size;
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { sizes }
+    }
+
+    ///
+    /// Returns the total size deltas by function name, sorted by the absolute delta descending.
+    ///
+    pub fn deltas_by_function(&self) -> Vec<(String, i64)> {
+        let mut deltas: BTreeMap<&str, i64> = BTreeMap::new();
+        for ((_, _, function), (reference, candidate)) in self.sizes.iter() {
+            *deltas.entry(function.as_str()).or_default() += *candidate as i64 - *reference as i64;
+        }
+
+        let mut deltas: Vec<(String, i64)> = deltas
+            .into_iter()
+            .filter(|(_, delta)| *delta != 0)
+            .map(|(function, delta)| (function.to_owned(), delta))
+            .collect();
+        deltas.sort_by_key(|(_, delta)| std::cmp::Reverse(delta.abs()));
+        deltas
+    }
+
+    ///
+    /// Prints the functions with the largest total size deltas.
+    ///
+    pub fn print_worst_results(&self, count: usize) {
+        let deltas = self.deltas_by_function();
+        println!(
+            "Function size (bytes) largest deltas {} out of {}:",
+            count,
+            deltas.len()
+        );
+        for (function, delta) in deltas.iter().take(count) {
+            println!("{delta:+010}: {function}");
+        }
+        println!();
+    }
+
+    ///
+    /// Writes the sizes in the differential folded stacks format, which is accepted by
+    /// the flamegraph tools, e.g. `flamegraph.pl` and `inferno-flamegraph`.
+    ///
+    /// Each line is `<group>;<element>;<function> <reference size> <candidate size>`.
+    ///
+    pub fn write_folded<W>(&self, writer: &mut W) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+    {
+        for ((group, element, function), (reference, candidate)) in self.sizes.iter() {
+            writeln!(
+                writer,
+                "{};{};{} {reference} {candidate}",
+                group.replace(';', ":"),
+                element.replace(';', ":"),
+                function.replace(';', ":"),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ use std::path::PathBuf;
 
 use clap::Parser;
 
+use crate::output_format::OutputFormat;
+
 ///
 /// The benchmark analyzer arguments.
 ///
@@ -24,7 +26,16 @@ pub struct Arguments {
     #[structopt(short = 'o', long)]
     pub output_file: Option<PathBuf>,
 
+    /// The output format: `text` (default) or `json-diff`.
+    #[structopt(long, default_value_t = OutputFormat::Text)]
+    pub output_format: OutputFormat,
+
     /// Maximum number of results displayed in a group.
     #[structopt(long, default_value_t = 100)]
     pub group_max: usize,
+
+    /// The output file of the EraVM code size deltas attributed to functions, in the differential
+    /// folded stacks format accepted by the flamegraph tools.
+    #[structopt(long)]
+    pub size_attribution: Option<PathBuf>,
 }
@@ -3,12 +3,14 @@
 //!
 
 pub(crate) mod arguments;
+pub(crate) mod output_format;
 
 use std::io::Write;
 
 use clap::Parser;
 
 use self::arguments::Arguments;
+use self::output_format::OutputFormat;
 
 ///
 /// The application entry point.
@@ -18,9 +20,31 @@ fn main() -> anyhow::Result<()> {
 
     let reference = benchmark_analyzer::Benchmark::try_from(arguments.reference)?;
     let candidate = benchmark_analyzer::Benchmark::try_from(arguments.candidate)?;
+    for (name, benchmark) in [("Reference", &reference), ("Candidate", &candidate)] {
+        if benchmark.incomplete {
+            eprintln!("Warning: {name} benchmark has been collected from an interrupted run");
+        }
+    }
+
+    if let OutputFormat::JsonDiff = arguments.output_format {
+        let diff = benchmark_analyzer::BenchmarkDiff::new(&reference, &candidate);
+        let contents = serde_json::to_string_pretty(&diff).expect("Always valid");
+        match arguments.output_file {
+            Some(output_path) => std::fs::write(output_path, contents)?,
+            None => println!("{contents}"),
+        }
+        return Ok(());
+    }
 
     let groups_results = benchmark_analyzer::Benchmark::compare(&reference, &candidate);
 
+    if let Some(path) = arguments.size_attribution {
+        let size_attribution = benchmark_analyzer::SizeAttribution::new(&reference, &candidate);
+        size_attribution.print_worst_results(arguments.group_max);
+        let mut file = std::fs::File::create(path)?;
+        size_attribution.write_folded(&mut file)?;
+    }
+
     match arguments.output_file {
         Some(output_path) => {
             let mut file = std::fs::File::create(output_path)?;
@@ -0,0 +1,44 @@
+//!
+//! The benchmark analyzer output format.
+//!
+
+///
+/// The benchmark analyzer output format.
+///
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// The human-readable tables.
+    #[default]
+    Text,
+    /// The machine-readable JSON diff.
+    JsonDiff,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json-diff" => Ok(Self::JsonDiff),
+            string => anyhow::bail!(
+                "Unknown output format `{string}`. Supported formats: {}",
+                [Self::Text, Self::JsonDiff]
+                    .into_iter()
+                    .map(|element| element.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            Self::Text => "text",
+            Self::JsonDiff => "json-diff",
+        };
+        f.write_str(repr)
+    }
+}
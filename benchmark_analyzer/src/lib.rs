@@ -4,13 +4,23 @@
 
 pub(crate) mod benchmark;
 
+pub use self::benchmark::configuration::Configuration as BenchmarkConfiguration;
+pub use self::benchmark::context::Context as BenchmarkContext;
+pub use self::benchmark::diff::classification::Classification as BenchmarkClassification;
+pub use self::benchmark::diff::delta::Delta as BenchmarkDelta;
+pub use self::benchmark::diff::element::ElementDiff as BenchmarkElementDiff;
+pub use self::benchmark::diff::group::GroupDiff as BenchmarkGroupDiff;
+pub use self::benchmark::diff::Diff as BenchmarkDiff;
 pub use self::benchmark::format::csv::Csv as CsvSerializer;
 pub use self::benchmark::format::json::Json as JsonSerializer;
+pub use self::benchmark::format::lnt::Lnt as LntSerializer;
 pub use self::benchmark::group::element::input::Input;
 pub use self::benchmark::group::element::selector::Selector as TestSelector;
 pub use self::benchmark::group::element::Element as BenchmarkElement;
+pub use self::benchmark::group::metric::Metric as BenchmarkMetric;
 pub use self::benchmark::group::Group as BenchmarkGroup;
 pub use self::benchmark::metadata::Metadata;
+pub use self::benchmark::size_attribution::SizeAttribution;
 pub use self::benchmark::Benchmark;
 
 ///
@@ -0,0 +1,52 @@
+//! { "cases": [ {
+//!     "name": "drain",
+//!     "inputs": [
+//!         {
+//!             "method": "deposit",
+//!             "calldata": [],
+//!             "value": "3 wei"
+//!         },
+//!         {
+//!             "instance": "$Attacker",
+//!             "method": "attack(address,bytes,bytes,uint256)",
+//!             "calldata": [
+//!                 "Test.address",
+//!                 "0x80",
+//!                 "0xc0",
+//!                 "2",
+//!                 "4",
+//!                 "0xd0e30db000000000000000000000000000000000000000000000000000000000",
+//!                 "4",
+//!                 "0x3ccfd60b00000000000000000000000000000000000000000000000000000000"
+//!             ],
+//!             "value": "1 wei",
+//!             "expected": [ "3" ]
+//!         },
+//!         {
+//!             "instance": "$Attacker",
+//!             "method": "reentries()",
+//!             "calldata": [],
+//!             "expected": [ "2" ]
+//!         }
+//!     ]
+//! } ] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.0;
+
+/// The bank vulnerable to re-entrancy, which pays out before clearing the balance.
+contract Test {
+    mapping(address => uint256) public balances;
+
+    function deposit() external payable {
+        balances[msg.sender] += msg.value;
+    }
+
+    function withdraw() external {
+        uint256 amount = balances[msg.sender];
+        (bool success, ) = msg.sender.call{value: amount}("");
+        require(success);
+        balances[msg.sender] = 0;
+    }
+}
@@ -0,0 +1,11 @@
+//! { "suppressed_errors": [], "expected_compilation_error": "send/transfer", "modes": [ "Y+" ], "cases": [] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.0;
+
+contract Test {
+    function pay(address payable receiver) external {
+        receiver.transfer(1);
+    }
+}
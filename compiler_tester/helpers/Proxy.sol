@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.6.2 <0.9.0;
+
+/// The built-in helper forwarding its calls to an implementation.
+///
+/// The calls are delegated to the implementation, whose address is kept in the EIP-1967 slot,
+/// so it does not collide with the implementation storage. The return data and reverts are
+/// bubbled up as is.
+contract Proxy {
+    bytes32 internal constant IMPLEMENTATION_SLOT =
+        0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc;
+
+    function setImplementation(address _implementation) public {
+        bytes32 slot = IMPLEMENTATION_SLOT;
+        assembly {
+            sstore(slot, _implementation)
+        }
+    }
+
+    function implementation() public view returns (address _implementation) {
+        bytes32 slot = IMPLEMENTATION_SLOT;
+        assembly {
+            _implementation := sload(slot)
+        }
+    }
+
+    fallback() external payable {
+        address _implementation = implementation();
+        assembly {
+            calldatacopy(0, 0, calldatasize())
+            let success := delegatecall(gas(), _implementation, 0, calldatasize(), 0, 0)
+            returndatacopy(0, 0, returndatasize())
+            switch success
+            case 0 {
+                revert(0, returndatasize())
+            }
+            default {
+                return(0, returndatasize())
+            }
+        }
+    }
+
+    receive() external payable {}
+}
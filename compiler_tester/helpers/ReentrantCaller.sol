@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity >=0.6.2 <0.9.0;
+
+/// The built-in helper re-entering a target from its callbacks.
+///
+/// Once armed, each call or plain transfer received by the helper re-enters the target
+/// with the armed calldata, until the re-entry budget is exhausted.
+contract ReentrantCaller {
+    address public target;
+    bytes public reentryData;
+    uint256 public reentriesLeft;
+    uint256 public reentries;
+    bool public lastReentrySuccess;
+
+    function arm(address _target, bytes memory _reentryData, uint256 _reentries) public {
+        target = _target;
+        reentryData = _reentryData;
+        reentriesLeft = _reentries;
+        reentries = 0;
+    }
+
+    function execute(address _target, bytes memory _data) public payable returns (bytes memory) {
+        (bool success, bytes memory result) = _target.call{value: msg.value}(_data);
+        if (!success) {
+            assembly {
+                revert(add(result, 32), mload(result))
+            }
+        }
+        return result;
+    }
+
+    fallback() external payable {
+        reenter();
+    }
+
+    receive() external payable {
+        reenter();
+    }
+
+    function reenter() internal {
+        if (reentriesLeft == 0) {
+            return;
+        }
+        reentriesLeft -= 1;
+        reentries += 1;
+        (bool success, ) = target.call(reentryData);
+        lastReentrySuccess = success;
+    }
+}
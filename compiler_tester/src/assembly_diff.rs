@@ -0,0 +1,104 @@
+//!
+//! The assembly diff between two modes.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The assembly diff between two modes.
+///
+/// Compares the assembly files dumped into the debug subdirectories of two modes,
+/// and writes a unified diff for each pair of files that differ.
+///
+pub struct AssemblyDiff;
+
+impl AssemblyDiff {
+    /// The assembly diff output subdirectory name.
+    pub const DIRECTORY: &'static str = "assembly_diff";
+
+    /// The EVM assembly file extension.
+    pub const EXTENSION_EVM_ASSEMBLY: &'static str = "asm";
+
+    /// The diff file extension.
+    pub const EXTENSION_DIFF: &'static str = "diff";
+
+    ///
+    /// Writes the diffs between the assembly dumps of `mode_a` and `mode_b`.
+    ///
+    /// Returns the number of diff files written.
+    ///
+    pub fn dump(
+        debug_config: &era_compiler_llvm_context::DebugConfig,
+        mode_a: &str,
+        mode_b: &str,
+    ) -> anyhow::Result<usize> {
+        let directory_a =
+            crate::utils::debug_config_for_mode(debug_config, mode_a).output_directory;
+        let directory_b =
+            crate::utils::debug_config_for_mode(debug_config, mode_b).output_directory;
+        let output_directory = debug_config.output_directory.join(Self::DIRECTORY).join(
+            crate::utils::sanitize_file_name(format!("{mode_a}__{mode_b}").as_str()),
+        );
+
+        let mut count = 0;
+        for path_a in Self::assembly_files(directory_a.as_path())?.into_iter() {
+            let relative_path = path_a.strip_prefix(directory_a.as_path())?;
+            let path_b = directory_b.join(relative_path);
+            if !path_b.exists() {
+                continue;
+            }
+
+            let assembly_a = std::fs::read_to_string(path_a.as_path())
+                .map_err(|error| anyhow::anyhow!("Assembly file {path_a:?} reading: {error}"))?;
+            let assembly_b = std::fs::read_to_string(path_b.as_path())
+                .map_err(|error| anyhow::anyhow!("Assembly file {path_b:?} reading: {error}"))?;
+            if assembly_a == assembly_b {
+                continue;
+            }
+
+            let diff = similar::TextDiff::from_lines(assembly_a.as_str(), assembly_b.as_str())
+                .unified_diff()
+                .header(
+                    path_a.to_string_lossy().as_ref(),
+                    path_b.to_string_lossy().as_ref(),
+                )
+                .to_string();
+
+            let mut output_path = output_directory.join(relative_path);
+            let mut extension = output_path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_string())
+                .unwrap_or_default();
+            extension.push('.');
+            extension.push_str(Self::EXTENSION_DIFF);
+            output_path.set_extension(extension);
+            if let Some(parent) = output_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(output_path.as_path(), diff).map_err(|error| {
+                anyhow::anyhow!("Assembly diff file {output_path:?} writing: {error}")
+            })?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    ///
+    /// Returns all assembly files in the `directory`, recursively.
+    ///
+    fn assembly_files(directory: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for extension in [
+            era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
+            Self::EXTENSION_EVM_ASSEMBLY,
+        ] {
+            let pattern = directory.join(format!("**/*.{extension}"));
+            for path in glob::glob(pattern.to_string_lossy().as_ref())? {
+                files.push(path?);
+            }
+        }
+        Ok(files)
+    }
+}
@@ -0,0 +1,339 @@
+//!
+//! The raw bytecode run.
+//!
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use colored::Colorize;
+use revm::primitives::ExecutionResult as RevmExecutionResult;
+
+use crate::directories::matter_labs::test::DEFAULT_CALLER_ADDRESS;
+use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::output::Output;
+use crate::test::case::input::value::Value;
+use crate::vm::eravm::deployers::system_contract_deployer::SystemContractDeployer;
+use crate::vm::eravm::deployers::EraVMDeployer;
+use crate::vm::eravm::EraVM;
+use crate::vm::execution_result::ExecutionResult;
+use crate::vm::revm::revm_type_conversions::transform_revert_output;
+use crate::vm::revm::revm_type_conversions::transform_success_output;
+use crate::vm::revm::trace::Trace;
+use crate::vm::revm::Revm;
+
+///
+/// The raw bytecode run.
+///
+/// Deploys a bytecode without a test and calls it once, which is a scratchpad for bug triage.
+/// Both transactions are sent by the default caller of the Matter Labs tests.
+///
+#[derive(Debug)]
+pub struct BytecodeRun {
+    /// The target the bytecode has been run on.
+    pub target: era_compiler_common::Target,
+    /// The deploy transaction result.
+    pub deploy: ExecutionResult,
+    /// The deployed contract address, if the deployment has succeeded.
+    pub address: Option<web3::types::Address>,
+    /// The call transaction result, if the deployment has succeeded.
+    pub call: Option<ExecutionResult>,
+}
+
+impl BytecodeRun {
+    /// The name of the deploy transaction, used for its trace file.
+    pub const DEPLOY_NAME: &'static str = "run-bytecode-deploy";
+
+    /// The name of the call transaction, used for its trace file.
+    pub const CALL_NAME: &'static str = "run-bytecode-call";
+
+    ///
+    /// Deploys the EraVM `bytecode` on the `vm` and calls it with `calldata`.
+    ///
+    /// The traces are written to `./trace/` by the VM, as for the tests.
+    ///
+    pub fn run_eravm(vm: EraVM, bytecode: Vec<u8>, calldata: Vec<u8>) -> anyhow::Result<Self> {
+        if bytecode.is_empty() || bytecode.len() % era_compiler_common::BYTE_LENGTH_FIELD != 0 {
+            anyhow::bail!(
+                "EraVM bytecode size {} is not a positive multiple of {} bytes",
+                bytecode.len(),
+                era_compiler_common::BYTE_LENGTH_FIELD
+            );
+        }
+        let bytecode_words: Vec<[u8; era_compiler_common::BYTE_LENGTH_FIELD]> = bytecode
+            .as_slice()
+            .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+            .map(|word| word.try_into().expect("Always valid"))
+            .collect();
+        let bytecode_hash = zkevm_opcode_defs::utils::bytecode_to_code_hash_for_mode::<
+            { era_compiler_common::BYTE_LENGTH_X64 },
+            zkevm_opcode_defs::decoding::EncodingModeProduction,
+        >(bytecode_words.as_slice())
+        .map_err(|_| anyhow::anyhow!("EraVM bytecode hashing error"))?;
+        let bytecode_hash = web3::types::U256::from_big_endian(bytecode_hash.as_slice());
+
+        let mut vm = EraVM::clone_with_contracts(
+            Arc::new(vm),
+            HashMap::from([(bytecode_hash, bytecode)]),
+            None,
+        );
+        let caller = web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+
+        let deploy = SystemContractDeployer::new().deploy_eravm::<false>(
+            Self::DEPLOY_NAME.to_owned(),
+            caller,
+            bytecode_hash,
+            vec![],
+            None,
+            &mut vm,
+        )?;
+        let address = match Self::deployed_address(&deploy.output) {
+            Some(address) => address,
+            None => return Ok(Self::new(era_compiler_common::Target::EraVM, deploy, None)),
+        };
+
+        let call = vm.execute::<false>(
+            Self::CALL_NAME.to_owned(),
+            address,
+            caller,
+            None,
+            calldata,
+            None,
+        )?;
+        Ok(Self::new(
+            era_compiler_common::Target::EraVM,
+            deploy,
+            Some((address, call)),
+        ))
+    }
+
+    ///
+    /// Deploys the EVM `deploy_code` on REVM and calls it with `calldata`.
+    ///
+    /// The EIP-3155 struct log traces of both transactions are written to `trace_directory`.
+    ///
+    pub fn run_revm(
+        deploy_code: Vec<u8>,
+        calldata: Vec<u8>,
+        trace_directory: &Path,
+    ) -> anyhow::Result<Self> {
+        let caller = web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+
+        let vm = Revm::new().update_deploy_balance(&caller);
+        let mut vm = vm.fill_deploy_new_transaction(caller, None, None, deploy_code);
+        let mut trace = Trace::new(&vm);
+        let result = Trace::transact_commit(&mut vm, Some(&mut trace))
+            .map_err(|error| anyhow::anyhow!("REVM deploy transaction error: {error:?}"))?;
+        trace.write(&vm, trace_directory, Self::DEPLOY_NAME)?;
+        let deploy = Self::revm_execution_result(result);
+        let address = match Self::deployed_address(&deploy.output) {
+            Some(address) => address,
+            None => return Ok(Self::new(era_compiler_common::Target::EVM, deploy, None)),
+        };
+
+        let vm = vm.fill_runtime_new_transaction(
+            address,
+            caller,
+            Calldata { inner: calldata },
+            None,
+            None,
+        );
+        let mut vm = vm.update_balance_if_lack_of_funds(caller);
+        let mut trace = Trace::new(&vm);
+        let result = Trace::transact_commit(&mut vm, Some(&mut trace))
+            .map_err(|error| anyhow::anyhow!("REVM call transaction error: {error:?}"))?;
+        trace.write(&vm, trace_directory, Self::CALL_NAME)?;
+        let call = Self::revm_execution_result(result);
+        Ok(Self::new(
+            era_compiler_common::Target::EVM,
+            deploy,
+            Some((address, call)),
+        ))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    fn new(
+        target: era_compiler_common::Target,
+        deploy: ExecutionResult,
+        call: Option<(web3::types::Address, ExecutionResult)>,
+    ) -> Self {
+        let (address, call) = match call {
+            Some((address, call)) => (Some(address), Some(call)),
+            None => (None, None),
+        };
+        Self {
+            target,
+            deploy,
+            address,
+            call,
+        }
+    }
+
+    ///
+    /// Returns the address returned by a successful deployment.
+    ///
+    fn deployed_address(output: &Output) -> Option<web3::types::Address> {
+        match (output.exception, output.return_data.first()) {
+            (false, Some(Value::Certain(value))) => Some(crate::utils::u256_to_address(value)),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Converts the REVM transaction result.
+    ///
+    fn revm_execution_result(result: RevmExecutionResult) -> ExecutionResult {
+        let (output, gas) = match result {
+            RevmExecutionResult::Success {
+                gas_used,
+                logs,
+                output,
+                ..
+            } => (transform_success_output(output, logs), gas_used),
+            RevmExecutionResult::Revert { gas_used, output } => {
+                (transform_revert_output(output), gas_used)
+            }
+            RevmExecutionResult::Halt { gas_used, .. } => {
+                (Output::new(vec![], true, vec![]), gas_used)
+            }
+        };
+        ExecutionResult::new_evm(output, gas)
+    }
+
+    ///
+    /// Writes the `name` transaction `result`.
+    ///
+    fn fmt_result(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        name: &str,
+        result: &ExecutionResult,
+    ) -> std::fmt::Result {
+        let status = if result.output.exception {
+            "reverted".bright_red()
+        } else {
+            "succeeded".green()
+        };
+        let cost = match self.target {
+            era_compiler_common::Target::EraVM => {
+                format!("cycles {}, ergs {}", result.cycles, result.ergs)
+            }
+            era_compiler_common::Target::EVM => format!("gas {}", result.gas),
+        };
+        writeln!(f, "{:>12} {status} ({cost})", name.bright_white().bold())?;
+        let return_data: Vec<String> = result
+            .output
+            .return_data
+            .iter()
+            .map(|value| match value {
+                Value::Certain(value) => format!("0x{}", crate::utils::u256_as_string(value)),
+                Value::Any => "*".to_owned(),
+            })
+            .collect();
+        writeln!(f, "             return data [{}]", return_data.join(", "))?;
+        for event in result.output.events.iter() {
+            writeln!(f, "             event {event:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for BytecodeRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_result(f, "Deploy", &self.deploy)?;
+        match (self.address, self.call.as_ref()) {
+            (Some(address), Some(call)) => {
+                writeln!(
+                    f,
+                    "             address 0x{}",
+                    crate::utils::address_as_string(&address)
+                )?;
+                self.fmt_result(f, "Call", call)?;
+            }
+            _ => writeln!(f, "{:>12} skipped", "Call".bright_white().bold())?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::value::Value;
+
+    use super::BytecodeRun;
+
+    ///
+    /// The runtime code returning the word `0x2a`.
+    ///
+    const RUNTIME_CODE: &str = "602a60005260206000f3";
+
+    ///
+    /// Runs the EVM `deploy_code` on REVM with a temporary trace directory, and returns the run
+    /// with the names of the written trace files.
+    ///
+    fn revm_run(deploy_code: &str) -> (BytecodeRun, Vec<String>) {
+        let trace_directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-run-bytecode-{}-{}",
+            deploy_code.len(),
+            std::process::id()
+        ));
+        std::fs::create_dir_all(trace_directory.as_path()).expect("Always valid");
+
+        let run = BytecodeRun::run_revm(
+            hex::decode(deploy_code).expect("Always valid"),
+            vec![],
+            trace_directory.as_path(),
+        );
+        let mut traces: Vec<String> = std::fs::read_dir(trace_directory.as_path())
+            .expect("Always valid")
+            .map(|entry| {
+                entry
+                    .expect("Always valid")
+                    .file_name()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        traces.sort();
+        std::fs::remove_dir_all(trace_directory.as_path()).expect("Always valid");
+        (run.expect("Always valid"), traces)
+    }
+
+    #[test]
+    fn run_revm() {
+        // Copies the 10 bytes of the runtime code at offset 12 to memory, and returns them.
+        let (run, traces) = revm_run(format!("600a600c600039600a6000f3{RUNTIME_CODE}").as_str());
+
+        assert_eq!(run.target, era_compiler_common::Target::EVM);
+        assert!(!run.deploy.output.exception);
+        assert!(run.deploy.gas > 0);
+        assert!(run.address.is_some());
+        let call = run.call.as_ref().expect("Always exists");
+        assert!(!call.output.exception);
+        assert!(call.gas > 0);
+        assert_eq!(
+            call.output.return_data,
+            vec![Value::Certain(web3::types::U256::from(0x2a))]
+        );
+        assert_eq!(
+            traces,
+            vec![
+                "run_bytecode_call.jsonl".to_owned(),
+                "run_bytecode_deploy.jsonl".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn run_revm_reverted_deploy() {
+        let (run, traces) = revm_run("60006000fd");
+
+        assert!(run.deploy.output.exception);
+        assert!(run.address.is_none());
+        assert!(run.call.is_none());
+        assert_eq!(traces, vec!["run_bytecode_deploy.jsonl".to_owned()]);
+    }
+}
@@ -4,6 +4,7 @@ pub enum BenchmarkFormat {
     #[default]
     Json,
     Csv,
+    Lnt,
 }
 
 impl std::str::FromStr for BenchmarkFormat {
@@ -13,9 +14,10 @@ impl std::str::FromStr for BenchmarkFormat {
         match string.to_lowercase().as_str() {
             "json" => Ok(Self::Json),
             "csv" => Ok(Self::Csv),
+            "lnt" => Ok(Self::Lnt),
             string => anyhow::bail!(
                 "Unknown benchmark format `{string}`. Supported formats: {}",
-                vec![Self::Json, Self::Csv]
+                vec![Self::Json, Self::Csv, Self::Lnt]
                     .into_iter()
                     .map(|element| element.to_string().to_lowercase())
                     .collect::<Vec<String>>()
@@ -30,6 +32,7 @@ impl std::fmt::Display for BenchmarkFormat {
         let repr = match self {
             BenchmarkFormat::Json => "json",
             BenchmarkFormat::Csv => "csv",
+            BenchmarkFormat::Lnt => "lnt",
         };
         f.write_str(repr)
     }
@@ -0,0 +1,68 @@
+//!
+//! The benchmark output argument.
+//!
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use super::benchmark_format::BenchmarkFormat;
+
+///
+/// The benchmark output argument, parsed from `<path>` or `<path>:<format>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchmarkOutput {
+    /// The output file path.
+    pub path: PathBuf,
+    /// The output format, if specified explicitly.
+    pub format: Option<BenchmarkFormat>,
+}
+
+impl BenchmarkOutput {
+    ///
+    /// Returns the output with the configuration `name` inserted into the file name before the extension.
+    ///
+    pub fn with_configuration(&self, name: &str) -> Self {
+        let file_name = match (self.path.file_stem(), self.path.extension()) {
+            (Some(stem), Some(extension)) => format!(
+                "{}.{name}.{}",
+                stem.to_string_lossy(),
+                extension.to_string_lossy()
+            ),
+            _ => format!(
+                "{}.{name}",
+                self.path
+                    .file_name()
+                    .map(|file_name| file_name.to_string_lossy())
+                    .unwrap_or_default()
+            ),
+        };
+        Self {
+            path: self.path.with_file_name(file_name),
+            format: self.format.clone(),
+        }
+    }
+}
+
+impl FromStr for BenchmarkOutput {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some((path, format)) = string.rsplit_once(':') {
+            if let Ok(format) = BenchmarkFormat::from_str(format) {
+                if path.is_empty() {
+                    anyhow::bail!("Invalid benchmark output `{string}`: the path is empty");
+                }
+                return Ok(Self {
+                    path: PathBuf::from(path),
+                    format: Some(format),
+                });
+            }
+        }
+
+        Ok(Self {
+            path: PathBuf::from(string),
+            format: None,
+        })
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The compiler tester subcommand.
+//!
+
+use std::path::PathBuf;
+
+///
+/// The compiler tester subcommand.
+///
+#[derive(Debug, Clone, clap::Subcommand)]
+pub enum Command {
+    /// Compares two JSON summaries written with `--summary-output`, and prints the tests
+    /// whose status has changed. Fails if any test is newly failing or invalid.
+    CompareSummaries {
+        /// The old summary path.
+        old: PathBuf,
+        /// The new summary path.
+        new: PathBuf,
+    },
+    /// Writes a Markdown index of the tests grouped by directory and group, with the
+    /// comments of the Matter Labs test metadata and the header comments of the Ethereum tests.
+    Docs {
+        /// The Markdown index path.
+        output: PathBuf,
+    },
+    /// Prints the machine-readable JSON mapping of the summary error codes to their names.
+    ErrorCodes,
+    /// Lints the Matter Labs tests of the first `--target` for authoring errors, such as
+    /// references to undefined instances, unused instances, and mode filters matching no mode.
+    /// Fails if any warnings are found.
+    Lint {
+        /// Builds the tests with `solc` to check the expected return data word counts against
+        /// the ABI outputs of the called methods. The build can be restricted with `--mode`.
+        #[arg(long)]
+        abi: bool,
+    },
+    /// Runs the tests on `EVM` with the `solc` and `ir-llvm` (`solx`) toolchains in separate
+    /// processes, and prints the parity matrix of the tests passing with both, only one, or
+    /// neither of them. Runs the upstream semantic tests unless `--path` is specified.
+    Parity {
+        /// The parity matrix JSON output path.
+        #[arg(long)]
+        json_output: Option<PathBuf>,
+        /// The parity matrix Markdown dashboard output path.
+        #[arg(long)]
+        markdown_output: Option<PathBuf>,
+    },
+    /// Deploys the raw bytecode on the single `--target` and calls it with the calldata, writing
+    /// the execution traces. The bytecode is an EraVM bytecode on `EraVM`, and an EVM deploy code
+    /// on `EVM`, which is run on REVM.
+    RunBytecode {
+        /// The hexadecimal bytecode, with or without the `0x` prefix.
+        #[arg(long)]
+        code: String,
+        /// The hexadecimal calldata, with or without the `0x` prefix.
+        #[arg(long, default_value = "")]
+        calldata: String,
+    },
+}
@@ -5,16 +5,24 @@
 use std::path::PathBuf;
 
 use benchmark_format::BenchmarkFormat;
+use benchmark_output::BenchmarkOutput;
 use clap::Parser;
+use command::Command;
 
 pub mod benchmark_format;
+pub mod benchmark_output;
+pub mod command;
 
 ///
 /// The compiler tester arguments.
 ///
 #[derive(Debug, Parser)]
-#[command(about, long_about = None)]
+#[command(about, long_about = None, subcommand_negates_reqs = true)]
 pub struct Arguments {
+    /// The subcommand to run instead of the tests.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// The logging level.
     #[arg(short, long)]
     pub verbose: bool,
@@ -23,10 +31,20 @@ pub struct Arguments {
     #[arg(short, long)]
     pub quiet: bool,
 
+    /// Disables the colored output, and restricts the standard output of test runs to a single
+    /// final JSON document with the summary and benchmark, redirecting everything else to
+    /// the standard error. Intended for wrapper tooling.
+    #[arg(long, global = true)]
+    pub machine: bool,
+
     /// Saves all IRs produced by compilers to `./debug/` directory.
     #[arg(short = 'D', long)]
     pub debug: bool,
 
+    /// Removes the `./debug/` directory before the run, so it only contains the data of this run.
+    #[arg(long)]
+    pub clean_debug: bool,
+
     /// Runs tests only in modes that contain any string from the specified ones.
     #[arg(short, long)]
     pub mode: Vec<String>,
@@ -39,40 +57,161 @@ pub struct Arguments {
     #[structopt(short, long)]
     pub group: Vec<String>,
 
-    /// The benchmark output path, if requested.
+    /// Runs only tests with any of the specified tags.
+    #[structopt(long)]
+    pub tag: Vec<String>,
+
+    /// Skips tests with any of the specified tags.
+    #[structopt(long)]
+    pub exclude_tag: Vec<String>,
+
+    /// Runs only tests and cases listed in the specified files of newline-separated selectors.
+    #[structopt(long)]
+    pub include_from: Vec<PathBuf>,
+
+    /// Skips tests and cases listed in the specified files of newline-separated selectors.
+    #[structopt(long)]
+    pub exclude_from: Vec<PathBuf>,
+
+    /// The benchmark output paths, if requested.
+    /// Each path may be suffixed with its format, e.g. `benchmark.csv:csv`.
     #[structopt(short, long)]
-    pub benchmark: Option<PathBuf>,
+    pub benchmark: Vec<BenchmarkOutput>,
 
-    /// The benchmark output format.
+    /// The benchmark output format for paths without an explicit format.
     #[structopt(long = "benchmark-format", default_value_t = BenchmarkFormat::Json)]
     pub benchmark_format: BenchmarkFormat,
 
+    /// The JSON summary output path, if requested.
+    /// Summaries of two runs can be compared with the `compare-summaries` subcommand.
+    #[structopt(long)]
+    pub summary_output: Option<PathBuf>,
+
+    /// The JUnit XML report output path, if requested.
+    #[structopt(long)]
+    pub junit_output: Option<PathBuf>,
+
+    /// The path of the JSON lines file with the test outcomes, written as soon as they are available.
+    #[structopt(long)]
+    pub events_output: Option<PathBuf>,
+
+    /// The YAML file with the expected outcomes of known-failing tests, as `path -> mode filter -> xfail`.
+    /// Their failures are reported as expected, and their passes as unexpected, which fails the run.
+    #[structopt(long)]
+    pub expected_outcomes: Option<PathBuf>,
+
+    /// The benchmark context JSON file with the `machine`, `toolchain`, and `llvm_commit` fields.
+    /// The context is attached to the benchmark metadata.
+    #[structopt(long)]
+    pub benchmark_context: Option<PathBuf>,
+
+    /// The YAML benchmark policy file with the metrics recorded per group, as `groups -> group -> metrics`,
+    /// and the optional `default` metrics of the other groups. All metrics are recorded by default.
+    #[structopt(long)]
+    pub benchmark_policy: Option<PathBuf>,
+
+    /// Fails the run if a benchmarked test group or a benchmark policy group is not declared in
+    /// the benchmark groups registry, or if the policy does not record an expected metric
+    /// of a group. The violations are only reported as warnings by default.
+    #[structopt(long)]
+    pub strict_groups: bool,
+
+    /// Path to the benchmark groups registry file.
+    /// Defaults to `./configs/benchmark-groups.yaml`.
+    #[structopt(long)]
+    pub benchmark_registry_path: Option<PathBuf>,
+
     /// Sets the number of threads, which execute the tests concurrently.
     #[structopt(short, long)]
     pub threads: Option<usize>,
 
+    /// The number of seconds to wait for the running tests after an interruption signal.
+    /// Afterwards, the partial summary and benchmarks are written, and the process exits.
+    #[structopt(long, default_value_t = 60)]
+    pub interruption_timeout: u64,
+
+    /// The resident memory limit of the process in MiB. Once it is exceeded, no new tests are
+    /// scheduled, the partial summary and benchmarks are written, and the process exits with
+    /// code 3.
+    #[structopt(long)]
+    pub memory_limit: Option<u64>,
+
+    /// Logs every compiler subprocess invocation to the specified directory: its arguments,
+    /// environment changes, standard input, and a `replay.sh` script reproducing it.
+    #[structopt(long)]
+    pub log_compiler_invocations: Option<PathBuf>,
+
+    /// Dumps the unified diff of the assembly produced under the two specified modes.
+    /// Implies `--debug`. The diffs are written to the `assembly_diff` debug subdirectory.
+    /// Not supported by the `EVMInterpreter` environment.
+    #[structopt(long, num_args = 2, value_names = ["MODE_A", "MODE_B"])]
+    pub dump_assembly_diff: Vec<String>,
+
     /// Whether to dump the debug data for system contracts.
     #[structopt(long)]
     pub dump_system: bool,
 
     /// Whether the deployer should be disabled.
+    /// Overridden by the `deployer` metadata of tests requiring a specific one.
     #[structopt(long)]
     pub disable_deployer: bool,
 
     /// Whether the msg.value simulator should be disabled.
+    /// Overridden by the `system_mode` metadata of tests requiring a specific one.
     #[structopt(long)]
     pub disable_value_simulator: bool,
 
+    /// Whether to deploy only the system contracts required by every EraVM test.
+    /// The others are deployed when a test input calls them, or a test group requires them.
+    #[structopt(long)]
+    pub minimal_system_contracts: bool,
+
+    /// The YAML file with the system contracts required by test groups, as `group -> [names]`.
+    /// Only used with `--minimal-system-contracts`.
+    #[structopt(long)]
+    pub system_contracts_groups: Option<PathBuf>,
+
+    /// The default gas limit of calls on the EVM interpreter.
+    /// Can be overridden by the `evm_gas_limit` of Matter Labs test inputs.
+    #[structopt(long)]
+    pub evm_gas_limit: Option<u64>,
+
     /// Path to the `zksolc` executable.
-    /// Is set to `zksolc` by default.
+    /// Is set to the `ZKSOLC` environment variable or `zksolc` by default.
     #[structopt(long)]
     pub zksolc: Option<PathBuf>,
 
     /// Path to the `zkvyper` executable.
-    /// Is set to `zkvyper` by default.
+    /// Is set to the `ZKVYPER` environment variable or `zkvyper` by default.
     #[structopt(long)]
     pub zkvyper: Option<PathBuf>,
 
+    /// The `zksolc` release version to use, which is downloaded to the per-user toolchain directory
+    /// unless it is already cached there.
+    #[structopt(long, conflicts_with = "zksolc")]
+    pub zksolc_version: Option<semver::Version>,
+
+    /// The `zkvyper` release version to use, which is downloaded to the per-user toolchain directory
+    /// unless it is already cached there.
+    #[structopt(long, conflicts_with = "zkvyper")]
+    pub zkvyper_version: Option<semver::Version>,
+
+    /// The `vyper` versions to run the Vyper tests with, separated by commas, e.g. `0.3.10,0.4.0`.
+    /// Every version must have an executable downloaded to `vyper-bin/`.
+    /// All downloaded versions are used by default.
+    #[structopt(long, value_delimiter = ',')]
+    pub vyper_versions: Vec<semver::Version>,
+
+    /// The `zksolc` release versions to run the tests with, separated by commas.
+    /// Each version is run in a separate process, and the tests whose outcomes differ
+    /// across versions are printed as a table.
+    #[structopt(
+        long,
+        value_delimiter = ',',
+        conflicts_with_all = ["zksolc", "zksolc_version", "summary_output"]
+    )]
+    pub zksolc_versions: Vec<semver::Version>,
+
     /// Specify the compiler toolchain.
     /// Available arguments: `ir-llvm`, `solc`, `solc-llvm`.
     /// The default for `EraVM` target is `ir-llvm`.
@@ -80,10 +219,11 @@ pub struct Arguments {
     #[structopt(long)]
     pub toolchain: Option<compiler_tester::Toolchain>,
 
-    /// Specify the target architecture.
+    /// Specify the target architectures, separated by commas.
     /// Available arguments: `eravm`, `evm`.
-    #[structopt(long)]
-    pub target: era_compiler_common::Target,
+    /// Several targets are run one after another in a single invocation, e.g. `--target eravm,evm`.
+    #[structopt(long, required = true, value_delimiter = ',')]
+    pub target: Vec<era_compiler_common::Target>,
 
     /// Specify the environment to run tests on.
     /// Available arguments: `zk_evm`, `FastVM`, `EVMInterpreter`, `REVM`.
@@ -92,10 +232,116 @@ pub struct Arguments {
     #[structopt(long)]
     pub environment: Option<compiler_tester::Environment>,
 
-    /// Choose between `build` to compile tests only without running, and `run` to compile and run.
+    /// Runs the tests of the registered target extension instead of the built-in environment.
+    /// The extension base environment must belong to `--target`, which must be a single target.
+    #[structopt(long)]
+    pub target_extension: Option<String>,
+
+    /// Runs the tests again with the specified toolchain, and reports the shared Solidity tests
+    /// which pass with one toolchain and fail with the other.
+    /// The second run uses the default environment of `--cross-check-target`.
+    #[structopt(long)]
+    pub cross_check: Option<compiler_tester::Toolchain>,
+
+    /// Specify the target architecture of the cross-check run.
+    /// Is set to `--target` by default, which must be a single target.
+    #[structopt(long)]
+    pub cross_check_target: Option<era_compiler_common::Target>,
+
+    /// The JSON output path of the cross-check divergence list, if requested.
+    #[structopt(long)]
+    pub cross_check_output: Option<PathBuf>,
+
+    /// Builds the tests again with the specified toolchain without running them, and reports the
+    /// contracts whose method identifiers or ABI differ between the toolchains.
+    #[structopt(long)]
+    pub interface_check: Option<compiler_tester::Toolchain>,
+
+    /// Specify the target architecture of the interface check build.
+    /// Is set to `--target` by default, which must be a single target.
+    #[structopt(long)]
+    pub interface_check_target: Option<era_compiler_common::Target>,
+
+    /// The JSON output path of the interface mismatch list, if requested.
+    #[structopt(long)]
+    pub interface_check_output: Option<PathBuf>,
+
+    /// Choose between `build` to compile tests only without running, `run` to compile and run,
+    /// and `dry-run` to only print the number of compilations that would be performed.
     #[structopt(long, default_value_t = compiler_tester::Workflow::BuildAndRun)]
     pub workflow: compiler_tester::Workflow,
 
+    /// A shortcut for `--workflow dry-run`.
+    #[structopt(long)]
+    pub dry_run: bool,
+
+    /// Skips the tests whose builds and inputs are identical to the ones passed in a previous run.
+    /// The passed tests are remembered in `./result_cache.json`.
+    /// Ignored if a benchmark is requested, as the skipped tests would be missing from it.
+    #[structopt(long)]
+    pub use_result_cache: bool,
+
+    /// Whether to write the EIP-3155 struct log traces of the inputs failed on REVM.
+    /// The traces are written to `./debug/revm_trace/`.
+    #[structopt(long)]
+    pub revm_trace_on_failure: bool,
+
+    /// The number of the slowest tests by compilation and execution time to print and include
+    /// in the summary, 10 by default if only `--slow-threshold` is specified.
+    #[structopt(long)]
+    pub slowest_tests: Option<usize>,
+
+    /// The wall time in seconds, e.g. `2.5`, above which the tests are marked as slow.
+    #[structopt(long)]
+    pub slow_threshold: Option<f64>,
+
+    /// The time budget in minutes, e.g. `10`, within which the tests are selected using their
+    /// wall times from `--time-budget-summary`. The tests failed in that run are selected first,
+    /// then the ones changed since then, and then the fastest ones.
+    #[structopt(long, requires = "time_budget_summary")]
+    pub time_budget: Option<f64>,
+
+    /// The JSON summary of a previous run written with `--summary-output`, whose test wall times
+    /// are used by `--time-budget`.
+    #[structopt(long, requires = "time_budget")]
+    pub time_budget_summary: Option<PathBuf>,
+
+    /// The JSON file tracking since when the tests have been invalid, updated after every run.
+    /// The invalid tests are reported with the number of days they have been invalid.
+    #[structopt(long)]
+    pub invalid_tests_state: Option<PathBuf>,
+
+    /// Fails the run if any test has been invalid for more than the specified number of days.
+    #[structopt(long, requires = "invalid_tests_state")]
+    pub fail_on_stale_invalid: Option<u64>,
+
+    /// Runs the tests in the order randomized with the specified seed, which is printed, to
+    /// detect the tests depending on the ones run before them. The tests are run in a single
+    /// thread, so the order is kept.
+    #[structopt(long)]
+    pub shuffle: Option<u64>,
+
+    /// Runs the tests in the sorted order and in the order shuffled with the specified seed in
+    /// separate processes, and fails if any test status differs between the runs.
+    #[structopt(long, conflicts_with = "shuffle")]
+    pub shuffle_check: Option<u64>,
+
+    /// The hardfork to run the tests with on REVM, e.g. `shanghai`.
+    /// The contracts are compiled for it, and the Ethereum tests whose `EVMVersion` excludes it
+    /// are skipped. By default, the contracts are compiled for Cancun.
+    #[structopt(long)]
+    pub revm_spec: Option<String>,
+
+    /// Path to the YAML file mapping addresses to the stubs installed there on REVM,
+    /// e.g. to make the calls to EraVM system contracts return deterministic data.
+    #[structopt(long)]
+    pub revm_address_overrides: Option<PathBuf>,
+
+    /// The JSON output path of the actual results of the Ethereum tests calls on REVM, if requested.
+    /// Used by the `tests-updater` to rewrite the expectations of the failed tests.
+    #[structopt(long)]
+    pub expectations_output: Option<PathBuf>,
+
     /// Path to the default `solc` executables download configuration file.
     #[structopt(long)]
     pub solc_bin_config_path: Option<PathBuf>,
@@ -112,6 +358,16 @@ pub struct Arguments {
     #[structopt(long)]
     pub save_system_contracts: Option<PathBuf>,
 
+    /// The system contracts profile, e.g. of an upcoming protocol upgrade.
+    /// The profile name is appended to the system contracts load and save paths.
+    #[structopt(long)]
+    pub system_contracts_profile: Option<String>,
+
+    /// Path to the system contracts profiles file.
+    /// Defaults to `./configs/system-contracts-profiles.yaml`.
+    #[structopt(long)]
+    pub system_contracts_profiles_path: Option<PathBuf>,
+
     /// Sets the `verify each` option in LLVM.
     #[structopt(long)]
     pub llvm_verify_each: bool,
@@ -119,4 +375,23 @@ pub struct Arguments {
     /// Sets the `debug logging` option in LLVM.
     #[structopt(long)]
     pub llvm_debug_logging: bool,
+
+    /// The extra LLVM options passed to the backend, separated by spaces,
+    /// e.g. `--llvm-options='-inline-threshold=100'`.
+    #[structopt(long, allow_hyphen_values = true)]
+    pub llvm_options: Option<String>,
+
+    /// The name of the LLVM options configuration.
+    /// Tags the benchmark output, and is inserted into the benchmark output file names.
+    #[structopt(long)]
+    pub llvm_options_name: Option<String>,
+
+    /// The JSON file with named sets of extra LLVM options, e.g. `{"baseline": [], "tuned": ["-opt"]}`.
+    /// The tests are run with each configuration in a separate process, and the benchmark output
+    /// file names are suffixed with the configuration name.
+    #[structopt(
+        long,
+        conflicts_with_all = ["llvm_options", "llvm_options_name", "zksolc_versions"]
+    )]
+    pub llvm_options_matrix: Option<PathBuf>,
 }
@@ -0,0 +1,123 @@
+//!
+//! The compiler tester benchmark writer.
+//!
+
+use std::sync::Arc;
+
+use crate::arguments::benchmark_format::BenchmarkFormat;
+use crate::arguments::benchmark_output::BenchmarkOutput;
+use crate::arguments::Arguments;
+
+///
+/// The compiler tester benchmark writer.
+///
+/// Collects the summary benchmark of all run targets and writes it to the requested outputs,
+/// both on completion and on interruption.
+///
+#[derive(Clone)]
+pub struct BenchmarkWriter {
+    /// The run targets, `None` for a single-target run, with their toolchains.
+    pub targets: Vec<(
+        Option<era_compiler_common::Target>,
+        compiler_tester::Toolchain,
+    )>,
+    /// The target extension run instead of the built-in environment.
+    pub extension: Option<Arc<dyn compiler_tester::TargetExtension>>,
+    /// The benchmark outputs.
+    pub outputs: Vec<BenchmarkOutput>,
+    /// The format of the outputs without their own one.
+    pub default_format: BenchmarkFormat,
+    /// The benchmark context.
+    pub context: Option<benchmark_analyzer::BenchmarkContext>,
+    /// The benchmark LLVM options configuration.
+    pub configuration: Option<benchmark_analyzer::BenchmarkConfiguration>,
+}
+
+impl BenchmarkWriter {
+    ///
+    /// Creates the writer of the benchmark of the target `runs`, or of the target `extension` run
+    /// if any, to the outputs requested by the `arguments`, which are suffixed with the LLVM
+    /// options `configuration` name.
+    ///
+    pub fn new(
+        arguments: &Arguments,
+        runs: &[(
+            era_compiler_common::Target,
+            compiler_tester::Toolchain,
+            compiler_tester::Environment,
+        )],
+        extension: Option<Arc<dyn compiler_tester::TargetExtension>>,
+        configuration: Option<benchmark_analyzer::BenchmarkConfiguration>,
+    ) -> anyhow::Result<Self> {
+        let is_multi_target = runs.len() > 1;
+        Ok(Self {
+            targets: runs
+                .iter()
+                .map(|(target, toolchain, _)| (is_multi_target.then_some(*target), *toolchain))
+                .collect(),
+            extension,
+            outputs: match configuration.as_ref() {
+                Some(configuration) => arguments
+                    .benchmark
+                    .iter()
+                    .map(|output| output.with_configuration(configuration.name.as_str()))
+                    .collect(),
+                None => arguments.benchmark.clone(),
+            },
+            default_format: arguments.benchmark_format.clone(),
+            context: arguments
+                .benchmark_context
+                .clone()
+                .map(benchmark_analyzer::BenchmarkContext::try_from)
+                .transpose()?,
+            configuration,
+        })
+    }
+
+    ///
+    /// Writes the summary benchmark to all requested outputs.
+    ///
+    pub fn write(&self, summary: &compiler_tester::Summary) -> anyhow::Result<()> {
+        if self.outputs.is_empty() {
+            return Ok(());
+        }
+
+        let benchmark = self.collect(summary)?;
+        for output in self.outputs.iter() {
+            let path = output.path.clone();
+            match output.format.as_ref().unwrap_or(&self.default_format) {
+                BenchmarkFormat::Json => {
+                    benchmark.write_to_file(path, benchmark_analyzer::JsonSerializer)?
+                }
+                BenchmarkFormat::Csv => {
+                    benchmark.write_to_file(path, benchmark_analyzer::CsvSerializer)?
+                }
+                BenchmarkFormat::Lnt => {
+                    benchmark.write_to_file(path, benchmark_analyzer::LntSerializer)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Collects the summary benchmark of all targets.
+    ///
+    pub fn collect(
+        &self,
+        summary: &compiler_tester::Summary,
+    ) -> anyhow::Result<benchmark_analyzer::Benchmark> {
+        let mut benchmark = benchmark_analyzer::Benchmark::default();
+        for (target, toolchain) in self.targets.iter() {
+            let target_benchmark = match self.extension.as_ref() {
+                Some(extension) => summary.extension_benchmark(*toolchain, extension.as_ref())?,
+                None => summary.benchmark(*toolchain, *target)?,
+            };
+            benchmark.incomplete |= target_benchmark.incomplete;
+            benchmark.groups.extend(target_benchmark.groups);
+        }
+        benchmark.context = self.context.clone();
+        benchmark.configuration = self.configuration.clone();
+        Ok(benchmark)
+    }
+}
@@ -0,0 +1,32 @@
+//!
+//! The compiler tester subcommand: the summaries comparison.
+//!
+
+use colored::Colorize;
+use std::path::PathBuf;
+
+///
+/// Compares two JSON summaries and prints the tests whose status has changed.
+///
+pub fn run(old: PathBuf, new: PathBuf) -> anyhow::Result<()> {
+    let old = compiler_tester::SummaryReport::try_from(old)?;
+    let new = compiler_tester::SummaryReport::try_from(new)?;
+    if old.incomplete || new.incomplete {
+        eprintln!(
+            "    {} comparing an incomplete summary, some tests may be reported as disappeared",
+            "Warning".bright_yellow().bold(),
+        );
+    }
+
+    let comparison = compiler_tester::SummaryComparison::new(&old, &new);
+    print!("{comparison}");
+    if comparison.has_regressions() {
+        anyhow::bail!(
+            "{} tests are newly failing, {} tests are newly invalid",
+            comparison.newly_failing.len(),
+            comparison.newly_invalid.len(),
+        );
+    }
+
+    Ok(())
+}
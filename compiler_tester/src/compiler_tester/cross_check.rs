@@ -0,0 +1,87 @@
+//!
+//! The compiler tester cross-check of the main run with another toolchain.
+//!
+
+use colored::Colorize;
+
+use crate::arguments::Arguments;
+use crate::executable_download_config_paths;
+use crate::resolve_environment;
+use crate::run_settings::RunSettings;
+use crate::run_tests;
+
+///
+/// Runs the tests with the `--cross-check` toolchain, and compares their statuses with the
+/// `report` of the main run on `target` with `toolchain`.
+///
+pub fn run(
+    arguments: &Arguments,
+    target: era_compiler_common::Target,
+    toolchain: compiler_tester::Toolchain,
+    report: &compiler_tester::SummaryReport,
+    filters: compiler_tester::Filters,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    system_contracts_profile: compiler_tester::EraVMSystemContractsProfile,
+) -> anyhow::Result<()> {
+    let cross_check_toolchain = match arguments.cross_check {
+        Some(toolchain) => toolchain,
+        None => return Ok(()),
+    };
+    let cross_check_target = arguments.cross_check_target.unwrap_or(target);
+    println!(
+        "  {} tests with the `{cross_check_toolchain}` toolchain on `{cross_check_target}`",
+        "Cross-checking".bright_green().bold(),
+    );
+
+    let cross_check_summary =
+        compiler_tester::Summary::new(arguments.verbose, arguments.quiet).wrap();
+    let cross_check_tester = compiler_tester::CompilerTester::new(
+        cross_check_summary.clone(),
+        filters,
+        debug_config.clone(),
+        arguments.workflow,
+        None,
+    )?;
+    let mut run_settings = RunSettings::new(
+        cross_check_target,
+        cross_check_toolchain,
+        resolve_environment(cross_check_target, None)?,
+        executable_download_config_paths(
+            cross_check_toolchain,
+            None,
+            arguments.vyper_bin_config_path.clone(),
+        ),
+        system_contracts_profile,
+        arguments,
+    );
+    if arguments.dump_system {
+        run_settings.system_contracts_debug_config = debug_config;
+    }
+    run_settings.revm_trace_on_failure = arguments.revm_trace_on_failure;
+    run_settings.revm_address_overrides = arguments.revm_address_overrides.clone();
+    run_tests(cross_check_tester, run_settings)?;
+    let mut cross_check_summary = compiler_tester::Summary::unwrap_arc(cross_check_summary);
+    cross_check_summary.take_compilations();
+    print!("{cross_check_summary}");
+    if compiler_tester::is_interrupted() {
+        anyhow::bail!("The cross-check run has been interrupted");
+    }
+
+    let cross_check = compiler_tester::SummaryCrossCheck::new(
+        format!("{target} {toolchain}"),
+        report,
+        format!("{cross_check_target} {cross_check_toolchain}"),
+        &cross_check_summary.report(),
+    );
+    print!("{cross_check}");
+    if let Some(path) = arguments.cross_check_output.as_deref() {
+        cross_check.write_to_file(path)?;
+    }
+    if !cross_check.is_aligned() {
+        anyhow::bail!(
+            "{} tests diverge between the toolchains",
+            cross_check.divergences.len()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//!
+//! The compiler tester subcommand: the tests documentation.
+//!
+
+use colored::Colorize;
+use std::path::PathBuf;
+
+///
+/// Writes the Markdown index of the tests.
+///
+pub fn run(output: PathBuf) -> anyhow::Result<()> {
+    let summary = compiler_tester::Summary::new(false, true).wrap();
+    let compiler_tester = compiler_tester::CompilerTester::new(
+        summary.clone(),
+        compiler_tester::Filters::default(),
+        None,
+        compiler_tester::Workflow::BuildOnly,
+        None,
+    )?;
+    let docs = compiler_tester.docs()?;
+    drop(compiler_tester);
+    docs.write_to_file(output.as_path())?;
+    println!(
+        "  {} {} tests to {output:?}",
+        "Documented".bright_green().bold(),
+        docs.len(),
+    );
+
+    let summary = compiler_tester::Summary::unwrap_arc(summary);
+    if !summary.is_successful() {
+        print!("{summary}");
+        anyhow::bail!("Some tests could not be documented");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+//!
+//! The compiler tester subcommand: the summary error codes listing.
+//!
+
+///
+/// Prints the JSON mapping of the summary error codes to their names.
+///
+pub fn run() -> anyhow::Result<()> {
+    let mapping = compiler_tester::SummaryErrorCode::mapping();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&mapping).expect("Always valid")
+    );
+    Ok(())
+}
@@ -0,0 +1,252 @@
+//!
+//! The compiler executable resolver.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use colored::Colorize;
+
+///
+/// The compiler executable resolver.
+///
+/// The executable is resolved in the following order:
+/// 1. The path specified explicitly.
+/// 2. The version specified explicitly, which is downloaded to the per-user toolchain directory
+///    unless it is already cached there.
+/// 3. The path in the executable environment variable, e.g. `ZKSOLC`.
+/// 4. The default executable name, which is looked up in `${PATH}`.
+///
+#[derive(Debug)]
+pub struct Executable {
+    /// The executable name.
+    pub name: &'static str,
+    /// The environment variable with the executable path.
+    pub environment_variable: &'static str,
+    /// The GitHub repository with the executable releases.
+    pub repository: &'static str,
+}
+
+impl Executable {
+    /// The `zksolc` executable.
+    pub const ZKSOLC: Self = Self {
+        name: era_compiler_solidity::DEFAULT_EXECUTABLE_NAME,
+        environment_variable: "ZKSOLC",
+        repository: "matter-labs/era-compiler-solidity",
+    };
+
+    /// The `zkvyper` executable.
+    pub const ZKVYPER: Self = Self {
+        name: era_compiler_vyper::DEFAULT_EXECUTABLE_NAME,
+        environment_variable: "ZKVYPER",
+        repository: "matter-labs/era-compiler-vyper",
+    };
+
+    /// The environment variable overriding the per-user toolchain directory.
+    pub const TOOLCHAIN_DIRECTORY_ENVIRONMENT_VARIABLE: &'static str =
+        "ERA_COMPILER_TESTER_TOOLCHAIN_DIRECTORY";
+
+    /// The per-user toolchain directory relative to the home directory.
+    pub const TOOLCHAIN_DIRECTORY_DEFAULT: &'static str = ".era-compiler-tester/toolchains";
+
+    /// The environment variable with the GitHub API token used for downloads.
+    pub const GITHUB_TOKEN_ENVIRONMENT_VARIABLE: &'static str = "GITHUB_TOKEN";
+
+    ///
+    /// Resolves the executable path.
+    ///
+    pub fn resolve(
+        &self,
+        path: Option<PathBuf>,
+        version: Option<&semver::Version>,
+    ) -> anyhow::Result<PathBuf> {
+        if let Some(path) = path {
+            return Ok(path);
+        }
+        if let Some(version) = version {
+            return self.cached_or_download(version);
+        }
+        if let Some(path) = std::env::var_os(self.environment_variable) {
+            return Ok(PathBuf::from(path));
+        }
+        Ok(PathBuf::from(self.name))
+    }
+
+    ///
+    /// Returns the cached executable of the `version`, downloading it if it is missing.
+    ///
+    fn cached_or_download(&self, version: &semver::Version) -> anyhow::Result<PathBuf> {
+        let mut path = Self::toolchain_directory()?;
+        path.push(self.name);
+        path.push(version.to_string());
+        path.push(format!("{}{}", self.name, std::env::consts::EXE_SUFFIX));
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let url = format!(
+            "https://github.com/{}/releases/download/{version}/{}-{}-v{version}{}",
+            self.repository,
+            self.name,
+            Self::platform()?,
+            std::env::consts::EXE_SUFFIX,
+        );
+        println!(
+            " {} `{}` v{version} from {url}",
+            "Downloading".bright_green().bold(),
+            self.name,
+        );
+
+        let http_client = reqwest::blocking::ClientBuilder::new()
+            .connect_timeout(Duration::from_secs(60))
+            .timeout(Duration::from_secs(300))
+            .build()?;
+        let mut request = http_client.get(url.as_str());
+        if let Ok(token) = std::env::var(Self::GITHUB_TOKEN_ENVIRONMENT_VARIABLE) {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(|error| anyhow::anyhow!("`{}` downloading error: {error}", self.name))?;
+        let expected_size = response.content_length();
+        let bytes = response
+            .bytes()
+            .map_err(|error| anyhow::anyhow!("`{}` downloading error: {error}", self.name))?;
+        if let Some(expected_size) = expected_size {
+            if bytes.len() as u64 != expected_size {
+                anyhow::bail!(
+                    "`{}` downloading error: received {} bytes out of {expected_size}",
+                    self.name,
+                    bytes.len()
+                );
+            }
+        }
+
+        let directory = path.parent().expect("Always exists");
+        std::fs::create_dir_all(directory)
+            .map_err(|error| anyhow::anyhow!("Directory {directory:?} creating: {error}"))?;
+        let temporary_path = path.with_extension(format!("download-{}", std::process::id()));
+        let result = self
+            .install(temporary_path.as_path(), bytes.as_ref(), version)
+            .and_then(|()| {
+                std::fs::rename(temporary_path.as_path(), path.as_path()).map_err(|error| {
+                    anyhow::anyhow!("Executable {temporary_path:?} renaming to {path:?}: {error}")
+                })
+            });
+        if result.is_err() {
+            let _ = std::fs::remove_file(temporary_path.as_path());
+        }
+        result?;
+
+        Ok(path)
+    }
+
+    ///
+    /// Writes the downloaded executable to `path`, and checks that it reports the expected `version`.
+    ///
+    /// The executable is written to a temporary path first and only renamed to the cached one
+    /// after the check, so an interrupted or corrupted download is never picked up from the cache.
+    ///
+    fn install(&self, path: &Path, bytes: &[u8], version: &semver::Version) -> anyhow::Result<()> {
+        std::fs::write(path, bytes)
+            .map_err(|error| anyhow::anyhow!("Executable {path:?} writing: {error}"))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+                .map_err(|error| anyhow::anyhow!("Executable {path:?} permissions: {error}"))?;
+        }
+        self.verify(path, version)
+    }
+
+    ///
+    /// Checks that the executable at `path` reports the expected `version`.
+    ///
+    fn verify(&self, path: &Path, version: &semver::Version) -> anyhow::Result<()> {
+        let output = std::process::Command::new(path)
+            .arg("--version")
+            .output()
+            .map_err(|error| anyhow::anyhow!("Executable {path:?} running: {error}"))?;
+        let stdout = String::from_utf8_lossy(output.stdout.as_slice());
+        if !output.status.success() || !stdout.contains(version.to_string().as_str()) {
+            anyhow::bail!(
+                "`{}` v{version} verification error: the executable reports `{}`",
+                self.name,
+                stdout.trim()
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the per-user toolchain directory.
+    ///
+    fn toolchain_directory() -> anyhow::Result<PathBuf> {
+        if let Some(path) = std::env::var_os(Self::TOOLCHAIN_DIRECTORY_ENVIRONMENT_VARIABLE) {
+            return Ok(PathBuf::from(path));
+        }
+
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The home directory is unknown, set `{}` to specify the toolchain directory",
+                    Self::TOOLCHAIN_DIRECTORY_ENVIRONMENT_VARIABLE
+                )
+            })?;
+        Ok(PathBuf::from(home).join(Self::TOOLCHAIN_DIRECTORY_DEFAULT))
+    }
+
+    ///
+    /// Returns the platform suffix of the release executables.
+    ///
+    fn platform() -> anyhow::Result<&'static str> {
+        let platform = match (std::env::consts::OS, std::env::consts::ARCH) {
+            ("linux", "x86_64") => "linux-amd64-musl",
+            ("linux", "aarch64") => "linux-arm64-musl",
+            ("macos", "x86_64") => "macosx-amd64",
+            ("macos", "aarch64") => "macosx-arm64",
+            ("windows", "x86_64") => "windows-amd64-gnu",
+            (os, arch) => anyhow::bail!("No release executables for the `{os}-{arch}` platform"),
+        };
+        Ok(platform)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::Executable;
+
+    #[test]
+    fn install() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-executable-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let version = semver::Version::new(1, 5, 7);
+
+        for (script, is_valid) in [
+            ("#!/bin/sh\necho 'zksolc v1.5.7'\n", true),
+            ("#!/bin/sh\necho 'zksolc v1.5.6'\n", false),
+            ("#!/bin/sh\nexit 1\n", false),
+        ] {
+            let path = directory.join("zksolc");
+            let _ = std::fs::remove_file(path.as_path());
+            let result = Executable::ZKSOLC.install(path.as_path(), script.as_bytes(), &version);
+            assert_eq!(result.is_ok(), is_valid, "Script `{script}`: {result:?}");
+            let mode = std::fs::metadata(path.as_path())
+                .expect("Always valid")
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o111, 0o111, "Script `{script}` is not executable");
+        }
+
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+    }
+}
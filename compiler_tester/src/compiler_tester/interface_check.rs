@@ -0,0 +1,88 @@
+//!
+//! The compiler tester interface check of the main run with another toolchain.
+//!
+
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+
+use crate::arguments::Arguments;
+use crate::executable_download_config_paths;
+use crate::resolve_environment;
+use crate::run_settings::RunSettings;
+use crate::run_tests;
+
+///
+/// Builds the tests with the `--interface-check` toolchain, and compares their contract
+/// interfaces with the `interfaces` of the main run on `target` with `toolchain`.
+///
+pub fn run(
+    arguments: &Arguments,
+    target: era_compiler_common::Target,
+    toolchain: compiler_tester::Toolchain,
+    interfaces: &BTreeMap<(String, String), compiler_tester::SummaryInterface>,
+    filters: compiler_tester::Filters,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    system_contracts_profile: compiler_tester::EraVMSystemContractsProfile,
+) -> anyhow::Result<()> {
+    let interface_check_toolchain = match arguments.interface_check {
+        Some(toolchain) => toolchain,
+        None => return Ok(()),
+    };
+    let interface_check_target = arguments.interface_check_target.unwrap_or(target);
+    println!(
+        "  {} tests with the `{interface_check_toolchain}` toolchain on `{interface_check_target}`",
+        "Interface-checking".bright_green().bold(),
+    );
+
+    let mut interface_check_summary =
+        compiler_tester::Summary::new(arguments.verbose, arguments.quiet);
+    interface_check_summary.enable_interfaces();
+    let interface_check_summary = interface_check_summary.wrap();
+    let interface_check_tester = compiler_tester::CompilerTester::new(
+        interface_check_summary.clone(),
+        filters,
+        debug_config,
+        compiler_tester::Workflow::BuildOnly,
+        None,
+    )?;
+    run_tests(
+        interface_check_tester,
+        RunSettings::new(
+            interface_check_target,
+            interface_check_toolchain,
+            resolve_environment(interface_check_target, None)?,
+            executable_download_config_paths(
+                interface_check_toolchain,
+                None,
+                arguments.vyper_bin_config_path.clone(),
+            ),
+            system_contracts_profile,
+            arguments,
+        ),
+    )?;
+    let interface_check_summary = compiler_tester::Summary::unwrap_arc(interface_check_summary);
+    if compiler_tester::is_interrupted() {
+        anyhow::bail!("The interface check build has been interrupted");
+    }
+
+    let interface_check = compiler_tester::SummaryInterfaceCheck::new(
+        format!("{target} {toolchain}"),
+        interfaces,
+        format!("{interface_check_target} {interface_check_toolchain}"),
+        interface_check_summary
+            .interfaces()
+            .expect("Always enabled"),
+    );
+    print!("{interface_check}");
+    if let Some(path) = arguments.interface_check_output.as_deref() {
+        interface_check.write_to_file(path)?;
+    }
+    if !interface_check.is_consistent() {
+        anyhow::bail!(
+            "{} contracts have different interfaces or are missing with one of the toolchains",
+            interface_check.mismatches.len()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//!
+//! The compiler tester subcommand: the tests linting.
+//!
+
+use colored::Colorize;
+
+use crate::arguments::Arguments;
+use crate::check_vyper_versions;
+use crate::executable_download_config_paths;
+use crate::resolve_toolchain;
+use crate::run_settings::RunSettings;
+use crate::run_tests;
+
+///
+/// Lints the Matter Labs tests, optionally building them to record their ABI.
+///
+pub fn run(arguments: &Arguments, is_abi_checked: bool) -> anyhow::Result<()> {
+    let target = arguments
+        .target
+        .first()
+        .copied()
+        .unwrap_or(era_compiler_common::Target::EraVM);
+    let toolchain = resolve_toolchain(target, arguments.toolchain);
+    let filters = || {
+        compiler_tester::Filters::new(
+            arguments.path.clone(),
+            arguments.mode.clone(),
+            arguments.group.clone(),
+            arguments.tag.clone(),
+            arguments.exclude_tag.clone(),
+            None,
+            vec![],
+        )
+    };
+
+    let interfaces = if is_abi_checked {
+        println!(
+            "    {} tests with the `{}` toolchain to record their ABI",
+            "Building".bright_green().bold(),
+            compiler_tester::Toolchain::Solc,
+        );
+        let mut build_summary = compiler_tester::Summary::new(false, true);
+        build_summary.enable_interfaces();
+        let build_summary = build_summary.wrap();
+        let build_tester = compiler_tester::CompilerTester::new(
+            build_summary.clone(),
+            filters(),
+            None,
+            compiler_tester::Workflow::BuildOnly,
+            None,
+        )?;
+        run_tests(
+            build_tester,
+            RunSettings::new(
+                era_compiler_common::Target::EVM,
+                compiler_tester::Toolchain::Solc,
+                compiler_tester::Environment::REVM,
+                executable_download_config_paths(
+                    compiler_tester::Toolchain::Solc,
+                    None,
+                    arguments.vyper_bin_config_path.clone(),
+                ),
+                compiler_tester::EraVMSystemContractsProfile::default(),
+                arguments,
+            ),
+        )?;
+        compiler_tester::Summary::unwrap_arc(build_summary)
+            .interfaces()
+            .cloned()
+    } else {
+        None
+    };
+
+    let config_paths = executable_download_config_paths(
+        toolchain,
+        arguments.solc_bin_config_path.clone(),
+        arguments.vyper_bin_config_path.clone(),
+    );
+    compiler_tester::EVM::download(config_paths.clone())?;
+    if let era_compiler_common::Target::EraVM = target {
+        check_vyper_versions(config_paths.as_slice())?;
+    }
+
+    let summary = compiler_tester::Summary::new(false, true).wrap();
+    let compiler_tester = compiler_tester::CompilerTester::new(
+        summary.clone(),
+        filters(),
+        None,
+        compiler_tester::Workflow::BuildOnly,
+        None,
+    )?;
+    let lint = compiler_tester.lint(target, toolchain, interfaces.as_ref())?;
+    drop(compiler_tester);
+    print!("{lint}");
+    println!(
+        "      {} {} tests on `{target}` with the `{toolchain}` toolchain, {} warnings",
+        "Linted".bright_green().bold(),
+        lint.tests,
+        lint.warnings.len(),
+    );
+
+    let summary = compiler_tester::Summary::unwrap_arc(summary);
+    if !summary.is_successful() {
+        print!("{summary}");
+        anyhow::bail!("Some tests could not be linted");
+    }
+    if !lint.is_clean() {
+        anyhow::bail!("{} lint warnings found", lint.warnings.len());
+    }
+
+    Ok(())
+}
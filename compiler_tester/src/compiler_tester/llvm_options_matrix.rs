@@ -0,0 +1,70 @@
+//!
+//! The compiler tester subcommand: the LLVM options matrix.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::subprocess::forwarded_arguments;
+use crate::subprocess::log_invocation;
+
+///
+/// Runs the tests with each LLVM options configuration of the matrix at `path` in a separate process.
+///
+pub fn run(path: &Path, is_benchmark_missing: bool) -> anyhow::Result<()> {
+    if is_benchmark_missing {
+        anyhow::bail!("The LLVM options matrix requires at least one `--benchmark` output");
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| anyhow::anyhow!("LLVM options matrix file {path:?} reading: {error}"))?;
+    let matrix: BTreeMap<String, Vec<String>> = serde_json::from_str(text.as_str())
+        .map_err(|error| anyhow::anyhow!("LLVM options matrix file {path:?} parsing: {error}"))?;
+    if matrix.is_empty() {
+        anyhow::bail!("LLVM options matrix file {path:?} has no configurations");
+    }
+    for name in matrix.keys() {
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "-_".contains(character))
+        {
+            anyhow::bail!("Invalid LLVM options configuration name `{name}`: only alphanumeric characters, `-`, and `_` are allowed");
+        }
+    }
+
+    let executable = std::env::current_exe()
+        .map_err(|error| anyhow::anyhow!("Current executable path getting: {error}"))?;
+    let forwarded_arguments =
+        forwarded_arguments(&["--llvm-options-matrix", "--log-compiler-invocations"]);
+
+    let mut failed = Vec::new();
+    for (name, llvm_options) in matrix.iter() {
+        println!(
+            "     {} the tests with the `{name}` LLVM options configuration",
+            "Running".bright_green().bold(),
+        );
+        let mut command = std::process::Command::new(executable.as_path());
+        command
+            .args(forwarded_arguments.iter())
+            .arg(format!("--llvm-options={}", llvm_options.join(" ")))
+            .arg("--llvm-options-name")
+            .arg(name);
+        log_invocation(&mut command)?;
+        let status = command
+            .status()
+            .map_err(|error| anyhow::anyhow!("{executable:?} subprocess spawning: {error}"))?;
+        if !status.success() {
+            failed.push(name.as_str());
+        }
+    }
+
+    if !failed.is_empty() {
+        anyhow::bail!(
+            "The runs with the LLVM options configurations {} have failed",
+            failed.join(", ")
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+//!
+//! The compiler tester machine output.
+//!
+
+use std::fs::File;
+use std::io::Write;
+use std::sync::Mutex;
+
+use once_cell::sync::OnceCell;
+
+/// The original standard output, where the machine output document is written.
+static STDOUT: OnceCell<Mutex<File>> = OnceCell::new();
+
+///
+/// The compiler tester machine output.
+///
+/// The single JSON document written to the standard output in the machine mode.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct MachineOutput<'a> {
+    /// The summary report output.
+    pub summary: compiler_tester::SummaryReportOutput,
+    /// The benchmark of all targets.
+    pub benchmark: &'a benchmark_analyzer::Benchmark,
+}
+
+impl<'a> MachineOutput<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        summary: &'a compiler_tester::SummaryReport,
+        benchmark: &'a benchmark_analyzer::Benchmark,
+    ) -> Self {
+        Self {
+            summary: compiler_tester::SummaryReportOutput::from(summary),
+            benchmark,
+        }
+    }
+
+    ///
+    /// Keeps the standard output for the machine output document, and redirects everything else
+    /// written to it to the standard error, including the output of the dependencies and
+    /// subprocesses.
+    ///
+    pub fn redirect_stdout() -> anyhow::Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::fd::FromRawFd;
+
+            std::io::stdout().flush()?;
+            let stdout = unsafe { libc::dup(libc::STDOUT_FILENO) };
+            if stdout < 0 {
+                anyhow::bail!(
+                    "Standard output duplication error: {}",
+                    std::io::Error::last_os_error()
+                );
+            }
+            if unsafe { libc::dup2(libc::STDERR_FILENO, libc::STDOUT_FILENO) } < 0 {
+                anyhow::bail!(
+                    "Standard output redirection error: {}",
+                    std::io::Error::last_os_error()
+                );
+            }
+            let stdout = unsafe { File::from_raw_fd(stdout) };
+            STDOUT
+                .set(Mutex::new(stdout))
+                .map_err(|_| anyhow::anyhow!("The standard output is already redirected"))?;
+            Ok(())
+        }
+        #[cfg(not(unix))]
+        {
+            anyhow::bail!("The machine mode is only supported on Unix")
+        }
+    }
+
+    ///
+    /// Whether the machine mode is enabled.
+    ///
+    pub fn is_enabled() -> bool {
+        STDOUT.get().is_some()
+    }
+
+    ///
+    /// Writes the document to the original standard output.
+    ///
+    pub fn write(&self) -> anyhow::Result<()> {
+        let stdout = STDOUT
+            .get()
+            .ok_or_else(|| anyhow::anyhow!("The machine mode is not enabled"))?;
+        let mut stdout = stdout.lock().expect("Sync");
+        serde_json::to_writer(&mut *stdout, self)?;
+        writeln!(stdout)?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
@@ -3,16 +3,43 @@
 //!
 
 pub(crate) mod arguments;
+pub(crate) mod benchmark_writer;
+pub(crate) mod compare_summaries;
+pub(crate) mod cross_check;
+pub(crate) mod docs;
+pub(crate) mod error_codes;
+pub(crate) mod executable;
+pub(crate) mod interface_check;
+pub(crate) mod lint;
+pub(crate) mod llvm_options_matrix;
+pub(crate) mod machine_output;
+pub(crate) mod memory_watchdog;
+pub(crate) mod parity;
+pub(crate) mod run_bytecode;
+pub(crate) mod run_settings;
+pub(crate) mod shuffle_check;
+pub(crate) mod subprocess;
+pub(crate) mod zksolc_matrix;
 
+use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
 use std::time::Instant;
 
-use arguments::benchmark_format::BenchmarkFormat;
+use arguments::command::Command;
 use clap::Parser;
 use colored::Colorize;
 
 use self::arguments::Arguments;
+use self::benchmark_writer::BenchmarkWriter;
+use self::executable::Executable;
+use self::machine_output::MachineOutput;
+use self::memory_watchdog::MemoryWatchdog;
+use self::run_settings::RunSettings;
 
 /// The rayon worker stack size.
 const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
@@ -21,6 +48,10 @@ const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
 /// The application entry point.
 ///
 fn main() {
+    if let Some(exit_code) = compiler_tester::InvocationLog::run_proxy() {
+        std::process::exit(exit_code);
+    }
+
     let exit_code = match Arguments::try_parse()
         .map_err(|error| anyhow::anyhow!(error))
         .and_then(main_inner)
@@ -39,6 +70,55 @@ fn main() {
 /// The entry point wrapper used for proper error handling.
 ///
 fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
+    if arguments.machine {
+        colored::control::set_override(false);
+    }
+    if let Some(directory) = arguments.log_compiler_invocations.clone() {
+        compiler_tester::InvocationLog::enable(directory)?;
+    }
+
+    if let Some(command) = arguments.command.clone() {
+        return match command {
+            Command::CompareSummaries { old, new } => compare_summaries::run(old, new),
+            Command::Docs { output } => docs::run(output),
+            Command::ErrorCodes => error_codes::run(),
+            Command::Lint { abi } => lint::run(&arguments, abi),
+            Command::Parity {
+                json_output,
+                markdown_output,
+            } => parity::run(&arguments, json_output, markdown_output),
+            Command::RunBytecode { code, calldata } => {
+                run_bytecode::run(&arguments, code, calldata)
+            }
+        };
+    }
+    if arguments.machine
+        && (arguments.dry_run
+            || !arguments.zksolc_versions.is_empty()
+            || arguments.llvm_options_matrix.is_some()
+            || arguments.shuffle_check.is_some())
+    {
+        anyhow::bail!(
+            "The machine mode is not supported with the dry run, zksolc and LLVM options matrices, and the shuffle check"
+        );
+    }
+    if !arguments.zksolc_versions.is_empty() {
+        return zksolc_matrix::run(arguments.zksolc_versions.as_slice());
+    }
+    if let Some(path) = arguments.llvm_options_matrix.as_deref() {
+        return llvm_options_matrix::run(path, arguments.benchmark.is_empty());
+    }
+    if let Some(seed) = arguments.shuffle_check {
+        return shuffle_check::run(seed, arguments.use_result_cache);
+    }
+    let is_multi_target = arguments.target.len() > 1;
+    check_run_arguments(&arguments)?;
+    let system_contracts_profile = system_contracts_profile(&arguments)?;
+
+    if arguments.machine {
+        MachineOutput::redirect_stdout()?;
+    }
+
     println!(
         "    {} {} v{} (LLVM build {})",
         "Starting".bright_green().bold(),
@@ -47,83 +127,682 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
         inkwell::support::get_commit_id().to_string(),
     );
 
+    let benchmark_configuration = initialize_llvm(&arguments)?;
+    initialize_executables(&arguments)?;
+    let debug_config = debug_config(&arguments)?;
+    initialize_thread_pool(&arguments)?;
+
+    let summary = summary(&arguments)?.wrap();
+    let time_budget = match (arguments.time_budget, arguments.time_budget_summary.clone()) {
+        (Some(minutes), Some(path)) => Some(Arc::new(compiler_tester::TimeBudget::new(
+            Duration::from_secs_f64(minutes * 60.0),
+            path,
+            rayon::current_num_threads(),
+        )?)),
+        _ => None,
+    };
+    let filters = filters(&arguments)?;
+
+    let result_cache = if arguments.use_result_cache && arguments.benchmark.is_empty() {
+        Some(Arc::new(compiler_tester::ResultCache::new(PathBuf::from(
+            compiler_tester::RESULT_CACHE_PATH,
+        ))?))
+    } else {
+        None
+    };
+
+    let runs = resolve_runs(&arguments)?;
+    let target_extension = match arguments.target_extension.as_deref() {
+        Some(name) => {
+            let extension = compiler_tester::get_target_extension(name)?;
+            let target = era_compiler_common::Target::from(extension.base_environment());
+            if runs.iter().any(|(run_target, _, _)| *run_target != target) {
+                anyhow::bail!(
+                    "Target extension `{name}` is based on the `{}` environment, which requires the `{target}` target",
+                    extension.base_environment()
+                );
+            }
+            Some(extension)
+        }
+        None => None,
+    };
+
+    if arguments.dry_run || matches!(arguments.workflow, compiler_tester::Workflow::DryRun) {
+        return dry_run(
+            &arguments,
+            runs.as_slice(),
+            summary,
+            filters,
+            debug_config,
+            time_budget,
+        );
+    }
+
+    let benchmark_writer = BenchmarkWriter::new(
+        &arguments,
+        runs.as_slice(),
+        target_extension.clone(),
+        benchmark_configuration,
+    )?;
+    set_interruption_handler(
+        summary.clone(),
+        benchmark_writer.clone(),
+        Duration::from_secs(arguments.interruption_timeout),
+    )?;
+    let memory_watchdog = match arguments.memory_limit {
+        Some(memory_limit) => Some(spawn_memory_watchdog(
+            memory_limit,
+            summary.clone(),
+            benchmark_writer.clone(),
+        )?),
+        None => None,
+    };
+
+    let run_time_start = Instant::now();
+    println!(
+        "     {} tests with {} worker threads",
+        "Running".bright_green().bold(),
+        rayon::current_num_threads(),
+    );
+    if let Some(seed) = arguments.shuffle {
+        println!(
+            "   {} the tests with seed {seed}",
+            "Shuffling".bright_green().bold(),
+        );
+    }
+
+    for (target, toolchain, environment) in runs.iter().copied() {
+        if compiler_tester::is_interrupted() {
+            break;
+        }
+        if is_multi_target {
+            println!(
+                "     {} tests on `{target}` with the `{toolchain}` toolchain",
+                "Running".bright_green().bold(),
+            );
+            summary.lock().expect("Sync").set_target(Some(target));
+        }
+
+        let mut compiler_tester = compiler_tester::CompilerTester::new(
+            summary.clone(),
+            filters.clone(),
+            debug_config.clone(),
+            arguments.workflow,
+            result_cache.clone(),
+        )?;
+        compiler_tester.set_time_budget(time_budget.clone());
+        compiler_tester.set_shuffle_seed(arguments.shuffle);
+        let mut run_settings = RunSettings::new(
+            target,
+            toolchain,
+            environment,
+            executable_download_config_paths(
+                toolchain,
+                arguments.solc_bin_config_path.clone(),
+                arguments.vyper_bin_config_path.clone(),
+            ),
+            system_contracts_profile.clone(),
+            &arguments,
+        );
+        if arguments.dump_system {
+            run_settings.system_contracts_debug_config = debug_config.clone();
+        }
+        run_settings.load_system_contracts = arguments.load_system_contracts.clone();
+        run_settings.save_system_contracts = arguments.save_system_contracts.clone();
+        run_settings.revm_trace_on_failure = arguments.revm_trace_on_failure;
+        run_settings.revm_address_overrides = arguments.revm_address_overrides.clone();
+        match target_extension.as_ref() {
+            Some(extension) => compiler_tester.run_extension(extension.clone(), toolchain)?,
+            None => run_tests(compiler_tester, run_settings)?,
+        }
+    }
+
+    if let Some(result_cache) = result_cache.as_ref() {
+        result_cache.write()?;
+    }
+    if let Some(debug_config) = debug_config.as_ref() {
+        dump_debug_artifacts(&arguments, debug_config)?;
+    }
+
+    if let Some(memory_watchdog) = memory_watchdog {
+        memory_watchdog.stop();
+    }
+    if !compiler_tester::start_finishing() {
+        compiler_tester::wait_for_exit();
+    }
+    let mut summary = summary.lock().unwrap_or_else(PoisonError::into_inner);
+    summary.take_compilations();
+    if compiler_tester::is_interrupted() {
+        summary.set_incomplete();
+    } else {
+        summary.report_unexpected_passes();
+    }
+    let mut report = summary.report();
+    if let Some(time_budget) = time_budget.as_ref() {
+        time_budget.complete_report(&mut report);
+    }
+    summary.finish(&report)?;
+    if let (false, Some(time_budget)) = (arguments.quiet, time_budget.as_ref()) {
+        print!("{time_budget}");
+    }
+    let stale_invalid_tests = update_invalid_tests(&arguments, &summary)?;
+    println!(
+        "    {} running tests in {}m{:02}s",
+        "Finished".bright_green().bold(),
+        run_time_start.elapsed().as_secs() / 60,
+        run_time_start.elapsed().as_secs() % 60,
+    );
+
+    benchmark_writer.write(&summary)?;
+    let benchmark_group_violations = check_benchmark_groups(&arguments, &summary)?;
+    let is_successful = summary.is_successful();
+    if MachineOutput::is_enabled() {
+        MachineOutput::new(&report, &benchmark_writer.collect(&summary)?).write()?;
+    }
+    let interfaces = summary.interfaces().cloned();
+    if let (Some(path), Some(expectations)) = (
+        arguments.expectations_output.as_deref(),
+        summary.expectations(),
+    ) {
+        expectations.write_to_file(path)?;
+    }
+    drop(summary);
+
+    if compiler_tester::is_interrupted() {
+        anyhow::bail!("The test run has been interrupted");
+    }
+
+    let (target, toolchain, _) = runs[0];
+    cross_check::run(
+        &arguments,
+        target,
+        toolchain,
+        &report,
+        filters.clone(),
+        debug_config.clone(),
+        system_contracts_profile.clone(),
+    )?;
+    if let Some(interfaces) = interfaces.as_ref() {
+        interface_check::run(
+            &arguments,
+            target,
+            toolchain,
+            interfaces,
+            filters,
+            debug_config,
+            system_contracts_profile,
+        )?;
+    }
+
+    if benchmark_group_violations > 0 {
+        anyhow::bail!(
+            "{benchmark_group_violations} benchmark groups registry violations found with `--strict-groups`"
+        );
+    }
+    if stale_invalid_tests > 0 {
+        anyhow::bail!(
+            "{stale_invalid_tests} tests have been invalid for more than {} days",
+            arguments.fail_on_stale_invalid.expect("Always exists")
+        );
+    }
+    if !is_successful {
+        anyhow::bail!("");
+    }
+
+    Ok(())
+}
+
+///
+/// Checks the run `arguments` which cannot be combined.
+///
+fn check_run_arguments(arguments: &Arguments) -> anyhow::Result<()> {
+    if arguments.target.len() > 1
+        && (arguments.cross_check.is_some()
+            || arguments.interface_check.is_some()
+            || arguments.load_system_contracts.is_some()
+            || arguments.save_system_contracts.is_some()
+            || arguments.target_extension.is_some())
+    {
+        anyhow::bail!(
+            "Cross-checking, interface checking, loading or saving system contracts, and target extensions are only supported with a single target"
+        );
+    }
+    if arguments.system_contracts_groups.is_some() && !arguments.minimal_system_contracts {
+        anyhow::bail!("System contracts groups require `--minimal-system-contracts`");
+    }
+    Ok(())
+}
+
+///
+/// Returns the toolchain and environment of each target, and selects the REVM spec.
+///
+fn resolve_runs(
+    arguments: &Arguments,
+) -> anyhow::Result<
+    Vec<(
+        era_compiler_common::Target,
+        compiler_tester::Toolchain,
+        compiler_tester::Environment,
+    )>,
+> {
+    let runs = arguments
+        .target
+        .iter()
+        .map(|target| {
+            Ok((
+                *target,
+                resolve_toolchain(*target, arguments.toolchain),
+                resolve_environment(*target, arguments.environment)?,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if let Some(revm_spec) = arguments.revm_spec.as_deref() {
+        if arguments.cross_check.is_some()
+            || arguments.interface_check.is_some()
+            || runs
+                .iter()
+                .any(|(_, _, environment)| *environment != compiler_tester::Environment::REVM)
+        {
+            anyhow::bail!("The REVM spec selection is only supported with the `REVM` environment");
+        }
+        compiler_tester::Revm::select_spec(solidity_adapter::EVM::try_from(revm_spec)?)?;
+    }
+    if !arguments.benchmark.is_empty() || arguments.verbose || arguments.junit_output.is_some() {
+        compiler_tester::Revm::enable_access_counting();
+    }
+    Ok(runs)
+}
+
+///
+/// Initializes the LLVM targets and options, and returns the benchmark configuration of the
+/// options, if it is named.
+///
+fn initialize_llvm(
+    arguments: &Arguments,
+) -> anyhow::Result<Option<benchmark_analyzer::BenchmarkConfiguration>> {
     inkwell::support::enable_llvm_pretty_stack_trace();
-    for target in [
+    for llvm_target in [
         era_compiler_common::Target::EraVM,
         era_compiler_common::Target::EVM,
     ]
     .into_iter()
     {
-        era_compiler_llvm_context::initialize_target(target);
+        era_compiler_llvm_context::initialize_target(llvm_target);
     }
+    let llvm_options: Vec<String> = arguments
+        .llvm_options
+        .as_deref()
+        .map(|options| options.split_whitespace().map(str::to_owned).collect())
+        .unwrap_or_default();
     compiler_tester::LLVMOptions::initialize(
         arguments.llvm_verify_each,
         arguments.llvm_debug_logging,
+        llvm_options.clone(),
     )?;
+    Ok(arguments
+        .llvm_options_name
+        .clone()
+        .map(|name| benchmark_analyzer::BenchmarkConfiguration::new(name, llvm_options)))
+}
 
+///
+/// Resolves the `zksolc` and `zkvyper` executables, and selects the `vyper` versions.
+///
+fn initialize_executables(arguments: &Arguments) -> anyhow::Result<()> {
     era_compiler_solidity::EXECUTABLE
-        .set(
-            arguments
-                .zksolc
-                .unwrap_or_else(|| PathBuf::from(era_compiler_solidity::DEFAULT_EXECUTABLE_NAME)),
-        )
+        .set(compiler_tester::InvocationLog::proxy(
+            Executable::ZKSOLC
+                .resolve(arguments.zksolc.clone(), arguments.zksolc_version.as_ref())?,
+        )?)
         .expect("Always valid");
     era_compiler_vyper::EXECUTABLE
-        .set(
-            arguments
-                .zkvyper
-                .unwrap_or_else(|| PathBuf::from(era_compiler_vyper::DEFAULT_EXECUTABLE_NAME)),
-        )
+        .set(compiler_tester::InvocationLog::proxy(
+            Executable::ZKVYPER.resolve(
+                arguments.zkvyper.clone(),
+                arguments.zkvyper_version.as_ref(),
+            )?,
+        )?)
         .expect("Always valid");
+    if !arguments.vyper_versions.is_empty() {
+        compiler_tester::VyperCompiler::select_versions(arguments.vyper_versions.clone())?;
+    }
+    Ok(())
+}
 
-    let debug_config = if arguments.debug {
-        std::fs::create_dir_all(compiler_tester::DEBUG_DIRECTORY)?;
-        Some(era_compiler_llvm_context::DebugConfig::new(
-            PathBuf::from_str(compiler_tester::DEBUG_DIRECTORY)?,
-        ))
-    } else {
-        None
-    };
+///
+/// Returns the debug configuration, if the debug artifacts are requested.
+///
+fn debug_config(
+    arguments: &Arguments,
+) -> anyhow::Result<Option<era_compiler_llvm_context::DebugConfig>> {
+    if !arguments.debug && arguments.dump_assembly_diff.is_empty() {
+        return Ok(None);
+    }
 
+    let debug_directory = Path::new(compiler_tester::DEBUG_DIRECTORY);
+    if arguments.clean_debug && debug_directory.exists() {
+        std::fs::remove_dir_all(debug_directory).map_err(|error| {
+            anyhow::anyhow!("Debug directory {debug_directory:?} pruning: {error}")
+        })?;
+    }
+    std::fs::create_dir_all(debug_directory)?;
+    Ok(Some(era_compiler_llvm_context::DebugConfig::new(
+        PathBuf::from_str(compiler_tester::DEBUG_DIRECTORY)?,
+    )))
+}
+
+///
+/// Builds the global thread pool running the tests.
+///
+fn initialize_thread_pool(arguments: &Arguments) -> anyhow::Result<()> {
+    let threads = match (arguments.shuffle, arguments.threads) {
+        (Some(_), Some(threads)) if threads != 1 => {
+            anyhow::bail!("The shuffled order can only be kept with a single thread")
+        }
+        (Some(_), _) => Some(1),
+        (None, threads) => threads,
+    };
     let mut thread_pool_builder = rayon::ThreadPoolBuilder::new();
-    if let Some(threads) = arguments.threads {
+    if let Some(threads) = threads {
         thread_pool_builder = thread_pool_builder.num_threads(threads);
     }
     thread_pool_builder
         .stack_size(RAYON_WORKER_STACK_SIZE)
         .build_global()
         .expect("Thread pool configuration failure");
+    Ok(())
+}
+
+///
+/// Creates the summary with the reports and sinks requested by the `arguments`.
+///
+fn summary(arguments: &Arguments) -> anyhow::Result<compiler_tester::Summary> {
+    let mut summary = compiler_tester::Summary::new(
+        arguments.verbose && !arguments.machine,
+        arguments.quiet || arguments.machine,
+    );
+    if arguments.interface_check.is_some() {
+        summary.enable_interfaces();
+    }
+    if arguments.expectations_output.is_some() {
+        summary.enable_expectations();
+    }
+    if let Some(path) = arguments.expected_outcomes.clone() {
+        summary.set_expected_outcomes(compiler_tester::SummaryExpectedOutcomes::try_from(path)?);
+    }
+    if let Some(path) = arguments.benchmark_policy.clone() {
+        summary.set_benchmark_policy(compiler_tester::SummaryBenchmarkPolicy::try_from(path)?);
+    }
+    if arguments.slowest_tests.is_some() || arguments.slow_threshold.is_some() {
+        summary.enable_slow_tests(
+            arguments
+                .slowest_tests
+                .unwrap_or(compiler_tester::Summary::SLOWEST_TESTS_DEFAULT_COUNT),
+            arguments
+                .slow_threshold
+                .map(|threshold| (threshold * 1000.0) as u64),
+        );
+    }
+    if let Some(path) = arguments.summary_output.clone() {
+        summary.enable_durations();
+        summary.add_sink(Box::new(compiler_tester::SummaryJsonSink::new(path)));
+    }
+    if let Some(path) = arguments.junit_output.clone() {
+        summary.add_sink(Box::new(compiler_tester::SummaryJUnitSink::new(path)));
+    }
+    if let Some(path) = arguments.events_output.as_deref() {
+        summary.add_sink(Box::new(compiler_tester::SummaryEventsSink::new(path)?));
+    }
+    Ok(summary)
+}
 
-    let summary = compiler_tester::Summary::new(arguments.verbose, arguments.quiet).wrap();
+///
+/// Returns the test filters, reading the selector lists.
+///
+fn filters(arguments: &Arguments) -> anyhow::Result<compiler_tester::Filters> {
+    let include_selectors = if arguments.include_from.is_empty() {
+        None
+    } else {
+        let mut include_selectors = Vec::new();
+        for path in arguments.include_from.iter() {
+            include_selectors.extend(compiler_tester::Filters::read_selectors(path)?);
+        }
+        Some(include_selectors)
+    };
+    let mut exclude_selectors = Vec::new();
+    for path in arguments.exclude_from.iter() {
+        exclude_selectors.extend(compiler_tester::Filters::read_selectors(path)?);
+    }
 
-    let filters = compiler_tester::Filters::new(arguments.path, arguments.mode, arguments.group);
+    Ok(compiler_tester::Filters::new(
+        arguments.path.clone(),
+        arguments.mode.clone(),
+        arguments.group.clone(),
+        arguments.tag.clone(),
+        arguments.exclude_tag.clone(),
+        include_selectors,
+        exclude_selectors,
+    ))
+}
 
-    let compiler_tester = compiler_tester::CompilerTester::new(
-        summary.clone(),
-        filters,
-        debug_config.clone(),
-        arguments.workflow,
+///
+/// Prints the tests selected for the `runs` without running them.
+///
+fn dry_run(
+    arguments: &Arguments,
+    runs: &[(
+        era_compiler_common::Target,
+        compiler_tester::Toolchain,
+        compiler_tester::Environment,
+    )],
+    summary: Arc<Mutex<compiler_tester::Summary>>,
+    filters: compiler_tester::Filters,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    time_budget: Option<Arc<compiler_tester::TimeBudget>>,
+) -> anyhow::Result<()> {
+    for (target, toolchain, _) in runs.iter().copied() {
+        let config_paths = executable_download_config_paths(
+            toolchain,
+            arguments.solc_bin_config_path.clone(),
+            arguments.vyper_bin_config_path.clone(),
+        );
+        compiler_tester::EVM::download(config_paths.clone())?;
+        if let era_compiler_common::Target::EraVM = target {
+            check_vyper_versions(config_paths.as_slice())?;
+        }
+        let mut compiler_tester = compiler_tester::CompilerTester::new(
+            summary.clone(),
+            filters.clone(),
+            debug_config.clone(),
+            compiler_tester::Workflow::DryRun,
+            None,
+        )?;
+        compiler_tester.set_time_budget(time_budget.clone());
+        let dry_run = compiler_tester.dry_run(target, toolchain)?;
+        if runs.len() > 1 {
+            println!("{} `{target}`:", "Target".bright_white().bold());
+        }
+        print!("{dry_run}");
+    }
+    if let Some(time_budget) = time_budget.as_ref() {
+        print!("{time_budget}");
+    }
+    Ok(())
+}
+
+///
+/// Sets the handler flushing the partial summary and benchmarks of an interrupted run.
+///
+/// The running tests are given `timeout` to complete after the first interruption, and the second
+/// one flushes them immediately.
+///
+fn set_interruption_handler(
+    summary: Arc<Mutex<compiler_tester::Summary>>,
+    benchmark_writer: BenchmarkWriter,
+    timeout: Duration,
+) -> anyhow::Result<()> {
+    ctrlc::set_handler(move || {
+        if compiler_tester::is_interrupted() {
+            flush_interrupted(
+                &summary,
+                &benchmark_writer,
+                era_compiler_common::EXIT_CODE_FAILURE,
+            );
+        }
+        compiler_tester::interrupt();
+        eprintln!(
+            " {} waiting up to {}s for the running tests, interrupt again to stop immediately",
+            "Interrupted".bright_yellow().bold(),
+            timeout.as_secs(),
+        );
+
+        let summary = summary.clone();
+        let benchmark_writer = benchmark_writer.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(timeout);
+            flush_interrupted(
+                &summary,
+                &benchmark_writer,
+                era_compiler_common::EXIT_CODE_FAILURE,
+            );
+        });
+    })
+    .map_err(|error| anyhow::anyhow!("Signal handler setting error: {error}"))
+}
+
+///
+/// Spawns the memory watchdog, which stops the run once its resident set size exceeds
+/// `memory_limit` MiB, flushing the partial summary and benchmarks.
+///
+fn spawn_memory_watchdog(
+    memory_limit: u64,
+    summary: Arc<Mutex<compiler_tester::Summary>>,
+    benchmark_writer: BenchmarkWriter,
+) -> anyhow::Result<MemoryWatchdog> {
+    MemoryWatchdog::spawn(memory_limit, move |rss_mib| {
+        compiler_tester::interrupt();
+        eprintln!(
+            "{} the resident set size of {rss_mib} MiB exceeds the limit of {memory_limit} MiB, stopping",
+            "Memory limit".bright_red().bold(),
+        );
+        flush_interrupted(
+            &summary,
+            &benchmark_writer,
+            MemoryWatchdog::EXIT_CODE_MEMORY_LIMIT,
+        );
+    })
+}
+
+///
+/// Dumps the assembly diff between the `--dump-assembly-diff` modes, and links the EraVM assembly
+/// next to the disassembly of the failed EVM tests if both targets have been run.
+///
+fn dump_debug_artifacts(
+    arguments: &Arguments,
+    debug_config: &era_compiler_llvm_context::DebugConfig,
+) -> anyhow::Result<()> {
+    if let [mode_a, mode_b] = arguments.dump_assembly_diff.as_slice() {
+        let count = compiler_tester::AssemblyDiff::dump(debug_config, mode_a, mode_b)?;
+        println!(
+            "     {} {count} assembly diff files between `{mode_a}` and `{mode_b}`",
+            "Dumped".bright_green().bold(),
+        );
+    }
+
+    if arguments
+        .target
+        .contains(&era_compiler_common::Target::EraVM)
+        && arguments.target.contains(&era_compiler_common::Target::EVM)
+    {
+        let count = compiler_tester::Disassembly::link_eravm_assembly(debug_config)?;
+        if count > 0 {
+            println!(
+                "     {} {count} EraVM assembly files next to the disassembly of the failed EVM tests",
+                "Copied".bright_green().bold(),
+            );
+        }
+    }
+    Ok(())
+}
+
+///
+/// Updates the invalid tests state file with the `summary`, and returns the number of tests
+/// invalid for longer than `--fail-on-stale-invalid` days.
+///
+fn update_invalid_tests(
+    arguments: &Arguments,
+    summary: &compiler_tester::Summary,
+) -> anyhow::Result<usize> {
+    let path = match arguments.invalid_tests_state.clone() {
+        Some(path) => path,
+        None => return Ok(0),
+    };
+    let mut invalid_tests = compiler_tester::InvalidTests::new(path)?;
+    invalid_tests.update(summary.invalid_tests());
+    invalid_tests.write()?;
+    if !arguments.quiet {
+        print!("{invalid_tests}");
+    }
+    Ok(arguments
+        .fail_on_stale_invalid
+        .map(|days| invalid_tests.stale_count(days))
+        .unwrap_or_default())
+}
+
+///
+/// Reports the violations of the benchmark groups registry as warnings, and returns their number
+/// if they fail the run with `--strict-groups`.
+///
+fn check_benchmark_groups(
+    arguments: &Arguments,
+    summary: &compiler_tester::Summary,
+) -> anyhow::Result<usize> {
+    if arguments.benchmark.is_empty() && !arguments.strict_groups {
+        return Ok(0);
+    }
+
+    let registry = compiler_tester::SummaryBenchmarkRegistry::try_from(
+        arguments
+            .benchmark_registry_path
+            .clone()
+            .unwrap_or_else(|| {
+                PathBuf::from(compiler_tester::SummaryBenchmarkRegistry::DEFAULT_PATH)
+            }),
     )?;
+    let violations = summary.benchmark_group_violations(&registry);
+    for violation in violations.iter() {
+        eprintln!("    {} {violation}", "Warning".bright_yellow().bold());
+    }
+    Ok(if arguments.strict_groups {
+        violations.len()
+    } else {
+        0
+    })
+}
 
-    let toolchain = match (arguments.target, arguments.toolchain) {
+///
+/// Returns the specified toolchain, or the default one for the `target`.
+///
+fn resolve_toolchain(
+    target: era_compiler_common::Target,
+    toolchain: Option<compiler_tester::Toolchain>,
+) -> compiler_tester::Toolchain {
+    match (target, toolchain) {
         (era_compiler_common::Target::EraVM, Some(toolchain)) => toolchain,
         (era_compiler_common::Target::EraVM, None) => compiler_tester::Toolchain::IrLLVM,
         (era_compiler_common::Target::EVM, Some(toolchain)) => toolchain,
         (era_compiler_common::Target::EVM, None) => compiler_tester::Toolchain::Solc,
-    };
-    let executable_download_config_paths = vec![
-        arguments.solc_bin_config_path.unwrap_or_else(|| {
-            PathBuf::from(match toolchain {
-                compiler_tester::Toolchain::IrLLVM => "./configs/solc-bin-default.json",
-                compiler_tester::Toolchain::Solc => "./configs/solc-bin-upstream.json",
-                compiler_tester::Toolchain::SolcLLVM => "./configs/solc-bin-llvm.json",
-            })
-        }),
-        arguments
-            .vyper_bin_config_path
-            .unwrap_or_else(|| PathBuf::from("./configs/vyper-bin-default.json")),
-    ];
-    let environment = match (arguments.target, arguments.environment) {
+    }
+}
+
+///
+/// Returns the specified environment, or the default one for the `target`.
+///
+fn resolve_environment(
+    target: era_compiler_common::Target,
+    environment: Option<compiler_tester::Environment>,
+) -> anyhow::Result<compiler_tester::Environment> {
+    let environment = match (target, environment) {
         (
             era_compiler_common::Target::EraVM,
             Some(environment @ compiler_tester::Environment::ZkEVM),
@@ -145,99 +824,169 @@ fn main_inner(arguments: Arguments) -> anyhow::Result<()> {
             "Target `{target}` and environment `{environment}` combination is not supported"
         ),
     };
+    Ok(environment)
+}
 
-    let run_time_start = Instant::now();
-    println!(
-        "     {} tests with {} worker threads",
-        "Running".bright_green().bold(),
-        rayon::current_num_threads(),
-    );
+///
+/// Returns the compiler executables download configuration paths for the `toolchain`.
+///
+fn executable_download_config_paths(
+    toolchain: compiler_tester::Toolchain,
+    solc_bin_config_path: Option<PathBuf>,
+    vyper_bin_config_path: Option<PathBuf>,
+) -> Vec<PathBuf> {
+    vec![
+        solc_bin_config_path.unwrap_or_else(|| {
+            PathBuf::from(match toolchain {
+                compiler_tester::Toolchain::IrLLVM => "./configs/solc-bin-default.json",
+                compiler_tester::Toolchain::Solc => "./configs/solc-bin-upstream.json",
+                compiler_tester::Toolchain::SolcLLVM => "./configs/solc-bin-llvm.json",
+            })
+        }),
+        vyper_bin_config_path.unwrap_or_else(|| PathBuf::from("./configs/vyper-bin-default.json")),
+    ]
+}
 
-    match environment {
+///
+/// Runs the tests with the run `settings`.
+///
+fn run_tests(
+    compiler_tester: compiler_tester::CompilerTester,
+    settings: RunSettings,
+) -> anyhow::Result<()> {
+    match settings.environment {
         compiler_tester::Environment::ZkEVM => {
-            let system_contracts_debug_config = if arguments.dump_system {
-                debug_config
-            } else {
-                None
-            };
-            let vm = compiler_tester::EraVM::new(
-                executable_download_config_paths,
-                PathBuf::from("./configs/solc-bin-system-contracts.json"),
-                system_contracts_debug_config,
-                arguments.load_system_contracts,
-                arguments.save_system_contracts,
-                arguments.target,
+            let vyper_config_paths = settings.executable_download_config_paths.clone();
+            let mut vm = compiler_tester::EraVM::new(
+                settings.executable_download_config_paths,
+                settings.system_contracts_profile,
+                settings.system_contracts_debug_config,
+                settings.load_system_contracts,
+                settings.save_system_contracts,
+                settings.target,
             )?;
-
-            match (
-                arguments.disable_deployer,
-                arguments.disable_value_simulator,
-            ) {
-                (true, true) => compiler_tester
-                    .run_eravm::<compiler_tester::EraVMNativeDeployer, false>(vm, toolchain),
-                (true, false) => compiler_tester
-                    .run_eravm::<compiler_tester::EraVMNativeDeployer, true>(vm, toolchain),
-                (false, true) => compiler_tester
-                    .run_eravm::<compiler_tester::EraVMSystemContractDeployer, false>(
-                        vm, toolchain,
-                    ),
-                (false, false) => compiler_tester
-                    .run_eravm::<compiler_tester::EraVMSystemContractDeployer, true>(vm, toolchain),
+            check_vyper_versions(vyper_config_paths.as_slice())?;
+            if let Some(evm_gas_limit) = settings.evm_gas_limit {
+                vm.set_evm_gas_limit(evm_gas_limit);
             }
+            if settings.minimal_system_contracts {
+                vm.enable_minimal_system_contracts(settings.system_contracts_groups.as_deref())?;
+            }
+
+            compiler_tester.run_eravm(
+                vm,
+                settings.toolchain,
+                compiler_tester::EraVMRunnerSettings::new(
+                    settings.disable_deployer,
+                    settings.disable_value_simulator,
+                ),
+            )
         }
         compiler_tester::Environment::FastVM => todo!(),
         compiler_tester::Environment::EVMInterpreter => {
-            let system_contract_debug_config = if arguments.dump_system {
-                debug_config
-            } else {
-                None
-            };
-            let vm = compiler_tester::EraVM::new(
-                executable_download_config_paths,
-                PathBuf::from("./configs/solc-bin-system-contracts.json"),
-                system_contract_debug_config,
-                arguments.load_system_contracts,
-                arguments.save_system_contracts,
-                arguments.target,
+            let mut vm = compiler_tester::EraVM::new(
+                settings.executable_download_config_paths,
+                settings.system_contracts_profile,
+                settings.system_contracts_debug_config,
+                settings.load_system_contracts,
+                settings.save_system_contracts,
+                settings.target,
             )?;
+            if let Some(evm_gas_limit) = settings.evm_gas_limit {
+                vm.set_evm_gas_limit(evm_gas_limit);
+            }
 
-            compiler_tester
-                .run_evm_interpreter::<compiler_tester::EraVMSystemContractDeployer, true>(
-                    vm, toolchain,
-                )
+            compiler_tester.run_evm_interpreter(
+                vm,
+                settings.toolchain,
+                compiler_tester::EraVMRunnerSettings::default(),
+            )
         }
         compiler_tester::Environment::REVM => {
-            compiler_tester::EVM::download(executable_download_config_paths)?;
-            compiler_tester.run_revm(toolchain)
+            compiler_tester::EVM::download(settings.executable_download_config_paths)?;
+            let address_overrides = match settings.revm_address_overrides {
+                Some(path) => compiler_tester::RevmAddressOverrides::try_from(path)?,
+                None => compiler_tester::RevmAddressOverrides::default(),
+            };
+            compiler_tester.run_revm(
+                settings.toolchain,
+                settings.revm_trace_on_failure,
+                address_overrides,
+            )
         }
-    }?;
-
-    let summary = compiler_tester::Summary::unwrap_arc(summary);
-    print!("{summary}");
-    println!(
-        "    {} running tests in {}m{:02}s",
-        "Finished".bright_green().bold(),
-        run_time_start.elapsed().as_secs() / 60,
-        run_time_start.elapsed().as_secs() % 60,
-    );
+    }
+}
 
-    if let Some(path) = arguments.benchmark {
-        let benchmark = summary.benchmark(toolchain)?;
-        match arguments.benchmark_format {
-            BenchmarkFormat::Json => {
-                benchmark.write_to_file(path, benchmark_analyzer::JsonSerializer)?
-            }
-            BenchmarkFormat::Csv => {
-                benchmark.write_to_file(path, benchmark_analyzer::CsvSerializer)?
-            }
-        }
+///
+/// Checks the downloaded `vyper` executables, printing the diagnostics of the missing ones.
+///
+fn check_vyper_versions(executable_download_config_paths: &[PathBuf]) -> anyhow::Result<()> {
+    for diagnostic in
+        compiler_tester::VyperCompiler::check_versions(executable_download_config_paths)?
+            .into_iter()
+    {
+        eprintln!("    {} {diagnostic}", "Warning".bright_yellow().bold());
     }
+    Ok(())
+}
 
-    if !summary.is_successful() {
-        anyhow::bail!("");
+///
+/// Returns the EraVM system contracts profile selected with `--system-contracts-profile`.
+///
+fn system_contracts_profile(
+    arguments: &Arguments,
+) -> anyhow::Result<compiler_tester::EraVMSystemContractsProfile> {
+    match arguments.system_contracts_profile.as_deref() {
+        Some(name) => compiler_tester::EraVMSystemContractsProfile::try_from_name(
+            arguments
+                .system_contracts_profiles_path
+                .clone()
+                .unwrap_or_else(|| {
+                    PathBuf::from(
+                        compiler_tester::EraVMSystemContractsProfile::DEFAULT_PROFILES_PATH,
+                    )
+                })
+                .as_path(),
+            name,
+        ),
+        None => Ok(compiler_tester::EraVMSystemContractsProfile::default()),
     }
+}
 
-    Ok(())
+///
+/// Passes the partial summary of an interrupted run to the summary sinks, writes the partial
+/// benchmarks, and exits with `exit_code`.
+///
+/// If the summary is already being written on completion or by another handler, waits for
+/// that to exit the process instead.
+///
+fn flush_interrupted(
+    summary: &Arc<Mutex<compiler_tester::Summary>>,
+    benchmark_writer: &BenchmarkWriter,
+    exit_code: i32,
+) -> ! {
+    if !compiler_tester::start_finishing() {
+        compiler_tester::wait_for_exit();
+    }
+    let mut summary = summary.lock().unwrap_or_else(PoisonError::into_inner);
+    summary.take_compilations();
+    summary.set_incomplete();
+    let report = summary.report();
+    if let Err(error) = summary.finish(&report) {
+        eprintln!("{error:?}");
+    }
+    if let Err(error) = benchmark_writer.write(&summary) {
+        eprintln!("{error:?}");
+    }
+    if MachineOutput::is_enabled() {
+        if let Err(error) = benchmark_writer
+            .collect(&summary)
+            .and_then(|benchmark| MachineOutput::new(&report, &benchmark).write())
+        {
+            eprintln!("{error:?}");
+        }
+    }
+    std::process::exit(exit_code);
 }
 
 #[cfg(test)]
@@ -251,32 +1000,84 @@ mod tests {
         std::env::set_current_dir("..").expect("Change directory failed");
 
         let arguments = Arguments {
+            command: None,
             verbose: false,
             quiet: false,
+            machine: false,
             debug: false,
+            clean_debug: false,
             mode: vec!["Y+M3B3 0.8.28".to_owned()],
             path: vec!["tests/solidity/simple/default.sol".to_owned()],
             group: vec![],
-            benchmark: None,
+            tag: vec![],
+            exclude_tag: vec![],
+            include_from: vec![],
+            exclude_from: vec![],
+            benchmark: vec![],
             benchmark_format: BenchmarkFormat::Json,
+            summary_output: None,
+            junit_output: None,
+            events_output: None,
+            expected_outcomes: None,
+            benchmark_policy: None,
+            strict_groups: false,
+            benchmark_registry_path: None,
+            benchmark_context: None,
             threads: Some(1),
+            dump_assembly_diff: vec![],
+            interruption_timeout: 60,
+            memory_limit: None,
+            log_compiler_invocations: None,
             dump_system: false,
             disable_deployer: false,
             disable_value_simulator: false,
+            minimal_system_contracts: false,
+            system_contracts_groups: None,
+            evm_gas_limit: None,
             zksolc: Some(PathBuf::from(
                 era_compiler_solidity::DEFAULT_EXECUTABLE_NAME,
             )),
             zkvyper: Some(PathBuf::from(era_compiler_vyper::DEFAULT_EXECUTABLE_NAME)),
+            zksolc_version: None,
+            zkvyper_version: None,
+            vyper_versions: vec![],
+            zksolc_versions: vec![],
             toolchain: Some(compiler_tester::Toolchain::IrLLVM),
-            target: era_compiler_common::Target::EraVM,
+            target: vec![era_compiler_common::Target::EraVM],
+            target_extension: None,
             environment: None,
+            cross_check: None,
+            cross_check_target: None,
+            cross_check_output: None,
+            interface_check: None,
+            interface_check_target: None,
+            interface_check_output: None,
             workflow: compiler_tester::Workflow::BuildAndRun,
+            dry_run: false,
+            use_result_cache: false,
+            revm_trace_on_failure: false,
+            revm_spec: None,
+            revm_address_overrides: None,
+            slowest_tests: None,
+            slow_threshold: None,
+            time_budget: None,
+            time_budget_summary: None,
+            invalid_tests_state: None,
+            fail_on_stale_invalid: None,
+            shuffle: None,
+            shuffle_check: None,
+            expectations_output: None,
             solc_bin_config_path: Some(PathBuf::from("./configs/solc-bin-default.json")),
             vyper_bin_config_path: Some(PathBuf::from("./configs/vyper-bin-default.json")),
             load_system_contracts: Some(PathBuf::from("system-contracts-stable-build")),
             save_system_contracts: None,
+            system_contracts_profile: None,
+            system_contracts_profiles_path: None,
             llvm_verify_each: false,
             llvm_debug_logging: false,
+            llvm_options: None,
+            llvm_options_name: None,
+            llvm_options_matrix: None,
         };
 
         crate::main_inner(arguments).expect("Manual testing failed");
@@ -0,0 +1,279 @@
+//!
+//! The compiler tester memory watchdog.
+//!
+
+#[cfg(target_os = "linux")]
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+///
+/// The compiler tester memory watchdog.
+///
+/// Samples the resident set size of the process, which includes all worker threads, and of its
+/// compiler subprocesses, so a run exceeding the memory limit is stopped with a diagnostic
+/// instead of being killed by the OS.
+///
+pub struct MemoryWatchdog {
+    /// Whether the sampling thread has been asked to stop.
+    is_stopped: Arc<AtomicBool>,
+    /// The sampling thread.
+    thread: std::thread::JoinHandle<()>,
+}
+
+impl MemoryWatchdog {
+    /// The exit code of a run stopped due to the memory limit.
+    pub const EXIT_CODE_MEMORY_LIMIT: i32 = 3;
+
+    /// The interval between the resident set size samples.
+    const SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+    ///
+    /// Spawns the thread sampling the resident set size, which calls `on_exceeded` with the size
+    /// in MiB once it exceeds `limit_mib`.
+    ///
+    /// The thread runs until the limit is exceeded or the watchdog is stopped.
+    ///
+    pub fn spawn<F>(limit_mib: u64, on_exceeded: F) -> anyhow::Result<Self>
+    where
+        F: FnOnce(u64) + Send + 'static,
+    {
+        if Self::resident_set_size_mib().is_none() {
+            anyhow::bail!("The memory limit is not supported on this platform");
+        }
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let thread = std::thread::spawn({
+            let is_stopped = is_stopped.clone();
+            move || loop {
+                std::thread::park_timeout(Self::SAMPLE_INTERVAL);
+                if is_stopped.load(Ordering::SeqCst) {
+                    return;
+                }
+                if let Some(rss_mib) = Self::resident_set_size_mib() {
+                    if rss_mib > limit_mib {
+                        on_exceeded(rss_mib);
+                        return;
+                    }
+                }
+            }
+        });
+        Ok(Self { is_stopped, thread })
+    }
+
+    ///
+    /// Stops the sampling thread and waits for it, so the limit cannot be reported after
+    /// the run has completed.
+    ///
+    /// If the limit has already been exceeded, waits for `on_exceeded` to return.
+    ///
+    pub fn stop(self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+        self.thread.thread().unpark();
+        if let Err(error) = self.thread.join() {
+            std::panic::resume_unwind(error);
+        }
+    }
+
+    ///
+    /// Returns the current resident set size of the process and its subprocesses in MiB.
+    ///
+    /// On Unix systems other than Linux, the peak resident set size of the process and of its
+    /// largest terminated subprocess is returned instead.
+    ///
+    fn resident_set_size_mib() -> Option<u64> {
+        #[cfg(target_os = "linux")]
+        {
+            // SAFETY: `sysconf` has no preconditions and only reads the system configuration.
+            let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+            if page_size <= 0 {
+                return None;
+            }
+            let mut pages = Self::resident_pages("self")?;
+            let parents = Self::parent_process_ids();
+            for process_id in Self::descendants(&parents, std::process::id()) {
+                // The subprocess may have exited since the process list was read.
+                pages += Self::resident_pages(process_id.to_string().as_str()).unwrap_or_default();
+            }
+            Some(pages * page_size as u64 / 1024 / 1024)
+        }
+
+        #[cfg(all(unix, not(target_os = "linux")))]
+        {
+            let mut peak_rss_kib = 0;
+            for who in [libc::RUSAGE_SELF, libc::RUSAGE_CHILDREN] {
+                // SAFETY: `rusage` is a plain C structure, for which all zeroes is a valid value.
+                let mut rusage: libc::rusage = unsafe { std::mem::zeroed() };
+                // SAFETY: `rusage` is a valid exclusive reference for the duration of the call.
+                if unsafe { libc::getrusage(who, &mut rusage) } != 0 {
+                    return None;
+                }
+                // The maximum resident set size is reported in bytes on macOS, and in kibibytes elsewhere.
+                peak_rss_kib += if cfg!(target_os = "macos") {
+                    rusage.ru_maxrss as u64 / 1024
+                } else {
+                    rusage.ru_maxrss as u64
+                };
+            }
+            Some(peak_rss_kib / 1024)
+        }
+
+        #[cfg(not(unix))]
+        {
+            None
+        }
+    }
+
+    ///
+    /// Returns the number of resident pages of the process `process` in `/proc`.
+    ///
+    #[cfg(target_os = "linux")]
+    fn resident_pages(process: &str) -> Option<u64> {
+        let statm = std::fs::read_to_string(format!("/proc/{process}/statm")).ok()?;
+        statm.split_whitespace().nth(1)?.parse().ok()
+    }
+
+    ///
+    /// Returns the parent process IDs of all processes in `/proc`, keyed by their IDs.
+    ///
+    #[cfg(target_os = "linux")]
+    fn parent_process_ids() -> BTreeMap<u32, u32> {
+        let mut parents = BTreeMap::new();
+        let entries = match std::fs::read_dir("/proc") {
+            Ok(entries) => entries,
+            Err(_) => return parents,
+        };
+        for entry in entries.flatten() {
+            let process_id = match entry.file_name().to_string_lossy().parse::<u32>() {
+                Ok(process_id) => process_id,
+                Err(_) => continue,
+            };
+            let stat = match std::fs::read_to_string(entry.path().join("stat")) {
+                Ok(stat) => stat,
+                Err(_) => continue,
+            };
+            if let Some(parent_process_id) = Self::parent_process_id(stat.as_str()) {
+                parents.insert(process_id, parent_process_id);
+            }
+        }
+        parents
+    }
+
+    ///
+    /// Parses the parent process ID from the contents of `/proc/<pid>/stat`.
+    ///
+    /// The executable name in parentheses may contain spaces and parentheses, so the fields
+    /// are counted from the last closing parenthesis.
+    ///
+    #[cfg(target_os = "linux")]
+    fn parent_process_id(stat: &str) -> Option<u32> {
+        let (_, fields) = stat.rsplit_once(')')?;
+        fields.split_whitespace().nth(1)?.parse().ok()
+    }
+
+    ///
+    /// Returns the IDs of all descendants of the process `root` by the `parents` map.
+    ///
+    #[cfg(target_os = "linux")]
+    fn descendants(parents: &BTreeMap<u32, u32>, root: u32) -> Vec<u32> {
+        let mut descendants = Vec::new();
+        let mut queue = vec![root];
+        while let Some(parent) = queue.pop() {
+            for (process_id, _) in parents.iter().filter(|(process_id, parent_process_id)| {
+                **parent_process_id == parent && **process_id != root
+            }) {
+                if !descendants.contains(process_id) {
+                    descendants.push(*process_id);
+                    queue.push(*process_id);
+                }
+            }
+        }
+        descendants
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(target_os = "linux")]
+    use std::collections::BTreeMap;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    use super::MemoryWatchdog;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parent_process_id() {
+        for (stat, expected) in [
+            ("42 (solc) S 7 42 7 0 -1", Some(7)),
+            ("43 (a b) (c)) R 42 43 7 0 -1", Some(42)),
+            ("44 (solc)", None),
+            ("", None),
+        ] {
+            assert_eq!(MemoryWatchdog::parent_process_id(stat), expected, "{stat}");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn descendants() {
+        let parents = BTreeMap::from([(1, 0), (2, 1), (3, 2), (4, 2), (5, 0), (6, 5)]);
+
+        let mut descendants = MemoryWatchdog::descendants(&parents, 1);
+        descendants.sort();
+
+        assert_eq!(descendants, vec![2, 3, 4]);
+        assert!(MemoryWatchdog::descendants(&parents, 3).is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn subprocesses() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("10")
+            .spawn()
+            .expect("Always valid");
+
+        let descendants =
+            MemoryWatchdog::descendants(&MemoryWatchdog::parent_process_ids(), std::process::id());
+
+        child.kill().expect("Always valid");
+        child.wait().expect("Always valid");
+        assert!(descendants.contains(&child.id()));
+    }
+
+    #[test]
+    fn stop() {
+        let is_exceeded = Arc::new(AtomicBool::new(false));
+        let watchdog = MemoryWatchdog::spawn(u64::MAX, {
+            let is_exceeded = is_exceeded.clone();
+            move |_| is_exceeded.store(true, Ordering::SeqCst)
+        })
+        .expect("Always valid");
+
+        let start = Instant::now();
+        watchdog.stop();
+
+        assert!(start.elapsed() < MemoryWatchdog::SAMPLE_INTERVAL);
+        assert!(!is_exceeded.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn exceeded() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let watchdog = MemoryWatchdog::spawn(0, move |rss_mib| {
+            sender.send(rss_mib).expect("Always valid");
+        })
+        .expect("Always valid");
+
+        let rss_mib = receiver
+            .recv_timeout(MemoryWatchdog::SAMPLE_INTERVAL * 4)
+            .expect("Always valid");
+        watchdog.stop();
+
+        assert!(rss_mib > 0);
+    }
+}
@@ -0,0 +1,143 @@
+//!
+//! The compiler tester subcommand: the toolchains parity matrix.
+//!
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use colored::Colorize;
+
+use crate::arguments::Arguments;
+use crate::subprocess::forwarded_arguments;
+use crate::subprocess::run_with_summary;
+
+///
+/// Runs the tests with the `solc` and `ir-llvm` toolchains on `EVM` in separate processes, and
+/// prints the parity matrix of their outcomes.
+///
+/// The compiler executables can only be set once per process, so the current executable is
+/// re-invoked with the arguments preceding the subcommand, each toolchain, and a temporary
+/// summary file.
+///
+pub fn run(
+    arguments: &Arguments,
+    json_output: Option<PathBuf>,
+    markdown_output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let mut forwarded_arguments = forwarded_arguments(&[
+        "--toolchain",
+        "--target",
+        "--summary-output",
+        "--log-compiler-invocations",
+    ]);
+    if let Some(position) = subcommand_position(forwarded_arguments.as_slice()) {
+        forwarded_arguments.truncate(position);
+    }
+    if arguments.path.is_empty() {
+        forwarded_arguments.push(OsString::from("--path"));
+        forwarded_arguments.push(OsString::from(
+            compiler_tester::CompilerTester::SOLIDITY_ETHEREUM_UPSTREAM,
+        ));
+    }
+
+    let mut reports = Vec::with_capacity(2);
+    for toolchain in [
+        compiler_tester::Toolchain::Solc,
+        compiler_tester::Toolchain::IrLLVM,
+    ] {
+        println!(
+            "     {} the tests on `{}` with the `{toolchain}` toolchain",
+            "Running".bright_green().bold(),
+            era_compiler_common::Target::EVM,
+        );
+        let report = run_with_summary(
+            forwarded_arguments.as_slice(),
+            &[
+                OsString::from("--target"),
+                OsString::from(era_compiler_common::Target::EVM.to_string()),
+                OsString::from("--toolchain"),
+                OsString::from(toolchain.to_string()),
+            ],
+            format!("with the `{toolchain}` toolchain").as_str(),
+        )?;
+        reports.push(report);
+    }
+
+    let parity = compiler_tester::SummaryParity::new(
+        compiler_tester::Toolchain::Solc.to_string(),
+        &reports[0],
+        format!("{} (solx)", compiler_tester::Toolchain::IrLLVM),
+        &reports[1],
+    );
+    print!("{parity}");
+    if let Some(path) = json_output.as_deref() {
+        parity.write_to_file(path)?;
+    }
+    if let Some(path) = markdown_output.as_deref() {
+        parity.write_markdown_to_file(path)?;
+    }
+    Ok(())
+}
+
+///
+/// Returns the position of the subcommand in `arguments`.
+///
+/// The subcommand name may also be a value of a preceding option or of its own options, so the
+/// subcommand is the first `parity` argument followed only by the subcommand options.
+///
+fn subcommand_position(arguments: &[OsString]) -> Option<usize> {
+    const OPTIONS: [&str; 2] = ["--json-output", "--markdown-output"];
+
+    arguments
+        .iter()
+        .enumerate()
+        .position(|(position, argument)| {
+            if argument != "parity" {
+                return false;
+            }
+            let mut options = arguments[position + 1..].iter();
+            while let Some(option) = options.next() {
+                if OPTIONS.iter().any(|name| option == *name) {
+                    if options.next().is_none() {
+                        return false;
+                    }
+                    continue;
+                }
+                let option = option.to_string_lossy();
+                if !OPTIONS
+                    .iter()
+                    .any(|name| option.starts_with(format!("{name}=").as_str()))
+                {
+                    return false;
+                }
+            }
+            true
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    #[test]
+    fn subcommand_position() {
+        for (arguments, expected) in [
+            (vec!["--mode", "Y+M3B", "parity"], Some(2)),
+            (vec!["--path", "parity", "parity"], Some(2)),
+            (
+                vec!["--path", "parity", "parity", "--json-output", "parity"],
+                Some(2),
+            ),
+            (vec!["parity", "--markdown-output=parity.md"], Some(0)),
+            (vec!["parity", "--json-output"], None),
+            (vec!["--path", "tests"], None),
+        ] {
+            let arguments: Vec<OsString> = arguments.into_iter().map(OsString::from).collect();
+            assert_eq!(
+                super::subcommand_position(arguments.as_slice()),
+                expected,
+                "{arguments:?}"
+            );
+        }
+    }
+}
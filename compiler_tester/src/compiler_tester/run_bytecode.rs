@@ -0,0 +1,60 @@
+//!
+//! The compiler tester subcommand: the raw bytecode run.
+//!
+
+use std::path::Path;
+
+use crate::arguments::Arguments;
+use crate::executable::Executable;
+use crate::system_contracts_profile;
+
+///
+/// Deploys the raw `code` on the `--target` and calls it with the `calldata`.
+///
+pub fn run(arguments: &Arguments, code: String, calldata: String) -> anyhow::Result<()> {
+    let decode = |name: &str, value: &str| {
+        hex::decode(value.strip_prefix("0x").unwrap_or(value))
+            .map_err(|error| anyhow::anyhow!("Invalid hexadecimal {name}: {error}"))
+    };
+    let code = decode("code", code.as_str())?;
+    let calldata = decode("calldata", calldata.as_str())?;
+
+    let target = match arguments.target.as_slice() {
+        [] => era_compiler_common::Target::EraVM,
+        [target] => *target,
+        targets => anyhow::bail!(
+            "The raw bytecode is run on a single target, but {} are specified",
+            targets.len()
+        ),
+    };
+    let run = match target {
+        era_compiler_common::Target::EraVM => {
+            era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+            compiler_tester::LLVMOptions::initialize(false, false, vec![])?;
+            era_compiler_solidity::EXECUTABLE
+                .set(
+                    Executable::ZKSOLC
+                        .resolve(arguments.zksolc.clone(), arguments.zksolc_version.as_ref())?,
+                )
+                .expect("Always valid");
+            let vm = compiler_tester::EraVM::new(
+                vec![],
+                system_contracts_profile(arguments)?,
+                None,
+                arguments.load_system_contracts.clone(),
+                arguments.save_system_contracts.clone(),
+                target,
+            )?;
+            compiler_tester::BytecodeRun::run_eravm(vm, code, calldata)?
+        }
+        era_compiler_common::Target::EVM => {
+            let trace_directory = Path::new(compiler_tester::REVM_TRACE_DIRECTORY);
+            std::fs::create_dir_all(trace_directory).map_err(|error| {
+                anyhow::anyhow!("Trace directory {trace_directory:?} creating: {error}")
+            })?;
+            compiler_tester::BytecodeRun::run_revm(code, calldata, trace_directory)?
+        }
+    };
+    print!("{run}");
+    Ok(())
+}
@@ -0,0 +1,80 @@
+//!
+//! The compiler tester run settings.
+//!
+
+use std::path::PathBuf;
+
+use crate::arguments::Arguments;
+
+///
+/// The compiler tester run settings.
+///
+/// The settings of a single run of the tests on an environment of a target with a toolchain.
+///
+pub struct RunSettings {
+    /// The target.
+    pub target: era_compiler_common::Target,
+    /// The toolchain.
+    pub toolchain: compiler_tester::Toolchain,
+    /// The environment.
+    pub environment: compiler_tester::Environment,
+    /// The compiler executables download configuration paths.
+    pub executable_download_config_paths: Vec<PathBuf>,
+    /// The EraVM system contracts profile.
+    pub system_contracts_profile: compiler_tester::EraVMSystemContractsProfile,
+    /// The EraVM system contracts debug configuration.
+    pub system_contracts_debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    /// The path to load the EraVM system contracts from.
+    pub load_system_contracts: Option<PathBuf>,
+    /// The path to save the EraVM system contracts to.
+    pub save_system_contracts: Option<PathBuf>,
+    /// Whether the EraVM deployer is disabled.
+    pub disable_deployer: bool,
+    /// Whether the EraVM value simulator is disabled.
+    pub disable_value_simulator: bool,
+    /// Whether only the EraVM system contracts required by the tests are deployed.
+    pub minimal_system_contracts: bool,
+    /// The EraVM system contracts groups file path.
+    pub system_contracts_groups: Option<PathBuf>,
+    /// The EVM gas limit.
+    pub evm_gas_limit: Option<u64>,
+    /// Whether the REVM traces of the failed tests are written.
+    pub revm_trace_on_failure: bool,
+    /// The REVM address overrides file path.
+    pub revm_address_overrides: Option<PathBuf>,
+}
+
+impl RunSettings {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The EraVM options are taken from the `arguments`. The system contracts debug configuration,
+    /// loading, and saving, as well as the REVM options, are left disabled, as only some runs use them.
+    ///
+    pub fn new(
+        target: era_compiler_common::Target,
+        toolchain: compiler_tester::Toolchain,
+        environment: compiler_tester::Environment,
+        executable_download_config_paths: Vec<PathBuf>,
+        system_contracts_profile: compiler_tester::EraVMSystemContractsProfile,
+        arguments: &Arguments,
+    ) -> Self {
+        Self {
+            target,
+            toolchain,
+            environment,
+            executable_download_config_paths,
+            system_contracts_profile,
+            system_contracts_debug_config: None,
+            load_system_contracts: None,
+            save_system_contracts: None,
+            disable_deployer: arguments.disable_deployer,
+            disable_value_simulator: arguments.disable_value_simulator,
+            minimal_system_contracts: arguments.minimal_system_contracts,
+            system_contracts_groups: arguments.system_contracts_groups.clone(),
+            evm_gas_limit: arguments.evm_gas_limit,
+            revm_trace_on_failure: false,
+            revm_address_overrides: None,
+        }
+    }
+}
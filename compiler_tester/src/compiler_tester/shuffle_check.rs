@@ -0,0 +1,89 @@
+//!
+//! The compiler tester subcommand: the tests order independence check.
+//!
+
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+
+use colored::Colorize;
+
+use crate::subprocess::forwarded_arguments;
+use crate::subprocess::run_with_summary;
+
+///
+/// Runs the tests in the sorted order and in the order shuffled with the `seed` in separate
+/// processes, and fails if any test status differs between the runs.
+///
+/// Both runs use a single thread, so the tests are executed in exactly the collected order.
+/// The result cache is not supported, as it would skip the tests in the second run.
+///
+pub fn run(seed: u64, use_result_cache: bool) -> anyhow::Result<()> {
+    if use_result_cache {
+        anyhow::bail!("The shuffle check is not supported with the result cache");
+    }
+    let forwarded_arguments = forwarded_arguments(&[
+        "--shuffle-check",
+        "--shuffle",
+        "--threads",
+        "--summary-output",
+        "--log-compiler-invocations",
+    ]);
+
+    let mut reports = Vec::with_capacity(2);
+    for shuffle in [None, Some(seed)] {
+        let order = match shuffle {
+            Some(seed) => format!("the order shuffled with seed {seed}"),
+            None => "the sorted order".to_owned(),
+        };
+        println!(
+            "     {} the tests in {order}",
+            "Running".bright_green().bold(),
+        );
+        let mut arguments = vec![OsString::from("--threads"), OsString::from("1")];
+        if let Some(seed) = shuffle {
+            arguments.push(OsString::from("--shuffle"));
+            arguments.push(OsString::from(seed.to_string()));
+        }
+        let report = run_with_summary(
+            forwarded_arguments.as_slice(),
+            arguments.as_slice(),
+            format!("in {order}").as_str(),
+        )?;
+        reports.push(report);
+    }
+
+    let normalize = |status: Option<&compiler_tester::SummaryStatus>| match status {
+        Some(compiler_tester::SummaryStatus::CachedPass) => {
+            Some(compiler_tester::SummaryStatus::Passed)
+        }
+        status => status.copied(),
+    };
+    let names: BTreeSet<&String> = reports[0]
+        .tests
+        .keys()
+        .chain(reports[1].tests.keys())
+        .collect();
+    let mut differing = 0;
+    for name in names.into_iter() {
+        let sorted = normalize(reports[0].tests.get(name));
+        let shuffled = normalize(reports[1].tests.get(name));
+        if sorted != shuffled {
+            println!(
+                "{:>12} {name}: {sorted:?} -> {shuffled:?}",
+                "Differs".bright_red().bold(),
+            );
+            differing += 1;
+        }
+    }
+
+    if differing > 0 {
+        anyhow::bail!(
+            "{differing} tests have different statuses in the order shuffled with seed {seed}"
+        );
+    }
+    println!(
+        "    {} the test statuses do not depend on the order",
+        "Finished".bright_green().bold(),
+    );
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//!
+//! The compiler tester re-invocation in a subprocess.
+//!
+
+use std::ffi::OsString;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+/// The index of the next re-invoked run, which makes its temporary summary path unique.
+static NEXT_RUN_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// Returns the arguments of the current invocation without the `excluded` options and their values.
+///
+pub fn forwarded_arguments(excluded: &[&str]) -> Vec<OsString> {
+    let mut forwarded_arguments = Vec::new();
+    let mut arguments = std::env::args_os().skip(1);
+    while let Some(argument) = arguments.next() {
+        if excluded.iter().any(|excluded| argument == *excluded) {
+            arguments.next();
+            continue;
+        }
+        if excluded.iter().any(|excluded| {
+            argument
+                .to_string_lossy()
+                .starts_with(format!("{excluded}=").as_str())
+        }) {
+            continue;
+        }
+        forwarded_arguments.push(argument);
+    }
+    forwarded_arguments
+}
+
+///
+/// Logs the tester re-invocation `command`, if the compiler invocations logging is enabled.
+///
+/// The compiler invocations of the re-invoked run are logged to the `invocations` subdirectory
+/// of its own log directory.
+///
+pub fn log_invocation(command: &mut std::process::Command) -> anyhow::Result<()> {
+    let directory = match compiler_tester::InvocationLog::next_directory(env!("CARGO_BIN_NAME"))? {
+        Some(directory) => directory,
+        None => return Ok(()),
+    };
+    command
+        .arg("--log-compiler-invocations")
+        .arg(directory.join("invocations"));
+    compiler_tester::InvocationLog::record_to(directory.as_path(), command, &[])
+}
+
+///
+/// Re-invokes the tester with the `forwarded_arguments` followed by the run-specific `arguments`,
+/// and returns the summary the run writes to a temporary file.
+///
+/// The `run` description completes the error messages, e.g. `with v1.5.0`. The run fails if it
+/// has not produced a summary or has been interrupted.
+///
+pub fn run_with_summary(
+    forwarded_arguments: &[OsString],
+    arguments: &[OsString],
+    run: &str,
+) -> anyhow::Result<compiler_tester::SummaryReport> {
+    let executable = std::env::current_exe()
+        .map_err(|error| anyhow::anyhow!("Current executable path getting: {error}"))?;
+    let summary_path = std::env::temp_dir().join(format!(
+        "era-compiler-tester-{}-{}.json",
+        std::process::id(),
+        NEXT_RUN_INDEX.fetch_add(1, Ordering::Relaxed),
+    ));
+
+    let mut command = std::process::Command::new(executable.as_path());
+    command
+        .args(forwarded_arguments)
+        .args(arguments)
+        .arg("--summary-output")
+        .arg(summary_path.as_path());
+    log_invocation(&mut command)?;
+    let status = command
+        .status()
+        .map_err(|error| anyhow::anyhow!("{executable:?} subprocess spawning: {error}"))?;
+    if !summary_path.exists() {
+        anyhow::bail!("The run {run} has not produced a summary: {status}");
+    }
+
+    let report = compiler_tester::SummaryReport::try_from(summary_path.clone());
+    let _ = std::fs::remove_file(summary_path.as_path());
+    let report = report?;
+    if report.incomplete {
+        anyhow::bail!("The run {run} has been interrupted");
+    }
+    Ok(report)
+}
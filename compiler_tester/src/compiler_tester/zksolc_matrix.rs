@@ -0,0 +1,52 @@
+//!
+//! The compiler tester subcommand: the zksolc versions matrix.
+//!
+
+use std::ffi::OsString;
+
+use colored::Colorize;
+
+use crate::executable::Executable;
+use crate::subprocess::forwarded_arguments;
+use crate::subprocess::run_with_summary;
+
+///
+/// Runs the tests with each of the zksolc `versions` in a separate process, and prints the tests
+/// whose outcomes differ across versions.
+///
+/// The compiler executable can only be set once per process, so the current executable is
+/// re-invoked with the same arguments, a single `--zksolc-version`, and a temporary summary file.
+///
+pub fn run(versions: &[semver::Version]) -> anyhow::Result<()> {
+    let forwarded_arguments = forwarded_arguments(&[
+        "--zksolc-versions",
+        "--summary-output",
+        "--log-compiler-invocations",
+    ]);
+
+    let mut reports = Vec::with_capacity(versions.len());
+    for version in versions.iter() {
+        Executable::ZKSOLC.resolve(None, Some(version))?;
+
+        println!(
+            "     {} the tests with `{}` v{version}",
+            "Running".bright_green().bold(),
+            era_compiler_solidity::DEFAULT_EXECUTABLE_NAME,
+        );
+        let report = run_with_summary(
+            forwarded_arguments.as_slice(),
+            &[
+                OsString::from("--zksolc-version"),
+                OsString::from(version.to_string()),
+            ],
+            format!("with v{version}").as_str(),
+        )?;
+        reports.push((version.to_string(), report));
+    }
+
+    print!(
+        "{}",
+        compiler_tester::SummaryMatrix::new(reports.as_slice())
+    );
+    Ok(())
+}
@@ -5,6 +5,7 @@
 pub mod mode;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 
 use era_solc::CollectableError;
@@ -28,6 +29,7 @@ impl Compiler for EraVMCompiler {
         _test_path: String,
         sources: Vec<(String, String)>,
         _libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         _mode: &Mode,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
@@ -64,7 +66,7 @@ impl Compiler for EraVMCompiler {
             .map(|(path, result)| Ok((path, result.expect("Always valid").build)))
             .collect::<anyhow::Result<HashMap<String, era_compiler_llvm_context::EraVMBuild>>>()?;
 
-        Ok(EraVMInput::new(builds, None, last_contract))
+        Ok(EraVMInput::new(builds, None, None, last_contract))
     }
 
     fn compile_for_evm(
@@ -72,6 +74,7 @@ impl Compiler for EraVMCompiler {
         _test_path: String,
         _sources: Vec<(String, String)>,
         _libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         _mode: &Mode,
         _test_params: Option<&solidity_adapter::Params>,
         _llvm_options: Vec<String>,
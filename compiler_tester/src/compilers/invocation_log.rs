@@ -0,0 +1,412 @@
+//!
+//! The compiler subprocess invocation log.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::io::IsTerminal;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use once_cell::sync::OnceCell;
+
+/// The directory the compiler invocations are logged to, if enabled.
+static DIRECTORY: OnceCell<PathBuf> = OnceCell::new();
+
+/// The number of the compiler invocations logged so far.
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The proxies of the compiler executables, keyed by the executable paths.
+static PROXIES: Lazy<Mutex<HashMap<PathBuf, PathBuf>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+///
+/// The compiler subprocess invocation log.
+///
+/// Every invocation is written to its own numbered subdirectory with its exact arguments,
+/// environment changes, and standard input, along with a script replaying it outside the tester.
+///
+/// The compilers spawned by the compiler libraries, such as the ZKsync `solc`, `vyper`, and the
+/// `zksolc` and `zkvyper` recursive processes, are given the path of a proxy instead. The proxy
+/// is a link to the tester executable, which logs the invocation and runs the compiler with it.
+///
+pub struct InvocationLog;
+
+impl InvocationLog {
+    /// The invocation description file name.
+    const FILE_NAME_COMMAND: &'static str = "command.json";
+
+    /// The standard input file name.
+    const FILE_NAME_STDIN: &'static str = "stdin";
+
+    /// The replay script file name.
+    const FILE_NAME_REPLAY: &'static str = "replay.sh";
+
+    /// The subdirectory of the compiler proxies.
+    const DIRECTORY_NAME_PROXIES: &'static str = "proxies";
+
+    /// The subdirectory of the invocations logged by the proxies, by the proxy process ID.
+    const DIRECTORY_NAME_PROXIED: &'static str = "proxied";
+
+    /// The extension of the files with the compiler paths, next to their proxies.
+    const FILE_EXTENSION_TARGET: &'static str = "target";
+
+    /// The environment variable with the compiler proxies directory, inherited by the libraries
+    /// spawning the compilers, so the tester executable can tell when it is run as a proxy.
+    const PROXIES_ENVIRONMENT_VARIABLE: &'static str =
+        "ERA_COMPILER_TESTER_INVOCATION_PROXIES_DIRECTORY";
+
+    ///
+    /// Enables logging the compiler invocations to `directory`, which is created if missing.
+    ///
+    pub fn enable(directory: PathBuf) -> anyhow::Result<()> {
+        let proxies = directory.join(Self::DIRECTORY_NAME_PROXIES);
+        std::fs::create_dir_all(proxies.as_path()).map_err(|error| {
+            anyhow::anyhow!("Compiler invocations directory {proxies:?} creating: {error}")
+        })?;
+        DIRECTORY
+            .set(directory)
+            .map_err(|_| anyhow::anyhow!("The compiler invocations logging is already enabled"))?;
+        std::env::set_var(Self::PROXIES_ENVIRONMENT_VARIABLE, proxies);
+        Ok(())
+    }
+
+    ///
+    /// Returns the path of the proxy logging the invocations of the compiler `executable`, which
+    /// is passed to the compiler libraries instead, if the logging is enabled.
+    ///
+    /// The proxies are only supported on Unix, so the `executable` is returned as is elsewhere.
+    ///
+    pub fn proxy(executable: PathBuf) -> anyhow::Result<PathBuf> {
+        let directory = match DIRECTORY.get() {
+            Some(directory) if cfg!(unix) => directory,
+            _ => return Ok(executable),
+        };
+        let mut proxies = PROXIES.lock().expect("Sync");
+        if let Some(proxy) = proxies.get(executable.as_path()) {
+            return Ok(proxy.to_owned());
+        }
+
+        let name = executable
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| executable.to_string_lossy().to_string());
+        let proxy = directory.join(Self::DIRECTORY_NAME_PROXIES).join(format!(
+            "{:03}-{}",
+            proxies.len(),
+            crate::utils::sanitize_file_name(name.as_str())
+        ));
+        let target_path = Self::target_path(proxy.as_path());
+        std::fs::write(
+            target_path.as_path(),
+            executable.to_string_lossy().as_bytes(),
+        )
+        .map_err(|error| anyhow::anyhow!("File {target_path:?} writing: {error}"))?;
+        #[cfg(unix)]
+        {
+            let tester = std::env::current_exe()
+                .map_err(|error| anyhow::anyhow!("Current executable path getting: {error}"))?;
+            std::os::unix::fs::symlink(tester.as_path(), proxy.as_path())
+                .map_err(|error| anyhow::anyhow!("Compiler proxy {proxy:?} creating: {error}"))?;
+        }
+
+        proxies.insert(executable, proxy.clone());
+        Ok(proxy)
+    }
+
+    ///
+    /// Runs the compiler invocation if the tester executable has been run as a compiler proxy,
+    /// and returns its exit code.
+    ///
+    /// The invocation is logged to the `proxied` subdirectory of the log, by the proxy process ID.
+    ///
+    pub fn run_proxy() -> Option<i32> {
+        let proxies = std::env::var_os(Self::PROXIES_ENVIRONMENT_VARIABLE)?;
+        let mut arguments = std::env::args_os();
+        let program = PathBuf::from(arguments.next()?);
+        if program.parent() != Some(Path::new(proxies.as_os_str())) {
+            return None;
+        }
+
+        match Self::run_proxy_inner(program.as_path(), arguments.collect()) {
+            Ok(exit_code) => Some(exit_code),
+            Err(error) => {
+                eprintln!("Compiler proxy {program:?} error: {error:?}");
+                Some(era_compiler_common::EXIT_CODE_FAILURE)
+            }
+        }
+    }
+
+    ///
+    /// Creates the numbered subdirectory of the next invocation of the compiler `name`,
+    /// if the logging is enabled.
+    ///
+    pub fn next_directory(name: &str) -> anyhow::Result<Option<PathBuf>> {
+        let directory = match DIRECTORY.get() {
+            Some(directory) => directory,
+            None => return Ok(None),
+        };
+        let index = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let directory = directory.join(format!(
+            "{index:06}-{}",
+            crate::utils::sanitize_file_name(name)
+        ));
+        std::fs::create_dir_all(directory.as_path()).map_err(|error| {
+            anyhow::anyhow!("Compiler invocation directory {directory:?} creating: {error}")
+        })?;
+        Ok(Some(directory))
+    }
+
+    ///
+    /// Logs the `command` to be spawned with `stdin`, if the logging is enabled.
+    ///
+    pub fn record(command: &std::process::Command, stdin: &[u8]) -> anyhow::Result<()> {
+        let program = command.get_program().to_string_lossy().to_string();
+        let name = Path::new(program.as_str())
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| program.clone());
+        match Self::next_directory(name.as_str())? {
+            Some(directory) => Self::record_to(directory.as_path(), command, stdin),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Logs the `command` to be spawned with `stdin` to the invocation `directory`.
+    ///
+    pub fn record_to(
+        directory: &Path,
+        command: &std::process::Command,
+        stdin: &[u8],
+    ) -> anyhow::Result<()> {
+        let program = command.get_program().to_string_lossy().to_string();
+        let arguments: Vec<String> = command
+            .get_args()
+            .map(|argument| argument.to_string_lossy().to_string())
+            .collect();
+        let environment: BTreeMap<String, Option<String>> = command
+            .get_envs()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().to_string(),
+                    value.map(|value| value.to_string_lossy().to_string()),
+                )
+            })
+            .collect();
+        let current_directory = match command.get_current_dir() {
+            Some(path) => path.to_path_buf(),
+            None => std::env::current_dir()?,
+        };
+
+        let description = serde_json::json!({
+            "program": program,
+            "arguments": arguments,
+            "environment": environment,
+            "current_directory": current_directory,
+        });
+        Self::write(
+            directory,
+            Self::FILE_NAME_COMMAND,
+            serde_json::to_vec_pretty(&description)
+                .expect("Always valid")
+                .as_slice(),
+        )?;
+        Self::write(directory, Self::FILE_NAME_STDIN, stdin)?;
+
+        let mut script = String::from("#!/bin/sh\n");
+        script.push_str("# Replays the compiler invocation, writing its output to stdout.\n");
+        script.push_str("set -e\n");
+        script.push_str("DIRECTORY=\"$(cd \"$(dirname \"$0\")\" && pwd)\"\n");
+        script.push_str(
+            format!(
+                "cd {}\n",
+                Self::quote(current_directory.to_string_lossy().as_ref())
+            )
+            .as_str(),
+        );
+        script.push_str("env");
+        for (key, value) in environment.iter() {
+            if value.is_none() {
+                script.push_str(format!(" -u {}", Self::quote(key.as_str())).as_str());
+            }
+        }
+        for (key, value) in environment.iter() {
+            if let Some(value) = value {
+                script.push_str(
+                    format!(" {}", Self::quote(format!("{key}={value}").as_str())).as_str(),
+                );
+            }
+        }
+        script.push_str(format!(" {}", Self::quote(program.as_str())).as_str());
+        for argument in arguments.iter() {
+            script.push_str(format!(" {}", Self::quote(argument.as_str())).as_str());
+        }
+        script.push_str(format!(" < \"${{DIRECTORY}}/{}\"\n", Self::FILE_NAME_STDIN).as_str());
+        Self::write(directory, Self::FILE_NAME_REPLAY, script.as_bytes())?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let script_path = directory.join(Self::FILE_NAME_REPLAY);
+            std::fs::set_permissions(
+                script_path.as_path(),
+                std::fs::Permissions::from_mode(0o755),
+            )
+            .map_err(|error| {
+                anyhow::anyhow!("File {script_path:?} permissions setting: {error}")
+            })?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Logs and runs the invocation of the compiler behind the `proxy` with the `arguments`.
+    ///
+    /// The standard input is forwarded to the compiler, unless it is a terminal, that is, it has
+    /// been inherited from the tester, which does not pass any input to the compiler then.
+    ///
+    fn run_proxy_inner(proxy: &Path, arguments: Vec<OsString>) -> anyhow::Result<i32> {
+        let target_path = Self::target_path(proxy);
+        let executable = std::fs::read_to_string(target_path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {target_path:?} reading: {error}"))?;
+
+        let mut stdin = Vec::new();
+        if !std::io::stdin().is_terminal() {
+            std::io::stdin()
+                .read_to_end(&mut stdin)
+                .map_err(|error| anyhow::anyhow!("Standard input reading: {error}"))?;
+        }
+
+        let mut command = std::process::Command::new(executable.as_str());
+        command.args(arguments);
+        let directory = proxy
+            .parent()
+            .and_then(Path::parent)
+            .expect("Always exists")
+            .join(Self::DIRECTORY_NAME_PROXIED)
+            .join(std::process::id().to_string());
+        DIRECTORY
+            .set(directory)
+            .expect("The proxy logging is only enabled once");
+        Self::record(&command, stdin.as_slice())?;
+
+        command.stdin(std::process::Stdio::piped());
+        let mut process = command
+            .spawn()
+            .map_err(|error| anyhow::anyhow!("{executable} subprocess spawning: {error}"))?;
+        process
+            .stdin
+            .as_mut()
+            .expect("Always exists")
+            .write_all(stdin.as_slice())
+            .map_err(|error| anyhow::anyhow!("{executable} stdin writing: {error}"))?;
+        drop(process.stdin.take());
+        let status = process
+            .wait()
+            .map_err(|error| anyhow::anyhow!("{executable} subprocess waiting: {error}"))?;
+        Ok(status
+            .code()
+            .unwrap_or(era_compiler_common::EXIT_CODE_FAILURE))
+    }
+
+    ///
+    /// Returns the path of the file with the compiler path of the `proxy`.
+    ///
+    fn target_path(proxy: &Path) -> PathBuf {
+        let mut path = proxy.as_os_str().to_owned();
+        path.push(format!(".{}", Self::FILE_EXTENSION_TARGET));
+        PathBuf::from(path)
+    }
+
+    ///
+    /// Writes the `contents` to the file `name` in `directory`.
+    ///
+    fn write(directory: &Path, name: &str, contents: &[u8]) -> anyhow::Result<()> {
+        let path = directory.join(name);
+        std::fs::write(path.as_path(), contents)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Quotes the `value` for the POSIX shell.
+    ///
+    fn quote(value: &str) -> String {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InvocationLog;
+
+    #[test]
+    fn quote() {
+        for (value, expected) in [
+            ("", "''"),
+            ("solc", "'solc'"),
+            ("a b", "'a b'"),
+            ("$HOME", "'$HOME'"),
+            ("it's", r"'it'\''s'"),
+        ] {
+            assert_eq!(InvocationLog::quote(value), expected);
+        }
+    }
+
+    #[test]
+    fn record_to() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-invocation-log-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+
+        let mut command = std::process::Command::new("cat");
+        command.arg("-").env("KEY", "it's").env_remove("REMOVED");
+        InvocationLog::record_to(directory.as_path(), &command, b"input").expect("Always valid");
+
+        let description: serde_json::Value = serde_json::from_slice(
+            std::fs::read(directory.join(InvocationLog::FILE_NAME_COMMAND))
+                .expect("Always valid")
+                .as_slice(),
+        )
+        .expect("Always valid");
+        assert_eq!(description["program"], "cat");
+        assert_eq!(description["arguments"], serde_json::json!(["-"]));
+        assert_eq!(
+            description["environment"],
+            serde_json::json!({ "KEY": "it's", "REMOVED": null })
+        );
+        assert_eq!(
+            std::fs::read(directory.join(InvocationLog::FILE_NAME_STDIN)).expect("Always valid"),
+            b"input"
+        );
+
+        let script = std::fs::read_to_string(directory.join(InvocationLog::FILE_NAME_REPLAY))
+            .expect("Always valid");
+        assert!(
+            script.contains(r#"env -u 'REMOVED' 'KEY=it'\''s' 'cat' '-' < "${DIRECTORY}/stdin""#)
+        );
+        #[cfg(unix)]
+        {
+            let output =
+                std::process::Command::new(directory.join(InvocationLog::FILE_NAME_REPLAY))
+                    .output()
+                    .expect("Always valid");
+            assert!(
+                output.status.success(),
+                "{}",
+                String::from_utf8_lossy(output.stderr.as_slice())
+            );
+            assert_eq!(output.stdout, b"input");
+        }
+
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+    }
+}
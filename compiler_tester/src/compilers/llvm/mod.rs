@@ -4,6 +4,7 @@
 
 pub mod mode;
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 
 use era_solc::CollectableError;
@@ -40,6 +41,7 @@ impl Compiler for LLVMCompiler {
         _test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         mode: &Mode,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
@@ -81,7 +83,7 @@ impl Compiler for LLVMCompiler {
             .map(|(path, result)| Ok((path, result.expect("Always valid").build)))
             .collect::<anyhow::Result<HashMap<String, era_compiler_llvm_context::EraVMBuild>>>()?;
 
-        Ok(EraVMInput::new(builds, None, last_contract))
+        Ok(EraVMInput::new(builds, None, None, last_contract))
     }
 
     fn compile_for_evm(
@@ -89,6 +91,7 @@ impl Compiler for LLVMCompiler {
         _test_path: String,
         sources: Vec<(String, String)>,
         _libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         mode: &Mode,
         _test_params: Option<&solidity_adapter::Params>,
         llvm_options: Vec<String>,
@@ -130,7 +133,7 @@ impl Compiler for LLVMCompiler {
             })
             .collect();
 
-        Ok(EVMInput::new(builds, None, last_contract))
+        Ok(EVMInput::new(builds, None, None, last_contract))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
@@ -4,12 +4,16 @@
 
 pub mod cache;
 pub mod eravm;
+pub mod invocation_log;
 pub mod llvm;
 pub mod mode;
 pub mod solidity;
+pub mod statistics;
 pub mod vyper;
 pub mod yul;
 
+use std::collections::BTreeSet;
+
 use crate::vm::eravm::input::Input as EraVMInput;
 use crate::vm::evm::input::Input as EVMInput;
 
@@ -27,6 +31,7 @@ pub trait Compiler: Send + Sync + 'static {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        remappings: BTreeSet<String>,
         mode: &Mode,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
@@ -40,6 +45,7 @@ pub trait Compiler: Send + Sync + 'static {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        remappings: BTreeSet<String>,
         mode: &Mode,
         test_params: Option<&solidity_adapter::Params>,
         llvm_options: Vec<String>,
@@ -13,6 +13,8 @@ pub struct LLVMOptions {
     is_verify_each_enabled: bool,
     /// Whether the LLVM `debug logging` option is enabled.
     is_debug_logging_enabled: bool,
+    /// The extra LLVM options passed to the backend.
+    extra: Vec<String>,
 }
 
 /// The one-time initialization cell for the global variable.
@@ -28,11 +30,13 @@ impl LLVMOptions {
     pub fn initialize(
         is_verify_each_enabled: bool,
         is_debug_logging_enabled: bool,
+        extra: Vec<String>,
     ) -> anyhow::Result<()> {
         let _ = LLVM_OPTIONS.get_or_try_init(|| -> anyhow::Result<LLVMOptions> {
             Ok(Self {
                 is_verify_each_enabled,
                 is_debug_logging_enabled,
+                extra,
             })
         })?;
         Ok(())
@@ -65,4 +69,13 @@ impl LLVMOptions {
 
         self.is_debug_logging_enabled
     }
+
+    ///
+    /// The extra LLVM options passed to the backend.
+    ///
+    pub fn extra(&self) -> Vec<String> {
+        let _guard = LLVM_OPTIONS_LOCK.lock().expect("Sync");
+
+        self.extra.clone()
+    }
 }
@@ -9,6 +9,8 @@ use std::collections::HashSet;
 use crate::compilers::eravm::mode::Mode as EraVMMode;
 use crate::compilers::llvm::mode::Mode as LLVMMode;
 use crate::compilers::solidity::mode::Mode as SolidityMode;
+use crate::compilers::solidity::suppressed_error::SuppressedError;
+use crate::compilers::solidity::suppressed_warning::SuppressedWarning;
 use crate::compilers::solidity::upstream::mode::Mode as SolidityUpstreamMode;
 use crate::compilers::vyper::mode::Mode as VyperMode;
 use crate::compilers::yul::mode::Mode as YulMode;
@@ -48,6 +50,31 @@ impl Mode {
         }
     }
 
+    ///
+    /// Overrides the suppressed `zksolc` errors and warnings if applicable.
+    ///
+    pub fn suppress(
+        &mut self,
+        errors: Option<&[SuppressedError]>,
+        warnings: Option<&[SuppressedWarning]>,
+    ) {
+        if let Self::Solidity(mode) = self {
+            if let Some(errors) = errors {
+                mode.suppressed_errors = errors.to_vec();
+            }
+            if let Some(warnings) = warnings {
+                mode.suppressed_warnings = warnings.to_vec();
+            }
+        }
+    }
+
+    ///
+    /// Whether the mode supports the suppressed `zksolc` errors and warnings.
+    ///
+    pub fn supports_suppressions(&self) -> bool {
+        matches!(self, Self::Solidity(_))
+    }
+
     ///
     /// Checks if the mode is compatible with the filters.
     ///
@@ -90,14 +117,57 @@ impl Mode {
     ///
     /// Checks if the self is compatible with version filter.
     ///
+    /// The modes not bound to a compiler version are incompatible with any filter.
+    ///
     pub fn check_version(&self, versions: &semver::VersionReq) -> bool {
-        let version = match self {
-            Mode::Solidity(mode) => &mode.solc_version,
-            Mode::SolidityUpstream(mode) => &mode.solc_version,
-            Mode::Vyper(mode) => &mode.vyper_version,
-            _ => return false,
+        match self.version() {
+            Some(version) => versions.matches(version),
+            None => false,
+        }
+    }
+
+    ///
+    /// Returns the compiler name of the mode.
+    ///
+    pub fn compiler_name(&self) -> &'static str {
+        match self {
+            Mode::Solidity(_) => "Solidity",
+            Mode::SolidityUpstream(_) => "Solidity upstream",
+            Mode::Yul(_) => "Yul",
+            Mode::YulUpstream(_) => "Yul upstream",
+            Mode::Vyper(_) => "Vyper",
+            Mode::LLVM(_) => "LLVM",
+            Mode::EraVM(_) => "EraVM",
+        }
+    }
+
+    ///
+    /// Returns the compiler version, if the mode is bound to one.
+    ///
+    pub fn version(&self) -> Option<&semver::Version> {
+        match self {
+            Mode::Solidity(mode) => Some(&mode.solc_version),
+            Mode::SolidityUpstream(mode) => Some(&mode.solc_version),
+            Mode::YulUpstream(mode) => Some(&mode.solc_version),
+            Mode::Vyper(mode) => Some(&mode.vyper_version),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the Solidity codegen, if applicable.
+    ///
+    pub fn codegen(&self) -> Option<&'static str> {
+        let (codegen, via_ir) = match self {
+            Mode::Solidity(mode) => (mode.solc_codegen, mode.via_ir),
+            Mode::SolidityUpstream(mode) => (mode.solc_codegen, mode.via_ir),
+            _ => return None,
         };
-        versions.matches(version)
+        Some(match (codegen, via_ir) {
+            (era_solc::StandardJsonInputCodegen::EVMLA, false) => "EVMLA",
+            (era_solc::StandardJsonInputCodegen::EVMLA, true) => "EVMLA via IR",
+            (era_solc::StandardJsonInputCodegen::Yul, _) => "Yul",
+        })
     }
 
     ///
@@ -138,6 +208,23 @@ impl Mode {
         }
     }
 
+    ///
+    /// Whether the mode uses the Solidity EVM legacy assembly codegen without IR.
+    ///
+    pub fn is_evmla(&self) -> bool {
+        match self {
+            Mode::Solidity(mode) => {
+                matches!(mode.solc_codegen, era_solc::StandardJsonInputCodegen::EVMLA)
+                    && !mode.via_ir
+            }
+            Mode::SolidityUpstream(mode) => {
+                matches!(mode.solc_codegen, era_solc::StandardJsonInputCodegen::EVMLA)
+                    && !mode.via_ir
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Normalizes the mode according to the filter.
     ///
@@ -2,6 +2,9 @@
 //! The Solidity compiler cache key.
 //!
 
+use crate::compilers::solidity::suppressed_error::SuppressedError;
+use crate::compilers::solidity::suppressed_warning::SuppressedWarning;
+
 ///
 /// The Solidity compiler cache key.
 ///
@@ -17,6 +20,10 @@ pub struct CacheKey {
     pub via_ir: bool,
     /// Whether to run the Solidity compiler optimizer.
     pub optimize: bool,
+    /// The suppressed `zksolc` errors, which are checked in the output.
+    pub suppressed_errors: Vec<SuppressedError>,
+    /// The suppressed `zksolc` warnings, which are checked in the output.
+    pub suppressed_warnings: Vec<SuppressedWarning>,
 }
 
 impl CacheKey {
@@ -29,6 +36,8 @@ impl CacheKey {
         codegen: era_solc::StandardJsonInputCodegen,
         via_ir: bool,
         optimize: bool,
+        suppressed_errors: Vec<SuppressedError>,
+        suppressed_warnings: Vec<SuppressedWarning>,
     ) -> Self {
         Self {
             test_path,
@@ -36,6 +45,8 @@ impl CacheKey {
             codegen,
             via_ir,
             optimize,
+            suppressed_errors,
+            suppressed_warnings,
         }
     }
 }
@@ -4,26 +4,35 @@
 
 pub mod cache_key;
 pub mod mode;
+pub mod suppressed_error;
+pub mod suppressed_warning;
 pub mod upstream;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::path::Path;
+use std::path::PathBuf;
+use std::time::Instant;
 
 use itertools::Itertools;
 
 use era_solc::CollectableError;
 
 use crate::compilers::cache::Cache;
+use crate::compilers::invocation_log::InvocationLog;
 use crate::compilers::mode::Mode;
+use crate::compilers::statistics::usage::Usage;
+use crate::compilers::statistics::Statistics;
 use crate::compilers::Compiler;
 use crate::vm::eravm::input::Input as EraVMInput;
 use crate::vm::evm::input::build::Build as EVMBuild;
 use crate::vm::evm::input::Input as EVMInput;
+use crate::vm::revm::Revm;
 
 use self::cache_key::CacheKey;
 use self::mode::Mode as SolidityMode;
+use self::upstream::solc::Compiler as SolcUpstreamCompiler;
 
 ///
 /// The Solidity compiler.
@@ -102,16 +111,23 @@ impl SolidityCompiler {
     /// Returns the `solc` executable by its version.
     ///
     pub fn executable(version: &semver::Version) -> anyhow::Result<era_solc::Compiler> {
-        era_solc::Compiler::try_from_path(format!("{}/solc-{}", Self::DIRECTORY, version).as_str())
+        let path = InvocationLog::proxy(PathBuf::from(format!(
+            "{}/solc-{}",
+            Self::DIRECTORY,
+            version
+        )))?;
+        era_solc::Compiler::try_from_path(path.to_string_lossy().as_ref())
     }
 
     ///
     /// Returns the `solc` executable used to compile system contracts.
     ///
     pub fn system_contract_executable() -> anyhow::Result<era_solc::Compiler> {
-        era_solc::Compiler::try_from_path(
-            format!("{}/solc-system-contracts", Self::DIRECTORY).as_str(),
-        )
+        let path = InvocationLog::proxy(PathBuf::from(format!(
+            "{}/solc-system-contracts",
+            Self::DIRECTORY
+        )))?;
+        era_solc::Compiler::try_from_path(path.to_string_lossy().as_ref())
     }
 
     ///
@@ -171,6 +187,7 @@ impl SolidityCompiler {
     fn standard_json_output(
         sources: &[(String, String)],
         libraries: &era_solc::StandardJsonInputLibraries,
+        remappings: &BTreeSet<String>,
         mode: &SolidityMode,
     ) -> anyhow::Result<era_solc::StandardJsonOutput> {
         let solc_compiler = if mode.is_system_contracts_mode {
@@ -182,6 +199,7 @@ impl SolidityCompiler {
         let mut output_selection =
             era_solc::StandardJsonInputSelection::new_required(mode.solc_codegen);
         output_selection.extend(era_solc::StandardJsonInputSelection::new(vec![
+            era_solc::StandardJsonInputSelectionFlag::ABI,
             era_solc::StandardJsonInputSelectionFlag::EraVMAssembly,
         ]));
 
@@ -190,6 +208,11 @@ impl SolidityCompiler {
         } else {
             None
         };
+        let evm_version = SolcUpstreamCompiler::spec_evm_version(
+            Revm::selected_spec(),
+            &mode.solc_version,
+            evm_version,
+        );
 
         let sources: BTreeMap<String, era_solc::StandardJsonInputSource> = sources
             .iter()
@@ -204,7 +227,7 @@ impl SolidityCompiler {
         let mut solc_input = era_solc::StandardJsonInput::try_from_solidity_sources(
             sources,
             libraries.to_owned(),
-            BTreeSet::new(),
+            remappings.to_owned(),
             era_solc::StandardJsonInputOptimizer::default(),
             Some(mode.solc_codegen),
             evm_version,
@@ -212,8 +235,16 @@ impl SolidityCompiler {
             output_selection,
             era_solc::StandardJsonInputMetadata::default(),
             vec![],
-            vec![era_solc::StandardJsonInputErrorType::SendTransfer],
-            vec![],
+            mode.suppressed_errors
+                .iter()
+                .copied()
+                .map(era_solc::StandardJsonInputErrorType::from)
+                .collect(),
+            mode.suppressed_warnings
+                .iter()
+                .copied()
+                .map(era_solc::StandardJsonInputWarningType::from)
+                .collect(),
             false,
             mode.via_ir,
         )
@@ -225,13 +256,33 @@ impl SolidityCompiler {
             .to_string_lossy()
             .to_string();
 
-        solc_compiler.standard_json(
+        let children_peak_rss = Usage::children_peak_rss();
+        let start = Instant::now();
+        let output = solc_compiler.standard_json(
             &mut solc_input,
             &mut vec![],
             None,
             vec![],
             Some(allow_paths),
-        )
+        );
+        Statistics::record(
+            "solc",
+            &mode.to_owned().into(),
+            &Usage::from_children(start, children_peak_rss, output.is_ok()),
+        );
+        output
+    }
+
+    ///
+    /// Returns the formatted warnings of the standard JSON output.
+    ///
+    fn get_warnings(solc_output: &era_solc::StandardJsonOutput) -> Vec<String> {
+        solc_output
+            .errors
+            .iter()
+            .filter(|error| error.severity.as_str() == "warning")
+            .map(|error| error.formatted_message.to_owned())
+            .collect()
     }
 
     ///
@@ -242,6 +293,7 @@ impl SolidityCompiler {
         test_path: String,
         sources: &[(String, String)],
         libraries: &era_solc::StandardJsonInputLibraries,
+        remappings: &BTreeSet<String>,
         mode: &SolidityMode,
     ) -> anyhow::Result<era_solc::StandardJsonOutput> {
         let cache_key = CacheKey::new(
@@ -250,11 +302,13 @@ impl SolidityCompiler {
             mode.solc_codegen,
             mode.via_ir,
             mode.solc_optimize,
+            mode.suppressed_errors.to_owned(),
+            mode.suppressed_warnings.to_owned(),
         );
 
         if !self.cache.contains(&cache_key) {
             self.cache.evaluate(cache_key.clone(), || {
-                Self::standard_json_output(sources, libraries, mode)
+                Self::standard_json_output(sources, libraries, remappings, mode)
             });
         }
 
@@ -297,6 +351,21 @@ impl SolidityCompiler {
         Ok(method_identifiers)
     }
 
+    ///
+    /// Get the contracts ABI from the solc output.
+    ///
+    fn get_abis(solc_output: &era_solc::StandardJsonOutput) -> BTreeMap<String, serde_json::Value> {
+        let mut abis = BTreeMap::new();
+        for (path, file) in solc_output.contracts.iter() {
+            for (name, contract) in file.iter() {
+                if !contract.abi.is_null() {
+                    abis.insert(format!("{path}:{name}"), contract.abi.to_owned());
+                }
+            }
+        }
+        abis
+    }
+
     ///
     /// Get the last contract from the solc output.
     ///
@@ -325,6 +394,7 @@ impl Compiler for SolidityCompiler {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        remappings: BTreeSet<String>,
         mode: &Mode,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
@@ -332,12 +402,21 @@ impl Compiler for SolidityCompiler {
         let mode = SolidityMode::unwrap(mode);
 
         let mut solc_output = self
-            .standard_json_output_cached(test_path, &sources, &libraries, mode)
+            .standard_json_output_cached(test_path, &sources, &libraries, &remappings, mode)
             .map_err(|error| anyhow::anyhow!("Solidity standard JSON I/O error: {}", error))?;
         solc_output.collect_errors()?;
+        let warnings = Self::get_warnings(&solc_output);
+        if let Some(debug_config) = debug_config.as_ref() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                "standard_json_output.json",
+                serde_json::to_vec_pretty(&solc_output)?,
+            )?;
+        }
 
         let method_identifiers = Self::get_method_identifiers(&solc_output)
             .map_err(|error| anyhow::anyhow!("Failed to get method identifiers: {}", error))?;
+        let abis = Self::get_abis(&solc_output);
 
         let last_contract = Self::get_last_contract(&solc_output, &sources)
             .map_err(|error| anyhow::anyhow!("Failed to get the last contract: {}", error))?;
@@ -395,11 +474,10 @@ impl Compiler for SolidityCompiler {
         )?;
         solc_output.collect_errors()?;
 
-        Ok(EraVMInput::new(
-            builds,
-            Some(method_identifiers),
-            last_contract,
-        ))
+        Ok(
+            EraVMInput::new(builds, Some(method_identifiers), Some(abis), last_contract)
+                .with_warnings(warnings),
+        )
     }
 
     fn compile_for_evm(
@@ -407,6 +485,7 @@ impl Compiler for SolidityCompiler {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        remappings: BTreeSet<String>,
         mode: &Mode,
         _test_params: Option<&solidity_adapter::Params>,
         llvm_options: Vec<String>,
@@ -415,10 +494,19 @@ impl Compiler for SolidityCompiler {
         let mode = SolidityMode::unwrap(mode);
 
         let mut solc_output =
-            self.standard_json_output_cached(test_path, &sources, &libraries, mode)?;
+            self.standard_json_output_cached(test_path, &sources, &libraries, &remappings, mode)?;
         solc_output.collect_errors()?;
+        let warnings = Self::get_warnings(&solc_output);
+        if let Some(debug_config) = debug_config.as_ref() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                "standard_json_output.json",
+                serde_json::to_vec_pretty(&solc_output)?,
+            )?;
+        }
 
         let method_identifiers = Self::get_method_identifiers(&solc_output)?;
+        let abis = Self::get_abis(&solc_output);
 
         let last_contract = Self::get_last_contract(&solc_output, &sources)?;
 
@@ -451,11 +539,10 @@ impl Compiler for SolidityCompiler {
             })
             .collect();
 
-        Ok(EVMInput::new(
-            builds,
-            Some(method_identifiers),
-            last_contract,
-        ))
+        Ok(
+            EVMInput::new(builds, Some(method_identifiers), Some(abis), last_contract)
+                .with_warnings(warnings),
+        )
     }
 
     fn all_modes(&self) -> Vec<Mode> {
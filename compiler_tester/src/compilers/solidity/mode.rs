@@ -7,6 +7,8 @@ use itertools::Itertools;
 use crate::compilers::mode::llvm_options::LLVMOptions;
 
 use crate::compilers::mode::Mode as ModeWrapper;
+use crate::compilers::solidity::suppressed_error::SuppressedError;
+use crate::compilers::solidity::suppressed_warning::SuppressedWarning;
 
 ///
 /// The compiler tester Solidity mode.
@@ -27,9 +29,16 @@ pub struct Mode {
     pub enable_eravm_extensions: bool,
     /// The system contract mode.
     pub is_system_contracts_mode: bool,
+    /// The suppressed `zksolc` errors.
+    pub suppressed_errors: Vec<SuppressedError>,
+    /// The suppressed `zksolc` warnings.
+    pub suppressed_warnings: Vec<SuppressedWarning>,
 }
 
 impl Mode {
+    /// The `zksolc` errors suppressed unless specified by the test.
+    pub const DEFAULT_SUPPRESSED_ERRORS: [SuppressedError; 1] = [SuppressedError::SendTransfer];
+
     ///
     /// A shortcut constructor.
     ///
@@ -55,6 +64,8 @@ impl Mode {
             llvm_optimizer_settings,
             enable_eravm_extensions,
             is_system_contracts_mode,
+            suppressed_errors: Self::DEFAULT_SUPPRESSED_ERRORS.to_vec(),
+            suppressed_warnings: vec![],
         }
     }
 
@@ -127,6 +138,38 @@ impl Mode {
         }
     }
 
+    ///
+    /// Returns the suppressions differing from the default ones, as `suppress=<names>`.
+    ///
+    /// The suppressions are a part of the mode name, so the builds with different ones are
+    /// distinguished in the reports.
+    ///
+    pub fn suppressions(&self) -> Option<String> {
+        if self.suppressed_errors == Self::DEFAULT_SUPPRESSED_ERRORS
+            && self.suppressed_warnings.is_empty()
+        {
+            return None;
+        }
+
+        let names: Vec<String> = self
+            .suppressed_errors
+            .iter()
+            .sorted()
+            .map(|error| error.to_string())
+            .chain(
+                self.suppressed_warnings
+                    .iter()
+                    .sorted()
+                    .map(|warning| warning.to_string()),
+            )
+            .collect();
+        Some(if names.is_empty() {
+            "suppress=none".to_owned()
+        } else {
+            format!("suppress={}", names.join(","))
+        })
+    }
+
     ///
     /// Returns a string representation excluding the solc version.
     ///
@@ -143,8 +186,54 @@ impl Mode {
         )
     }
 }
+
 impl std::fmt::Display for Mode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.repr_without_version(), self.solc_version)
+        write!(f, "{} {}", self.repr_without_version(), self.solc_version)?;
+        if let Some(suppressions) = self.suppressions() {
+            write!(f, " {suppressions}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compilers::solidity::suppressed_error::SuppressedError;
+    use crate::compilers::solidity::suppressed_warning::SuppressedWarning;
+
+    use super::Mode;
+
+    #[test]
+    fn suppressions() {
+        for (errors, warnings, expected) in [
+            (
+                Mode::DEFAULT_SUPPRESSED_ERRORS.to_vec(),
+                vec![],
+                "Y+M3B3 0.8.28",
+            ),
+            (vec![], vec![], "Y+M3B3 0.8.28 suppress=none"),
+            (
+                vec![SuppressedError::SendTransfer],
+                vec![
+                    SuppressedWarning::TxOrigin,
+                    SuppressedWarning::AssemblyCreate,
+                ],
+                "Y+M3B3 0.8.28 suppress=sendtransfer,txorigin,assemblycreate",
+            ),
+        ] {
+            let mode = Mode {
+                solc_version: semver::Version::new(0, 8, 28),
+                solc_codegen: era_solc::StandardJsonInputCodegen::Yul,
+                via_ir: false,
+                solc_optimize: true,
+                llvm_optimizer_settings: era_compiler_llvm_context::OptimizerSettings::cycles(),
+                enable_eravm_extensions: false,
+                is_system_contracts_mode: false,
+                suppressed_errors: errors,
+                suppressed_warnings: warnings,
+            };
+            assert_eq!(mode.to_string(), expected);
+        }
     }
 }
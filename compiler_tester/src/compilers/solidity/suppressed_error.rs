@@ -0,0 +1,31 @@
+//!
+//! The `zksolc` error which can be suppressed.
+//!
+
+///
+/// The `zksolc` error which can be suppressed.
+///
+/// The names are the ones of the `--suppress-errors` option.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuppressedError {
+    /// The `send` and `transfer` calls.
+    SendTransfer,
+}
+
+impl From<SuppressedError> for era_solc::StandardJsonInputErrorType {
+    fn from(error: SuppressedError) -> Self {
+        match error {
+            SuppressedError::SendTransfer => Self::SendTransfer,
+        }
+    }
+}
+
+impl std::fmt::Display for SuppressedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SendTransfer => write!(f, "sendtransfer"),
+        }
+    }
+}
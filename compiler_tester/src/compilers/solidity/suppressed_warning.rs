@@ -0,0 +1,35 @@
+//!
+//! The `zksolc` warning which can be suppressed.
+//!
+
+///
+/// The `zksolc` warning which can be suppressed.
+///
+/// The names are the ones of the `--suppress-warnings` option.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuppressedWarning {
+    /// The `tx.origin` usage.
+    TxOrigin,
+    /// The `create` and `create2` usage in assembly.
+    AssemblyCreate,
+}
+
+impl From<SuppressedWarning> for era_solc::StandardJsonInputWarningType {
+    fn from(warning: SuppressedWarning) -> Self {
+        match warning {
+            SuppressedWarning::TxOrigin => Self::TxOrigin,
+            SuppressedWarning::AssemblyCreate => Self::AssemblyCreate,
+        }
+    }
+}
+
+impl std::fmt::Display for SuppressedWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TxOrigin => write!(f, "txorigin"),
+            Self::AssemblyCreate => write!(f, "assemblycreate"),
+        }
+    }
+}
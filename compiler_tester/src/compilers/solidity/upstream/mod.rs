@@ -6,18 +6,21 @@ pub mod mode;
 pub mod solc;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::path::Path;
 
 use crate::compilers::cache::Cache;
 use crate::compilers::mode::Mode;
 use crate::compilers::solidity::cache_key::CacheKey;
+use crate::compilers::statistics::Statistics;
 use crate::compilers::yul::mode_upstream::Mode as YulUpstreamMode;
 use crate::compilers::Compiler;
 use crate::toolchain::Toolchain;
 use crate::vm::eravm::input::Input as EraVMInput;
 use crate::vm::evm::input::build::Build as EVMBuild;
 use crate::vm::evm::input::Input as EVMInput;
+use crate::vm::revm::Revm;
 
 use self::mode::Mode as SolidityUpstreamMode;
 use self::solc::standard_json::input::language::Language as SolcStandardJsonInputLanguage;
@@ -195,6 +198,7 @@ impl SolidityCompiler {
         toolchain: Toolchain,
         sources: &[(String, String)],
         libraries: &era_solc::StandardJsonInputLibraries,
+        remappings: &BTreeSet<String>,
         mode: &Mode,
         test_params: Option<&solidity_adapter::Params>,
     ) -> anyhow::Result<SolcStandardJsonOutput> {
@@ -227,6 +231,11 @@ impl SolidityCompiler {
             Mode::YulUpstream(_mode) => Some(era_compiler_common::EVMVersion::Cancun),
             mode => anyhow::bail!("Unsupported mode: {mode}"),
         };
+        let evm_version = SolcUpstreamCompiler::spec_evm_version(
+            Revm::selected_spec(),
+            solc_version,
+            evm_version,
+        );
 
         let via_ir = match mode {
             Mode::SolidityUpstream(mode) => mode.via_ir,
@@ -253,7 +262,11 @@ impl SolidityCompiler {
             evm_version,
             sources.iter().cloned().collect(),
             libraries.clone(),
-            None,
+            if remappings.is_empty() {
+                None
+            } else {
+                Some(remappings.to_owned())
+            },
             output_selection,
             via_ir,
             via_mlir,
@@ -268,7 +281,11 @@ impl SolidityCompiler {
             .to_string_lossy()
             .to_string();
 
-        solc.standard_json(solc_input, None, vec![], Some(allow_paths))
+        let output = solc.standard_json(solc_input, None, vec![], Some(allow_paths));
+        if let Some(usage) = solc.usage() {
+            Statistics::record(toolchain.to_string().as_str(), mode, usage);
+        }
+        output
     }
 
     ///
@@ -280,6 +297,7 @@ impl SolidityCompiler {
         language: SolcStandardJsonInputLanguage,
         sources: &[(String, String)],
         libraries: &era_solc::StandardJsonInputLibraries,
+        remappings: &BTreeSet<String>,
         mode: &Mode,
         test_params: Option<&solidity_adapter::Params>,
     ) -> anyhow::Result<SolcStandardJsonOutput> {
@@ -308,6 +326,7 @@ impl SolidityCompiler {
                     self.toolchain,
                     sources,
                     libraries,
+                    remappings,
                     mode,
                     test_params,
                 )
@@ -366,6 +385,21 @@ impl SolidityCompiler {
         Ok(method_identifiers)
     }
 
+    ///
+    /// Get the contracts ABI from the solc output.
+    ///
+    pub fn get_abis(solc_output: &SolcStandardJsonOutput) -> BTreeMap<String, serde_json::Value> {
+        let mut abis = BTreeMap::new();
+        for (path, contracts) in solc_output.contracts.iter().flatten() {
+            for (name, contract) in contracts.iter() {
+                if let Some(abi) = contract.abi.as_ref() {
+                    abis.insert(format!("{path}:{name}"), abi.to_owned());
+                }
+            }
+        }
+        abis
+    }
+
     ///
     /// Get the last contract from the solc output.
     ///
@@ -424,15 +458,17 @@ impl Compiler for SolidityCompiler {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        remappings: BTreeSet<String>,
         mode: &Mode,
         _llvm_options: Vec<String>,
-        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EraVMInput> {
         let solc_output = self.standard_json_output_cached(
             test_path,
             self.language,
             &sources,
             &libraries,
+            &remappings,
             mode,
             None,
         )?;
@@ -452,12 +488,20 @@ impl Compiler for SolidityCompiler {
                 anyhow::bail!("`solc` errors found: {:?}", error_messages);
             }
         }
+        if let Some(debug_config) = debug_config.as_ref() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                "standard_json_output.json",
+                serde_json::to_vec_pretty(&solc_output)?,
+            )?;
+        }
 
-        let method_identifiers = match self.language {
-            SolcStandardJsonInputLanguage::Solidity => {
-                Some(Self::get_method_identifiers(&solc_output)?)
-            }
-            SolcStandardJsonInputLanguage::Yul => None,
+        let (method_identifiers, abis) = match self.language {
+            SolcStandardJsonInputLanguage::Solidity => (
+                Some(Self::get_method_identifiers(&solc_output)?),
+                Some(Self::get_abis(&solc_output)),
+            ),
+            SolcStandardJsonInputLanguage::Yul => (None, None),
         };
 
         let last_contract = Self::get_last_contract(self.language, &solc_output, &sources)?;
@@ -508,7 +552,12 @@ impl Compiler for SolidityCompiler {
             }
         }
 
-        Ok(EraVMInput::new(builds, method_identifiers, last_contract))
+        Ok(EraVMInput::new(
+            builds,
+            method_identifiers,
+            abis,
+            last_contract,
+        ))
     }
 
     fn compile_for_evm(
@@ -516,16 +565,18 @@ impl Compiler for SolidityCompiler {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        remappings: BTreeSet<String>,
         mode: &Mode,
         test_params: Option<&solidity_adapter::Params>,
         _llvm_options: Vec<String>,
-        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EVMInput> {
         let solc_output = self.standard_json_output_cached(
             test_path,
             self.language,
             &sources,
             &libraries,
+            &remappings,
             mode,
             test_params,
         )?;
@@ -545,12 +596,20 @@ impl Compiler for SolidityCompiler {
                 anyhow::bail!("`solc` errors found: {:?}", error_messages);
             }
         }
+        if let Some(debug_config) = debug_config.as_ref() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                "standard_json_output.json",
+                serde_json::to_vec_pretty(&solc_output)?,
+            )?;
+        }
 
-        let method_identifiers = match self.language {
-            SolcStandardJsonInputLanguage::Solidity => {
-                Some(Self::get_method_identifiers(&solc_output)?)
-            }
-            SolcStandardJsonInputLanguage::Yul => None,
+        let (method_identifiers, abis) = match self.language {
+            SolcStandardJsonInputLanguage::Solidity => (
+                Some(Self::get_method_identifiers(&solc_output)?),
+                Some(Self::get_abis(&solc_output)),
+            ),
+            SolcStandardJsonInputLanguage::Yul => (None, None),
         };
 
         let last_contract = Self::get_last_contract(self.language, &solc_output, &sources)?;
@@ -559,10 +618,34 @@ impl Compiler for SolidityCompiler {
             .contracts
             .ok_or_else(|| anyhow::anyhow!("Solidity contracts not found in the output"))?;
 
+        let immutable_names = solc_output
+            .sources
+            .iter()
+            .flatten()
+            .flat_map(|(_path, source)| source.immutable_names())
+            .collect::<BTreeMap<String, String>>();
+
         let mut builds = HashMap::with_capacity(contracts.len());
         for (file, contracts) in contracts.into_iter() {
             for (name, contract) in contracts.into_iter() {
                 let path = format!("{file}:{name}");
+                let immutables = contract
+                    .evm
+                    .as_ref()
+                    .and_then(|evm| evm.deployed_bytecode.as_ref())
+                    .map(|deployed_bytecode| {
+                        deployed_bytecode
+                            .immutable_references
+                            .iter()
+                            .filter_map(|(id, references)| {
+                                Some((
+                                    immutable_names.get(id)?.to_owned(),
+                                    references.iter().map(|reference| reference.start).collect(),
+                                ))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
                 let bytecode_string = contract
                     .evm
                     .as_ref()
@@ -581,12 +664,18 @@ impl Compiler for SolidityCompiler {
                         anyhow::anyhow!("EVM bytecode of the contract `{path}` is invalid: {error}")
                     })?,
                     vec![],
-                );
+                )
+                .with_immutables(immutables);
                 builds.insert(path, build);
             }
         }
 
-        Ok(EVMInput::new(builds, method_identifiers, last_contract))
+        Ok(EVMInput::new(
+            builds,
+            method_identifiers,
+            abis,
+            last_contract,
+        ))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
@@ -0,0 +1,141 @@
+//!
+//! The Solidity compiler library.
+//!
+
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// The `solidity_compile` function signature.
+type CompileFunction = unsafe extern "C" fn(
+    input: *const c_char,
+    read_callback: *const c_void,
+    read_context: *mut c_void,
+) -> *mut c_char;
+
+/// The `solidity_free` function signature.
+type FreeFunction = unsafe extern "C" fn(data: *mut c_char);
+
+/// The `solidity_reset` function signature.
+type ResetFunction = unsafe extern "C" fn();
+
+/// The library slots keyed by path, including the ones of missing libraries.
+///
+/// The map lock is only held to get the slot, so loading one library does not block the others.
+static LIBRARIES: once_cell::sync::Lazy<Mutex<HashMap<PathBuf, Arc<LibrarySlot>>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The lazily loaded library, which is `None` if it is missing or unsupported.
+type LibrarySlot = OnceLock<Option<Arc<Library>>>;
+
+///
+/// The Solidity compiler library.
+///
+/// The `libsolc` keeps its state in globals, so the calls to the same library are serialized,
+/// whereas the libraries of different versions are independent and called in parallel.
+///
+pub struct Library {
+    /// The library path.
+    path: PathBuf,
+    /// The loaded library.
+    library: Mutex<libloading::Library>,
+}
+
+impl Library {
+    ///
+    /// Returns the library built alongside the `executable`, if any.
+    ///
+    /// The library is expected at `lib<executable>.so` in the executable directory, for example,
+    /// `solc-bin-upstream/libsolc-0.8.24.so` for `solc-bin-upstream/solc-0.8.24`.
+    /// Versions without a library build or without the `solidity_compile` API are skipped.
+    ///
+    pub fn get(executable: &str) -> Option<Arc<Self>> {
+        let executable = Path::new(executable);
+        let file_name = executable.file_name()?.to_string_lossy();
+        let path = executable.with_file_name(format!(
+            "lib{file_name}.{}",
+            std::env::consts::DLL_EXTENSION
+        ));
+
+        let slot = LIBRARIES
+            .lock()
+            .expect("Sync")
+            .entry(path.clone())
+            .or_default()
+            .clone();
+        slot.get_or_init(|| Self::load(path).map(Arc::new)).clone()
+    }
+
+    ///
+    /// Compiles the Solidity `--standard-json` input.
+    ///
+    /// Imports are not resolved, so the input must contain the contents of all sources.
+    ///
+    pub fn standard_json(&self, input: &[u8]) -> anyhow::Result<Vec<u8>> {
+        let input = CString::new(input).map_err(|error| {
+            anyhow::anyhow!("{} input error: {}", self.path.to_string_lossy(), error)
+        })?;
+
+        let library = self.library.lock().expect("Sync");
+        // SAFETY: the symbols have been checked in `Self::load`, and the calls are serialized.
+        unsafe {
+            let compile = library.get::<CompileFunction>(b"solidity_compile\0")?;
+            let free = library.get::<FreeFunction>(b"solidity_free\0")?;
+            let reset = library.get::<ResetFunction>(b"solidity_reset\0")?;
+
+            let output = compile(input.as_ptr(), std::ptr::null(), std::ptr::null_mut());
+            if output.is_null() {
+                anyhow::bail!("{} returned no output", self.path.to_string_lossy());
+            }
+            let result = CStr::from_ptr(output).to_bytes().to_vec();
+            free(output);
+            reset();
+            Ok(result)
+        }
+    }
+
+    ///
+    /// Loads the library at `path`, if it exists and provides the `solidity_compile` API.
+    ///
+    fn load(path: PathBuf) -> Option<Self> {
+        if !path.exists() {
+            return None;
+        }
+
+        // SAFETY: the library is a `libsolc` build with no initialization side effects.
+        let library = match unsafe { libloading::Library::new(path.as_path()) } {
+            Ok(library) => library,
+            Err(error) => {
+                eprintln!(
+                    "{} loading error, falling back to the executable: {}",
+                    path.to_string_lossy(),
+                    error
+                );
+                return None;
+            }
+        };
+        // SAFETY: the symbols are only checked for existence here.
+        let is_supported = unsafe {
+            library
+                .get::<CompileFunction>(b"solidity_compile\0")
+                .is_ok()
+                && library.get::<FreeFunction>(b"solidity_free\0").is_ok()
+                && library.get::<ResetFunction>(b"solidity_reset\0").is_ok()
+        };
+        if !is_supported {
+            return None;
+        }
+
+        Some(Self {
+            path,
+            library: Mutex::new(library),
+        })
+    }
+}
@@ -2,10 +2,20 @@
 //! The Solidity compiler.
 //!
 
+#[cfg(feature = "solc-ffi")]
+pub mod library;
 pub mod standard_json;
 
 use std::io::Write;
+#[cfg(feature = "solc-ffi")]
+use std::sync::Arc;
+use std::time::Instant;
 
+use crate::compilers::invocation_log::InvocationLog;
+use crate::compilers::statistics::usage::Usage;
+
+#[cfg(feature = "solc-ffi")]
+use self::library::Library;
 use self::standard_json::input::Input as StandardJsonInput;
 use self::standard_json::output::Output as StandardJsonOutput;
 
@@ -15,6 +25,11 @@ use self::standard_json::output::Output as StandardJsonOutput;
 pub struct Compiler {
     /// The executable name.
     pub executable: String,
+    /// The in-process library, if built for the executable version.
+    #[cfg(feature = "solc-ffi")]
+    library: Option<Arc<Library>>,
+    /// The resource usage of the last subprocess.
+    usage: Option<Usage>,
 }
 
 impl Compiler {
@@ -27,6 +42,72 @@ impl Compiler {
     /// The first version of `solc`, where `Cancun` EVM version is supported.
     pub const FIRST_CANCUN_VERSION: semver::Version = semver::Version::new(0, 8, 24);
 
+    ///
+    /// Returns the first version of `solc`, where the EVM version is supported.
+    ///
+    pub fn first_evm_version(evm: solidity_adapter::EVM) -> semver::Version {
+        match evm {
+            solidity_adapter::EVM::Homestead
+            | solidity_adapter::EVM::TangerineWhistle
+            | solidity_adapter::EVM::SpuriousDragon
+            | solidity_adapter::EVM::Byzantium
+            | solidity_adapter::EVM::Constantinople => semver::Version::new(0, 4, 21),
+            solidity_adapter::EVM::Petersburg => semver::Version::new(0, 5, 5),
+            solidity_adapter::EVM::Istanbul => semver::Version::new(0, 5, 14),
+            solidity_adapter::EVM::Berlin => semver::Version::new(0, 8, 5),
+            solidity_adapter::EVM::London => semver::Version::new(0, 8, 7),
+            solidity_adapter::EVM::Paris => semver::Version::new(0, 8, 18),
+            solidity_adapter::EVM::Shanghai => semver::Version::new(0, 8, 20),
+            solidity_adapter::EVM::Cancun => Self::FIRST_CANCUN_VERSION,
+        }
+    }
+
+    ///
+    /// Converts the hardfork to the `solc` EVM version.
+    ///
+    pub fn evm_version(evm: solidity_adapter::EVM) -> era_compiler_common::EVMVersion {
+        match evm {
+            solidity_adapter::EVM::Homestead => era_compiler_common::EVMVersion::Homestead,
+            solidity_adapter::EVM::TangerineWhistle => {
+                era_compiler_common::EVMVersion::TangerineWhistle
+            }
+            solidity_adapter::EVM::SpuriousDragon => {
+                era_compiler_common::EVMVersion::SpuriousDragon
+            }
+            solidity_adapter::EVM::Byzantium => era_compiler_common::EVMVersion::Byzantium,
+            solidity_adapter::EVM::Constantinople => {
+                era_compiler_common::EVMVersion::Constantinople
+            }
+            solidity_adapter::EVM::Petersburg => era_compiler_common::EVMVersion::Petersburg,
+            solidity_adapter::EVM::Istanbul => era_compiler_common::EVMVersion::Istanbul,
+            solidity_adapter::EVM::Berlin => era_compiler_common::EVMVersion::Berlin,
+            solidity_adapter::EVM::London => era_compiler_common::EVMVersion::London,
+            solidity_adapter::EVM::Paris => era_compiler_common::EVMVersion::Paris,
+            solidity_adapter::EVM::Shanghai => era_compiler_common::EVMVersion::Shanghai,
+            solidity_adapter::EVM::Cancun => era_compiler_common::EVMVersion::Cancun,
+        }
+    }
+
+    ///
+    /// Returns the EVM version to compile for with the hardfork `spec` selected for the run.
+    ///
+    /// Without a selected hardfork, the `default` EVM version is returned. If the `solc` version
+    /// predates the hardfork, the `solc` default EVM version is used.
+    ///
+    pub fn spec_evm_version(
+        spec: Option<solidity_adapter::EVM>,
+        solc_version: &semver::Version,
+        default: Option<era_compiler_common::EVMVersion>,
+    ) -> Option<era_compiler_common::EVMVersion> {
+        match spec {
+            Some(evm) if solc_version >= &Self::first_evm_version(evm) => {
+                Some(Self::evm_version(evm))
+            }
+            Some(_) => None,
+            None => default,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -40,12 +121,20 @@ impl Compiler {
                 error
             );
         }
-        Ok(Self { executable })
+        Ok(Self {
+            #[cfg(feature = "solc-ffi")]
+            library: Library::get(executable.as_str()),
+            executable,
+            usage: None,
+        })
     }
 
     ///
     /// Compiles the Solidity `--standard-json` input into Yul IR.
     ///
+    /// With the `solc-ffi` feature, the in-process library is used if available and no paths
+    /// for import resolution are specified.
+    ///
     pub fn standard_json(
         &mut self,
         input: StandardJsonInput,
@@ -53,6 +142,18 @@ impl Compiler {
         include_paths: Vec<String>,
         allow_paths: Option<String>,
     ) -> anyhow::Result<StandardJsonOutput> {
+        #[cfg(feature = "solc-ffi")]
+        if let (Some(library), None, true, None) = (
+            self.library.as_ref(),
+            base_path.as_ref(),
+            include_paths.is_empty(),
+            allow_paths.as_ref(),
+        ) {
+            let input_json = serde_json::to_vec(&input).expect("Always valid");
+            let output = library.standard_json(input_json.as_slice())?;
+            return self.parse_output(output.as_slice());
+        }
+
         let mut command = std::process::Command::new(self.executable.as_str());
         command.stdin(std::process::Stdio::piped());
         command.stdout(std::process::Stdio::piped());
@@ -72,7 +173,9 @@ impl Compiler {
         }
 
         let input_json = serde_json::to_vec(&input).expect("Always valid");
+        InvocationLog::record(&command, input_json.as_slice())?;
 
+        let start = Instant::now();
         let process = command.spawn().map_err(|error| {
             anyhow::anyhow!("{} subprocess spawning error: {:?}", self.executable, error)
         })?;
@@ -85,9 +188,10 @@ impl Compiler {
                 anyhow::anyhow!("{} stdin writing error: {:?}", self.executable, error)
             })?;
 
-        let output = process.wait_with_output().map_err(|error| {
+        let (output, usage) = Usage::wait_with_output(process, start).map_err(|error| {
             anyhow::anyhow!("{} subprocess output error: {:?}", self.executable, error)
         })?;
+        self.usage = Some(usage);
         if !output.status.success() {
             anyhow::bail!(
                 "{} error: {}",
@@ -96,22 +200,80 @@ impl Compiler {
             );
         }
 
-        let output: StandardJsonOutput = era_compiler_common::deserialize_from_slice(
-            output.stdout.as_slice(),
-        )
-        .map_err(|error| {
-            anyhow::anyhow!(
-                "{} subprocess output parsing error: {}\n{}",
-                self.executable,
-                error,
-                era_compiler_common::deserialize_from_slice::<serde_json::Value>(
-                    output.stdout.as_slice()
+        self.parse_output(output.stdout.as_slice())
+    }
+
+    ///
+    /// Returns the resource usage of the last subprocess, if any has been spawned.
+    ///
+    pub fn usage(&self) -> Option<&Usage> {
+        self.usage.as_ref()
+    }
+
+    ///
+    /// Parses the Solidity `--standard-json` output.
+    ///
+    fn parse_output(&self, output: &[u8]) -> anyhow::Result<StandardJsonOutput> {
+        let output: StandardJsonOutput = era_compiler_common::deserialize_from_slice(output)
+            .map_err(|error| {
+                anyhow::anyhow!(
+                    "{} subprocess output parsing error: {}\n{}",
+                    self.executable,
+                    error,
+                    era_compiler_common::deserialize_from_slice::<serde_json::Value>(output)
+                        .map(|json| serde_json::to_string_pretty(&json).expect("Always valid"))
+                        .unwrap_or_else(|_| String::from_utf8_lossy(output).to_string()),
                 )
-                .map(|json| serde_json::to_string_pretty(&json).expect("Always valid"))
-                .unwrap_or_else(|_| String::from_utf8_lossy(output.stdout.as_slice()).to_string()),
-            )
-        })?;
+            })?;
 
         Ok(output)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Compiler;
+
+    ///
+    /// A Shanghai run never compiles for Cancun, even with the `solc` versions defaulting to it,
+    /// so the contracts contain no `MCOPY`, `TLOAD`, `TSTORE`, `BLOBHASH`, or `BLOBBASEFEE`.
+    ///
+    #[test]
+    fn spec_evm_version() {
+        let cancun = Some(era_compiler_common::EVMVersion::Cancun);
+        for (spec, solc_version, expected) in [
+            (None, semver::Version::new(0, 8, 24), cancun),
+            (
+                Some(solidity_adapter::EVM::Shanghai),
+                semver::Version::new(0, 8, 28),
+                Some(era_compiler_common::EVMVersion::Shanghai),
+            ),
+            (
+                Some(solidity_adapter::EVM::Shanghai),
+                semver::Version::new(0, 8, 20),
+                Some(era_compiler_common::EVMVersion::Shanghai),
+            ),
+            (
+                Some(solidity_adapter::EVM::Shanghai),
+                semver::Version::new(0, 8, 19),
+                None,
+            ),
+            (
+                Some(solidity_adapter::EVM::Cancun),
+                semver::Version::new(0, 8, 23),
+                None,
+            ),
+            (
+                Some(solidity_adapter::EVM::Paris),
+                semver::Version::new(0, 8, 24),
+                Some(era_compiler_common::EVMVersion::Paris),
+            ),
+        ] {
+            assert_eq!(
+                Compiler::spec_evm_version(spec, &solc_version, cancun),
+                expected,
+                "{spec:?} with solc v{solc_version}"
+            );
+        }
+    }
+}
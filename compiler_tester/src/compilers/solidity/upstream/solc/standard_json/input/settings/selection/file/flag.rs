@@ -11,9 +11,15 @@ use serde::Serialize;
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum Flag {
+    /// The ABI JSON.
+    #[serde(rename = "abi")]
+    ABI,
     /// The combined bytecode.
     #[serde(rename = "evm.bytecode")]
     Bytecode,
+    /// The immutable references in the runtime code.
+    #[serde(rename = "evm.deployedBytecode.immutableReferences")]
+    ImmutableReferences,
     /// The function signature hashes JSON.
     #[serde(rename = "evm.methodIdentifiers")]
     MethodIdentifiers,
@@ -40,7 +46,9 @@ impl From<era_solc::StandardJsonInputCodegen> for Flag {
 impl std::fmt::Display for Flag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::ABI => write!(f, "abi"),
             Self::Bytecode => write!(f, "evm.bytecode"),
+            Self::ImmutableReferences => write!(f, "evm.deployedBytecode.immutableReferences"),
             Self::MethodIdentifiers => write!(f, "evm.methodIdentifiers"),
             Self::AST => write!(f, "ast"),
             Self::Yul => write!(f, "irOptimized"),
@@ -31,7 +31,9 @@ impl File {
         Self {
             per_file: Some(HashSet::from_iter([SelectionFlag::AST])),
             per_contract: Some(HashSet::from_iter([
+                SelectionFlag::ABI,
                 SelectionFlag::Bytecode,
+                SelectionFlag::ImmutableReferences,
                 SelectionFlag::MethodIdentifiers,
                 SelectionFlag::from(codegen),
             ])),
@@ -0,0 +1,17 @@
+//!
+//! The `solc --standard-json` output contract EVM immutable reference.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The `solc --standard-json` output contract EVM immutable reference.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct ImmutableReference {
+    /// The byte offset of the immutable value in the runtime code.
+    pub start: usize,
+    /// The byte length of the immutable value.
+    pub length: usize,
+}
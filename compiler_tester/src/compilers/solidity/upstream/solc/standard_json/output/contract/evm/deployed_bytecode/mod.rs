@@ -0,0 +1,23 @@
+//!
+//! The `solc --standard-json` output contract EVM deployed bytecode.
+//!
+
+pub mod immutable_reference;
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use self::immutable_reference::ImmutableReference;
+
+///
+/// The `solc --standard-json` output contract EVM deployed bytecode.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeployedBytecode {
+    /// The immutable references in the runtime code by the AST IDs of the immutable variables.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub immutable_references: BTreeMap<String, Vec<ImmutableReference>>,
+}
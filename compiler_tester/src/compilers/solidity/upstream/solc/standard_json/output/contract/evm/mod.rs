@@ -3,22 +3,28 @@
 //!
 
 pub mod bytecode;
+pub mod deployed_bytecode;
 
 use std::collections::BTreeMap;
 
 use serde::Deserialize;
+use serde::Serialize;
 
 use self::bytecode::Bytecode;
+use self::deployed_bytecode::DeployedBytecode;
 
 ///
 /// The `solc --standard-json` output contract EVM data.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct EVM {
     /// The contract bytecode.
     /// Is reset by that of EraVM before yielding the compiled project artifacts.
     pub bytecode: Option<Bytecode>,
+    /// The contract deployed bytecode data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployed_bytecode: Option<DeployedBytecode>,
     /// The contract function signatures.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub method_identifiers: Option<BTreeMap<String, String>>,
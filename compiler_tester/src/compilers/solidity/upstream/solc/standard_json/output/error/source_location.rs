@@ -5,11 +5,12 @@
 use std::str::FromStr;
 
 use serde::Deserialize;
+use serde::Serialize;
 
 ///
 /// The `solc --standard-json` output error source location.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceLocation {
     /// The source file path.
@@ -9,6 +9,7 @@ pub mod source;
 use std::collections::BTreeMap;
 
 use serde::Deserialize;
+use serde::Serialize;
 
 use self::contract::Contract;
 use self::error::Error;
@@ -17,7 +18,7 @@ use self::source::Source;
 ///
 /// The `solc --standard-json` output.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Output {
     /// The file-contract hashmap.
     #[serde(default, skip_serializing_if = "Option::is_none")]
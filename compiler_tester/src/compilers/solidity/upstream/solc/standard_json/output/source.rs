@@ -2,12 +2,15 @@
 //! The `solc --standard-json` output source.
 //!
 
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
+use serde::Serialize;
 
 ///
 /// The `solc --standard-json` output source.
 ///
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Source {
     /// The source code ID.
@@ -39,4 +42,86 @@ impl Source {
             .last()
             .ok_or_else(|| anyhow::anyhow!("The last contract not found in the AST"))
     }
+
+    ///
+    /// Returns the names of the immutable variables declared in the source by their AST IDs.
+    ///
+    pub fn immutable_names(&self) -> BTreeMap<String, String> {
+        let mut names = BTreeMap::new();
+        if let Some(ast) = self.ast.as_ref() {
+            Self::collect_immutable_names(ast, &mut names);
+        }
+        names
+    }
+
+    ///
+    /// Collects the names of the immutable variables declared in the AST `node` and its children.
+    ///
+    fn collect_immutable_names(node: &serde_json::Value, names: &mut BTreeMap<String, String>) {
+        match node {
+            serde_json::Value::Object(object) => {
+                if let (Some("VariableDeclaration"), Some("immutable"), Some(id), Some(name)) = (
+                    object.get("nodeType").and_then(|value| value.as_str()),
+                    object.get("mutability").and_then(|value| value.as_str()),
+                    object.get("id").and_then(|value| value.as_u64()),
+                    object.get("name").and_then(|value| value.as_str()),
+                ) {
+                    names.insert(id.to_string(), name.to_owned());
+                }
+                for value in object.values() {
+                    Self::collect_immutable_names(value, names);
+                }
+            }
+            serde_json::Value::Array(array) => {
+                for value in array.iter() {
+                    Self::collect_immutable_names(value, names);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Source;
+
+    #[test]
+    fn immutable_names() {
+        let source: Source = serde_json::from_str(
+            r#"{
+                "id": 0,
+                "ast": {
+                    "nodeType": "SourceUnit",
+                    "nodes": [{
+                        "nodeType": "ContractDefinition",
+                        "name": "Test",
+                        "nodes": [
+                            { "nodeType": "VariableDeclaration", "id": 3, "name": "owner", "mutability": "immutable" },
+                            { "nodeType": "VariableDeclaration", "id": 5, "name": "counter", "mutability": "mutable" },
+                            { "nodeType": "VariableDeclaration", "id": 7, "name": "limit", "mutability": "constant" },
+                            {
+                                "nodeType": "FunctionDefinition",
+                                "id": 9,
+                                "name": "get",
+                                "body": { "nodeType": "Block", "statements": [] }
+                            },
+                            { "nodeType": "VariableDeclaration", "id": 12, "name": "value", "mutability": "immutable" }
+                        ]
+                    }]
+                }
+            }"#,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            source.immutable_names(),
+            BTreeMap::from([
+                ("12".to_owned(), "value".to_owned()),
+                ("3".to_owned(), "owner".to_owned()),
+            ])
+        );
+    }
 }
@@ -0,0 +1,49 @@
+//!
+//! The compiler subprocess statistics.
+//!
+
+pub mod usage;
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::compilers::mode::Mode;
+use crate::summary::report::compilation::Compilation;
+
+use self::usage::Usage;
+
+/// The statistics of compiler subprocesses, keyed by the compiler and mode.
+static STATISTICS: Mutex<BTreeMap<String, BTreeMap<String, Compilation>>> =
+    Mutex::new(BTreeMap::new());
+
+///
+/// The compiler subprocess statistics.
+///
+/// The subprocesses are spawned deep inside the cached compiler calls, so the statistics are
+/// collected globally and taken by the summary of the run, so they are not shared with
+/// the summaries of the later runs in the same process, e.g. a cross-check.
+///
+pub struct Statistics;
+
+impl Statistics {
+    ///
+    /// Records a subprocess of the `compiler` spawned for the `mode`.
+    ///
+    pub fn record(compiler: &str, mode: &Mode, usage: &Usage) {
+        STATISTICS
+            .lock()
+            .expect("Sync")
+            .entry(compiler.to_owned())
+            .or_default()
+            .entry(mode.to_string())
+            .or_default()
+            .record(usage);
+    }
+
+    ///
+    /// Returns the statistics recorded since the last call, and resets them.
+    ///
+    pub fn take() -> BTreeMap<String, BTreeMap<String, Compilation>> {
+        std::mem::take(&mut *STATISTICS.lock().expect("Sync"))
+    }
+}
@@ -0,0 +1,181 @@
+//!
+//! The compiler subprocess resource usage.
+//!
+
+use std::time::Duration;
+use std::time::Instant;
+
+///
+/// The compiler subprocess resource usage.
+///
+#[derive(Debug, Clone)]
+pub struct Usage {
+    /// The wall time.
+    pub wall_time: Duration,
+    /// The peak resident set size in kibibytes, if it could be measured.
+    pub peak_rss: Option<u64>,
+    /// Whether the subprocess has exited successfully.
+    pub is_success: bool,
+}
+
+impl Usage {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(wall_time: Duration, peak_rss: Option<u64>, is_success: bool) -> Self {
+        Self {
+            wall_time,
+            peak_rss,
+            is_success,
+        }
+    }
+
+    ///
+    /// Creates the usage of a subprocess spawned by a dependency, which cannot be reaped here.
+    ///
+    /// The peak resident set size is taken from the maximum of all the reaped children, which is
+    /// only attributable to the subprocess if it has grown since `children_peak_rss_before`,
+    /// as returned by [`Self::children_peak_rss`] before the subprocess was spawned. Otherwise,
+    /// the subprocess has not exceeded the earlier ones, and its peak is left unmeasured.
+    ///
+    pub fn from_children(
+        start: Instant,
+        children_peak_rss_before: Option<u64>,
+        is_success: bool,
+    ) -> Self {
+        let wall_time = start.elapsed();
+        let peak_rss = Self::children_peak_rss()
+            .filter(|peak_rss| children_peak_rss_before.is_none_or(|before| *peak_rss > before));
+        Self::new(wall_time, peak_rss, is_success)
+    }
+
+    ///
+    /// Returns the largest peak resident set size of the reaped children in kibibytes.
+    ///
+    pub fn children_peak_rss() -> Option<u64> {
+        #[cfg(unix)]
+        {
+            // SAFETY: `rusage` is a plain C structure, for which all zeroes is a valid value.
+            let mut rusage: libc::rusage = unsafe { std::mem::zeroed() };
+            // SAFETY: `rusage` is a valid exclusive reference for the duration of the call.
+            if unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, &mut rusage) } != 0 {
+                return None;
+            }
+            Some(Self::max_rss_kib(&rusage))
+        }
+
+        #[cfg(not(unix))]
+        {
+            None
+        }
+    }
+
+    ///
+    /// Closes the stdin of the `child` process, collects its stdout and stderr if they are piped,
+    /// and waits for it to exit.
+    ///
+    /// On Unix, the process is reaped with `wait4` to obtain its peak resident set size.
+    ///
+    pub fn wait_with_output(
+        mut child: std::process::Child,
+        start: Instant,
+    ) -> std::io::Result<(std::process::Output, Self)> {
+        #[cfg(unix)]
+        {
+            use std::io::Read;
+            use std::os::unix::process::ExitStatusExt;
+
+            drop(child.stdin.take());
+            let stderr = child.stderr.take().map(|mut stderr| {
+                std::thread::spawn(move || -> std::io::Result<Vec<u8>> {
+                    let mut buffer = Vec::new();
+                    stderr.read_to_end(&mut buffer)?;
+                    Ok(buffer)
+                })
+            });
+            let mut stdout = Vec::new();
+            if let Some(mut pipe) = child.stdout.take() {
+                pipe.read_to_end(&mut stdout)?;
+            }
+            let stderr = match stderr {
+                Some(thread) => thread.join().expect("Sync")?,
+                None => Vec::new(),
+            };
+
+            let mut status = 0;
+            let mut rusage: libc::rusage = unsafe { std::mem::zeroed() };
+            loop {
+                let result =
+                    unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, 0, &mut rusage) };
+                if result >= 0 {
+                    break;
+                }
+                let error = std::io::Error::last_os_error();
+                if error.kind() != std::io::ErrorKind::Interrupted {
+                    return Err(error);
+                }
+            }
+            let wall_time = start.elapsed();
+
+            let peak_rss = Self::max_rss_kib(&rusage);
+
+            let status = std::process::ExitStatus::from_raw(status);
+            let usage = Self::new(wall_time, Some(peak_rss), status.success());
+            Ok((
+                std::process::Output {
+                    status,
+                    stdout,
+                    stderr,
+                },
+                usage,
+            ))
+        }
+
+        #[cfg(not(unix))]
+        {
+            let output = child.wait_with_output()?;
+            let usage = Self::new(start.elapsed(), None, output.status.success());
+            Ok((output, usage))
+        }
+    }
+
+    ///
+    /// Returns the maximum resident set size of `rusage` in kibibytes.
+    ///
+    #[cfg(unix)]
+    fn max_rss_kib(rusage: &libc::rusage) -> u64 {
+        // The maximum resident set size is reported in bytes on macOS, and in kibibytes elsewhere.
+        if cfg!(target_os = "macos") {
+            rusage.ru_maxrss as u64 / 1024
+        } else {
+            rusage.ru_maxrss as u64
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::time::Instant;
+
+    use super::Usage;
+
+    #[test]
+    fn from_children() {
+        let start = Instant::now();
+        let (_output, usage) = Usage::wait_with_output(
+            std::process::Command::new("true")
+                .spawn()
+                .expect("Always valid"),
+            start,
+        )
+        .expect("Always valid");
+        let reaped = usage.peak_rss.expect("Always exists");
+        assert!(Usage::children_peak_rss().expect("Always exists") >= reaped);
+
+        for (before, is_measured) in [(None, true), (Some(0), true), (Some(u64::MAX), false)] {
+            let usage = Usage::from_children(start, before, true);
+            assert_eq!(usage.peak_rss.is_some(), is_measured, "{before:?}");
+            assert!(usage.is_success);
+        }
+    }
+}
@@ -6,20 +6,32 @@ pub mod cache_key;
 pub mod mode;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Instant;
 
 use itertools::Itertools;
 
 use crate::compilers::cache::Cache;
+use crate::compilers::invocation_log::InvocationLog;
 use crate::compilers::mode::Mode;
+use crate::compilers::statistics::usage::Usage;
+use crate::compilers::statistics::Statistics;
 use crate::compilers::Compiler;
 use crate::vm::eravm::input::Input as EraVMInput;
 
 use self::cache_key::CacheKey;
 use self::mode::Mode as VyperMode;
 
+/// The Vyper interface file extension.
+pub const EXTENSION_VYPER_INTERFACE: &str = "vyi";
+
+/// The `vyper` versions the modes are restricted to, if selected.
+static VERSIONS: once_cell::sync::OnceCell<Vec<semver::Version>> = once_cell::sync::OnceCell::new();
+
 ///
 /// The Vyper compiler.
 ///
@@ -33,11 +45,15 @@ lazy_static::lazy_static! {
     /// All supported modes.
     ///
     static ref MODES: Vec<Mode> = {
-        let vyper_versions = VyperCompiler::all_versions().expect("`vyper` versions analysis error");
+        let vyper_versions = match VERSIONS.get() {
+            Some(versions) => versions.to_owned(),
+            None => VyperCompiler::all_versions().expect("`vyper` versions analysis error"),
+        };
 
         era_compiler_llvm_context::OptimizerSettings::combinations()
             .into_iter()
             .cartesian_product(vyper_versions)
+            // TODO: add `codesize` once `era_compiler_vyper::VyperCompiler::batch` accepts it
             .cartesian_product(vec![false, true])
             .map(
                 |((llvm_optimizer_settings, vyper_version), vyper_optimize)| {
@@ -67,13 +83,150 @@ impl VyperCompiler {
         }
     }
 
+    ///
+    /// Restricts the modes to the `vyper` `versions`.
+    ///
+    /// Must be called before the modes are enumerated.
+    ///
+    pub fn select_versions(versions: Vec<semver::Version>) -> anyhow::Result<()> {
+        VERSIONS
+            .set(versions)
+            .map_err(|_| anyhow::anyhow!("The `vyper` versions are already selected"))
+    }
+
+    ///
+    /// Checks the downloaded `vyper` executables, and returns the diagnostics of the missing ones.
+    ///
+    /// Every selected version must have a valid executable, otherwise an error is returned.
+    /// If no versions are selected, the versions enabled in the download configurations at
+    /// `config_paths` are expected, and the missing ones are only reported.
+    ///
+    pub fn check_versions(config_paths: &[PathBuf]) -> anyhow::Result<Vec<String>> {
+        let downloaded = Self::all_versions()?;
+
+        if let Some(selected) = VERSIONS.get() {
+            Self::check_selected_versions(selected.as_slice(), downloaded.as_slice())?;
+            for version in selected.iter() {
+                Self::executable(version).map_err(|error| {
+                    anyhow::anyhow!("The `vyper` {version} executable is invalid: {error}")
+                })?;
+            }
+            return Ok(vec![]);
+        }
+
+        let configured = Self::configured_versions(config_paths)?;
+        Ok(Self::diagnostics(
+            configured.as_slice(),
+            downloaded.as_slice(),
+        ))
+    }
+
+    ///
+    /// Checks that every `selected` version is `downloaded`.
+    ///
+    fn check_selected_versions(
+        selected: &[semver::Version],
+        downloaded: &[semver::Version],
+    ) -> anyhow::Result<()> {
+        let missing = selected
+            .iter()
+            .filter(|version| !downloaded.contains(version))
+            .map(|version| version.to_string())
+            .collect::<Vec<String>>();
+        if !missing.is_empty() {
+            anyhow::bail!(
+                "The selected `vyper` versions have no executables in `{}`: {}. Enable them in the `vyper` download configuration.",
+                Self::DIRECTORY,
+                missing.join(", "),
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the diagnostics of the `configured` versions missing in the `downloaded` ones,
+    /// and of the lack of executables.
+    ///
+    fn diagnostics(configured: &[semver::Version], downloaded: &[semver::Version]) -> Vec<String> {
+        let mut diagnostics = Vec::new();
+        let missing = configured
+            .iter()
+            .filter(|version| !downloaded.contains(version))
+            .map(|version| version.to_string())
+            .collect::<Vec<String>>();
+        if !missing.is_empty() {
+            diagnostics.push(format!(
+                "The `vyper` versions enabled in the download configuration have no executables in `{}`: {}",
+                Self::DIRECTORY,
+                missing.join(", "),
+            ));
+        }
+        if downloaded.is_empty() {
+            diagnostics.push(format!(
+                "No `vyper` executables found in `{}`, so the Vyper tests have no modes",
+                Self::DIRECTORY,
+            ));
+        }
+        diagnostics
+    }
+
+    ///
+    /// Returns the `vyper` versions enabled in the download configurations at `config_paths`.
+    ///
+    /// Only the executables downloaded to `DIRECTORY` are considered, so the configurations of
+    /// other compilers are skipped.
+    ///
+    fn configured_versions(config_paths: &[PathBuf]) -> anyhow::Result<Vec<semver::Version>> {
+        let mut versions = Vec::new();
+        for path in config_paths.iter() {
+            let text = std::fs::read_to_string(path.as_path())
+                .map_err(|error| anyhow::anyhow!("Download config {path:?} reading: {error}"))?;
+            let config: serde_json::Value = serde_json::from_str(text.as_str())
+                .map_err(|error| anyhow::anyhow!("Download config {path:?} parsing: {error}"))?;
+            let binaries = match config
+                .get("binaries")
+                .and_then(serde_json::Value::as_object)
+            {
+                Some(binaries) => binaries,
+                None => continue,
+            };
+            for (version, binary) in binaries.iter() {
+                let is_enabled = binary
+                    .get("is_enabled")
+                    .and_then(serde_json::Value::as_bool)
+                    .unwrap_or_default();
+                let is_vyper = binary
+                    .get("destination")
+                    .and_then(serde_json::Value::as_str)
+                    .map(|destination| {
+                        destination
+                            .trim_start_matches("./")
+                            .starts_with(Self::DIRECTORY)
+                    })
+                    .unwrap_or_default();
+                if !is_enabled || !is_vyper {
+                    continue;
+                }
+                let version = semver::Version::parse(version).map_err(|error| {
+                    anyhow::anyhow!("Download config {path:?} version `{version}` parsing: {error}")
+                })?;
+                versions.push(version);
+            }
+        }
+        versions.sort();
+        versions.dedup();
+        Ok(versions)
+    }
+
     ///
     /// Returns the Vyper executable by its version.
     ///
     fn executable(version: &semver::Version) -> anyhow::Result<era_compiler_vyper::VyperCompiler> {
-        era_compiler_vyper::VyperCompiler::new(
-            format!("{}/vyper-{version}", Self::DIRECTORY).as_str(),
-        )
+        let path = InvocationLog::proxy(PathBuf::from(format!(
+            "{}/vyper-{version}",
+            Self::DIRECTORY
+        )))?;
+        era_compiler_vyper::VyperCompiler::new(path.to_string_lossy().as_ref())
     }
 
     ///
@@ -81,6 +234,9 @@ impl VyperCompiler {
     ///
     fn all_versions() -> anyhow::Result<Vec<semver::Version>> {
         let mut versions = Vec::new();
+        if !Path::new(Self::DIRECTORY).exists() {
+            return Ok(versions);
+        }
         for entry in std::fs::read_dir(Self::DIRECTORY)? {
             let entry = entry?;
             let path = entry.path();
@@ -102,6 +258,7 @@ impl VyperCompiler {
             };
             versions.push(version);
         }
+        versions.sort();
         Ok(versions)
     }
 
@@ -114,13 +271,7 @@ impl VyperCompiler {
     ) -> anyhow::Result<era_compiler_vyper::Project> {
         let vyper = Self::executable(&mode.vyper_version)?;
 
-        let paths = sources
-            .into_iter()
-            .map(|(path, _)| {
-                PathBuf::from_str(path.as_str())
-                    .map_err(|error| anyhow::anyhow!("Invalid source code path `{path}`: {error}"))
-            })
-            .collect::<anyhow::Result<Vec<PathBuf>>>()?;
+        let paths = Self::contract_paths(sources.as_slice())?;
 
         let evm_version = if mode.vyper_version >= semver::Version::new(0, 3, 10) {
             Some(era_compiler_common::EVMVersion::Cancun)
@@ -128,7 +279,9 @@ impl VyperCompiler {
             None
         };
 
-        vyper.batch(
+        let children_peak_rss = Usage::children_peak_rss();
+        let start = Instant::now();
+        let project = vyper.batch(
             &mode.vyper_version,
             paths,
             &[
@@ -138,7 +291,38 @@ impl VyperCompiler {
             evm_version,
             true,
             mode.vyper_optimize,
-        )
+        );
+        Statistics::record(
+            "vyper",
+            &mode.to_owned().into(),
+            &Usage::from_children(start, children_peak_rss, project.is_ok()),
+        );
+        project
+    }
+
+    ///
+    /// Returns the paths of the sources to compile.
+    ///
+    /// Interfaces are skipped, as `vyper` resolves them from the imports of the contracts.
+    ///
+    fn contract_paths(sources: &[(String, String)]) -> anyhow::Result<Vec<PathBuf>> {
+        sources
+            .iter()
+            .filter(|(path, _)| !Self::is_interface(path.as_str()))
+            .map(|(path, _)| {
+                PathBuf::from_str(path.as_str())
+                    .map_err(|error| anyhow::anyhow!("Invalid source code path `{path}`: {error}"))
+            })
+            .collect()
+    }
+
+    ///
+    /// Whether the source at `path` is a Vyper interface.
+    ///
+    fn is_interface(path: &str) -> bool {
+        Path::new(path)
+            .extension()
+            .is_some_and(|extension| extension == EXTENSION_VYPER_INTERFACE)
     }
 
     ///
@@ -196,6 +380,7 @@ impl Compiler for VyperCompiler {
         test_path: String,
         sources: Vec<(String, String)>,
         _libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         mode: &Mode,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
@@ -203,8 +388,10 @@ impl Compiler for VyperCompiler {
         let mode = VyperMode::unwrap(mode);
 
         let last_contract = sources
-            .last()
-            .ok_or_else(|| anyhow::anyhow!("The Vyper sources are empty"))?
+            .iter()
+            .rev()
+            .find(|(path, _)| !Self::is_interface(path.as_str()))
+            .ok_or_else(|| anyhow::anyhow!("The Vyper sources contain no contracts"))?
             .0
             .clone();
 
@@ -251,6 +438,7 @@ impl Compiler for VyperCompiler {
         Ok(EraVMInput::new(
             builds,
             Some(method_identifiers),
+            None,
             last_contract,
         ))
     }
@@ -260,6 +448,7 @@ impl Compiler for VyperCompiler {
         _test_path: String,
         _sources: Vec<(String, String)>,
         _libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         _mode: &Mode,
         _test_params: Option<&solidity_adapter::Params>,
         _llvm_options: Vec<String>,
@@ -276,3 +465,141 @@ impl Compiler for VyperCompiler {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::VyperCompiler;
+
+    #[test]
+    fn contract_paths() {
+        let sources = vec![
+            ("tests/vyper/complex/a.vy".to_owned(), String::new()),
+            (
+                "tests/vyper/complex/interfaces/IToken.vyi".to_owned(),
+                String::new(),
+            ),
+            ("tests/vyper/complex/b.vy".to_owned(), String::new()),
+        ];
+        assert_eq!(
+            VyperCompiler::contract_paths(sources.as_slice()).expect("Always valid"),
+            vec![
+                PathBuf::from("tests/vyper/complex/a.vy"),
+                PathBuf::from("tests/vyper/complex/b.vy"),
+            ]
+        );
+    }
+
+    #[test]
+    fn configured_versions() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-vyper-versions-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let vyper_config_path = directory.join("vyper-bin.json");
+        std::fs::write(
+            vyper_config_path.as_path(),
+            r#"{
+                "binaries": {
+                    "0.4.0": { "is_enabled": true, "destination": "./vyper-bin/vyper-${VERSION}" },
+                    "0.3.10": { "is_enabled": true, "destination": "vyper-bin/vyper-${VERSION}" },
+                    "0.3.9": { "is_enabled": false, "destination": "./vyper-bin/vyper-${VERSION}" }
+                }
+            }"#,
+        )
+        .expect("Always valid");
+        let solc_config_path = directory.join("solc-bin.json");
+        std::fs::write(
+            solc_config_path.as_path(),
+            r#"{
+                "binaries": {
+                    "0.8.30": { "is_enabled": true, "destination": "./solc-bin/solc-${VERSION}" }
+                }
+            }"#,
+        )
+        .expect("Always valid");
+
+        let versions =
+            VyperCompiler::configured_versions(&[solc_config_path, vyper_config_path.clone()]);
+        let duplicated =
+            VyperCompiler::configured_versions(&[vyper_config_path.clone(), vyper_config_path]);
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+
+        let expected = vec![
+            semver::Version::new(0, 3, 10),
+            semver::Version::new(0, 4, 0),
+        ];
+        assert_eq!(versions.expect("Always valid"), expected);
+        assert_eq!(duplicated.expect("Always valid"), expected);
+    }
+
+    #[test]
+    fn check_selected_versions() {
+        let downloaded = [
+            semver::Version::new(0, 3, 9),
+            semver::Version::new(0, 3, 10),
+        ];
+        for (selected, error) in [
+            (vec![semver::Version::new(0, 3, 10)], None),
+            (vec![], None),
+            (
+                vec![
+                    semver::Version::new(0, 3, 10),
+                    semver::Version::new(0, 4, 0),
+                    semver::Version::new(0, 4, 1),
+                ],
+                Some("The selected `vyper` versions have no executables in `vyper-bin/`: 0.4.0, 0.4.1. Enable them in the `vyper` download configuration."),
+            ),
+        ] {
+            let result = VyperCompiler::check_selected_versions(selected.as_slice(), &downloaded);
+            assert_eq!(
+                result.err().map(|error| error.to_string()).as_deref(),
+                error,
+                "{selected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn diagnostics() {
+        let configured = [
+            semver::Version::new(0, 3, 9),
+            semver::Version::new(0, 3, 10),
+        ];
+        for (downloaded, expected) in [
+            (configured.to_vec(), vec![]),
+            (
+                vec![semver::Version::new(0, 3, 10), semver::Version::new(0, 4, 0)],
+                vec!["The `vyper` versions enabled in the download configuration have no executables in `vyper-bin/`: 0.3.9"],
+            ),
+            (
+                vec![],
+                vec![
+                    "The `vyper` versions enabled in the download configuration have no executables in `vyper-bin/`: 0.3.9, 0.3.10",
+                    "No `vyper` executables found in `vyper-bin/`, so the Vyper tests have no modes",
+                ],
+            ),
+        ] {
+            assert_eq!(
+                VyperCompiler::diagnostics(&configured, downloaded.as_slice()),
+                expected,
+                "{downloaded:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_interface() {
+        for (path, expected) in [
+            ("IToken.vyi", true),
+            ("interfaces/IToken.vyi", true),
+            ("Token.vy", false),
+            ("Token.sol", false),
+            ("vyi", false),
+        ] {
+            assert_eq!(VyperCompiler::is_interface(path), expected, "{path}");
+        }
+    }
+}
@@ -13,7 +13,7 @@ use crate::compilers::mode::Mode as ModeWrapper;
 pub struct Mode {
     /// The Vyper compiler version.
     pub vyper_version: semver::Version,
-    /// Whether to run the Vyper compiler optimizer.
+    /// Whether to run the Vyper compiler optimizer, which optimizes for gas by default.
     pub vyper_optimize: bool,
     /// The optimizer settings.
     pub llvm_optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
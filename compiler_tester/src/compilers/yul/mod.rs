@@ -5,16 +5,15 @@
 pub mod mode;
 pub mod mode_upstream;
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 
 use era_solc::CollectableError;
 
+use crate::compilers::cache::Cache;
 use crate::compilers::mode::Mode;
-use crate::compilers::solidity::upstream::solc::standard_json::input::language::Language as SolcStandardJsonInputLanguage;
-use crate::compilers::solidity::upstream::SolidityCompiler as SolidityUpstreamCompiler;
 use crate::compilers::solidity::SolidityCompiler;
 use crate::compilers::Compiler;
-use crate::toolchain::Toolchain;
 use crate::vm::eravm::input::Input as EraVMInput;
 use crate::vm::evm::input::build::Build as EVMBuild;
 use crate::vm::evm::input::Input as EVMInput;
@@ -25,8 +24,8 @@ use self::mode::Mode as YulMode;
 /// The Yul compiler.
 ///
 pub struct YulCompiler {
-    /// The compiler toolchain to use.
-    toolchain: Toolchain,
+    /// The EVM projects parsed from the Yul object trees, by the test paths.
+    cache: Cache<String, era_compiler_solidity::Project>,
 }
 
 lazy_static::lazy_static! {
@@ -41,12 +40,60 @@ lazy_static::lazy_static! {
     };
 }
 
+impl Default for YulCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl YulCompiler {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(toolchain: Toolchain) -> Self {
-        Self { toolchain }
+    pub fn new() -> Self {
+        Self {
+            cache: Cache::new(),
+        }
+    }
+
+    ///
+    /// Parses the EVM project from the Yul object trees or loads it from the cache.
+    ///
+    /// The project does not depend on the mode, so it is parsed once per test and shared by
+    /// all the LLVM optimizer settings.
+    ///
+    fn evm_project_cached(
+        &self,
+        test_path: String,
+        sources: Vec<(String, String)>,
+        libraries: era_solc::StandardJsonInputLibraries,
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<era_compiler_solidity::Project> {
+        if !self.cache.contains(&test_path) {
+            self.cache.evaluate(test_path.clone(), || {
+                let solc_version = era_solc::Version::new(
+                    era_solc::Compiler::LAST_SUPPORTED_VERSION.to_string(),
+                    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+                    SolidityCompiler::LAST_ZKSYNC_SOLC_REVISION,
+                );
+                let sources = sources
+                    .into_iter()
+                    .map(|(path, source)| (path, era_solc::StandardJsonInputSource::from(source)))
+                    .collect();
+
+                // The whole object tree is parsed, so the `datacopy`, `dataoffset`, and `datasize`
+                // references to nested objects are resolved, and the outermost object is deployed.
+                era_compiler_solidity::Project::try_from_yul_sources(
+                    sources,
+                    libraries,
+                    None,
+                    Some(&solc_version),
+                    debug_config,
+                )
+            });
+        }
+
+        self.cache.get_cloned(&test_path)
     }
 }
 
@@ -56,6 +103,7 @@ impl Compiler for YulCompiler {
         _test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         mode: &Mode,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
@@ -120,7 +168,7 @@ impl Compiler for YulCompiler {
             })
             .collect::<anyhow::Result<HashMap<String, era_compiler_llvm_context::EraVMBuild>>>()?;
 
-        Ok(EraVMInput::new(builds, None, last_contract))
+        Ok(EraVMInput::new(builds, None, None, last_contract))
     }
 
     fn compile_for_evm(
@@ -128,39 +176,13 @@ impl Compiler for YulCompiler {
         test_path: String,
         sources: Vec<(String, String)>,
         libraries: era_solc::StandardJsonInputLibraries,
+        _remappings: BTreeSet<String>,
         mode: &Mode,
-        test_params: Option<&solidity_adapter::Params>,
-        _llvm_options: Vec<String>,
-        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        _test_params: Option<&solidity_adapter::Params>,
+        llvm_options: Vec<String>,
+        debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EVMInput> {
-        let language = SolcStandardJsonInputLanguage::Yul;
-
-        let solc_compiler = SolidityUpstreamCompiler::new(language, self.toolchain);
-
-        let solc_output = solc_compiler.standard_json_output_cached(
-            test_path,
-            language,
-            &sources,
-            &libraries,
-            mode,
-            test_params,
-        )?;
-
-        if let Some(errors) = solc_output.errors.as_deref() {
-            let mut has_errors = false;
-            let mut error_messages = Vec::with_capacity(errors.len());
-
-            for error in errors.iter() {
-                if error.severity.as_str() == "error" {
-                    has_errors = true;
-                    error_messages.push(error.formatted_message.to_owned());
-                }
-            }
-
-            if has_errors {
-                anyhow::bail!("`solc` errors found: {:?}", error_messages);
-            }
-        }
+        let mode = YulMode::unwrap(mode);
 
         let last_contract = sources
             .last()
@@ -168,34 +190,29 @@ impl Compiler for YulCompiler {
             .0
             .clone();
 
-        let contracts = solc_output
-            .contracts
-            .ok_or_else(|| anyhow::anyhow!("Solidity contracts not found in the output"))?;
-
-        let mut builds = HashMap::with_capacity(contracts.len());
-        for (file, contracts) in contracts.into_iter() {
-            for (name, contract) in contracts.into_iter() {
-                let path = format!("{file}:{name}");
-                let bytecode_string = contract
-                    .evm
-                    .as_ref()
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("EVM object of the contract `{path}` not found")
-                    })?
-                    .bytecode
-                    .as_ref()
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("EVM bytecode of the contract `{path}` not found")
-                    })?
-                    .object
-                    .as_str();
-                let build =
-                    EVMBuild::new(hex::decode(bytecode_string).expect("Always valid"), vec![]);
-                builds.insert(path, build);
-            }
-        }
+        let project =
+            self.evm_project_cached(test_path, sources, libraries, debug_config.as_ref())?;
+
+        let build = project.compile_to_evm(
+            &mut vec![],
+            mode.llvm_optimizer_settings.to_owned(),
+            llvm_options,
+            era_compiler_common::HashType::Ipfs,
+            None,
+            debug_config,
+        )?;
+        build.collect_errors()?;
+        let builds: HashMap<String, EVMBuild> = build
+            .results
+            .into_iter()
+            .map(|(path, result)| {
+                let contract = result.expect("Always valid");
+                let build = EVMBuild::new(contract.deploy_build, contract.runtime_build);
+                (path, build)
+            })
+            .collect();
 
-        Ok(EVMInput::new(builds, None, last_contract))
+        Ok(EVMInput::new(builds, None, None, last_contract))
     }
 
     fn all_modes(&self) -> Vec<Mode> {
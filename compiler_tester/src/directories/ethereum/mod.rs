@@ -12,6 +12,7 @@ use std::sync::Mutex;
 use crate::directories::Collection;
 use crate::filters::Filters;
 use crate::summary::Summary;
+use crate::test::selector::registry::Registry as SelectorRegistry;
 
 use self::test::EthereumTest;
 
@@ -28,6 +29,13 @@ impl EthereumDirectory {
     ///
     const INDEX_NAME_UPSTREAM: &'static str = "ethereum.yaml";
 
+    ///
+    /// The environment variable overriding the upstream index file path, e.g. with the index
+    /// being updated by the tests updater.
+    ///
+    pub const INDEX_PATH_UPSTREAM_ENVIRONMENT_VARIABLE: &'static str =
+        "ERA_COMPILER_TESTER_ETHEREUM_INDEX";
+
     ///
     /// The ZKsync index file name.
     ///
@@ -54,6 +62,7 @@ impl Collection for EthereumDirectory {
         _extension: &'static str,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
+        selectors: &SelectorRegistry,
     ) -> anyhow::Result<Vec<Self::Test>> {
         let index_path = match target {
             era_compiler_common::Target::EraVM => {
@@ -61,13 +70,18 @@ impl Collection for EthereumDirectory {
                 index_path.push(Self::INDEX_NAME_ZKSYNC);
                 index_path
             }
-            era_compiler_common::Target::EVM => PathBuf::from(Self::INDEX_NAME_UPSTREAM),
+            era_compiler_common::Target::EVM => {
+                match std::env::var_os(Self::INDEX_PATH_UPSTREAM_ENVIRONMENT_VARIABLE) {
+                    Some(path) => PathBuf::from(path),
+                    None => PathBuf::from(Self::INDEX_NAME_UPSTREAM),
+                }
+            }
         };
 
         Ok(Self::read_index(index_path.as_path())?
             .into_enabled_list(directory_path)
             .into_iter()
-            .filter_map(|test| EthereumTest::new(test, summary.clone(), filters))
+            .filter_map(|test| EthereumTest::new(test, summary.clone(), filters, selectors))
             .collect())
     }
 }
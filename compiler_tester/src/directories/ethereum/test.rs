@@ -3,23 +3,31 @@
 //!
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::compilers::mode::llvm_options::LLVMOptions;
 use crate::compilers::mode::Mode;
 use crate::compilers::Compiler;
 use crate::directories::Buildable;
+use crate::docs::entry::Entry as DocsEntry;
+use crate::docs::Documented;
 use crate::environment::Environment;
 use crate::filters::Filters;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::report::pass_rates::feature_pass_rates::FeaturePassRates;
 use crate::summary::Summary;
 use crate::test::case::Case;
 use crate::test::description::TestDescription;
+use crate::test::selector::registry::Registry as SelectorRegistry;
 use crate::test::selector::TestSelector;
 use crate::test::Test;
 use crate::vm::address_iterator::AddressIterator;
 use crate::vm::eravm::address_iterator::EraVMAddressIterator;
 use crate::vm::evm::address_iterator::EVMAddressIterator;
+use crate::vm::revm::Revm;
 
 ///
 /// The Ethereum compiler test.
@@ -35,13 +43,23 @@ pub struct EthereumTest {
 }
 
 impl EthereumTest {
+    /// The tag of the tests with several sources, added to their index tags.
+    pub const MULTI_SOURCE_TAG: &'static str = "multi-source";
+
+    /// The tag of the tests with imports, added to their index tags.
+    pub const IMPORTS_TAG: &'static str = "imports";
+
     ///
     /// Try to create new test.
     ///
+    /// The tags are checked before the test is read with and without the tags depending on its
+    /// sources, and once again after it is read.
+    ///
     pub fn new(
         index_entity: solidity_adapter::EnabledTest,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
+        selectors: &SelectorRegistry,
     ) -> Option<Self> {
         let path = index_entity.path.to_string_lossy().to_string();
 
@@ -53,19 +71,81 @@ impl EthereumTest {
             return None;
         }
 
+        if !filters.may_check_tags(
+            index_entity.tags.as_slice(),
+            &[Self::MULTI_SOURCE_TAG, Self::IMPORTS_TAG],
+        ) {
+            return None;
+        }
+
         let selector = TestSelector {
             path,
             case: None,
             input: None,
         };
+        if let Err(error) = selectors.register(&selector) {
+            Summary::invalid(
+                summary,
+                TestDescription::default_for(selector),
+                ErrorCode::InvalidMetadata,
+                error,
+            );
+            return None;
+        }
+        if let Err(error) = FeaturePassRates::validate(index_entity.features.as_slice()) {
+            Summary::invalid(
+                summary,
+                TestDescription::default_for(selector),
+                ErrorCode::InvalidMetadata,
+                error,
+            );
+            return None;
+        }
         let test = match solidity_adapter::Test::try_from(index_entity.path.as_path()) {
             Ok(test) => test,
             Err(error) => {
-                Summary::invalid(summary, TestDescription::default_for(selector), error);
+                Summary::invalid(
+                    summary,
+                    TestDescription::default_for(selector),
+                    ErrorCode::InvalidMetadata,
+                    error,
+                );
                 return None;
             }
         };
 
+        if let Some(spec) = Revm::selected_spec() {
+            if !test.params.evm_version.matches(&spec) {
+                Summary::skipped(
+                    summary,
+                    TestDescription::default_for(selector),
+                    format!(
+                        "EVM version `{}` excludes the REVM spec `{spec}`",
+                        test.params.evm_version
+                    ),
+                );
+                return None;
+            }
+        }
+
+        let mut index_entity = index_entity;
+        if test.sources.len() > 1 {
+            index_entity.tags.push(Self::MULTI_SOURCE_TAG.to_owned());
+        }
+        if test.has_imports {
+            index_entity.tags.push(Self::IMPORTS_TAG.to_owned());
+        }
+        if !filters.check_tags(index_entity.tags.as_slice()) {
+            return None;
+        }
+
+        Summary::tagged(summary.clone(), index_entity.tags.as_slice());
+        Summary::featured(
+            summary,
+            selector.path.as_str(),
+            index_entity.features.as_slice(),
+        );
+
         Some(Self {
             selector,
             index_entity,
@@ -120,6 +200,38 @@ impl EthereumTest {
         calls.insert(constructor_insert_index, constructor);
     }
 
+    ///
+    /// Sets the sources of the libraries deployed without them.
+    ///
+    /// As in `isoltest`, such a library is looked up in all the sources, so it may be defined
+    /// in a source other than the last one. If it is not found, the last source is used.
+    ///
+    fn resolve_library_sources(
+        &self,
+        calls: &mut [solidity_adapter::FunctionCall],
+        last_source: &str,
+    ) {
+        for call in calls.iter_mut() {
+            if let solidity_adapter::FunctionCall::Library { name, source } = call {
+                if source.is_some() {
+                    continue;
+                }
+                let regex =
+                    regex::Regex::new(format!(r"\blibrary\s+{}\b", regex::escape(name)).as_str())
+                        .expect("Always valid");
+                let library_source = self
+                    .test
+                    .sources
+                    .iter()
+                    .rev()
+                    .find(|(_, code)| regex.is_match(code.as_str()))
+                    .map(|(path, _)| path.as_str())
+                    .unwrap_or(last_source);
+                *source = Some(library_source.to_owned());
+            }
+        }
+    }
+
     ///
     /// Returns all addresses.
     ///
@@ -189,6 +301,7 @@ impl EthereumTest {
                         mode: Some(mode.clone()),
                         selector: self.selector.clone(),
                     },
+                    ErrorCode::InvalidMetadata,
                     anyhow::anyhow!("The Ethereum test `{}` sources are empty", &self.selector),
                 );
                 None
@@ -198,6 +311,10 @@ impl EthereumTest {
 }
 
 impl Buildable for EthereumTest {
+    fn selector(&self) -> &TestSelector {
+        &self.selector
+    }
+
     fn build_for_eravm(
         &self,
         mode: Mode,
@@ -213,6 +330,7 @@ impl Buildable for EthereumTest {
         self.insert_deploy_calls(&mut calls);
 
         let last_source = self.last_source(summary.clone(), &mode)?;
+        self.resolve_library_sources(&mut calls, last_source.as_str());
 
         let test_description = TestDescription {
             group: None,
@@ -229,30 +347,59 @@ impl Buildable for EthereumTest {
                 (contract_address, libraries_addresses, libraries)
             }
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
 
         let evm_version = self.test.params.evm_version;
+        let debug_config = match crate::utils::debug_config_for_test(
+            debug_config.as_ref(),
+            self.selector.path.as_str(),
+        ) {
+            Ok(debug_config) => debug_config,
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        };
         let eravm_input = match compiler
             .compile_for_eravm(
                 self.selector.to_string(),
                 self.test.sources.clone(),
                 libraries,
+                BTreeSet::new(),
                 &mode,
-                vec![],
-                debug_config,
+                LLVMOptions::get().extra(),
+                debug_config.clone(),
             )
             .map_err(|error| anyhow::anyhow!("Failed to compile sources:\n{error}"))
         {
             Ok(output) => output,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(
+                    summary,
+                    test_description,
+                    ErrorCode::CompilationFailed,
+                    error,
+                );
                 return None;
             }
         };
 
+        if let Some(debug_config) = debug_config.as_ref() {
+            if let Err(error) = eravm_input.write_debug_artifacts(debug_config) {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        }
+        Summary::record_interfaces(
+            summary.clone(),
+            self.selector.path.as_str(),
+            eravm_input.method_identifiers.as_ref(),
+            eravm_input.abis.as_ref(),
+        );
+
         let instances = match eravm_input.get_instances(
             &BTreeMap::new(),
             libraries_addresses,
@@ -260,7 +407,7 @@ impl Buildable for EthereumTest {
         ) {
             Ok(instance) => instance,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
@@ -269,15 +416,22 @@ impl Buildable for EthereumTest {
             &calls,
             instances,
             last_source.as_str(),
+            self.test.params.events_unordered == solidity_adapter::EventsUnordered::True,
             era_compiler_common::Target::EraVM,
         ) {
             Ok(case) => case,
             Err(error) => {
-                Summary::invalid(summary.clone(), test_description, error);
+                Summary::invalid(
+                    summary.clone(),
+                    test_description,
+                    ErrorCode::InvalidMetadata,
+                    error,
+                );
                 return None;
             }
         };
 
+        let function_sizes = eravm_input.function_sizes();
         let builds = eravm_input
             .builds
             .into_values()
@@ -297,8 +451,10 @@ impl Buildable for EthereumTest {
             mode,
             self.index_entity.group.clone(),
             builds,
+            function_sizes,
             HashMap::new(),
             Some(evm_version),
+            true,
         ))
     }
 
@@ -322,6 +478,7 @@ impl Buildable for EthereumTest {
             selector: self.selector.clone(),
         };
         let last_source = self.last_source(summary.clone(), &mode)?;
+        self.resolve_library_sources(&mut calls, last_source.as_str());
 
         let (contract_address, libraries_addresses, libraries) = match self.get_addresses(
             EVMAddressIterator::default(),
@@ -332,31 +489,60 @@ impl Buildable for EthereumTest {
                 (contract_address, libraries_addresses, libraries)
             }
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
 
         let evm_version = self.test.params.evm_version;
+        let debug_config = match crate::utils::debug_config_for_test(
+            debug_config.as_ref(),
+            self.selector.path.as_str(),
+        ) {
+            Ok(debug_config) => debug_config,
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        };
         let evm_input = match compiler
             .compile_for_evm(
                 self.selector.to_string(),
                 self.test.sources.clone(),
                 libraries,
+                BTreeSet::new(),
                 &mode,
                 Some(&self.test.params),
-                vec![],
-                debug_config,
+                LLVMOptions::get().extra(),
+                debug_config.clone(),
             )
             .map_err(|error| anyhow::anyhow!("Failed to compile sources:\n{error}"))
         {
             Ok(output) => output,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(
+                    summary,
+                    test_description,
+                    ErrorCode::CompilationFailed,
+                    error,
+                );
                 return None;
             }
         };
 
+        if let Some(debug_config) = debug_config.as_ref() {
+            if let Err(error) = evm_input.write_debug_artifacts(debug_config) {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        }
+        Summary::record_interfaces(
+            summary.clone(),
+            self.selector.path.as_str(),
+            evm_input.method_identifiers.as_ref(),
+            evm_input.abis.as_ref(),
+        );
+
         let instances = match evm_input.get_instances(
             &BTreeMap::new(),
             libraries_addresses,
@@ -364,7 +550,7 @@ impl Buildable for EthereumTest {
         ) {
             Ok(instance) => instance,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
@@ -373,11 +559,17 @@ impl Buildable for EthereumTest {
             &calls,
             instances,
             last_source.as_str(),
+            self.test.params.events_unordered == solidity_adapter::EventsUnordered::True,
             era_compiler_common::Target::EVM,
         ) {
             Ok(case) => case,
             Err(error) => {
-                Summary::invalid(summary.clone(), test_description, error);
+                Summary::invalid(
+                    summary.clone(),
+                    test_description,
+                    ErrorCode::InvalidMetadata,
+                    error,
+                );
                 return None;
             }
         };
@@ -388,8 +580,49 @@ impl Buildable for EthereumTest {
             mode,
             self.index_entity.group.clone(),
             HashMap::new(),
+            HashMap::new(),
             evm_input.builds,
             Some(evm_version),
+            true,
         ))
     }
 }
+
+impl Documented for EthereumTest {
+    fn docs_entry(&self) -> DocsEntry {
+        let comment_prefix = if self
+            .index_entity
+            .path
+            .extension()
+            .is_some_and(|extension| extension == era_compiler_common::EXTENSION_VYPER)
+        {
+            "#"
+        } else {
+            "//"
+        };
+
+        let description = self
+            .test
+            .sources
+            .first()
+            .map(|(_, source)| {
+                source
+                    .lines()
+                    .skip_while(|line| line.trim().is_empty())
+                    .map_while(|line| line.trim().strip_prefix(comment_prefix))
+                    .map(str::trim)
+                    .take_while(|line| !line.starts_with("====") && !line.starts_with("----"))
+                    .filter(|line| !line.is_empty() && !line.starts_with("SPDX-License-Identifier"))
+                    .map(str::to_owned)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        DocsEntry::new(
+            self.selector.path.to_owned(),
+            self.index_entity.group.clone(),
+            description,
+            vec![],
+        )
+    }
+}
@@ -0,0 +1,189 @@
+//!
+//! The Matter Labs compiler tests directory defaults.
+//!
+
+use std::path::Path;
+
+use crate::directories::matter_labs::test::metadata::Metadata;
+
+///
+/// The Matter Labs compiler tests directory defaults.
+///
+/// Applied to all tests beneath the directory, unless the tests specify the values themselves.
+/// The defaults of a nested directory override the ones of its parents.
+///
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Defaults {
+    /// The default test group.
+    pub group: Option<String>,
+    /// The default mode filter.
+    pub modes: Option<Vec<String>>,
+    /// The default target filter.
+    pub targets: Option<Vec<era_compiler_common::Target>>,
+    /// The default caller address of the inputs.
+    pub caller: Option<String>,
+}
+
+impl Defaults {
+    /// The defaults file name.
+    pub const FILE_NAME: &'static str = "defaults.json";
+
+    ///
+    /// Returns the defaults of `directory`, overriding these ones with its defaults file, if any.
+    ///
+    pub fn extend(&self, directory: &Path) -> anyhow::Result<Self> {
+        let path = directory.join(Self::FILE_NAME);
+        if !path.is_file() {
+            return Ok(self.to_owned());
+        }
+
+        let file = std::fs::File::open(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Defaults file {path:?} reading: {error}"))?;
+        let defaults: Self = serde_json::from_reader(std::io::BufReader::new(file))
+            .map_err(|error| anyhow::anyhow!("Defaults file {path:?} parsing: {error}"))?;
+        Ok(Self {
+            group: defaults.group.or_else(|| self.group.clone()),
+            modes: defaults.modes.or_else(|| self.modes.clone()),
+            targets: defaults.targets.or_else(|| self.targets.clone()),
+            caller: defaults.caller.or_else(|| self.caller.clone()),
+        })
+    }
+
+    ///
+    /// Sets the values not specified by the test `metadata`.
+    ///
+    pub fn apply(&self, metadata: &mut Metadata) {
+        if metadata.group.is_none() {
+            metadata.group = self.group.clone();
+        }
+        if metadata.modes.is_none() {
+            metadata.modes = self.modes.clone();
+        }
+        if metadata.targets.is_none() {
+            metadata.targets = self.targets.clone();
+        }
+        if let Some(caller) = self.caller.as_ref() {
+            for case in metadata.cases.iter_mut() {
+                for input in case
+                    .inputs
+                    .iter_mut()
+                    .chain(case.setup.iter_mut())
+                    .chain(case.teardown.iter_mut())
+                {
+                    if input.caller.is_none() {
+                        input.caller = Some(caller.to_owned());
+                    }
+                }
+                if let Some(property) = case.property.as_mut() {
+                    if property.caller.is_none() {
+                        property.caller = Some(caller.to_owned());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::directories::matter_labs::test::metadata::Metadata;
+
+    use super::Defaults;
+
+    #[test]
+    fn extend() {
+        let root = std::env::temp_dir().join(format!(
+            "era-compiler-tester-defaults-{}",
+            std::process::id()
+        ));
+        let nested = root.join("nested");
+        let malformed = root.join("malformed");
+        std::fs::create_dir_all(nested.as_path()).expect("Always valid");
+        std::fs::create_dir_all(malformed.as_path()).expect("Always valid");
+        std::fs::write(
+            root.join(Defaults::FILE_NAME),
+            r#"{ "group": "EVMInterpreter", "modes": ["Y+"], "caller": "01" }"#,
+        )
+        .expect("Always valid");
+        std::fs::write(
+            nested.join(Defaults::FILE_NAME),
+            r#"{ "group": "RealWorld", "targets": ["EVM"] }"#,
+        )
+        .expect("Always valid");
+        std::fs::write(
+            malformed.join(Defaults::FILE_NAME),
+            r#"{ "groups": "RealWorld" }"#,
+        )
+        .expect("Always valid");
+
+        let parent = Defaults::default()
+            .extend(root.as_path())
+            .expect("Always valid");
+        let child = parent.extend(nested.as_path()).expect("Always valid");
+        let unchanged = child
+            .extend(nested.join("missing").as_path())
+            .expect("Always valid");
+        let error = parent.extend(malformed.as_path());
+        std::fs::remove_dir_all(root.as_path()).expect("Always valid");
+
+        assert_eq!(parent.group.as_deref(), Some("EVMInterpreter"));
+        for defaults in [&child, &unchanged] {
+            assert_eq!(defaults.group.as_deref(), Some("RealWorld"));
+            assert_eq!(defaults.modes, Some(vec!["Y+".to_owned()]));
+            assert_eq!(
+                defaults.targets,
+                Some(vec![era_compiler_common::Target::EVM])
+            );
+            assert_eq!(defaults.caller.as_deref(), Some("01"));
+        }
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn apply() {
+        let defaults = Defaults {
+            group: Some("EVMInterpreter".to_owned()),
+            modes: Some(vec!["Y+".to_owned()]),
+            targets: None,
+            caller: Some("01".to_owned()),
+        };
+        let mut metadata = Metadata::from_str(
+            r#"{
+                "group": "RealWorld",
+                "cases": [{
+                    "name": "main",
+                    "inputs": [
+                        { "method": "first", "calldata": [] },
+                        { "method": "second", "calldata": [], "caller": "02" }
+                    ],
+                    "setup": [{ "method": "setup", "calldata": [] }],
+                    "property": { "method": "check(bool)", "arguments": ["bool"] }
+                }]
+            }"#,
+        )
+        .expect("Always valid");
+        defaults.apply(&mut metadata);
+
+        assert_eq!(metadata.group.as_deref(), Some("RealWorld"));
+        assert_eq!(metadata.modes, Some(vec!["Y+".to_owned()]));
+        assert_eq!(metadata.targets, None);
+        let case = &metadata.cases[0];
+        for (input, caller) in [
+            (&case.inputs[0], "01"),
+            (&case.inputs[1], "02"),
+            (&case.setup[0], "01"),
+        ] {
+            assert_eq!(input.caller(), caller, "{}", input.method);
+        }
+        let property = case.property.as_ref().expect("Always exists");
+        let inputs = property
+            .inputs(case.name.as_str(), "Y+")
+            .expect("Always valid");
+        assert!(inputs
+            .iter()
+            .all(|input| input.caller.as_deref() == Some("01")));
+    }
+}
@@ -2,6 +2,7 @@
 //! The Matter Labs compiler tests directory.
 //!
 
+pub mod defaults;
 pub mod test;
 
 use std::fs;
@@ -9,11 +10,18 @@ use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::directories::real_world::RealWorldDirectory;
 use crate::directories::Collection;
 use crate::filters::Filters;
+use crate::summary::element::outcome::error_code::ErrorCode;
 use crate::summary::Summary;
+use crate::test::description::TestDescription;
+use crate::test::selector::registry::Registry as SelectorRegistry;
+use crate::test::selector::TestSelector;
 
+use self::defaults::Defaults;
 use self::test::MatterLabsTest;
+use self::test::VENDORED_LIBRARIES_DIRECTORY;
 
 ///
 /// The Matter Labs compiler tests directory.
@@ -29,7 +37,42 @@ impl Collection for MatterLabsDirectory {
         extension: &'static str,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
+        selectors: &SelectorRegistry,
     ) -> anyhow::Result<Vec<Self::Test>> {
+        Self::read_directory(
+            directory_path,
+            extension,
+            summary,
+            filters,
+            selectors,
+            &Ok(Defaults::default()),
+        )
+    }
+}
+
+impl MatterLabsDirectory {
+    ///
+    /// Reads the tests of the directory and its subdirectories, applying the `defaults`
+    /// of the parent directories overridden by the directory defaults file.
+    ///
+    /// If the defaults file of the directory or of one of its parents is invalid, the tests
+    /// beneath it are reported as invalid, while the rest of the collection proceeds.
+    ///
+    fn read_directory(
+        directory_path: &Path,
+        extension: &'static str,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        selectors: &SelectorRegistry,
+        defaults: &Result<Defaults, String>,
+    ) -> anyhow::Result<Vec<MatterLabsTest>> {
+        let defaults = match defaults {
+            Ok(defaults) => defaults
+                .extend(directory_path)
+                .map_err(|error| error.to_string()),
+            Err(error) => Err(error.to_owned()),
+        };
+
         let mut tests = Vec::new();
 
         for entry in fs::read_dir(directory_path)? {
@@ -44,19 +87,27 @@ impl Collection for MatterLabsDirectory {
             })?;
 
             if entry_type.is_dir() {
-                tests.extend(Self::read_all(
-                    _target,
+                if entry.file_name() == RealWorldDirectory::DIRECTORY_NAME
+                    || entry.file_name() == VENDORED_LIBRARIES_DIRECTORY
+                {
+                    continue;
+                }
+                tests.extend(Self::read_directory(
                     &path,
                     extension,
                     summary.clone(),
                     filters,
+                    selectors,
+                    &defaults,
                 )?);
                 continue;
             } else if !entry_type.is_file() {
                 anyhow::bail!("Invalid type of file `{}`", path.to_string_lossy());
             }
 
-            if entry.file_name().to_string_lossy().starts_with('.') {
+            if entry.file_name().to_string_lossy().starts_with('.')
+                || entry.file_name() == Defaults::FILE_NAME
+            {
                 continue;
             }
 
@@ -70,7 +121,29 @@ impl Collection for MatterLabsDirectory {
                 continue;
             }
 
-            if let Some(test) = MatterLabsTest::new(path, summary.clone(), filters) {
+            let defaults = match defaults.as_ref() {
+                Ok(defaults) => defaults,
+                Err(error) => {
+                    let path = path.to_string_lossy().to_string();
+                    if filters.check_test_path(path.as_str()) {
+                        Summary::invalid(
+                            summary.clone(),
+                            TestDescription::default_for(TestSelector {
+                                path,
+                                case: None,
+                                input: None,
+                            }),
+                            ErrorCode::InvalidMetadata,
+                            error,
+                        );
+                    }
+                    continue;
+                }
+            };
+
+            if let Some(test) =
+                MatterLabsTest::new(path, summary.clone(), filters, selectors, defaults)
+            {
                 tests.push(test);
             }
         }
@@ -0,0 +1,157 @@
+//!
+//! The Matter Labs compiler test built-in helper instance.
+//!
+
+///
+/// The Matter Labs compiler test built-in helper instance.
+///
+/// The helper contracts are shipped with the tester, and are added to the Solidity tests
+/// referencing their reserved instance names, so the re-entrancy and callback scenarios
+/// do not have to vendor their own helper sources.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Helper {
+    /// Re-enters an armed target from its callbacks.
+    ReentrantCaller,
+    /// Drains a target with the classic deposit and withdrawal re-entrancy attack.
+    Attacker,
+    /// Delegates its calls to an implementation kept in the EIP-1967 slot.
+    Proxy,
+}
+
+impl Helper {
+    /// The prefix of the reserved helper instance names.
+    pub const INSTANCE_PREFIX: &'static str = "$";
+
+    /// The virtual directory of the helper sources.
+    pub const SOURCE_DIRECTORY: &'static str = "compiler-tester-helpers";
+
+    /// All the helpers.
+    pub const ALL: [Self; 3] = [Self::ReentrantCaller, Self::Attacker, Self::Proxy];
+
+    ///
+    /// Returns the helper with the reserved instance name, `None` if the name is not reserved.
+    ///
+    pub fn try_from_instance(instance: &str) -> anyhow::Result<Option<Self>> {
+        let name = match instance.strip_prefix(Self::INSTANCE_PREFIX) {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        Self::ALL
+            .into_iter()
+            .find(|helper| helper.contract_name() == name)
+            .map(Some)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Unknown built-in helper instance `{instance}`, expected one of: {}",
+                    Self::ALL
+                        .iter()
+                        .map(|helper| format!("`{}`", helper.instance()))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+    }
+
+    ///
+    /// Returns the reserved instance name.
+    ///
+    pub fn instance(&self) -> String {
+        format!("{}{}", Self::INSTANCE_PREFIX, self.contract_name())
+    }
+
+    ///
+    /// Returns the contract name.
+    ///
+    pub fn contract_name(&self) -> &'static str {
+        match self {
+            Self::ReentrantCaller => "ReentrantCaller",
+            Self::Attacker => "Attacker",
+            Self::Proxy => "Proxy",
+        }
+    }
+
+    ///
+    /// Returns the virtual path of the source file.
+    ///
+    pub fn source_path(&self) -> String {
+        format!(
+            "{}/{}.{}",
+            Self::SOURCE_DIRECTORY,
+            self.contract_name(),
+            era_compiler_common::EXTENSION_SOLIDITY
+        )
+    }
+
+    ///
+    /// Returns the contract path in the `path:Name` notation.
+    ///
+    pub fn contract_path(&self) -> String {
+        format!("{}:{}", self.source_path(), self.contract_name())
+    }
+
+    ///
+    /// Returns the source code.
+    ///
+    pub fn source_code(&self) -> &'static str {
+        match self {
+            Self::ReentrantCaller => include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/helpers/ReentrantCaller.sol"
+            )),
+            Self::Attacker => {
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/helpers/Attacker.sol"))
+            }
+            Self::Proxy => include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/helpers/Proxy.sol")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Helper;
+
+    #[test]
+    fn try_from_instance() {
+        for helper in Helper::ALL.into_iter() {
+            assert_eq!(
+                Helper::try_from_instance(helper.instance().as_str()).expect("Always valid"),
+                Some(helper),
+                "Invalid helper {helper:?}"
+            );
+        }
+        assert_eq!(
+            Helper::try_from_instance("Attacker").expect("Always valid"),
+            None
+        );
+        assert_eq!(
+            Helper::try_from_instance("$Unknown")
+                .expect_err("Always invalid")
+                .to_string(),
+            "Unknown built-in helper instance `$Unknown`, expected one of: `$ReentrantCaller`, `$Attacker`, `$Proxy`"
+        );
+    }
+
+    #[test]
+    fn contract_path() {
+        assert_eq!(
+            Helper::Attacker.contract_path(),
+            "compiler-tester-helpers/Attacker.sol:Attacker"
+        );
+    }
+
+    #[test]
+    fn source_code() {
+        for helper in Helper::ALL.into_iter() {
+            let source_code = helper.source_code();
+            assert!(
+                source_code.contains(format!("contract {} {{", helper.contract_name()).as_str()),
+                "Invalid source code of {helper:?}"
+            );
+            assert!(
+                source_code.contains("pragma solidity >=0.6.2 <0.9.0;"),
+                "Invalid pragma of {helper:?}"
+            );
+        }
+    }
+}
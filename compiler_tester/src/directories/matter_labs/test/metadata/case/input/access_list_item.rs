@@ -0,0 +1,16 @@
+//!
+//! The Matter Labs compiler test metadata case input access list item.
+//!
+
+///
+/// The Matter Labs compiler test metadata case input access list item.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AccessListItem {
+    /// The pre-warmed address, either an instance reference or an address literal.
+    pub address: String,
+    /// The pre-warmed storage keys of the address.
+    #[serde(default)]
+    pub storage_keys: Vec<String>,
+}
@@ -2,8 +2,12 @@
 //! The Matter Labs compiler test metadata case input calldata.
 //!
 
+use std::path::Path;
+
 use serde::Deserialize;
 
+use super::hex_file::HexFile;
+
 ///
 /// The Matter Labs compiler test metadata case input calldata.
 ///
@@ -14,6 +18,28 @@ pub enum Calldata {
     Value(String),
     /// The list of values.
     List(Vec<String>),
+    /// The values ABI-encoded according to their types, including the dynamic offsets.
+    Structured {
+        /// The ABI types, e.g. `uint256[]` or `string`.
+        types: Vec<String>,
+        /// The values, where arrays and tuples are lists.
+        values: Vec<serde_json::Value>,
+    },
+    /// The hexadecimal file, resolved into a single value when the test is read.
+    File(HexFile),
+}
+
+impl Calldata {
+    ///
+    /// Replaces the file reference with its contents, resolving its path against the test
+    /// `directory`.
+    ///
+    pub fn resolve_file(&mut self, directory: &Path) -> anyhow::Result<()> {
+        if let Self::File(file) = self {
+            *self = Self::Value(format!("0x{}", hex::encode(file.read(directory)?)));
+        }
+        Ok(())
+    }
 }
 
 impl Default for Calldata {
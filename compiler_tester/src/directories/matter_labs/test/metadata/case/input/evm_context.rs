@@ -0,0 +1,19 @@
+//!
+//! The Matter Labs compiler test metadata case input EVM interpreter context.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The Matter Labs compiler test metadata case input EVM interpreter context.
+///
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct EVMContext {
+    /// The gas price returned by `GASPRICE`.
+    pub gas_price: Option<String>,
+    /// The transaction origin returned by `ORIGIN`.
+    pub origin: Option<String>,
+    /// The block hashes returned by `BLOCKHASH`, keyed by the decimal block number.
+    #[serde(default)]
+    pub block_hashes: BTreeMap<String, String>,
+}
@@ -4,6 +4,8 @@
 
 pub mod variant;
 
+use std::path::Path;
+
 use serde::Deserialize;
 
 use crate::compilers::mode::Mode;
@@ -38,11 +40,32 @@ impl Expected {
         Self::Single(Variant::Extended(Extended {
             return_data: vec![],
             events: vec![],
+            events_unordered: false,
             exception,
+            revert_reason: None,
+            created_address: None,
+            return_data_size: None,
+            return_data_prefix: None,
             compiler_version: None,
+            when: None,
         }))
     }
 
+    ///
+    /// Replaces the file references of the variants with the return values they contain.
+    ///
+    pub fn resolve_files(&mut self, directory: &Path) -> anyhow::Result<()> {
+        match self {
+            Self::Single(variant) => variant.resolve_file(directory),
+            Self::Multiple(variants) => {
+                for variant in variants.iter_mut() {
+                    variant.resolve_file(directory)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     ///
     /// Returns exception flag for specified mode.
     ///
@@ -53,20 +76,11 @@ impl Expected {
         };
         let variant = variants
             .into_iter()
-            .find(|variant| {
-                let version = match variant {
-                    Variant::Simple(_) => None,
-                    Variant::Extended(inner) => inner.compiler_version.as_ref(),
-                };
-                match version {
-                    Some(version) => mode.check_version(version),
-                    None => true,
-                }
-            })
+            .find(|variant| variant.check_mode(mode))
             .ok_or_else(|| anyhow::anyhow!("Version is not covered"))?;
         Ok(match variant {
-            Variant::Simple(_) => false,
-            Variant::Extended(inner) => inner.exception,
+            Variant::Simple(_) | Variant::File(_) => false,
+            Variant::Extended(inner) => inner.exception || inner.revert_reason.is_some(),
         })
     }
 }
@@ -0,0 +1,100 @@
+//!
+//! The Matter Labs compiler test metadata expected data condition.
+//!
+
+use serde::Deserialize;
+
+use crate::compilers::mode::Mode;
+
+///
+/// The Matter Labs compiler test metadata expected data condition.
+///
+/// The expected data is only selected for the modes satisfying all specified requirements.
+///
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Condition {
+    /// The `solc` version requirement, unsatisfied by the modes without `solc`.
+    pub solc: Option<semver::VersionReq>,
+    /// The `vyper` version requirement, unsatisfied by the modes without `vyper`.
+    pub vyper: Option<semver::VersionReq>,
+}
+
+impl Condition {
+    ///
+    /// Checks if the `mode` satisfies the condition.
+    ///
+    pub fn check_mode(&self, mode: &Mode) -> bool {
+        let is_vyper = matches!(mode, Mode::Vyper(_));
+        Self::check_version(self.solc.as_ref(), mode, !is_vyper)
+            && Self::check_version(self.vyper.as_ref(), mode, is_vyper)
+    }
+
+    ///
+    /// Checks if the version of the `mode` satisfies the `requirement`, if any, which is only
+    /// possible if the mode is `compiled` with the required compiler.
+    ///
+    fn check_version(
+        requirement: Option<&semver::VersionReq>,
+        mode: &Mode,
+        is_compiled: bool,
+    ) -> bool {
+        match requirement {
+            Some(requirement) => is_compiled && mode.check_version(requirement),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compilers::eravm::mode::Mode as EraVMMode;
+    use crate::compilers::mode::Mode;
+    use crate::compilers::solidity::upstream::mode::Mode as SolidityUpstreamMode;
+    use crate::compilers::yul::mode_upstream::Mode as YulUpstreamMode;
+
+    use super::Condition;
+
+    ///
+    /// Returns the condition with the `solc` and `vyper` version requirements.
+    ///
+    fn condition(solc: Option<&str>, vyper: Option<&str>) -> Condition {
+        Condition {
+            solc: solc.map(|solc| semver::VersionReq::parse(solc).expect("Always valid")),
+            vyper: vyper.map(|vyper| semver::VersionReq::parse(vyper).expect("Always valid")),
+        }
+    }
+
+    #[test]
+    fn check_mode() {
+        let solidity = Mode::SolidityUpstream(SolidityUpstreamMode::new(
+            semver::Version::new(0, 8, 29),
+            era_solc::StandardJsonInputCodegen::Yul,
+            true,
+            false,
+            true,
+        ));
+        let yul = Mode::YulUpstream(YulUpstreamMode::new(
+            semver::Version::new(0, 8, 28),
+            false,
+            true,
+        ));
+        let eravm = Mode::EraVM(EraVMMode::default());
+
+        for (condition, mode, expected) in [
+            (condition(None, None), &eravm, true),
+            (condition(Some(">=0.8.29"), None), &solidity, true),
+            (condition(Some(">=0.8.29"), None), &yul, false),
+            (condition(Some("<0.8.29"), None), &yul, true),
+            (condition(Some(">=0.8.0"), None), &eravm, false),
+            (condition(None, Some(">=0.3.10")), &solidity, false),
+            (
+                condition(Some(">=0.8.0"), Some(">=0.3.10")),
+                &solidity,
+                false,
+            ),
+        ] {
+            assert_eq!(condition.check_mode(mode), expected, "{condition:?} {mode}");
+        }
+    }
+}
@@ -9,9 +9,9 @@ use serde::Deserialize;
 ///
 #[derive(Debug, Clone, Deserialize)]
 pub struct Event {
-    /// The emitter contract address.
+    /// The emitter contract address, or `*` to match any emitter.
     pub address: Option<String>,
-    /// The indexed topics.
+    /// The indexed topics, where `*` matches any topic.
     pub topics: Vec<String>,
     /// The ordinary values.
     pub values: Vec<String>,
@@ -2,11 +2,17 @@
 //! The Matter Labs compiler test metadata extended expected data.
 //!
 
+pub mod condition;
 pub mod event;
+pub mod revert_reason;
 
 use serde::Deserialize;
 
+use crate::compilers::mode::Mode;
+
+use self::condition::Condition;
 use self::event::Event;
+use self::revert_reason::RevertReason;
 
 ///
 /// The Matter Labs compiler test metadata extended expected data.
@@ -14,13 +20,52 @@ use self::event::Event;
 #[derive(Debug, Default, Clone, Deserialize)]
 pub struct Extended {
     /// The return data values.
+    #[serde(default)]
     pub return_data: Vec<String>,
     /// The emitted events.
     #[serde(default)]
     pub events: Vec<Event>,
+    /// Whether the events may be emitted in any order, e.g. if the optimizer reorders them.
+    #[serde(default)]
+    pub events_unordered: bool,
     /// Whether an exception is expected,
     #[serde(default)]
     pub exception: bool,
+    /// The expected revert reason, which implies an exception.
+    #[serde(default)]
+    pub revert_reason: Option<RevertReason>,
+    /// The address expected to be created by a deployer call, which replaces the return data.
+    ///
+    /// Unlike `<instance>.address`, which is derived by the tester, a literal address locks in
+    /// the `CREATE` and `CREATE2` address derivation of the target.
+    #[serde(default)]
+    pub created_address: Option<String>,
+    /// The expected return data size in bytes, checked instead of the return data.
+    #[serde(default)]
+    pub return_data_size: Option<usize>,
+    /// The expected hexadecimal prefix of the return data, checked instead of the return data.
+    #[serde(default)]
+    pub return_data_prefix: Option<String>,
     /// The compiler version filter.
     pub compiler_version: Option<semver::VersionReq>,
+    /// The condition on the compilers of the mode, e.g. `{ "solc": ">=0.8.29" }`.
+    #[serde(default)]
+    pub when: Option<Condition>,
+}
+
+impl Extended {
+    ///
+    /// Checks if the expected data applies to the `mode`.
+    ///
+    pub fn check_mode(&self, mode: &Mode) -> bool {
+        let is_version_matched = match self.compiler_version.as_ref() {
+            Some(version) => mode.check_version(version),
+            None => true,
+        };
+        let is_condition_matched = match self.when.as_ref() {
+            Some(condition) => condition.check_mode(mode),
+            None => true,
+        };
+        is_version_matched && is_condition_matched
+    }
 }
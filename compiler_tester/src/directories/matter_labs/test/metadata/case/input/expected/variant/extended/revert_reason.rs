@@ -0,0 +1,23 @@
+//!
+//! The Matter Labs compiler test metadata expected revert reason.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Matter Labs compiler test metadata expected revert reason.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RevertReason {
+    /// The `Error(string)` message.
+    Message(String),
+    /// The custom error or `Panic(uint256)`.
+    Error {
+        /// The error signature, e.g. `InsufficientBalance(uint256,uint256)`, or its selector.
+        error: String,
+        /// The static error arguments.
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
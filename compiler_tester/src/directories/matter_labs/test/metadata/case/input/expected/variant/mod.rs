@@ -4,8 +4,13 @@
 
 pub mod extended;
 
+use std::path::Path;
+
 use serde::Deserialize;
 
+use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::case::input::hex_file::HexFile;
+
 use self::extended::Extended;
 
 ///
@@ -16,6 +21,79 @@ use self::extended::Extended;
 pub enum Variant {
     /// The return values only list.
     Simple(Vec<String>),
+    /// The hexadecimal file with the return data, resolved into the return values list when
+    /// the test is read. Goes before the extended variant, whose fields are all optional.
+    File(HexFile),
     /// The extended snapshot data testing.
     Extended(Extended),
 }
+
+impl Variant {
+    ///
+    /// Replaces the file reference with the return values it contains, resolving its path
+    /// against the test `directory`.
+    ///
+    pub fn resolve_file(&mut self, directory: &Path) -> anyhow::Result<()> {
+        if let Self::File(file) = self {
+            let return_data = file.read(directory)?;
+            if return_data.len() % era_compiler_common::BYTE_LENGTH_FIELD != 0 {
+                anyhow::bail!(
+                    "Return data file `{}` size {} is not a multiple of {} bytes",
+                    file.file,
+                    return_data.len(),
+                    era_compiler_common::BYTE_LENGTH_FIELD
+                );
+            }
+            *self = Self::Simple(
+                return_data
+                    .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+                    .map(|word| format!("0x{}", hex::encode(word)))
+                    .collect(),
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks if the variant applies to the `mode`.
+    ///
+    pub fn check_mode(&self, mode: &Mode) -> bool {
+        match self {
+            Self::Simple(_) | Self::File(_) => true,
+            Self::Extended(inner) => inner.check_mode(mode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Variant;
+
+    #[test]
+    fn deserialization() {
+        for (json, expected) in [
+            (r#"["0x01"]"#, "simple"),
+            (r#"{ "file": "return_data.hex" }"#, "file"),
+            (
+                r#"{ "return_data": ["0x01"], "exception": true }"#,
+                "extended",
+            ),
+            (
+                r#"{ "file": "return_data.hex", "exception": true }"#,
+                "extended",
+            ),
+            (
+                r#"{ "file": "return_data.hex", "return_data": ["0x01"] }"#,
+                "extended",
+            ),
+        ] {
+            let variant: Variant = serde_json::from_str(json).expect("Always valid");
+            let variant = match variant {
+                Variant::Simple(_) => "simple",
+                Variant::File(_) => "file",
+                Variant::Extended(_) => "extended",
+            };
+            assert_eq!(variant, expected, "{json}");
+        }
+    }
+}
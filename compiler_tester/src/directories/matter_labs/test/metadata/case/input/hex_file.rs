@@ -0,0 +1,38 @@
+//!
+//! The Matter Labs compiler test metadata hexadecimal file reference.
+//!
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+///
+/// The Matter Labs compiler test metadata hexadecimal file reference.
+///
+/// Keeps large payloads out of the metadata. The file contains a hexadecimal string, optionally
+/// prefixed with `0x`, and may be split into several lines.
+///
+/// Unknown fields are rejected, so an extended expected data object with a misplaced `file`
+/// field is not silently read as a file reference.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HexFile {
+    /// The file path relative to the test.
+    pub file: String,
+}
+
+impl HexFile {
+    ///
+    /// Reads and decodes the file, resolving its path against the test `directory`.
+    ///
+    pub fn read(&self, directory: &Path) -> anyhow::Result<Vec<u8>> {
+        let path = directory.join(self.file.as_str());
+        let text = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Hexadecimal file {path:?} reading: {error}"))?;
+        let hex: String = text.split_whitespace().collect();
+        let hex = hex.strip_prefix("0x").unwrap_or(hex.as_str());
+        hex::decode(hex)
+            .map_err(|error| anyhow::anyhow!("Hexadecimal file {path:?} decoding: {error}"))
+    }
+}
@@ -2,16 +2,25 @@
 //! The Matter Labs compiler test metadata case input.
 //!
 
+pub mod access_list_item;
 pub mod calldata;
+pub mod evm_context;
 pub mod expected;
+pub mod hex_file;
 pub mod storage;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::path::Path;
 
-use crate::directories::matter_labs::test::default_caller_address;
 use crate::directories::matter_labs::test::simple_tests_instance;
+use crate::directories::matter_labs::test::DEFAULT_CALLER_ADDRESS;
+use crate::test::case::input::call_kind::CallKind;
+use crate::test::case::input::fixture::Fixture;
 
+use self::access_list_item::AccessListItem;
 use self::calldata::Calldata;
+use self::evm_context::EVMContext;
 use self::expected::Expected;
 use self::storage::Storage;
 
@@ -25,20 +34,37 @@ pub struct Input {
     /// The contract instance.
     #[serde(default = "simple_tests_instance")]
     pub instance: String,
-    /// The caller address.
-    #[serde(default = "default_caller_address")]
-    pub caller: String,
+    /// The caller address, the directory default or the default caller if not specified.
+    pub caller: Option<String>,
     /// The contract method name.
     /// `#deployer` for the deployer call
-    /// `#fallback` for the fallback
+    /// `#fallback` for the fallback, whose calldata is passed as is, without a selector
     pub method: String,
     /// The passed calldata.
     pub calldata: Calldata,
-    /// The passed value.
+    /// The passed value, e.g. `1 ETH`, `100 wei`, or `0xff wei`.
     pub value: Option<String>,
+    /// The call kind: `call`, `delegatecall`, or `staticcall`.
+    #[serde(default)]
+    pub call_kind: CallKind,
+    /// The gas limit of the call on the EVM interpreter, overriding the default one.
+    pub evm_gas_limit: Option<u64>,
+    /// The context of the call on the EVM interpreter, overriding the default one.
+    /// Only supported on the EVM interpreter.
+    pub evm_context: Option<EVMContext>,
     /// The initial contracts storage.
     #[serde(default)]
     pub storage: HashMap<String, Storage>,
+    /// The addresses and storage keys pre-warmed by the transaction access list.
+    /// Only supported on REVM.
+    #[serde(default)]
+    pub access_list: Vec<AccessListItem>,
+    /// The instance to register with the address returned by the call, e.g. a contract created
+    /// by a factory, so that the subsequent inputs can reference it. Only supported on REVM.
+    pub register: Option<String>,
+    /// Whether the value is passed via the `msg.value` simulator system call on EraVM,
+    /// overriding the settings of the case and test.
+    pub system_mode: Option<bool>,
 
     /// The expected return data.
     pub expected: Option<Expected>,
@@ -46,6 +72,20 @@ pub struct Input {
     pub expected_eravm: Option<Expected>,
     /// The expected return data for EVM.
     pub expected_evm: Option<Expected>,
+
+    /// The expected immutable values by index, checked after the deployer call.
+    pub expected_immutables: Option<HashMap<String, String>>,
+    /// The expected immutable values for EraVM.
+    pub expected_immutables_eravm: Option<HashMap<String, String>>,
+    /// The expected immutable values for EVM.
+    pub expected_immutables_evm: Option<HashMap<String, String>>,
+    /// The expected balances as `address -> value`, checked after the input.
+    #[serde(default)]
+    pub expected_balances: BTreeMap<String, String>,
+
+    /// The fixture kind, if the input is a setup or teardown fixture call.
+    #[serde(skip)]
+    pub fixture: Option<Fixture>,
 }
 
 impl Input {
@@ -56,15 +96,57 @@ impl Input {
         Self {
             comment: None,
             instance: instance.clone(),
-            caller: default_caller_address(),
+            caller: None,
             calldata: Calldata::default(),
             method: "#deployer".to_string(),
             value: None,
+            call_kind: CallKind::default(),
+            evm_gas_limit: None,
+            evm_context: None,
             storage: HashMap::new(),
+            access_list: Vec::new(),
+            register: None,
+            system_mode: None,
 
             expected: Some(Expected::successful_deployer_expected(instance.clone())),
             expected_eravm: Some(Expected::successful_deployer_expected(instance.clone())),
             expected_evm: Some(Expected::successful_deployer_expected(instance)),
+
+            expected_immutables: None,
+            expected_immutables_eravm: None,
+            expected_immutables_evm: None,
+            expected_balances: BTreeMap::new(),
+            fixture: None,
+        }
+    }
+
+    ///
+    /// Returns the caller address, or the default one if not specified.
+    ///
+    pub fn caller(&self) -> &str {
+        self.caller.as_deref().unwrap_or(DEFAULT_CALLER_ADDRESS)
+    }
+
+    ///
+    /// Replaces the calldata and expected data file references with their contents,
+    /// resolving their paths against the test `directory`.
+    ///
+    pub fn resolve_files(&mut self, directory: &Path) -> anyhow::Result<()> {
+        self.calldata
+            .resolve_file(directory)
+            .map_err(|error| anyhow::anyhow!("Invalid calldata: {error}"))?;
+        for expected in [
+            self.expected.as_mut(),
+            self.expected_eravm.as_mut(),
+            self.expected_evm.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            expected
+                .resolve_files(directory)
+                .map_err(|error| anyhow::anyhow!("Invalid expected data: {error}"))?;
         }
+        Ok(())
     }
 }
@@ -16,6 +16,22 @@ pub enum Storage {
     Map(HashMap<String, String>),
 }
 
+impl Storage {
+    ///
+    /// Returns the storage as a map, where the list indexes are the keys.
+    ///
+    pub fn to_map(&self) -> HashMap<String, String> {
+        match self {
+            Self::List(list) => list
+                .iter()
+                .enumerate()
+                .map(|(key, value)| (key.to_string(), value.to_owned()))
+                .collect(),
+            Self::Map(map) => map.clone(),
+        }
+    }
+}
+
 impl Default for Storage {
     fn default() -> Self {
         Self::Map(HashMap::new())
@@ -3,21 +3,29 @@
 //!
 
 pub mod input;
+pub mod property;
 
 use std::collections::BTreeMap;
+use std::path::Path;
 use std::str::FromStr;
 
 use serde::Deserialize;
 
 use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::deployer::Deployer;
 use crate::environment::Environment;
+use crate::test::case::input::fixture::Fixture;
+use crate::test::case::vm_state::VMState;
 use crate::test::instance::Instance;
 use crate::vm::address_iterator::AddressIterator;
 use crate::vm::eravm::address_iterator::EraVMAddressIterator;
+use crate::vm::eravm::runner_settings::overrides::Overrides as RunnerOverrides;
 use crate::vm::evm::address_iterator::EVMAddressIterator;
 
 use self::input::expected::Expected;
+use self::input::storage::Storage;
 use self::input::Input;
+use self::property::Property;
 
 ///
 /// The Matter Labs compiler test metadata case.
@@ -31,12 +39,34 @@ pub struct Case {
     /// The mode filter.
     pub modes: Option<Vec<String>>,
     /// The case inputs.
+    #[serde(default)]
     pub inputs: Vec<Input>,
+    /// The fixture calls run before the main inputs, excluded from the benchmarks.
+    /// Their return data is only checked if expected, but they fail if reverted.
+    #[serde(default)]
+    pub setup: Vec<Input>,
+    /// The fixture calls run after the main inputs, excluded from the benchmarks.
+    /// Their return data is only checked if expected, but they fail if reverted.
+    #[serde(default)]
+    pub teardown: Vec<Input>,
+    /// The property checked with pseudo-random arguments after the inputs.
+    pub property: Option<Property>,
     /// If the test case must be ignored.
     #[serde(default)]
     pub ignore: bool,
     /// Overrides the default number of cycles.
     pub cycles: Option<usize>,
+    /// The EraVM deployer required by the case, overriding the one of the test.
+    #[serde(default)]
+    pub deployer: Option<Deployer>,
+    /// Whether the value is passed via the `msg.value` simulator system call, overriding
+    /// the setting of the test.
+    #[serde(default)]
+    pub system_mode: Option<bool>,
+    /// The VM state the case starts from, `fresh` by default, or `shared` to continue from
+    /// the state left by the previous case.
+    #[serde(default)]
+    pub vm_state: VMState,
 
     /// The expected return data.
     pub expected: Option<Expected>,
@@ -47,20 +77,117 @@ pub struct Case {
 }
 
 impl Case {
+    ///
+    /// Replaces the file references of the inputs and the expected data with their contents,
+    /// resolving their paths against the test `directory`.
+    ///
+    pub fn resolve_files(&mut self, directory: &Path) -> anyhow::Result<()> {
+        for (kind, inputs) in [
+            ("Input", &mut self.inputs),
+            ("Setup input", &mut self.setup),
+            ("Teardown input", &mut self.teardown),
+        ] {
+            for (index, input) in inputs.iter_mut().enumerate() {
+                input
+                    .resolve_files(directory)
+                    .map_err(|error| anyhow::anyhow!("{kind} #{index}: {error}"))?;
+            }
+        }
+        for expected in [
+            self.expected.as_mut(),
+            self.expected_eravm.as_mut(),
+            self.expected_evm.as_mut(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            expected
+                .resolve_files(directory)
+                .map_err(|error| anyhow::anyhow!("Invalid expected data: {error}"))?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the EraVM runner settings overrides of the case.
+    ///
+    pub fn runner_overrides(&self) -> RunnerOverrides {
+        RunnerOverrides::new(
+            self.deployer.map(|deployer| deployer.is_disabled()),
+            self.system_mode.map(|system_mode| !system_mode),
+        )
+    }
+
     ///
     /// Normalizes the case.
     ///
     pub fn normalize(
         mut self,
         contracts: &BTreeMap<String, String>,
+        instance_storage: &BTreeMap<String, Storage>,
         instances: &BTreeMap<String, Instance>,
         environment: Environment,
+        mode: &Mode,
     ) -> anyhow::Result<Self> {
+        self.normalize_property(mode)?;
+        self.normalize_fixtures()?;
         self.normalize_deployer_calls(contracts, instances, environment)?;
+        self.normalize_instance_storage(instance_storage);
         self.normalize_expected();
         Ok(self)
     }
 
+    ///
+    /// Surrounds the inputs with the setup and teardown fixture calls.
+    ///
+    /// The fixtures can only be contract calls, as the checks derived from an input, like
+    /// the balance checks, would not be excluded from the benchmarks.
+    ///
+    pub fn normalize_fixtures(&mut self) -> anyhow::Result<()> {
+        if self.setup.is_empty() && self.teardown.is_empty() {
+            return Ok(());
+        }
+
+        let mut inputs =
+            Vec::with_capacity(self.setup.len() + self.inputs.len() + self.teardown.len());
+        for (kind, fixtures) in [
+            (Fixture::Setup, &mut self.setup),
+            (Fixture::Teardown, &mut self.teardown),
+        ] {
+            for (index, fixture) in fixtures.iter_mut().enumerate() {
+                if fixture.method.as_str() == "#deployer" {
+                    anyhow::bail!("The {kind} input #{index} cannot be a deployer call");
+                }
+                if fixture.register.is_some() {
+                    anyhow::bail!("The {kind} input #{index} cannot register an instance");
+                }
+                if !fixture.expected_balances.is_empty() {
+                    anyhow::bail!("The {kind} input #{index} cannot check balances");
+                }
+                fixture.fixture = Some(kind);
+            }
+        }
+        inputs.append(&mut self.setup);
+        inputs.append(&mut self.inputs);
+        inputs.append(&mut self.teardown);
+        self.inputs = inputs;
+
+        Ok(())
+    }
+
+    ///
+    /// Appends the property sample calls to the inputs.
+    ///
+    pub fn normalize_property(&mut self, mode: &Mode) -> anyhow::Result<()> {
+        if let Some(property) = self.property.as_ref() {
+            let inputs = property
+                .inputs(self.name.as_str(), mode.to_string().as_str())
+                .map_err(|error| anyhow::anyhow!("Invalid property: {}", error))?;
+            self.inputs.extend(inputs);
+        }
+        Ok(())
+    }
+
     ///
     /// Validates deployer calls, adds libraries deployer calls, contracts deployer calls if they are not present.
     ///
@@ -130,10 +257,40 @@ impl Case {
     }
 
     ///
-    /// Copies the final expected data to the last input.
+    /// Sets the initial storage of the instances in the first input after the deployer calls.
+    ///
+    /// The storage specified by the input itself takes precedence over the instance storage.
+    ///
+    pub fn normalize_instance_storage(&mut self, instance_storage: &BTreeMap<String, Storage>) {
+        let input = match self
+            .inputs
+            .iter_mut()
+            .find(|input| input.method.as_str() != "#deployer")
+        {
+            Some(input) => input,
+            None => return,
+        };
+
+        for (instance, storage) in instance_storage.iter() {
+            let mut storage = storage.to_map();
+            let address = format!("{instance}.address");
+            if let Some(input_storage) = input.storage.remove(address.as_str()) {
+                storage.extend(input_storage.to_map());
+            }
+            input.storage.insert(address, Storage::Map(storage));
+        }
+    }
+
+    ///
+    /// Copies the final expected data to the last input, except the teardown fixtures.
     ///
     pub fn normalize_expected(&mut self) {
-        if let Some(input) = self.inputs.last_mut() {
+        if let Some(input) = self
+            .inputs
+            .iter_mut()
+            .rev()
+            .find(|input| input.fixture.is_none())
+        {
             if input.expected.is_none() {
                 input.expected.clone_from(&self.expected);
             }
@@ -152,8 +309,8 @@ impl Case {
     pub fn set_variables(
         &self,
         instances: &mut BTreeMap<String, Instance>,
-        mut eravm_address_iterator: EraVMAddressIterator,
-        mut evm_address_iterator: EVMAddressIterator,
+        eravm_address_iterator: &mut EraVMAddressIterator,
+        evm_address_iterator: &mut EVMAddressIterator,
         mode: &Mode,
     ) -> anyhow::Result<()> {
         for (index, input) in self.inputs.iter().enumerate() {
@@ -175,15 +332,14 @@ impl Case {
                 continue;
             }
 
-            let caller =
-                web3::types::Address::from_str(input.caller.as_str()).map_err(|error| {
-                    anyhow::anyhow!(
-                        "Input #{} has invalid caller `{}`: {}",
-                        index,
-                        input.caller.as_str(),
-                        error
-                    )
-                })?;
+            let caller = web3::types::Address::from_str(input.caller()).map_err(|error| {
+                anyhow::anyhow!(
+                    "Input #{} has invalid caller `{}`: {}",
+                    index,
+                    input.caller(),
+                    error
+                )
+            })?;
 
             match instances.get_mut(input.instance.as_str()) {
                 Some(instance @ Instance::EraVM(_)) => {
@@ -198,3 +354,68 @@ impl Case {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::fixture::Fixture;
+
+    use super::Case;
+
+    #[test]
+    fn normalize_fixtures() {
+        let mut case: Case = serde_json::from_str(
+            r##"{
+                "name": "fixtures",
+                "setup": [{ "method": "initialize", "calldata": [] }],
+                "inputs": [{ "method": "get", "calldata": [] }],
+                "teardown": [{ "method": "reset", "calldata": [] }],
+                "expected": ["42"]
+            }"##,
+        )
+        .expect("Always valid");
+        case.normalize_fixtures().expect("Always valid");
+        case.normalize_expected();
+
+        assert_eq!(
+            case.inputs
+                .iter()
+                .map(|input| (
+                    input.method.as_str(),
+                    input.fixture,
+                    input.expected.is_some()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("initialize", Some(Fixture::Setup), false),
+                ("get", None, true),
+                ("reset", Some(Fixture::Teardown), false),
+            ]
+        );
+
+        for (fixtures, expected) in [
+            (
+                r##""setup": [{ "method": "#deployer", "calldata": [] }]"##,
+                "The setup input #0 cannot be a deployer call",
+            ),
+            (
+                r##""teardown": [{ "method": "get", "calldata": [] }, { "method": "get", "calldata": [], "register": "Test" }]"##,
+                "The teardown input #1 cannot register an instance",
+            ),
+            (
+                r##""setup": [{ "method": "get", "calldata": [], "expected_balances": { "Test.address": "0" } }]"##,
+                "The setup input #0 cannot check balances",
+            ),
+        ] {
+            let mut case: Case =
+                serde_json::from_str(format!(r#"{{ "name": "fixtures", {fixtures} }}"#).as_str())
+                    .expect("Always valid");
+            assert_eq!(
+                case.normalize_fixtures()
+                    .expect_err("Always invalid")
+                    .to_string(),
+                expected,
+                "{fixtures}"
+            );
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//!
+//! The Matter Labs compiler test metadata case property.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use sha3::Digest;
+
+use crate::directories::matter_labs::test::simple_tests_instance;
+use crate::test::case::input::call_kind::CallKind;
+
+use super::input::calldata::Calldata;
+use super::input::expected::variant::Variant;
+use super::input::expected::Expected;
+use super::input::Input;
+
+///
+/// The Matter Labs compiler test metadata case property.
+///
+/// The invariant method is called with pseudo-random arguments and must return `true`.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Property {
+    /// The contract instance.
+    #[serde(default = "simple_tests_instance")]
+    pub instance: String,
+    /// The caller address, the directory default or the default caller if not specified.
+    pub caller: Option<String>,
+    /// The invariant method, e.g. `checkAddCommutative(uint256,uint256)`.
+    pub method: String,
+    /// The argument domains: `uintN`, `intN`, `bool`, or an inclusive decimal range `min..max`.
+    pub arguments: Vec<String>,
+    /// The number of samples per mode.
+    #[serde(default = "Property::default_samples")]
+    pub samples: usize,
+    /// The seed of the pseudo-random generator.
+    #[serde(default)]
+    pub seed: u64,
+}
+
+impl Property {
+    ///
+    /// The default number of samples per mode.
+    ///
+    pub fn default_samples() -> usize {
+        16
+    }
+
+    ///
+    /// Generates the invariant method calls.
+    ///
+    /// The arguments are derived from the seed, case name, and mode, so every run of the same
+    /// mode checks the same samples, while different modes check different ones.
+    ///
+    pub fn inputs(&self, case: &str, mode: &str) -> anyhow::Result<Vec<Input>> {
+        let domains = self
+            .arguments
+            .iter()
+            .map(|domain| Domain::try_from(domain.as_str()))
+            .collect::<anyhow::Result<Vec<Domain>>>()?;
+
+        let mut inputs = Vec::with_capacity(self.samples);
+        for sample in 0..self.samples {
+            let calldata = domains
+                .iter()
+                .enumerate()
+                .map(|(index, domain)| {
+                    domain
+                        .sample(self.random(case, mode, sample, index))
+                        .to_string()
+                })
+                .collect::<Vec<String>>();
+
+            inputs.push(Input {
+                comment: Some(format!(
+                    "Property sample #{sample}: {}",
+                    calldata.join(", ")
+                )),
+                instance: self.instance.clone(),
+                caller: self.caller.clone(),
+                method: self.method.clone(),
+                calldata: Calldata::List(calldata),
+                value: None,
+                call_kind: CallKind::default(),
+                evm_gas_limit: None,
+                evm_context: None,
+                storage: HashMap::new(),
+                access_list: Vec::new(),
+                register: None,
+                system_mode: None,
+
+                expected: Some(Expected::Single(Variant::Simple(vec!["1".to_owned()]))),
+                expected_eravm: None,
+                expected_evm: None,
+
+                expected_immutables: None,
+                expected_immutables_eravm: None,
+                expected_immutables_evm: None,
+                expected_balances: BTreeMap::new(),
+                fixture: None,
+            });
+        }
+        Ok(inputs)
+    }
+
+    ///
+    /// Returns a pseudo-random word for the specified sample argument.
+    ///
+    fn random(&self, case: &str, mode: &str, sample: usize, argument: usize) -> [u8; 32] {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(self.seed.to_be_bytes());
+        hasher.update(case.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(mode.as_bytes());
+        hasher.update([0u8]);
+        hasher.update((sample as u64).to_be_bytes());
+        hasher.update((argument as u64).to_be_bytes());
+        hasher.finalize().into()
+    }
+}
+
+///
+/// The property argument domain.
+///
+#[derive(Debug, Clone, PartialEq)]
+enum Domain {
+    /// The unsigned integer of the specified bit length.
+    Unsigned(usize),
+    /// The signed integer of the specified bit length.
+    Signed(usize),
+    /// The boolean.
+    Boolean,
+    /// The inclusive unsigned range.
+    Range(web3::types::U256, web3::types::U256),
+}
+
+impl Domain {
+    ///
+    /// Maps a pseudo-random word to a value of the domain.
+    ///
+    /// One sample of eight is taken from the domain bounds, as they are the most likely
+    /// to break arithmetic invariants.
+    ///
+    fn sample(&self, random: [u8; 32]) -> web3::types::U256 {
+        let is_bound = random[0] % 8 == 0;
+        let is_upper = random[1] % 2 == 1;
+        let random = web3::types::U256::from_big_endian(random.as_slice());
+
+        match self {
+            Self::Unsigned(bit_length) => {
+                let max = web3::types::U256::max_value()
+                    >> (era_compiler_common::BIT_LENGTH_FIELD - bit_length);
+                match (is_bound, is_upper) {
+                    (true, false) => web3::types::U256::zero(),
+                    (true, true) => max,
+                    (false, _) => random & max,
+                }
+            }
+            Self::Signed(bit_length) => {
+                let mask = web3::types::U256::max_value()
+                    >> (era_compiler_common::BIT_LENGTH_FIELD - bit_length);
+                let value = match (is_bound, is_upper) {
+                    (true, false) => web3::types::U256::one() << (bit_length - 1),
+                    (true, true) => mask >> 1,
+                    (false, _) => random & mask,
+                };
+                if value.bit(bit_length - 1) {
+                    value | !mask
+                } else {
+                    value
+                }
+            }
+            Self::Boolean => web3::types::U256::from(random.bit(0) as u8),
+            Self::Range(min, max) => match (is_bound, is_upper) {
+                (true, false) => *min,
+                (true, true) => *max,
+                (false, _) => match (*max - *min).checked_add(web3::types::U256::one()) {
+                    Some(length) => *min + random % length,
+                    None => random,
+                },
+            },
+        }
+    }
+}
+
+impl TryFrom<&str> for Domain {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let bit_length = |bit_length: &str| -> anyhow::Result<usize> {
+            let bit_length = if bit_length.is_empty() {
+                era_compiler_common::BIT_LENGTH_FIELD
+            } else {
+                bit_length.parse::<usize>()?
+            };
+            if bit_length == 0
+                || bit_length > era_compiler_common::BIT_LENGTH_FIELD
+                || bit_length % era_compiler_common::BIT_LENGTH_BYTE != 0
+            {
+                anyhow::bail!("Invalid bit length `{bit_length}`");
+            }
+            Ok(bit_length)
+        };
+
+        if value == "bool" {
+            Ok(Self::Boolean)
+        } else if let Some(bits) = value.strip_prefix("uint") {
+            Ok(Self::Unsigned(bit_length(bits).map_err(|error| {
+                anyhow::anyhow!("Invalid domain `{value}`: {error}")
+            })?))
+        } else if let Some(bits) = value.strip_prefix("int") {
+            Ok(Self::Signed(bit_length(bits).map_err(|error| {
+                anyhow::anyhow!("Invalid domain `{value}`: {error}")
+            })?))
+        } else if let Some((min, max)) = value.split_once("..") {
+            let min = web3::types::U256::from_dec_str(min)
+                .map_err(|error| anyhow::anyhow!("Invalid domain `{value}` minimum: {error}"))?;
+            let max = web3::types::U256::from_dec_str(max)
+                .map_err(|error| anyhow::anyhow!("Invalid domain `{value}` maximum: {error}"))?;
+            if min > max {
+                anyhow::bail!("Invalid domain `{value}`: the minimum exceeds the maximum");
+            }
+            Ok(Self::Range(min, max))
+        } else {
+            anyhow::bail!(
+                "Invalid domain `{value}`: expected `uintN`, `intN`, `bool`, or `min..max`"
+            )
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::Domain;
+
+    ///
+    /// Returns a pseudo-random word with the bound and upper selectors, and the lowest byte.
+    ///
+    fn random(is_bound: bool, is_upper: bool, lowest: u8) -> [u8; 32] {
+        let mut random = [0u8; 32];
+        random[0] = if is_bound { 0 } else { 1 };
+        random[1] = is_upper as u8;
+        random[31] = lowest;
+        random
+    }
+
+    #[test]
+    fn try_from() {
+        for (value, expected) in [
+            ("bool", Some(Domain::Boolean)),
+            ("uint", Some(Domain::Unsigned(256))),
+            ("uint8", Some(Domain::Unsigned(8))),
+            ("int", Some(Domain::Signed(256))),
+            ("int16", Some(Domain::Signed(16))),
+            (
+                "1..10",
+                Some(Domain::Range(
+                    web3::types::U256::from(1),
+                    web3::types::U256::from(10),
+                )),
+            ),
+            (
+                "5..5",
+                Some(Domain::Range(
+                    web3::types::U256::from(5),
+                    web3::types::U256::from(5),
+                )),
+            ),
+            ("uint0", None),
+            ("uint7", None),
+            ("uint264", None),
+            ("int12", None),
+            ("10..1", None),
+            ("x..1", None),
+            ("address", None),
+        ] {
+            let domain = Domain::try_from(value).ok();
+
+            assert_eq!(domain, expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn sample() {
+        let max = web3::types::U256::max_value();
+        for (domain, random, expected) in [
+            (Domain::Unsigned(8), random(true, false, 0xab), 0.into()),
+            (Domain::Unsigned(8), random(true, true, 0xab), 0xff.into()),
+            (Domain::Unsigned(8), random(false, false, 0xab), 0xab.into()),
+            (Domain::Unsigned(256), random(true, true, 0), max),
+            (Domain::Signed(8), random(true, false, 0), max - 127),
+            (Domain::Signed(8), random(true, true, 0), 127.into()),
+            (Domain::Signed(8), random(false, false, 0xff), max),
+            (Domain::Signed(8), random(false, false, 0x7f), 127.into()),
+            (
+                Domain::Signed(256),
+                random(true, false, 0),
+                web3::types::U256::one() << 255,
+            ),
+            (Domain::Signed(256), random(true, true, 0), max >> 1),
+            (Domain::Boolean, random(false, false, 3), 1.into()),
+            (Domain::Boolean, random(true, true, 2), 0.into()),
+            (
+                Domain::Range(10.into(), 20.into()),
+                random(true, false, 0),
+                10.into(),
+            ),
+            (
+                Domain::Range(10.into(), 20.into()),
+                random(true, true, 0),
+                20.into(),
+            ),
+            (
+                Domain::Range(10.into(), 20.into()),
+                random(false, false, 5),
+                18.into(),
+            ),
+            (
+                Domain::Range(0.into(), max),
+                random(false, false, 5),
+                web3::types::U256::from_big_endian(random(false, false, 5).as_slice()),
+            ),
+        ] {
+            assert_eq!(domain.sample(random), expected, "{domain:?}");
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//!
+//! The Matter Labs compiler test metadata contract.
+//!
+
+use super::case::input::storage::Storage;
+
+///
+/// The Matter Labs compiler test metadata contract.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Contract {
+    /// The contract path, e.g. `main.sol:Test`.
+    Path(String),
+    /// The contract path with the initial storage of the instance.
+    Extended {
+        /// The contract path, e.g. `main.sol:Test`.
+        path: String,
+        /// The instance storage, set after its deployment and before the first call.
+        #[serde(default)]
+        storage: Storage,
+    },
+}
+
+impl Contract {
+    ///
+    /// Returns the contract path.
+    ///
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Path(path) => path.as_str(),
+            Self::Extended { path, .. } => path.as_str(),
+        }
+    }
+
+    ///
+    /// Returns the mutable contract path.
+    ///
+    pub fn path_mut(&mut self) -> &mut String {
+        match self {
+            Self::Path(path) => path,
+            Self::Extended { path, .. } => path,
+        }
+    }
+
+    ///
+    /// Returns the initial storage of the instance, if specified.
+    ///
+    pub fn storage(&self) -> Option<&Storage> {
+        match self {
+            Self::Path(_) => None,
+            Self::Extended { storage, .. } => Some(storage),
+        }
+    }
+}
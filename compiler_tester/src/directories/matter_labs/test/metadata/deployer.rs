@@ -0,0 +1,28 @@
+//!
+//! The Matter Labs compiler test metadata deployer.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Matter Labs compiler test metadata deployer.
+///
+/// Overrides the EraVM deployer of the run for tests which only make sense with a specific one.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Deployer {
+    /// The contracts are deployed by calling the system contract deployer.
+    System,
+    /// The contracts are deployed natively, bypassing the system contracts.
+    Native,
+}
+
+impl Deployer {
+    ///
+    /// Whether the system contract deployer is disabled.
+    ///
+    pub fn is_disabled(&self) -> bool {
+        matches!(self, Self::Native)
+    }
+}
@@ -3,12 +3,21 @@
 //!
 
 pub mod case;
+pub mod contract;
+pub mod deployer;
 pub mod evm_contract;
 
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use crate::compilers::solidity::suppressed_error::SuppressedError;
+use crate::compilers::solidity::suppressed_warning::SuppressedWarning;
+use crate::vm::eravm::runner_settings::overrides::Overrides as RunnerOverrides;
+
+use self::case::input::storage::Storage;
 use self::case::Case;
+use self::contract::Contract;
+use self::deployer::Deployer;
 use self::evm_contract::EVMContract;
 
 ///
@@ -24,23 +33,86 @@ pub struct Metadata {
     pub modes: Option<Vec<String>>,
     /// The test group.
     pub group: Option<String>,
-    /// The test contracts as `instance -> path`.
+    /// The test tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The compiler features covered by the test, e.g. `mcopy` or `transient-storage`.
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// The test contracts as `instance -> path`, or `instance -> { path, storage }`.
     #[serde(default)]
-    pub contracts: BTreeMap<String, String>,
+    pub contracts: BTreeMap<String, Contract>,
     /// The EVM auxiliary contracts as `instance -> deploy code`.
     #[serde(default)]
     pub evm_contracts: BTreeMap<String, EVMContract>,
     /// The test libraries for linking.
     #[serde(default)]
     pub libraries: BTreeMap<String, BTreeMap<String, String>>,
+    /// The import remappings as `[context:]prefix=target`, with paths relative to the test directory.
+    #[serde(default)]
+    pub remappings: Vec<String>,
     /// Enable the EraVM extensions.
     #[serde(default)]
     pub enable_eravm_extensions: bool,
+    /// Whether `stack too deep` errors of the EVM legacy assembly codegen are expected.
+    #[serde(default)]
+    pub stack_too_deep_ok: bool,
+    /// The suppressed `zksolc` errors, overriding the default `sendtransfer` one.
+    #[serde(default)]
+    pub suppressed_errors: Option<Vec<SuppressedError>>,
+    /// The suppressed `zksolc` warnings, overriding the default empty list.
+    #[serde(default)]
+    pub suppressed_warnings: Option<Vec<SuppressedWarning>>,
+    /// The substring of the compilation error the test is expected to fail with, e.g. if
+    /// a `zksolc` error is not suppressed. The test passes if the compilation fails with it.
+    #[serde(default)]
+    pub expected_compilation_error: Option<String>,
+    /// The substrings of the compilation warnings the test is expected to produce, one per warning,
+    /// e.g. if a `zksolc` warning is not suppressed. An empty list expects no warnings.
+    #[serde(default)]
+    pub expected_compilation_warnings: Option<Vec<String>>,
+    /// Whether the EVM bytecodes must be published by the deploy inputs on EVM interpreter,
+    /// instead of being published before running the test.
+    #[serde(default)]
+    pub disable_evm_bytecode_prepublication: bool,
+    /// The EraVM deployer required by the test, overriding the one of the run.
+    #[serde(default)]
+    pub deployer: Option<Deployer>,
+    /// Whether the value is passed via the `msg.value` simulator system call, overriding
+    /// the setting of the run.
+    #[serde(default)]
+    pub system_mode: Option<bool>,
     /// If the entire test file must be ignored.
     #[serde(default)]
     pub ignore: bool,
 }
 
+impl Metadata {
+    ///
+    /// Returns the EraVM runner settings overrides of the test.
+    ///
+    pub fn runner_overrides(&self) -> RunnerOverrides {
+        RunnerOverrides::new(
+            self.deployer.map(|deployer| deployer.is_disabled()),
+            self.system_mode.map(|system_mode| !system_mode),
+        )
+    }
+
+    ///
+    /// Returns the initial storage of the instances which specify it.
+    ///
+    pub fn instance_storage(&self) -> BTreeMap<String, Storage> {
+        self.contracts
+            .iter()
+            .filter_map(|(instance, contract)| {
+                contract
+                    .storage()
+                    .map(|storage| (instance.to_owned(), storage.to_owned()))
+            })
+            .collect()
+    }
+}
+
 impl FromStr for Metadata {
     type Err = anyhow::Error;
 
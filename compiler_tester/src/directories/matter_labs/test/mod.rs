@@ -2,32 +2,55 @@
 //! The Matter Labs compiler test.
 //!
 
+pub mod helper;
 pub mod metadata;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::compilers::mode::llvm_options::LLVMOptions;
 use crate::compilers::mode::Mode;
+use crate::compilers::vyper::EXTENSION_VYPER_INTERFACE;
 use crate::compilers::Compiler;
+use crate::directories::matter_labs::defaults::Defaults;
 use crate::directories::Buildable;
+use crate::docs::case::Case as DocsCase;
+use crate::docs::entry::Entry as DocsEntry;
+use crate::docs::Documented;
 use crate::environment::Environment;
 use crate::filters::Filters;
+use crate::lint::kind::Kind as LintKind;
+use crate::lint::warning::Warning as LintWarning;
+use crate::lint::Lint;
+use crate::lint::Linted;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::report::interface_check::interface::Interface;
+use crate::summary::report::pass_rates::feature_pass_rates::FeaturePassRates;
 use crate::summary::Summary;
+use crate::test::case::input::call_kind::CallKind;
+use crate::test::case::input::output::revert_reason::RevertReason;
+use crate::test::case::input::value::Value;
+use crate::test::case::vm_state::VMState;
 use crate::test::case::Case;
 use crate::test::description::TestDescription;
 use crate::test::instance::Instance;
+use crate::test::selector::registry::Registry as SelectorRegistry;
 use crate::test::selector::TestSelector;
 use crate::test::Test;
 use crate::vm::address_iterator::AddressIterator;
 use crate::vm::eravm::address_iterator::EraVMAddressIterator;
 use crate::vm::evm::address_iterator::EVMAddressIterator;
 
+use self::helper::Helper;
 use self::metadata::case::input::calldata::Calldata as MatterLabsCaseInputCalldata;
+use self::metadata::case::input::expected::variant::Variant as MatterLabsCaseInputExpectedVariant;
 use self::metadata::case::input::expected::Expected as MatterLabsCaseInputExpected;
 use self::metadata::case::input::Input as MatterLabsCaseInput;
 use self::metadata::case::Case as MatterLabsCase;
@@ -39,6 +62,18 @@ pub const SIMPLE_TESTS_CONTRACT_NAME: &str = "Test";
 /// The default simple contract instance name.
 pub const SIMPLE_TESTS_INSTANCE: &str = "Test";
 
+/// The `solc` stack-too-deep error message fragment.
+pub const STACK_TOO_DEEP_ERROR: &str = "Stack too deep";
+
+/// The vendored libraries directory next to the test.
+pub const VENDORED_LIBRARIES_DIRECTORY: &str = "lib";
+
+/// The extensions of the Vyper complex test sources.
+pub const VYPER_SOURCE_EXTENSIONS: [&str; 2] = [
+    era_compiler_common::EXTENSION_VYPER,
+    EXTENSION_VYPER_INTERFACE,
+];
+
 /// The default address of the caller.
 pub const DEFAULT_CALLER_ADDRESS: &str = "deadbeef01000000000000000000000000000000";
 
@@ -49,13 +84,6 @@ pub fn simple_tests_instance() -> String {
     SIMPLE_TESTS_INSTANCE.to_string()
 }
 
-///
-/// Used for default initialization.
-///
-pub fn default_caller_address() -> String {
-    DEFAULT_CALLER_ADDRESS.to_string()
-}
-
 ///
 /// The Matter Labs compiler test.
 ///
@@ -69,13 +97,25 @@ pub struct MatterLabsTest {
     metadata: Metadata,
     /// The test sources.
     sources: Vec<(String, String)>,
+    /// The import remappings resolved relative to the test directory.
+    remappings: BTreeSet<String>,
+    /// The built-in helper instances referenced by the test.
+    helpers: BTreeSet<Helper>,
 }
 
 impl MatterLabsTest {
     ///
     /// Try to create new test.
     ///
-    pub fn new(path: PathBuf, summary: Arc<Mutex<Summary>>, filters: &Filters) -> Option<Self> {
+    /// The directory `defaults` are used for the values the metadata does not specify.
+    ///
+    pub fn new(
+        path: PathBuf,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        selectors: &SelectorRegistry,
+        defaults: &Defaults,
+    ) -> Option<Self> {
         let selector = TestSelector {
             path: path.to_string_lossy().to_string(),
             case: None,
@@ -88,10 +128,15 @@ impl MatterLabsTest {
 
         let test_description = TestDescription::default_for(selector.clone());
 
+        if let Err(error) = selectors.register(&selector) {
+            Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
+            return None;
+        }
+
         let main_file_string = match std::fs::read_to_string(path.as_path()) {
             Ok(data) => data,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
@@ -101,7 +146,7 @@ impl MatterLabsTest {
         {
             Ok(metadata) => metadata,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
@@ -111,11 +156,39 @@ impl MatterLabsTest {
             return None;
         }
 
+        defaults.apply(&mut metadata);
+
         if !filters.check_group(&metadata.group) {
             return None;
         }
 
-        let sources = if metadata.contracts.is_empty() {
+        if !filters.check_tags(metadata.tags.as_slice()) {
+            return None;
+        }
+
+        let mut test_directory = path.clone();
+        test_directory.pop();
+        for case in metadata.cases.iter_mut() {
+            if let Err(error) = case.resolve_files(test_directory.as_path()) {
+                Summary::invalid(
+                    summary,
+                    test_description,
+                    ErrorCode::InvalidMetadata,
+                    anyhow::anyhow!("Case `{}` is invalid: {error}", case.name),
+                );
+                return None;
+            }
+        }
+
+        let remappings = match Self::resolve_remappings(&path, metadata.remappings.as_slice()) {
+            Ok(remappings) => remappings,
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
+                return None;
+            }
+        };
+
+        let mut sources = if metadata.contracts.is_empty() {
             if path.ends_with("test.json") {
                 vec![]
             } else {
@@ -124,13 +197,35 @@ impl MatterLabsTest {
         } else {
             let mut sources = HashMap::new();
             let mut paths = HashSet::with_capacity(metadata.contracts.len());
-            for (_, path_string) in metadata.contracts.iter_mut() {
+            let mut source_extensions = [era_compiler_common::EXTENSION_SOLIDITY].as_slice();
+            for (instance, contract) in metadata.contracts.iter_mut() {
+                let path_string = contract.path_mut();
                 let mut file_path = path.clone();
                 file_path.pop();
                 let mut path_string_split = path_string.split(':');
                 let file_relative_path = path_string_split.next().expect("Always exists");
                 let contract_name = path_string_split.next();
                 file_path.push(file_relative_path);
+                match file_path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                {
+                    Some(era_compiler_common::EXTENSION_VYPER) => {
+                        source_extensions = VYPER_SOURCE_EXTENSIONS.as_slice();
+                    }
+                    Some(EXTENSION_VYPER_INTERFACE) => {
+                        Summary::invalid(
+                            summary,
+                            test_description,
+                            ErrorCode::InvalidMetadata,
+                            anyhow::anyhow!(
+                                "Instance `{instance}` refers to the Vyper interface `{file_relative_path}`, which cannot be deployed"
+                            ),
+                        );
+                        return None;
+                    }
+                    _ => {}
+                }
                 *path_string = if let Some(contract_name) = contract_name {
                     format!("{}:{}", file_path.to_string_lossy(), contract_name)
                 } else {
@@ -141,13 +236,10 @@ impl MatterLabsTest {
 
             let mut test_directory_path = path.clone();
             test_directory_path.pop();
-            for entry in
-                glob::glob(format!("{}/**/*.sol", test_directory_path.to_string_lossy()).as_str())
-                    .expect("Always valid")
-                    .filter_map(Result::ok)
-            {
-                paths.insert(entry.to_string_lossy().to_string());
-            }
+            paths.extend(Self::directory_sources(
+                test_directory_path.as_path(),
+                source_extensions,
+            ));
 
             for path in paths.into_iter() {
                 let source_code = match std::fs::read_to_string(path.as_str())
@@ -155,7 +247,12 @@ impl MatterLabsTest {
                 {
                     Ok(source) => source,
                     Err(error) => {
-                        Summary::invalid(summary, test_description, error);
+                        Summary::invalid(
+                            summary,
+                            test_description,
+                            ErrorCode::InvalidMetadata,
+                            error,
+                        );
                         return None;
                     }
                 };
@@ -164,6 +261,54 @@ impl MatterLabsTest {
             sources.into_iter().collect()
         };
 
+        match Self::vendored_libraries(
+            path.as_path(),
+            sources.as_slice(),
+            metadata.remappings.as_slice(),
+        ) {
+            Ok(libraries) => {
+                sources.splice(0..0, libraries);
+            }
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
+                return None;
+            }
+        }
+
+        if let Err(error) = FeaturePassRates::validate(metadata.features.as_slice()) {
+            Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
+            return None;
+        }
+
+        let helpers = match Self::referenced_helpers(&metadata) {
+            Ok(helpers) => helpers,
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
+                return None;
+            }
+        };
+        if !helpers.is_empty() {
+            if sources.is_empty()
+                || sources.iter().any(|(path, _)| {
+                    !path
+                        .ends_with(format!(".{}", era_compiler_common::EXTENSION_SOLIDITY).as_str())
+                })
+            {
+                Summary::invalid(
+                    summary,
+                    test_description,
+                    ErrorCode::InvalidMetadata,
+                    "The built-in helper instances are only supported in Solidity tests",
+                );
+                return None;
+            }
+            sources.extend(
+                helpers
+                    .iter()
+                    .map(|helper| (helper.source_path(), helper.source_code().to_owned())),
+            );
+        }
+
         metadata.cases.retain(|case| {
             let selector_with_case = TestSelector {
                 path: selector.path.clone(),
@@ -181,14 +326,32 @@ impl MatterLabsTest {
             if !filters.check_case_path(&case_name) {
                 return false;
             }
+            if let Err(error) = selectors.register(&selector_with_case) {
+                Summary::invalid(
+                    summary.clone(),
+                    TestDescription::default_for(selector_with_case),
+                    ErrorCode::InvalidMetadata,
+                    error,
+                );
+                return false;
+            }
             true
         });
 
+        Summary::tagged(summary.clone(), metadata.tags.as_slice());
+        Summary::featured(
+            summary,
+            selector.path.as_str(),
+            metadata.features.as_slice(),
+        );
+
         Some(Self {
             path,
             selector,
             metadata,
             sources,
+            remappings,
+            helpers,
         })
     }
 
@@ -221,13 +384,35 @@ impl MatterLabsTest {
     }
 
     ///
-    /// Adds the default contract to the list of contracts if it is empty.
+    /// Returns the paths of the sources with the `extensions` in the test `directory` and its subdirectories.
     ///
-    fn push_default_contract(
-        &self,
-        contracts: &mut BTreeMap<String, String>,
-        is_multi_contract: bool,
-    ) {
+    /// Vyper interfaces are collected along with the Vyper sources, since the latter may import them.
+    ///
+    fn directory_sources(directory: &Path, extensions: &[&str]) -> HashSet<String> {
+        extensions
+            .iter()
+            .flat_map(|extension| {
+                glob::glob(format!("{}/**/*.{extension}", directory.to_string_lossy()).as_str())
+                    .expect("Always valid")
+                    .filter_map(Result::ok)
+            })
+            .map(|entry| entry.to_string_lossy().to_string())
+            .collect()
+    }
+
+    ///
+    /// Returns the contracts to deploy, with the default contract if the list is empty.
+    ///
+    /// For compilers with single-contract files, like Vyper, the contract names are stripped
+    /// from the paths, so complex tests can use the same `path:Name` notation as Solidity ones.
+    ///
+    fn contracts(&self, is_multi_contract: bool) -> BTreeMap<String, String> {
+        let mut contracts: BTreeMap<String, String> = self
+            .metadata
+            .contracts
+            .iter()
+            .map(|(instance, contract)| (instance.to_owned(), contract.path().to_owned()))
+            .collect();
         if contracts.is_empty() {
             let contract_name = if is_multi_contract {
                 format!("{}:{}", self.selector.path, SIMPLE_TESTS_CONTRACT_NAME)
@@ -235,7 +420,65 @@ impl MatterLabsTest {
                 self.selector.path.to_string()
             };
             contracts.insert(SIMPLE_TESTS_INSTANCE.to_owned(), contract_name);
+        } else if !is_multi_contract {
+            for path in contracts.values_mut() {
+                if let Some((file_path, _contract_name)) = path.split_once(':') {
+                    *path = file_path.to_owned();
+                }
+            }
+        }
+        for helper in self.helpers.iter() {
+            contracts.insert(helper.instance(), helper.contract_path());
+        }
+        contracts
+    }
+
+    ///
+    /// Returns the built-in helper instances referenced by the test metadata.
+    ///
+    /// The reserved helper instance names cannot be used by the test contracts.
+    ///
+    fn referenced_helpers(metadata: &Metadata) -> anyhow::Result<BTreeSet<Helper>> {
+        for instance in metadata
+            .contracts
+            .keys()
+            .chain(metadata.evm_contracts.keys())
+        {
+            if instance.starts_with(Helper::INSTANCE_PREFIX) {
+                anyhow::bail!(
+                    "Instance name `{instance}` is reserved for the built-in helper instances"
+                );
+            }
+        }
+
+        let mut references = BTreeSet::new();
+        for storage in metadata.instance_storage().values() {
+            for (key, value) in storage.to_map().iter() {
+                Self::collect_references(key.as_str(), &mut references);
+                Self::collect_references(value.as_str(), &mut references);
+            }
+        }
+        for case in metadata.cases.iter() {
+            if let Some(property) = case.property.as_ref() {
+                references.insert(property.instance.to_owned());
+            }
+            for input in case
+                .setup
+                .iter()
+                .chain(case.inputs.iter())
+                .chain(case.teardown.iter())
+            {
+                Self::collect_input_references(input, &mut references);
+            }
+        }
+
+        let mut helpers = BTreeSet::new();
+        for reference in references.iter() {
+            if let Some(helper) = Helper::try_from_instance(reference.as_str())? {
+                helpers.insert(helper);
+            }
         }
+        Ok(helpers)
     }
 
     ///
@@ -255,9 +498,7 @@ impl MatterLabsTest {
         let mut library_addresses = BTreeMap::new();
 
         for (file, metadata_file_libraries) in self.metadata.libraries.iter() {
-            let mut file_path = self.path.clone();
-            file_path.pop();
-            file_path.push(file);
+            let file_path = self.library_file_path(file.as_str());
 
             let mut file_libraries = BTreeMap::new();
             for name in metadata_file_libraries.keys() {
@@ -280,6 +521,142 @@ impl MatterLabsTest {
         (libraries.into(), library_addresses)
     }
 
+    ///
+    /// Returns the path of the library `file`, which is relative to the test directory.
+    ///
+    fn library_file_path(&self, file: &str) -> PathBuf {
+        let mut file_path = self.path.clone();
+        file_path.pop();
+        file_path.push(file);
+        file_path
+    }
+
+    ///
+    /// Resolves the metadata import remappings, whose context and target paths are relative to
+    /// the test directory, into the source unit names passed to the compiler.
+    ///
+    fn resolve_remappings(path: &Path, remappings: &[String]) -> anyhow::Result<BTreeSet<String>> {
+        let test_directory = path.parent().unwrap_or_else(|| Path::new(""));
+        let resolve = |relative_path: &str| {
+            if relative_path.is_empty() {
+                String::new()
+            } else {
+                format!("{}/{relative_path}", test_directory.to_string_lossy())
+            }
+        };
+
+        remappings
+            .iter()
+            .map(|remapping| {
+                let (context_prefix, target) = remapping.split_once('=').ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid remapping `{remapping}`: expected `[context:]prefix=target`"
+                    )
+                })?;
+                let (context, prefix) = match context_prefix.split_once(':') {
+                    Some((context, prefix)) => (Some(context), prefix),
+                    None => (None, context_prefix),
+                };
+                if prefix.is_empty() || target.is_empty() {
+                    anyhow::bail!("Invalid remapping `{remapping}`: empty prefix or target");
+                }
+                Ok(match context {
+                    Some(context) => format!("{}:{prefix}={}", resolve(context), resolve(target)),
+                    None => format!("{prefix}={}", resolve(target)),
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the sources in the vendored libraries directory next to the test at `path`, which
+    /// are imported by the test `sources`, directly or transitively.
+    ///
+    /// Only the imported sources are added, so the libraries shared by the tests in a directory
+    /// are not compiled with the tests which do not use them. The imports are resolved with
+    /// the test `remappings`, or relative to the importing source if they start with `./` or `../`.
+    /// The sources which are already a part of the test are skipped.
+    ///
+    fn vendored_libraries(
+        path: &Path,
+        sources: &[(String, String)],
+        remappings: &[String],
+    ) -> anyhow::Result<Vec<(String, String)>> {
+        let test_directory = path.parent().unwrap_or_else(|| Path::new(""));
+        let libraries_directory = test_directory.join(VENDORED_LIBRARIES_DIRECTORY);
+        if !libraries_directory.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let remappings: Vec<(&str, &str)> = remappings
+            .iter()
+            .filter_map(|remapping| {
+                let (context_prefix, target) = remapping.split_once('=')?;
+                let prefix = match context_prefix.split_once(':') {
+                    Some((_context, prefix)) => prefix,
+                    None => context_prefix,
+                };
+                Some((prefix, target))
+            })
+            .collect();
+
+        let mut names: HashSet<String> = sources.iter().map(|(name, _)| name.to_owned()).collect();
+        let mut queue: Vec<(PathBuf, String)> = sources
+            .iter()
+            .map(|(name, source)| (PathBuf::from(name), source.to_owned()))
+            .collect();
+        let mut libraries = Vec::new();
+        while let Some((importing_path, source)) = queue.pop() {
+            for import in solidity_adapter::imports(source.as_str()) {
+                let import_path = if import.starts_with("./") || import.starts_with("../") {
+                    importing_path
+                        .parent()
+                        .unwrap_or_else(|| Path::new(""))
+                        .join(import)
+                } else {
+                    match remappings
+                        .iter()
+                        .filter(|(prefix, _)| import.starts_with(prefix))
+                        .max_by_key(|(prefix, _)| prefix.len())
+                    {
+                        Some((prefix, target)) => {
+                            test_directory.join(format!("{target}{}", &import[prefix.len()..]))
+                        }
+                        None => test_directory.join(import),
+                    }
+                };
+
+                let mut normalized_path = PathBuf::new();
+                for component in import_path.components() {
+                    match component {
+                        std::path::Component::CurDir => {}
+                        std::path::Component::ParentDir => {
+                            normalized_path.pop();
+                        }
+                        component => normalized_path.push(component),
+                    }
+                }
+                let name = normalized_path.to_string_lossy().to_string();
+                if !normalized_path.starts_with(libraries_directory.as_path())
+                    || !normalized_path.is_file()
+                    || names.contains(&name)
+                {
+                    continue;
+                }
+
+                let source =
+                    std::fs::read_to_string(normalized_path.as_path()).map_err(|error| {
+                        anyhow::anyhow!("Vendored library `{name}` reading error: {error}")
+                    })?;
+                names.insert(name.clone());
+                queue.push((normalized_path, source.clone()));
+                libraries.push((name, source));
+            }
+        }
+        libraries.sort();
+        Ok(libraries)
+    }
+
     ///
     /// Returns precompiled EVM contract instances.
     ///
@@ -343,11 +720,17 @@ impl MatterLabsTest {
                     MatterLabsCaseInput {
                         comment: None,
                         instance: before.to_owned(),
-                        caller: default_caller_address(),
+                        caller: None,
                         method: "#fallback".to_owned(),
                         calldata: MatterLabsCaseInputCalldata::List(vec![]),
                         value: None,
+                        call_kind: CallKind::default(),
+                        evm_gas_limit: None,
+                        evm_context: None,
                         storage: HashMap::new(),
+                        access_list: Vec::new(),
+                        register: None,
+                        system_mode: None,
                         expected: Some(
                             MatterLabsCaseInputExpected::successful_evm_interpreter_benchmark(
                                 false,
@@ -355,15 +738,27 @@ impl MatterLabsTest {
                         ),
                         expected_eravm: None,
                         expected_evm: None,
+
+                        expected_immutables: None,
+                        expected_immutables_eravm: None,
+                        expected_immutables_evm: None,
+                        expected_balances: BTreeMap::new(),
+                        fixture: None,
                     },
                     MatterLabsCaseInput {
                         comment: None,
                         instance: template.to_owned(),
-                        caller: default_caller_address(),
+                        caller: None,
                         method: "#fallback".to_owned(),
                         calldata: MatterLabsCaseInputCalldata::List(vec![]),
                         value: None,
+                        call_kind: CallKind::default(),
+                        evm_gas_limit: None,
+                        evm_context: None,
                         storage: HashMap::new(),
+                        access_list: Vec::new(),
+                        register: None,
+                        system_mode: None,
                         expected: Some(
                             MatterLabsCaseInputExpected::successful_evm_interpreter_benchmark(
                                 false,
@@ -371,15 +766,27 @@ impl MatterLabsTest {
                         ),
                         expected_eravm: None,
                         expected_evm: None,
+
+                        expected_immutables: None,
+                        expected_immutables_eravm: None,
+                        expected_immutables_evm: None,
+                        expected_balances: BTreeMap::new(),
+                        fixture: None,
                     },
                     MatterLabsCaseInput {
                         comment: None,
                         instance: full.to_owned(),
-                        caller: default_caller_address(),
+                        caller: None,
                         method: "#fallback".to_owned(),
                         calldata: MatterLabsCaseInputCalldata::List(vec![]),
                         value: None,
+                        call_kind: CallKind::default(),
+                        evm_gas_limit: None,
+                        evm_context: None,
                         storage: HashMap::new(),
+                        access_list: Vec::new(),
+                        register: None,
+                        system_mode: None,
                         expected: Some(
                             MatterLabsCaseInputExpected::successful_evm_interpreter_benchmark(
                                 exception,
@@ -387,10 +794,22 @@ impl MatterLabsTest {
                         ),
                         expected_eravm: None,
                         expected_evm: None,
+
+                        expected_immutables: None,
+                        expected_immutables_eravm: None,
+                        expected_immutables_evm: None,
+                        expected_balances: BTreeMap::new(),
+                        fixture: None,
                     },
                 ],
+                property: None,
+                setup: vec![],
+                teardown: vec![],
                 ignore: false,
                 cycles: None,
+                deployer: None,
+                system_mode: None,
+                vm_state: VMState::default(),
                 expected: Some(
                     MatterLabsCaseInputExpected::successful_evm_interpreter_benchmark(exception),
                 ),
@@ -400,9 +819,147 @@ impl MatterLabsTest {
         }
         metadata_cases
     }
+
+    ///
+    /// Applies the suppressed `zksolc` errors and warnings of the metadata to the `mode`.
+    ///
+    fn suppress(&self, mode: &mut Mode) {
+        mode.suppress(
+            self.metadata.suppressed_errors.as_deref(),
+            self.metadata.suppressed_warnings.as_deref(),
+        );
+    }
+
+    ///
+    /// Whether the metadata expects the compilation errors or warnings.
+    ///
+    fn has_compilation_expectations(&self) -> bool {
+        self.metadata.expected_compilation_error.is_some()
+            || self.metadata.expected_compilation_warnings.is_some()
+    }
+
+    ///
+    /// Skips the test if it expects the compilation errors or warnings, which depend on the
+    /// `zksolc` suppressions, and the `mode` does not support them, e.g. with upstream `solc`.
+    ///
+    fn check_compilation_expectations(
+        &self,
+        summary: Arc<Mutex<Summary>>,
+        test_description: &TestDescription,
+        mode: &Mode,
+    ) -> Option<()> {
+        if self.has_compilation_expectations() && !mode.supports_suppressions() {
+            Summary::skipped(
+                summary,
+                test_description.to_owned(),
+                "The compilation expectations only apply to the `zksolc` modes",
+            );
+            return None;
+        }
+        Some(())
+    }
+
+    ///
+    /// Reports a compilation error.
+    ///
+    /// The test passes if its metadata expects the error. The `stack too deep` errors of the EVM
+    /// legacy assembly codegen are reported as expected failures if the test metadata allows them.
+    ///
+    fn compilation_error(
+        &self,
+        summary: Arc<Mutex<Summary>>,
+        test_description: TestDescription,
+        mode: &Mode,
+        error: anyhow::Error,
+    ) {
+        if let Some(expected) = self.metadata.expected_compilation_error.as_deref() {
+            if error.to_string().contains(expected) {
+                Summary::passed_special(summary, test_description);
+            } else {
+                Summary::invalid(
+                    summary,
+                    test_description,
+                    ErrorCode::CompilationFailed,
+                    anyhow::anyhow!(
+                        "Expected the compilation error containing `{expected}`, found:\n{error}"
+                    ),
+                );
+            }
+        } else if self.metadata.stack_too_deep_ok
+            && mode.is_evmla()
+            && error.to_string().contains(STACK_TOO_DEEP_ERROR)
+        {
+            Summary::expected_failure(
+                summary,
+                test_description,
+                ErrorCode::CompilationFailed,
+                error,
+            );
+        } else {
+            Summary::invalid(
+                summary,
+                test_description,
+                ErrorCode::CompilationFailed,
+                error,
+            );
+        }
+    }
+
+    ///
+    /// Checks the successful compilation against the compilation expectations of the metadata.
+    ///
+    /// The test passes if the `warnings` match the expected ones, and is reported as
+    /// an unexpected pass if it expects an error, or the warnings differ.
+    ///
+    fn compilation_success(
+        &self,
+        summary: Arc<Mutex<Summary>>,
+        test_description: TestDescription,
+        warnings: &[String],
+    ) -> Option<()> {
+        if !self.has_compilation_expectations() {
+            return Some(());
+        }
+
+        let is_matching = self.metadata.expected_compilation_error.is_none()
+            && self
+                .metadata
+                .expected_compilation_warnings
+                .as_deref()
+                .is_some_and(|expected| Self::check_warnings(expected, warnings));
+        if is_matching {
+            Summary::passed_special(summary, test_description);
+        } else {
+            Summary::unexpected_pass(summary, test_description);
+        }
+        None
+    }
+
+    ///
+    /// Checks if each of the `warnings` contains its own `expected` substring.
+    ///
+    fn check_warnings(expected: &[String], warnings: &[String]) -> bool {
+        let mut warnings: Vec<&String> = warnings.iter().collect();
+        for expected in expected.iter() {
+            match warnings
+                .iter()
+                .position(|warning| warning.contains(expected.as_str()))
+            {
+                Some(position) => {
+                    warnings.remove(position);
+                }
+                None => return false,
+            }
+        }
+        warnings.is_empty()
+    }
 }
 
 impl Buildable for MatterLabsTest {
+    fn selector(&self) -> &TestSelector {
+        &self.selector
+    }
+
     fn build_for_eravm(
         &self,
         mut mode: Mode,
@@ -413,10 +970,10 @@ impl Buildable for MatterLabsTest {
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> Option<Test> {
         mode.enable_eravm_extensions(self.metadata.enable_eravm_extensions);
+        self.suppress(&mut mode);
         self.check_filters(filters, &mode, era_compiler_common::Target::EraVM)?;
 
-        let mut contracts = self.metadata.contracts.clone();
-        self.push_default_contract(&mut contracts, compiler.allows_multi_contract_files());
+        let contracts = self.contracts(compiler.allows_multi_contract_files());
 
         let mut eravm_address_iterator = EraVMAddressIterator::new();
         let evm_address_iterator = EVMAddressIterator::default();
@@ -426,25 +983,56 @@ impl Buildable for MatterLabsTest {
             mode: Some(mode.clone()),
             selector: self.selector.clone(),
         };
+        self.check_compilation_expectations(summary.clone(), &test_description, &mode)?;
 
         let (libraries, library_addresses) = self.get_libraries(&mut eravm_address_iterator);
+        let debug_config = match crate::utils::debug_config_for_test(
+            debug_config.as_ref(),
+            self.selector.path.as_str(),
+        ) {
+            Ok(debug_config) => debug_config,
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        };
         let eravm_input = match compiler
             .compile_for_eravm(
                 self.selector.path.to_string(),
                 self.sources.clone(),
                 libraries,
+                self.remappings.clone(),
                 &mode,
-                vec![],
-                debug_config,
+                LLVMOptions::get().extra(),
+                debug_config.clone(),
             )
             .map_err(|error| anyhow::anyhow!("Failed to compile sources:\n{error}"))
         {
             Ok(vm_input) => vm_input,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                self.compilation_error(summary, test_description, &mode, error);
                 return None;
             }
         };
+        self.compilation_success(
+            summary.clone(),
+            test_description.clone(),
+            eravm_input.warnings.as_slice(),
+        )?;
+
+        if let Some(debug_config) = debug_config.as_ref() {
+            if let Err(error) = eravm_input.write_debug_artifacts(debug_config) {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        }
+        Summary::record_interfaces(
+            summary.clone(),
+            self.selector.path.as_str(),
+            eravm_input.method_identifiers.as_ref(),
+            eravm_input.abis.as_ref(),
+        );
+        let custom_errors = RevertReason::custom_errors(eravm_input.abis.as_ref());
 
         let mut instances = match eravm_input.get_instances(
             &contracts,
@@ -453,7 +1041,7 @@ impl Buildable for MatterLabsTest {
         ) {
             Ok(instances) => instances,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
@@ -461,7 +1049,7 @@ impl Buildable for MatterLabsTest {
         let evm_instances = match self.get_evm_instances() {
             Ok(evm_instances) => evm_instances,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
@@ -471,6 +1059,9 @@ impl Buildable for MatterLabsTest {
         metadata_cases.extend(self.evm_interpreter_benchmark_cases());
 
         let mut cases = Vec::with_capacity(metadata_cases.len());
+        let mut registered_instances = BTreeSet::new();
+        let mut case_eravm_address_iterator = eravm_address_iterator.clone();
+        let mut case_evm_address_iterator = evm_address_iterator.clone();
         for case in metadata_cases.into_iter() {
             if let Some(filters) = case.modes.as_ref() {
                 if !mode.check_extended_filters(filters.as_slice()) {
@@ -478,47 +1069,61 @@ impl Buildable for MatterLabsTest {
                 }
             }
 
-            let case = match case.normalize(&contracts, &instances, environment) {
+            let case = match case.normalize(
+                &contracts,
+                &self.metadata.instance_storage(),
+                &instances,
+                environment,
+                &mode,
+            ) {
                 Ok(case) => case,
                 Err(error) => {
-                    Summary::invalid(summary, test_description, error);
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                     return None;
                 }
             };
 
+            if case.vm_state == VMState::Fresh {
+                case_eravm_address_iterator = eravm_address_iterator.clone();
+                case_evm_address_iterator = evm_address_iterator.clone();
+            }
             match case.set_variables(
                 &mut instances,
-                eravm_address_iterator.clone(),
-                evm_address_iterator.clone(),
+                &mut case_eravm_address_iterator,
+                &mut case_evm_address_iterator,
                 &mode,
             ) {
                 Ok(_) => {}
                 Err(error) => {
-                    Summary::invalid(summary, test_description, error);
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                     return None;
                 }
             }
 
             let case_name = case.name.to_owned();
+            let runner_overrides = case.runner_overrides().or(self.metadata.runner_overrides());
             let case = match Case::try_from_matter_labs(
                 case,
                 &mode,
                 &instances,
+                &mut registered_instances,
                 &eravm_input.method_identifiers,
+                &custom_errors,
                 era_compiler_common::Target::EraVM,
             )
             .map_err(|error| anyhow::anyhow!("Case `{}` is invalid: {}", case_name, error))
             {
                 Ok(case) => case,
                 Err(error) => {
-                    Summary::invalid(summary, test_description, error);
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                     return None;
                 }
             };
 
-            cases.push(case);
+            cases.push(case.with_runner_overrides(runner_overrides));
         }
 
+        let function_sizes = eravm_input.function_sizes();
         let builds = eravm_input
             .builds
             .into_values()
@@ -538,23 +1143,25 @@ impl Buildable for MatterLabsTest {
             mode,
             self.metadata.group.clone(),
             builds,
+            function_sizes,
             HashMap::new(),
             None,
+            !self.metadata.disable_evm_bytecode_prepublication,
         ))
     }
 
     fn build_for_evm(
         &self,
-        mode: Mode,
+        mut mode: Mode,
         compiler: Arc<dyn Compiler>,
         environment: Environment,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> Option<Test> {
+        self.suppress(&mut mode);
         self.check_filters(filters, &mode, era_compiler_common::Target::EVM)?;
-        let mut contracts = self.metadata.contracts.clone();
-        self.push_default_contract(&mut contracts, compiler.allows_multi_contract_files());
+        let contracts = self.contracts(compiler.allows_multi_contract_files());
         let sources = self.sources.to_owned();
 
         let mut evm_address_iterator = EVMAddressIterator::default();
@@ -566,35 +1173,68 @@ impl Buildable for MatterLabsTest {
             mode: Some(mode.clone()),
             selector: self.selector.clone(),
         };
+        self.check_compilation_expectations(summary.clone(), &test_description, &mode)?;
 
+        let debug_config = match crate::utils::debug_config_for_test(
+            debug_config.as_ref(),
+            self.selector.path.as_str(),
+        ) {
+            Ok(debug_config) => debug_config,
+            Err(error) => {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        };
         let evm_input = match compiler
             .compile_for_evm(
                 self.selector.path.to_string(),
                 sources,
                 libraries,
+                self.remappings.clone(),
                 &mode,
                 None,
-                vec![],
-                debug_config,
+                LLVMOptions::get().extra(),
+                debug_config.clone(),
             )
             .map_err(|error| anyhow::anyhow!("Failed to compile sources:\n{error}"))
         {
             Ok(output) => output,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                self.compilation_error(summary, test_description, &mode, error);
                 return None;
             }
         };
+        self.compilation_success(
+            summary.clone(),
+            test_description.clone(),
+            evm_input.warnings.as_slice(),
+        )?;
+
+        if let Some(debug_config) = debug_config.as_ref() {
+            if let Err(error) = evm_input.write_debug_artifacts(debug_config) {
+                Summary::invalid(summary, test_description, ErrorCode::InternalError, error);
+                return None;
+            }
+        }
+        Summary::record_interfaces(
+            summary.clone(),
+            self.selector.path.as_str(),
+            evm_input.method_identifiers.as_ref(),
+            evm_input.abis.as_ref(),
+        );
+        let custom_errors = RevertReason::custom_errors(evm_input.abis.as_ref());
 
         let mut instances = match evm_input.get_instances(&contracts, library_addresses, None) {
             Ok(instances) => instances,
             Err(error) => {
-                Summary::invalid(summary, test_description, error);
+                Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                 return None;
             }
         };
 
         let mut cases = Vec::with_capacity(self.metadata.cases.len());
+        let mut registered_instances = BTreeSet::new();
+        let mut case_evm_address_iterator = evm_address_iterator.clone();
         for case in self.metadata.cases.iter() {
             if let Some(filters) = case.modes.as_ref() {
                 if !mode.check_extended_filters(filters.as_slice()) {
@@ -602,48 +1242,57 @@ impl Buildable for MatterLabsTest {
                 }
             }
 
-            let case = match case
-                .to_owned()
-                .normalize(&contracts, &instances, environment)
-            {
+            let case = match case.to_owned().normalize(
+                &contracts,
+                &self.metadata.instance_storage(),
+                &instances,
+                environment,
+                &mode,
+            ) {
                 Ok(case) => case,
                 Err(error) => {
-                    Summary::invalid(summary, test_description, error);
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                     return None;
                 }
             };
 
+            if case.vm_state == VMState::Fresh {
+                case_evm_address_iterator = evm_address_iterator.clone();
+            }
             match case.set_variables(
                 &mut instances,
-                EraVMAddressIterator::new(),
-                evm_address_iterator.clone(),
+                &mut EraVMAddressIterator::new(),
+                &mut case_evm_address_iterator,
                 &mode,
             ) {
                 Ok(_) => {}
                 Err(error) => {
-                    Summary::invalid(summary, test_description, error);
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                     return None;
                 }
             }
 
             let case_name = case.name.to_owned();
+            let runner_overrides = case.runner_overrides().or(self.metadata.runner_overrides());
             let case = match Case::try_from_matter_labs(
                 case,
                 &mode,
                 &instances,
+                &mut registered_instances,
                 &evm_input.method_identifiers,
+                &custom_errors,
                 era_compiler_common::Target::EVM,
             )
             .map_err(|error| anyhow::anyhow!("Case `{}` is invalid: {}", case_name, error))
             {
                 Ok(case) => case,
                 Err(error) => {
-                    Summary::invalid(summary, test_description, error);
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
                     return None;
                 }
             };
 
-            cases.push(case);
+            cases.push(case.with_runner_overrides(runner_overrides));
         }
 
         Some(Test::new(
@@ -652,8 +1301,688 @@ impl Buildable for MatterLabsTest {
             mode,
             self.metadata.group.clone(),
             HashMap::new(),
+            HashMap::new(),
             evm_input.builds,
             None,
+            !self.metadata.disable_evm_bytecode_prepublication,
         ))
     }
 }
+
+impl Documented for MatterLabsTest {
+    fn docs_entry(&self) -> DocsEntry {
+        let cases = self
+            .metadata
+            .cases
+            .iter()
+            .map(|case| {
+                DocsCase::new(
+                    case.name.to_owned(),
+                    case.comment.clone(),
+                    case.inputs
+                        .iter()
+                        .filter_map(|input| input.comment.clone())
+                        .collect(),
+                )
+            })
+            .collect();
+
+        DocsEntry::new(
+            self.selector.path.to_owned(),
+            self.metadata.group.clone(),
+            vec![],
+            cases,
+        )
+    }
+}
+
+impl Linted for MatterLabsTest {
+    fn lint(
+        &self,
+        target: era_compiler_common::Target,
+        compiler: &dyn Compiler,
+        interfaces: Option<&BTreeMap<(String, String), Interface>>,
+    ) -> Vec<LintWarning> {
+        let path = self.selector.path.as_str();
+        let mut warnings = Vec::new();
+
+        let contracts = self.contracts(compiler.allows_multi_contract_files());
+        let mut defined: BTreeSet<String> = contracts.keys().cloned().collect();
+        defined.extend(self.metadata.evm_contracts.keys().cloned());
+
+        let mut referenced = BTreeSet::new();
+        for storage in self.metadata.instance_storage().values() {
+            for (key, value) in storage.to_map().iter() {
+                Self::collect_references(key.as_str(), &mut referenced);
+                Self::collect_references(value.as_str(), &mut referenced);
+            }
+        }
+        for reference in referenced.iter() {
+            if !defined.contains(reference) {
+                warnings.push(LintWarning::new(
+                    path.to_owned(),
+                    None,
+                    LintKind::UndefinedInstance,
+                    format!("the contract storage references undefined instance `{reference}`"),
+                ));
+            }
+        }
+
+        for case in self.metadata.cases.iter() {
+            let mut case_referenced = BTreeSet::new();
+            if let Some(property) = case.property.as_ref() {
+                case_referenced.insert(property.instance.to_owned());
+            }
+            for input in case
+                .setup
+                .iter()
+                .chain(case.inputs.iter())
+                .chain(case.teardown.iter())
+            {
+                Self::collect_input_references(input, &mut case_referenced);
+
+                if let Some(interfaces) = interfaces {
+                    if let Some(warning) =
+                        self.lint_word_count(case.name.as_str(), input, &contracts, interfaces)
+                    {
+                        warnings.push(warning);
+                    }
+                }
+            }
+            for reference in case_referenced.iter() {
+                if !defined.contains(reference) {
+                    warnings.push(LintWarning::new(
+                        path.to_owned(),
+                        Some(case.name.to_owned()),
+                        LintKind::UndefinedInstance,
+                        format!("references undefined instance `{reference}`"),
+                    ));
+                }
+            }
+            referenced.extend(case_referenced);
+        }
+        referenced.extend(self.implicitly_used_instances());
+        for instance in defined.difference(&referenced) {
+            warnings.push(LintWarning::new(
+                path.to_owned(),
+                None,
+                LintKind::UnusedInstance,
+                format!("instance `{instance}` is never referenced by any case"),
+            ));
+        }
+
+        if self
+            .metadata
+            .targets
+            .as_ref()
+            .map_or(true, |targets| targets.contains(&target))
+        {
+            let modes = compiler.all_modes();
+            let mode_filters = self
+                .metadata
+                .modes
+                .iter()
+                .flatten()
+                .map(|filter| (None, filter))
+                .chain(self.metadata.cases.iter().flat_map(|case| {
+                    case.modes
+                        .iter()
+                        .flatten()
+                        .map(|filter| (Some(case.name.to_owned()), filter))
+                }));
+            for (case, filter) in mode_filters {
+                if !modes
+                    .iter()
+                    .any(|mode| mode.check_extended_filters(std::slice::from_ref(filter)))
+                {
+                    warnings.push(LintWarning::new(
+                        path.to_owned(),
+                        case,
+                        LintKind::UnmatchedModeFilter,
+                        format!("mode filter `{filter}` matches no mode generated for `{target}`"),
+                    ));
+                }
+            }
+        }
+
+        warnings
+    }
+}
+
+impl MatterLabsTest {
+    ///
+    /// Collects the instances referenced by the `input` into `references`.
+    ///
+    fn collect_input_references(input: &MatterLabsCaseInput, references: &mut BTreeSet<String>) {
+        references.insert(input.instance.to_owned());
+        if let Some(instance) = input.register.as_ref() {
+            references.insert(instance.to_owned());
+        }
+        for item in input.access_list.iter() {
+            Self::collect_references(item.address.as_str(), references);
+            for key in item.storage_keys.iter() {
+                Self::collect_references(key.as_str(), references);
+            }
+        }
+        for (address, storage) in input.storage.iter() {
+            Self::collect_references(address.as_str(), references);
+            for (key, value) in storage.to_map().iter() {
+                Self::collect_references(key.as_str(), references);
+                Self::collect_references(value.as_str(), references);
+            }
+        }
+        for (address, balance) in input.expected_balances.iter() {
+            Self::collect_references(address.as_str(), references);
+            Self::collect_references(balance.as_str(), references);
+        }
+
+        match &input.calldata {
+            MatterLabsCaseInputCalldata::Value(value) => {
+                Self::collect_references(value.as_str(), references)
+            }
+            MatterLabsCaseInputCalldata::List(values) => {
+                for value in values.iter() {
+                    Self::collect_references(value.as_str(), references);
+                }
+            }
+            MatterLabsCaseInputCalldata::File(_) => {}
+            MatterLabsCaseInputCalldata::Structured { values, .. } => {
+                let mut values: Vec<&serde_json::Value> = values.iter().collect();
+                while let Some(value) = values.pop() {
+                    match value {
+                        serde_json::Value::String(value) => {
+                            Self::collect_references(value.as_str(), references)
+                        }
+                        serde_json::Value::Array(inner) => values.extend(inner.iter()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for expected in [
+            input.expected.as_ref(),
+            input.expected_eravm.as_ref(),
+            input.expected_evm.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for variant in Self::lint_variants(expected) {
+                match variant {
+                    MatterLabsCaseInputExpectedVariant::Simple(values) => {
+                        for value in values.iter() {
+                            Self::collect_references(value.as_str(), references);
+                        }
+                    }
+                    MatterLabsCaseInputExpectedVariant::File(_) => {}
+                    MatterLabsCaseInputExpectedVariant::Extended(extended) => {
+                        for value in extended.return_data.iter() {
+                            Self::collect_references(value.as_str(), references);
+                        }
+                        for event in extended.events.iter() {
+                            for value in event
+                                .address
+                                .iter()
+                                .chain(event.topics.iter())
+                                .chain(event.values.iter())
+                            {
+                                Self::collect_references(value.as_str(), references);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for immutables in [
+            input.expected_immutables.as_ref(),
+            input.expected_immutables_eravm.as_ref(),
+            input.expected_immutables_evm.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for value in immutables.values() {
+                Self::collect_references(value.as_str(), references);
+            }
+        }
+    }
+
+    ///
+    /// Collects the instances referenced by the metadata `value` into `references`, that is
+    /// the instance addresses and the `#create2` deployers and code hashes.
+    ///
+    fn collect_references(value: &str, references: &mut BTreeSet<String>) {
+        references.extend(
+            Value::referenced_instances(value)
+                .into_iter()
+                .map(str::to_owned),
+        );
+    }
+
+    ///
+    /// Returns the instances which are used without being referenced by the cases, that is
+    /// the linked libraries and the built-in helpers.
+    ///
+    fn implicitly_used_instances(&self) -> BTreeSet<String> {
+        let mut library_paths = BTreeSet::new();
+        for (file, names) in self.metadata.libraries.iter() {
+            let file_path = self.library_file_path(file.as_str());
+            for name in names.keys() {
+                library_paths.insert(format!("{}:{name}", file_path.to_string_lossy()));
+            }
+        }
+
+        self.metadata
+            .contracts
+            .iter()
+            .filter(|(_, contract)| library_paths.contains(contract.path()))
+            .map(|(instance, _)| instance.to_owned())
+            .chain(self.helpers.iter().map(Helper::instance))
+            .collect()
+    }
+
+    ///
+    /// Checks the expected return data word count of the `input` against the method ABI outputs.
+    ///
+    /// The expectations of reverts and with a return data size assertion are not checked.
+    ///
+    fn lint_word_count(
+        &self,
+        case: &str,
+        input: &MatterLabsCaseInput,
+        contracts: &BTreeMap<String, String>,
+        interfaces: &BTreeMap<(String, String), Interface>,
+    ) -> Option<LintWarning> {
+        if input.method.starts_with('#') {
+            return None;
+        }
+        let contract = contracts.get(input.instance.as_str())?;
+        let abi = interfaces
+            .get(&(self.selector.path.to_owned(), contract.to_owned()))?
+            .abi
+            .as_ref()?;
+        let output_words = Lint::output_words(abi, input.method.as_str())?;
+
+        for expected in [
+            input.expected.as_ref(),
+            input.expected_eravm.as_ref(),
+            input.expected_evm.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for variant in Self::lint_variants(expected) {
+                let expected_words = match variant {
+                    MatterLabsCaseInputExpectedVariant::Simple(values) => values.len(),
+                    MatterLabsCaseInputExpectedVariant::File(_) => continue,
+                    MatterLabsCaseInputExpectedVariant::Extended(extended)
+                        if !extended.exception
+                            && extended.revert_reason.is_none()
+                            && extended.return_data_size.is_none()
+                            && extended.created_address.is_none() =>
+                    {
+                        extended.return_data.len()
+                    }
+                    MatterLabsCaseInputExpectedVariant::Extended(_) => continue,
+                };
+                if expected_words != output_words {
+                    return Some(LintWarning::new(
+                        self.selector.path.to_owned(),
+                        Some(case.to_owned()),
+                        LintKind::WordCount,
+                        format!(
+                            "`{}` returns {output_words} words, but {expected_words} are expected",
+                            input.method
+                        ),
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    ///
+    /// Returns the variants of the `expected` data.
+    ///
+    fn lint_variants(
+        expected: &MatterLabsCaseInputExpected,
+    ) -> Vec<&MatterLabsCaseInputExpectedVariant> {
+        match expected {
+            MatterLabsCaseInputExpected::Single(variant) => vec![variant],
+            MatterLabsCaseInputExpected::Multiple(variants) => variants.iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::compilers::eravm::EraVMCompiler;
+    use crate::directories::matter_labs::defaults::Defaults;
+    use crate::filters::Filters;
+    use crate::lint::kind::Kind as LintKind;
+    use crate::lint::Linted;
+    use crate::summary::element::outcome::Outcome;
+    use crate::summary::Summary;
+    use crate::test::selector::registry::Registry as SelectorRegistry;
+
+    use super::helper::Helper;
+    use super::MatterLabsTest;
+
+    const SOURCE: &str = r##"//! {
+//!     "cases": [{
+//!         "name": "main",
+//!         "inputs": [{
+//!             "instance": "Main",
+//!             "method": "create",
+//!             "calldata": [],
+//!             "expected": ["#create2(Factory, Salt.address, Child)"]
+//!         }]
+//!     }],
+//!     "contracts": {
+//!         "Main": "Test.sol:Main",
+//!         "Factory": "Test.sol:Factory",
+//!         "Salt": "Test.sol:Salt",
+//!         "Child": "Test.sol:Child",
+//!         "Library": "Test.sol:Library",
+//!         "Unused": "Test.sol:Unused"
+//!     },
+//!     "libraries": {
+//!         "Test.sol": { "Library": "Library" }
+//!     }
+//! }
+"##;
+
+    #[test]
+    fn check_warnings() {
+        let warnings = vec![
+            "Warning: You are checking for 'tx.origin' in your code".to_owned(),
+            "Warning: Unused local variable.".to_owned(),
+        ];
+        for (expected, is_matching) in [
+            (vec!["tx.origin", "Unused"], true),
+            (vec!["Unused", "tx.origin"], true),
+            (vec!["tx.origin"], false),
+            (vec!["tx.origin", "tx.origin"], false),
+            (vec![], false),
+        ] {
+            let expected: Vec<String> = expected.into_iter().map(str::to_owned).collect();
+            assert_eq!(
+                MatterLabsTest::check_warnings(expected.as_slice(), warnings.as_slice()),
+                is_matching,
+                "{expected:?}"
+            );
+        }
+        assert!(MatterLabsTest::check_warnings(&[], &[]));
+    }
+
+    #[test]
+    fn suppression_samples() {
+        let directory = std::path::Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/suppressions"
+        ));
+        for (name, errors, warnings) in [
+            ("send_transfer.sol", Some(0), None),
+            ("tx_origin.sol", None, None),
+            ("tx_origin_suppressed.sol", None, Some(1)),
+        ] {
+            let summary = Summary::new(false, true).wrap();
+            let test = MatterLabsTest::new(
+                directory.join(name),
+                summary.clone(),
+                &Filters::default(),
+                &SelectorRegistry::default(),
+                &Defaults::default(),
+            )
+            .expect("Always valid");
+            assert!(test.has_compilation_expectations(), "{name}");
+            assert_eq!(
+                test.metadata.suppressed_errors.as_ref().map(Vec::len),
+                errors,
+                "{name}"
+            );
+            assert_eq!(
+                test.metadata.suppressed_warnings.as_ref().map(Vec::len),
+                warnings,
+                "{name}"
+            );
+            assert!(Summary::unwrap_arc(summary).elements().is_empty(), "{name}");
+        }
+    }
+
+    #[test]
+    fn helper_sample() {
+        let path = std::path::Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/helpers/reentrancy.sol"
+        ));
+        let summary = Summary::new(false, true).wrap();
+        let test = MatterLabsTest::new(
+            path.to_path_buf(),
+            summary.clone(),
+            &Filters::default(),
+            &SelectorRegistry::default(),
+            &Defaults::default(),
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            test.helpers.iter().copied().collect::<Vec<Helper>>(),
+            vec![Helper::Attacker]
+        );
+        assert!(test
+            .sources
+            .iter()
+            .any(|(path, _)| path == Helper::Attacker.source_path().as_str()));
+        assert_eq!(
+            test.contracts(true).get("$Attacker"),
+            Some(&Helper::Attacker.contract_path())
+        );
+        assert!(Summary::unwrap_arc(summary).elements().is_empty());
+    }
+
+    #[test]
+    fn helper_reserved_instance() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-matter-labs-helpers-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let path = directory.join("Test.sol");
+        std::fs::write(
+            path.as_path(),
+            r#"//! { "cases": [], "contracts": { "$Proxy": "Test.sol:Test" } }
+contract Test {}
+"#,
+        )
+        .expect("Always valid");
+
+        let summary = Summary::new(false, true).wrap();
+        let test = MatterLabsTest::new(
+            path,
+            summary.clone(),
+            &Filters::default(),
+            &SelectorRegistry::default(),
+            &Defaults::default(),
+        );
+        let _ = std::fs::remove_dir_all(directory.as_path());
+
+        assert!(test.is_none());
+        let summary = Summary::unwrap_arc(summary);
+        assert!(
+            matches!(
+                summary.elements(),
+                [element] if matches!(
+                    element.outcome,
+                    Outcome::Invalid { ref error, .. } if error.contains("reserved for the built-in helper")
+                )
+            ),
+            "{:?}",
+            summary.elements()
+        );
+    }
+
+    #[test]
+    fn lint_unused_instances() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-matter-labs-lint-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let path = directory.join("Test.sol");
+        std::fs::write(path.as_path(), SOURCE).expect("Always valid");
+
+        let test = MatterLabsTest::new(
+            path,
+            Summary::new(false, true).wrap(),
+            &Filters::default(),
+            &SelectorRegistry::default(),
+            &Defaults::default(),
+        );
+        let _ = std::fs::remove_dir_all(directory.as_path());
+        let test = test.expect("Always valid");
+
+        let unused: Vec<String> = test
+            .lint(era_compiler_common::Target::EraVM, &EraVMCompiler, None)
+            .into_iter()
+            .filter(|warning| warning.kind == LintKind::UnusedInstance)
+            .map(|warning| warning.message)
+            .collect();
+        assert_eq!(
+            unused,
+            vec!["instance `Unused` is never referenced by any case".to_owned()]
+        );
+    }
+
+    #[test]
+    fn vyper_sources() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-matter-labs-vyper-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.join("interfaces")).expect("Always valid");
+        for (name, source) in [
+            ("main.vy", "import interfaces.IToken as IToken\n"),
+            ("token.vy", "implements: IToken\n"),
+            (
+                "interfaces/IToken.vyi",
+                "def transfer(to: address, amount: uint256): ...\n",
+            ),
+            ("unrelated.sol", "contract Unrelated {}\n"),
+        ] {
+            std::fs::write(directory.join(name), source).expect("Always valid");
+        }
+
+        for (contracts, expected) in [
+            (
+                r#"{ "Main": "main.vy", "Token": "token.vy:Token" }"#,
+                Ok(vec!["interfaces/IToken.vyi", "main.vy", "token.vy"]),
+            ),
+            (
+                r#"{ "Main": "main.vy", "Token": "interfaces/IToken.vyi" }"#,
+                Err("Instance `Token` refers to the Vyper interface `interfaces/IToken.vyi`, which cannot be deployed"),
+            ),
+        ] {
+            let path = directory.join("test.json");
+            std::fs::write(
+                path.as_path(),
+                format!(r#"{{ "cases": [], "contracts": {contracts} }}"#),
+            )
+            .expect("Always valid");
+
+            let summary = Summary::new(false, true).wrap();
+            let test = MatterLabsTest::new(
+                path,
+                summary.clone(),
+                &Filters::default(),
+                &SelectorRegistry::default(),
+                &Defaults::default(),
+            );
+            match expected {
+                Ok(expected) => {
+                    let mut sources: Vec<String> = test
+                        .expect("Always valid")
+                        .sources
+                        .into_iter()
+                        .map(|(path, _)| {
+                            Path::new(path.as_str())
+                                .strip_prefix(directory.as_path())
+                                .expect("Always valid")
+                                .to_string_lossy()
+                                .to_string()
+                        })
+                        .collect();
+                    sources.sort();
+                    assert_eq!(sources, expected, "{contracts}");
+                }
+                Err(expected) => {
+                    assert!(test.is_none(), "{contracts}");
+                    let summary = Summary::unwrap_arc(summary);
+                    let elements = summary.elements();
+                    assert_eq!(elements.len(), 1, "{contracts}");
+                    assert!(
+                        format!("{:?}", elements[0]).contains(expected),
+                        "{contracts}: {:?}",
+                        elements[0]
+                    );
+                }
+            }
+        }
+        let _ = std::fs::remove_dir_all(directory.as_path());
+    }
+
+    #[test]
+    fn vendored_libraries() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-matter-labs-libraries-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.join("lib/a/utils")).expect("Always valid");
+        for (name, source) in [
+            (
+                "lib/a/A.sol",
+                r#"import "./utils/B.sol"; import "../../lib/a/utils/B.sol";"#,
+            ),
+            ("lib/a/utils/B.sol", r#"// import "./Unused.sol";"#),
+            ("lib/a/Unused.sol", ""),
+        ] {
+            std::fs::write(directory.join(name), source).expect("Always valid");
+        }
+        let test_path = directory.join("Test.sol");
+
+        for (source, expected) in [
+            (
+                r#"import { A } from "@a/A.sol";"#,
+                vec!["lib/a/A.sol", "lib/a/utils/B.sol"],
+            ),
+            (
+                r#"import "./lib/a/utils/B.sol";"#,
+                vec!["lib/a/utils/B.sol"],
+            ),
+            (r#"contract Sibling {}"#, vec![]),
+            (r#"import "@a/Missing.sol";"#, vec![]),
+        ] {
+            let libraries = MatterLabsTest::vendored_libraries(
+                test_path.as_path(),
+                &[(test_path.to_string_lossy().to_string(), source.to_owned())],
+                &["@a/=lib/a/".to_owned()],
+            )
+            .expect("Always valid");
+            let names: Vec<String> = libraries
+                .into_iter()
+                .map(|(name, _)| {
+                    Path::new(name.as_str())
+                        .strip_prefix(directory.as_path())
+                        .expect("Always valid")
+                        .to_string_lossy()
+                        .to_string()
+                })
+                .collect();
+            assert_eq!(names, expected, "{source}");
+        }
+        let _ = std::fs::remove_dir_all(directory.as_path());
+    }
+}
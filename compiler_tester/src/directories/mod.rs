@@ -4,6 +4,9 @@
 
 pub mod ethereum;
 pub mod matter_labs;
+pub mod real_world;
+pub mod state_tests;
+pub mod system_contracts;
 
 use std::path::Path;
 use std::sync::Arc;
@@ -14,6 +17,8 @@ use crate::compilers::Compiler;
 use crate::environment::Environment;
 use crate::filters::Filters;
 use crate::summary::Summary;
+use crate::test::selector::registry::Registry as SelectorRegistry;
+use crate::test::selector::TestSelector;
 use crate::test::Test;
 
 ///
@@ -34,6 +39,7 @@ pub trait Collection {
         extension: &'static str,
         summary: Arc<Mutex<Summary>>,
         filters: &Filters,
+        selectors: &SelectorRegistry,
     ) -> anyhow::Result<Vec<Self::Test>>;
 }
 
@@ -41,6 +47,11 @@ pub trait Collection {
 /// The buildable compiler test trait.
 ///
 pub trait Buildable: Send + Sync + 'static {
+    ///
+    /// Returns the test selector.
+    ///
+    fn selector(&self) -> &TestSelector;
+
     ///
     /// Builds the test for EraVM.
     ///
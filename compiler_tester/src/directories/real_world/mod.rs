@@ -0,0 +1,187 @@
+//!
+//! The real-world protocol tests directory.
+//!
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::directories::matter_labs::defaults::Defaults;
+use crate::directories::matter_labs::test::MatterLabsTest;
+use crate::directories::Collection;
+use crate::filters::Filters;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::Summary;
+use crate::test::description::TestDescription;
+use crate::test::selector::registry::Registry as SelectorRegistry;
+use crate::test::selector::TestSelector;
+
+///
+/// The real-world protocol tests directory.
+///
+/// Each subdirectory contains the vendored sources of a protocol, e.g. an ERC-20 token or
+/// a Uniswap V2 pair, and the `test.<extension>` metadata with the deploy and interaction scenarios
+/// as cases. The tests without an explicit group are benchmarked in the real-world group.
+/// The protocols without the metadata are reported as invalid.
+///
+pub struct RealWorldDirectory;
+
+impl RealWorldDirectory {
+    /// The directory name, which is skipped by the Matter Labs tests directory.
+    pub const DIRECTORY_NAME: &'static str = "real-world";
+
+    /// The metadata file name without the extension.
+    const METADATA_FILE_NAME: &'static str = "test";
+}
+
+impl Collection for RealWorldDirectory {
+    type Test = MatterLabsTest;
+
+    fn read_all(
+        _target: era_compiler_common::Target,
+        directory_path: &Path,
+        extension: &'static str,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        selectors: &SelectorRegistry,
+    ) -> anyhow::Result<Vec<Self::Test>> {
+        if !directory_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut tests = Vec::new();
+        for entry in fs::read_dir(directory_path)? {
+            let entry = entry?;
+            if !entry.path().is_dir() || entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+
+            let path = entry
+                .path()
+                .join(format!("{}.{extension}", Self::METADATA_FILE_NAME));
+            if !path.exists() {
+                let selector = TestSelector {
+                    path: path.to_string_lossy().to_string(),
+                    case: None,
+                    input: None,
+                };
+                if filters.check_test_path(selector.path.as_str()) {
+                    Summary::invalid(
+                        summary.clone(),
+                        TestDescription::default_for(selector),
+                        ErrorCode::InvalidMetadata,
+                        anyhow::anyhow!(
+                            "The real-world test metadata `{}` is missing",
+                            path.to_string_lossy()
+                        ),
+                    );
+                }
+                continue;
+            }
+
+            if let Some(test) = MatterLabsTest::new(
+                path,
+                summary.clone(),
+                filters,
+                selectors,
+                &Defaults {
+                    group: Some(benchmark_analyzer::Benchmark::REAL_WORLD_GROUP_NAME.to_owned()),
+                    ..Defaults::default()
+                },
+            ) {
+                tests.push(test);
+            }
+        }
+
+        Ok(tests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::directories::Collection;
+    use crate::filters::Filters;
+    use crate::summary::element::outcome::Outcome;
+    use crate::summary::Summary;
+    use crate::test::selector::registry::Registry as SelectorRegistry;
+
+    use super::RealWorldDirectory;
+
+    /// The counter protocol sources.
+    const COUNTER_SOURCE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity >=0.8.0;
+
+contract Counter {
+    uint256 public count;
+
+    function increment() external {
+        count += 1;
+    }
+}
+"#;
+
+    /// The counter protocol scenario, deploying the counter and incrementing it twice.
+    const COUNTER_METADATA: &str = r##"{
+    "contracts": {
+        "Counter": "Counter.sol:Counter"
+    },
+    "cases": [
+        {
+            "name": "increment",
+            "inputs": [
+                { "instance": "Counter", "method": "#deployer", "calldata": [], "expected": [] },
+                { "instance": "Counter", "method": "increment", "calldata": [], "expected": [] },
+                { "instance": "Counter", "method": "increment", "calldata": [], "expected": [] },
+                { "instance": "Counter", "method": "count", "calldata": [], "expected": ["2"] }
+            ]
+        }
+    ]
+}
+"##;
+
+    fn write(directory: &Path, name: &str, contents: &str) {
+        std::fs::create_dir_all(directory).expect("Always valid");
+        std::fs::write(directory.join(name), contents).expect("Always valid");
+    }
+
+    #[test]
+    fn missing_metadata() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-real-world-{}",
+            std::process::id()
+        ));
+        let counter = directory.join("counter");
+        write(counter.as_path(), "Counter.sol", COUNTER_SOURCE);
+        write(counter.as_path(), "test.json", COUNTER_METADATA);
+        write(
+            directory.join("unfinished").as_path(),
+            "Unfinished.sol",
+            COUNTER_SOURCE,
+        );
+        let summary = Summary::new(false, true).wrap();
+
+        let tests = RealWorldDirectory::read_all(
+            era_compiler_common::Target::EVM,
+            directory.as_path(),
+            era_compiler_common::EXTENSION_JSON,
+            summary.clone(),
+            &Filters::default(),
+            &SelectorRegistry::default(),
+        );
+        let _ = std::fs::remove_dir_all(directory.as_path());
+
+        assert_eq!(tests.expect("Always valid").len(), 1);
+        let summary = summary.lock().expect("Sync");
+        assert_eq!(summary.elements().len(), 1);
+        let element = &summary.elements()[0];
+        assert!(matches!(element.outcome, Outcome::Invalid { .. }));
+        assert!(element
+            .test_description
+            .selector
+            .path
+            .ends_with("unfinished/test.json"));
+    }
+}
@@ -0,0 +1,94 @@
+//!
+//! The Ethereum state tests directory.
+//!
+
+pub mod test;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::directories::Collection;
+use crate::filters::Filters;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::Summary;
+use crate::test::description::TestDescription;
+use crate::test::selector::registry::Registry as SelectorRegistry;
+use crate::test::selector::TestSelector;
+
+use self::test::definition::Definition;
+use self::test::StateTest;
+
+///
+/// The Ethereum state tests directory.
+///
+/// Reads the filled `GeneralStateTests` of the `ethereum/tests` repository.
+///
+pub struct StateTestsDirectory;
+
+impl Collection for StateTestsDirectory {
+    type Test = StateTest;
+
+    fn read_all(
+        _target: era_compiler_common::Target,
+        directory_path: &Path,
+        extension: &'static str,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        selectors: &SelectorRegistry,
+    ) -> anyhow::Result<Vec<Self::Test>> {
+        if !directory_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut tests = Vec::new();
+
+        let pattern = directory_path.join(format!("**/*.{extension}"));
+        for path in glob::glob(pattern.to_string_lossy().as_ref())? {
+            let path = path?;
+            let path_string = path.to_string_lossy().to_string();
+            if !filters.check_test_path(path_string.as_str()) {
+                continue;
+            }
+
+            let definitions: BTreeMap<String, Definition> =
+                match std::fs::read_to_string(path.as_path())
+                    .map_err(anyhow::Error::from)
+                    .and_then(|data| {
+                        serde_json::from_str(data.as_str()).map_err(anyhow::Error::from)
+                    }) {
+                    Ok(definitions) => definitions,
+                    Err(error) => {
+                        Summary::invalid(
+                            summary.clone(),
+                            TestDescription::default_for(TestSelector {
+                                path: path_string,
+                                case: None,
+                                input: None,
+                            }),
+                            ErrorCode::InvalidMetadata,
+                            error,
+                        );
+                        continue;
+                    }
+                };
+
+            tests.extend(definitions.into_iter().filter_map(|(name, definition)| {
+                let test = StateTest::new(path_string.as_str(), name, definition, filters)?;
+                if let Err(error) = selectors.register(&test.selector) {
+                    Summary::invalid(
+                        summary.clone(),
+                        TestDescription::default_for(test.selector),
+                        ErrorCode::InvalidMetadata,
+                        error,
+                    );
+                    return None;
+                }
+                Some(test)
+            }));
+        }
+
+        Ok(tests)
+    }
+}
@@ -0,0 +1,23 @@
+//!
+//! The Ethereum state test pre-state account.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+///
+/// The Ethereum state test pre-state account.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Account {
+    /// The account balance.
+    pub balance: web3::types::U256,
+    /// The account code.
+    pub code: web3::types::Bytes,
+    /// The account nonce.
+    pub nonce: web3::types::U256,
+    /// The account storage.
+    #[serde(default)]
+    pub storage: BTreeMap<web3::types::U256, web3::types::U256>,
+}
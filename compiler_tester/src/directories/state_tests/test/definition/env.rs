@@ -0,0 +1,33 @@
+//!
+//! The Ethereum state test block environment.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Ethereum state test block environment.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Env {
+    /// The block beneficiary.
+    pub current_coinbase: web3::types::Address,
+    /// The block gas limit.
+    pub current_gas_limit: web3::types::U256,
+    /// The block number.
+    pub current_number: web3::types::U256,
+    /// The block timestamp.
+    pub current_timestamp: web3::types::U256,
+    /// The block difficulty, used before the merge.
+    #[serde(default)]
+    pub current_difficulty: Option<web3::types::U256>,
+    /// The block randomness, used after the merge.
+    #[serde(default)]
+    pub current_random: Option<web3::types::H256>,
+    /// The block base fee, used since London.
+    #[serde(default)]
+    pub current_base_fee: Option<web3::types::U256>,
+    /// The block excess blob gas, used since Cancun.
+    #[serde(default)]
+    pub current_excess_blob_gas: Option<web3::types::U256>,
+}
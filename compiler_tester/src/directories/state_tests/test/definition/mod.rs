@@ -0,0 +1,34 @@
+//!
+//! The Ethereum state test definition.
+//!
+
+pub mod account;
+pub mod env;
+pub mod post;
+pub mod transaction;
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use self::account::Account;
+use self::env::Env;
+use self::post::Post;
+use self::transaction::Transaction;
+
+///
+/// The Ethereum state test definition.
+///
+/// Follows the filled `GeneralStateTests` format of the `ethereum/tests` repository.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Definition {
+    /// The block environment.
+    pub env: Env,
+    /// The pre-state accounts.
+    pub pre: BTreeMap<web3::types::Address, Account>,
+    /// The transaction template.
+    pub transaction: Transaction,
+    /// The expected post-states as `fork -> entries`.
+    pub post: BTreeMap<String, Vec<Post>>,
+}
@@ -0,0 +1,18 @@
+//!
+//! The Ethereum state test post-state transaction indexes.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Ethereum state test post-state transaction indexes.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Indexes {
+    /// The calldata variant index.
+    pub data: usize,
+    /// The gas limit variant index.
+    pub gas: usize,
+    /// The value variant index.
+    pub value: usize,
+}
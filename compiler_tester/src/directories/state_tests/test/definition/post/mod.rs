@@ -0,0 +1,26 @@
+//!
+//! The Ethereum state test post-state entry.
+//!
+
+pub mod indexes;
+
+use serde::Deserialize;
+
+use self::indexes::Indexes;
+
+///
+/// The Ethereum state test post-state entry.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Post {
+    /// The expected state trie root.
+    pub hash: web3::types::H256,
+    /// The expected hash of the RLP-encoded logs.
+    pub logs: web3::types::H256,
+    /// The transaction variant indexes.
+    pub indexes: Indexes,
+    /// The expected transaction exception, if the transaction must be rejected.
+    #[serde(default)]
+    pub expect_exception: Option<String>,
+}
@@ -0,0 +1,17 @@
+//!
+//! The Ethereum state test transaction access list item.
+//!
+
+use serde::Deserialize;
+
+///
+/// The Ethereum state test transaction access list item.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListItem {
+    /// The accessed account.
+    pub address: web3::types::Address,
+    /// The accessed storage keys.
+    pub storage_keys: Vec<web3::types::U256>,
+}
@@ -0,0 +1,84 @@
+//!
+//! The Ethereum state test transaction template.
+//!
+
+pub mod access_list_item;
+
+use serde::Deserialize;
+
+use self::access_list_item::AccessListItem;
+
+///
+/// The Ethereum state test transaction template.
+///
+/// The `data`, `gas_limit`, and `value` fields are lists of variants, which are
+/// selected by the indexes of each post-state entry.
+///
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    /// The calldata variants.
+    pub data: Vec<web3::types::Bytes>,
+    /// The gas limit variants.
+    pub gas_limit: Vec<web3::types::U256>,
+    /// The value variants.
+    pub value: Vec<web3::types::U256>,
+    /// The access list variants, matching the calldata ones.
+    #[serde(default)]
+    pub access_lists: Vec<Option<Vec<AccessListItem>>>,
+    /// The legacy gas price.
+    #[serde(default)]
+    pub gas_price: Option<web3::types::U256>,
+    /// The EIP-1559 maximum fee per gas.
+    #[serde(default)]
+    pub max_fee_per_gas: Option<web3::types::U256>,
+    /// The EIP-1559 maximum priority fee per gas.
+    #[serde(default)]
+    pub max_priority_fee_per_gas: Option<web3::types::U256>,
+    /// The EIP-4844 maximum fee per blob gas.
+    #[serde(default)]
+    pub max_fee_per_blob_gas: Option<web3::types::U256>,
+    /// The EIP-4844 blob versioned hashes.
+    #[serde(default)]
+    pub blob_versioned_hashes: Vec<web3::types::H256>,
+    /// The sender nonce.
+    pub nonce: web3::types::U256,
+    /// The sender secret key.
+    pub secret_key: web3::types::H256,
+    /// The sender address, which is only present in newer tests.
+    #[serde(default)]
+    pub sender: Option<web3::types::Address>,
+    /// The recipient address, or an empty string for contract creation.
+    pub to: String,
+}
+
+impl Transaction {
+    ///
+    /// Returns the sender address, recovering it from the secret key if it is not specified.
+    ///
+    pub fn sender(&self) -> anyhow::Result<web3::types::Address> {
+        if let Some(sender) = self.sender {
+            return Ok(sender);
+        }
+
+        let secret_key = web3::signing::SecretKey::from_slice(self.secret_key.as_bytes())
+            .map_err(|error| anyhow::anyhow!("Invalid secret key: {error}"))?;
+        Ok(web3::signing::Key::address(
+            &web3::signing::SecretKeyRef::new(&secret_key),
+        ))
+    }
+
+    ///
+    /// Returns the recipient address, or `None` for contract creation.
+    ///
+    pub fn to(&self) -> anyhow::Result<Option<web3::types::Address>> {
+        if self.to.is_empty() {
+            return Ok(None);
+        }
+
+        self.to
+            .parse()
+            .map(Some)
+            .map_err(|error| anyhow::anyhow!("Invalid recipient `{}`: {error}", self.to))
+    }
+}
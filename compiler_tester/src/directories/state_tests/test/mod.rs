@@ -0,0 +1,144 @@
+//!
+//! The Ethereum state test.
+//!
+
+pub mod definition;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::compilers::mode::Mode;
+use crate::compilers::Compiler;
+use crate::directories::Buildable;
+use crate::environment::Environment;
+use crate::filters::Filters;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::Summary;
+use crate::test::case::input::state_transition::StateTransition;
+use crate::test::case::input::Input;
+use crate::test::case::Case;
+use crate::test::description::TestDescription;
+use crate::test::selector::TestSelector;
+use crate::test::Test;
+
+use self::definition::Definition;
+
+///
+/// The Ethereum state test.
+///
+/// Every supported fork becomes a test case, and every post-state entry of the fork
+/// becomes a state transition input of the case.
+///
+#[derive(Debug)]
+pub struct StateTest {
+    /// The test selector.
+    pub selector: TestSelector,
+    /// The test definition.
+    pub definition: Definition,
+}
+
+impl StateTest {
+    ///
+    /// Try to create new test.
+    ///
+    pub fn new(
+        path: &str,
+        name: String,
+        definition: Definition,
+        filters: &Filters,
+    ) -> Option<Self> {
+        let path = format!("{path}::{name}");
+
+        if !filters.check_case_path(&path) {
+            return None;
+        }
+
+        if !filters.check_tags(&[]) {
+            return None;
+        }
+
+        Some(Self {
+            selector: TestSelector {
+                path,
+                case: None,
+                input: None,
+            },
+            definition,
+        })
+    }
+}
+
+impl Buildable for StateTest {
+    fn selector(&self) -> &TestSelector {
+        &self.selector
+    }
+
+    fn build_for_eravm(
+        &self,
+        _mode: Mode,
+        _compiler: Arc<dyn Compiler>,
+        _environment: Environment,
+        _summary: Arc<Mutex<Summary>>,
+        _filters: &Filters,
+        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> Option<Test> {
+        None
+    }
+
+    fn build_for_evm(
+        &self,
+        mode: Mode,
+        _compiler: Arc<dyn Compiler>,
+        _environment: Environment,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> Option<Test> {
+        if !filters.check_mode(&mode) {
+            return None;
+        }
+
+        let test_description = TestDescription {
+            group: None,
+            mode: Some(mode.clone()),
+            selector: self.selector.clone(),
+        };
+
+        let mut cases = Vec::with_capacity(self.definition.post.len());
+        for (fork, posts) in self.definition.post.iter() {
+            let spec_id = match StateTransition::spec_id(fork.as_str()) {
+                Some(spec_id) => spec_id,
+                None => continue,
+            };
+
+            let inputs = match posts
+                .iter()
+                .map(|post| {
+                    StateTransition::try_from_definition(&self.definition, spec_id, post)
+                        .map(Input::StateTransition)
+                })
+                .collect::<anyhow::Result<Vec<Input>>>()
+            {
+                Ok(inputs) => inputs,
+                Err(error) => {
+                    Summary::invalid(summary, test_description, ErrorCode::InvalidMetadata, error);
+                    return None;
+                }
+            };
+            cases.push(Case::new(Some(fork.to_owned()), inputs));
+        }
+
+        Some(Test::new(
+            self.selector.path.to_owned(),
+            cases,
+            mode,
+            None,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            true,
+        ))
+    }
+}
@@ -0,0 +1,65 @@
+//!
+//! The EraVM system contracts benchmark directory.
+//!
+
+pub mod test;
+
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::directories::Collection;
+use crate::filters::Filters;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::Summary;
+use crate::test::description::TestDescription;
+use crate::test::selector::registry::Registry as SelectorRegistry;
+
+use self::test::SystemContractTest;
+
+///
+/// The EraVM system contracts benchmark directory.
+///
+/// The tests are generated by the tester, so the directory path is only used as the selector prefix.
+///
+pub struct SystemContractsDirectory;
+
+impl Collection for SystemContractsDirectory {
+    type Test = SystemContractTest;
+
+    fn read_all(
+        target: era_compiler_common::Target,
+        directory_path: &Path,
+        _extension: &'static str,
+        summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        selectors: &SelectorRegistry,
+    ) -> anyhow::Result<Vec<Self::Test>> {
+        if let era_compiler_common::Target::EVM = target {
+            return Ok(vec![]);
+        }
+        if !filters.check_group(&Some(
+            benchmark_analyzer::Benchmark::SYSTEM_CONTRACTS_GROUP_NAME.to_owned(),
+        )) {
+            return Ok(vec![]);
+        }
+
+        let mut tests = Vec::new();
+        for test in SystemContractTest::all(directory_path) {
+            if !filters.check_test_path(test.selector.path.as_str()) {
+                continue;
+            }
+            if let Err(error) = selectors.register(&test.selector) {
+                Summary::invalid(
+                    summary.clone(),
+                    TestDescription::default_for(test.selector),
+                    ErrorCode::InvalidMetadata,
+                    error,
+                );
+                continue;
+            }
+            tests.push(test);
+        }
+        Ok(tests)
+    }
+}
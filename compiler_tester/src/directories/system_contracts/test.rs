@@ -0,0 +1,297 @@
+//!
+//! The EraVM system contract benchmark test.
+//!
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use sha3::Digest;
+
+use crate::compilers::mode::Mode;
+use crate::compilers::Compiler;
+use crate::directories::matter_labs::test::DEFAULT_CALLER_ADDRESS;
+use crate::directories::Buildable;
+use crate::environment::Environment;
+use crate::filters::Filters;
+use crate::summary::Summary;
+use crate::test::case::input::call_kind::CallKind;
+use crate::test::case::input::output::Output;
+use crate::test::case::input::runtime::Runtime;
+use crate::test::case::input::storage::Storage;
+use crate::test::case::input::value::Value;
+use crate::test::case::input::Input;
+use crate::test::case::Case;
+use crate::test::selector::TestSelector;
+use crate::test::Test;
+use crate::vm::address_iterator::AddressIterator;
+use crate::vm::eravm::address_iterator::EraVMAddressIterator;
+
+///
+/// The EraVM system contract benchmark test.
+///
+/// Calls the entry point of a system contract directly with inputs of several sizes,
+/// with each input size being a separate case.
+///
+#[derive(Debug)]
+pub struct SystemContractTest {
+    /// The test selector.
+    pub selector: TestSelector,
+    /// The test cases.
+    pub cases: Vec<Case>,
+}
+
+impl SystemContractTest {
+    /// The hashed input sizes in bytes, including an empty input and a multi-block one.
+    const HASH_INPUT_SIZES: [usize; 4] = [0, 32, 136, 1024];
+
+    /// The valid `ecrecover` input: the message hash, `v`, `r`, and `s`.
+    const ECRECOVER_INPUT: &'static str = "38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e000000000000000000000000000000000000000000000000000000000000001b38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e789d1dd423d25f0772d2748d60f7e4b81bb14d086eba8e8e8efb6dcff8a4ae02";
+
+    /// The address recovered from `ECRECOVER_INPUT`.
+    const ECRECOVER_OUTPUT: &'static str = "ceaccac640adf55b2028469bd36ba501f28b699d";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(directory_path: &Path, name: &str, cases: Vec<Case>) -> Self {
+        Self {
+            selector: TestSelector {
+                path: directory_path.join(name).to_string_lossy().to_string(),
+                case: None,
+                input: None,
+            },
+            cases,
+        }
+    }
+
+    ///
+    /// Returns the tests of all benchmarked system contracts.
+    ///
+    pub fn all(directory_path: &Path) -> Vec<Self> {
+        vec![
+            Self::keccak256(directory_path),
+            Self::sha256(directory_path),
+            Self::ecrecover(directory_path),
+            Self::contract_deployer(directory_path),
+            Self::l1_messenger(directory_path),
+        ]
+    }
+
+    ///
+    /// The `Keccak256` precompile test, which hashes the raw calldata.
+    ///
+    fn keccak256(directory_path: &Path) -> Self {
+        let cases = Self::HASH_INPUT_SIZES
+            .into_iter()
+            .map(|size| {
+                let input = vec![0xab; size];
+                let hash = sha3::Keccak256::digest(input.as_slice());
+                Self::case(
+                    size.to_string(),
+                    zkevm_opcode_defs::ADDRESS_KECCAK256.into(),
+                    "#fallback",
+                    input,
+                    vec![Value::Certain(web3::types::U256::from_big_endian(
+                        hash.as_slice(),
+                    ))],
+                )
+            })
+            .collect();
+        Self::new(directory_path, "Keccak256", cases)
+    }
+
+    ///
+    /// The `SHA256` precompile test, which hashes the raw calldata.
+    ///
+    fn sha256(directory_path: &Path) -> Self {
+        let cases = Self::HASH_INPUT_SIZES
+            .into_iter()
+            .map(|size| {
+                let input = vec![0xab; size];
+                let hash = sha2::Sha256::digest(input.as_slice());
+                Self::case(
+                    size.to_string(),
+                    zkevm_opcode_defs::ADDRESS_SHA256.into(),
+                    "#fallback",
+                    input,
+                    vec![Value::Certain(web3::types::U256::from_big_endian(
+                        hash.as_slice(),
+                    ))],
+                )
+            })
+            .collect();
+        Self::new(directory_path, "SHA256", cases)
+    }
+
+    ///
+    /// The `EcRecover` precompile test.
+    ///
+    fn ecrecover(directory_path: &Path) -> Self {
+        let input = hex::decode(Self::ECRECOVER_INPUT).expect("Always valid");
+        let output = web3::types::Address::from_str(Self::ECRECOVER_OUTPUT).expect("Always valid");
+        let case = Self::case(
+            "valid".to_owned(),
+            zkevm_opcode_defs::ADDRESS_ECRECOVER.into(),
+            "#fallback",
+            input,
+            vec![Value::Certain(web3::types::U256::from_big_endian(
+                output.as_bytes(),
+            ))],
+        );
+        Self::new(directory_path, "EcRecover", vec![case])
+    }
+
+    ///
+    /// The `ContractDeployer` test, which derives a `CREATE` address.
+    ///
+    fn contract_deployer(directory_path: &Path) -> Self {
+        let sender = web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+        let address = EraVMAddressIterator::new().next(&sender, false);
+
+        let mut calldata = crate::utils::selector("getNewAddressCreate(address,uint256)").to_vec();
+        calldata.extend(
+            [0; era_compiler_common::BYTE_LENGTH_FIELD
+                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+        );
+        calldata.extend(sender.as_bytes());
+        calldata.extend([0; era_compiler_common::BYTE_LENGTH_FIELD]);
+
+        let case = Self::case(
+            "getNewAddressCreate".to_owned(),
+            zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
+            "getNewAddressCreate",
+            calldata,
+            vec![Value::Certain(web3::types::U256::from_big_endian(
+                address.as_bytes(),
+            ))],
+        );
+        Self::new(directory_path, "ContractDeployer", vec![case])
+    }
+
+    ///
+    /// The `L1Messenger` test, which sends messages of several sizes to L1.
+    ///
+    /// The `sendToL1` method returns the `keccak256` hash of the message.
+    ///
+    fn l1_messenger(directory_path: &Path) -> Self {
+        let cases = Self::HASH_INPUT_SIZES
+            .into_iter()
+            .map(|size| {
+                let message = vec![0xab; size];
+                let hash = sha3::Keccak256::digest(message.as_slice());
+
+                let mut calldata = crate::utils::selector("sendToL1(bytes)").to_vec();
+                calldata.extend(
+                    web3::types::H256::from_low_u64_be(
+                        era_compiler_common::BYTE_LENGTH_FIELD as u64,
+                    )
+                    .as_bytes(),
+                );
+                calldata.extend(web3::types::H256::from_low_u64_be(size as u64).as_bytes());
+                calldata.extend(message);
+                calldata.extend(vec![
+                    0;
+                    size.next_multiple_of(
+                        era_compiler_common::BYTE_LENGTH_FIELD
+                    ) - size
+                ]);
+
+                Self::case(
+                    size.to_string(),
+                    zkevm_opcode_defs::ADDRESS_L1_MESSENGER.into(),
+                    "sendToL1",
+                    calldata,
+                    vec![Value::Certain(web3::types::U256::from_big_endian(
+                        hash.as_slice(),
+                    ))],
+                )
+            })
+            .collect();
+        Self::new(directory_path, "L1Messenger", cases)
+    }
+
+    ///
+    /// Creates a case with a single call to the system contract at `address`.
+    ///
+    fn case(
+        name: String,
+        address: u64,
+        entry: &str,
+        calldata: Vec<u8>,
+        return_data: Vec<Value>,
+    ) -> Case {
+        let input = Runtime::new(
+            entry.to_owned(),
+            web3::types::Address::from_low_u64_be(address),
+            calldata.into(),
+            web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid"),
+            None,
+            CallKind::Call,
+            None,
+            Storage::default(),
+            Output::new(return_data, false, vec![]),
+        );
+        Case::new(Some(name), vec![Input::Runtime(input)])
+    }
+}
+
+impl Buildable for SystemContractTest {
+    fn selector(&self) -> &TestSelector {
+        &self.selector
+    }
+
+    fn build_for_eravm(
+        &self,
+        mode: Mode,
+        _compiler: Arc<dyn Compiler>,
+        _environment: Environment,
+        _summary: Arc<Mutex<Summary>>,
+        filters: &Filters,
+        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> Option<Test> {
+        if !filters.check_mode(&mode) {
+            return None;
+        }
+
+        let cases = self
+            .cases
+            .iter()
+            .filter(|case| {
+                let selector = TestSelector {
+                    path: self.selector.path.clone(),
+                    case: case.name().map(str::to_owned),
+                    input: None,
+                };
+                filters.check_case_path(selector.to_string().as_str())
+            })
+            .cloned()
+            .collect();
+
+        Some(Test::new(
+            self.selector.path.to_owned(),
+            cases,
+            mode,
+            Some(benchmark_analyzer::Benchmark::SYSTEM_CONTRACTS_GROUP_NAME.to_owned()),
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            true,
+        ))
+    }
+
+    fn build_for_evm(
+        &self,
+        _mode: Mode,
+        _compiler: Arc<dyn Compiler>,
+        _environment: Environment,
+        _summary: Arc<Mutex<Summary>>,
+        _filters: &Filters,
+        _debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    ) -> Option<Test> {
+        None
+    }
+}
@@ -0,0 +1,324 @@
+//!
+//! The EVM bytecode disassembly of a failed test.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The EVM bytecode disassembly of a failed test.
+///
+/// Lists the instructions with their offsets, and maps each jump destination to the offsets
+/// of the jumps to it, whose targets are pushed right before them. The trailing CBOR metadata
+/// appended by `solc` is not disassembled.
+///
+#[derive(Debug)]
+pub struct Disassembly {
+    /// The instructions with their offsets and immediate arguments.
+    instructions: Vec<(usize, u8, Vec<u8>)>,
+    /// The jump destinations with the offsets of the static jumps to them.
+    jumpdests: BTreeMap<usize, Vec<usize>>,
+    /// The trailing CBOR metadata, if present.
+    metadata: Option<Vec<u8>>,
+}
+
+impl Disassembly {
+    /// The disassembly file extension.
+    pub const EXTENSION: &'static str = "disasm";
+
+    /// The subdirectory of a test with the EraVM assembly of the other target.
+    pub const ERAVM_DIRECTORY: &'static str = "eravm";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(bytecode: &[u8]) -> Self {
+        let (code, metadata) = Self::split_metadata(bytecode);
+
+        let mut instructions = Vec::new();
+        let mut offset = 0;
+        while offset < code.len() {
+            let opcode = code[offset];
+            let immediate_size = if (revm::interpreter::opcode::PUSH1
+                ..=revm::interpreter::opcode::PUSH32)
+                .contains(&opcode)
+            {
+                (opcode - revm::interpreter::opcode::PUSH1 + 1) as usize
+            } else {
+                0
+            };
+            let immediate_end = std::cmp::min(offset + 1 + immediate_size, code.len());
+            instructions.push((offset, opcode, code[offset + 1..immediate_end].to_vec()));
+            offset = offset + 1 + immediate_size;
+        }
+
+        let mut jumpdests: BTreeMap<usize, Vec<usize>> = instructions
+            .iter()
+            .filter(|(_, opcode, _)| *opcode == revm::interpreter::opcode::JUMPDEST)
+            .map(|(offset, _, _)| (*offset, vec![]))
+            .collect();
+        for window in instructions.windows(2) {
+            let (_, push_opcode, target) = &window[0];
+            let (jump_offset, jump_opcode, _) = &window[1];
+            if !(revm::interpreter::opcode::PUSH1..=revm::interpreter::opcode::PUSH32)
+                .contains(push_opcode)
+                || ![
+                    revm::interpreter::opcode::JUMP,
+                    revm::interpreter::opcode::JUMPI,
+                ]
+                .contains(jump_opcode)
+                || target.len() > std::mem::size_of::<usize>()
+            {
+                continue;
+            }
+            let target = target
+                .iter()
+                .fold(0usize, |target, byte| (target << 8) | (*byte as usize));
+            if let Some(sources) = jumpdests.get_mut(&target) {
+                sources.push(*jump_offset);
+            }
+        }
+
+        Self {
+            instructions,
+            jumpdests,
+            metadata,
+        }
+    }
+
+    ///
+    /// Copies the EraVM assembly of the tests, whose EVM bytecode has been disassembled,
+    /// from the debug subdirectories of the EraVM modes into their EVM debug subdirectories.
+    ///
+    /// The EraVM assembly is only available if both targets have been built with debugging.
+    /// Returns the number of copied assembly files.
+    ///
+    pub fn link_eravm_assembly(
+        debug_config: &era_compiler_llvm_context::DebugConfig,
+    ) -> anyhow::Result<usize> {
+        let disassembly_pattern = debug_config
+            .output_directory
+            .join(format!("*/*/*.{}", Self::EXTENSION));
+        let mut test_directories = Vec::new();
+        for path in glob::glob(disassembly_pattern.to_string_lossy().as_ref())? {
+            let path = path?;
+            let test_directory = path.parent().expect("Always exists").to_path_buf();
+            if !test_directories.contains(&test_directory) {
+                test_directories.push(test_directory);
+            }
+        }
+
+        let mut count = 0;
+        for test_directory in test_directories.into_iter() {
+            let test_hash = test_directory.file_name().expect("Always exists");
+            let assembly_pattern = debug_config.output_directory.join(format!(
+                "*/{}/*.{}",
+                test_hash.to_string_lossy(),
+                era_compiler_common::EXTENSION_ERAVM_ASSEMBLY
+            ));
+            for path in glob::glob(assembly_pattern.to_string_lossy().as_ref())? {
+                let path = path?;
+                if path.parent() == Some(test_directory.as_path()) {
+                    continue;
+                }
+
+                let output_directory = test_directory
+                    .join(Self::ERAVM_DIRECTORY)
+                    .join(Self::mode_directory(path.as_path()));
+                std::fs::create_dir_all(output_directory.as_path())?;
+                let output_path = output_directory.join(path.file_name().expect("Always exists"));
+                std::fs::copy(path.as_path(), output_path.as_path()).map_err(|error| {
+                    anyhow::anyhow!("EraVM assembly file {path:?} copying: {error}")
+                })?;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    ///
+    /// Returns the length of the trailing CBOR metadata of the bytecode, including its
+    /// two-byte length suffix, or zero if there is no metadata.
+    ///
+    pub fn metadata_size(bytecode: &[u8]) -> usize {
+        let (code, _) = Self::split_metadata(bytecode);
+        bytecode.len() - code.len()
+    }
+
+    ///
+    /// Splits the trailing CBOR metadata off the bytecode.
+    ///
+    /// The metadata is a CBOR map followed by its two-byte big-endian length.
+    ///
+    fn split_metadata(bytecode: &[u8]) -> (&[u8], Option<Vec<u8>>) {
+        if bytecode.len() < 2 {
+            return (bytecode, None);
+        }
+        let length =
+            u16::from_be_bytes([bytecode[bytecode.len() - 2], bytecode[bytecode.len() - 1]])
+                as usize;
+        if length == 0 || length + 2 > bytecode.len() {
+            return (bytecode, None);
+        }
+        let metadata_offset = bytecode.len() - 2 - length;
+        if !(0xa1..=0xa5).contains(&bytecode[metadata_offset]) {
+            return (bytecode, None);
+        }
+        (
+            &bytecode[..metadata_offset],
+            Some(bytecode[metadata_offset..].to_vec()),
+        )
+    }
+
+    ///
+    /// Returns the mode subdirectory name of the test debug artifact at `path`.
+    ///
+    fn mode_directory(path: &Path) -> PathBuf {
+        path.parent()
+            .and_then(Path::parent)
+            .and_then(Path::file_name)
+            .map(PathBuf::from)
+            .expect("Always exists")
+    }
+}
+
+impl std::fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "; jump destinations:")?;
+        for (jumpdest, sources) in self.jumpdests.iter() {
+            if sources.is_empty() {
+                writeln!(f, ";   0x{jumpdest:04x}")?;
+            } else {
+                writeln!(
+                    f,
+                    ";   0x{jumpdest:04x} <- {}",
+                    sources
+                        .iter()
+                        .map(|source| format!("0x{source:04x}"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )?;
+            }
+        }
+        writeln!(f)?;
+
+        for (offset, opcode, immediate) in self.instructions.iter() {
+            let name = match revm::interpreter::OpCode::new(*opcode) {
+                Some(opcode) => opcode.as_str().to_owned(),
+                None => format!("INVALID(0x{opcode:02x})"),
+            };
+            if *opcode == revm::interpreter::opcode::JUMPDEST {
+                writeln!(f)?;
+            }
+            if immediate.is_empty() {
+                writeln!(f, "0x{offset:04x}: {name}")?;
+            } else {
+                writeln!(f, "0x{offset:04x}: {name} 0x{}", hex::encode(immediate))?;
+            }
+        }
+
+        if let Some(metadata) = self.metadata.as_ref() {
+            writeln!(f)?;
+            writeln!(f, "; metadata: {}", hex::encode(metadata))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disassembly;
+
+    /// The `{"solc": 0x00081c}` CBOR map followed by its length.
+    const METADATA: &str = "a164736f6c634300081c000a";
+
+    #[test]
+    fn new() {
+        // PUSH1 0x05 JUMP INVALID JUMPDEST PUSH2 0x0005 GAS JUMPDEST PUSH1 (truncated)
+        let bytecode =
+            hex::decode(format!("600556fe5b6100055a5b60{METADATA}")).expect("Always valid");
+        let disassembly = Disassembly::new(bytecode.as_slice());
+
+        assert_eq!(
+            disassembly.instructions,
+            vec![
+                (0x00, 0x60, vec![0x05]),
+                (0x02, 0x56, vec![]),
+                (0x03, 0xfe, vec![]),
+                (0x04, 0x5b, vec![]),
+                (0x05, 0x61, vec![0x00, 0x05]),
+                (0x08, 0x5a, vec![]),
+                (0x09, 0x5b, vec![]),
+                (0x0a, 0x60, vec![]),
+            ]
+        );
+        assert_eq!(
+            disassembly.jumpdests.into_iter().collect::<Vec<_>>(),
+            vec![(0x04, vec![]), (0x09, vec![])],
+            "Only the jumps to existing destinations must be mapped"
+        );
+        assert_eq!(
+            disassembly.metadata.map(hex::encode).as_deref(),
+            Some(METADATA)
+        );
+    }
+
+    #[test]
+    fn display() {
+        // PUSH1 0x04 JUMP INVALID JUMPDEST PUSH1 0x04 JUMPI
+        let bytecode = hex::decode("600456fe5b600457").expect("Always valid");
+        let disassembly = Disassembly::new(bytecode.as_slice()).to_string();
+
+        assert_eq!(
+            disassembly,
+            "\
+; jump destinations:
+;   0x0004 <- 0x0002, 0x0007
+
+0x0000: PUSH1 0x04
+0x0002: JUMP
+0x0003: INVALID
+
+0x0004: JUMPDEST
+0x0005: PUSH1 0x04
+0x0007: JUMPI
+"
+        );
+    }
+
+    #[test]
+    fn split_metadata() {
+        for (bytecode, code, metadata) in [
+            (
+                format!("6080604052{METADATA}"),
+                "6080604052",
+                Some(METADATA),
+            ),
+            ("6080604052".to_owned(), "6080604052", None),
+            ("600060000002".to_owned(), "600060000002", None),
+            ("000a".to_owned(), "000a", None),
+            ("00".to_owned(), "00", None),
+            (String::new(), "", None),
+        ] {
+            let bytecode = hex::decode(bytecode.as_str()).expect("Always valid");
+            let (found_code, found_metadata) = Disassembly::split_metadata(bytecode.as_slice());
+            assert_eq!(hex::encode(found_code), code);
+            assert_eq!(found_metadata.map(hex::encode).as_deref(), metadata);
+        }
+    }
+
+    #[test]
+    fn metadata_size() {
+        for (bytecode, expected) in [
+            (format!("6080604052{METADATA}"), 12),
+            (METADATA.to_owned(), 12),
+            ("6080604052".to_owned(), 0),
+            ("600060000002".to_owned(), 0),
+        ] {
+            let bytecode = hex::decode(bytecode.as_str()).expect("Always valid");
+            assert_eq!(Disassembly::metadata_size(bytecode.as_slice()), expected);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! The test documentation case.
+//!
+
+///
+/// The test documentation case.
+///
+#[derive(Debug, Clone)]
+pub struct Case {
+    /// The case name.
+    pub name: String,
+    /// The case comment.
+    pub comment: Option<String>,
+    /// The comments of the case inputs.
+    pub inputs: Vec<String>,
+}
+
+impl Case {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, comment: Option<String>, inputs: Vec<String>) -> Self {
+        Self {
+            name,
+            comment,
+            inputs,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//!
+//! The test documentation entry.
+//!
+
+use super::case::Case;
+
+///
+/// The test documentation entry.
+///
+#[derive(Debug, Clone)]
+pub struct Entry {
+    /// The test path.
+    pub path: String,
+    /// The test group.
+    pub group: Option<String>,
+    /// The test description lines, e.g. the header comment of an Ethereum test.
+    pub description: Vec<String>,
+    /// The documented cases.
+    pub cases: Vec<Case>,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        path: String,
+        group: Option<String>,
+        description: Vec<String>,
+        cases: Vec<Case>,
+    ) -> Self {
+        Self {
+            path,
+            group,
+            description,
+            cases,
+        }
+    }
+}
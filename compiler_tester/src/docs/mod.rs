@@ -0,0 +1,118 @@
+//!
+//! The test documentation index.
+//!
+
+pub mod case;
+pub mod entry;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use self::entry::Entry;
+
+///
+/// The test documentation index.
+///
+/// The entries are grouped by the tests directory and the test group, and sorted by path.
+///
+#[derive(Debug, Default)]
+pub struct Docs {
+    /// The entries as `directory -> group -> path -> entry`.
+    pub directories: BTreeMap<String, BTreeMap<String, BTreeMap<String, Entry>>>,
+}
+
+impl Docs {
+    /// The group name of the tests without a group.
+    pub const UNGROUPED: &'static str = "Ungrouped";
+
+    ///
+    /// Adds an entry of the tests `directory`.
+    ///
+    pub fn push(&mut self, directory: &str, entry: Entry) {
+        self.directories
+            .entry(directory.to_owned())
+            .or_default()
+            .entry(
+                entry
+                    .group
+                    .clone()
+                    .unwrap_or_else(|| Self::UNGROUPED.to_owned()),
+            )
+            .or_default()
+            .insert(entry.path.clone(), entry);
+    }
+
+    ///
+    /// Returns the number of documented tests.
+    ///
+    pub fn len(&self) -> usize {
+        self.directories
+            .values()
+            .flat_map(|groups| groups.values())
+            .map(|entries| entries.len())
+            .sum()
+    }
+
+    ///
+    /// Whether no tests are documented.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    ///
+    /// Writes the Markdown index to the specified file.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_string())
+            .map_err(|error| anyhow::anyhow!("Test documentation file {path:?} writing: {error}"))
+    }
+}
+
+impl std::fmt::Display for Docs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# Tests")?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Generated by `compiler-tester docs` from the test metadata. Do not edit manually."
+        )?;
+
+        for (directory, groups) in self.directories.iter() {
+            writeln!(f)?;
+            writeln!(f, "## `{directory}`")?;
+            for (group, entries) in groups.iter() {
+                writeln!(f)?;
+                writeln!(f, "### {group}")?;
+                writeln!(f)?;
+                for entry in entries.values() {
+                    writeln!(f, "- [`{}`]({})", entry.path, entry.path)?;
+                    if !entry.description.is_empty() {
+                        writeln!(f, "  {}", entry.description.join(" "))?;
+                    }
+                    for case in entry.cases.iter() {
+                        match case.comment.as_deref() {
+                            Some(comment) => writeln!(f, "  - `{}`: {comment}", case.name)?,
+                            None => writeln!(f, "  - `{}`", case.name)?,
+                        }
+                        for input in case.inputs.iter() {
+                            writeln!(f, "    - {input}")?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// The test which can be documented.
+///
+pub trait Documented {
+    ///
+    /// Returns the documentation entry of the test.
+    ///
+    fn docs_entry(&self) -> Entry;
+}
@@ -0,0 +1,27 @@
+//!
+//! The compiler executable of a download configuration.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The compiler executable of a download configuration.
+///
+/// Only the fields required for verification are read, so the rest of the schema is handled
+/// by the downloader itself.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Binary {
+    /// Whether the executable is downloaded.
+    #[serde(default)]
+    pub is_enabled: bool,
+    /// The download source URL template.
+    pub source: String,
+    /// The download destination path template.
+    pub destination: String,
+    /// The expected SHA-256 checksums of the executable, keyed by the platform.
+    #[serde(default)]
+    pub sha256: BTreeMap<String, String>,
+    /// The detached GPG signature URL template, if the executable is signed.
+    pub signature: Option<String>,
+}
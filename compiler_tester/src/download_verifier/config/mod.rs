@@ -0,0 +1,36 @@
+//!
+//! The compiler executables download configuration.
+//!
+
+pub mod binary;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use self::binary::Binary;
+
+///
+/// The compiler executables download configuration.
+///
+/// Extends the downloader schema with the checksums and signatures of the executables.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Config {
+    /// The compiler executables, keyed by the version.
+    pub binaries: BTreeMap<String, Binary>,
+    /// The platform names used in the source URLs, keyed by the platform.
+    #[serde(default)]
+    pub platforms: BTreeMap<String, String>,
+}
+
+impl TryFrom<&Path> for Config {
+    type Error = anyhow::Error;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Download config {path:?} reading: {error}"))?;
+        let config: Self = serde_json::from_str(text.as_str())
+            .map_err(|error| anyhow::anyhow!("Download config {path:?} parsing: {error}"))?;
+        Ok(config)
+    }
+}
@@ -0,0 +1,368 @@
+//!
+//! The compiler executables download verifier.
+//!
+
+pub mod config;
+
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use sha2::Digest;
+
+use self::config::binary::Binary;
+use self::config::Config;
+
+///
+/// The compiler executables download verifier.
+///
+/// Checks the SHA-256 checksums and, optionally, the detached GPG signatures of the executables
+/// downloaded according to a configuration. The executables with mismatching checksums are
+/// removed and downloaded again once, as they are usually left corrupted by an interrupted
+/// download or a stale cache.
+///
+#[derive(Debug)]
+pub struct DownloadVerifier {
+    /// The HTTP client.
+    http_client: reqwest::blocking::Client,
+}
+
+impl DownloadVerifier {
+    /// The detached signature file extension.
+    pub const SIGNATURE_EXTENSION: &'static str = "asc";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(http_client: reqwest::blocking::Client) -> Self {
+        Self { http_client }
+    }
+
+    ///
+    /// Verifies the executables downloaded according to the configuration at `config_path`.
+    ///
+    /// The executables without checksums for the current platform are not checked.
+    ///
+    pub fn verify(&self, config_path: &Path) -> anyhow::Result<()> {
+        let config = Config::try_from(config_path)?;
+        let platform = match Self::platform(std::env::consts::OS, std::env::consts::ARCH) {
+            Some(platform) => platform,
+            None => return Ok(()),
+        };
+        let platform_name = config
+            .platforms
+            .get(platform)
+            .map(String::as_str)
+            .unwrap_or(platform);
+
+        let mismatching = Self::remove_mismatching(config_path, &config, platform, platform_name)?;
+        if !mismatching.is_empty() {
+            era_compiler_downloader::Downloader::new(self.http_client.clone())
+                .download(config_path)?;
+        }
+        for (version, binary) in mismatching.into_iter() {
+            let expected = binary.sha256.get(platform).expect("Always exists");
+            let destination = PathBuf::from(Self::substitute(
+                binary.destination.as_str(),
+                version,
+                platform_name,
+            ));
+            let found = Self::sha256(destination.as_path())?;
+            if !found.eq_ignore_ascii_case(expected) {
+                anyhow::bail!(
+                    "Executable {destination:?} downloaded from `{}` has the SHA-256 checksum {found}, but {expected} is expected. If the release has been replaced upstream, update the `sha256` of version `{version}` for `{platform}` in {config_path:?}",
+                    Self::substitute(binary.source.as_str(), version, platform_name),
+                );
+            }
+        }
+
+        for (version, binary) in config.binaries.iter() {
+            if binary.is_enabled && binary.signature.is_some() {
+                self.verify_signature(config_path, version, binary, platform_name)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the executables of the `config` with checksums for the `platform`, removes the ones
+    /// with mismatching checksums, and returns them.
+    ///
+    /// The enabled executables without checksums are reported with their actual checksums,
+    /// so the configuration can be completed after a trusted download.
+    ///
+    fn remove_mismatching<'a>(
+        config_path: &Path,
+        config: &'a Config,
+        platform: &str,
+        platform_name: &str,
+    ) -> anyhow::Result<Vec<(&'a String, &'a Binary)>> {
+        let mut mismatching = Vec::new();
+        for (version, binary) in config.binaries.iter() {
+            if !binary.is_enabled {
+                continue;
+            }
+            let destination = PathBuf::from(Self::substitute(
+                binary.destination.as_str(),
+                version,
+                platform_name,
+            ));
+            let expected = match binary.sha256.get(platform) {
+                Some(expected) => expected,
+                None => {
+                    if let Ok(found) = Self::sha256(destination.as_path()) {
+                        println!(
+                            "    {} {destination:?}: no checksum for `{platform}` in {config_path:?}, the downloaded executable has {found}",
+                            "Warning".bright_yellow().bold(),
+                        );
+                    }
+                    continue;
+                }
+            };
+            let found = Self::sha256(destination.as_path())?;
+            if !found.eq_ignore_ascii_case(expected) {
+                println!(
+                    "    {} {destination:?}: checksum {found} mismatches the expected {expected}, downloading again",
+                    "Warning".bright_yellow().bold(),
+                );
+                std::fs::remove_file(destination.as_path()).map_err(|error| {
+                    anyhow::anyhow!("Executable {destination:?} removing: {error}")
+                })?;
+                mismatching.push((version, binary));
+            }
+        }
+        Ok(mismatching)
+    }
+
+    ///
+    /// Verifies the detached GPG signature of the executable with the `gpg` executable.
+    ///
+    /// The signature is downloaded next to the executable, and its signing key must be imported
+    /// to the keyring in advance. The executable and signature are removed if the verification fails,
+    /// so they are downloaded again by the next run.
+    ///
+    fn verify_signature(
+        &self,
+        config_path: &Path,
+        version: &str,
+        binary: &Binary,
+        platform_name: &str,
+    ) -> anyhow::Result<()> {
+        let destination = PathBuf::from(Self::substitute(
+            binary.destination.as_str(),
+            version,
+            platform_name,
+        ));
+        let signature_path = PathBuf::from(format!(
+            "{}.{}",
+            destination.to_string_lossy(),
+            Self::SIGNATURE_EXTENSION
+        ));
+        if !signature_path.exists() {
+            let url = Self::substitute(
+                binary.signature.as_deref().expect("Always exists"),
+                version,
+                platform_name,
+            );
+            let signature = self
+                .http_client
+                .get(url.as_str())
+                .send()
+                .and_then(reqwest::blocking::Response::error_for_status)
+                .and_then(reqwest::blocking::Response::bytes)
+                .map_err(|error| anyhow::anyhow!("Signature `{url}` downloading: {error}"))?;
+            std::fs::write(signature_path.as_path(), signature).map_err(|error| {
+                anyhow::anyhow!("Signature {signature_path:?} writing: {error}")
+            })?;
+        }
+
+        let output = std::process::Command::new("gpg")
+            .arg("--batch")
+            .arg("--verify")
+            .arg(signature_path.as_path())
+            .arg(destination.as_path())
+            .output()
+            .map_err(|error| {
+                anyhow::anyhow!(
+                    "The `gpg` executable is required to verify {destination:?}: {error}. Install GnuPG, or remove the `signature` of version `{version}` from {config_path:?}"
+                )
+            })?;
+        if !output.status.success() {
+            let _ = std::fs::remove_file(destination.as_path());
+            let _ = std::fs::remove_file(signature_path.as_path());
+            anyhow::bail!(
+                "Executable {destination:?} signature verification failed: {}. Make sure the release signing key is imported with `gpg --import`, and run again to download the executable anew",
+                String::from_utf8_lossy(output.stderr.as_slice()).trim(),
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the lowercase hexadecimal SHA-256 checksum of the file at `path`.
+    ///
+    fn sha256(path: &Path) -> anyhow::Result<String> {
+        let mut file = std::fs::File::open(path)
+            .map_err(|error| anyhow::anyhow!("Executable {path:?} opening: {error}"))?;
+        let mut hasher = sha2::Sha256::new();
+        let mut buffer = vec![0u8; 1 << 16];
+        loop {
+            let size = file
+                .read(buffer.as_mut_slice())
+                .map_err(|error| anyhow::anyhow!("Executable {path:?} reading: {error}"))?;
+            if size == 0 {
+                break;
+            }
+            hasher.update(&buffer[..size]);
+        }
+        Ok(hex::encode(hasher.finalize()))
+    }
+
+    ///
+    /// Substitutes the version and platform name into a configuration `template`.
+    ///
+    fn substitute(template: &str, version: &str, platform_name: &str) -> String {
+        template
+            .replace("${VERSION}", version)
+            .replace("${PLATFORM}", platform_name)
+    }
+
+    ///
+    /// Returns the platform of the `os` and `arch` as named in the download configurations,
+    /// if supported.
+    ///
+    fn platform(os: &str, arch: &str) -> Option<&'static str> {
+        match (os, arch) {
+            ("linux", "x86_64") => Some("linux-amd64"),
+            ("linux", "aarch64") => Some("linux-arm64"),
+            ("macos", "x86_64") => Some("macos-amd64"),
+            ("macos", "aarch64") => Some("macos-arm64"),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::config::binary::Binary;
+    use super::config::Config;
+    use super::DownloadVerifier;
+
+    /// The SHA-256 checksum matching none of the test executables.
+    const MISMATCHING_SHA256: &str =
+        "0000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test]
+    fn platform() {
+        for (os, arch, expected) in [
+            ("linux", "x86_64", Some("linux-amd64")),
+            ("linux", "aarch64", Some("linux-arm64")),
+            ("macos", "x86_64", Some("macos-amd64")),
+            ("macos", "aarch64", Some("macos-arm64")),
+            ("windows", "x86_64", None),
+            ("linux", "riscv64", None),
+        ] {
+            assert_eq!(
+                DownloadVerifier::platform(os, arch),
+                expected,
+                "{os} {arch}"
+            );
+        }
+    }
+
+    #[test]
+    fn substitute() {
+        assert_eq!(
+            DownloadVerifier::substitute(
+                "https://host/${VERSION}/solc-${PLATFORM}-${VERSION}",
+                "0.8.28",
+                "macosx-arm64",
+            ),
+            "https://host/0.8.28/solc-macosx-arm64-0.8.28"
+        );
+    }
+
+    #[test]
+    fn remove_mismatching() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-download-verifier-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let content_sha256 = {
+            let path = directory.join("solc-reference");
+            std::fs::write(path.as_path(), "solc").expect("Always valid");
+            DownloadVerifier::sha256(path.as_path()).expect("Always valid")
+        };
+        let binary = |is_enabled: bool, sha256: Option<&str>| Binary {
+            is_enabled,
+            source: "https://host/${VERSION}/solc-${PLATFORM}".to_owned(),
+            destination: format!("{}/solc-${{PLATFORM}}-${{VERSION}}", directory.display()),
+            sha256: sha256
+                .map(|sha256| BTreeMap::from([("linux-amd64".to_owned(), sha256.to_owned())]))
+                .unwrap_or_default(),
+            signature: None,
+        };
+        let config = Config {
+            binaries: BTreeMap::from([
+                (
+                    "0.8.1".to_owned(),
+                    binary(true, Some(content_sha256.as_str())),
+                ),
+                (
+                    "0.8.2".to_owned(),
+                    binary(true, Some(content_sha256.to_uppercase().as_str())),
+                ),
+                ("0.8.3".to_owned(), binary(true, Some(MISMATCHING_SHA256))),
+                ("0.8.4".to_owned(), binary(false, Some(MISMATCHING_SHA256))),
+                ("0.8.5".to_owned(), binary(true, None)),
+            ]),
+            platforms: BTreeMap::from([("linux-amd64".to_owned(), "amd64".to_owned())]),
+        };
+        for version in config.binaries.keys() {
+            std::fs::write(directory.join(format!("solc-amd64-{version}")), "solc")
+                .expect("Always valid");
+        }
+
+        let mismatching = DownloadVerifier::remove_mismatching(
+            directory.join("config.json").as_path(),
+            &config,
+            "linux-amd64",
+            "amd64",
+        )
+        .expect("Always valid");
+
+        let mismatching: Vec<&str> = mismatching
+            .into_iter()
+            .map(|(version, _)| version.as_str())
+            .collect();
+        assert_eq!(mismatching, vec!["0.8.3"]);
+        for (version, exists) in [
+            ("0.8.1", true),
+            ("0.8.2", true),
+            ("0.8.3", false),
+            ("0.8.4", true),
+            ("0.8.5", true),
+        ] {
+            assert_eq!(
+                directory.join(format!("solc-amd64-{version}")).exists(),
+                exists,
+                "{version}"
+            );
+        }
+
+        let result = DownloadVerifier::remove_mismatching(
+            directory.join("config.json").as_path(),
+            &config,
+            "linux-amd64",
+            "amd64",
+        );
+        assert!(result.is_err());
+
+        std::fs::remove_dir_all(directory).expect("Always valid");
+    }
+}
@@ -0,0 +1,89 @@
+//!
+//! The compiler tester dry run statistics.
+//!
+
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+
+use crate::compilers::mode::Mode;
+
+///
+/// The compiler tester dry run statistics.
+///
+/// Counts the compilations that would be performed after test discovery and mode expansion.
+/// The counts are an upper bound, since the mode restrictions in test metadata are only
+/// checked when the tests are built.
+///
+#[derive(Debug, Default)]
+pub struct DryRun {
+    /// The number of compilations, keyed by the compiler, version, and codegen.
+    pub compilations:
+        BTreeMap<(&'static str, Option<semver::Version>, Option<&'static str>), usize>,
+}
+
+impl DryRun {
+    ///
+    /// Counts a compilation of a test in `mode`.
+    ///
+    pub fn push(&mut self, mode: &Mode) {
+        *self
+            .compilations
+            .entry((
+                mode.compiler_name(),
+                mode.version().cloned(),
+                mode.codegen(),
+            ))
+            .or_default() += 1;
+    }
+
+    ///
+    /// Returns the total number of compilations.
+    ///
+    pub fn total(&self) -> usize {
+        self.compilations.values().sum()
+    }
+
+    ///
+    /// Returns the number of compilations by compiler.
+    ///
+    pub fn by_compiler(&self) -> BTreeMap<&'static str, usize> {
+        let mut compilers = BTreeMap::new();
+        for ((compiler, _, _), count) in self.compilations.iter() {
+            *compilers.entry(*compiler).or_default() += count;
+        }
+        compilers
+    }
+}
+
+impl std::fmt::Display for DryRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} {} compilations would be performed",
+            "Dry run".bright_green().bold(),
+            self.total(),
+        )?;
+        for (compiler, count) in self.by_compiler().into_iter() {
+            writeln!(f, "{:>10} {}", count, compiler.bright_white())?;
+            for ((_, version, codegen), count) in self
+                .compilations
+                .iter()
+                .filter(|((name, _, _), _)| *name == compiler)
+            {
+                let mut details = Vec::with_capacity(2);
+                if let Some(version) = version {
+                    details.push(format!("v{version}"));
+                }
+                if let Some(codegen) = codegen {
+                    details.push(codegen.to_string());
+                }
+                if details.is_empty() {
+                    continue;
+                }
+                writeln!(f, "{:>14} {}", count, details.join(" "))?;
+            }
+        }
+        Ok(())
+    }
+}
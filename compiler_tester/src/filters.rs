@@ -3,13 +3,16 @@
 //!
 
 use std::collections::HashSet;
+use std::path::Path;
 
 use crate::compilers::mode::Mode;
 
 ///
 /// The compiler tester filters.
 ///
-#[derive(Debug)]
+/// The default filters select all tests.
+///
+#[derive(Debug, Default, Clone)]
 pub struct Filters {
     /// The path filters.
     path_filters: HashSet<String>,
@@ -17,6 +20,14 @@ pub struct Filters {
     mode_filters: HashSet<String>,
     /// The group filters.
     group_filters: HashSet<String>,
+    /// The tag filters.
+    tag_filters: HashSet<String>,
+    /// The excluded tag filters.
+    exclude_tag_filters: HashSet<String>,
+    /// The included test selectors, if the list has been specified.
+    include_selectors: Option<HashSet<String>>,
+    /// The excluded test selectors.
+    exclude_selectors: HashSet<String>,
 }
 
 impl Filters {
@@ -27,18 +38,62 @@ impl Filters {
         path_filters: Vec<String>,
         mode_filters: Vec<String>,
         group_filters: Vec<String>,
+        tag_filters: Vec<String>,
+        exclude_tag_filters: Vec<String>,
+        include_selectors: Option<Vec<String>>,
+        exclude_selectors: Vec<String>,
     ) -> Self {
         Self {
             path_filters: path_filters.into_iter().collect(),
             mode_filters: mode_filters.into_iter().collect(),
             group_filters: group_filters.into_iter().collect(),
+            tag_filters: tag_filters.into_iter().collect(),
+            exclude_tag_filters: exclude_tag_filters.into_iter().collect(),
+            include_selectors: include_selectors
+                .map(|include_selectors| include_selectors.into_iter().collect()),
+            exclude_selectors: exclude_selectors.into_iter().collect(),
         }
     }
 
+    ///
+    /// Reads the newline-separated test selectors from the file at `path`.
+    ///
+    /// Empty lines and lines starting with `#` are skipped. If a line contains several words,
+    /// e.g. a mode and a selector from a summary, the last one is taken as the selector.
+    /// The input part of a selector is dropped, since the filters apply to tests and cases.
+    ///
+    pub fn read_selectors(path: &Path) -> anyhow::Result<Vec<String>> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Selector list file {path:?} reading: {error}"))?;
+        let selectors = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_whitespace().last())
+            .map(|selector| match selector.find('[') {
+                Some(position) => selector[..position].to_owned(),
+                None => selector.to_owned(),
+            })
+            .collect();
+        Ok(selectors)
+    }
+
     ///
     /// Check if the test path is compatible with the filters.
     ///
     pub fn check_test_path(&self, path: &str) -> bool {
+        if self.exclude_selectors.contains(path) {
+            return false;
+        }
+        if let Some(include_selectors) = self.include_selectors.as_ref() {
+            if !include_selectors
+                .iter()
+                .any(|selector| &selector[..selector.find("::").unwrap_or(selector.len())] == path)
+            {
+                return false;
+            }
+        }
+
         if self.path_filters.is_empty() {
             return true;
         }
@@ -52,6 +107,22 @@ impl Filters {
     /// Check if the test case path is compatible with the filters.
     ///
     pub fn check_case_path(&self, path: &str) -> bool {
+        if self
+            .exclude_selectors
+            .iter()
+            .any(|selector| Self::is_selected(selector, path))
+        {
+            return false;
+        }
+        if let Some(include_selectors) = self.include_selectors.as_ref() {
+            if !include_selectors
+                .iter()
+                .any(|selector| Self::is_selected(selector, path))
+            {
+                return false;
+            }
+        }
+
         self.path_filters.is_empty() || self.path_filters.iter().any(|filter| path.contains(filter))
     }
 
@@ -76,4 +147,86 @@ impl Filters {
             false
         }
     }
+
+    ///
+    /// Check if the test tags are compatible with the filters.
+    ///
+    /// The test must have at least one of the requested tags, and none of the excluded ones.
+    ///
+    pub fn check_tags(&self, tags: &[String]) -> bool {
+        if !self.tag_filters.is_empty() && !tags.iter().any(|tag| self.tag_filters.contains(tag)) {
+            return false;
+        }
+
+        !tags
+            .iter()
+            .any(|tag| self.exclude_tag_filters.contains(tag))
+    }
+
+    ///
+    /// Check if the test tags are compatible with the filters with any subset of the `optional`
+    /// tags, which are only known once the test is read.
+    ///
+    pub fn may_check_tags(&self, tags: &[String], optional: &[&str]) -> bool {
+        (0..1usize << optional.len()).any(|mask| {
+            let mut tags = tags.to_vec();
+            tags.extend(
+                optional
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| mask & (1 << index) != 0)
+                    .map(|(_, tag)| tag.to_string()),
+            );
+            self.check_tags(tags.as_slice())
+        })
+    }
+
+    ///
+    /// Whether the case `path` is selected by the `selector` of either the case or its test.
+    ///
+    fn is_selected(selector: &str, path: &str) -> bool {
+        path == selector
+            || path
+                .strip_prefix(selector)
+                .is_some_and(|case| case.starts_with("::"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filters;
+
+    ///
+    /// Returns the filters with the included and excluded tags.
+    ///
+    fn filters(tags: &[&str], exclude_tags: &[&str]) -> Filters {
+        Filters::new(
+            vec![],
+            vec![],
+            vec![],
+            tags.iter().map(|tag| tag.to_string()).collect(),
+            exclude_tags.iter().map(|tag| tag.to_string()).collect(),
+            None,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn may_check_tags() {
+        let tags = vec!["events".to_owned()];
+        for (filters, expected) in [
+            (filters(&[], &[]), true),
+            (filters(&["imports"], &[]), true),
+            (filters(&["slow"], &[]), false),
+            (filters(&[], &["imports"]), true),
+            (filters(&[], &["events"]), false),
+            (filters(&["imports"], &["multi-source"]), true),
+        ] {
+            assert_eq!(
+                filters.may_check_tags(tags.as_slice(), &["multi-source", "imports"]),
+                expected,
+                "{filters:?}"
+            );
+        }
+    }
 }
@@ -0,0 +1,56 @@
+//!
+//! The test run interruption flag.
+//!
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+/// Whether the test run has been interrupted by a signal.
+static IS_INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the summary of the test run is being written.
+static IS_FINISHING: AtomicBool = AtomicBool::new(false);
+
+///
+/// Marks the test run as interrupted, so no new tests are scheduled.
+///
+pub fn interrupt() {
+    IS_INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+///
+/// Whether the test run has been interrupted.
+///
+pub fn is_interrupted() -> bool {
+    IS_INTERRUPTED.load(Ordering::SeqCst)
+}
+
+///
+/// Claims writing the summary of the test run, and returns whether it has not been claimed yet.
+///
+/// The summary is written either on completion, or by the interruption handlers, whichever
+/// comes first, so it is never written twice.
+///
+pub fn start_finishing() -> bool {
+    !IS_FINISHING.swap(true, Ordering::SeqCst)
+}
+
+///
+/// Blocks the calling thread forever, after the summary has been claimed by another thread,
+/// which exits the process once it is written.
+///
+pub fn wait_for_exit() -> ! {
+    loop {
+        std::thread::park();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn start_finishing() {
+        assert!(super::start_finishing());
+        assert!(!super::start_finishing());
+        assert!(!super::start_finishing());
+    }
+}
@@ -0,0 +1,183 @@
+//!
+//! The compiler tester invalid tests quarantine.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use colored::Colorize;
+
+///
+/// The compiler tester invalid tests quarantine.
+///
+/// Remembers since when each test has been reported invalid, e.g. due to its metadata or
+/// compilation failures, so the tests left broken for a long time can be reported. A test is
+/// released from the quarantine as soon as it is run without being invalid, and the tests
+/// not run at all, e.g. filtered out, keep their state.
+///
+#[derive(Debug)]
+pub struct InvalidTests {
+    /// The state file path.
+    path: PathBuf,
+    /// The UNIX timestamps in seconds since which the tests have been invalid, by test name.
+    since: BTreeMap<String, u64>,
+    /// The current UNIX timestamp in seconds.
+    now: u64,
+}
+
+impl InvalidTests {
+    /// The number of seconds in a day.
+    const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+    ///
+    /// Reads the state from `path`, or creates an empty one if the file does not exist.
+    ///
+    pub fn new(path: PathBuf) -> anyhow::Result<Self> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Always valid")
+            .as_secs();
+        Self::new_at(path, now)
+    }
+
+    ///
+    /// Reads the state from `path` as of the UNIX timestamp `now` in seconds, or creates an empty
+    /// one if the file does not exist.
+    ///
+    pub fn new_at(path: PathBuf, now: u64) -> anyhow::Result<Self> {
+        let since = if path.exists() {
+            let text = std::fs::read_to_string(path.as_path()).map_err(|error| {
+                anyhow::anyhow!("Invalid tests state {path:?} reading: {error}")
+            })?;
+            serde_json::from_str(text.as_str())
+                .map_err(|error| anyhow::anyhow!("Invalid tests state {path:?} parsing: {error}"))?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self { path, since, now })
+    }
+
+    ///
+    /// Updates the state with the validity of the tests run, given by test name.
+    ///
+    pub fn update(&mut self, validity: BTreeMap<String, bool>) {
+        for (name, is_invalid) in validity.into_iter() {
+            if is_invalid {
+                self.since.entry(name).or_insert(self.now);
+            } else {
+                self.since.remove(name.as_str());
+            }
+        }
+    }
+
+    ///
+    /// Returns the number of tests invalid for more than `days`.
+    ///
+    pub fn stale_count(&self, days: u64) -> usize {
+        self.since
+            .values()
+            .filter(|since| self.age_days(**since) > days)
+            .count()
+    }
+
+    ///
+    /// Writes the state back to its file.
+    ///
+    pub fn write(&self) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(&self.since).expect("Always valid");
+        std::fs::write(self.path.as_path(), contents).map_err(|error| {
+            anyhow::anyhow!("Invalid tests state {:?} writing: {error}", self.path)
+        })
+    }
+
+    ///
+    /// Returns the number of full days elapsed since the UNIX timestamp `since`.
+    ///
+    fn age_days(&self, since: u64) -> u64 {
+        self.now.saturating_sub(since) / Self::SECONDS_PER_DAY
+    }
+}
+
+impl std::fmt::Display for InvalidTests {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.since.is_empty() {
+            return Ok(());
+        }
+
+        let mut tests: Vec<(u64, &str)> = self
+            .since
+            .iter()
+            .map(|(name, since)| (self.age_days(*since), name.as_str()))
+            .collect();
+        tests.sort_by(|(age_a, name_a), (age_b, name_b)| {
+            age_b.cmp(age_a).then_with(|| name_a.cmp(name_b))
+        });
+
+        writeln!(
+            f,
+            " {} {} tests:",
+            "Quarantined".bright_red().bold(),
+            tests.len()
+        )?;
+        for (age, name) in tests.into_iter() {
+            writeln!(f, "{:>12} {name}", format!("{age}d"))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::InvalidTests;
+
+    #[test]
+    fn aging() {
+        let path = std::env::temp_dir().join(format!(
+            "era-compiler-tester-invalid-tests-{}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(path.as_path());
+        let day = InvalidTests::SECONDS_PER_DAY;
+
+        let mut invalid_tests = InvalidTests::new_at(path.clone(), 0).expect("Always valid");
+        invalid_tests.update(BTreeMap::from([
+            ("broken".to_owned(), true),
+            ("fixed".to_owned(), true),
+        ]));
+        invalid_tests.write().expect("Always valid");
+
+        let mut invalid_tests = InvalidTests::new_at(path.clone(), 5 * day).expect("Always valid");
+        invalid_tests.update(BTreeMap::from([
+            ("broken".to_owned(), true),
+            ("fixed".to_owned(), false),
+            ("new".to_owned(), true),
+        ]));
+        invalid_tests.write().expect("Always valid");
+
+        let invalid_tests = InvalidTests::new_at(path.clone(), 10 * day + 1).expect("Always valid");
+        let _ = std::fs::remove_file(path.as_path());
+
+        assert_eq!(
+            invalid_tests.since,
+            BTreeMap::from([("broken".to_owned(), 0), ("new".to_owned(), 5 * day)])
+        );
+        for (days, expected) in [(0, 2), (5, 1), (9, 1), (10, 0)] {
+            assert_eq!(
+                invalid_tests.stale_count(days),
+                expected,
+                "Invalid stale count for {days} days"
+            );
+        }
+        assert!(
+            invalid_tests
+                .to_string()
+                .ends_with("         10d broken\n          5d new\n"),
+            "{invalid_tests}"
+        );
+    }
+}
@@ -1,32 +1,59 @@
 //!
 //! The compiler tester library.
 //!
+//! Besides the `compiler-tester` executable, the library can be used by other toolchains
+//! to discover, build, and run the tests directly. The items re-exported from the crate root
+//! are the public API, which follows semantic versioning. The modules are internal.
+//!
+//! A typical run creates a [`Summary`] and [`Filters`], passes them to a [`CompilerTester`],
+//! and inspects the [`SummaryElement`]s after running the tests on a VM.
+//!
 
 #![allow(non_camel_case_types)]
 #![allow(clippy::upper_case_acronyms)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::type_complexity)]
 
+pub(crate) mod assembly_diff;
+pub(crate) mod bytecode_run;
 pub(crate) mod compilers;
 pub(crate) mod directories;
+pub(crate) mod disassembly;
+pub(crate) mod docs;
+pub(crate) mod download_verifier;
+pub(crate) mod dry_run;
 pub(crate) mod environment;
 pub(crate) mod filters;
+pub(crate) mod interruption;
+pub(crate) mod invalid_tests;
+pub(crate) mod lint;
+pub(crate) mod result_cache;
 pub(crate) mod summary;
+pub(crate) mod target_extension;
 pub(crate) mod test;
+pub(crate) mod time_budget;
 pub(crate) mod toolchain;
 pub(crate) mod utils;
 pub(crate) mod vm;
 pub(crate) mod workflow;
 
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Instant;
 
 use itertools::Itertools;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::docs::Documented;
+use crate::lint::Linted;
+
+pub use crate::assembly_diff::AssemblyDiff;
+pub use crate::bytecode_run::BytecodeRun;
 pub use crate::compilers::eravm::EraVMCompiler;
+pub use crate::compilers::invocation_log::InvocationLog;
 pub use crate::compilers::llvm::LLVMCompiler;
 pub use crate::compilers::mode::llvm_options::LLVMOptions;
 pub use crate::compilers::mode::Mode;
@@ -40,26 +67,88 @@ pub use crate::compilers::Compiler;
 pub use crate::directories::ethereum::test::EthereumTest;
 pub use crate::directories::ethereum::EthereumDirectory;
 pub use crate::directories::matter_labs::MatterLabsDirectory;
+pub use crate::directories::real_world::RealWorldDirectory;
+pub use crate::directories::state_tests::StateTestsDirectory;
+pub use crate::directories::system_contracts::SystemContractsDirectory;
 pub use crate::directories::Buildable;
 pub use crate::directories::Collection;
+pub use crate::disassembly::Disassembly;
+pub use crate::docs::Docs;
+pub use crate::dry_run::DryRun;
 pub use crate::environment::Environment;
 pub use crate::filters::Filters;
+pub use crate::interruption::interrupt;
+pub use crate::interruption::is_interrupted;
+pub use crate::interruption::start_finishing;
+pub use crate::interruption::wait_for_exit;
+pub use crate::invalid_tests::InvalidTests;
+pub use crate::lint::kind::Kind as LintKind;
+pub use crate::lint::warning::Warning as LintWarning;
+pub use crate::lint::Lint;
+pub use crate::result_cache::ResultCache;
+pub use crate::summary::benchmark_policy::BenchmarkPolicy as SummaryBenchmarkPolicy;
+pub use crate::summary::benchmark_registry::group::Group as SummaryBenchmarkGroup;
+pub use crate::summary::benchmark_registry::BenchmarkRegistry as SummaryBenchmarkRegistry;
+pub use crate::summary::element::outcome::deploy_metrics::DeployMetrics as SummaryDeployMetrics;
+pub use crate::summary::element::outcome::error_code::ErrorCode as SummaryErrorCode;
+pub use crate::summary::element::outcome::passed_variant::PassedVariant as SummaryPassedVariant;
+pub use crate::summary::element::outcome::Outcome as SummaryOutcome;
+pub use crate::summary::element::Element as SummaryElement;
+pub use crate::summary::expected_outcomes::ExpectedOutcomes as SummaryExpectedOutcomes;
+pub use crate::summary::report::comparison::Comparison as SummaryComparison;
+pub use crate::summary::report::cross_check::CrossCheck as SummaryCrossCheck;
+pub use crate::summary::report::interface_check::interface::Interface as SummaryInterface;
+pub use crate::summary::report::interface_check::InterfaceCheck as SummaryInterfaceCheck;
+pub use crate::summary::report::matrix::Matrix as SummaryMatrix;
+pub use crate::summary::report::output::Output as SummaryReportOutput;
+pub use crate::summary::report::parity::Parity as SummaryParity;
+pub use crate::summary::report::status::Status as SummaryStatus;
+pub use crate::summary::report::Report as SummaryReport;
+pub use crate::summary::sink::console::ConsoleSink as SummaryConsoleSink;
+pub use crate::summary::sink::events::EventsSink as SummaryEventsSink;
+pub use crate::summary::sink::json::JsonSink as SummaryJsonSink;
+pub use crate::summary::sink::junit::JUnitSink as SummaryJUnitSink;
+pub use crate::summary::sink::SummarySink;
 pub use crate::summary::Summary;
+pub use crate::target_extension::get as get_target_extension;
+pub use crate::target_extension::register as register_target_extension;
+pub use crate::target_extension::TargetExtension;
+pub use crate::test::case::input::output::Output;
+pub use crate::test::case::input::value::Value;
+pub use crate::test::case::input::Input;
+pub use crate::test::case::Case;
+pub use crate::test::description::TestDescription;
+pub use crate::test::selector::registry::Registry as SelectorRegistry;
+pub use crate::test::selector::TestSelector;
+pub use crate::test::Test;
+pub use crate::time_budget::TimeBudget;
 pub use crate::toolchain::Toolchain;
 pub use crate::vm::eravm::deployers::dummy_deployer::DummyDeployer as EraVMNativeDeployer;
 pub use crate::vm::eravm::deployers::system_contract_deployer::SystemContractDeployer as EraVMSystemContractDeployer;
 pub use crate::vm::eravm::deployers::EraVMDeployer;
+pub use crate::vm::eravm::runner_settings::overrides::Overrides as EraVMRunnerOverrides;
+pub use crate::vm::eravm::runner_settings::RunnerSettings as EraVMRunnerSettings;
+pub use crate::vm::eravm::system_contracts_profile::SystemContractsProfile as EraVMSystemContractsProfile;
 pub use crate::vm::eravm::EraVM;
+pub use crate::vm::evm::input::build::Build as EVMBuild;
 pub use crate::vm::evm::EVM;
+pub use crate::vm::revm::address_overrides::AddressOverrides as RevmAddressOverrides;
+pub use crate::vm::revm::Revm;
 pub use crate::workflow::Workflow;
 
 /// The debug directory path.
 pub const DEBUG_DIRECTORY: &str = "./debug/";
 
+/// The REVM traces of failed inputs directory path.
+pub const REVM_TRACE_DIRECTORY: &str = "./debug/revm_trace/";
+
+/// The result cache file path.
+pub const RESULT_CACHE_PATH: &str = "./result_cache.json";
+
 ///
 /// The compiler test generic representation.
 ///
-type Test = (Arc<dyn Buildable>, Arc<dyn Compiler>, Mode);
+type GenericTest = (Arc<dyn Buildable>, Arc<dyn Compiler>, Mode);
 
 ///
 /// The compiler tester.
@@ -73,6 +162,14 @@ pub struct CompilerTester {
     pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     /// Actions to perform.
     pub workflow: Workflow,
+    /// The cache of passed tests, if enabled.
+    pub result_cache: Option<Arc<ResultCache>>,
+    /// The selectors of the discovered tests.
+    pub selectors: SelectorRegistry,
+    /// The time budget the tests are selected within, if specified.
+    pub time_budget: Option<Arc<TimeBudget>>,
+    /// The seed the tests are shuffled with, if specified.
+    pub shuffle_seed: Option<u64>,
 }
 
 impl CompilerTester {
@@ -80,10 +177,15 @@ impl CompilerTester {
     const SOLIDITY_SIMPLE: &'static str = "tests/solidity/simple";
     /// The Solidity complex tests directory.
     const SOLIDITY_COMPLEX: &'static str = "tests/solidity/complex";
+    /// The Solidity real-world protocol tests directory.
+    const SOLIDITY_REAL_WORLD: &'static str = "tests/solidity/complex/real-world";
     /// The Solidity Ethereum tests directory.
     const SOLIDITY_ETHEREUM: &'static str = "tests/solidity/ethereum";
     /// The Solidity Ethereum upstream tests directory.
-    const SOLIDITY_ETHEREUM_UPSTREAM: &'static str = "solidity/test/libsolidity/semanticTests";
+    pub const SOLIDITY_ETHEREUM_UPSTREAM: &'static str = "solidity/test/libsolidity/semanticTests";
+
+    /// The Ethereum upstream state tests directory.
+    const STATE_TESTS_UPSTREAM: &'static str = "ethereum-tests/GeneralStateTests";
 
     /// The Vyper simple tests directory.
     const VYPER_SIMPLE: &'static str = "tests/vyper/simple";
@@ -100,6 +202,9 @@ impl CompilerTester {
 
     /// The EraVM simple tests directory.
     const ERAVM_SIMPLE: &'static str = "tests/eravm";
+
+    /// The EraVM system contracts benchmark pseudo-directory.
+    const ERAVM_SYSTEM_CONTRACTS: &'static str = "system-contracts";
 }
 
 impl CompilerTester {
@@ -111,43 +216,81 @@ impl CompilerTester {
         filters: Filters,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
         workflow: Workflow,
+        result_cache: Option<Arc<ResultCache>>,
     ) -> anyhow::Result<Self> {
         Ok(Self {
             summary,
             filters,
             debug_config,
             workflow,
+            result_cache,
+            selectors: SelectorRegistry::default(),
+            time_budget: None,
+            shuffle_seed: None,
         })
     }
 
+    ///
+    /// Sets the time budget the tests are selected within.
+    ///
+    pub fn set_time_budget(&mut self, time_budget: Option<Arc<TimeBudget>>) {
+        self.time_budget = time_budget;
+    }
+
+    ///
+    /// Sets the seed the tests are shuffled with.
+    ///
+    pub fn set_shuffle_seed(&mut self, shuffle_seed: Option<u64>) {
+        self.shuffle_seed = shuffle_seed;
+    }
+
     ///
     /// Runs all tests on EraVM.
     ///
-    pub fn run_eravm<D, const M: bool>(self, vm: EraVM, toolchain: Toolchain) -> anyhow::Result<()>
-    where
-        D: EraVMDeployer,
-    {
+    /// The `runner_settings` are overridden by the tests requiring other ones.
+    ///
+    pub fn run_eravm(
+        self,
+        vm: EraVM,
+        toolchain: Toolchain,
+        runner_settings: EraVMRunnerSettings,
+    ) -> anyhow::Result<()> {
         let tests = self.all_tests(era_compiler_common::Target::EraVM, toolchain)?;
+        let system_contracts_hash = self
+            .result_cache
+            .as_ref()
+            .map(|_| vm.system_contracts_hash());
         let vm = Arc::new(vm);
 
         let _: Vec<()> = tests
             .into_par_iter()
             .map(|(test, compiler, mode)| {
+                if interruption::is_interrupted() {
+                    return;
+                }
                 let mode_string = mode.to_string();
-                let specialized_debug_config = self
-                    .debug_config
-                    .as_ref()
-                    .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
-                if let Some(test) = test.build_for_eravm(
-                    mode,
-                    compiler,
-                    Environment::ZkEVM,
-                    self.summary.clone(),
-                    &self.filters,
-                    specialized_debug_config,
-                ) {
+                let specialized_debug_config = self.debug_config.as_ref().map(|config| {
+                    crate::utils::debug_config_for_mode(config, mode_string.as_str())
+                });
+                if let Some(test) = self.build_timed(test.selector(), mode.clone(), || {
+                    test.build_for_eravm(
+                        mode,
+                        compiler,
+                        Environment::ZkEVM,
+                        self.summary.clone(),
+                        &self.filters,
+                        specialized_debug_config,
+                    )
+                }) {
                     if let Workflow::BuildAndRun = self.workflow {
-                        test.run_eravm::<D, M>(self.summary.clone(), vm.clone())
+                        self.run_cached(
+                            test,
+                            Environment::ZkEVM,
+                            system_contracts_hash.as_deref(),
+                            |test| {
+                                test.run_eravm(self.summary.clone(), vm.clone(), runner_settings)
+                            },
+                        );
                     };
                 }
             })
@@ -159,27 +302,34 @@ impl CompilerTester {
     ///
     /// Runs all tests on EVM emulator.
     ///
+    /// The results are not cached, as the emulator has no environment to key them by, and sharing
+    /// the REVM key would skip the REVM runs of the tests passed on the emulator.
+    ///
     pub fn run_evm(self, toolchain: Toolchain) -> anyhow::Result<()> {
         let tests = self.all_tests(era_compiler_common::Target::EVM, toolchain)?;
 
         let _: Vec<()> = tests
             .into_par_iter()
             .map(|(test, compiler, mode)| {
+                if interruption::is_interrupted() {
+                    return;
+                }
                 let mode_string = mode.to_string();
-                let specialized_debug_config = self
-                    .debug_config
-                    .as_ref()
-                    .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
-                if let Some(test) = test.build_for_evm(
-                    mode,
-                    compiler,
-                    Environment::REVM,
-                    self.summary.clone(),
-                    &self.filters,
-                    specialized_debug_config,
-                ) {
+                let specialized_debug_config = self.debug_config.as_ref().map(|config| {
+                    crate::utils::debug_config_for_mode(config, mode_string.as_str())
+                });
+                if let Some(test) = self.build_timed(test.selector(), mode.clone(), || {
+                    test.build_for_evm(
+                        mode,
+                        compiler,
+                        Environment::REVM,
+                        self.summary.clone(),
+                        &self.filters,
+                        specialized_debug_config,
+                    )
+                }) {
                     if let Workflow::BuildAndRun = self.workflow {
-                        test.run_evm_emulator(self.summary.clone())
+                        self.run_timed(test, |test| test.run_evm_emulator(self.summary.clone()));
                     };
                 }
             })
@@ -191,27 +341,65 @@ impl CompilerTester {
     ///
     /// Runs all tests on REVM.
     ///
-    pub fn run_revm(self, toolchain: Toolchain) -> anyhow::Result<()> {
+    /// If `trace_on_failure` is set, the traces of failed inputs are written to `REVM_TRACE_DIRECTORY`.
+    /// The `address_overrides` stubs are installed into the state of every case.
+    ///
+    pub fn run_revm(
+        self,
+        toolchain: Toolchain,
+        trace_on_failure: bool,
+        address_overrides: RevmAddressOverrides,
+    ) -> anyhow::Result<()> {
+        let trace_directory = if trace_on_failure {
+            std::fs::create_dir_all(REVM_TRACE_DIRECTORY)?;
+            Some(Path::new(REVM_TRACE_DIRECTORY))
+        } else {
+            None
+        };
+        let address_overrides_hash = if address_overrides.inner.is_empty() {
+            None
+        } else {
+            Some(address_overrides.hash())
+        };
+        let address_overrides = address_overrides.bytecodes()?;
+
         let tests = self.all_tests(era_compiler_common::Target::EVM, toolchain)?;
 
         let _: Vec<()> = tests
             .into_par_iter()
             .map(|(test, compiler, mode)| {
+                if interruption::is_interrupted() {
+                    return;
+                }
                 let mode_string = mode.to_string();
-                let specialized_debug_config = self
-                    .debug_config
-                    .as_ref()
-                    .and_then(|config| config.create_subdirectory(mode_string.as_str()).ok());
-                if let Some(test) = test.build_for_evm(
-                    mode,
-                    compiler,
-                    Environment::REVM,
-                    self.summary.clone(),
-                    &self.filters,
-                    specialized_debug_config,
-                ) {
+                let specialized_debug_config = self.debug_config.as_ref().map(|config| {
+                    crate::utils::debug_config_for_mode(config, mode_string.as_str())
+                });
+                if let Some(test) = self.build_timed(test.selector(), mode.clone(), || {
+                    test.build_for_evm(
+                        mode,
+                        compiler,
+                        Environment::REVM,
+                        self.summary.clone(),
+                        &self.filters,
+                        specialized_debug_config,
+                    )
+                }) {
                     if let Workflow::BuildAndRun = self.workflow {
-                        test.run_revm(self.summary.clone())
+                        self.run_cached(
+                            test,
+                            Environment::REVM,
+                            address_overrides_hash.as_deref(),
+                            |test| {
+                                self.disassemble_on_failure(test, |test| {
+                                    test.run_revm(
+                                        self.summary.clone(),
+                                        trace_directory,
+                                        address_overrides.as_slice(),
+                                    )
+                                })
+                            },
+                        );
                     };
                 }
             })
@@ -223,30 +411,52 @@ impl CompilerTester {
     ///
     /// Runs all tests on EVM interpreter.
     ///
-    pub fn run_evm_interpreter<D, const M: bool>(
+    /// The `runner_settings` are overridden by the tests requiring other ones.
+    ///
+    pub fn run_evm_interpreter(
         self,
         vm: EraVM,
         toolchain: Toolchain,
-    ) -> anyhow::Result<()>
-    where
-        D: EraVMDeployer,
-    {
+        runner_settings: EraVMRunnerSettings,
+    ) -> anyhow::Result<()> {
         let tests = self.all_tests(era_compiler_common::Target::EVM, toolchain)?;
+        let system_contracts_hash = self
+            .result_cache
+            .as_ref()
+            .map(|_| vm.system_contracts_hash());
         let vm = Arc::new(vm);
 
         let _: Vec<()> = tests
             .into_par_iter()
             .map(|(test, compiler, mode)| {
-                if let Some(test) = test.build_for_evm(
-                    mode,
-                    compiler,
-                    Environment::EVMInterpreter,
-                    self.summary.clone(),
-                    &self.filters,
-                    self.debug_config.clone(),
-                ) {
+                if interruption::is_interrupted() {
+                    return;
+                }
+                if let Some(test) = self.build_timed(test.selector(), mode.clone(), || {
+                    test.build_for_evm(
+                        mode,
+                        compiler,
+                        Environment::EVMInterpreter,
+                        self.summary.clone(),
+                        &self.filters,
+                        self.debug_config.clone(),
+                    )
+                }) {
                     if let Workflow::BuildAndRun = self.workflow {
-                        test.run_evm_interpreter::<D, M>(self.summary.clone(), vm.clone());
+                        self.run_cached(
+                            test,
+                            Environment::EVMInterpreter,
+                            system_contracts_hash.as_deref(),
+                            |test| {
+                                self.disassemble_on_failure(test, |test| {
+                                    test.run_evm_interpreter(
+                                        self.summary.clone(),
+                                        vm.clone(),
+                                        runner_settings,
+                                    )
+                                })
+                            },
+                        );
                     }
                 }
             })
@@ -255,6 +465,218 @@ impl CompilerTester {
         Ok(())
     }
 
+    ///
+    /// Runs the tests of the target `extension`.
+    ///
+    /// The tests are built with the pipeline of the extension base environment, and run by
+    /// the extension itself. The benchmark of the run can be collected with
+    /// `Summary::extension_benchmark`.
+    ///
+    pub fn run_extension(
+        self,
+        extension: Arc<dyn TargetExtension>,
+        toolchain: Toolchain,
+    ) -> anyhow::Result<()> {
+        let environment = extension.base_environment();
+        let target = era_compiler_common::Target::from(environment);
+        let compiler = extension.compiler(toolchain);
+
+        let mut tests = Vec::new();
+        for (path, file_extension) in extension.directories().into_iter() {
+            tests.extend(self.directory::<MatterLabsDirectory>(
+                target,
+                path,
+                file_extension,
+                compiler.clone(),
+            )?);
+        }
+
+        let _: Vec<()> = tests
+            .into_par_iter()
+            .map(|(test, compiler, mode)| {
+                if interruption::is_interrupted() {
+                    return;
+                }
+                let mode_string = mode.to_string();
+                let specialized_debug_config = self.debug_config.as_ref().map(|config| {
+                    crate::utils::debug_config_for_mode(config, mode_string.as_str())
+                });
+                if let Some(test) = self.build_timed(test.selector(), mode.clone(), || match target
+                {
+                    era_compiler_common::Target::EraVM => test.build_for_eravm(
+                        mode,
+                        compiler,
+                        environment,
+                        self.summary.clone(),
+                        &self.filters,
+                        specialized_debug_config,
+                    ),
+                    era_compiler_common::Target::EVM => test.build_for_evm(
+                        mode,
+                        compiler,
+                        environment,
+                        self.summary.clone(),
+                        &self.filters,
+                        specialized_debug_config,
+                    ),
+                }) {
+                    if let Workflow::BuildAndRun = self.workflow {
+                        self.run_cached(test, environment, Some(extension.name()), |test| {
+                            extension.run(test, self.summary.clone())
+                        });
+                    }
+                }
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    ///
+    /// Runs the test with `run`, unless it has passed with the same key in a previous run.
+    ///
+    /// The test is remembered as passed if it has no failed or invalid outcomes.
+    ///
+    fn run_cached<F>(
+        &self,
+        test: Test,
+        environment: Environment,
+        vm_configuration_hash: Option<&str>,
+        run: F,
+    ) where
+        F: FnOnce(Test),
+    {
+        let result_cache = match self.result_cache.as_ref() {
+            Some(result_cache) => result_cache,
+            None => return self.run_timed(test, run),
+        };
+
+        let key = test.result_cache_key(environment, vm_configuration_hash);
+        let description = test.description();
+        if result_cache.contains(key.as_str()) {
+            Summary::cached_pass(self.summary.clone(), description);
+            return;
+        }
+
+        self.run_timed(test, run);
+        if interruption::is_interrupted() {
+            return;
+        }
+        let has_failures = self.summary.lock().expect("Sync").has_failures(
+            description.selector.path.as_str(),
+            description.mode.as_ref().expect("Always exists"),
+        );
+        if !has_failures {
+            result_cache.insert(key);
+        }
+    }
+
+    ///
+    /// Runs the test with `run`, recording its execution time.
+    ///
+    fn run_timed<F>(&self, test: Test, run: F)
+    where
+        F: FnOnce(Test),
+    {
+        let description = test.description();
+        let start = Instant::now();
+        run(test);
+        Summary::record_execution_time(self.summary.clone(), &description, start.elapsed());
+    }
+
+    ///
+    /// Runs the EVM test with `run`, and writes the disassembly of the runtime bytecode of its
+    /// contracts to the test debug directory if the test has failed.
+    ///
+    fn disassemble_on_failure<F>(&self, test: Test, run: F)
+    where
+        F: FnOnce(Test),
+    {
+        let debug_config = match self.debug_config.as_ref() {
+            Some(debug_config) => debug_config,
+            None => return run(test),
+        };
+        let description = test.description();
+        let evm_builds = test.evm_builds().clone();
+
+        run(test);
+
+        let mode = description.mode.as_ref().expect("Always exists");
+        let path = description.selector.path.as_str();
+        if !self.summary.lock().expect("Sync").has_failures(path, mode) {
+            return;
+        }
+        let debug_config = match crate::utils::debug_config_for_test(
+            Some(&crate::utils::debug_config_for_mode(
+                debug_config,
+                mode.to_string().as_str(),
+            )),
+            path,
+        ) {
+            Ok(Some(debug_config)) => debug_config,
+            Ok(None) => return,
+            Err(error) => {
+                eprintln!("EVM disassembly writing error: {error}");
+                return;
+            }
+        };
+        for (contract_path, build) in evm_builds.iter() {
+            if let Err(error) = crate::utils::write_debug_artifact(
+                &debug_config,
+                format!("{contract_path}.runtime.{}", Disassembly::EXTENSION).as_str(),
+                Disassembly::new(build.runtime_build.as_slice()).to_string(),
+            ) {
+                eprintln!("EVM disassembly writing error: {error}");
+            }
+        }
+    }
+
+    ///
+    /// Builds the test with `build`, recording its compilation time.
+    ///
+    /// The time of the failed builds is recorded as well, whereas the tests filtered out
+    /// without building are skipped.
+    ///
+    fn build_timed<F>(&self, selector: &TestSelector, mode: Mode, build: F) -> Option<Test>
+    where
+        F: FnOnce() -> Option<Test>,
+    {
+        let start = Instant::now();
+        let test = build();
+        let is_attempted = test.is_some()
+            || self
+                .summary
+                .lock()
+                .expect("Sync")
+                .has_failures(selector.path.as_str(), &mode);
+        if is_attempted {
+            let description = TestDescription {
+                group: None,
+                mode: Some(mode),
+                selector: selector.to_owned(),
+            };
+            Summary::record_compilation_time(self.summary.clone(), &description, start.elapsed());
+        }
+        test
+    }
+
+    ///
+    /// Discovers all tests and counts the compilations that would be performed for the `target`.
+    ///
+    pub fn dry_run(
+        &self,
+        target: era_compiler_common::Target,
+        toolchain: Toolchain,
+    ) -> anyhow::Result<DryRun> {
+        let mut dry_run = DryRun::default();
+        for (_test, _compiler, mode) in self.all_tests(target, toolchain)?.into_iter() {
+            if self.filters.check_mode(&mode) {
+                dry_run.push(&mode);
+            }
+        }
+        Ok(dry_run)
+    }
+
     ///
     /// Returns all tests from all directories.
     ///
@@ -262,7 +684,7 @@ impl CompilerTester {
         &self,
         target: era_compiler_common::Target,
         toolchain: Toolchain,
-    ) -> anyhow::Result<Vec<Test>> {
+    ) -> anyhow::Result<Vec<GenericTest>> {
         let solidity_compiler = Arc::new(SolidityCompiler::new());
         let solidity_upstream_compiler = Arc::new(SolidityUpstreamCompiler::new(
             SolcStandardJsonInputLanguage::Solidity,
@@ -272,7 +694,7 @@ impl CompilerTester {
             SolcStandardJsonInputLanguage::Yul,
             toolchain,
         ));
-        let yul_compiler = Arc::new(YulCompiler::new(toolchain));
+        let yul_compiler = Arc::new(YulCompiler::new());
         let vyper_compiler = Arc::new(VyperCompiler::new());
         let llvm_compiler = Arc::new(LLVMCompiler);
         let eravm_compiler = Arc::new(EraVMCompiler);
@@ -315,7 +737,13 @@ impl CompilerTester {
             target,
             Self::ERAVM_SIMPLE,
             era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
-            eravm_compiler,
+            eravm_compiler.clone(),
+        )?);
+        tests.extend(self.directory::<SystemContractsDirectory>(
+            target,
+            Self::ERAVM_SYSTEM_CONTRACTS,
+            era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
+            eravm_compiler.clone(),
         )?);
 
         tests.extend(self.directory::<MatterLabsDirectory>(
@@ -327,6 +755,15 @@ impl CompilerTester {
                 Toolchain::Solc | Toolchain::SolcLLVM => solidity_upstream_compiler.clone(),
             },
         )?);
+        tests.extend(self.directory::<RealWorldDirectory>(
+            target,
+            Self::SOLIDITY_REAL_WORLD,
+            era_compiler_common::EXTENSION_JSON,
+            match toolchain {
+                Toolchain::IrLLVM => solidity_compiler.clone(),
+                Toolchain::Solc | Toolchain::SolcLLVM => solidity_upstream_compiler.clone(),
+            },
+        )?);
         if let era_compiler_common::Target::EraVM = target {
             tests.extend(self.directory::<MatterLabsDirectory>(
                 target,
@@ -357,9 +794,221 @@ impl CompilerTester {
             )?);
         }
 
+        if let era_compiler_common::Target::EVM = target {
+            // The state tests are not compiled, so the EraVM compiler only provides its empty mode.
+            tests.extend(self.directory::<StateTestsDirectory>(
+                target,
+                Self::STATE_TESTS_UPSTREAM,
+                era_compiler_common::EXTENSION_JSON,
+                eravm_compiler,
+            )?);
+        }
+
+        if let Some(time_budget) = self.time_budget.as_ref() {
+            tests = time_budget.select(target, tests, &self.filters);
+        }
+        if let Some(seed) = self.shuffle_seed {
+            crate::utils::shuffle(tests.as_mut_slice(), seed);
+        }
+
         Ok(tests)
     }
 
+    ///
+    /// Collects the documentation index of the Matter Labs and Ethereum tests.
+    ///
+    /// The tests are read with the same parsers as for running them, so the invalid ones are
+    /// reported to the summary. The directories missing in the checkout are skipped.
+    ///
+    pub fn docs(&self) -> anyhow::Result<Docs> {
+        let mut docs = Docs::default();
+
+        for (path, extension) in [
+            (
+                Self::SOLIDITY_SIMPLE,
+                era_compiler_common::EXTENSION_SOLIDITY,
+            ),
+            (Self::SOLIDITY_COMPLEX, era_compiler_common::EXTENSION_JSON),
+            (Self::VYPER_SIMPLE, era_compiler_common::EXTENSION_VYPER),
+            (Self::VYPER_COMPLEX, era_compiler_common::EXTENSION_JSON),
+            (Self::YUL_SIMPLE, era_compiler_common::EXTENSION_YUL),
+            (
+                Self::LLVM_SIMPLE,
+                era_compiler_common::EXTENSION_LLVM_SOURCE,
+            ),
+            (
+                Self::ERAVM_SIMPLE,
+                era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
+            ),
+        ]
+        .into_iter()
+        {
+            self.docs_directory::<MatterLabsDirectory>(
+                &mut docs,
+                era_compiler_common::Target::EraVM,
+                path,
+                extension,
+            )?;
+        }
+        self.docs_directory::<RealWorldDirectory>(
+            &mut docs,
+            era_compiler_common::Target::EraVM,
+            Self::SOLIDITY_REAL_WORLD,
+            era_compiler_common::EXTENSION_JSON,
+        )?;
+
+        for (target, path, extension) in [
+            (
+                era_compiler_common::Target::EraVM,
+                Self::SOLIDITY_ETHEREUM,
+                era_compiler_common::EXTENSION_SOLIDITY,
+            ),
+            (
+                era_compiler_common::Target::EVM,
+                Self::SOLIDITY_ETHEREUM_UPSTREAM,
+                era_compiler_common::EXTENSION_SOLIDITY,
+            ),
+            (
+                era_compiler_common::Target::EraVM,
+                Self::VYPER_ETHEREUM,
+                era_compiler_common::EXTENSION_VYPER,
+            ),
+        ]
+        .into_iter()
+        {
+            self.docs_directory::<EthereumDirectory>(&mut docs, target, path, extension)?;
+        }
+
+        Ok(docs)
+    }
+
+    ///
+    /// Adds the documentation entries of the tests from the specified directory.
+    ///
+    fn docs_directory<T>(
+        &self,
+        docs: &mut Docs,
+        target: era_compiler_common::Target,
+        path: &str,
+        extension: &'static str,
+    ) -> anyhow::Result<()>
+    where
+        T: Collection,
+        T::Test: Documented,
+    {
+        if !Path::new(path).exists() {
+            return Ok(());
+        }
+
+        for test in T::read_all(
+            target,
+            Path::new(path),
+            extension,
+            self.summary.clone(),
+            &self.filters,
+            &self.selectors,
+        )
+        .map_err(|error| anyhow::anyhow!("Failed to read the tests directory `{path}`: {error}"))?
+        .into_iter()
+        {
+            docs.push(path, test.docs_entry());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Lints the Matter Labs tests built by the `toolchain` for the `target`.
+    ///
+    /// The expected word counts are checked against the ABI in the `interfaces`, if they have
+    /// been recorded by building the tests. The directories missing in the checkout are skipped.
+    ///
+    pub fn lint(
+        &self,
+        target: era_compiler_common::Target,
+        toolchain: Toolchain,
+        interfaces: Option<&BTreeMap<(String, String), SummaryInterface>>,
+    ) -> anyhow::Result<Lint> {
+        let solidity_compiler: Arc<dyn Compiler> = match toolchain {
+            Toolchain::IrLLVM => Arc::new(SolidityCompiler::new()),
+            Toolchain::Solc | Toolchain::SolcLLVM => Arc::new(SolidityUpstreamCompiler::new(
+                SolcStandardJsonInputLanguage::Solidity,
+                toolchain,
+            )),
+        };
+        let yul_compiler: Arc<dyn Compiler> = match toolchain {
+            Toolchain::IrLLVM => Arc::new(YulCompiler::new()),
+            Toolchain::Solc | Toolchain::SolcLLVM => Arc::new(SolidityUpstreamCompiler::new(
+                SolcStandardJsonInputLanguage::Yul,
+                toolchain,
+            )),
+        };
+        let vyper_compiler: Arc<dyn Compiler> = Arc::new(VyperCompiler::new());
+
+        let mut directories = vec![
+            (
+                Self::SOLIDITY_SIMPLE,
+                era_compiler_common::EXTENSION_SOLIDITY,
+                solidity_compiler.clone(),
+            ),
+            (
+                Self::SOLIDITY_COMPLEX,
+                era_compiler_common::EXTENSION_JSON,
+                solidity_compiler,
+            ),
+            (
+                Self::YUL_SIMPLE,
+                era_compiler_common::EXTENSION_YUL,
+                yul_compiler,
+            ),
+            (
+                Self::LLVM_SIMPLE,
+                era_compiler_common::EXTENSION_LLVM_SOURCE,
+                Arc::new(LLVMCompiler) as Arc<dyn Compiler>,
+            ),
+            (
+                Self::ERAVM_SIMPLE,
+                era_compiler_common::EXTENSION_ERAVM_ASSEMBLY,
+                Arc::new(EraVMCompiler) as Arc<dyn Compiler>,
+            ),
+        ];
+        if let era_compiler_common::Target::EraVM = target {
+            directories.push((
+                Self::VYPER_SIMPLE,
+                era_compiler_common::EXTENSION_VYPER,
+                vyper_compiler.clone(),
+            ));
+            directories.push((
+                Self::VYPER_COMPLEX,
+                era_compiler_common::EXTENSION_JSON,
+                vyper_compiler,
+            ));
+        }
+
+        let mut lint = Lint::default();
+        for (path, extension, compiler) in directories.into_iter() {
+            if !Path::new(path).exists() {
+                continue;
+            }
+
+            for test in MatterLabsDirectory::read_all(
+                target,
+                Path::new(path),
+                extension,
+                self.summary.clone(),
+                &self.filters,
+                &self.selectors,
+            )
+            .map_err(|error| {
+                anyhow::anyhow!("Failed to read the tests directory `{path}`: {error}")
+            })?
+            .into_iter()
+            {
+                lint.push(test.lint(target, compiler.as_ref(), interfaces));
+            }
+        }
+        Ok(lint)
+    }
+
     ///
     /// Returns all tests from the specified directory for the specified compiler.
     ///
@@ -369,7 +1018,7 @@ impl CompilerTester {
         path: &str,
         extension: &'static str,
         compiler: Arc<dyn Compiler>,
-    ) -> anyhow::Result<Vec<Test>>
+    ) -> anyhow::Result<Vec<GenericTest>>
     where
         T: Collection,
     {
@@ -379,6 +1028,7 @@ impl CompilerTester {
             extension,
             self.summary.clone(),
             &self.filters,
+            &self.selectors,
         )
         .map_err(|error| anyhow::anyhow!("Failed to read the tests directory `{path}`: {error}"))?
         .into_iter()
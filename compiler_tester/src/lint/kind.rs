@@ -0,0 +1,29 @@
+//!
+//! The test lint warning kind.
+//!
+
+///
+/// The test lint warning kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    /// A case references an instance not defined by the test.
+    UndefinedInstance,
+    /// An instance defined by the test is never referenced by any case.
+    UnusedInstance,
+    /// The expected return data word count differs from the method ABI outputs.
+    WordCount,
+    /// A mode filter matches no mode generated for the target.
+    UnmatchedModeFilter,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UndefinedInstance => write!(f, "undefined-instance"),
+            Self::UnusedInstance => write!(f, "unused-instance"),
+            Self::WordCount => write!(f, "word-count"),
+            Self::UnmatchedModeFilter => write!(f, "unmatched-mode-filter"),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//!
+//! The test-suite lint.
+//!
+
+pub mod kind;
+pub mod warning;
+
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+
+use crate::compilers::Compiler;
+use crate::summary::report::interface_check::interface::Interface;
+
+use self::warning::Warning;
+
+///
+/// The test-suite lint.
+///
+/// Collects the authoring errors of the tests, which are not reported by running them, e.g.
+/// the instances never referenced by any case, or the mode filters never matching.
+///
+#[derive(Debug, Default)]
+pub struct Lint {
+    /// The number of linted tests.
+    pub tests: usize,
+    /// The warnings sorted by the test path.
+    pub warnings: Vec<Warning>,
+}
+
+impl Lint {
+    ///
+    /// Adds the warnings of a linted test.
+    ///
+    pub fn push(&mut self, warnings: Vec<Warning>) {
+        self.tests += 1;
+        self.warnings.extend(warnings);
+    }
+
+    ///
+    /// Whether no warnings have been found.
+    ///
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    ///
+    /// Returns the number of words returned by the `method` according to the contract `abi`.
+    ///
+    /// The `method` is either a name, or a signature to distinguish overloads. Returns `None`
+    /// if the method is not found, is ambiguous, or returns dynamic data, whose word count
+    /// depends on the values.
+    ///
+    pub fn output_words(abi: &serde_json::Value, method: &str) -> Option<usize> {
+        let mut functions = abi.as_array()?.iter().filter(|entry| {
+            if entry.get("type").and_then(serde_json::Value::as_str) != Some("function") {
+                return false;
+            }
+            let name = entry
+                .get("name")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default();
+            if method.contains('(') {
+                Self::signature(entry).as_deref() == Some(method)
+            } else {
+                name == method
+            }
+        });
+        let function = functions.next()?;
+        if functions.next().is_some() {
+            return None;
+        }
+
+        function
+            .get("outputs")?
+            .as_array()?
+            .iter()
+            .map(Self::static_words)
+            .sum()
+    }
+
+    ///
+    /// Returns the signature of the ABI function `entry`, e.g. `f(uint256,(bool,address))`.
+    ///
+    fn signature(entry: &serde_json::Value) -> Option<String> {
+        let name = entry.get("name")?.as_str()?;
+        let inputs = entry
+            .get("inputs")?
+            .as_array()?
+            .iter()
+            .map(Self::canonical_type)
+            .collect::<Option<Vec<String>>>()?;
+        Some(format!("{name}({})", inputs.join(",")))
+    }
+
+    ///
+    /// Returns the canonical type of the ABI `parameter`, with the tuples expanded.
+    ///
+    fn canonical_type(parameter: &serde_json::Value) -> Option<String> {
+        let r#type = parameter.get("type")?.as_str()?;
+        match r#type.strip_prefix("tuple") {
+            Some(suffix) => {
+                let components = parameter
+                    .get("components")?
+                    .as_array()?
+                    .iter()
+                    .map(Self::canonical_type)
+                    .collect::<Option<Vec<String>>>()?;
+                Some(format!("({}){suffix}", components.join(",")))
+            }
+            None => Some(r#type.to_owned()),
+        }
+    }
+
+    ///
+    /// Returns the number of words of the ABI `parameter`, or `None` if it is dynamic.
+    ///
+    fn static_words(parameter: &serde_json::Value) -> Option<usize> {
+        let r#type = parameter.get("type")?.as_str()?;
+        Self::static_type_words(r#type, parameter.get("components"))
+    }
+
+    ///
+    /// Returns the number of words of the ABI `type`, or `None` if it is dynamic.
+    ///
+    fn static_type_words(r#type: &str, components: Option<&serde_json::Value>) -> Option<usize> {
+        if let Some((element_type, length)) = r#type
+            .strip_suffix(']')
+            .and_then(|r#type| r#type.rsplit_once('['))
+        {
+            let length: usize = length.parse().ok()?;
+            return Some(length * Self::static_type_words(element_type, components)?);
+        }
+
+        match r#type {
+            "tuple" => components?.as_array()?.iter().map(Self::static_words).sum(),
+            "string" | "bytes" | "function" => None,
+            _ => Some(1),
+        }
+    }
+}
+
+impl std::fmt::Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut warnings = self.warnings.clone();
+        warnings.sort();
+        for warning in warnings.iter() {
+            writeln!(f, "     {} {warning}", "Warning".bright_yellow().bold())?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// The test which can be linted.
+///
+pub trait Linted {
+    ///
+    /// Returns the lint warnings of the test built by the `compiler` for the `target`.
+    ///
+    /// The expected word counts are only checked against the `interfaces` if they are recorded.
+    ///
+    fn lint(
+        &self,
+        target: era_compiler_common::Target,
+        compiler: &dyn Compiler,
+        interfaces: Option<&BTreeMap<(String, String), Interface>>,
+    ) -> Vec<Warning>;
+}
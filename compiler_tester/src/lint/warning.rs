@@ -0,0 +1,47 @@
+//!
+//! The test lint warning.
+//!
+
+use super::kind::Kind;
+
+///
+/// The test lint warning.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Warning {
+    /// The test path.
+    pub path: String,
+    /// The case name, if the warning is specific to a case.
+    pub case: Option<String>,
+    /// The warning kind.
+    pub kind: Kind,
+    /// The warning message.
+    pub message: String,
+}
+
+impl Warning {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, case: Option<String>, kind: Kind, message: String) -> Self {
+        Self {
+            path,
+            case,
+            kind,
+            message,
+        }
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.case.as_deref() {
+            Some(case) => write!(
+                f,
+                "{} [{}] case `{case}`: {}",
+                self.path, self.kind, self.message
+            ),
+            None => write!(f, "{} [{}]: {}", self.path, self.kind, self.message),
+        }
+    }
+}
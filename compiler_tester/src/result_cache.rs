@@ -0,0 +1,66 @@
+//!
+//! The compiler tester result cache.
+//!
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+///
+/// The compiler tester result cache.
+///
+/// Stores the keys of tests that have passed, where each key is a hash of the test builds,
+/// case inputs, VM environment, and system contracts. A test with a known key can be skipped.
+///
+#[derive(Debug)]
+pub struct ResultCache {
+    /// The cache file path.
+    path: PathBuf,
+    /// The keys of passed tests.
+    entries: Mutex<BTreeSet<String>>,
+}
+
+impl ResultCache {
+    ///
+    /// Reads the cache from `path`, or creates an empty one if the file does not exist.
+    ///
+    pub fn new(path: PathBuf) -> anyhow::Result<Self> {
+        let entries = if path.exists() {
+            let text = std::fs::read_to_string(path.as_path())
+                .map_err(|error| anyhow::anyhow!("Result cache {path:?} reading: {error}"))?;
+            serde_json::from_str(text.as_str())
+                .map_err(|error| anyhow::anyhow!("Result cache {path:?} parsing: {error}"))?
+        } else {
+            BTreeSet::new()
+        };
+
+        Ok(Self {
+            path,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    ///
+    /// Whether the test with `key` has passed before.
+    ///
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.lock().expect("Sync").contains(key)
+    }
+
+    ///
+    /// Remembers the test with `key` as passed.
+    ///
+    pub fn insert(&self, key: String) {
+        self.entries.lock().expect("Sync").insert(key);
+    }
+
+    ///
+    /// Writes the cache back to its file.
+    ///
+    pub fn write(&self) -> anyhow::Result<()> {
+        let entries = self.entries.lock().expect("Sync");
+        let contents = serde_json::to_string_pretty(&*entries).expect("Always valid");
+        std::fs::write(self.path.as_path(), contents)
+            .map_err(|error| anyhow::anyhow!("Result cache {:?} writing: {error}", self.path))
+    }
+}
@@ -24,6 +24,12 @@ impl From<InputIdentifier> for benchmark_analyzer::Input {
             InputIdentifier::Balance { input_index } => {
                 benchmark_analyzer::Input::Balance { input_index }
             }
+            InputIdentifier::StateTransition { input_index } => {
+                benchmark_analyzer::Input::StateTransition { input_index }
+            }
+            InputIdentifier::Immutables { input_index } => {
+                benchmark_analyzer::Input::Immutables { input_index }
+            }
         }
     }
 }
@@ -8,6 +8,7 @@ use crate::Mode;
 pub fn convert_description(
     description: &TestDescription,
     default_group: &str,
+    target: Option<era_compiler_common::Target>,
 ) -> benchmark_analyzer::Metadata {
     let TestDescription {
         group,
@@ -26,6 +27,7 @@ pub fn convert_description(
         mode,
         version,
         group,
+        target: target.map(|target| target.to_string()),
     }
 }
 
@@ -0,0 +1,45 @@
+//!
+//! The benchmark recording policy.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+///
+/// The benchmark recording policy.
+///
+/// Maps the benchmark groups, e.g. `EVMInterpreter`, to the metrics recorded for them, so that
+/// the metrics meaningless for a group, e.g. the ergs of EVM interpreter runs, are not exported.
+/// The aggregate group is configured by its name, e.g. `All`.
+///
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+pub struct BenchmarkPolicy {
+    /// The metrics recorded for the groups without a policy, all if unset.
+    #[serde(default)]
+    pub default: Option<BTreeSet<benchmark_analyzer::BenchmarkMetric>>,
+    /// The metrics recorded by the group name.
+    #[serde(default)]
+    pub groups: BTreeMap<String, BTreeSet<benchmark_analyzer::BenchmarkMetric>>,
+}
+
+impl BenchmarkPolicy {
+    ///
+    /// Returns the metrics recorded for the `group`, or `None` if all are recorded.
+    ///
+    pub fn metrics(&self, group: &str) -> Option<BTreeSet<benchmark_analyzer::BenchmarkMetric>> {
+        self.groups.get(group).or(self.default.as_ref()).cloned()
+    }
+}
+
+impl TryFrom<PathBuf> for BenchmarkPolicy {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Benchmark policy file {path:?} reading: {error}"))?;
+        let policy: Self = serde_yaml::from_str(text.as_str())
+            .map_err(|error| anyhow::anyhow!("Benchmark policy file {path:?} parsing: {error}"))?;
+        Ok(policy)
+    }
+}
@@ -0,0 +1,16 @@
+//!
+//! The benchmark group declaration.
+//!
+
+///
+/// The benchmark group declaration.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Group {
+    /// The group description.
+    pub description: String,
+    /// The metrics expected to be recorded for the group.
+    #[serde(default)]
+    pub metrics: Vec<benchmark_analyzer::BenchmarkMetric>,
+}
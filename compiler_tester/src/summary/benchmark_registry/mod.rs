@@ -0,0 +1,177 @@
+//!
+//! The benchmark groups registry.
+//!
+
+pub mod group;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::summary::benchmark_policy::BenchmarkPolicy;
+
+use self::group::Group;
+
+///
+/// The benchmark groups registry.
+///
+/// Declares the groups the tests may be benchmarked in, so that a typo in a test group name
+/// is reported instead of silently starting a new LNT series. The groups are read from a YAML
+/// file mapping the group names to their declarations.
+///
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct BenchmarkRegistry {
+    /// The declared groups, keyed by the group name, as reported to LNT.
+    pub groups: BTreeMap<String, Group>,
+}
+
+impl BenchmarkRegistry {
+    /// The default registry file.
+    pub const DEFAULT_PATH: &'static str = "./configs/benchmark-groups.yaml";
+
+    ///
+    /// Returns the violations of the registry by the benchmarked `groups`, given with their
+    /// number of tests, and by the benchmark `policy`.
+    ///
+    /// The groups must be declared, and the policy must name only the declared groups and
+    /// record their expected metrics.
+    ///
+    pub fn violations(
+        &self,
+        groups: &BTreeMap<String, usize>,
+        policy: Option<&BenchmarkPolicy>,
+    ) -> Vec<String> {
+        let mut violations = Vec::new();
+        for (name, count) in groups.iter() {
+            if !self.groups.contains_key(name) {
+                violations.push(format!(
+                    "Group `{name}` of {count} benchmarked tests is not declared"
+                ));
+            }
+        }
+
+        if let Some(policy) = policy {
+            for name in policy.groups.keys() {
+                if !self.groups.contains_key(name) {
+                    violations.push(format!(
+                        "Group `{name}` of the benchmark policy is not declared"
+                    ));
+                }
+            }
+            for (name, group) in self.groups.iter() {
+                let metrics = match policy.metrics(name) {
+                    Some(metrics) => metrics,
+                    None => continue,
+                };
+                for metric in group.metrics.iter() {
+                    if !metrics.contains(metric) {
+                        violations.push(format!(
+                            "Group `{name}` expected metric `{metric}` is not recorded by the benchmark policy"
+                        ));
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl TryFrom<PathBuf> for BenchmarkRegistry {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path()).map_err(|error| {
+            anyhow::anyhow!("Benchmark groups registry file {path:?} reading: {error}")
+        })?;
+        let registry: Self = serde_yaml::from_str(text.as_str()).map_err(|error| {
+            anyhow::anyhow!("Benchmark groups registry file {path:?} parsing: {error}")
+        })?;
+        Ok(registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use crate::summary::benchmark_policy::BenchmarkPolicy;
+
+    use super::BenchmarkRegistry;
+
+    ///
+    /// Returns the registry with the `EVMInterpreter` and `SystemContracts` groups.
+    ///
+    fn registry() -> BenchmarkRegistry {
+        serde_yaml::from_str(
+            r#"
+EVMInterpreter:
+  description: The EVM interpreter.
+  metrics: [ gas ]
+SystemContracts:
+  description: The system contracts.
+  metrics: [ cycles, ergs ]
+"#,
+        )
+        .expect("Always valid")
+    }
+
+    #[test]
+    fn default() {
+        let registry = BenchmarkRegistry::try_from(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(BenchmarkRegistry::DEFAULT_PATH),
+        )
+        .expect("Always valid");
+
+        for name in [
+            benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME,
+            benchmark_analyzer::Benchmark::EVM_INTERPRETER_GROUP_NAME,
+            benchmark_analyzer::Benchmark::SYSTEM_CONTRACTS_GROUP_NAME,
+            benchmark_analyzer::Benchmark::REAL_WORLD_GROUP_NAME,
+        ] {
+            assert!(registry.groups.contains_key(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn violations() {
+        let registry = registry();
+        let groups = BTreeMap::from([
+            ("EVMInterpreter".to_owned(), 2),
+            ("EVMInterpeter".to_owned(), 1),
+        ]);
+        let policy: BenchmarkPolicy = serde_yaml::from_str(
+            r#"
+groups:
+  EVMInterpreter: [ gas ]
+  SystemContracts: [ cycles ]
+  Unknown: [ size ]
+"#,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            registry.violations(&groups, Some(&policy)),
+            vec![
+                "Group `EVMInterpeter` of 1 benchmarked tests is not declared".to_owned(),
+                "Group `Unknown` of the benchmark policy is not declared".to_owned(),
+                "Group `SystemContracts` expected metric `ergs` is not recorded by the benchmark policy"
+                    .to_owned(),
+            ]
+        );
+        assert!(registry
+            .violations(&BTreeMap::from([("SystemContracts".to_owned(), 1)]), None)
+            .is_empty());
+    }
+
+    #[test]
+    fn unknown_fields() {
+        assert!(serde_yaml::from_str::<BenchmarkRegistry>(
+            "All:\n  description: All.\n  metric: [ size ]\n"
+        )
+        .is_err());
+    }
+}
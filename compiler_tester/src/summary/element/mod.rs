@@ -3,13 +3,16 @@
 //!
 
 pub mod outcome;
+pub mod return_data_diff;
 
 use colored::Colorize;
 
 use crate::test::description::TestDescription;
+use crate::vm::storage_access::StorageAccess;
 
 use self::outcome::passed_variant::PassedVariant;
 use self::outcome::Outcome;
+use self::return_data_diff::ReturnDataDiff;
 
 ///
 /// The compiler tester summary element.
@@ -20,6 +23,8 @@ pub struct Element {
     pub test_description: TestDescription,
     /// The test outcome.
     pub outcome: Outcome,
+    /// The target architecture, if several targets are run in a single invocation.
+    pub target: Option<era_compiler_common::Target>,
 }
 
 impl Element {
@@ -30,15 +35,33 @@ impl Element {
         Self {
             test_description: name,
             outcome,
+            target: None,
         }
     }
 
+    ///
+    /// Returns the element name, consisting of the target, mode, and selector.
+    ///
+    pub fn name(&self) -> String {
+        let mut name = String::new();
+        if let Some(target) = self.target {
+            name.push_str(format!("{target} ").as_str());
+        }
+        if let Some(mode) = self.test_description.mode.as_ref() {
+            name.push_str(format!("{mode} ").as_str());
+        }
+        name.push_str(self.test_description.selector.to_string().as_str());
+        name
+    }
+
     ///
     /// Prints the element.
     ///
     pub fn print(&self, verbosity: bool) -> Option<String> {
         match self.outcome {
             Outcome::Passed { .. } if !verbosity => return None,
+            Outcome::CachedPass if !verbosity => return None,
+            Outcome::Skipped { .. } if !verbosity => return None,
             Outcome::Ignored => return None,
             _ => {}
         }
@@ -47,7 +70,11 @@ impl Element {
             Outcome::Passed { .. } => "PASSED".green(),
             Outcome::Failed { .. } => "FAILED".bright_red(),
             Outcome::Invalid { .. } => "INVALID".red(),
+            Outcome::ExpectedFailure { .. } => "XFAILED".yellow(),
+            Outcome::UnexpectedPass => "XPASSED".bright_red(),
             Outcome::Ignored => "IGNORED".bright_black(),
+            Outcome::Skipped { .. } => "SKIPPED".bright_black(),
+            Outcome::CachedPass => "CACHED".green(),
         };
 
         let details = match self.outcome {
@@ -56,18 +83,56 @@ impl Element {
                 ref group,
             } => {
                 let mut details = Vec::new();
-                if let PassedVariant::Deploy { size, .. } = variant {
-                    details.push(format!("size {size}").bright_white().to_string())
+                if let PassedVariant::Deploy {
+                    size, instructions, ..
+                } = variant
+                {
+                    details.push(format!("size {size}").bright_white().to_string());
+                    if let Some(instructions) = instructions {
+                        details.push(
+                            format!("instructions {instructions}")
+                                .bright_white()
+                                .to_string(),
+                        );
+                    }
                 };
                 match variant {
-                    PassedVariant::Deploy { cycles, ergs, .. } => {
+                    PassedVariant::Deploy {
+                        cycles,
+                        ergs,
+                        pubdata,
+                        ..
+                    } => {
                         details.push(format!("cycles {cycles}").bright_white().to_string());
                         details.push(format!("ergs {ergs}").bright_white().to_string());
+                        if let Some(pubdata) = pubdata {
+                            details.push(format!("pubdata ~{pubdata}").bright_white().to_string());
+                        }
                     }
-                    PassedVariant::Runtime { cycles, ergs, gas } => {
+                    PassedVariant::Runtime {
+                        cycles,
+                        ergs,
+                        gas,
+                        pubdata,
+                        cold_accesses,
+                        warm_accesses,
+                        ..
+                    } => {
                         details.push(format!("cycles {cycles}").bright_white().to_string());
                         details.push(format!("ergs {ergs}").bright_white().to_string());
                         details.push(format!("gas {gas}").bright_white().to_string());
+                        if let Some(pubdata) = pubdata {
+                            details.push(format!("pubdata ~{pubdata}").bright_white().to_string());
+                        }
+                        if let (Some(cold_accesses), Some(warm_accesses)) =
+                            (cold_accesses, warm_accesses)
+                        {
+                            details.push(
+                                format!("accesses {cold_accesses} cold / {warm_accesses} warm")
+                                    .bright_white()
+                                    .to_string(),
+                            );
+                        }
                     }
                     _ => {}
                 };
@@ -84,6 +149,23 @@ impl Element {
                 ref expected,
                 ref found,
                 ref calldata,
+                ref storage,
+            } if verbosity => {
+                let mut details = format!(
+                    "(calldata {calldata})\n{}",
+                    ReturnDataDiff::new(expected, found).to_string().trim_end()
+                );
+                if !storage.is_empty() {
+                    details.push('\n');
+                    details.push_str(StorageAccess::format_excerpt(storage.as_slice()).as_str());
+                }
+                details
+            }
+            Outcome::Failed {
+                ref expected,
+                ref found,
+                ref calldata,
+                ..
             } => {
                 format!(
                     "(expected {}, found {}, calldata {})",
@@ -94,18 +176,31 @@ impl Element {
                     calldata,
                 )
             }
-            Outcome::Invalid { ref error } => error.to_string(),
+            Outcome::Invalid { ref error, .. } => error.to_string(),
+            Outcome::ExpectedFailure { ref error, .. } => error.to_string(),
+            Outcome::Skipped { ref reason } => format!("({reason})"),
+            Outcome::UnexpectedPass => {
+                "(expected to fail, remove it from the expected outcomes)".to_string()
+            }
             _ => String::new(),
         };
+        let details = match self.outcome.error_code() {
+            Some(code) => format!("[{code}] {details}"),
+            None => details,
+        };
 
+        let mut mode = self
+            .test_description
+            .mode
+            .as_ref()
+            .map(|mode| mode.to_string())
+            .unwrap_or_default();
+        if let Some(target) = self.target {
+            mode = format!("{target} {mode}");
+        }
         Some(format!(
             "{:16} {:>7} {} {}",
-            self.test_description
-                .mode
-                .as_ref()
-                .map(|mode| mode.to_string())
-                .unwrap_or_default()
-                .bright_white(),
+            mode.bright_white(),
             outcome,
             self.test_description.selector,
             details
@@ -0,0 +1,35 @@
+//!
+//! The compiler tester summary element deploy metrics.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The compiler tester summary element deploy metrics.
+///
+/// The metrics not collected by a VM are left at their defaults.
+///
+#[derive(Debug, Default)]
+pub struct DeployMetrics {
+    /// The contract size in bytes.
+    pub size: usize,
+    /// The contract runtime code size in bytes, `Some` for EraVM, REVM, and EVM interpreter.
+    pub runtime_size: Option<usize>,
+    /// The CBOR metadata length in bytes at the end of the runtime code, `Some` for REVM, and
+    /// EVM interpreter if the runtime code has been published by the deploy.
+    pub metadata_size: Option<usize>,
+    /// The contract size in instructions, `Some` for EraVM.
+    pub instructions: Option<usize>,
+    /// The contract code size of each function in bytes, `Some` for EraVM with assembly.
+    pub function_sizes: Option<BTreeMap<String, usize>>,
+    /// The number of execution cycles.
+    pub cycles: usize,
+    /// The number of used ergs.
+    pub ergs: u64,
+    /// The number of used gas.
+    pub gas: u64,
+    /// The maximum call stack depth, `Some` for EraVM.
+    pub stack_depth: Option<usize>,
+    /// The estimated number of published pubdata bytes, `Some` for EraVM.
+    pub pubdata: Option<usize>,
+}
@@ -0,0 +1,126 @@
+//!
+//! The compiler tester summary element outcome error code.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The compiler tester summary element outcome error code.
+///
+/// The codes are stable, so the downstream automation can branch on the failure classes.
+/// New codes are only appended, and the existing ones are never reassigned.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum ErrorCode {
+    /// The test sources have not been compiled.
+    #[serde(rename = "CT001")]
+    CompilationFailed,
+    /// The output of an input does not match the expected one.
+    #[serde(rename = "CT002")]
+    OutputMismatch,
+    /// The test file, metadata, or cases are invalid.
+    #[serde(rename = "CT003")]
+    InvalidMetadata,
+    /// The virtual machine has failed to execute an input.
+    #[serde(rename = "CT004")]
+    ExecutionFailed,
+    /// The test is expected to fail, but has passed.
+    #[serde(rename = "CT005")]
+    UnexpectedPass,
+    /// The tester has failed for a reason unrelated to the test, e.g. writing the debug artifacts.
+    #[serde(rename = "CT006")]
+    InternalError,
+}
+
+impl ErrorCode {
+    /// All error codes.
+    pub const ALL: [Self; 6] = [
+        Self::CompilationFailed,
+        Self::OutputMismatch,
+        Self::InvalidMetadata,
+        Self::ExecutionFailed,
+        Self::UnexpectedPass,
+        Self::InternalError,
+    ];
+
+    ///
+    /// Returns the error code name.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CompilationFailed => "CompilationFailed",
+            Self::OutputMismatch => "OutputMismatch",
+            Self::InvalidMetadata => "InvalidMetadata",
+            Self::ExecutionFailed => "ExecutionFailed",
+            Self::UnexpectedPass => "UnexpectedPass",
+            Self::InternalError => "InternalError",
+        }
+    }
+
+    ///
+    /// Returns the machine-readable mapping of the error codes to their names.
+    ///
+    pub fn mapping() -> BTreeMap<String, &'static str> {
+        Self::ALL
+            .into_iter()
+            .map(|code| (code.to_string(), code.name()))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = match self {
+            Self::CompilationFailed => "CT001",
+            Self::OutputMismatch => "CT002",
+            Self::InvalidMetadata => "CT003",
+            Self::ExecutionFailed => "CT004",
+            Self::UnexpectedPass => "CT005",
+            Self::InternalError => "CT006",
+        };
+        write!(f, "{code}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ErrorCode;
+
+    #[test]
+    fn stable_codes() {
+        for (code, expected, name) in [
+            (ErrorCode::CompilationFailed, "CT001", "CompilationFailed"),
+            (ErrorCode::OutputMismatch, "CT002", "OutputMismatch"),
+            (ErrorCode::InvalidMetadata, "CT003", "InvalidMetadata"),
+            (ErrorCode::ExecutionFailed, "CT004", "ExecutionFailed"),
+            (ErrorCode::UnexpectedPass, "CT005", "UnexpectedPass"),
+            (ErrorCode::InternalError, "CT006", "InternalError"),
+        ] {
+            assert_eq!(code.to_string(), expected, "Invalid code of {name}");
+            assert_eq!(code.name(), name, "Invalid name of {expected}");
+            assert_eq!(
+                serde_json::to_string(&code).expect("Always valid"),
+                format!("\"{expected}\""),
+                "Invalid serialization of {name}"
+            );
+            assert_eq!(
+                serde_json::from_str::<ErrorCode>(format!("\"{expected}\"").as_str())
+                    .expect("Always valid"),
+                code,
+                "Invalid deserialization of {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn mapping() {
+        let mapping = ErrorCode::mapping();
+        assert_eq!(mapping.len(), ErrorCode::ALL.len());
+        assert_eq!(
+            serde_json::to_string(&mapping).expect("Always valid"),
+            r#"{"CT001":"CompilationFailed","CT002":"OutputMismatch","CT003":"InvalidMetadata","CT004":"ExecutionFailed","CT005":"UnexpectedPass","CT006":"InternalError"}"#
+        );
+    }
+}
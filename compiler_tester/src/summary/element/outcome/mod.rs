@@ -2,10 +2,14 @@
 //! The compiler tester summary element outcome.
 //!
 
+pub mod deploy_metrics;
+pub mod error_code;
 pub mod passed_variant;
 
 use crate::test::case::input::output::Output;
+use crate::vm::storage_access::StorageAccess;
 
+use self::error_code::ErrorCode;
 use self::passed_variant::PassedVariant;
 
 ///
@@ -28,14 +32,34 @@ pub enum Outcome {
         found: Output,
         /// The calldata.
         calldata: String,
+        /// The storage slots accessed by the failed input, if tracked by the VM.
+        storage: Vec<StorageAccess>,
     },
     /// The `invalid` outcome. The test is incorrect.
     Invalid {
+        /// The error code.
+        code: ErrorCode,
         /// The building error description.
         error: String,
     },
+    /// The `expected failure` outcome. The test fails due to a known compiler limitation.
+    ExpectedFailure {
+        /// The error code of the expected failure.
+        code: ErrorCode,
+        /// The building error description.
+        error: String,
+    },
+    /// The `unexpected pass` outcome. The test is expected to fail, but has passed.
+    UnexpectedPass,
     /// The `ignored` outcome. The test is ignored.
     Ignored,
+    /// The `skipped` outcome. The test is not applicable to the run configuration.
+    Skipped {
+        /// The reason the test is skipped.
+        reason: String,
+    },
+    /// The `cached pass` outcome. The test has passed with the same builds in a previous run.
+    CachedPass,
 }
 
 impl Outcome {
@@ -49,30 +73,123 @@ impl Outcome {
     ///
     /// A shortcut constructor.
     ///
-    pub fn failed(expected: Output, found: Output, calldata: Vec<u8>) -> Self {
+    pub fn failed(
+        expected: Output,
+        found: Output,
+        calldata: Vec<u8>,
+        storage: Vec<StorageAccess>,
+    ) -> Self {
         Self::Failed {
             expected,
             found,
             calldata: hex::encode(calldata.as_slice()),
+            storage,
         }
     }
 
     ///
     /// A shortcut constructor.
     ///
-    pub fn invalid<S>(error: S) -> Self
+    pub fn invalid<S>(code: ErrorCode, error: S) -> Self
     where
         S: ToString,
     {
         Self::Invalid {
+            code,
             error: error.to_string(),
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn expected_failure<S>(code: ErrorCode, error: S) -> Self
+    where
+        S: ToString,
+    {
+        Self::ExpectedFailure {
+            code,
+            error: error.to_string(),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn unexpected_pass() -> Self {
+        Self::UnexpectedPass
+    }
+
     ///
     /// A shortcut constructor.
     ///
     pub fn ignored() -> Self {
         Self::Ignored
     }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn skipped<S>(reason: S) -> Self
+    where
+        S: ToString,
+    {
+        Self::Skipped {
+            reason: reason.to_string(),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn cached_pass() -> Self {
+        Self::CachedPass
+    }
+
+    ///
+    /// Returns the error code of the outcome, if it is a failure of any kind.
+    ///
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::Failed { .. } => Some(ErrorCode::OutputMismatch),
+            Self::Invalid { code, .. } => Some(*code),
+            Self::ExpectedFailure { code, .. } => Some(*code),
+            Self::UnexpectedPass => Some(ErrorCode::UnexpectedPass),
+            Self::Passed { .. } | Self::Ignored | Self::Skipped { .. } | Self::CachedPass => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::output::Output;
+
+    use super::error_code::ErrorCode;
+    use super::passed_variant::PassedVariant;
+    use super::Outcome;
+
+    #[test]
+    fn error_code() {
+        for (outcome, code) in [
+            (Outcome::passed(None, PassedVariant::Special), None),
+            (
+                Outcome::failed(Output::from(true), Output::from(false), vec![], vec![]),
+                Some(ErrorCode::OutputMismatch),
+            ),
+            (
+                Outcome::invalid(ErrorCode::CompilationFailed, "error"),
+                Some(ErrorCode::CompilationFailed),
+            ),
+            (
+                Outcome::expected_failure(ErrorCode::ExecutionFailed, "error"),
+                Some(ErrorCode::ExecutionFailed),
+            ),
+            (Outcome::unexpected_pass(), Some(ErrorCode::UnexpectedPass)),
+            (Outcome::ignored(), None),
+            (Outcome::skipped("reason"), None),
+            (Outcome::cached_pass(), None),
+        ] {
+            assert_eq!(outcome.error_code(), code, "{outcome:?}");
+        }
+    }
 }
@@ -2,6 +2,8 @@
 //! The compiler tester summary element passed outcome variant.
 //!
 
+use std::collections::BTreeMap;
+
 ///
 /// The compiler tester summary element passed outcome variant.
 ///
@@ -9,14 +11,26 @@
 pub enum PassedVariant {
     /// The contract deploy.
     Deploy {
-        /// The contract size in instructions.
+        /// The contract size in bytes.
         size: usize,
+        /// The contract runtime code size in bytes, `Some` for EraVM and REVM.
+        runtime_size: Option<usize>,
+        /// The CBOR metadata length in bytes at the end of the runtime code, `Some` for REVM.
+        metadata_size: Option<usize>,
+        /// The contract size in instructions, `Some` for EraVM.
+        instructions: Option<usize>,
+        /// The contract code size of each function in bytes, `Some` for EraVM with assembly.
+        function_sizes: Option<BTreeMap<String, usize>>,
         /// The number of execution cycles.
         cycles: usize,
         /// The number of used ergs.
         ergs: u64,
         /// The number of used gas.
         gas: u64,
+        /// The maximum call stack depth, `Some` for EraVM.
+        stack_depth: Option<usize>,
+        /// The estimated number of published pubdata bytes, `Some` for EraVM.
+        pubdata: Option<usize>,
     },
     /// The contract call.
     Runtime {
@@ -26,7 +40,148 @@ pub enum PassedVariant {
         ergs: u64,
         /// The number of used gas.
         gas: u64,
+        /// The maximum call stack depth, `Some` for EraVM.
+        stack_depth: Option<usize>,
+        /// The estimated number of published pubdata bytes, `Some` for EraVM.
+        pubdata: Option<usize>,
+        /// The number of cold storage slot and account accesses, `Some` for REVM.
+        cold_accesses: Option<usize>,
+        /// The number of warm storage slot and account accesses, `Some` for REVM.
+        warm_accesses: Option<usize>,
     },
     /// The special function call.
     Special,
 }
+
+impl PassedVariant {
+    ///
+    /// Returns the benchmark element with the metrics of the outcome and `metadata`,
+    /// or `None` for the special function calls.
+    ///
+    pub fn benchmark_element(
+        &self,
+        metadata: benchmark_analyzer::Metadata,
+    ) -> Option<benchmark_analyzer::BenchmarkElement> {
+        match self {
+            Self::Deploy {
+                size,
+                runtime_size,
+                metadata_size,
+                instructions,
+                function_sizes,
+                cycles,
+                ergs,
+                gas,
+                stack_depth,
+                pubdata,
+            } => Some(benchmark_analyzer::BenchmarkElement {
+                metadata,
+                size: Some(*size),
+                runtime_size: *runtime_size,
+                metadata_size: *metadata_size,
+                instructions: *instructions,
+                function_sizes: function_sizes.clone(),
+                cycles: Some(*cycles),
+                ergs: Some(*ergs),
+                gas: Some(*gas),
+                stack_depth: *stack_depth,
+                pubdata: *pubdata,
+                cold_accesses: None,
+                warm_accesses: None,
+            }),
+            Self::Runtime {
+                cycles,
+                ergs,
+                gas,
+                stack_depth,
+                pubdata,
+                cold_accesses,
+                warm_accesses,
+            } => Some(benchmark_analyzer::BenchmarkElement {
+                metadata,
+                size: None,
+                runtime_size: None,
+                metadata_size: None,
+                instructions: None,
+                function_sizes: None,
+                cycles: Some(*cycles),
+                ergs: Some(*ergs),
+                gas: Some(*gas),
+                stack_depth: *stack_depth,
+                pubdata: *pubdata,
+                cold_accesses: *cold_accesses,
+                warm_accesses: *warm_accesses,
+            }),
+            Self::Special => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PassedVariant;
+
+    #[test]
+    fn benchmark_element() {
+        let metadata = benchmark_analyzer::Metadata {
+            selector: benchmark_analyzer::TestSelector {
+                path: "tests/test.sol".to_owned(),
+                case: None,
+                input: None,
+            },
+            mode: None,
+            version: None,
+            group: String::new(),
+            target: None,
+        };
+        let deploy = PassedVariant::Deploy {
+            size: 100,
+            runtime_size: Some(80),
+            metadata_size: None,
+            instructions: None,
+            function_sizes: None,
+            cycles: 10,
+            ergs: 20,
+            gas: 30,
+            stack_depth: Some(2),
+            pubdata: None,
+        };
+        let runtime = PassedVariant::Runtime {
+            cycles: 11,
+            ergs: 21,
+            gas: 31,
+            stack_depth: None,
+            pubdata: None,
+            cold_accesses: Some(1),
+            warm_accesses: Some(2),
+        };
+
+        let element = deploy
+            .benchmark_element(metadata.clone())
+            .expect("Always exists");
+        assert_eq!(
+            (element.size, element.runtime_size, element.stack_depth),
+            (Some(100), Some(80), Some(2))
+        );
+        assert_eq!(
+            (element.cycles, element.ergs, element.gas),
+            (Some(10), Some(20), Some(30))
+        );
+        assert_eq!(element.cold_accesses, None);
+
+        let element = runtime
+            .benchmark_element(metadata.clone())
+            .expect("Always exists");
+        assert_eq!(element.size, None);
+        assert_eq!(
+            (element.cycles, element.ergs, element.gas),
+            (Some(11), Some(21), Some(31))
+        );
+        assert_eq!(
+            (element.cold_accesses, element.warm_accesses),
+            (Some(1), Some(2))
+        );
+
+        assert!(PassedVariant::Special.benchmark_element(metadata).is_none());
+    }
+}
@@ -0,0 +1,175 @@
+//!
+//! The word-aligned diff of the expected and found outputs.
+//!
+
+use colored::Colorize;
+
+use crate::test::case::input::output::return_data_assertion::ReturnDataAssertion;
+use crate::test::case::input::output::revert_reason::RevertReason;
+use crate::test::case::input::output::Output;
+use crate::test::case::input::value::Value;
+
+///
+/// The word-aligned diff of the expected and found outputs.
+///
+/// The return data words are printed side by side, with the differing ones highlighted.
+/// Small integers are printed as decimals, and values fitting into an address as addresses.
+///
+#[derive(Debug)]
+pub struct ReturnDataDiff<'a> {
+    /// The expected output.
+    pub expected: &'a Output,
+    /// The found output.
+    pub found: &'a Output,
+}
+
+impl<'a> ReturnDataDiff<'a> {
+    /// The placeholder of a missing word.
+    const MISSING_WORD: &'static str = "-";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(expected: &'a Output, found: &'a Output) -> Self {
+        Self { expected, found }
+    }
+
+    ///
+    /// Returns the human-readable representation of a word.
+    ///
+    fn word_as_string(value: Option<&Value>) -> String {
+        match value {
+            None => Self::MISSING_WORD.to_owned(),
+            Some(Value::Any) => "*".to_owned(),
+            Some(Value::Certain(value)) if value.bits() <= 64 => value.to_string(),
+            Some(Value::Certain(value)) if value.bits() <= 160 => format!(
+                "0x{}",
+                crate::utils::address_as_string(&crate::utils::u256_to_address(value))
+            ),
+            Some(Value::Certain(value)) => format!("0x{}", crate::utils::u256_as_string(value)),
+        }
+    }
+
+    ///
+    /// Whether the expected and found words match.
+    ///
+    fn is_matching(expected: Option<&Value>, found: Option<&Value>) -> bool {
+        match (expected, found) {
+            (Some(Value::Any), Some(_)) => true,
+            (Some(Value::Certain(expected)), Some(Value::Certain(found))) => expected == found,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnDataDiff<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let length = std::cmp::max(
+            self.expected.return_data.len(),
+            self.found.return_data.len(),
+        );
+        let rows: Vec<(String, String, bool)> = (0..length)
+            .map(|index| {
+                let expected = self.expected.return_data.get(index);
+                let found = self.found.return_data.get(index);
+                (
+                    Self::word_as_string(expected),
+                    Self::word_as_string(found),
+                    Self::is_matching(expected, found),
+                )
+            })
+            .collect();
+        let width = rows
+            .iter()
+            .map(|(expected, _, _)| expected.len())
+            .max()
+            .unwrap_or_default()
+            .max("expected".len());
+
+        writeln!(
+            f,
+            "    {:>5}  {}  {}",
+            "#".bright_white(),
+            format!("{:width$}", "expected").bright_white(),
+            "found".bright_white(),
+        )?;
+        for (index, (expected, found, is_matching)) in rows.into_iter().enumerate() {
+            let expected = format!("{expected:width$}");
+            if is_matching {
+                writeln!(f, "    {index:>5}  {expected}  {found}")?;
+            } else {
+                writeln!(
+                    f,
+                    "    {index:>5}  {}  {}",
+                    expected.green(),
+                    found.bright_red()
+                )?;
+            }
+        }
+
+        if self.expected.exception != self.found.exception {
+            writeln!(
+                f,
+                "    {} expected {}, found {}",
+                "exception".bright_white(),
+                self.expected.exception.to_string().green(),
+                self.found.exception.to_string().bright_red(),
+            )?;
+        }
+        if let Some(revert_reason) = self.expected.revert_reason.as_ref() {
+            let found = match RevertReason::decode(self.found.return_data.as_slice()) {
+                Some(found) => found.with_signature_of(revert_reason).to_string(),
+                None => "none".to_owned(),
+            };
+            writeln!(
+                f,
+                "    {} expected {}, found {}",
+                "revert reason".bright_white(),
+                revert_reason.to_string().green(),
+                found.bright_red(),
+            )?;
+        }
+        if let Some(assertion) = self.expected.return_data_assertion.as_ref() {
+            if let Some(size) = assertion.size {
+                writeln!(
+                    f,
+                    "    {} expected {}, found {}",
+                    "return data size".bright_white(),
+                    size.to_string().green(),
+                    ReturnDataAssertion::found_size(self.found)
+                        .to_string()
+                        .bright_red(),
+                )?;
+            }
+            if !assertion.prefix.is_empty() {
+                writeln!(
+                    f,
+                    "    {} expected {}",
+                    "return data prefix".bright_white(),
+                    format!("0x{}", hex::encode(assertion.prefix.as_slice())).green(),
+                )?;
+            }
+        }
+        if self.expected.events.len() != self.found.events.len()
+            || self
+                .expected
+                .events
+                .iter()
+                .zip(self.found.events.iter())
+                .any(|(expected, found)| expected != found)
+        {
+            writeln!(
+                f,
+                "    {} expected {}, found {}",
+                "events".bright_white(),
+                ron::ser::to_string(&self.expected.events)
+                    .expect("Always valid")
+                    .green(),
+                ron::ser::to_string(&self.found.events)
+                    .expect("Always valid")
+                    .bright_red(),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+//!
+//! The expected outcome of a test.
+//!
+
+///
+/// The expected outcome of a test.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpectedOutcome {
+    /// The test is known to fail or be invalid.
+    Xfail,
+}
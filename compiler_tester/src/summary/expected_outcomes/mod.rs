@@ -0,0 +1,135 @@
+//!
+//! The expected test outcomes overriding the actual ones.
+//!
+
+pub mod expected_outcome;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::compilers::mode::Mode;
+
+use self::expected_outcome::ExpectedOutcome;
+
+///
+/// The expected test outcomes overriding the actual ones.
+///
+/// Maps test path patterns to mode filters, e.g. `Y+ >=0.8.0` or `*` for all modes, and the
+/// outcomes expected in the matching modes. Used to mark known failures on a particular toolchain centrally.
+///
+#[derive(Debug, Default, Clone)]
+pub struct ExpectedOutcomes {
+    /// The expected outcomes by test path pattern and mode filter, with the patterns compiled once on loading.
+    pub inner: Vec<(String, glob::Pattern, BTreeMap<String, ExpectedOutcome>)>,
+}
+
+impl ExpectedOutcomes {
+    ///
+    /// Returns the outcome expected for the test at `path` in `mode`, if any.
+    ///
+    /// The path may be a glob pattern, e.g. `tests/solidity/complex/**`.
+    ///
+    pub fn get(&self, path: &str, mode: &Mode) -> Option<ExpectedOutcome> {
+        self.inner
+            .iter()
+            .filter(|(literal, pattern, _)| literal.as_str() == path || pattern.matches(path))
+            .flat_map(|(_, _, modes)| modes.iter())
+            .find_map(|(filter, outcome)| {
+                if filter == "*" || mode.check_extended_filters(&[filter.to_owned()]) {
+                    Some(*outcome)
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+impl FromStr for ExpectedOutcomes {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let outcomes: BTreeMap<String, BTreeMap<String, ExpectedOutcome>> =
+            serde_yaml::from_str(string)?;
+        let inner = outcomes
+            .into_iter()
+            .map(|(literal, modes)| {
+                let pattern = glob::Pattern::new(literal.as_str()).map_err(|error| {
+                    anyhow::anyhow!("Invalid test path pattern `{literal}`: {error}")
+                })?;
+                Ok((literal, pattern, modes))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self { inner })
+    }
+}
+
+impl TryFrom<PathBuf> for ExpectedOutcomes {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Expected outcomes file {path:?} reading: {error}"))?;
+        Self::from_str(text.as_str())
+            .map_err(|error| anyhow::anyhow!("Expected outcomes file {path:?} parsing: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::compilers::eravm::mode::Mode as EraVMMode;
+    use crate::compilers::mode::Mode;
+
+    use super::expected_outcome::ExpectedOutcome;
+    use super::ExpectedOutcomes;
+
+    #[test]
+    fn get() {
+        let expected_outcomes = ExpectedOutcomes::from_str(
+            r#"
+tests/solidity/simple/failing.sol:
+  "*": xfail
+tests/solidity/complex/**:
+  "*": xfail
+tests/solidity/simple/upstream.sol:
+  "Y+": xfail
+"#,
+        )
+        .expect("Always valid");
+        let mode = Mode::EraVM(EraVMMode::default());
+
+        for (path, expected) in [
+            (
+                "tests/solidity/simple/failing.sol",
+                Some(ExpectedOutcome::Xfail),
+            ),
+            (
+                "tests/solidity/complex/defi/test.json",
+                Some(ExpectedOutcome::Xfail),
+            ),
+            ("tests/solidity/simple/upstream.sol", None),
+            ("tests/solidity/simple/passing.sol", None),
+        ] {
+            assert_eq!(expected_outcomes.get(path, &mode), expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn from_str_invalid_pattern() {
+        let error = ExpectedOutcomes::from_str(
+            r#"
+tests/solidity/simple/[.sol:
+  "*": xfail
+"#,
+        )
+        .expect_err("Always invalid");
+        assert!(
+            error
+                .to_string()
+                .starts_with("Invalid test path pattern `tests/solidity/simple/[.sol`"),
+            "{error}"
+        );
+    }
+}
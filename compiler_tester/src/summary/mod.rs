@@ -3,21 +3,50 @@
 //!
 
 pub mod benchmark_adapters;
+pub mod benchmark_policy;
+pub mod benchmark_registry;
 pub mod element;
+pub mod expected_outcomes;
+pub mod report;
+pub mod sink;
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use benchmark_adapters::metadata::convert_description;
 use colored::Colorize;
 
+use crate::compilers::mode::Mode;
+use crate::compilers::statistics::Statistics;
+use crate::target_extension::TargetExtension;
 use crate::test::case::input::output::Output;
 use crate::test::description::TestDescription;
+use crate::test::selector::TestSelector;
 use crate::toolchain::Toolchain;
+use crate::vm::storage_access::StorageAccess;
 
+use self::benchmark_policy::BenchmarkPolicy;
+use self::benchmark_registry::BenchmarkRegistry;
+use self::element::outcome::deploy_metrics::DeployMetrics;
+use self::element::outcome::error_code::ErrorCode;
 use self::element::outcome::passed_variant::PassedVariant;
 use self::element::outcome::Outcome;
 use self::element::Element;
+use self::expected_outcomes::expected_outcome::ExpectedOutcome;
+use self::expected_outcomes::ExpectedOutcomes;
+use self::report::compilation::Compilation;
+use self::report::interface_check::interface::Interface;
+use self::report::pass_rates::feature_pass_rates::FeaturePassRates;
+use self::report::pass_rates::PassRates;
+use self::report::slow_tests::SlowTests;
+use self::report::status::Status;
+use self::report::Report;
+use self::sink::console::ConsoleSink;
+use self::sink::SummarySink;
 
 ///
 /// The compiler tester summary.
@@ -26,8 +55,8 @@ use self::element::Element;
 pub struct Summary {
     /// The summary elements.
     elements: Vec<Element>,
-    /// The output verbosity.
-    verbosity: bool,
+    /// The output sinks, starting with the console.
+    sinks: Vec<Box<dyn SummarySink>>,
     /// Whether the output is suppressed.
     quiet: bool,
     /// The passed tests counter.
@@ -36,27 +65,357 @@ pub struct Summary {
     failed: usize,
     /// The invalid tests counter.
     invalid: usize,
+    /// The expected failures counter.
+    expected_failures: usize,
     /// The ignored tests counter.
     ignored: usize,
+    /// The skipped tests counter.
+    skipped: usize,
+    /// The cached passed tests counter.
+    cached: usize,
+    /// The selected tests counters by tag.
+    tags: BTreeMap<String, usize>,
+    /// The compiler features declared by the tests, keyed by the test path.
+    features: BTreeMap<String, Vec<String>>,
+    /// Whether the test run has been interrupted before completion.
+    is_incomplete: bool,
+    /// The tests with failed or invalid outcomes, identified by target, mode, and path.
+    failing: HashSet<String>,
+    /// The target of the tests being run, set if several targets are run in a single invocation.
+    target: Option<era_compiler_common::Target>,
+    /// The contract interfaces by test selector and contract name, if their recording is enabled.
+    interfaces: Option<BTreeMap<(String, String), Interface>>,
+    /// The actual results of the Ethereum tests calls on REVM, if their recording is enabled.
+    expectations: Option<solidity_adapter::Expectations>,
+    /// The expected outcomes overriding the actual ones, if specified.
+    expected_outcomes: Option<ExpectedOutcomes>,
+    /// The benchmark recording policy, if specified.
+    benchmark_policy: Option<BenchmarkPolicy>,
+    /// The unexpected passes counter.
+    unexpected_passes: usize,
+    /// The tests expected to fail, which have passed so far, by their failing keys.
+    expected_failing_passed:
+        BTreeMap<String, (Option<era_compiler_common::Target>, TestDescription)>,
+    /// The number of the slowest tests to report, if their tracking is enabled.
+    slow_tests_count: Option<usize>,
+    /// The slow test threshold in milliseconds, if specified.
+    slow_threshold_ms: Option<u64>,
+    /// The compilation wall times in milliseconds by test name.
+    compilation_times: Vec<(String, u64)>,
+    /// The execution wall times in milliseconds by test name.
+    execution_times: Vec<(String, u64)>,
+    /// Whether the test wall times are written to the report.
+    is_durations_enabled: bool,
+    /// The compiler subprocess statistics taken at the end of the run, keyed by the compiler and mode.
+    compilations: BTreeMap<String, BTreeMap<String, Compilation>>,
 }
 
 impl Summary {
     /// The elements vector default capacity.
     pub const ELEMENTS_INITIAL_CAPACITY: usize = 1024 * 4096;
 
+    /// The default number of the slowest tests to report.
+    pub const SLOWEST_TESTS_DEFAULT_COUNT: usize = 10;
+
     ///
     /// A shortcut constructor.
     ///
+    /// The console sink is registered by default.
+    ///
     pub fn new(verbosity: bool, quiet: bool) -> Self {
         Self {
             elements: Vec::with_capacity(Self::ELEMENTS_INITIAL_CAPACITY),
-            verbosity,
+            sinks: vec![Box::new(ConsoleSink::new(verbosity, quiet))],
             quiet,
             passed: 0,
             failed: 0,
             invalid: 0,
+            expected_failures: 0,
             ignored: 0,
+            skipped: 0,
+            cached: 0,
+            tags: BTreeMap::new(),
+            features: BTreeMap::new(),
+            is_incomplete: false,
+            failing: HashSet::new(),
+            target: None,
+            interfaces: None,
+            expectations: None,
+            expected_outcomes: None,
+            benchmark_policy: None,
+            unexpected_passes: 0,
+            expected_failing_passed: BTreeMap::new(),
+            slow_tests_count: None,
+            slow_threshold_ms: None,
+            compilation_times: Vec::new(),
+            execution_times: Vec::new(),
+            is_durations_enabled: false,
+            compilations: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Registers an output sink, which receives the elements added afterwards.
+    ///
+    pub fn add_sink(&mut self, sink: Box<dyn SummarySink>) {
+        self.sinks.push(sink);
+    }
+
+    ///
+    /// Passes the final summary and its `report` to the sinks.
+    ///
+    /// All sinks are finished even if some of them fail, and the first error is returned.
+    ///
+    pub fn finish(&mut self, report: &Report) -> anyhow::Result<()> {
+        let mut sinks = std::mem::take(&mut self.sinks);
+        let mut result = Ok(());
+        for sink in sinks.iter_mut() {
+            if let Err(error) = sink.finish(self, report) {
+                if result.is_ok() {
+                    result = Err(error);
+                } else {
+                    eprintln!("{error:?}");
+                }
+            }
+        }
+        self.sinks = sinks;
+        result
+    }
+
+    ///
+    /// Enables tracking of the test wall times, reporting the `count` slowest tests and marking
+    /// the ones exceeding `threshold_ms`.
+    ///
+    pub fn enable_slow_tests(&mut self, count: usize, threshold_ms: Option<u64>) {
+        self.slow_tests_count = Some(count);
+        self.slow_threshold_ms = threshold_ms;
+    }
+
+    ///
+    /// Enables tracking of the test wall times, writing them to the report.
+    ///
+    pub fn enable_durations(&mut self) {
+        self.is_durations_enabled = true;
+    }
+
+    ///
+    /// Returns the slowest tests, if their tracking is enabled.
+    ///
+    pub fn slow_tests(&self) -> Option<SlowTests> {
+        self.slow_tests_count.map(|count| {
+            SlowTests::new(
+                count,
+                self.slow_threshold_ms,
+                self.compilation_times.as_slice(),
+                self.execution_times.as_slice(),
+            )
+        })
+    }
+
+    ///
+    /// Returns the pass rates of the tests by the target, compiler version, and codegen.
+    ///
+    pub fn pass_rates(&self) -> PassRates {
+        let mut pass_rates = PassRates::default();
+        for (target, mode, _, is_failed, is_expected_failure) in self.test_results().into_values() {
+            pass_rates.add(target, mode, is_failed, is_expected_failure);
+        }
+        pass_rates
+    }
+
+    ///
+    /// Returns the pass rates of the tests by the compiler features they declare.
+    ///
+    pub fn feature_pass_rates(&self) -> FeaturePassRates {
+        let mut feature_pass_rates = FeaturePassRates::default();
+        for (_, _, path, is_failed, is_expected_failure) in self.test_results().into_values() {
+            if let Some(features) = self.features.get(path) {
+                feature_pass_rates.add(features.as_slice(), is_failed, is_expected_failure);
+            }
+        }
+        feature_pass_rates
+    }
+
+    ///
+    /// Returns whether each test has failed or is an expected failure in each of its modes,
+    /// keyed by the target, mode, and path of the test.
+    ///
+    fn test_results(
+        &self,
+    ) -> BTreeMap<String, (Option<era_compiler_common::Target>, &Mode, &str, bool, bool)> {
+        let mut tests: BTreeMap<
+            String,
+            (Option<era_compiler_common::Target>, &Mode, &str, bool, bool),
+        > = BTreeMap::new();
+        for element in self.elements.iter() {
+            let mode = match element.test_description.mode.as_ref() {
+                Some(mode) => mode,
+                None => continue,
+            };
+            let (is_failed, is_expected_failure) = match element.outcome {
+                Outcome::Passed { .. } | Outcome::CachedPass => (false, false),
+                Outcome::Failed { .. } | Outcome::Invalid { .. } | Outcome::UnexpectedPass => {
+                    (true, false)
+                }
+                Outcome::ExpectedFailure { .. } => (false, true),
+                Outcome::Ignored | Outcome::Skipped { .. } => continue,
+            };
+            let key = Self::failing_key(
+                element.target,
+                mode,
+                element.test_description.selector.path.as_str(),
+            );
+            let test = tests.entry(key).or_insert((
+                element.target,
+                mode,
+                element.test_description.selector.path.as_str(),
+                false,
+                false,
+            ));
+            test.3 |= is_failed;
+            test.4 |= is_expected_failure;
+        }
+        tests
+    }
+
+    ///
+    /// Sets the target the subsequently added outcomes belong to.
+    ///
+    pub fn set_target(&mut self, target: Option<era_compiler_common::Target>) {
+        self.target = target;
+    }
+
+    ///
+    /// Enables the recording of the contract interfaces exported by the compilers.
+    ///
+    pub fn enable_interfaces(&mut self) {
+        self.interfaces = Some(BTreeMap::new());
+    }
+
+    ///
+    /// Returns the contract interfaces recorded so far, if their recording is enabled.
+    ///
+    pub fn interfaces(&self) -> Option<&BTreeMap<(String, String), Interface>> {
+        self.interfaces.as_ref()
+    }
+
+    ///
+    /// Enables the recording of the actual results of the Ethereum tests calls on REVM.
+    ///
+    pub fn enable_expectations(&mut self) {
+        self.expectations = Some(solidity_adapter::Expectations::default());
+    }
+
+    ///
+    /// Returns the actual results of the Ethereum tests calls recorded so far, if their recording is enabled.
+    ///
+    pub fn expectations(&self) -> Option<&solidity_adapter::Expectations> {
+        self.expectations.as_ref()
+    }
+
+    ///
+    /// Sets the expected outcomes overriding the actual ones.
+    ///
+    /// The failures of the tests expected to fail are reported as expected failures.
+    ///
+    pub fn set_expected_outcomes(&mut self, expected_outcomes: ExpectedOutcomes) {
+        self.expected_outcomes = Some(expected_outcomes);
+    }
+
+    ///
+    /// Sets the benchmark recording policy, which selects the metrics recorded per group.
+    ///
+    pub fn set_benchmark_policy(&mut self, benchmark_policy: BenchmarkPolicy) {
+        self.benchmark_policy = Some(benchmark_policy);
+    }
+
+    ///
+    /// Reports the tests which have been expected to fail, but have passed in all inputs,
+    /// so that they are removed from the expected outcomes.
+    ///
+    /// Must be called once after all tests have been run.
+    ///
+    pub fn report_unexpected_passes(&mut self) {
+        let target = self.target;
+        let expected_failing_passed = std::mem::take(&mut self.expected_failing_passed);
+        for (key, (test_target, test)) in expected_failing_passed.into_iter() {
+            if self.failing.contains(&key) {
+                continue;
+            }
+            self.target = test_target;
+            self.push_element(Element::new(test, Outcome::unexpected_pass()));
         }
+        self.target = target;
+    }
+
+    ///
+    /// Returns the elements added so far.
+    ///
+    pub fn elements(&self) -> &[Element] {
+        self.elements.as_slice()
+    }
+
+    ///
+    /// Returns whether the tests run are invalid, by the test target, mode, and path.
+    ///
+    /// A test is invalid if any of its outcomes is invalid. The ignored and skipped tests
+    /// have not been run, so they are not included.
+    ///
+    pub fn invalid_tests(&self) -> BTreeMap<String, bool> {
+        let mut tests = BTreeMap::new();
+        for element in self.elements.iter() {
+            let is_invalid = match element.outcome {
+                Outcome::Invalid { .. } => true,
+                Outcome::Ignored | Outcome::Skipped { .. } => continue,
+                _ => false,
+            };
+            let path = element.test_description.selector.path.as_str();
+            let name = match (element.test_description.mode.as_ref(), element.target) {
+                (Some(mode), target) => Self::failing_key(target, mode, path),
+                (None, Some(target)) => format!("{target} {path}"),
+                (None, None) => path.to_owned(),
+            };
+            *tests.entry(name).or_insert(false) |= is_invalid;
+        }
+        tests
+    }
+
+    ///
+    /// Returns the violations of the benchmark groups `registry` by the groups of the passed tests
+    /// and by the benchmark policy.
+    ///
+    pub fn benchmark_group_violations(&self, registry: &BenchmarkRegistry) -> Vec<String> {
+        let mut groups = BTreeMap::new();
+        for element in self.elements.iter() {
+            if let Outcome::Passed {
+                group: Some(group), ..
+            } = &element.outcome
+            {
+                *groups.entry(group.to_owned()).or_insert(0) += 1;
+            }
+        }
+        registry.violations(&groups, self.benchmark_policy.as_ref())
+    }
+
+    ///
+    /// Takes the compiler subprocess statistics recorded since they were last taken.
+    ///
+    /// Must be called at the end of the run, before the report is created.
+    ///
+    pub fn take_compilations(&mut self) {
+        for (compiler, modes) in Statistics::take().into_iter() {
+            let compilations = self.compilations.entry(compiler).or_default();
+            for (mode, compilation) in modes.into_iter() {
+                compilations.entry(mode).or_default().merge(&compilation);
+            }
+        }
+    }
+
+    ///
+    /// Marks the summary as incomplete, e.g. if the test run has been interrupted.
+    ///
+    pub fn set_incomplete(&mut self) {
+        self.is_incomplete = true;
     }
 
     ///
@@ -68,18 +427,47 @@ impl Summary {
                 Outcome::Passed { .. } => continue,
                 Outcome::Failed { .. } => return false,
                 Outcome::Invalid { .. } => return false,
+                Outcome::ExpectedFailure { .. } => continue,
+                Outcome::UnexpectedPass => return false,
                 Outcome::Ignored => continue,
+                Outcome::Skipped { .. } => continue,
+                Outcome::CachedPass => continue,
             }
         }
 
         true
     }
 
+    ///
+    /// Whether the test in the specified mode has any failed or invalid outcomes.
+    ///
+    pub fn has_failures(&self, path: &str, mode: &Mode) -> bool {
+        self.failing
+            .contains(&Self::failing_key(self.target, mode, path))
+    }
+
     ///
     /// Returns the benchmark structure.
     ///
-    pub fn benchmark(&self, toolchain: Toolchain) -> anyhow::Result<benchmark_analyzer::Benchmark> {
-        let mut benchmark = benchmark_analyzer::Benchmark::default();
+    /// If the `target` is specified, only its outcomes are included, and the target is appended
+    /// to the group names, so that benchmarks of several targets can be merged.
+    ///
+    pub fn benchmark(
+        &self,
+        toolchain: Toolchain,
+        target: Option<era_compiler_common::Target>,
+    ) -> anyhow::Result<benchmark_analyzer::Benchmark> {
+        let mut benchmark = benchmark_analyzer::Benchmark {
+            incomplete: self.is_incomplete,
+            ..Default::default()
+        };
+        let new_group = |name: &str| {
+            benchmark_analyzer::BenchmarkGroup::with_metrics(
+                self.benchmark_policy
+                    .as_ref()
+                    .and_then(|policy| policy.metrics(name)),
+            )
+        };
         match toolchain {
             Toolchain::IrLLVM => {
                 benchmark.groups.insert(
@@ -88,7 +476,7 @@ impl Summary {
                         benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME,
                         era_compiler_llvm_context::OptimizerSettings::cycles(),
                     ),
-                    benchmark_analyzer::BenchmarkGroup::default(),
+                    new_group(benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME),
                 );
                 benchmark.groups.insert(
                     format!(
@@ -96,13 +484,13 @@ impl Summary {
                         benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME,
                         era_compiler_llvm_context::OptimizerSettings::size(),
                     ),
-                    benchmark_analyzer::BenchmarkGroup::default(),
+                    new_group(benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME),
                 );
             }
             Toolchain::Solc => {
                 benchmark.groups.insert(
                     benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME.to_owned(),
-                    benchmark_analyzer::BenchmarkGroup::default(),
+                    new_group(benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME),
                 );
             }
             Toolchain::SolcLLVM => {
@@ -111,21 +499,12 @@ impl Summary {
         }
 
         for element in self.elements.iter() {
-            let (size, cycles, ergs, group, gas) = match &element.outcome {
-                Outcome::Passed {
-                    variant:
-                        PassedVariant::Deploy {
-                            size,
-                            cycles,
-                            ergs,
-                            gas,
-                        },
-                    group,
-                } => (Some(*size), *cycles, *ergs, group.clone(), *gas),
-                Outcome::Passed {
-                    variant: PassedVariant::Runtime { cycles, ergs, gas },
-                    group,
-                } => (None, *cycles, *ergs, group.clone(), *gas),
+            if target.is_some() && element.target != target {
+                continue;
+            }
+
+            let (variant, group) = match &element.outcome {
+                Outcome::Passed { variant, group } => (variant, group),
                 _ => continue,
             };
 
@@ -147,20 +526,21 @@ impl Summary {
 
             let metadata = {
                 let default_group = group.clone().unwrap_or_default();
-                convert_description(&element.test_description, &default_group)
+                convert_description(&element.test_description, &default_group, target)
+            };
+            let benchmark_element = match variant.benchmark_element(metadata) {
+                Some(benchmark_element) => benchmark_element,
+                None => continue,
             };
-            let benchmark_element =
-                benchmark_analyzer::BenchmarkElement::new(metadata, size, cycles, ergs, gas);
             if let Some(group) = group {
                 let group_key = match mode {
                     Some(ref mode) => format!("{group} {mode}"),
-                    None => group,
+                    None => group.clone(),
                 };
                 benchmark
                     .groups
                     .entry(group_key)
-                    .or_default()
-                    .elements
+                    .or_insert_with(|| new_group(group.as_str()))
                     .insert(key.clone(), benchmark_element.clone());
             }
 
@@ -171,12 +551,103 @@ impl Summary {
                 None => benchmark_analyzer::BENCHMARK_ALL_GROUP_NAME.to_owned(),
             };
             if let Some(group) = benchmark.groups.get_mut(group_key.as_str()) {
-                group.elements.insert(key, benchmark_element);
+                group.insert(key, benchmark_element);
             }
         }
+
+        if let Some(target) = target {
+            benchmark.groups = benchmark
+                .groups
+                .into_iter()
+                .map(|(name, group)| (format!("{name} {target}"), group))
+                .collect();
+        }
         Ok(benchmark)
     }
 
+    ///
+    /// Returns the benchmark of a target `extension` run, suffixing the group names with
+    /// the extension name.
+    ///
+    pub fn extension_benchmark(
+        &self,
+        toolchain: Toolchain,
+        extension: &dyn TargetExtension,
+    ) -> anyhow::Result<benchmark_analyzer::Benchmark> {
+        let mut benchmark = self.benchmark(toolchain, None)?;
+        benchmark.groups = benchmark
+            .groups
+            .into_iter()
+            .map(|(name, group)| (format!("{name} {}", extension.name()), group))
+            .collect();
+        Ok(benchmark)
+    }
+
+    ///
+    /// Returns the report with the status of each test.
+    ///
+    pub fn report(&self) -> Report {
+        let tests = self
+            .elements
+            .iter()
+            .map(|element| (element.name(), Status::from(&element.outcome)))
+            .collect();
+        let error_codes = self
+            .elements
+            .iter()
+            .filter_map(|element| {
+                element
+                    .outcome
+                    .error_code()
+                    .map(|code| (element.name(), code))
+            })
+            .collect();
+        let skip_reasons = self
+            .elements
+            .iter()
+            .filter_map(|element| match element.outcome {
+                Outcome::Skipped { ref reason } => Some((element.name(), reason.to_owned())),
+                _ => None,
+            })
+            .collect();
+        let mut durations = BTreeMap::new();
+        if self.is_durations_enabled {
+            for (name, wall_time_ms) in self
+                .compilation_times
+                .iter()
+                .chain(self.execution_times.iter())
+            {
+                *durations.entry(name.to_owned()).or_insert(0) += *wall_time_ms;
+            }
+        }
+        let mut file_hashes = BTreeMap::new();
+        if self.is_durations_enabled {
+            for element in self.elements.iter() {
+                let path =
+                    crate::utils::test_file_path(element.test_description.selector.path.as_str());
+                if file_hashes.contains_key(path) {
+                    continue;
+                }
+                if let Some(hash) = crate::utils::file_hash(Path::new(path)) {
+                    file_hashes.insert(path.to_owned(), hash);
+                }
+            }
+        }
+        Report {
+            incomplete: self.is_incomplete,
+            tests,
+            error_codes,
+            skip_reasons,
+            compilations: self.compilations.clone(),
+            slow_tests: self.slow_tests(),
+            pass_rates: self.pass_rates(),
+            feature_pass_rates: self.feature_pass_rates(),
+            durations,
+            failing: self.failing.iter().cloned().collect(),
+            file_hashes,
+        }
+    }
+
     ///
     /// Wraps data into a thread-safe shared reference.
     ///
@@ -195,21 +666,67 @@ impl Summary {
     }
 
     ///
-    /// Adds a passed outcome of a deploy call.
+    /// Records the contract interfaces of the test at `selector`, if their recording is enabled.
     ///
-    pub fn passed_deploy(
+    /// The interfaces do not depend on the mode, so only the first recorded ones are kept.
+    ///
+    pub fn record_interfaces(
         summary: Arc<Mutex<Self>>,
-        test: TestDescription,
-        size: usize,
-        cycles: usize,
-        ergs: u64,
-        gas: u64,
+        selector: &str,
+        method_identifiers: Option<&BTreeMap<String, BTreeMap<String, u32>>>,
+        abis: Option<&BTreeMap<String, serde_json::Value>>,
     ) {
+        let method_identifiers = match method_identifiers {
+            Some(method_identifiers) => method_identifiers,
+            None => return,
+        };
+        let mut summary = summary.lock().expect("Sync");
+        let interfaces = match summary.interfaces.as_mut() {
+            Some(interfaces) => interfaces,
+            None => return,
+        };
+        for (contract, contract_identifiers) in method_identifiers.iter() {
+            interfaces
+                .entry((selector.to_owned(), contract.to_owned()))
+                .or_insert_with(|| {
+                    Interface::new(
+                        contract_identifiers.to_owned(),
+                        abis.and_then(|abis| abis.get(contract)).cloned(),
+                    )
+                });
+        }
+    }
+
+    ///
+    /// Records the actual result of the call at `input_index` of the test at `path`, if the recording
+    /// is enabled.
+    ///
+    pub fn record_expectation(
+        summary: Arc<Mutex<Self>>,
+        path: &str,
+        input_index: usize,
+        expectation: solidity_adapter::Expectation,
+    ) {
+        if let Some(expectations) = summary.lock().expect("Sync").expectations.as_mut() {
+            expectations.insert(path.to_owned(), input_index, expectation);
+        }
+    }
+
+    ///
+    /// Adds a passed outcome of a deploy call.
+    ///
+    pub fn passed_deploy(summary: Arc<Mutex<Self>>, test: TestDescription, metrics: DeployMetrics) {
         let passed_variant = PassedVariant::Deploy {
-            size,
-            cycles,
-            ergs,
-            gas,
+            size: metrics.size,
+            runtime_size: metrics.runtime_size,
+            metadata_size: metrics.metadata_size,
+            instructions: metrics.instructions,
+            function_sizes: metrics.function_sizes,
+            cycles: metrics.cycles,
+            ergs: metrics.ergs,
+            gas: metrics.gas,
+            stack_depth: metrics.stack_depth,
+            pubdata: metrics.pubdata,
         };
         Self::passed(summary, test, passed_variant);
     }
@@ -223,8 +740,20 @@ impl Summary {
         cycles: usize,
         ergs: u64,
         gas: u64,
+        stack_depth: Option<usize>,
+        pubdata: Option<usize>,
+        cold_accesses: Option<usize>,
+        warm_accesses: Option<usize>,
     ) {
-        let passed_variant = PassedVariant::Runtime { cycles, ergs, gas };
+        let passed_variant = PassedVariant::Runtime {
+            cycles,
+            ergs,
+            gas,
+            stack_depth,
+            pubdata,
+            cold_accesses,
+            warm_accesses,
+        };
         Self::passed(summary, test, passed_variant);
     }
 
@@ -246,21 +775,121 @@ impl Summary {
         found: Output,
         calldata: Vec<u8>,
     ) {
-        let element = Element::new(test, Outcome::failed(expected, found, calldata));
+        Self::failed_with_storage(summary, test, expected, found, calldata, Vec::new());
+    }
+
+    ///
+    /// Adds a failed outcome with the storage slots accessed by the failed input.
+    ///
+    pub fn failed_with_storage(
+        summary: Arc<Mutex<Self>>,
+        test: TestDescription,
+        expected: Output,
+        found: Output,
+        calldata: Vec<u8>,
+        storage: Vec<StorageAccess>,
+    ) {
+        let element = Element::new(test, Outcome::failed(expected, found, calldata, storage));
         summary.lock().expect("Sync").push_element(element);
     }
 
     ///
     /// Adds an invalid outcome.
     ///
-    pub fn invalid<S>(summary: Arc<Mutex<Self>>, test: TestDescription, error: S)
+    pub fn invalid<S>(summary: Arc<Mutex<Self>>, test: TestDescription, code: ErrorCode, error: S)
     where
         S: ToString,
     {
-        let element = Element::new(test, Outcome::invalid(error));
+        let element = Element::new(test, Outcome::invalid(code, error));
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Adds an unexpected pass outcome.
+    ///
+    pub fn unexpected_pass(summary: Arc<Mutex<Self>>, test: TestDescription) {
+        let element = Element::new(test, Outcome::unexpected_pass());
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Adds an expected failure outcome.
+    ///
+    pub fn expected_failure<S>(
+        summary: Arc<Mutex<Self>>,
+        test: TestDescription,
+        code: ErrorCode,
+        error: S,
+    ) where
+        S: ToString,
+    {
+        let element = Element::new(test, Outcome::expected_failure(code, error));
         summary.lock().expect("Sync").push_element(element);
     }
 
+    ///
+    /// Records the compilation wall time of a test, if the tracking is enabled.
+    ///
+    pub fn record_compilation_time(
+        summary: Arc<Mutex<Self>>,
+        test: &TestDescription,
+        wall_time: Duration,
+    ) {
+        let mut summary = summary.lock().expect("Sync");
+        if summary.slow_tests_count.is_some() || summary.is_durations_enabled {
+            let name = summary.timing_name(test);
+            summary
+                .compilation_times
+                .push((name, wall_time.as_millis() as u64));
+        }
+    }
+
+    ///
+    /// Records the execution wall time of a test, if the tracking is enabled.
+    ///
+    pub fn record_execution_time(
+        summary: Arc<Mutex<Self>>,
+        test: &TestDescription,
+        wall_time: Duration,
+    ) {
+        let mut summary = summary.lock().expect("Sync");
+        if summary.slow_tests_count.is_some() || summary.is_durations_enabled {
+            let name = summary.timing_name(test);
+            summary
+                .execution_times
+                .push((name, wall_time.as_millis() as u64));
+        }
+    }
+
+    ///
+    /// Counts a selected test for each of its tags.
+    ///
+    pub fn tagged(summary: Arc<Mutex<Self>>, tags: &[String]) {
+        if tags.is_empty() {
+            return;
+        }
+
+        let mut summary = summary.lock().expect("Sync");
+        for tag in tags.iter() {
+            *summary.tags.entry(tag.to_owned()).or_default() += 1;
+        }
+    }
+
+    ///
+    /// Records the compiler features declared by the test at `path`.
+    ///
+    pub fn featured(summary: Arc<Mutex<Self>>, path: &str, features: &[String]) {
+        if features.is_empty() {
+            return;
+        }
+
+        summary
+            .lock()
+            .expect("Sync")
+            .features
+            .insert(path.to_owned(), features.to_vec());
+    }
+
     ///
     /// Adds an ignored outcome.
     ///
@@ -269,6 +898,25 @@ impl Summary {
         summary.lock().expect("Sync").push_element(element);
     }
 
+    ///
+    /// Adds a skipped outcome with the `reason` the test is not applicable to the run.
+    ///
+    pub fn skipped<S>(summary: Arc<Mutex<Self>>, test: TestDescription, reason: S)
+    where
+        S: ToString,
+    {
+        let element = Element::new(test.with_erased_mode(), Outcome::skipped(reason));
+        summary.lock().expect("Sync").push_element(element);
+    }
+
+    ///
+    /// Adds a cached pass outcome.
+    ///
+    pub fn cached_pass(summary: Arc<Mutex<Self>>, test: TestDescription) {
+        let element = Element::new(test, Outcome::cached_pass());
+        summary.lock().expect("Sync").push_element(element);
+    }
+
     ///
     /// The unified function for passed outcomes.
     ///
@@ -281,43 +929,119 @@ impl Summary {
     ///
     /// Pushes an element to the summary, printing it.
     ///
-    fn push_element(&mut self, element: Element) {
-        if let Some(string) = element.print(self.verbosity) {
-            println!("{string}");
-        }
+    fn push_element(&mut self, mut element: Element) {
+        element.target = self.target;
+        self.apply_expected_outcome(&mut element);
 
-        let is_executed = match element.outcome {
-            Outcome::Passed { .. } => {
-                self.passed += 1;
-                true
-            }
+        match element.outcome {
+            Outcome::Passed { .. } => self.passed += 1,
             Outcome::Failed { .. } => {
                 self.failed += 1;
-                true
+                self.mark_failing(&element);
             }
             Outcome::Invalid { .. } => {
                 self.invalid += 1;
-                true
+                self.mark_failing(&element);
             }
-            Outcome::Ignored => {
-                self.ignored += 1;
-                false
+            Outcome::ExpectedFailure { .. } => self.expected_failures += 1,
+            Outcome::UnexpectedPass => self.unexpected_passes += 1,
+            Outcome::Ignored => self.ignored += 1,
+            Outcome::Skipped { .. } => self.skipped += 1,
+            Outcome::CachedPass => self.cached += 1,
+        }
+
+        let mut sinks = std::mem::take(&mut self.sinks);
+        for sink in sinks.iter_mut() {
+            sink.element(self, &element);
+        }
+        self.sinks = sinks;
+
+        self.elements.push(element);
+    }
+
+    ///
+    /// Turns a failure of a test expected to fail into an expected failure, and remembers
+    /// the passed tests expected to fail to report them later.
+    ///
+    fn apply_expected_outcome(&mut self, element: &mut Element) {
+        let mode = match element.test_description.mode.as_ref() {
+            Some(mode) => mode,
+            None => return,
+        };
+        let path = element.test_description.selector.path.as_str();
+        match self
+            .expected_outcomes
+            .as_ref()
+            .and_then(|expected_outcomes| expected_outcomes.get(path, mode))
+        {
+            Some(ExpectedOutcome::Xfail) => {}
+            None => return,
+        }
+
+        let (code, error) = match element.outcome {
+            Outcome::Failed { .. } => (
+                ErrorCode::OutputMismatch,
+                "Known failure: the output does not match".to_owned(),
+            ),
+            Outcome::Invalid { code, ref error } => (code, format!("Known failure: {error}")),
+            Outcome::Passed { .. } | Outcome::CachedPass => {
+                let key = Self::failing_key(element.target, mode, path);
+                let test = TestDescription {
+                    group: element.test_description.group.clone(),
+                    mode: Some(mode.to_owned()),
+                    selector: TestSelector {
+                        path: path.to_owned(),
+                        case: None,
+                        input: None,
+                    },
+                };
+                self.expected_failing_passed
+                    .entry(key)
+                    .or_insert((element.target, test));
+                return;
             }
+            _ => return,
         };
+        self.mark_failing(element);
+        element.outcome = Outcome::expected_failure(code, error);
+    }
 
-        if is_executed {
-            let milestone = if self.verbosity {
-                usize::pow(10, 3)
-            } else {
-                usize::pow(10, 5)
-            };
+    ///
+    /// Returns the name of a test in the wall time lists, consisting of the target, mode, and path.
+    ///
+    fn timing_name(&self, test: &TestDescription) -> String {
+        let mut name = String::new();
+        if let Some(target) = self.target {
+            name.push_str(format!("{target} ").as_str());
+        }
+        if let Some(mode) = test.mode.as_ref() {
+            name.push_str(format!("{mode} ").as_str());
+        }
+        name.push_str(test.selector.path.as_str());
+        name
+    }
 
-            if (self.passed + self.failed + self.invalid) % milestone == 0 {
-                println!("{self}");
-            }
+    ///
+    /// Remembers the test of the element as failing.
+    ///
+    fn mark_failing(&mut self, element: &Element) {
+        if let Some(mode) = element.test_description.mode.as_ref() {
+            self.failing.insert(Self::failing_key(
+                element.target,
+                mode,
+                element.test_description.selector.path.as_str(),
+            ));
         }
+    }
 
-        self.elements.push(element);
+    ///
+    /// Returns the key identifying a test in the set of failing ones.
+    ///
+    fn failing_key(target: Option<era_compiler_common::Target>, mode: &Mode, path: &str) -> String {
+        match target {
+            Some(target) => format!("{target} {mode} {path}"),
+            None => format!("{mode} {path}"),
+        }
     }
 }
 
@@ -353,17 +1077,62 @@ impl std::fmt::Display for Summary {
             "INVALID".red(),
             self.invalid.to_string().red(),
         )?;
+        writeln!(
+            f,
+            "║     {:7}                                   {:10}     ║",
+            "XFAILED".yellow(),
+            self.expected_failures.to_string().yellow(),
+        )?;
+        if self.unexpected_passes > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "XPASSED".bright_red(),
+                self.unexpected_passes.to_string().bright_red(),
+            )?;
+        }
         writeln!(
             f,
             "║     {:7}                                   {:10}     ║",
             "IGNORED".bright_black(),
             self.ignored.to_string().bright_black(),
         )?;
+        if self.skipped > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "SKIPPED".bright_black(),
+                self.skipped.to_string().bright_black(),
+            )?;
+        }
+        if self.cached > 0 {
+            writeln!(
+                f,
+                "║     {:7}                                   {:10}     ║",
+                "CACHED".green(),
+                self.cached.to_string().green(),
+            )?;
+        }
+        for (tag, count) in self.tags.iter() {
+            writeln!(
+                f,
+                "║     {:41} {:10}     ║",
+                format!("#{tag}").bright_white(),
+                count.to_string().bright_white(),
+            )?;
+        }
         writeln!(
             f,
             "║               {:10} TESTS MILESTONE                     ║",
-            self.passed + self.failed + self.invalid,
+            self.passed + self.failed + self.invalid + self.expected_failures,
         )?;
+        if self.is_incomplete {
+            writeln!(
+                f,
+                "║{}║",
+                format!("{:^62}", "INTERRUPTED, THE RESULTS ARE INCOMPLETE").bright_yellow(),
+            )?;
+        }
         writeln!(
             f,
             "╚══════════════════════════════════════════════════════════════╝"
@@ -0,0 +1,84 @@
+//!
+//! The comparison of two compiler tester summary reports.
+//!
+
+use colored::Colorize;
+
+use crate::summary::report::status::Status;
+use crate::summary::report::Report;
+
+///
+/// The comparison of two compiler tester summary reports.
+///
+#[derive(Debug, Default)]
+pub struct Comparison {
+    /// The tests which are failing now, but were not failing before.
+    pub newly_failing: Vec<String>,
+    /// The tests which are passing now, but were not passing before.
+    pub newly_passing: Vec<String>,
+    /// The tests which are invalid now, but were not invalid before.
+    pub newly_invalid: Vec<String>,
+    /// The tests which are missing in the new report.
+    pub disappeared: Vec<String>,
+}
+
+impl Comparison {
+    ///
+    /// Compares the `new` report against the `old` one.
+    ///
+    pub fn new(old: &Report, new: &Report) -> Self {
+        let mut comparison = Self::default();
+
+        for (name, old_status) in old.tests.iter() {
+            let new_status = match new.tests.get(name) {
+                Some(new_status) => new_status,
+                None => {
+                    comparison.disappeared.push(name.to_owned());
+                    continue;
+                }
+            };
+            if old_status == new_status {
+                continue;
+            }
+
+            match new_status {
+                Status::Failed => comparison.newly_failing.push(name.to_owned()),
+                Status::Passed | Status::UnexpectedPass => {
+                    comparison.newly_passing.push(name.to_owned())
+                }
+                Status::Invalid => comparison.newly_invalid.push(name.to_owned()),
+                Status::ExpectedFailure
+                | Status::Ignored
+                | Status::Skipped
+                | Status::CachedPass => {}
+            }
+        }
+
+        comparison
+    }
+
+    ///
+    /// Whether there are any tests which are failing or invalid now, but were not before.
+    ///
+    pub fn has_regressions(&self) -> bool {
+        !self.newly_failing.is_empty() || !self.newly_invalid.is_empty()
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (title, tests) in [
+            ("Newly failing".bright_red(), &self.newly_failing),
+            ("Newly passing".green(), &self.newly_passing),
+            ("Newly invalid".red(), &self.newly_invalid),
+            ("Disappeared".bright_black(), &self.disappeared),
+        ] {
+            writeln!(f, "{title} ({}):", tests.len())?;
+            for test in tests.iter() {
+                writeln!(f, "    {test}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
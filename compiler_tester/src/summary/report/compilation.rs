@@ -0,0 +1,93 @@
+//!
+//! The compiler subprocess statistics of a summary report.
+//!
+
+use crate::compilers::statistics::usage::Usage;
+
+///
+/// The compiler subprocess statistics of a summary report.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Compilation {
+    /// The number of subprocesses.
+    pub subprocesses: usize,
+    /// The number of subprocesses which have not exited successfully.
+    pub failures: usize,
+    /// The total wall time in milliseconds.
+    pub wall_time_ms: u64,
+    /// The longest wall time in milliseconds.
+    pub max_wall_time_ms: u64,
+    /// The largest peak resident set size in kibibytes, if it could be measured.
+    pub peak_rss_kib: Option<u64>,
+}
+
+impl Compilation {
+    ///
+    /// Adds a subprocess to the statistics.
+    ///
+    pub fn record(&mut self, usage: &Usage) {
+        let wall_time_ms = usage.wall_time.as_millis() as u64;
+
+        self.subprocesses += 1;
+        if !usage.is_success {
+            self.failures += 1;
+        }
+        self.wall_time_ms += wall_time_ms;
+        self.max_wall_time_ms = self.max_wall_time_ms.max(wall_time_ms);
+        if let Some(peak_rss) = usage.peak_rss {
+            self.peak_rss_kib = Some(self.peak_rss_kib.unwrap_or_default().max(peak_rss));
+        }
+    }
+
+    ///
+    /// Adds the `other` statistics of the same compiler and mode.
+    ///
+    pub fn merge(&mut self, other: &Self) {
+        self.subprocesses += other.subprocesses;
+        self.failures += other.failures;
+        self.wall_time_ms += other.wall_time_ms;
+        self.max_wall_time_ms = self.max_wall_time_ms.max(other.max_wall_time_ms);
+        self.peak_rss_kib = self.peak_rss_kib.max(other.peak_rss_kib);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::compilers::statistics::usage::Usage;
+
+    use super::Compilation;
+
+    #[test]
+    fn merge() {
+        let mut first = Compilation::default();
+        first.record(&Usage::new(Duration::from_millis(300), Some(1024), true));
+        first.record(&Usage::new(Duration::from_millis(100), None, false));
+        let mut second = Compilation::default();
+        second.record(&Usage::new(Duration::from_millis(200), None, true));
+
+        for (mut compilation, other, expected) in [
+            (first.clone(), &second, (3, 1, 600, 300, Some(1024))),
+            (second.clone(), &first, (3, 1, 600, 300, Some(1024))),
+            (
+                second.clone(),
+                &Compilation::default(),
+                (1, 0, 200, 200, None),
+            ),
+        ] {
+            compilation.merge(other);
+            assert_eq!(
+                (
+                    compilation.subprocesses,
+                    compilation.failures,
+                    compilation.wall_time_ms,
+                    compilation.max_wall_time_ms,
+                    compilation.peak_rss_kib,
+                ),
+                expected,
+                "{other:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//!
+//! The test status divergence between two toolchains.
+//!
+
+use crate::summary::report::status::Status;
+
+///
+/// The test status divergence between two toolchains.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Divergence {
+    /// The test selector.
+    pub selector: String,
+    /// The test status with the first toolchain.
+    pub first: Status,
+    /// The test status with the second toolchain.
+    pub second: Status,
+}
+
+impl Divergence {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(selector: String, first: Status, second: Status) -> Self {
+        Self {
+            selector,
+            first,
+            second,
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The cross-check of two compiler tester summary reports produced with different toolchains.
+//!
+
+pub mod divergence;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::summary::report::status::Status;
+use crate::summary::report::Report;
+
+use self::divergence::Divergence;
+
+///
+/// The cross-check of two compiler tester summary reports produced with different toolchains.
+///
+/// The statuses are compared by test selector, since the modes of toolchains are different.
+/// Only the Solidity tests present in both reports are compared.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct CrossCheck {
+    /// The first toolchain description.
+    pub first: String,
+    /// The second toolchain description.
+    pub second: String,
+    /// The tests passing with one toolchain and failing with the other.
+    pub divergences: Vec<Divergence>,
+}
+
+impl CrossCheck {
+    /// The shared Solidity tests directory prefix.
+    const SHARED_TESTS_PREFIX: &'static str = "tests/solidity/";
+
+    ///
+    /// Cross-checks the `first` and `second` reports.
+    ///
+    pub fn new(first_name: String, first: &Report, second_name: String, second: &Report) -> Self {
+        let first_statuses = Self::statuses_by_selector(first, Self::SHARED_TESTS_PREFIX);
+        let second_statuses = Self::statuses_by_selector(second, Self::SHARED_TESTS_PREFIX);
+
+        let mut divergences = Vec::new();
+        for (selector, first_status) in first_statuses.into_iter() {
+            let second_status = match second_statuses.get(selector.as_str()) {
+                Some(second_status) => *second_status,
+                None => continue,
+            };
+            if Self::is_passed(first_status) && Self::is_failed(second_status)
+                || Self::is_failed(first_status) && Self::is_passed(second_status)
+            {
+                divergences.push(Divergence::new(selector, first_status, second_status));
+            }
+        }
+
+        Self {
+            first: first_name,
+            second: second_name,
+            divergences,
+        }
+    }
+
+    ///
+    /// Whether the toolchains agree on all shared tests.
+    ///
+    pub fn is_aligned(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    ///
+    /// Writes the divergence list to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self).expect("Always valid");
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Cross-check file {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Returns the statuses of the tests whose selectors start with `prefix` by selector,
+    /// taking the worst status among all modes.
+    ///
+    pub fn statuses_by_selector(report: &Report, prefix: &str) -> BTreeMap<String, Status> {
+        let mut statuses: BTreeMap<String, Status> = BTreeMap::new();
+        for (name, status) in report.tests.iter() {
+            let selector = match name.split_whitespace().last() {
+                Some(selector) if selector.starts_with(prefix) => selector,
+                _ => continue,
+            };
+            statuses
+                .entry(selector.to_owned())
+                .and_modify(|worst| {
+                    if Self::severity(*status) > Self::severity(*worst) {
+                        *worst = *status;
+                    }
+                })
+                .or_insert(*status);
+        }
+        statuses
+    }
+
+    ///
+    /// Returns the status severity used to pick the worst status among modes.
+    ///
+    fn severity(status: Status) -> usize {
+        match status {
+            Status::Ignored | Status::Skipped | Status::ExpectedFailure => 0,
+            Status::Passed | Status::UnexpectedPass | Status::CachedPass => 1,
+            Status::Invalid | Status::Failed => 2,
+        }
+    }
+
+    ///
+    /// Whether the status is a pass.
+    ///
+    pub fn is_passed(status: Status) -> bool {
+        matches!(
+            status,
+            Status::Passed | Status::UnexpectedPass | Status::CachedPass
+        )
+    }
+
+    ///
+    /// Whether the status is a failure.
+    ///
+    pub fn is_failed(status: Status) -> bool {
+        matches!(status, Status::Failed | Status::Invalid)
+    }
+}
+
+impl std::fmt::Display for CrossCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} between `{}` and `{}` ({}):",
+            "Divergences".bright_red(),
+            self.first,
+            self.second,
+            self.divergences.len(),
+        )?;
+        for divergence in self.divergences.iter() {
+            writeln!(
+                f,
+                "    {} ({:?} vs {:?})",
+                divergence.selector, divergence.first, divergence.second,
+            )?;
+        }
+
+        Ok(())
+    }
+}
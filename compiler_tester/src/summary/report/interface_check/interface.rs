@@ -0,0 +1,36 @@
+//!
+//! The contract interface exported by a compiler.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The contract interface exported by a compiler.
+///
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct Interface {
+    /// The method identifiers, keyed by the method signature.
+    pub method_identifiers: BTreeMap<String, u32>,
+    /// The contract ABI, with the entries sorted to make it independent of the compiler order.
+    pub abi: Option<serde_json::Value>,
+}
+
+impl Interface {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(method_identifiers: BTreeMap<String, u32>, abi: Option<serde_json::Value>) -> Self {
+        let abi = abi.map(|abi| match abi {
+            serde_json::Value::Array(mut entries) => {
+                entries.sort_by_key(|entry| entry.to_string());
+                serde_json::Value::Array(entries)
+            }
+            abi => abi,
+        });
+
+        Self {
+            method_identifiers,
+            abi,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//!
+//! The contract interface mismatch between two toolchains.
+//!
+
+///
+/// The contract interface mismatch between two toolchains.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Mismatch {
+    /// The test selector.
+    pub selector: String,
+    /// The contract full name.
+    pub contract: String,
+    /// The method signatures whose identifiers differ or are missing with one of the toolchains.
+    pub methods: Vec<String>,
+    /// Whether the ABI differs.
+    pub is_abi_different: bool,
+    /// The toolchain which has not built the contract, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_with: Option<String>,
+}
+
+impl Mismatch {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        selector: String,
+        contract: String,
+        methods: Vec<String>,
+        is_abi_different: bool,
+    ) -> Self {
+        Self {
+            selector,
+            contract,
+            methods,
+            is_abi_different,
+            missing_with: None,
+        }
+    }
+
+    ///
+    /// Creates a mismatch of a contract which has not been built with the `toolchain`.
+    ///
+    pub fn missing(selector: String, contract: String, toolchain: String) -> Self {
+        Self {
+            selector,
+            contract,
+            methods: vec![],
+            is_abi_different: false,
+            missing_with: Some(toolchain),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//!
+//! The contract interface check of two toolchains.
+//!
+
+pub mod interface;
+pub mod mismatch;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use colored::Colorize;
+
+use self::interface::Interface;
+use self::mismatch::Mismatch;
+
+///
+/// The contract interface check of two toolchains.
+///
+/// The method identifiers and ABI exported by each toolchain are compared by test selector and
+/// contract name. The contracts built with only one of the toolchains are reported as mismatches,
+/// since a test invalid with one of them would otherwise go unnoticed.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct InterfaceCheck {
+    /// The first toolchain description.
+    pub first: String,
+    /// The second toolchain description.
+    pub second: String,
+    /// The number of contracts built with either toolchain.
+    pub compared: usize,
+    /// The contracts whose interfaces differ.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl InterfaceCheck {
+    ///
+    /// Compares the interfaces exported by the `first` and `second` toolchains.
+    ///
+    pub fn new(
+        first_name: String,
+        first: &BTreeMap<(String, String), Interface>,
+        second_name: String,
+        second: &BTreeMap<(String, String), Interface>,
+    ) -> Self {
+        let mut compared = 0;
+        let mut mismatches = Vec::new();
+        for key in first.keys().chain(second.keys()).collect::<BTreeSet<_>>() {
+            let (selector, contract) = key;
+            compared += 1;
+            let (first_interface, second_interface) = match (first.get(key), second.get(key)) {
+                (Some(first_interface), Some(second_interface)) => {
+                    (first_interface, second_interface)
+                }
+                (Some(_), None) => {
+                    mismatches.push(Mismatch::missing(
+                        selector.to_owned(),
+                        contract.to_owned(),
+                        second_name.clone(),
+                    ));
+                    continue;
+                }
+                (None, _) => {
+                    mismatches.push(Mismatch::missing(
+                        selector.to_owned(),
+                        contract.to_owned(),
+                        first_name.clone(),
+                    ));
+                    continue;
+                }
+            };
+            if first_interface == second_interface {
+                continue;
+            }
+
+            let methods = first_interface
+                .method_identifiers
+                .keys()
+                .chain(second_interface.method_identifiers.keys())
+                .collect::<BTreeSet<&String>>()
+                .into_iter()
+                .filter(|method| {
+                    first_interface.method_identifiers.get(*method)
+                        != second_interface.method_identifiers.get(*method)
+                })
+                .cloned()
+                .collect();
+            mismatches.push(Mismatch::new(
+                selector.to_owned(),
+                contract.to_owned(),
+                methods,
+                first_interface.abi != second_interface.abi,
+            ));
+        }
+
+        Self {
+            first: first_name,
+            second: second_name,
+            compared,
+            mismatches,
+        }
+    }
+
+    ///
+    /// Whether the toolchains have built the same contracts with the same interfaces.
+    ///
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    ///
+    /// Writes the mismatch list to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self).expect("Always valid");
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Interface check file {path:?} writing: {error}"))
+    }
+}
+
+impl std::fmt::Display for InterfaceCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} between `{}` and `{}` ({} out of {} contracts):",
+            "Interface mismatches".bright_red(),
+            self.first,
+            self.second,
+            self.mismatches.len(),
+            self.compared,
+        )?;
+        for mismatch in self.mismatches.iter() {
+            write!(f, "    {} {}", mismatch.selector, mismatch.contract)?;
+            if mismatch.is_abi_different {
+                write!(f, " (ABI)")?;
+            }
+            if let Some(toolchain) = mismatch.missing_with.as_deref() {
+                write!(f, " (missing with `{toolchain}`)")?;
+            }
+            writeln!(f)?;
+            for method in mismatch.methods.iter() {
+                writeln!(f, "        {method}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::interface::Interface;
+    use super::InterfaceCheck;
+
+    #[test]
+    fn new() {
+        let interface =
+            |selector: u32| Interface::new(BTreeMap::from([("get()".to_owned(), selector)]), None);
+        let key = |contract: &str| ("tests/test.sol".to_owned(), contract.to_owned());
+        let first = BTreeMap::from([
+            (key("Same"), interface(0x6d4ce63c)),
+            (key("Different"), interface(0x6d4ce63c)),
+            (key("FirstOnly"), interface(0x6d4ce63c)),
+        ]);
+        let second = BTreeMap::from([
+            (key("Same"), interface(0x6d4ce63c)),
+            (key("Different"), interface(0x12345678)),
+            (key("SecondOnly"), interface(0x6d4ce63c)),
+        ]);
+
+        let check = InterfaceCheck::new(
+            "evm solc".to_owned(),
+            &first,
+            "evm ir-llvm".to_owned(),
+            &second,
+        );
+        assert_eq!(check.compared, 4);
+        assert!(!check.is_consistent());
+        assert_eq!(
+            check
+                .mismatches
+                .iter()
+                .map(|mismatch| (
+                    mismatch.contract.as_str(),
+                    mismatch.methods.clone(),
+                    mismatch.missing_with.as_deref(),
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Different", vec!["get()".to_owned()], None),
+                ("FirstOnly", vec![], Some("evm ir-llvm")),
+                ("SecondOnly", vec![], Some("evm solc")),
+            ]
+        );
+
+        let check = InterfaceCheck::new(
+            "evm solc".to_owned(),
+            &second,
+            "evm solc".to_owned(),
+            &second,
+        );
+        assert!(check.is_consistent());
+    }
+}
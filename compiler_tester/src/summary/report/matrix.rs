@@ -0,0 +1,95 @@
+//!
+//! The per-version outcome matrix of compiler tester summary reports.
+//!
+
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+
+use crate::summary::report::status::Status;
+use crate::summary::report::Report;
+
+///
+/// The per-version outcome matrix of compiler tester summary reports.
+///
+/// Only the tests whose status is not the same across all versions are kept.
+///
+#[derive(Debug, Default)]
+pub struct Matrix {
+    /// The compiler versions, in the order of the columns.
+    pub versions: Vec<String>,
+    /// The test statuses by version, keyed by the test mode and selector.
+    pub tests: BTreeMap<String, Vec<Option<Status>>>,
+}
+
+impl Matrix {
+    ///
+    /// Builds the matrix from the `reports` of each version.
+    ///
+    pub fn new(reports: &[(String, Report)]) -> Self {
+        let versions = reports
+            .iter()
+            .map(|(version, _)| version.to_owned())
+            .collect();
+
+        let mut tests: BTreeMap<String, Vec<Option<Status>>> = BTreeMap::new();
+        for (index, (_, report)) in reports.iter().enumerate() {
+            for (name, status) in report.tests.iter() {
+                tests
+                    .entry(name.to_owned())
+                    .or_insert_with(|| vec![None; reports.len()])[index] = Some(*status);
+            }
+        }
+        tests.retain(|_, statuses| statuses.iter().any(|status| *status != statuses[0]));
+
+        Self { versions, tests }
+    }
+
+    ///
+    /// Returns the short colored label of a status.
+    ///
+    fn status_label(status: Option<Status>) -> colored::ColoredString {
+        match status {
+            Some(Status::Passed) => "PASSED".green(),
+            Some(Status::Failed) => "FAILED".bright_red(),
+            Some(Status::Invalid) => "INVALID".red(),
+            Some(Status::ExpectedFailure) => "XFAIL".bright_black(),
+            Some(Status::UnexpectedPass) => "XPASS".bright_red(),
+            Some(Status::Ignored) => "IGNORED".bright_black(),
+            Some(Status::Skipped) => "SKIPPED".bright_black(),
+            Some(Status::CachedPass) => "CACHED".green(),
+            None => "-".bright_black(),
+        }
+    }
+}
+
+impl std::fmt::Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .versions
+            .iter()
+            .map(|version| version.len())
+            .max()
+            .unwrap_or_default()
+            .max("INVALID".len());
+
+        writeln!(
+            f,
+            "Tests with different outcomes across versions ({}):",
+            self.tests.len()
+        )?;
+        for version in self.versions.iter() {
+            write!(f, "{} ", format!("{version:width$}").bright_white())?;
+        }
+        writeln!(f)?;
+        for (name, statuses) in self.tests.iter() {
+            for status in statuses.iter() {
+                let label = Self::status_label(*status);
+                write!(f, "{label}{} ", " ".repeat(width - label.len()))?;
+            }
+            writeln!(f, "{name}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+//!
+//! The compiler tester summary report.
+//!
+
+pub mod comparison;
+pub mod compilation;
+pub mod cross_check;
+pub mod interface_check;
+pub mod matrix;
+pub mod output;
+pub mod parity;
+pub mod pass_rates;
+pub mod slow_tests;
+pub mod status;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::summary::element::outcome::error_code::ErrorCode;
+
+use self::compilation::Compilation;
+use self::output::Output;
+use self::pass_rates::feature_pass_rates::FeaturePassRates;
+use self::pass_rates::PassRates;
+use self::slow_tests::SlowTests;
+use self::status::Status;
+
+///
+/// The compiler tester summary report.
+///
+/// Contains the status of each test, and is used to detect functional drift between runs.
+///
+/// The report is read and written through its versioned JSON layout `Output`.
+///
+#[derive(Debug, Default)]
+pub struct Report {
+    /// Whether the test run has been interrupted before completion.
+    pub incomplete: bool,
+    /// The test statuses, keyed by the test mode and selector.
+    pub tests: BTreeMap<String, Status>,
+    /// The error codes of the failed tests, keyed by the test mode and selector.
+    pub error_codes: BTreeMap<String, ErrorCode>,
+    /// The reasons of the skipped tests, keyed by the test name.
+    pub skip_reasons: BTreeMap<String, String>,
+    /// The compiler subprocess statistics, keyed by the compiler and mode.
+    pub compilations: BTreeMap<String, BTreeMap<String, Compilation>>,
+    /// The slowest tests by compilation and execution time, if their tracking is enabled.
+    pub slow_tests: Option<SlowTests>,
+    /// The pass rates by the target, compiler version, and codegen.
+    pub pass_rates: PassRates,
+    /// The pass rates by the compiler features declared by the tests.
+    pub feature_pass_rates: FeaturePassRates,
+    /// The test wall times in milliseconds, keyed by the test target, mode, and path.
+    pub durations: BTreeMap<String, u64>,
+    /// The tests with failed or invalid outcomes, identified by the test target, mode, and path.
+    pub failing: BTreeSet<String>,
+    /// The SHA-256 hashes of the test files, keyed by their paths, used to find the tests
+    /// changed since the run.
+    pub file_hashes: BTreeMap<String, String>,
+}
+
+impl Report {
+    ///
+    /// Parses the report from the JSON `text` of its output layout.
+    ///
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        Output::from_json(text).map(Self::from)
+    }
+
+    ///
+    /// Writes the report to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(&Output::from(self)).expect("Always valid");
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Summary file {path:?} writing: {error}"))
+    }
+}
+
+impl TryFrom<PathBuf> for Report {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Summary file {:?} reading: {}", path, error))?;
+        let json = Self::from_json(text.as_str())
+            .map_err(|error| anyhow::anyhow!("Summary file {:?} parsing: {}", path, error))?;
+        Ok(json)
+    }
+}
@@ -0,0 +1,294 @@
+//!
+//! The compiler tester summary report output.
+//!
+
+pub mod status;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::report::compilation::Compilation;
+use crate::summary::report::pass_rates::feature_pass_rates::FeaturePassRates;
+use crate::summary::report::pass_rates::PassRates;
+use crate::summary::report::slow_tests::SlowTests;
+use crate::summary::report::Report;
+
+use self::status::Status;
+
+///
+/// The compiler tester summary report output.
+///
+/// The JSON layout of the summary report read by external consumers, kept apart from the
+/// internal `Report`, so the latter may be changed freely. A change renaming or removing a field
+/// or a status, or changing the meaning of a value, must increment `SCHEMA_VERSION`, whereas
+/// new optional fields do not.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Output {
+    /// The version of the report JSON layout, missing in the reports written before versioning.
+    #[serde(default)]
+    pub schema_version: u32,
+    /// Whether the test run has been interrupted before completion.
+    #[serde(default)]
+    pub incomplete: bool,
+    /// The test statuses, keyed by the test mode and selector.
+    pub tests: BTreeMap<String, Status>,
+    /// The error codes of the failed tests, keyed by the test mode and selector.
+    #[serde(default)]
+    pub error_codes: BTreeMap<String, ErrorCode>,
+    /// The reasons of the skipped tests, keyed by the test name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub skip_reasons: BTreeMap<String, String>,
+    /// The compiler subprocess statistics, keyed by the compiler and mode.
+    #[serde(default)]
+    pub compilations: BTreeMap<String, BTreeMap<String, Compilation>>,
+    /// The slowest tests by compilation and execution time, if their tracking is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slow_tests: Option<SlowTests>,
+    /// The pass rates by the target, compiler version, and codegen.
+    #[serde(default, skip_serializing_if = "PassRates::is_empty")]
+    pub pass_rates: PassRates,
+    /// The pass rates by the compiler features declared by the tests.
+    #[serde(default, skip_serializing_if = "FeaturePassRates::is_empty")]
+    pub feature_pass_rates: FeaturePassRates,
+    /// The test wall times in milliseconds, keyed by the test target, mode, and path.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub durations: BTreeMap<String, u64>,
+    /// The tests with failed or invalid outcomes, identified by the test target, mode, and path.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub failing: BTreeSet<String>,
+    /// The SHA-256 hashes of the test files, keyed by their paths.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub file_hashes: BTreeMap<String, String>,
+}
+
+impl Output {
+    /// The current version of the report JSON layout.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    ///
+    /// Parses the output from the JSON `text`.
+    ///
+    /// The reports without a version have the layout of the first version. The reports of newer
+    /// versions are rejected, as their fields may have changed their meaning.
+    ///
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        let output: Self = serde_json::from_str(text)?;
+        if output.schema_version > Self::SCHEMA_VERSION {
+            anyhow::bail!(
+                "Schema version {} is newer than the supported version {}",
+                output.schema_version,
+                Self::SCHEMA_VERSION
+            );
+        }
+        Ok(output)
+    }
+}
+
+impl From<&Report> for Output {
+    fn from(report: &Report) -> Self {
+        Self {
+            schema_version: Self::SCHEMA_VERSION,
+            incomplete: report.incomplete,
+            tests: report
+                .tests
+                .iter()
+                .map(|(name, status)| (name.to_owned(), Status::from(*status)))
+                .collect(),
+            error_codes: report.error_codes.clone(),
+            skip_reasons: report.skip_reasons.clone(),
+            compilations: report.compilations.clone(),
+            slow_tests: report.slow_tests.clone(),
+            pass_rates: report.pass_rates.clone(),
+            feature_pass_rates: report.feature_pass_rates.clone(),
+            durations: report.durations.clone(),
+            failing: report.failing.clone(),
+            file_hashes: report.file_hashes.clone(),
+        }
+    }
+}
+
+impl From<Output> for Report {
+    fn from(output: Output) -> Self {
+        Self {
+            incomplete: output.incomplete,
+            tests: output
+                .tests
+                .into_iter()
+                .map(|(name, status)| (name, status.into()))
+                .collect(),
+            error_codes: output.error_codes,
+            skip_reasons: output.skip_reasons,
+            compilations: output.compilations,
+            slow_tests: output.slow_tests,
+            pass_rates: output.pass_rates,
+            feature_pass_rates: output.feature_pass_rates,
+            durations: output.durations,
+            failing: output.failing,
+            file_hashes: output.file_hashes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::summary::element::outcome::error_code::ErrorCode;
+    use crate::summary::report::compilation::Compilation;
+    use crate::summary::report::pass_rates::feature_pass_rates::FeaturePassRates;
+    use crate::summary::report::pass_rates::pass_rate::PassRate;
+    use crate::summary::report::pass_rates::PassRates;
+    use crate::summary::report::slow_tests::slow_test::SlowTest;
+    use crate::summary::report::slow_tests::SlowTests;
+
+    use super::status::Status;
+    use super::Output;
+
+    /// The golden output of the current schema version.
+    const GOLDEN: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/fixtures/summary_report_v1.json"
+    ));
+
+    fn golden() -> Output {
+        let pass_rate = PassRate {
+            passed: 3,
+            failed: 2,
+            expected_failures: 1,
+        };
+        Output {
+            schema_version: Output::SCHEMA_VERSION,
+            incomplete: true,
+            tests: [
+                ("M3B3 tests/passed.sol", Status::Passed),
+                ("M3B3 tests/failed.sol", Status::Failed),
+                ("M3B3 tests/invalid.sol", Status::Invalid),
+                ("M3B3 tests/expected_failure.sol", Status::ExpectedFailure),
+                ("M3B3 tests/unexpected_pass.sol", Status::UnexpectedPass),
+                ("M3B3 tests/ignored.sol", Status::Ignored),
+                ("M3B3 tests/skipped.sol", Status::Skipped),
+                ("M3B3 tests/cached_pass.sol", Status::CachedPass),
+            ]
+            .into_iter()
+            .map(|(name, status)| (name.to_owned(), status))
+            .collect(),
+            error_codes: [
+                ("M3B3 tests/compilation.sol", ErrorCode::CompilationFailed),
+                ("M3B3 tests/output.sol", ErrorCode::OutputMismatch),
+                ("M3B3 tests/metadata.sol", ErrorCode::InvalidMetadata),
+                ("M3B3 tests/execution.sol", ErrorCode::ExecutionFailed),
+                ("M3B3 tests/unexpected_pass.sol", ErrorCode::UnexpectedPass),
+                ("M3B3 tests/internal.sol", ErrorCode::InternalError),
+            ]
+            .into_iter()
+            .map(|(name, code)| (name.to_owned(), code))
+            .collect(),
+            skip_reasons: BTreeMap::from([(
+                "M3B3 tests/skipped.sol".to_owned(),
+                "EVM only".to_owned(),
+            )]),
+            compilations: BTreeMap::from([(
+                "solc".to_owned(),
+                BTreeMap::from([(
+                    "Y+M3B3".to_owned(),
+                    Compilation {
+                        subprocesses: 4,
+                        failures: 1,
+                        wall_time_ms: 1000,
+                        max_wall_time_ms: 400,
+                        peak_rss_kib: Some(65536),
+                    },
+                )]),
+            )]),
+            slow_tests: Some(SlowTests {
+                threshold_ms: Some(500),
+                compilation: vec![SlowTest {
+                    name: "M3B3 tests/slow.sol".to_owned(),
+                    wall_time_ms: 700,
+                    is_over_threshold: true,
+                }],
+                execution: vec![],
+                compilation_over_threshold: 1,
+                execution_over_threshold: 0,
+            }),
+            pass_rates: PassRates {
+                groups: BTreeMap::from([(
+                    "Solidity upstream v0.8.30 EVMLA".to_owned(),
+                    pass_rate.clone(),
+                )]),
+            },
+            feature_pass_rates: FeaturePassRates {
+                features: BTreeMap::from([("mcopy".to_owned(), pass_rate)]),
+            },
+            durations: BTreeMap::from([("EVM M3B3 tests/passed.sol".to_owned(), 42)]),
+            failing: ["EVM M3B3 tests/failed.sol".to_owned()]
+                .into_iter()
+                .collect(),
+            file_hashes: BTreeMap::from([(
+                "tests/passed.sol".to_owned(),
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_owned(),
+            )]),
+        }
+    }
+
+    #[test]
+    fn golden_serialization() {
+        let expected: serde_json::Value = serde_json::from_str(GOLDEN).expect("Always valid");
+
+        let actual = serde_json::to_value(golden()).expect("Always valid");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn golden_deserialization() {
+        let output = Output::from_json(GOLDEN).expect("Always valid");
+
+        let actual = serde_json::to_value(output).expect("Always valid");
+
+        assert_eq!(
+            actual,
+            serde_json::to_value(golden()).expect("Always valid")
+        );
+    }
+
+    #[test]
+    fn unversioned() {
+        let input = r#"{"tests":{"M3B3 tests/solidity/simple/default.sol":"passed"}}"#;
+
+        let output = Output::from_json(input).expect("Always valid");
+
+        assert_eq!(output.schema_version, 0);
+        assert_eq!(
+            output.tests.get("M3B3 tests/solidity/simple/default.sol"),
+            Some(&Status::Passed)
+        );
+    }
+
+    #[test]
+    fn current() {
+        let output = Output {
+            schema_version: Output::SCHEMA_VERSION,
+            ..Default::default()
+        };
+        let input = serde_json::to_string(&output).expect("Always valid");
+
+        let output = Output::from_json(input.as_str()).expect("Always valid");
+
+        assert_eq!(output.schema_version, Output::SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn error_newer() {
+        let input = format!(
+            r#"{{"schema_version":{},"tests":{{}}}}"#,
+            Output::SCHEMA_VERSION + 1
+        );
+
+        let result = Output::from_json(input.as_str());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,68 @@
+//!
+//! The compiler tester summary report output test status.
+//!
+
+use crate::summary::report::status::Status as ReportStatus;
+
+///
+/// The compiler tester summary report output test status.
+///
+/// The names are spelled out explicitly, so renaming a variant does not change the layout.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Status {
+    /// The test has passed.
+    #[serde(rename = "passed")]
+    Passed,
+    /// The test has failed.
+    #[serde(rename = "failed")]
+    Failed,
+    /// The test is invalid.
+    #[serde(rename = "invalid")]
+    Invalid,
+    /// The test has failed due to a known compiler limitation.
+    #[serde(rename = "expected_failure")]
+    ExpectedFailure,
+    /// The test has been expected to fail, but has passed.
+    #[serde(rename = "unexpected_pass")]
+    UnexpectedPass,
+    /// The test has been ignored.
+    #[serde(rename = "ignored")]
+    Ignored,
+    /// The test has been skipped, as it is not applicable to the run configuration.
+    #[serde(rename = "skipped")]
+    Skipped,
+    /// The test has been skipped, as it has passed with the same builds in a previous run.
+    #[serde(rename = "cached_pass")]
+    CachedPass,
+}
+
+impl From<ReportStatus> for Status {
+    fn from(status: ReportStatus) -> Self {
+        match status {
+            ReportStatus::Passed => Self::Passed,
+            ReportStatus::Failed => Self::Failed,
+            ReportStatus::Invalid => Self::Invalid,
+            ReportStatus::ExpectedFailure => Self::ExpectedFailure,
+            ReportStatus::UnexpectedPass => Self::UnexpectedPass,
+            ReportStatus::Ignored => Self::Ignored,
+            ReportStatus::Skipped => Self::Skipped,
+            ReportStatus::CachedPass => Self::CachedPass,
+        }
+    }
+}
+
+impl From<Status> for ReportStatus {
+    fn from(status: Status) -> Self {
+        match status {
+            Status::Passed => Self::Passed,
+            Status::Failed => Self::Failed,
+            Status::Invalid => Self::Invalid,
+            Status::ExpectedFailure => Self::ExpectedFailure,
+            Status::UnexpectedPass => Self::UnexpectedPass,
+            Status::Ignored => Self::Ignored,
+            Status::Skipped => Self::Skipped,
+            Status::CachedPass => Self::CachedPass,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//!
+//! The test parity category between `solc` and `solx`.
+//!
+
+///
+/// The test parity category between `solc` and `solx`.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    /// The test passes with both toolchains.
+    BothPass,
+    /// The test passes with `solc` only.
+    OnlySolc,
+    /// The test passes with `solx` only.
+    OnlySolx,
+    /// The test fails with both toolchains.
+    BothFail,
+}
+
+impl Category {
+    /// All categories, in the order of the matrix columns.
+    pub const ALL: [Self; 4] = [
+        Self::BothPass,
+        Self::OnlySolc,
+        Self::OnlySolx,
+        Self::BothFail,
+    ];
+
+    ///
+    /// Returns the category of a test which passes with `solc` if `is_solc_passed`,
+    /// and with `solx` if `is_solx_passed`.
+    ///
+    pub fn new(is_solc_passed: bool, is_solx_passed: bool) -> Self {
+        match (is_solc_passed, is_solx_passed) {
+            (true, true) => Self::BothPass,
+            (true, false) => Self::OnlySolc,
+            (false, true) => Self::OnlySolx,
+            (false, false) => Self::BothFail,
+        }
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BothPass => write!(f, "both-pass"),
+            Self::OnlySolc => write!(f, "only-solc"),
+            Self::OnlySolx => write!(f, "only-solx"),
+            Self::BothFail => write!(f, "both-fail"),
+        }
+    }
+}
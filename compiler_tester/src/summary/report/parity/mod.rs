@@ -0,0 +1,238 @@
+//!
+//! The parity matrix of the `solc` and `solx` compiler tester summary reports.
+//!
+
+pub mod category;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::summary::report::cross_check::CrossCheck;
+use crate::summary::report::status::Status;
+use crate::summary::report::Report;
+
+use self::category::Category;
+
+///
+/// The parity matrix of the `solc` and `solx` compiler tester summary reports.
+///
+/// The statuses are joined by test selector as in the cross-check, since the modes of toolchains
+/// are different, and a test passes with a toolchain if it does not fail in any of its modes.
+/// The tests not run with either toolchain, e.g. skipped or ignored in all modes, are left out.
+///
+#[derive(Debug, serde::Serialize)]
+pub struct Parity {
+    /// The `solc` run description.
+    pub solc: String,
+    /// The `solx` run description.
+    pub solx: String,
+    /// The number of tests in each category.
+    pub counts: BTreeMap<Category, usize>,
+    /// The test categories, keyed by the test selector.
+    pub tests: BTreeMap<String, Category>,
+}
+
+impl Parity {
+    ///
+    /// Joins the `solc` and `solx` reports by test selector.
+    ///
+    pub fn new(solc_name: String, solc: &Report, solx_name: String, solx: &Report) -> Self {
+        let solc_statuses = CrossCheck::statuses_by_selector(solc, "");
+        let solx_statuses = CrossCheck::statuses_by_selector(solx, "");
+
+        let mut counts: BTreeMap<Category, usize> = Category::ALL
+            .into_iter()
+            .map(|category| (category, 0))
+            .collect();
+        let mut tests = BTreeMap::new();
+        for (selector, solc_status) in solc_statuses.into_iter() {
+            let solx_status = match solx_statuses.get(selector.as_str()) {
+                Some(solx_status) => *solx_status,
+                None => continue,
+            };
+            let category = match (Self::result(solc_status), Self::result(solx_status)) {
+                (Some(is_solc_passed), Some(is_solx_passed)) => {
+                    Category::new(is_solc_passed, is_solx_passed)
+                }
+                _ => continue,
+            };
+            *counts.entry(category).or_default() += 1;
+            tests.insert(selector, category);
+        }
+
+        Self {
+            solc: solc_name,
+            solx: solx_name,
+            counts,
+            tests,
+        }
+    }
+
+    ///
+    /// Writes the matrix to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self).expect("Always valid");
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Parity file {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Writes the matrix as a Markdown dashboard to the file at `path`.
+    ///
+    /// The dashboard contains the number of tests in each category, and the lists of tests
+    /// passing with only one of the toolchains.
+    ///
+    pub fn write_markdown_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let mut contents = String::new();
+        writeln!(contents, "# Parity of `{}` and `{}`", self.solc, self.solx)
+            .expect("Always valid");
+        writeln!(contents).expect("Always valid");
+        writeln!(contents, "| Category | Tests | Share |").expect("Always valid");
+        writeln!(contents, "|----------|------:|------:|").expect("Always valid");
+        for (category, count) in self.counts.iter() {
+            writeln!(
+                contents,
+                "| {category} | {count} | {:.2}% |",
+                self.share(*count)
+            )
+            .expect("Always valid");
+        }
+        for category in [Category::OnlySolc, Category::OnlySolx] {
+            writeln!(contents).expect("Always valid");
+            writeln!(contents, "## {category}").expect("Always valid");
+            writeln!(contents).expect("Always valid");
+            for (selector, _) in self
+                .tests
+                .iter()
+                .filter(|(_, test_category)| **test_category == category)
+            {
+                writeln!(contents, "- `{selector}`").expect("Always valid");
+            }
+        }
+
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Parity dashboard file {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Returns the share of `count` among all tests of the matrix, in percent.
+    ///
+    fn share(&self, count: usize) -> f64 {
+        if self.tests.is_empty() {
+            return 0.0;
+        }
+        (count as f64) * 100.0 / (self.tests.len() as f64)
+    }
+
+    ///
+    /// Returns whether the test with the worst `status` among its modes has passed, or `None`
+    /// if it has not been run in any mode.
+    ///
+    fn result(status: Status) -> Option<bool> {
+        if CrossCheck::is_passed(status) {
+            Some(true)
+        } else if CrossCheck::is_failed(status) || status == Status::ExpectedFailure {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::fmt::Display for Parity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} of `{}` and `{}` ({} tests):",
+            "Parity".bright_white().bold(),
+            self.solc,
+            self.solx,
+            self.tests.len(),
+        )?;
+        for (category, count) in self.counts.iter() {
+            let label = match category {
+                Category::BothPass => category.to_string().green(),
+                Category::OnlySolc | Category::OnlySolx => category.to_string().bright_red(),
+                Category::BothFail => category.to_string().bright_black(),
+            };
+            writeln!(
+                f,
+                "    {label}{} {count:>6} ({:.2}%)",
+                " ".repeat(12 - label.len()),
+                self.share(*count)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::summary::report::status::Status;
+    use crate::summary::report::Report;
+
+    use super::category::Category;
+    use super::Parity;
+
+    ///
+    /// Returns a report with the test `statuses`, keyed by the test mode and selector.
+    ///
+    fn report(statuses: &[(&str, Status)]) -> Report {
+        Report {
+            tests: statuses
+                .iter()
+                .map(|(name, status)| (name.to_string(), *status))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn new() {
+        let solc = report(&[
+            ("Y+M3B a.sol", Status::Passed),
+            ("Y-M3B a.sol", Status::Passed),
+            ("Y+M3B b.sol", Status::Passed),
+            ("Y+M3B c.sol", Status::Failed),
+            ("Y+M3B d.sol", Status::ExpectedFailure),
+            ("Y+M3B e.sol", Status::Passed),
+            ("Y+M3B f.sol", Status::Passed),
+        ]);
+        let solx = report(&[
+            ("E+M3B a.sol", Status::CachedPass),
+            ("E+M3B b.sol", Status::Passed),
+            ("E-M3B b.sol", Status::Invalid),
+            ("E+M3B c.sol", Status::Passed),
+            ("E+M3B d.sol", Status::Failed),
+            ("E+M3B e.sol", Status::Skipped),
+        ]);
+
+        let parity = Parity::new("solc".to_owned(), &solc, "solx".to_owned(), &solx);
+
+        assert_eq!(
+            parity.tests,
+            BTreeMap::from([
+                ("a.sol".to_owned(), Category::BothPass),
+                ("b.sol".to_owned(), Category::OnlySolc),
+                ("c.sol".to_owned(), Category::OnlySolx),
+                ("d.sol".to_owned(), Category::BothFail),
+            ])
+        );
+        assert_eq!(
+            parity.counts,
+            BTreeMap::from([
+                (Category::BothPass, 1),
+                (Category::OnlySolc, 1),
+                (Category::OnlySolx, 1),
+                (Category::BothFail, 1),
+            ])
+        );
+    }
+}
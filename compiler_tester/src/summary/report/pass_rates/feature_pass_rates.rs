@@ -0,0 +1,138 @@
+//!
+//! The pass rates of a summary report by compiler feature.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::summary::report::pass_rates::pass_rate::PassRate;
+
+///
+/// The pass rates of a summary report by compiler feature.
+///
+/// Groups the tests by the compiler features they declare, e.g. `mcopy` or `transient-storage`,
+/// giving a feature-centric view of the suite. A test is counted once for each of its features
+/// in each of its modes, and the tests without features are left out.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FeaturePassRates {
+    /// The pass rates, keyed by the feature name.
+    pub features: BTreeMap<String, PassRate>,
+}
+
+impl FeaturePassRates {
+    ///
+    /// Checks that the `features` declared by a test are unique lowercase kebab-case names,
+    /// e.g. `transient-storage`, so that a typo does not create a separate group.
+    ///
+    pub fn validate(features: &[String]) -> anyhow::Result<()> {
+        let mut unique = BTreeSet::new();
+        for feature in features.iter() {
+            if feature.is_empty()
+                || feature.starts_with('-')
+                || feature.ends_with('-')
+                || feature.contains("--")
+                || !feature
+                    .chars()
+                    .all(|character| matches!(character, 'a'..='z' | '0'..='9' | '-'))
+            {
+                anyhow::bail!(
+                    "Invalid compiler feature `{feature}`: expected a lowercase kebab-case name"
+                );
+            }
+            if !unique.insert(feature.as_str()) {
+                anyhow::bail!("Duplicate compiler feature `{feature}`");
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Adds a test to the groups of its `features`.
+    ///
+    pub fn add(&mut self, features: &[String], is_failed: bool, is_expected_failure: bool) {
+        for feature in features.iter() {
+            self.features
+                .entry(feature.to_owned())
+                .or_default()
+                .add(is_failed, is_expected_failure);
+        }
+    }
+
+    ///
+    /// Whether there are no features.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+}
+
+impl std::fmt::Display for FeaturePassRates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.features.is_empty() {
+            return Ok(());
+        }
+
+        PassRate::write_header(f, "Pass rates by compiler feature:")?;
+        for (feature, pass_rate) in self.features.iter() {
+            pass_rate.write_row(f, feature.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FeaturePassRates;
+
+    #[test]
+    fn add() {
+        let mut pass_rates = FeaturePassRates::default();
+        for (features, is_failed, is_expected_failure) in [
+            (vec!["mcopy", "transient-storage"], false, false),
+            (vec!["mcopy"], true, false),
+            (vec!["transient-storage"], false, true),
+            (vec![], true, false),
+        ] {
+            let features: Vec<String> = features.into_iter().map(str::to_owned).collect();
+            pass_rates.add(features.as_slice(), is_failed, is_expected_failure);
+        }
+
+        for (feature, passed, failed, expected_failures) in
+            [("mcopy", 1, 1, 0), ("transient-storage", 1, 0, 1)]
+        {
+            let pass_rate = pass_rates.features.get(feature).expect("Always exists");
+            assert_eq!(
+                (
+                    pass_rate.passed,
+                    pass_rate.failed,
+                    pass_rate.expected_failures
+                ),
+                (passed, failed, expected_failures),
+                "{feature}"
+            );
+        }
+        assert_eq!(pass_rates.features.len(), 2);
+    }
+
+    #[test]
+    fn validate() {
+        for (features, is_valid) in [
+            (vec![], true),
+            (vec!["mcopy", "transient-storage", "eip-7702"], true),
+            (vec![""], false),
+            (vec!["Transient-Storage"], false),
+            (vec!["transient_storage"], false),
+            (vec!["transient--storage"], false),
+            (vec!["-mcopy"], false),
+            (vec!["mcopy", "mcopy"], false),
+        ] {
+            let features: Vec<String> = features.into_iter().map(str::to_owned).collect();
+            assert_eq!(
+                FeaturePassRates::validate(features.as_slice()).is_ok(),
+                is_valid,
+                "{features:?}"
+            );
+        }
+    }
+}
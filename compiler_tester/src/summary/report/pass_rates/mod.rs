@@ -0,0 +1,167 @@
+//!
+//! The pass rates of a summary report.
+//!
+
+pub mod feature_pass_rates;
+pub mod pass_rate;
+
+use std::collections::BTreeMap;
+
+use crate::compilers::mode::Mode;
+
+use self::pass_rate::PassRate;
+
+///
+/// The pass rates of a summary report.
+///
+/// Groups the tests by the target, compiler, compiler version, and codegen, so a regression
+/// confined to a single compiler version or codegen stands out from the ones spread across
+/// the mode matrix.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PassRates {
+    /// The pass rates, keyed by the group name.
+    pub groups: BTreeMap<String, PassRate>,
+}
+
+impl PassRates {
+    ///
+    /// Adds a test to the group of its `target` and `mode`.
+    ///
+    /// A test is failed if any of its outcomes is a failure, and is an expected failure if any
+    /// of its outcomes is an expected failure and none is a failure.
+    ///
+    pub fn add(
+        &mut self,
+        target: Option<era_compiler_common::Target>,
+        mode: &Mode,
+        is_failed: bool,
+        is_expected_failure: bool,
+    ) {
+        self.groups
+            .entry(Self::group(target, mode))
+            .or_default()
+            .add(is_failed, is_expected_failure);
+    }
+
+    ///
+    /// Whether there are no groups.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    ///
+    /// Returns the group name of the `target` and `mode`.
+    ///
+    fn group(target: Option<era_compiler_common::Target>, mode: &Mode) -> String {
+        let mut group = Vec::with_capacity(4);
+        if let Some(target) = target {
+            group.push(target.to_string());
+        }
+        group.push(mode.compiler_name().to_owned());
+        if let Some(version) = mode.version() {
+            group.push(format!("v{version}"));
+        }
+        if let Some(codegen) = mode.codegen() {
+            group.push(codegen.to_owned());
+        }
+        group.join(" ")
+    }
+}
+
+impl std::fmt::Display for PassRates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.groups.is_empty() {
+            return Ok(());
+        }
+
+        PassRate::write_header(f, "Pass rates by compiler version and codegen:")?;
+        for (group, pass_rate) in self.groups.iter() {
+            pass_rate.write_row(f, group.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::compilers::mode::Mode;
+    use crate::compilers::solidity::upstream::mode::Mode as SolidityUpstreamMode;
+    use crate::compilers::yul::mode_upstream::Mode as YulUpstreamMode;
+
+    use super::PassRates;
+
+    fn solidity(version: u64, codegen: era_solc::StandardJsonInputCodegen, via_ir: bool) -> Mode {
+        Mode::SolidityUpstream(SolidityUpstreamMode::new(
+            semver::Version::new(0, 8, version),
+            codegen,
+            via_ir,
+            false,
+            true,
+        ))
+    }
+
+    #[test]
+    fn add() {
+        let legacy = solidity(30, era_solc::StandardJsonInputCodegen::EVMLA, false);
+        let via_ir = solidity(30, era_solc::StandardJsonInputCodegen::Yul, true);
+        let previous = solidity(29, era_solc::StandardJsonInputCodegen::Yul, true);
+        let yul = Mode::YulUpstream(YulUpstreamMode::new(
+            semver::Version::new(0, 8, 30),
+            false,
+            true,
+        ));
+
+        let mut pass_rates = PassRates::default();
+        for (target, mode, is_failed, is_expected_failure) in [
+            (
+                Some(era_compiler_common::Target::EVM),
+                &legacy,
+                false,
+                false,
+            ),
+            (Some(era_compiler_common::Target::EVM), &via_ir, true, false),
+            (
+                Some(era_compiler_common::Target::EVM),
+                &via_ir,
+                false,
+                false,
+            ),
+            (Some(era_compiler_common::Target::EVM), &via_ir, false, true),
+            (
+                Some(era_compiler_common::Target::EVM),
+                &previous,
+                false,
+                false,
+            ),
+            (None, &previous, false, false),
+            (None, &yul, true, false),
+        ] {
+            pass_rates.add(target, mode, is_failed, is_expected_failure);
+        }
+
+        let groups: Vec<(&str, usize, usize, usize)> = pass_rates
+            .groups
+            .iter()
+            .map(|(group, pass_rate)| {
+                (
+                    group.as_str(),
+                    pass_rate.passed,
+                    pass_rate.failed,
+                    pass_rate.expected_failures,
+                )
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("EVM Solidity upstream v0.8.29 Yul", 1, 0, 0),
+                ("EVM Solidity upstream v0.8.30 EVMLA", 1, 0, 0),
+                ("EVM Solidity upstream v0.8.30 Yul", 1, 1, 1),
+                ("Solidity upstream v0.8.29 Yul", 1, 0, 0),
+                ("Yul upstream v0.8.30", 0, 1, 0),
+            ]
+        );
+    }
+}
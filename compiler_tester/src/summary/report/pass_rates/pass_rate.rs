@@ -0,0 +1,107 @@
+//!
+//! The pass rate of a summary report group.
+//!
+
+use colored::Colorize;
+
+///
+/// The pass rate of a summary report group.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PassRate {
+    /// The number of passed tests.
+    pub passed: usize,
+    /// The number of failed or invalid tests.
+    pub failed: usize,
+    /// The number of tests failed due to known compiler limitations.
+    pub expected_failures: usize,
+}
+
+impl PassRate {
+    ///
+    /// Adds a test, which is failed if any of its outcomes is a failure, and is an expected
+    /// failure if any of its outcomes is an expected failure and none is a failure.
+    ///
+    pub fn add(&mut self, is_failed: bool, is_expected_failure: bool) {
+        if is_failed {
+            self.failed += 1;
+        } else if is_expected_failure {
+            self.expected_failures += 1;
+        } else {
+            self.passed += 1;
+        }
+    }
+
+    ///
+    /// Returns the percentage of the passed tests, excluding the expected failures.
+    ///
+    pub fn percentage(&self) -> f64 {
+        let total = self.passed + self.failed;
+        if total == 0 {
+            return 100.0;
+        }
+        (self.passed as f64) * 100.0 / (total as f64)
+    }
+
+    ///
+    /// Writes the header of a pass rates table with the `title`.
+    ///
+    pub fn write_header(f: &mut std::fmt::Formatter<'_>, title: &str) -> std::fmt::Result {
+        writeln!(f, "{}", title.bright_white())?;
+        writeln!(
+            f,
+            "{:>10} {:>10} {:>10} {:>9}  GROUP",
+            "PASSED", "FAILED", "XFAILED", "RATE"
+        )
+    }
+
+    ///
+    /// Writes the pass rate of the `group` as a pass rates table row.
+    ///
+    pub fn write_row(&self, f: &mut std::fmt::Formatter<'_>, group: &str) -> std::fmt::Result {
+        let rate = format!("{:>8.2}%", self.percentage());
+        let failed = format!("{:>10}", self.failed);
+        if self.failed > 0 {
+            writeln!(
+                f,
+                "{:>10} {} {:>10} {}  {}",
+                self.passed,
+                failed.bright_red(),
+                self.expected_failures,
+                rate.bright_red(),
+                group,
+            )
+        } else {
+            writeln!(
+                f,
+                "{:>10} {} {:>10} {}  {}",
+                self.passed, failed, self.expected_failures, rate, group,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PassRate;
+
+    #[test]
+    fn percentage() {
+        for (outcomes, expected) in [
+            (vec![], 100.0),
+            (vec![(false, false), (false, true)], 100.0),
+            (vec![(false, false), (true, false)], 50.0),
+            (
+                vec![(true, true), (false, false), (false, false)],
+                200.0 / 3.0,
+            ),
+            (vec![(true, false), (false, true)], 0.0),
+        ] {
+            let mut pass_rate = PassRate::default();
+            for (is_failed, is_expected_failure) in outcomes.iter().copied() {
+                pass_rate.add(is_failed, is_expected_failure);
+            }
+            assert_eq!(pass_rate.percentage(), expected, "{outcomes:?}");
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//!
+//! The slowest tests of a summary report.
+//!
+
+pub mod slow_test;
+
+use colored::Colorize;
+
+use self::slow_test::SlowTest;
+
+///
+/// The slowest tests of a summary report.
+///
+/// Contains the top slowest tests by compilation and execution wall time, so the chronic time hogs
+/// of the suite become visible.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SlowTests {
+    /// The slow test threshold in milliseconds, if specified.
+    pub threshold_ms: Option<u64>,
+    /// The slowest tests by compilation time.
+    pub compilation: Vec<SlowTest>,
+    /// The slowest tests by execution time.
+    pub execution: Vec<SlowTest>,
+    /// The number of tests whose compilation time exceeds the threshold.
+    pub compilation_over_threshold: usize,
+    /// The number of tests whose execution time exceeds the threshold.
+    pub execution_over_threshold: usize,
+}
+
+impl SlowTests {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Takes the top `count` slowest tests of the recorded wall times in milliseconds.
+    ///
+    pub fn new(
+        count: usize,
+        threshold_ms: Option<u64>,
+        compilation_times: &[(String, u64)],
+        execution_times: &[(String, u64)],
+    ) -> Self {
+        let (compilation, compilation_over_threshold) =
+            Self::slowest(count, threshold_ms, compilation_times);
+        let (execution, execution_over_threshold) =
+            Self::slowest(count, threshold_ms, execution_times);
+        Self {
+            threshold_ms,
+            compilation,
+            execution,
+            compilation_over_threshold,
+            execution_over_threshold,
+        }
+    }
+
+    ///
+    /// Returns the top `count` slowest tests and the number of tests exceeding the threshold.
+    ///
+    fn slowest(
+        count: usize,
+        threshold_ms: Option<u64>,
+        times: &[(String, u64)],
+    ) -> (Vec<SlowTest>, usize) {
+        let is_over_threshold = |wall_time_ms: u64| match threshold_ms {
+            Some(threshold_ms) => wall_time_ms > threshold_ms,
+            None => false,
+        };
+
+        let mut times = times.iter().collect::<Vec<&(String, u64)>>();
+        times.sort_by(|(name_a, time_a), (name_b, time_b)| {
+            time_b.cmp(time_a).then_with(|| name_a.cmp(name_b))
+        });
+        let over_threshold = times
+            .iter()
+            .filter(|(_, wall_time_ms)| is_over_threshold(*wall_time_ms))
+            .count();
+        let slowest = times
+            .into_iter()
+            .take(count)
+            .map(|(name, wall_time_ms)| SlowTest {
+                name: name.to_owned(),
+                wall_time_ms: *wall_time_ms,
+                is_over_threshold: is_over_threshold(*wall_time_ms),
+            })
+            .collect();
+        (slowest, over_threshold)
+    }
+
+    ///
+    /// Writes a titled list of the slowest tests.
+    ///
+    fn fmt_list(
+        f: &mut std::fmt::Formatter<'_>,
+        title: &str,
+        tests: &[SlowTest],
+        over_threshold: usize,
+        threshold_ms: Option<u64>,
+    ) -> std::fmt::Result {
+        if tests.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "{}", format!("Slowest tests by {title}:").bright_white())?;
+        for test in tests.iter() {
+            let wall_time = format!(
+                "{:>5}.{:03}s",
+                test.wall_time_ms / 1000,
+                test.wall_time_ms % 1000
+            );
+            if test.is_over_threshold {
+                writeln!(
+                    f,
+                    "{} {} {}",
+                    wall_time.bright_red(),
+                    "SLOW".bright_red(),
+                    test.name
+                )?;
+            } else {
+                writeln!(f, "{} {:4} {}", wall_time, "", test.name)?;
+            }
+        }
+        if let Some(threshold_ms) = threshold_ms {
+            writeln!(
+                f,
+                "{over_threshold} tests exceed the {}.{:03}s {title} threshold",
+                threshold_ms / 1000,
+                threshold_ms % 1000,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for SlowTests {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Self::fmt_list(
+            f,
+            "compilation",
+            self.compilation.as_slice(),
+            self.compilation_over_threshold,
+            self.threshold_ms,
+        )?;
+        Self::fmt_list(
+            f,
+            "execution",
+            self.execution.as_slice(),
+            self.execution_over_threshold,
+            self.threshold_ms,
+        )
+    }
+}
@@ -0,0 +1,16 @@
+//!
+//! The slow test of a summary report.
+//!
+
+///
+/// The slow test of a summary report.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SlowTest {
+    /// The test name, consisting of the target, mode, and path.
+    pub name: String,
+    /// The wall time in milliseconds.
+    pub wall_time_ms: u64,
+    /// Whether the wall time exceeds the slow test threshold.
+    pub is_over_threshold: bool,
+}
@@ -0,0 +1,44 @@
+//!
+//! The compiler tester summary report test status.
+//!
+
+use crate::summary::element::outcome::Outcome;
+
+///
+/// The compiler tester summary report test status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The test has passed.
+    Passed,
+    /// The test has failed.
+    Failed,
+    /// The test is invalid.
+    Invalid,
+    /// The test has failed due to a known compiler limitation.
+    ExpectedFailure,
+    /// The test has been expected to fail, but has passed.
+    UnexpectedPass,
+    /// The test has been ignored.
+    Ignored,
+    /// The test has been skipped, as it is not applicable to the run configuration.
+    Skipped,
+    /// The test has been skipped, as it has passed with the same builds in a previous run.
+    CachedPass,
+}
+
+impl From<&Outcome> for Status {
+    fn from(outcome: &Outcome) -> Self {
+        match outcome {
+            Outcome::Passed { .. } => Self::Passed,
+            Outcome::Failed { .. } => Self::Failed,
+            Outcome::Invalid { .. } => Self::Invalid,
+            Outcome::ExpectedFailure { .. } => Self::ExpectedFailure,
+            Outcome::UnexpectedPass => Self::UnexpectedPass,
+            Outcome::Ignored => Self::Ignored,
+            Outcome::Skipped { .. } => Self::Skipped,
+            Outcome::CachedPass => Self::CachedPass,
+        }
+    }
+}
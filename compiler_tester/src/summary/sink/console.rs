@@ -0,0 +1,80 @@
+//!
+//! The console summary sink.
+//!
+
+use crate::summary::element::outcome::Outcome;
+use crate::summary::element::Element;
+use crate::summary::report::Report;
+use crate::summary::sink::SummarySink;
+use crate::summary::Summary;
+
+///
+/// The console summary sink.
+///
+/// Prints the elements as they are added, the summary table at the execution milestones,
+/// and the final summary table with the pass rates and slowest tests.
+///
+#[derive(Debug)]
+pub struct ConsoleSink {
+    /// The output verbosity.
+    verbosity: bool,
+    /// Whether the output is suppressed.
+    quiet: bool,
+    /// The number of executed tests.
+    executed: usize,
+}
+
+impl ConsoleSink {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verbosity: bool, quiet: bool) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            executed: 0,
+        }
+    }
+}
+
+impl SummarySink for ConsoleSink {
+    fn element(&mut self, summary: &Summary, element: &Element) {
+        if let Some(string) = element.print(self.verbosity) {
+            println!("{string}");
+        }
+
+        let is_executed = matches!(
+            element.outcome,
+            Outcome::Passed { .. }
+                | Outcome::Failed { .. }
+                | Outcome::Invalid { .. }
+                | Outcome::ExpectedFailure { .. }
+        );
+        if is_executed {
+            self.executed += 1;
+
+            let milestone = if self.verbosity {
+                usize::pow(10, 3)
+            } else {
+                usize::pow(10, 5)
+            };
+
+            if self.executed % milestone == 0 {
+                println!("{summary}");
+            }
+        }
+    }
+
+    fn finish(&mut self, summary: &Summary, _report: &Report) -> anyhow::Result<()> {
+        print!("{summary}");
+        if self.quiet {
+            return Ok(());
+        }
+        print!("{}", summary.pass_rates());
+        print!("{}", summary.feature_pass_rates());
+        if let Some(slow_tests) = summary.slow_tests() {
+            print!("{slow_tests}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+//!
+//! The streaming events summary sink.
+//!
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::summary::element::Element;
+use crate::summary::report::status::Status;
+use crate::summary::report::Report;
+use crate::summary::sink::SummarySink;
+use crate::summary::Summary;
+
+///
+/// The streaming events summary sink.
+///
+/// Writes a JSON line with the name, status, and error code of every element as soon as it is
+/// added, so the progress of a long run can be followed, e.g. with `tail -f`.
+///
+#[derive(Debug)]
+pub struct EventsSink {
+    /// The events file path.
+    path: PathBuf,
+    /// The events file writer.
+    writer: BufWriter<File>,
+    /// The first writing error, after which the events are not written anymore.
+    error: Option<String>,
+}
+
+impl EventsSink {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: &Path) -> anyhow::Result<Self> {
+        let file = File::create(path)
+            .map_err(|error| anyhow::anyhow!("Events file {path:?} creating: {error}"))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+            error: None,
+        })
+    }
+
+    ///
+    /// Writes the event of an `element`.
+    ///
+    fn write(&mut self, element: &Element) -> anyhow::Result<()> {
+        let event = serde_json::json!({
+            "name": element.name(),
+            "status": Status::from(&element.outcome),
+            "error_code": element.outcome.error_code(),
+        });
+        serde_json::to_writer(&mut self.writer, &event)?;
+        writeln!(self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+impl SummarySink for EventsSink {
+    fn element(&mut self, _summary: &Summary, element: &Element) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(error) = self.write(element) {
+            self.error = Some(error.to_string());
+        }
+    }
+
+    fn finish(&mut self, _summary: &Summary, _report: &Report) -> anyhow::Result<()> {
+        if let Some(error) = self.error.take() {
+            anyhow::bail!("Events file {:?} writing: {error}", self.path);
+        }
+        self.writer
+            .flush()
+            .map_err(|error| anyhow::anyhow!("Events file {:?} writing: {error}", self.path))
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The JSON summary sink.
+//!
+
+use std::path::PathBuf;
+
+use crate::summary::element::Element;
+use crate::summary::report::Report;
+use crate::summary::sink::SummarySink;
+use crate::summary::Summary;
+
+///
+/// The JSON summary sink.
+///
+/// Writes the summary report, which can be compared with the report of another run.
+///
+#[derive(Debug)]
+pub struct JsonSink {
+    /// The report file path.
+    path: PathBuf,
+}
+
+impl JsonSink {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl SummarySink for JsonSink {
+    fn element(&mut self, _summary: &Summary, _element: &Element) {}
+
+    fn finish(&mut self, _summary: &Summary, report: &Report) -> anyhow::Result<()> {
+        report.write_to_file(self.path.as_path())
+    }
+}
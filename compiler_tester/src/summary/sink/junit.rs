@@ -0,0 +1,305 @@
+//!
+//! The JUnit summary sink.
+//!
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::summary::element::outcome::Outcome;
+use crate::summary::element::Element;
+use crate::summary::report::Report;
+use crate::summary::sink::SummarySink;
+use crate::summary::Summary;
+use crate::vm::storage_access::StorageAccess;
+
+///
+/// The JUnit summary sink.
+///
+/// Writes the JUnit XML report understood by the CI test report viewers. The test suites are
+/// the targets and modes, and the test cases are the test selectors. The failed outcomes are
+/// reported as failures, the invalid ones as errors, and the expected failures as skipped.
+///
+#[derive(Debug)]
+pub struct JUnitSink {
+    /// The report file path.
+    path: PathBuf,
+}
+
+impl JUnitSink {
+    /// The name of the test suite of the elements without a mode.
+    pub const DEFAULT_SUITE_NAME: &'static str = "default";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    ///
+    /// Writes the test suite named `name` with its `elements`.
+    ///
+    fn write_suite(
+        xml: &mut String,
+        name: &str,
+        elements: &[&Element],
+    ) -> Result<(), std::fmt::Error> {
+        let failures = elements
+            .iter()
+            .filter(|element| {
+                matches!(
+                    element.outcome,
+                    Outcome::Failed { .. } | Outcome::UnexpectedPass
+                )
+            })
+            .count();
+        let errors = elements
+            .iter()
+            .filter(|element| matches!(element.outcome, Outcome::Invalid { .. }))
+            .count();
+        let skipped = elements
+            .iter()
+            .filter(|element| {
+                matches!(
+                    element.outcome,
+                    Outcome::ExpectedFailure { .. } | Outcome::Ignored | Outcome::Skipped { .. }
+                )
+            })
+            .count();
+        writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{failures}" errors="{errors}" skipped="{skipped}">"#,
+            Self::escape(name),
+            elements.len(),
+        )?;
+        for element in elements.iter() {
+            write!(
+                xml,
+                r#"    <testcase classname="{}" name="{}""#,
+                Self::escape(name),
+                Self::escape(element.test_description.selector.to_string().as_str()),
+            )?;
+            match element.outcome {
+                Outcome::Passed { .. } | Outcome::CachedPass => {
+                    writeln!(xml, "/>")?;
+                    continue;
+                }
+                _ => writeln!(xml, ">")?,
+            }
+            match element.outcome {
+                Outcome::Failed {
+                    ref expected,
+                    ref found,
+                    ref calldata,
+                    ref storage,
+                } => {
+                    let mut details =
+                        format!("calldata: {calldata}\nexpected: {expected:?}\nfound: {found:?}");
+                    if !storage.is_empty() {
+                        details.push('\n');
+                        details
+                            .push_str(StorageAccess::format_excerpt(storage.as_slice()).as_str());
+                    }
+                    writeln!(
+                        xml,
+                        r#"      <failure message="Return data mismatch" type="{}">{}</failure>"#,
+                        element.outcome.error_code().expect("Always exists"),
+                        Self::escape(details.as_str()),
+                    )?
+                }
+                Outcome::UnexpectedPass => writeln!(
+                    xml,
+                    r#"      <failure message="Expected to fail, but passed" type="{}"/>"#,
+                    element.outcome.error_code().expect("Always exists"),
+                )?,
+                Outcome::Invalid { code, ref error } => writeln!(
+                    xml,
+                    r#"      <error message="{}" type="{code}"/>"#,
+                    Self::escape(error.as_str()),
+                )?,
+                Outcome::ExpectedFailure { code, ref error } => writeln!(
+                    xml,
+                    r#"      <skipped message="{}"/>"#,
+                    Self::escape(format!("Expected failure [{code}] {error}").as_str()),
+                )?,
+                Outcome::Skipped { ref reason } => writeln!(
+                    xml,
+                    r#"      <skipped message="{}"/>"#,
+                    Self::escape(reason.as_str()),
+                )?,
+                Outcome::Ignored => writeln!(xml, r#"      <skipped message="Ignored"/>"#)?,
+                Outcome::Passed { .. } | Outcome::CachedPass => {}
+            }
+            writeln!(xml, "    </testcase>")?;
+        }
+        writeln!(xml, "  </testsuite>")
+    }
+
+    ///
+    /// Escapes the XML special characters in `text`.
+    ///
+    fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len());
+        for character in text.chars() {
+            match character {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                character if character.is_control() && !character.is_whitespace() => {}
+                character => escaped.push(character),
+            }
+        }
+        escaped
+    }
+}
+
+impl SummarySink for JUnitSink {
+    fn element(&mut self, _summary: &Summary, _element: &Element) {}
+
+    fn finish(&mut self, summary: &Summary, _report: &Report) -> anyhow::Result<()> {
+        let mut suites: BTreeMap<String, Vec<&Element>> = BTreeMap::new();
+        for element in summary.elements().iter() {
+            let mut name = Vec::with_capacity(2);
+            if let Some(target) = element.target {
+                name.push(target.to_string());
+            }
+            if let Some(mode) = element.test_description.mode.as_ref() {
+                name.push(mode.to_string());
+            }
+            let name = if name.is_empty() {
+                Self::DEFAULT_SUITE_NAME.to_owned()
+            } else {
+                name.join(" ")
+            };
+            suites.entry(name).or_default().push(element);
+        }
+
+        let mut xml = String::with_capacity(summary.elements().len() * 128);
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<testsuites name=\"compiler-tester\">\n");
+        for (name, elements) in suites.iter() {
+            Self::write_suite(&mut xml, name.as_str(), elements.as_slice()).expect("Always valid");
+        }
+        xml.push_str("</testsuites>\n");
+
+        std::fs::write(self.path.as_path(), xml)
+            .map_err(|error| anyhow::anyhow!("JUnit report file {:?} writing: {error}", self.path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::summary::element::outcome::error_code::ErrorCode;
+    use crate::summary::element::outcome::passed_variant::PassedVariant;
+    use crate::summary::element::outcome::Outcome;
+    use crate::summary::element::Element;
+    use crate::test::description::TestDescription;
+    use crate::test::selector::TestSelector;
+
+    use super::JUnitSink;
+
+    fn element(path: &str, outcome: Outcome) -> Element {
+        Element::new(
+            TestDescription::default_for(TestSelector {
+                path: path.to_owned(),
+                case: None,
+                input: None,
+            }),
+            outcome,
+        )
+    }
+
+    #[test]
+    fn escape() {
+        for (text, expected) in [
+            ("plain text", "plain text"),
+            ("a & b", "a &amp; b"),
+            ("<tag>", "&lt;tag&gt;"),
+            (
+                r#""double" 'single'"#,
+                "&quot;double&quot; &apos;single&apos;",
+            ),
+            ("line\nbreak\ttab", "line\nbreak\ttab"),
+            ("null\u{0}bell\u{7}", "nullbell"),
+        ] {
+            assert_eq!(
+                JUnitSink::escape(text),
+                expected,
+                "Invalid escaping of {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn write_suite() {
+        let elements = [
+            element(
+                "tests/passed.sol",
+                Outcome::passed(
+                    None,
+                    PassedVariant::Runtime {
+                        cycles: 1,
+                        ergs: 2,
+                        gas: 3,
+                        stack_depth: None,
+                        pubdata: None,
+                        cold_accesses: None,
+                        warm_accesses: None,
+                    },
+                ),
+            ),
+            element(
+                "tests/invalid.sol",
+                Outcome::Invalid {
+                    code: ErrorCode::InternalError,
+                    error: "a < b".to_owned(),
+                },
+            ),
+            element(
+                "tests/expected_failure.sol",
+                Outcome::ExpectedFailure {
+                    code: ErrorCode::CompilationFailed,
+                    error: r#""unsupported""#.to_owned(),
+                },
+            ),
+            element("tests/unexpected_pass.sol", Outcome::UnexpectedPass),
+            element(
+                "tests/skipped.sol",
+                Outcome::Skipped {
+                    reason: "EVM only".to_owned(),
+                },
+            ),
+            element("tests/ignored.sol", Outcome::Ignored),
+        ];
+        let elements: Vec<&Element> = elements.iter().collect();
+
+        let mut xml = String::new();
+        JUnitSink::write_suite(&mut xml, "M3B3 & more", elements.as_slice()).expect("Always valid");
+
+        assert_eq!(
+            xml,
+            r#"  <testsuite name="M3B3 &amp; more" tests="6" failures="1" errors="1" skipped="3">
+    <testcase classname="M3B3 &amp; more" name="tests/passed.sol"/>
+    <testcase classname="M3B3 &amp; more" name="tests/invalid.sol">
+      <error message="a &lt; b" type="CT006"/>
+    </testcase>
+    <testcase classname="M3B3 &amp; more" name="tests/expected_failure.sol">
+      <skipped message="Expected failure [CT001] &quot;unsupported&quot;"/>
+    </testcase>
+    <testcase classname="M3B3 &amp; more" name="tests/unexpected_pass.sol">
+      <failure message="Expected to fail, but passed" type="CT005"/>
+    </testcase>
+    <testcase classname="M3B3 &amp; more" name="tests/skipped.sol">
+      <skipped message="EVM only"/>
+    </testcase>
+    <testcase classname="M3B3 &amp; more" name="tests/ignored.sol">
+      <skipped message="Ignored"/>
+    </testcase>
+  </testsuite>
+"#
+        );
+    }
+}
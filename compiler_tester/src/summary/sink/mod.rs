@@ -0,0 +1,34 @@
+//!
+//! The compiler tester summary sink.
+//!
+
+pub mod console;
+pub mod events;
+pub mod json;
+pub mod junit;
+
+use crate::summary::element::Element;
+use crate::summary::report::Report;
+use crate::summary::Summary;
+
+///
+/// The compiler tester summary sink.
+///
+/// Receives the summary elements as they are added, and the final summary when the run is over.
+/// The sinks are registered in the summary at startup, so several outputs, e.g. the console and
+/// a JUnit report, are produced simultaneously. The library users may register their own sinks,
+/// e.g. to store the results in a database.
+///
+pub trait SummarySink: std::fmt::Debug + Send + 'static {
+    ///
+    /// Handles an `element` added to the `summary`.
+    ///
+    /// The element is not yet in the elements of the summary, but is already counted.
+    ///
+    fn element(&mut self, summary: &Summary, element: &Element);
+
+    ///
+    /// Handles the final `summary` and its `report`, either after the run or after its interruption.
+    ///
+    fn finish(&mut self, summary: &Summary, report: &Report) -> anyhow::Result<()>;
+}
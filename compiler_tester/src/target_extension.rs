@@ -0,0 +1,132 @@
+//!
+//! The tester target extension.
+//!
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::RwLock;
+
+use crate::compilers::Compiler;
+use crate::environment::Environment;
+use crate::summary::Summary;
+use crate::test::Test;
+use crate::toolchain::Toolchain;
+
+///
+/// The registered target extensions, keyed by their names.
+static EXTENSIONS: LazyLock<RwLock<BTreeMap<&'static str, Arc<dyn TargetExtension>>>> =
+    LazyLock::new(|| RwLock::new(BTreeMap::new()));
+
+///
+/// The tester target extension.
+///
+/// Allows an experimental backend, e.g. compiling Yul to WASM and running it in `wasmtime`,
+/// to plug its own compiler modes, test directories, and execution environment into the tester
+/// without changing the built-in targets. The extension tests are read as Matter Labs tests
+/// and built with the pipeline of the base environment, so the extension compiler is expected
+/// to pack its artifacts into the contract builds of that environment.
+///
+pub trait TargetExtension: Send + Sync + 'static {
+    ///
+    /// Returns the target name, e.g. `wasm`, which is also the benchmark target suffix.
+    ///
+    fn name(&self) -> &'static str;
+
+    ///
+    /// Returns the environment whose build pipeline and test metadata targets are reused.
+    ///
+    fn base_environment(&self) -> Environment;
+
+    ///
+    /// Returns the Matter Labs test directories with their file extensions.
+    ///
+    fn directories(&self) -> Vec<(&'static str, &'static str)>;
+
+    ///
+    /// Returns the compiler, whose modes are the compiler modes of the target.
+    ///
+    fn compiler(&self, toolchain: Toolchain) -> Arc<dyn Compiler>;
+
+    ///
+    /// Runs the built test in the target environment, reporting the outcomes to the `summary`.
+    ///
+    fn run(&self, test: Test, summary: Arc<Mutex<Summary>>);
+}
+
+///
+/// Registers the target `extension`, making it selectable with `--target-extension`.
+///
+pub fn register(extension: Arc<dyn TargetExtension>) -> anyhow::Result<()> {
+    let mut extensions = EXTENSIONS.write().expect("Sync");
+    let name = extension.name();
+    if extensions.contains_key(name) {
+        anyhow::bail!("Target extension `{name}` is already registered");
+    }
+    extensions.insert(name, extension);
+    Ok(())
+}
+
+///
+/// Returns the registered target extension `name`.
+///
+pub fn get(name: &str) -> anyhow::Result<Arc<dyn TargetExtension>> {
+    let extensions = EXTENSIONS.read().expect("Sync");
+    extensions.get(name).cloned().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Target extension `{name}` is not registered. Registered extensions: [{}]",
+            extensions.keys().copied().collect::<Vec<&str>>().join(", ")
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use crate::compilers::eravm::EraVMCompiler;
+    use crate::compilers::Compiler;
+    use crate::environment::Environment;
+    use crate::summary::Summary;
+    use crate::test::Test;
+    use crate::toolchain::Toolchain;
+
+    use super::TargetExtension;
+
+    ///
+    /// The target extension without tests.
+    ///
+    struct Empty;
+
+    impl TargetExtension for Empty {
+        fn name(&self) -> &'static str {
+            "target-extension-test"
+        }
+
+        fn base_environment(&self) -> Environment {
+            Environment::REVM
+        }
+
+        fn directories(&self) -> Vec<(&'static str, &'static str)> {
+            vec![]
+        }
+
+        fn compiler(&self, _toolchain: Toolchain) -> Arc<dyn Compiler> {
+            Arc::new(EraVMCompiler)
+        }
+
+        fn run(&self, _test: Test, _summary: Arc<Mutex<Summary>>) {}
+    }
+
+    #[test]
+    fn register() {
+        assert!(super::get("target-extension-test").is_err());
+        super::register(Arc::new(Empty)).expect("Always valid");
+        assert!(super::register(Arc::new(Empty)).is_err());
+
+        let extension = super::get("target-extension-test").expect("Always exists");
+        assert_eq!(extension.base_environment(), Environment::REVM);
+    }
+}
@@ -0,0 +1,71 @@
+//!
+//! The test input access list.
+//!
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::directories::matter_labs::test::metadata::case::input::access_list_item::AccessListItem as MatterLabsTestAccessListItem;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The test input access list.
+///
+/// The addresses and storage keys pre-warmed by the EIP-2930 transaction access list.
+///
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct AccessList {
+    /// The pre-warmed addresses with their storage keys.
+    pub inner: Vec<(web3::types::Address, Vec<web3::types::U256>)>,
+}
+
+impl AccessList {
+    ///
+    /// Try convert from Matter Labs compiler test access list items.
+    ///
+    pub fn try_from_matter_labs(
+        items: Vec<MatterLabsTestAccessListItem>,
+        instances: &BTreeMap<String, Instance>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<Self> {
+        let mut result = Vec::with_capacity(items.len());
+
+        for item in items.into_iter() {
+            let address = item.address;
+            let address = if let Some(instance) = Value::instance_name(address.as_str()) {
+                Value::instance_address(instance, instances)
+            } else {
+                let literal = address.strip_prefix("0x").unwrap_or(address.as_str());
+                crate::utils::validate_address_checksum(literal).and_then(|()| {
+                    web3::types::Address::from_str(literal)
+                        .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
+                })
+            }
+            .map_err(|error| anyhow::anyhow!("Invalid access list address: {}", error))?;
+
+            let mut storage_keys = Vec::with_capacity(item.storage_keys.len());
+            for key in item.storage_keys.into_iter() {
+                let key =
+                    match Value::try_from_matter_labs(key, instances, target).map_err(|error| {
+                        anyhow::anyhow!("Invalid access list storage key: {}", error)
+                    })? {
+                        Value::Certain(value) => value,
+                        Value::Any => anyhow::bail!("Access list storage key can not be `*`"),
+                    };
+                storage_keys.push(key);
+            }
+
+            result.push((address, storage_keys));
+        }
+
+        Ok(Self { inner: result })
+    }
+
+    ///
+    /// Whether the access list is empty.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
@@ -2,13 +2,17 @@
 //! The balance check input variant.
 //!
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
 use crate::summary::Summary;
 use crate::test::case::input::identifier::InputIdentifier;
+use crate::test::case::input::value::Value;
 use crate::test::context::input::InputContext;
 use crate::test::description::TestDescription;
+use crate::test::instance::Instance;
 use crate::vm::eravm::system_context::SystemContext;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::EVM;
@@ -18,7 +22,7 @@ use crate::vm::revm::Revm;
 ///
 /// The balance check input variant.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Balance {
     /// The account address.
     address: web3::types::Address,
@@ -33,6 +37,37 @@ impl Balance {
     pub fn new(address: web3::types::Address, balance: web3::types::U256) -> Self {
         Self { address, balance }
     }
+
+    ///
+    /// Try convert from the expected balances of the Matter Labs compiler test metadata input.
+    ///
+    /// The addresses may be literals or instance references, e.g. `Test.address`.
+    ///
+    pub fn try_from_matter_labs(
+        input: &MatterLabsTestInput,
+        instances: &BTreeMap<String, Instance>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<Vec<Self>> {
+        let mut result = Vec::with_capacity(input.expected_balances.len());
+        for (address, balance) in input.expected_balances.iter() {
+            let address = match Value::try_from_matter_labs(address.to_owned(), instances, target)
+                .map_err(|error| {
+                anyhow::anyhow!("Invalid balance address: {}", error)
+            })? {
+                Value::Certain(address) => crate::utils::u256_to_address(&address),
+                Value::Any => anyhow::bail!("Balance address can not be `*`"),
+            };
+            let balance = match Value::try_from_matter_labs(balance.to_owned(), instances, target)
+                .map_err(|error| {
+                anyhow::anyhow!("Invalid balance value: {}", error)
+            })? {
+                Value::Certain(balance) => balance,
+                Value::Any => anyhow::bail!("Balance value can not be `*`"),
+            };
+            result.push(Self::new(address, balance));
+        }
+        Ok(result)
+    }
 }
 
 impl Balance {
@@ -75,15 +110,28 @@ impl Balance {
     }
 
     ///
-    /// Runs the balance check on EVM emulator.
+    /// Runs the balance check on the EVM emulator.
     ///
     pub fn run_evm_emulator(
         self,
-        _summary: Arc<Mutex<Summary>>,
-        _vm: &EVM,
-        _context: InputContext<'_>,
+        summary: Arc<Mutex<Summary>>,
+        vm: &EVM,
+        context: InputContext<'_>,
     ) {
-        todo!()
+        let input_index = context.selector;
+        let test = TestDescription::from_context(context, InputIdentifier::Balance { input_index });
+        let found = vm.get_balance(self.address);
+        if found == self.balance {
+            Summary::passed_special(summary, test);
+        } else {
+            Summary::failed(
+                summary,
+                test,
+                self.balance.into(),
+                found.into(),
+                self.address.to_fixed_bytes().to_vec(),
+            );
+        }
     }
 
     ///
@@ -149,3 +197,59 @@ impl Balance {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
+    use crate::test::instance::Instance;
+
+    use super::Balance;
+
+    #[test]
+    fn try_from_matter_labs() {
+        let input: MatterLabsTestInput = serde_json::from_str(
+            r##"{
+                "method": "#fallback",
+                "calldata": [],
+                "expected_balances": {
+                    "Test.address": "1000",
+                    "0xdeadbeef01000000000000000000000000000000": "0"
+                }
+            }"##,
+        )
+        .expect("Always valid");
+        let address = web3::types::Address::from_low_u64_be(0x1234);
+        let instances = BTreeMap::from([(
+            "Test".to_owned(),
+            Instance::evm(
+                "test.sol:Test".to_owned(),
+                Some(address),
+                true,
+                false,
+                vec![],
+            ),
+        )]);
+
+        let balances =
+            Balance::try_from_matter_labs(&input, &instances, era_compiler_common::Target::EVM)
+                .expect("Always valid");
+        let balances: Vec<(web3::types::Address, web3::types::U256)> = balances
+            .into_iter()
+            .map(|balance| (balance.address, balance.balance))
+            .collect();
+        assert_eq!(
+            balances,
+            vec![
+                (
+                    "deadbeef01000000000000000000000000000000"
+                        .parse()
+                        .expect("Always valid"),
+                    web3::types::U256::zero(),
+                ),
+                (address, web3::types::U256::from(1000)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//!
+//! The test input call kind.
+//!
+
+use std::str::FromStr;
+
+///
+/// The test input call kind.
+///
+/// The delegate and static calls are issued by a proxy account, which is the `address(this)`
+/// of a delegate call, and the `msg.sender` of a static call.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CallKind {
+    /// The ordinary call.
+    #[default]
+    Call,
+    /// The delegate call from the proxy account.
+    DelegateCall,
+    /// The static call from the proxy account.
+    StaticCall,
+}
+
+impl CallKind {
+    /// The delegate call proxy account address.
+    pub const DELEGATECALL_PROXY_ADDRESS: &'static str =
+        "0x00000000000000000000000000000000de1e9a7e";
+
+    /// The static call proxy account address.
+    pub const STATICCALL_PROXY_ADDRESS: &'static str = "0x0000000000000000000000000000000057a71c00";
+
+    ///
+    /// Returns the proxy account address, if the call is issued by a proxy.
+    ///
+    pub fn proxy_address(&self) -> Option<web3::types::Address> {
+        let address = match self {
+            Self::Call => return None,
+            Self::DelegateCall => Self::DELEGATECALL_PROXY_ADDRESS,
+            Self::StaticCall => Self::STATICCALL_PROXY_ADDRESS,
+        };
+        Some(web3::types::Address::from_str(address).expect("Always valid"))
+    }
+
+    ///
+    /// Returns the EVM proxy bytecode, if the call is issued by a proxy.
+    ///
+    /// The proxy takes the target address from the first calldata word, forwards the rest of
+    /// the calldata to the target, and returns or reverts with the target return data.
+    ///
+    pub fn evm_proxy_bytecode(&self) -> Option<Vec<u8>> {
+        let opcode = match self {
+            Self::Call => return None,
+            Self::DelegateCall => 0xf4,
+            Self::StaticCall => 0xfa,
+        };
+
+        let mut bytecode = vec![];
+        // CALLDATACOPY(0, 32, CALLDATASIZE - 32)
+        bytecode.extend([0x60, 0x20, 0x36, 0x03, 0x60, 0x20, 0x60, 0x00, 0x37]);
+        // <opcode>(GAS, CALLDATALOAD(0), 0, CALLDATASIZE - 32, 0, 0)
+        bytecode.extend([0x60, 0x00, 0x60, 0x00, 0x60, 0x20, 0x36, 0x03, 0x60, 0x00]);
+        bytecode.extend([0x60, 0x00, 0x35, 0x5a, opcode]);
+        // RETURNDATACOPY(0, 0, RETURNDATASIZE)
+        bytecode.extend([0x3d, 0x60, 0x00, 0x60, 0x00, 0x3e]);
+        // JUMPI(success, result)
+        bytecode.extend([0x60, 0x25, 0x57]);
+        // REVERT(0, RETURNDATASIZE)
+        bytecode.extend([0x3d, 0x60, 0x00, 0xfd]);
+        // success: RETURN(0, RETURNDATASIZE)
+        bytecode.extend([0x5b, 0x3d, 0x60, 0x00, 0xf3]);
+        Some(bytecode)
+    }
+}
@@ -11,7 +11,7 @@ use crate::test::instance::Instance;
 ///
 /// The test input calldata.
 ///
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct Calldata {
     /// The calldata bytes.
     pub inner: Vec<u8>,
@@ -50,6 +50,28 @@ impl Calldata {
                 }
                 result
             }
+            MatterLabsTestInputCalldata::Structured { types, values } => {
+                if types.len() != values.len() {
+                    anyhow::bail!(
+                        "Expected {} values for the ABI types, found {}",
+                        types.len(),
+                        values.len()
+                    );
+                }
+
+                let mut tokens = Vec::with_capacity(values.len());
+                for (type_string, value) in types.into_iter().zip(values.into_iter()) {
+                    let r#type = web3::ethabi::param_type::Reader::read(type_string.as_str())
+                        .map_err(|error| {
+                            anyhow::anyhow!("Invalid ABI type `{type_string}`: {error}")
+                        })?;
+                    tokens.push(Self::token(&r#type, value, instances, target)?);
+                }
+                web3::ethabi::encode(tokens.as_slice())
+            }
+            MatterLabsTestInputCalldata::File(file) => {
+                anyhow::bail!("Calldata file `{}` has not been resolved", file.file)
+            }
         };
         Ok(Self { inner: calldata })
     }
@@ -62,6 +84,130 @@ impl Calldata {
         calldata_with_selector.append(&mut self.inner);
         self.inner = calldata_with_selector;
     }
+
+    ///
+    /// Converts a Matter Labs compiler test metadata value to an ABI token of the `type`.
+    ///
+    /// Integers and addresses accept the same literals as the raw calldata words, and integers
+    /// must fit into their bit length, while `bytes` and fixed bytes are expected as hexadecimals
+    /// starting with `0x`.
+    ///
+    fn token(
+        r#type: &web3::ethabi::ParamType,
+        value: serde_json::Value,
+        instances: &BTreeMap<String, Instance>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<web3::ethabi::Token> {
+        let token = match (r#type, value) {
+            (
+                web3::ethabi::ParamType::Uint(_)
+                | web3::ethabi::ParamType::Int(_)
+                | web3::ethabi::ParamType::Address,
+                value @ (serde_json::Value::String(_) | serde_json::Value::Number(_)),
+            ) => {
+                let value = match value {
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                };
+                let value = match Value::try_from_matter_labs(value, instances, target)? {
+                    Value::Certain(value) => value,
+                    Value::Any => anyhow::bail!("The `*` wildcard is not allowed in calldata"),
+                };
+                match r#type {
+                    web3::ethabi::ParamType::Uint(bit_length) => {
+                        if *bit_length < era_compiler_common::BIT_LENGTH_FIELD
+                            && value >> *bit_length != web3::types::U256::zero()
+                        {
+                            anyhow::bail!("Value `{value}` does not fit into `uint{bit_length}`");
+                        }
+                        web3::ethabi::Token::Uint(value)
+                    }
+                    web3::ethabi::ParamType::Int(bit_length) => {
+                        let bound = web3::types::U256::one() << bit_length.saturating_sub(1);
+                        if *bit_length < era_compiler_common::BIT_LENGTH_FIELD
+                            && value >= bound
+                            && value < web3::types::U256::zero().overflowing_sub(bound).0
+                        {
+                            anyhow::bail!("Value `{value}` does not fit into `int{bit_length}`");
+                        }
+                        web3::ethabi::Token::Int(value)
+                    }
+                    _ => web3::ethabi::Token::Address(crate::utils::u256_to_address(&value)),
+                }
+            }
+            (web3::ethabi::ParamType::Bool, serde_json::Value::Bool(value)) => {
+                web3::ethabi::Token::Bool(value)
+            }
+            (web3::ethabi::ParamType::String, serde_json::Value::String(value)) => {
+                web3::ethabi::Token::String(value)
+            }
+            (
+                web3::ethabi::ParamType::Bytes | web3::ethabi::ParamType::FixedBytes(_),
+                serde_json::Value::String(value),
+            ) => {
+                let hex = value.strip_prefix("0x").ok_or_else(|| {
+                    anyhow::anyhow!("Expected a hexadecimal starting with `0x`, found `{value}`")
+                })?;
+                let bytes = hex::decode(hex).map_err(|error| {
+                    anyhow::anyhow!("Hexadecimal value `{value}` decoding error: {error}")
+                })?;
+                match r#type {
+                    web3::ethabi::ParamType::FixedBytes(size) if bytes.len() != *size => {
+                        anyhow::bail!("Expected {size} bytes, found {}", bytes.len())
+                    }
+                    web3::ethabi::ParamType::FixedBytes(_) => {
+                        web3::ethabi::Token::FixedBytes(bytes)
+                    }
+                    _ => web3::ethabi::Token::Bytes(bytes),
+                }
+            }
+            (web3::ethabi::ParamType::Array(r#type), serde_json::Value::Array(values)) => {
+                web3::ethabi::Token::Array(
+                    values
+                        .into_iter()
+                        .map(|value| Self::token(r#type, value, instances, target))
+                        .collect::<anyhow::Result<Vec<_>>>()?,
+                )
+            }
+            (
+                web3::ethabi::ParamType::FixedArray(r#type, size),
+                serde_json::Value::Array(values),
+            ) => {
+                if values.len() != *size {
+                    anyhow::bail!("Expected {size} array elements, found {}", values.len());
+                }
+                web3::ethabi::Token::FixedArray(
+                    values
+                        .into_iter()
+                        .map(|value| Self::token(r#type, value, instances, target))
+                        .collect::<anyhow::Result<Vec<_>>>()?,
+                )
+            }
+            (web3::ethabi::ParamType::Tuple(types), serde_json::Value::Array(values)) => {
+                if values.len() != types.len() {
+                    anyhow::bail!(
+                        "Expected {} tuple elements, found {}",
+                        types.len(),
+                        values.len()
+                    );
+                }
+                web3::ethabi::Token::Tuple(
+                    types
+                        .iter()
+                        .zip(values)
+                        .map(|(r#type, value)| Self::token(r#type, value, instances, target))
+                        .collect::<anyhow::Result<Vec<_>>>()?,
+                )
+            }
+            (r#type, value) => {
+                anyhow::bail!(
+                    "Value `{value}` is not compatible with the ABI type `{}`",
+                    r#type
+                )
+            }
+        };
+        Ok(token)
+    }
 }
 
 impl From<Vec<u8>> for Calldata {
@@ -69,3 +215,98 @@ impl From<Vec<u8>> for Calldata {
         Self { inner: value }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Calldata;
+
+    ///
+    /// Encodes the `value` of the ABI `type` to calldata, returning the 32-byte words as hexadecimals.
+    ///
+    fn encode(r#type: &str, value: serde_json::Value) -> anyhow::Result<Vec<String>> {
+        let r#type = web3::ethabi::param_type::Reader::read(r#type).expect("Always valid");
+        let token = Calldata::token(
+            &r#type,
+            value,
+            &BTreeMap::new(),
+            era_compiler_common::Target::EVM,
+        )?;
+        Ok(web3::ethabi::encode(&[token])
+            .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+            .map(hex::encode)
+            .collect())
+    }
+
+    ///
+    /// Returns the 32-byte word with the `value` in its lowest bytes.
+    ///
+    fn word(value: &str) -> String {
+        format!("{value:0>64}")
+    }
+
+    #[test]
+    fn dynamic_offsets() {
+        for (r#type, value, expected) in [
+            (
+                "uint256[]",
+                serde_json::json!(["1", "2"]),
+                vec![word("20"), word("2"), word("1"), word("2")],
+            ),
+            (
+                "string",
+                serde_json::json!("abc"),
+                vec![word("20"), word("3"), format!("{:0<64}", "616263")],
+            ),
+            (
+                "(uint256,(string,uint256[]))",
+                serde_json::json!(["1", ["ab", ["3"]]]),
+                vec![
+                    word("20"),
+                    word("1"),
+                    word("40"),
+                    word("40"),
+                    word("80"),
+                    word("2"),
+                    format!("{:0<64}", "6162"),
+                    word("1"),
+                    word("3"),
+                ],
+            ),
+        ] {
+            let words = encode(r#type, value).expect("Always valid");
+
+            assert_eq!(words, expected, "{}", r#type);
+        }
+    }
+
+    #[test]
+    fn signed_bounds() {
+        let negative = |lowest: &str| format!("{lowest:f>64}");
+        for (r#type, value, expected) in [
+            ("int8", "127", Some(word("7f"))),
+            ("int8", "-128", Some(negative("80"))),
+            ("int8", "-1", Some(negative(""))),
+            ("int8", "128", None),
+            ("int8", "-129", None),
+            ("int256", "-1", Some(negative(""))),
+            (
+                "int256",
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819968",
+                Some(format!("{:0<64}", "8")),
+            ),
+            (
+                "int256",
+                "-57896044618658097711785492504343953926634992332820282019728792003956564819969",
+                None,
+            ),
+            ("uint8", "255", Some(word("ff"))),
+            ("uint8", "256", None),
+        ] {
+            let words = encode(r#type, serde_json::Value::String(value.to_owned())).ok();
+
+            assert_eq!(words, expected.map(|word| vec![word]), "{} {value}", r#type);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//!
+//! The test input EVM interpreter context overrides.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::directories::matter_labs::test::metadata::case::input::evm_context::EVMContext as MatterLabsTestEVMContext;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+use crate::vm::eravm::system_context::SystemContext;
+
+///
+/// The test input EVM interpreter context overrides.
+///
+/// The overrides are written to the `SystemContext` storage the interpreter reads its context
+/// from, so they do not depend on the inputs run before.
+///
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ContextOverrides {
+    /// The gas price returned by `GASPRICE`.
+    pub gas_price: Option<web3::types::U256>,
+    /// The transaction origin returned by `ORIGIN`.
+    pub origin: Option<web3::types::Address>,
+    /// The block hashes returned by `BLOCKHASH`, keyed by the block number.
+    pub block_hashes: BTreeMap<u128, web3::types::H256>,
+}
+
+impl ContextOverrides {
+    ///
+    /// Try convert from Matter Labs compiler test metadata EVM interpreter context.
+    ///
+    pub fn try_from_matter_labs(
+        context: MatterLabsTestEVMContext,
+        instances: &BTreeMap<String, Instance>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<Self> {
+        let certain = |name: &str, value: String| -> anyhow::Result<web3::types::U256> {
+            match Value::try_from_matter_labs(value, instances, target)
+                .map_err(|error| anyhow::anyhow!("Invalid {name}: {error}"))?
+            {
+                Value::Certain(value) => Ok(value),
+                Value::Any => anyhow::bail!("The {name} can not be `*`"),
+            }
+        };
+
+        let gas_price = match context.gas_price {
+            Some(gas_price) => Some(certain("gas price", gas_price)?),
+            None => None,
+        };
+        let origin = match context.origin {
+            Some(origin) => Some(crate::utils::u256_to_address(&certain("origin", origin)?)),
+            None => None,
+        };
+        let mut block_hashes = BTreeMap::new();
+        for (number, hash) in context.block_hashes.into_iter() {
+            let number: u128 = number
+                .parse()
+                .map_err(|error| anyhow::anyhow!("Invalid block number `{number}`: {error}"))?;
+            if number >= SystemContext::CURRENT_BLOCK_NUMBER {
+                anyhow::bail!(
+                    "The hash of block {number} is unavailable, as the current block is {}",
+                    SystemContext::CURRENT_BLOCK_NUMBER
+                );
+            }
+            let hash = crate::utils::u256_to_h256(&certain("block hash", hash)?);
+            block_hashes.insert(number, hash);
+        }
+
+        Ok(Self {
+            gas_price,
+            origin,
+            block_hashes,
+        })
+    }
+
+    ///
+    /// Whether no context value is overridden.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.gas_price.is_none() && self.origin.is_none() && self.block_hashes.is_empty()
+    }
+
+    ///
+    /// Returns the overridden values by their `SystemContext` storage positions.
+    ///
+    pub fn system_context_values(&self) -> Vec<(web3::types::U256, web3::types::H256)> {
+        let mut values = Vec::with_capacity(2 + self.block_hashes.len());
+        if let Some(gas_price) = self.gas_price.as_ref() {
+            values.push((
+                web3::types::U256::from(SystemContext::SYSTEM_CONTEXT_GAS_PRICE_POSITION),
+                crate::utils::u256_to_h256(gas_price),
+            ));
+        }
+        if let Some(origin) = self.origin.as_ref() {
+            values.push((
+                web3::types::U256::from(SystemContext::SYSTEM_CONTEXT_ORIGIN_POSITION),
+                crate::utils::address_to_h256(origin),
+            ));
+        }
+        for (number, hash) in self.block_hashes.iter() {
+            values.push((SystemContext::block_hash_position(*number), *hash));
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::directories::matter_labs::test::metadata::case::input::evm_context::EVMContext as MatterLabsTestEVMContext;
+    use crate::vm::eravm::system_context::SystemContext;
+
+    use super::ContextOverrides;
+
+    ///
+    /// Converts the metadata `context` without instances on `EVM`.
+    ///
+    fn convert(context: MatterLabsTestEVMContext) -> anyhow::Result<ContextOverrides> {
+        ContextOverrides::try_from_matter_labs(
+            context,
+            &BTreeMap::new(),
+            era_compiler_common::Target::EVM,
+        )
+    }
+
+    #[test]
+    fn try_from_matter_labs() {
+        let overrides = convert(MatterLabsTestEVMContext {
+            gas_price: Some("100".to_owned()),
+            origin: Some("0x1234".to_owned()),
+            block_hashes: BTreeMap::from([("1".to_owned(), "0xabcd".to_owned())]),
+        })
+        .expect("Always valid");
+
+        assert!(!overrides.is_empty());
+        assert_eq!(
+            overrides.system_context_values(),
+            vec![
+                (
+                    web3::types::U256::from(SystemContext::SYSTEM_CONTEXT_GAS_PRICE_POSITION),
+                    web3::types::H256::from_low_u64_be(100),
+                ),
+                (
+                    web3::types::U256::from(SystemContext::SYSTEM_CONTEXT_ORIGIN_POSITION),
+                    web3::types::H256::from_low_u64_be(0x1234),
+                ),
+                (
+                    SystemContext::block_hash_position(1),
+                    web3::types::H256::from_low_u64_be(0xabcd),
+                ),
+            ]
+        );
+        assert!(convert(MatterLabsTestEVMContext::default())
+            .expect("Always valid")
+            .is_empty());
+    }
+
+    #[test]
+    fn try_from_matter_labs_invalid() {
+        for context in [
+            MatterLabsTestEVMContext {
+                gas_price: Some("*".to_owned()),
+                ..Default::default()
+            },
+            MatterLabsTestEVMContext {
+                origin: Some("Unknown.address".to_owned()),
+                ..Default::default()
+            },
+            MatterLabsTestEVMContext {
+                block_hashes: BTreeMap::from([("one".to_owned(), "0x01".to_owned())]),
+                ..Default::default()
+            },
+            MatterLabsTestEVMContext {
+                block_hashes: BTreeMap::from([(
+                    SystemContext::CURRENT_BLOCK_NUMBER.to_string(),
+                    "0x01".to_owned(),
+                )]),
+                ..Default::default()
+            },
+        ] {
+            assert!(convert(context.clone()).is_err(), "{context:?}");
+        }
+    }
+}
@@ -5,6 +5,8 @@
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use crate::summary::element::outcome::deploy_metrics::DeployMetrics;
+use crate::summary::element::outcome::error_code::ErrorCode;
 use crate::summary::Summary;
 use crate::test::case::input::calldata::Calldata;
 use crate::test::case::input::identifier::InputIdentifier;
@@ -18,7 +20,7 @@ use crate::vm::eravm::EraVM;
 ///
 /// The EraVM deploy contract call input variant.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DeployEraVM {
     /// The contract path.
     path: String,
@@ -29,7 +31,7 @@ pub struct DeployEraVM {
     /// The caller.
     caller: web3::types::Address,
     /// The value in wei.
-    value: Option<u128>,
+    value: Option<web3::types::U256>,
     /// The contracts storage to set before running.
     storage: Storage,
     /// The expected output.
@@ -45,7 +47,7 @@ impl DeployEraVM {
         hash: web3::types::U256,
         calldata: Calldata,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         storage: Storage,
         expected: Output,
     ) -> Self {
@@ -74,6 +76,11 @@ impl DeployEraVM {
     ) where
         D: EraVMDeployer,
     {
+        let function_sizes = context
+            .case_context
+            .eravm_function_sizes
+            .get(&self.hash)
+            .cloned();
         let test = TestDescription::from_context(
             context,
             InputIdentifier::Deployer {
@@ -92,20 +99,28 @@ impl DeployEraVM {
         ) {
             Ok(result) => result,
             Err(error) => {
-                Summary::invalid(summary, test, error);
+                Summary::invalid(summary, test, ErrorCode::ExecutionFailed, error);
                 return;
             }
         };
 
         if result.output == self.expected {
             let build_size = vm.get_contract_size(self.hash);
+            let build_instructions = function_sizes.as_ref().map(EraVM::instructions);
             Summary::passed_deploy(
                 summary,
                 test,
-                build_size,
-                result.cycles,
-                result.ergs,
-                result.gas,
+                DeployMetrics {
+                    size: build_size,
+                    instructions: build_instructions,
+                    function_sizes,
+                    cycles: result.cycles,
+                    ergs: result.ergs,
+                    gas: result.gas,
+                    stack_depth: result.stack_depth,
+                    pubdata: result.pubdata,
+                    ..DeployMetrics::default()
+                },
             );
         } else {
             Summary::failed(
@@ -9,25 +9,30 @@ use revm::primitives::EVMError;
 use revm::primitives::ExecutionResult;
 use solidity_adapter::EVMVersion;
 
+use crate::disassembly::Disassembly;
+use crate::summary::element::outcome::deploy_metrics::DeployMetrics;
+use crate::summary::element::outcome::error_code::ErrorCode;
 use crate::summary::Summary;
 use crate::test::case::input::calldata::Calldata;
 use crate::test::case::input::identifier::InputIdentifier;
 use crate::test::case::input::output::Output;
 use crate::test::case::input::storage::Storage;
+use crate::test::case::input::value::Value;
 use crate::test::description::TestDescription;
 use crate::test::InputContext;
 use crate::vm::eravm::deployers::EraVMDeployer;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::EVM;
 
-use crate::vm::revm::revm_type_conversions::revm_bytes_to_vec_value;
+use crate::vm::revm::revm_type_conversions::transform_revert_output;
 use crate::vm::revm::revm_type_conversions::transform_success_output;
+use crate::vm::revm::trace::Trace;
 use crate::vm::revm::Revm;
 
 ///
 /// The EVM deploy contract call input variant.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DeployEVM {
     /// The contract identifier.
     identifier: String,
@@ -38,7 +43,7 @@ pub struct DeployEVM {
     /// The caller.
     caller: web3::types::Address,
     /// The value in wei.
-    value: Option<u128>,
+    value: Option<web3::types::U256>,
     /// The contracts storage to set before running.
     storage: Storage,
     /// The expected output.
@@ -54,7 +59,7 @@ impl DeployEVM {
         deploy_code: Vec<u8>,
         calldata: Calldata,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         storage: Storage,
         expected: Output,
     ) -> Self {
@@ -98,12 +103,22 @@ impl DeployEVM {
         ) {
             Ok(execution_result) => execution_result,
             Err(error) => {
-                Summary::invalid(summary, test, error);
+                Summary::invalid(summary, test, ErrorCode::ExecutionFailed, error);
                 return;
             }
         };
         if result.output == self.expected {
-            Summary::passed_runtime(summary, test, result.cycles, 0, result.gas);
+            Summary::passed_runtime(
+                summary,
+                test,
+                result.cycles,
+                0,
+                result.gas,
+                result.stack_depth,
+                result.pubdata,
+                None,
+                None,
+            );
         } else {
             Summary::failed(
                 summary,
@@ -125,6 +140,7 @@ impl DeployEVM {
         evm_version: Option<EVMVersion>,
         context: InputContext<'_>,
     ) -> Revm<'b> {
+        let trace_directory = context.case_context.revm_trace_directory;
         let test = TestDescription::from_context(
             context,
             InputIdentifier::Deployer {
@@ -140,7 +156,8 @@ impl DeployEVM {
         let vm = vm.update_deploy_balance(&self.caller);
         let mut vm = vm.fill_deploy_new_transaction(self.caller, self.value, evm_version, code);
 
-        let result = match vm.state.transact_commit() {
+        let mut trace = trace_directory.map(|_| Trace::new(&vm));
+        let result = match Trace::transact_commit(&mut vm, trace.as_mut()) {
             Ok(res) => res,
             Err(error) => {
                 let error_msg = match error {
@@ -151,11 +168,13 @@ impl DeployEVM {
                     EVMError::Precompile(error) => format!("Error on Precompile: {error:?}"),
                 };
 
-                Summary::invalid(summary.clone(), test, error_msg);
+                Summary::invalid(summary.clone(), test, ErrorCode::ExecutionFailed, error_msg);
                 return vm;
             }
         };
 
+        let mut runtime_size = None;
+        let mut metadata_size = None;
         let (output, gas, error) = match result {
             ExecutionResult::Success {
                 reason: _,
@@ -163,20 +182,50 @@ impl DeployEVM {
                 gas_refunded: _,
                 logs,
                 output,
-            } => (transform_success_output(output, logs), gas_used, None),
+            } => {
+                if let revm::primitives::Output::Create(ref runtime_code, _) = output {
+                    runtime_size = Some(runtime_code.len());
+                    metadata_size = Some(Disassembly::metadata_size(runtime_code.as_ref()));
+                }
+                (transform_success_output(output, logs), gas_used, None)
+            }
             ExecutionResult::Revert { gas_used, output } => {
-                let return_data_value = revm_bytes_to_vec_value(output);
-                (Output::new(return_data_value, true, vec![]), gas_used, None)
+                (transform_revert_output(output), gas_used, None)
             }
             ExecutionResult::Halt { reason, gas_used } => {
                 (Output::new(vec![], true, vec![]), gas_used, Some(reason))
             }
         };
 
+        if output != self.expected {
+            if let (Some(trace), Some(trace_directory)) = (trace, trace_directory) {
+                if let Err(error) =
+                    trace.write(&vm, trace_directory, Trace::test_name(&test).as_str())
+                {
+                    eprintln!("REVM trace writing error: {error}");
+                }
+            }
+        }
+
         if output == self.expected {
-            Summary::passed_deploy(summary, test, size, 0, 0, gas);
+            Summary::passed_deploy(
+                summary,
+                test,
+                DeployMetrics {
+                    size,
+                    runtime_size,
+                    metadata_size,
+                    gas,
+                    ..DeployMetrics::default()
+                },
+            );
         } else if let Some(error) = error {
-            Summary::invalid(summary, test, format!("{error:?}"));
+            Summary::invalid(
+                summary,
+                test,
+                ErrorCode::ExecutionFailed,
+                format!("{error:?}"),
+            );
         } else {
             Summary::failed(summary, test, self.expected, output, calldata);
         }
@@ -217,12 +266,34 @@ impl DeployEVM {
         ) {
             Ok(result) => result,
             Err(error) => {
-                Summary::invalid(summary, test, error);
+                Summary::invalid(summary, test, ErrorCode::ExecutionFailed, error);
                 return;
             }
         };
         if result.output == self.expected {
-            Summary::passed_deploy(summary, test, size, result.cycles, result.ergs, result.gas);
+            let address = match result.output.return_data.first() {
+                Some(Value::Certain(address)) => Some(crate::utils::u256_to_address(address)),
+                _ => None,
+            };
+            let runtime_size = address.and_then(|address| vm.get_evm_code_size(&address));
+            let metadata_size = address
+                .and_then(|address| vm.get_evm_code(&address))
+                .map(|runtime_code| Disassembly::metadata_size(runtime_code.as_slice()));
+            Summary::passed_deploy(
+                summary,
+                test,
+                DeployMetrics {
+                    size,
+                    runtime_size,
+                    metadata_size,
+                    cycles: result.cycles,
+                    ergs: result.ergs,
+                    gas: result.gas,
+                    stack_depth: result.stack_depth,
+                    pubdata: result.pubdata,
+                    ..DeployMetrics::default()
+                },
+            );
         } else {
             Summary::failed(
                 summary,
@@ -0,0 +1,26 @@
+//!
+//! The fixture kind of a test input.
+//!
+
+///
+/// The fixture kind of a test input.
+///
+/// The fixtures are numbered separately from the main inputs, so that they do not shift the
+/// indexes the main inputs are reported with.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+pub enum Fixture {
+    /// Run before the main inputs.
+    Setup,
+    /// Run after the main inputs.
+    Teardown,
+}
+
+impl std::fmt::Display for Fixture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Setup => write!(f, "setup"),
+            Self::Teardown => write!(f, "teardown"),
+        }
+    }
+}
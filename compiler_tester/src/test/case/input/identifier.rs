@@ -15,6 +15,10 @@ pub enum InputIdentifier {
     StorageEmpty { input_index: usize },
     /// Check account balance.
     Balance { input_index: usize },
+    /// The state test transaction.
+    StateTransition { input_index: usize },
+    /// The immutables check.
+    Immutables { input_index: usize },
 }
 
 impl std::fmt::Display for InputIdentifier {
@@ -32,6 +36,12 @@ impl std::fmt::Display for InputIdentifier {
             InputIdentifier::Balance { input_index } => {
                 f.write_fmt(format_args!("#balance_check:{input_index}"))
             }
+            InputIdentifier::StateTransition { input_index } => {
+                f.write_fmt(format_args!("#state_transition:{input_index}"))
+            }
+            InputIdentifier::Immutables { input_index } => {
+                f.write_fmt(format_args!("#immutables_check:{input_index}"))
+            }
         }
     }
 }
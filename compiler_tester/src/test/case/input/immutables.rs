@@ -0,0 +1,298 @@
+//!
+//! The immutables check input variant.
+//!
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use revm::Database;
+
+use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
+use crate::summary::Summary;
+use crate::test::case::input::identifier::InputIdentifier;
+use crate::test::case::input::value::Value;
+use crate::test::context::input::InputContext;
+use crate::test::description::TestDescription;
+use crate::test::instance::Instance;
+use crate::vm::eravm::EraVM;
+use crate::vm::revm::revm_type_conversions::web3_address_to_revm_address;
+use crate::vm::revm::Revm;
+
+///
+/// The immutables check input variant.
+///
+/// On EraVM, the index is the immutable index in the `ImmutableSimulator` contract.
+/// On EVM, the index is the byte offset of the immutable in the deployed runtime code,
+/// resolved from the immutable name with the immutable references of the build.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Immutables {
+    /// The contract address.
+    address: web3::types::Address,
+    /// The expected immutable values by index.
+    expected: BTreeMap<web3::types::U256, web3::types::U256>,
+}
+
+impl Immutables {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        address: web3::types::Address,
+        expected: BTreeMap<web3::types::U256, web3::types::U256>,
+    ) -> Self {
+        Self { address, expected }
+    }
+
+    ///
+    /// Try convert from the expected immutables of the Matter Labs compiler test metadata input.
+    ///
+    /// Returns `None` if the input does not expect any immutables for the `target`.
+    ///
+    /// On EVM, the immutables are expected by name rather than by byte offset, so that the
+    /// tests do not depend on the code layout. The value is checked at every reference.
+    ///
+    pub fn try_from_matter_labs(
+        input: &MatterLabsTestInput,
+        instances: &BTreeMap<String, Instance>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<Option<Self>> {
+        let expected = match target {
+            era_compiler_common::Target::EraVM => input
+                .expected_immutables_eravm
+                .as_ref()
+                .or(input.expected_immutables.as_ref()),
+            era_compiler_common::Target::EVM => input
+                .expected_immutables_evm
+                .as_ref()
+                .or(input.expected_immutables.as_ref()),
+        };
+        let expected = match expected {
+            Some(expected) => expected,
+            None => return Ok(None),
+        };
+
+        if input.method.as_str() != "#deployer" {
+            anyhow::bail!("Immutables can only be checked after the `#deployer` call");
+        }
+
+        let instance = instances
+            .get(&input.instance)
+            .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", input.instance))?;
+        let address = instance.address().copied().ok_or_else(|| {
+            anyhow::anyhow!("Instance `{}` has no predicted address", input.instance)
+        })?;
+
+        let mut result = BTreeMap::new();
+        for (key, value) in expected.iter() {
+            let indexes = match instance.immutables() {
+                Some(immutables) => immutables
+                    .get(key.as_str())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Immutable `{key}` not found in the immutable references of instance `{}`. Available: {:?}",
+                            input.instance,
+                            immutables.keys().collect::<Vec<&String>>(),
+                        )
+                    })?
+                    .iter()
+                    .map(|offset| web3::types::U256::from(*offset))
+                    .collect(),
+                None => match Value::try_from_matter_labs(key.to_owned(), instances, target)
+                    .map_err(|error| anyhow::anyhow!("Invalid immutable index: {}", error))?
+                {
+                    Value::Certain(index) => vec![index],
+                    Value::Any => anyhow::bail!("Immutable index can not be `*`"),
+                },
+            };
+            let value = match Value::try_from_matter_labs(value.to_owned(), instances, target)
+                .map_err(|error| anyhow::anyhow!("Invalid immutable value: {}", error))?
+            {
+                Value::Certain(value) => value,
+                Value::Any => anyhow::bail!("Immutable value can not be `*`"),
+            };
+            result.extend(indexes.into_iter().map(|index| (index, value)));
+        }
+
+        Ok(Some(Self::new(address, result)))
+    }
+}
+
+impl Immutables {
+    ///
+    /// Runs the immutables check on EraVM.
+    ///
+    pub fn run_eravm(self, summary: Arc<Mutex<Summary>>, vm: &EraVM, context: InputContext<'_>) {
+        let input_index = context.selector;
+        let test =
+            TestDescription::from_context(context, InputIdentifier::Immutables { input_index });
+
+        for (index, expected) in self.expected.into_iter() {
+            let found = vm.get_immutable(self.address, index);
+            let found = web3::types::U256::from_big_endian(found.as_bytes());
+            if found != expected {
+                Summary::failed(
+                    summary,
+                    test,
+                    expected.into(),
+                    found.into(),
+                    crate::utils::u256_to_h256(&index).to_fixed_bytes().to_vec(),
+                );
+                return;
+            }
+        }
+
+        Summary::passed_special(summary, test);
+    }
+
+    ///
+    /// Skips the immutables check on the EVM emulator, which does not expose the deployed code.
+    ///
+    pub fn run_unsupported(self, summary: Arc<Mutex<Summary>>, context: InputContext<'_>) {
+        let input_index = context.selector;
+        let test =
+            TestDescription::from_context(context, InputIdentifier::Immutables { input_index });
+        Summary::ignored(summary, test);
+    }
+
+    ///
+    /// Runs the immutables check on REVM.
+    ///
+    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, vm: &mut Revm, context: InputContext<'_>) {
+        let input_index = context.selector;
+        let test =
+            TestDescription::from_context(context, InputIdentifier::Immutables { input_index });
+
+        let address = web3_address_to_revm_address(&self.address);
+        let code = match vm.state.db_mut().basic(address) {
+            Ok(Some(info)) => match info.code {
+                Some(code) => code.original_bytes().to_vec(),
+                None => vm
+                    .state
+                    .db_mut()
+                    .code_by_hash(info.code_hash)
+                    .map(|code| code.original_bytes().to_vec())
+                    .unwrap_or_default(),
+            },
+            _ => vec![],
+        };
+
+        for (index, expected) in self.expected.into_iter() {
+            let found = usize::try_from(index)
+                .ok()
+                .and_then(|offset| {
+                    code.get(offset..offset + era_compiler_common::BYTE_LENGTH_FIELD)
+                })
+                .map(web3::types::U256::from_big_endian)
+                .unwrap_or_default();
+            if found != expected {
+                Summary::failed(
+                    summary,
+                    test,
+                    expected.into(),
+                    found.into(),
+                    crate::utils::u256_to_h256(&index).to_fixed_bytes().to_vec(),
+                );
+                return;
+            }
+        }
+
+        Summary::passed_special(summary, test);
+    }
+
+    ///
+    /// Runs the immutables check on EVM interpreter.
+    ///
+    /// The immutables are embedded into the EVM bytecode, which is not exposed by the interpreter.
+    ///
+    pub fn run_evm_interpreter(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        _vm: &EraVM,
+        context: InputContext<'_>,
+    ) {
+        let input_index = context.selector;
+        let test =
+            TestDescription::from_context(context, InputIdentifier::Immutables { input_index });
+        Summary::ignored(summary, test);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
+    use crate::test::instance::Instance;
+
+    use super::Immutables;
+
+    #[test]
+    fn try_from_matter_labs() {
+        let address = web3::types::Address::from_low_u64_be(0x1234);
+        let instances = BTreeMap::from([(
+            "Test".to_owned(),
+            Instance::evm(
+                "test.sol:Test".to_owned(),
+                Some(address),
+                true,
+                false,
+                vec![],
+            )
+            .with_immutables(BTreeMap::from([
+                ("owner".to_owned(), vec![64, 160]),
+                ("value".to_owned(), vec![96]),
+            ])),
+        )]);
+
+        for (expected_immutables, expected) in [
+            (
+                r#"{ "owner": "Test.address", "value": "42" }"#,
+                Ok(vec![
+                    (64, web3::types::U256::from(0x1234)),
+                    (96, web3::types::U256::from(42)),
+                    (160, web3::types::U256::from(0x1234)),
+                ]),
+            ),
+            (
+                r#"{ "96": "42" }"#,
+                Err(
+                    r#"Immutable `96` not found in the immutable references of instance `Test`. Available: ["owner", "value"]"#,
+                ),
+            ),
+        ] {
+            let input: MatterLabsTestInput = serde_json::from_str(
+                format!(
+                    r##"{{
+                        "method": "#deployer",
+                        "calldata": [],
+                        "expected_immutables_evm": {expected_immutables}
+                    }}"##
+                )
+                .as_str(),
+            )
+            .expect("Always valid");
+
+            let immutables = Immutables::try_from_matter_labs(
+                &input,
+                &instances,
+                era_compiler_common::Target::EVM,
+            )
+            .map(|immutables| {
+                immutables
+                    .expect("Always exists")
+                    .expected
+                    .into_iter()
+                    .map(|(index, value)| (index.as_usize(), value))
+                    .collect::<Vec<(usize, web3::types::U256)>>()
+            })
+            .map_err(|error| error.to_string());
+            assert_eq!(
+                immutables,
+                expected.map_err(str::to_owned),
+                "{expected_immutables}"
+            );
+        }
+    }
+}
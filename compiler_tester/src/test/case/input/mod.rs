@@ -2,18 +2,26 @@
 //! The test input.
 //!
 
+pub mod access_list;
 pub mod balance;
+pub mod call_kind;
 pub mod calldata;
+pub mod context_overrides;
 pub mod deploy_eravm;
 pub mod deploy_evm;
+pub mod fixture;
 pub mod identifier;
+pub mod immutables;
 pub mod output;
 pub mod runtime;
+pub mod state_transition;
 pub mod storage;
 pub mod storage_empty;
 pub mod value;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -28,19 +36,24 @@ use crate::vm::eravm::EraVM;
 use crate::vm::evm::EVM;
 use crate::vm::revm::Revm;
 
+use self::access_list::AccessList;
 use self::balance::Balance;
+use self::call_kind::CallKind;
 use self::calldata::Calldata;
+use self::context_overrides::ContextOverrides;
 use self::deploy_eravm::DeployEraVM;
 use self::deploy_evm::DeployEVM;
+use self::immutables::Immutables;
 use self::output::Output;
 use self::runtime::Runtime;
+use self::state_transition::StateTransition;
 use self::storage::Storage;
 use self::storage_empty::StorageEmpty;
 
 ///
 /// The test input.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub enum Input {
     /// The EraVM contract deploy.
     DeployEraVM(DeployEraVM),
@@ -52,6 +65,10 @@ pub enum Input {
     StorageEmpty(StorageEmpty),
     /// Check account balance.
     Balance(Balance),
+    /// The Ethereum state test transaction.
+    StateTransition(StateTransition),
+    /// The immutables check.
+    Immutables(Immutables),
 }
 
 impl Input {
@@ -63,23 +80,21 @@ impl Input {
         mode: &Mode,
         instances: &BTreeMap<String, Instance>,
         method_identifiers: &Option<BTreeMap<String, BTreeMap<String, u32>>>,
+        custom_errors: &BTreeMap<String, BTreeSet<String>>,
         target: era_compiler_common::Target,
     ) -> anyhow::Result<Self> {
-        let caller = web3::types::Address::from_str(input.caller.as_str())
-            .map_err(|error| anyhow::anyhow!("Invalid caller `{}`: {}", input.caller, error))?;
+        let caller = web3::types::Address::from_str(input.caller())
+            .map_err(|error| anyhow::anyhow!("Invalid caller `{}`: {}", input.caller(), error))?;
 
         let value = match input.value {
             Some(value) => Some(if let Some(value) = value.strip_suffix(" ETH") {
-                u128::from_str(value)
-                    .map_err(|error| anyhow::anyhow!("Invalid value literal `{value}`: {}", error))?
-                    .checked_mul(10u128.pow(18))
+                Self::parse_value_literal(value)?
+                    .checked_mul(web3::types::U256::exp10(18))
                     .ok_or_else(|| {
-                        anyhow::anyhow!("Invalid value literal `{value}`: u128 overflow")
+                        anyhow::anyhow!("Invalid value literal `{value}`: U256 overflow")
                     })?
             } else if let Some(value) = value.strip_suffix(" wei") {
-                u128::from_str(value).map_err(|error| {
-                    anyhow::anyhow!("Invalid value literal `{value}`: {}", error)
-                })?
+                Self::parse_value_literal(value)?
             } else {
                 anyhow::bail!("Invalid value `{value}`");
             }),
@@ -93,22 +108,68 @@ impl Input {
             era_compiler_common::Target::EraVM => input.expected_eravm.or(input.expected),
             era_compiler_common::Target::EVM => input.expected_evm.or(input.expected),
         };
+        let is_expected_specified = expected.is_some();
         let expected = match expected {
-            Some(expected) => {
-                Output::try_from_matter_labs_expected(expected, mode, instances, target)
-                    .map_err(|error| anyhow::anyhow!("Invalid expected metadata: {}", error))?
-            }
+            Some(expected) => Output::try_from_matter_labs_expected(
+                expected,
+                mode,
+                instances,
+                custom_errors,
+                target,
+            )
+            .map_err(|error| anyhow::anyhow!("Invalid expected metadata: {}", error))?,
             None => Output::default(),
         };
 
         let storage = Storage::try_from_matter_labs(input.storage, instances, target)
             .map_err(|error| anyhow::anyhow!("Invalid storage: {}", error))?;
 
+        let access_list = AccessList::try_from_matter_labs(input.access_list, instances, target)
+            .map_err(|error| anyhow::anyhow!("Invalid access list: {}", error))?;
+
+        let context_overrides = match input.evm_context {
+            Some(context) => ContextOverrides::try_from_matter_labs(context, instances, target)
+                .map_err(|error| anyhow::anyhow!("Invalid EVM context: {}", error))?,
+            None => ContextOverrides::default(),
+        };
+
         let instance = instances
             .get(&input.instance)
             .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", input.instance))?;
 
-        let input = match input.method.as_str() {
+        if input.call_kind != CallKind::Call {
+            if input.method.as_str() == "#deployer" {
+                anyhow::bail!("The call kind cannot be specified for the `#deployer` call");
+            }
+            if value.is_some() {
+                anyhow::bail!("Value cannot be passed with a delegate or static call");
+            }
+        }
+        if input.evm_gas_limit.is_some() && input.method.as_str() == "#deployer" {
+            anyhow::bail!("The EVM gas limit cannot be specified for the `#deployer` call");
+        }
+        if !context_overrides.is_empty() && input.method.as_str() == "#deployer" {
+            anyhow::bail!("The EVM context cannot be specified for the `#deployer` call");
+        }
+        if !access_list.is_empty() && input.method.as_str() == "#deployer" {
+            anyhow::bail!("The access list cannot be specified for the `#deployer` call");
+        }
+        if input.register.is_some() && input.method.as_str() == "#deployer" {
+            anyhow::bail!("The instance cannot be registered by the `#deployer` call");
+        }
+        if input.system_mode.is_some() {
+            if input.method.as_str() == "#deployer" {
+                anyhow::bail!("The system mode cannot be specified for the `#deployer` call");
+            }
+            if value.is_none() {
+                anyhow::bail!("The system mode can only be specified for a call with value");
+            }
+        }
+
+        let register = input.register;
+        let system_mode = input.system_mode;
+        let fixture = input.fixture;
+        let mut input = match input.method.as_str() {
             "#deployer" => match instance {
                 Instance::EraVM(instance) => Input::DeployEraVM(DeployEraVM::new(
                     instance.path.to_owned(),
@@ -143,6 +204,8 @@ impl Input {
                     calldata,
                     caller,
                     value,
+                    input.call_kind,
+                    input.evm_gas_limit,
                     storage,
                     expected,
                 ))
@@ -198,11 +261,28 @@ impl Input {
                     calldata,
                     caller,
                     value,
+                    input.call_kind,
+                    input.evm_gas_limit,
                     storage,
                     expected,
                 ))
             }
         };
+        if let (Input::Runtime(runtime), Some(register)) = (&mut input, register.as_deref()) {
+            runtime.set_register(register);
+        }
+        if let (Input::Runtime(runtime), Some(system_mode)) = (&mut input, system_mode) {
+            runtime.set_system_mode(system_mode);
+        }
+        if let (Input::Runtime(runtime), false) = (&mut input, access_list.is_empty()) {
+            runtime.set_access_list(access_list);
+        }
+        if let (Input::Runtime(runtime), false) = (&mut input, context_overrides.is_empty()) {
+            runtime.set_context_overrides(context_overrides);
+        }
+        if let (Input::Runtime(runtime), Some(fixture)) = (&mut input, fixture) {
+            runtime.set_fixture(fixture, is_expected_specified);
+        }
 
         Ok(input)
     }
@@ -215,6 +295,7 @@ impl Input {
         instances: &BTreeMap<String, Instance>,
         last_source: &str,
         caller: &web3::types::Address,
+        events_unordered: bool,
         target: era_compiler_common::Target,
     ) -> anyhow::Result<Option<Self>> {
         let main_contract_instance = instances
@@ -231,12 +312,7 @@ impl Input {
                 events,
                 ..
             } => {
-                let value = match value {
-                    Some(value) => Some((*value).try_into().map_err(|error| {
-                        anyhow::anyhow!("Invalid value literal `{:X}`: {}", value, error)
-                    })?),
-                    None => None,
-                };
+                let value = *value;
 
                 let expected = Output::from_ethereum_expected(
                     &[web3::types::U256::from_big_endian(
@@ -244,6 +320,7 @@ impl Input {
                     )],
                     false,
                     events,
+                    events_unordered,
                     main_contract_address,
                     target,
                 );
@@ -288,6 +365,7 @@ impl Input {
                     )],
                     false,
                     &[],
+                    events_unordered,
                     main_contract_address,
                     target,
                 );
@@ -331,17 +409,13 @@ impl Input {
                 events,
                 ..
             } => {
-                let value = match value {
-                    Some(value) => Some((*value).try_into().map_err(|error| {
-                        anyhow::anyhow!("Invalid value literal `{:X}`: {}", value, error)
-                    })?),
-                    None => None,
-                };
+                let value = *value;
 
                 let expected = Output::from_ethereum_expected(
                     expected,
                     *failure,
                     events,
+                    events_unordered,
                     main_contract_address,
                     target,
                 );
@@ -352,6 +426,8 @@ impl Input {
                     calldata.clone().into(),
                     *caller,
                     value,
+                    CallKind::Call,
+                    None,
                     Storage::default(),
                     expected,
                 )))
@@ -382,6 +458,10 @@ impl Input {
             Self::Runtime(runtime) => runtime.run_eravm::<M>(summary, vm, context),
             Self::StorageEmpty(storage_empty) => storage_empty.run_eravm(summary, vm, context),
             Self::Balance(balance_check) => balance_check.run_eravm(summary, vm, context),
+            Self::StateTransition { .. } => {
+                panic!("State test transaction cannot be run on EraVM")
+            }
+            Self::Immutables(immutables) => immutables.run_eravm(summary, vm, context),
         };
     }
 
@@ -402,6 +482,10 @@ impl Input {
                 storage_empty.run_evm_emulator(summary, vm, context)
             }
             Self::Balance(balance_check) => balance_check.run_evm_emulator(summary, vm, context),
+            Self::StateTransition(state_transition) => {
+                state_transition.run_unsupported(summary, context)
+            }
+            Self::Immutables(immutables) => immutables.run_unsupported(summary, context),
         };
     }
 
@@ -414,11 +498,14 @@ impl Input {
         mut vm: Revm<'b>,
         evm_version: Option<solidity_adapter::EVMVersion>,
         context: InputContext<'_>,
+        registered_addresses: &mut HashMap<web3::types::Address, web3::types::Address>,
     ) -> Revm<'b> {
         match self {
             Self::DeployEraVM { .. } => panic!("EraVM deploy transaction cannot be run on REVM"),
             Self::DeployEVM(deploy) => deploy.run_revm(summary, vm, evm_version, context),
-            Self::Runtime(runtime) => runtime.run_revm(summary, vm, evm_version, context),
+            Self::Runtime(runtime) => {
+                runtime.run_revm(summary, vm, evm_version, context, registered_addresses)
+            }
             Self::StorageEmpty(storage_empty) => {
                 storage_empty.run_revm(summary, &mut vm, context);
                 vm
@@ -427,6 +514,14 @@ impl Input {
                 balance_check.run_revm(summary, &mut vm, context);
                 vm
             }
+            Self::StateTransition(state_transition) => {
+                state_transition.run_revm(summary, context);
+                vm
+            }
+            Self::Immutables(immutables) => {
+                immutables.run_revm(summary, &mut vm, context);
+                vm
+            }
         }
     }
 
@@ -454,6 +549,25 @@ impl Input {
                 storage_empty.run_evm_interpreter(summary, vm, context)
             }
             Self::Balance(balance_check) => balance_check.run_evm_interpreter(summary, vm, context),
+            Self::StateTransition(state_transition) => {
+                state_transition.run_unsupported(summary, context)
+            }
+            Self::Immutables(immutables) => immutables.run_evm_interpreter(summary, vm, context),
+        };
+    }
+
+    ///
+    /// Parses a decimal or `0x`-prefixed hexadecimal value literal.
+    ///
+    fn parse_value_literal(value: &str) -> anyhow::Result<web3::types::U256> {
+        let result = match value.strip_prefix("0x") {
+            Some(hexadecimal) => web3::types::U256::from_str_radix(
+                hexadecimal,
+                era_compiler_common::BASE_HEXADECIMAL,
+            )
+            .map_err(|error| error.to_string()),
+            None => web3::types::U256::from_dec_str(value).map_err(|error| error.to_string()),
         };
+        result.map_err(|error| anyhow::anyhow!("Invalid value literal `{value}`: {error}"))
     }
 }
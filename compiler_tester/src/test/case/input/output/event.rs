@@ -3,6 +3,7 @@
 //!
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::directories::matter_labs::test::metadata::case::input::expected::variant::extended::event::Event as MatterLabsTestExpectedEvent;
@@ -38,6 +39,25 @@ impl Event {
         }
     }
 
+    ///
+    /// Replaces the registration placeholders with the registered addresses.
+    ///
+    pub fn substitute_addresses(
+        &mut self,
+        addresses: &HashMap<web3::types::Address, web3::types::Address>,
+    ) {
+        if let Some(address) = self
+            .address
+            .as_ref()
+            .and_then(|address| addresses.get(address))
+        {
+            self.address = Some(*address);
+        }
+        for value in self.topics.iter_mut().chain(self.values.iter_mut()) {
+            value.substitute_address(addresses);
+        }
+    }
+
     ///
     /// Try convert from Matter Labs compiler test metadata expected event.
     ///
@@ -52,19 +72,16 @@ impl Event {
             .map_err(|error| anyhow::anyhow!("Invalid values: {}", error))?;
 
         let address = match event.address {
+            Some(address) if address == "*" => None,
             Some(address) => Some(
-                if let Some(instance) = address.strip_suffix(".address") {
-                    instances
-                        .get(instance)
-                        .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", instance))?
-                        .address()
-                        .copied()
-                        .ok_or_else(|| {
-                            anyhow::anyhow!("Instance `{}` was not successfully deployed", instance)
-                        })
+                if let Some(instance) = Value::instance_name(address.as_str()) {
+                    Value::instance_address(instance, instances)
                 } else {
-                    web3::types::Address::from_str(address.as_str())
-                        .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
+                    let literal = address.strip_prefix("0x").unwrap_or(address.as_str());
+                    crate::utils::validate_address_checksum(literal).and_then(|()| {
+                        web3::types::Address::from_str(literal)
+                            .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
+                    })
                 }
                 .map_err(|error| anyhow::anyhow!("Invalid event address `{address}`: {error}"))?,
             ),
@@ -3,8 +3,12 @@
 //!
 
 pub mod event;
+pub mod return_data_assertion;
+pub mod revert_reason;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::compilers::mode::Mode;
@@ -15,6 +19,8 @@ use crate::test::instance::Instance;
 use crate::vm::evm::output::Output as EVMOutput;
 
 use self::event::Event;
+use self::return_data_assertion::ReturnDataAssertion;
+use self::revert_reason::RevertReason;
 
 ///
 /// The compiler test outcome data.
@@ -27,6 +33,18 @@ pub struct Output {
     pub exception: bool,
     /// The emitted events.
     pub events: Vec<Event>,
+    /// The expected revert reason, compared instead of the return data if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<RevertReason>,
+    /// The expected return data size and prefix, checked instead of the return data if specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_data_assertion: Option<ReturnDataAssertion>,
+    /// The return data size in bytes, if the output is produced by a VM.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_data_size: Option<usize>,
+    /// Whether the events are compared regardless of their order.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub events_unordered: bool,
 }
 
 impl Output {
@@ -38,6 +56,25 @@ impl Output {
             return_data,
             exception,
             events,
+            revert_reason: None,
+            return_data_assertion: None,
+            return_data_size: None,
+            events_unordered: false,
+        }
+    }
+
+    ///
+    /// Replaces the registration placeholders with the registered addresses.
+    ///
+    pub fn substitute_addresses(
+        &mut self,
+        addresses: &HashMap<web3::types::Address, web3::types::Address>,
+    ) {
+        for value in self.return_data.iter_mut() {
+            value.substitute_address(addresses);
+        }
+        for event in self.events.iter_mut() {
+            event.substitute_addresses(addresses);
         }
     }
 
@@ -48,6 +85,7 @@ impl Output {
         expected: MatterLabsTestExpected,
         mode: &Mode,
         instances: &BTreeMap<String, Instance>,
+        custom_errors: &BTreeMap<String, BTreeSet<String>>,
         target: era_compiler_common::Target,
     ) -> anyhow::Result<Self> {
         let variants = match expected {
@@ -56,25 +94,59 @@ impl Output {
         };
         let variant = variants
             .into_iter()
-            .find(|variant| {
-                let version = match variant {
-                    MatterLabsTestExpectedVariant::Simple(_) => None,
-                    MatterLabsTestExpectedVariant::Extended(inner) => {
-                        inner.compiler_version.as_ref()
-                    }
-                };
-                match version {
-                    Some(version) => mode.check_version(version),
-                    None => true,
-                }
-            })
+            .find(|variant| variant.check_mode(mode))
             .ok_or_else(|| anyhow::anyhow!("Version not covered"))?;
 
-        let (return_data, exception, events) = match variant {
-            MatterLabsTestExpectedVariant::Simple(return_data) => (return_data, false, Vec::new()),
+        let (
+            return_data,
+            exception,
+            events,
+            revert_reason,
+            return_data_assertion,
+            events_unordered,
+        ) = match variant {
+            MatterLabsTestExpectedVariant::Simple(return_data) => {
+                (return_data, false, Vec::new(), None, None, false)
+            }
+            MatterLabsTestExpectedVariant::File(file) => {
+                anyhow::bail!("Return data file `{}` has not been resolved", file.file)
+            }
             MatterLabsTestExpectedVariant::Extended(expected) => {
-                let return_data = expected.return_data;
-                let exception = expected.exception;
+                let return_data = match expected.created_address {
+                    Some(created_address) if expected.return_data.is_empty() => {
+                        vec![created_address]
+                    }
+                    Some(_) => anyhow::bail!(
+                        "The created address cannot be specified together with the return data"
+                    ),
+                    None => expected.return_data,
+                };
+                let revert_reason = expected
+                    .revert_reason
+                    .map(|reason| {
+                        RevertReason::try_from_matter_labs(reason, instances, custom_errors, target)
+                    })
+                    .transpose()
+                    .map_err(|error| anyhow::anyhow!("Invalid revert reason: {error}"))?;
+                let return_data_assertion = match (
+                    expected.return_data_size,
+                    expected.return_data_prefix,
+                ) {
+                    (None, None) => None,
+                    (size, prefix) => {
+                        if !return_data.is_empty() || revert_reason.is_some() {
+                            anyhow::bail!(
+                                "The return data size and prefix cannot be specified together with the return data or revert reason"
+                            );
+                        }
+                        Some(
+                            ReturnDataAssertion::try_from_matter_labs(size, prefix).map_err(
+                                |error| anyhow::anyhow!("Invalid return data assertion: {error}"),
+                            )?,
+                        )
+                    }
+                };
+                let exception = expected.exception || revert_reason.is_some();
                 let events = expected
                     .events
                     .into_iter()
@@ -86,7 +158,14 @@ impl Output {
                     })
                     .collect::<anyhow::Result<Vec<Event>>>()
                     .map_err(|error| anyhow::anyhow!("Invalid events: {}", error))?;
-                (return_data, exception, events)
+                (
+                    return_data,
+                    exception,
+                    events,
+                    revert_reason,
+                    return_data_assertion,
+                    expected.events_unordered,
+                )
             }
         };
         let return_data = Value::try_from_vec_matter_labs(return_data, instances, target)
@@ -96,6 +175,10 @@ impl Output {
             return_data,
             exception,
             events,
+            revert_reason,
+            return_data_assertion,
+            return_data_size: None,
+            events_unordered,
         })
     }
 
@@ -106,6 +189,7 @@ impl Output {
         expected: &[web3::types::U256],
         exception: bool,
         events: &[solidity_adapter::Event],
+        events_unordered: bool,
         contract_address: &web3::types::Address,
         target: era_compiler_common::Target,
     ) -> Self {
@@ -135,8 +219,50 @@ impl Output {
             return_data,
             exception,
             events,
+            revert_reason: None,
+            return_data_assertion: None,
+            return_data_size: None,
+            events_unordered,
         }
     }
+
+    ///
+    /// Checks whether every event has a distinct matching event in the other list.
+    ///
+    /// Wildcards may match several events, so a greedy search is not enough, and the
+    /// matching is found with augmenting paths.
+    ///
+    fn events_match_unordered(events_1: &[Event], events_2: &[Event]) -> bool {
+        fn augment(
+            index_1: usize,
+            events_1: &[Event],
+            events_2: &[Event],
+            visited: &mut [bool],
+            matches: &mut [Option<usize>],
+        ) -> bool {
+            for (index_2, event_2) in events_2.iter().enumerate() {
+                if visited[index_2] || &events_1[index_1] != event_2 {
+                    continue;
+                }
+                visited[index_2] = true;
+                let is_available = match matches[index_2] {
+                    Some(matched) => augment(matched, events_1, events_2, visited, matches),
+                    None => true,
+                };
+                if is_available {
+                    matches[index_2] = Some(index_1);
+                    return true;
+                }
+            }
+            false
+        }
+
+        let mut matches = vec![None; events_2.len()];
+        (0..events_1.len()).all(|index_1| {
+            let mut visited = vec![false; events_2.len()];
+            augment(index_1, events_1, events_2, &mut visited, &mut matches)
+        })
+    }
 }
 
 impl From<web3::types::U256> for Output {
@@ -145,6 +271,10 @@ impl From<web3::types::U256> for Output {
             return_data: vec![Value::Certain(value)],
             exception: false,
             events: vec![],
+            revert_reason: None,
+            return_data_assertion: None,
+            return_data_size: None,
+            events_unordered: false,
         }
     }
 }
@@ -178,6 +308,7 @@ impl From<zkevm_tester::compiler_tests::VmSnapshot> for Output {
 
         match snapshot.execution_result {
             zkevm_tester::compiler_tests::VmExecutionResult::Ok(return_data) => {
+                let return_data_size = return_data.len();
                 let return_data = return_data
                     .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
                     .map(|word| {
@@ -200,9 +331,14 @@ impl From<zkevm_tester::compiler_tests::VmSnapshot> for Output {
                     return_data,
                     exception: false,
                     events,
+                    revert_reason: None,
+                    return_data_assertion: None,
+                    return_data_size: Some(return_data_size),
+                    events_unordered: false,
                 }
             }
             zkevm_tester::compiler_tests::VmExecutionResult::Revert(return_data) => {
+                let return_data_size = return_data.len();
                 let return_data = return_data
                     .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
                     .map(|word| {
@@ -225,18 +361,30 @@ impl From<zkevm_tester::compiler_tests::VmSnapshot> for Output {
                     return_data,
                     exception: true,
                     events,
+                    revert_reason: None,
+                    return_data_assertion: None,
+                    return_data_size: Some(return_data_size),
+                    events_unordered: false,
                 }
             }
             zkevm_tester::compiler_tests::VmExecutionResult::Panic => Self {
                 return_data: vec![],
                 exception: true,
                 events,
+                revert_reason: None,
+                return_data_assertion: None,
+                return_data_size: None,
+                events_unordered: false,
             },
             zkevm_tester::compiler_tests::VmExecutionResult::MostLikelyDidNotFinish { .. } => {
                 Self {
                     return_data: vec![],
                     exception: true,
                     events,
+                    revert_reason: None,
+                    return_data_assertion: None,
+                    return_data_size: None,
+                    events_unordered: false,
                 }
             }
         }
@@ -245,6 +393,7 @@ impl From<zkevm_tester::compiler_tests::VmSnapshot> for Output {
 
 impl From<EVMOutput> for Output {
     fn from(output: EVMOutput) -> Self {
+        let return_data_size = output.return_data.len();
         let return_data = output
             .return_data
             .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
@@ -269,6 +418,10 @@ impl From<EVMOutput> for Output {
             return_data,
             exception: output.exception,
             events,
+            revert_reason: None,
+            return_data_assertion: None,
+            return_data_size: Some(return_data_size),
+            events_unordered: false,
         }
     }
 }
@@ -281,20 +434,45 @@ impl PartialEq<Self> for Output {
         if self.events.len() != other.events.len() {
             return false;
         }
-        if self.return_data.len() != other.return_data.len() {
-            return false;
-        }
 
-        for index in 0..self.return_data.len() {
-            if let (Value::Certain(value_1), Value::Certain(value_2)) =
-                (&self.return_data[index], &other.return_data[index])
-            {
-                if value_1 != value_2 {
-                    return false;
+        let revert_reason = match (&self.revert_reason, &other.revert_reason) {
+            (Some(expected), _) => Some((expected, other)),
+            (None, Some(expected)) => Some((expected, self)),
+            (None, None) => None,
+        };
+        let return_data_assertion =
+            match (&self.return_data_assertion, &other.return_data_assertion) {
+                (Some(expected), _) => Some((expected, other)),
+                (None, Some(expected)) => Some((expected, self)),
+                (None, None) => None,
+            };
+        if let Some((expected, found)) = revert_reason {
+            if RevertReason::decode(found.return_data.as_slice()).as_ref() != Some(expected) {
+                return false;
+            }
+        } else if let Some((expected, found)) = return_data_assertion {
+            if !expected.is_satisfied(found) {
+                return false;
+            }
+        } else {
+            if self.return_data.len() != other.return_data.len() {
+                return false;
+            }
+
+            for index in 0..self.return_data.len() {
+                if let (Value::Certain(value_1), Value::Certain(value_2)) =
+                    (&self.return_data[index], &other.return_data[index])
+                {
+                    if value_1 != value_2 {
+                        return false;
+                    }
                 }
             }
         }
 
+        if self.events_unordered || other.events_unordered {
+            return Self::events_match_unordered(self.events.as_slice(), other.events.as_slice());
+        }
         for index in 0..self.events.len() {
             if self.events[index] != other.events[index] {
                 return false;
@@ -304,3 +482,51 @@ impl PartialEq<Self> for Output {
         true
     }
 }
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::value::Value;
+
+    use super::event::Event;
+    use super::Output;
+
+    ///
+    /// Returns an event with a single topic, which is `*` if `None`.
+    ///
+    fn event(topic: Option<u64>) -> Event {
+        let topic = match topic {
+            Some(topic) => Value::Certain(web3::types::U256::from(topic)),
+            None => Value::Any,
+        };
+        Event::new(None, vec![topic], vec![])
+    }
+
+    #[test]
+    fn events_match_unordered() {
+        for (expected, actual, is_matched) in [
+            (vec![None, Some(1)], vec![Some(1), Some(2)], true),
+            (vec![None, None], vec![Some(1), Some(2)], true),
+            (vec![None, Some(2)], vec![Some(2), Some(3)], true),
+            (
+                vec![Some(1), None, Some(1)],
+                vec![Some(1), Some(1), Some(2)],
+                true,
+            ),
+            (vec![Some(1), Some(1)], vec![Some(1), Some(2)], false),
+            (
+                vec![Some(1), None, Some(1)],
+                vec![Some(1), Some(2), Some(2)],
+                false,
+            ),
+            (vec![None], vec![], false),
+        ] {
+            let expected: Vec<Event> = expected.into_iter().map(event).collect();
+            let actual: Vec<Event> = actual.into_iter().map(event).collect();
+
+            assert_eq!(
+                Output::events_match_unordered(expected.as_slice(), actual.as_slice()),
+                is_matched,
+                "{expected:?} and {actual:?}",
+            );
+        }
+    }
+}
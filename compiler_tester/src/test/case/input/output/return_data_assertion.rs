@@ -0,0 +1,238 @@
+//!
+//! The compiler test outcome return data assertion.
+//!
+
+use crate::test::case::input::output::Output;
+use crate::test::case::input::value::Value;
+
+///
+/// The compiler test outcome return data assertion.
+///
+/// Checks the size and prefix of the return data instead of its every word, e.g. for the large
+/// buffers ending with the metadata hash, which changes with every compiler version.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReturnDataAssertion {
+    /// The expected return data size in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    /// The expected return data prefix.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_hex"
+    )]
+    pub prefix: Vec<u8>,
+}
+
+impl ReturnDataAssertion {
+    ///
+    /// Try convert from Matter Labs compiler test metadata expected size and hexadecimal prefix.
+    ///
+    pub fn try_from_matter_labs(
+        size: Option<usize>,
+        prefix: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let prefix = match prefix {
+            Some(prefix) => {
+                let hex = prefix.strip_prefix("0x").ok_or_else(|| {
+                    anyhow::anyhow!("Expected a hexadecimal starting with `0x`, found `{prefix}`")
+                })?;
+                hex::decode(hex).map_err(|error| {
+                    anyhow::anyhow!("Hexadecimal value `{prefix}` decoding error: {error}")
+                })?
+            }
+            None => Vec::new(),
+        };
+        if let Some(size) = size {
+            if prefix.len() > size {
+                anyhow::bail!(
+                    "The return data prefix of {} bytes exceeds the return data size of {size} bytes",
+                    prefix.len()
+                );
+            }
+        }
+        Ok(Self { size, prefix })
+    }
+
+    ///
+    /// Whether the return data of the `found` output satisfies the assertion.
+    ///
+    pub fn is_satisfied(&self, found: &Output) -> bool {
+        let found_size = Self::found_size(found);
+        if let Some(size) = self.size {
+            if found_size != size {
+                return false;
+            }
+        }
+        if self.prefix.len() > found_size {
+            return false;
+        }
+        found
+            .return_data
+            .iter()
+            .flat_map(|value| {
+                let mut bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+                if let Value::Certain(value) = value {
+                    value.to_big_endian(&mut bytes);
+                }
+                bytes
+            })
+            .take(self.prefix.len())
+            .eq(self.prefix.iter().copied())
+    }
+
+    ///
+    /// Returns the return data size of the `found` output.
+    ///
+    /// If the exact size is unknown, the return data is assumed to be word-aligned.
+    ///
+    pub fn found_size(found: &Output) -> usize {
+        found
+            .return_data_size
+            .unwrap_or(found.return_data.len() * era_compiler_common::BYTE_LENGTH_FIELD)
+    }
+}
+
+///
+/// Serializes the `bytes` as a hexadecimal starting with `0x`.
+///
+fn serialize_hex<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(format!("0x{}", hex::encode(bytes)).as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::output::Output;
+    use crate::test::case::input::value::Value;
+
+    use super::ReturnDataAssertion;
+
+    ///
+    /// Returns an output with the `words` of return data, `size` bytes of which are returned.
+    ///
+    fn found(words: &[&str], size: Option<usize>) -> Output {
+        let return_data = words
+            .iter()
+            .map(|word| {
+                Value::Certain(
+                    web3::types::U256::from_str_radix(word, era_compiler_common::BASE_HEXADECIMAL)
+                        .expect("Always valid"),
+                )
+            })
+            .collect();
+        let mut output = Output::new(return_data, false, vec![]);
+        output.return_data_size = size;
+        output
+    }
+
+    #[test]
+    fn try_from_matter_labs() {
+        for (size, prefix, error) in [
+            (Some(64), Some("0x6080"), None),
+            (None, Some("0x"), None),
+            (Some(1), None, None),
+            (
+                None,
+                Some("6080"),
+                Some("Expected a hexadecimal starting with `0x`, found `6080`"),
+            ),
+            (
+                None,
+                Some("0x608"),
+                Some("Hexadecimal value `0x608` decoding error: Odd number of digits"),
+            ),
+            (
+                Some(1),
+                Some("0x6080"),
+                Some("The return data prefix of 2 bytes exceeds the return data size of 1 bytes"),
+            ),
+        ] {
+            let result = ReturnDataAssertion::try_from_matter_labs(size, prefix.map(str::to_owned));
+            match error {
+                Some(error) => assert_eq!(
+                    result.err().map(|error| error.to_string()).as_deref(),
+                    Some(error),
+                    "Invalid validation of {size:?} {prefix:?}"
+                ),
+                None => assert!(result.is_ok(), "Invalid validation of {size:?} {prefix:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn is_satisfied() {
+        let words = [
+            "6080604052348015600e575f5ffd5b50603e80601a5f395ff3fe60806040525f",
+            "fdfea2646970667358221220aabbccddeeff00112233445566778899aabbccdd",
+        ];
+        for (size, prefix, found_size, is_satisfied) in [
+            (Some(64), None, Some(64), true),
+            (Some(64), None, Some(63), false),
+            (Some(63), None, Some(63), true),
+            (Some(64), None, None, true),
+            (None, Some("0x60806040"), Some(63), true),
+            (Some(63), Some("0x60806040"), Some(63), true),
+            (Some(63), Some("0x60806041"), Some(63), false),
+            (None, Some("0x608060405234"), Some(4), false),
+            (
+                None,
+                Some("0x6080604052348015600e575f5ffd5b50603e80601a5f395ff3fe60806040525ffdfe"),
+                Some(64),
+                true,
+            ),
+        ] {
+            let assertion =
+                ReturnDataAssertion::try_from_matter_labs(size, prefix.map(str::to_owned))
+                    .expect("Always valid");
+            assert_eq!(
+                assertion.is_satisfied(&found(&words, found_size)),
+                is_satisfied,
+                "{size:?} {prefix:?} {found_size:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn output_eq() {
+        let expected = Output {
+            return_data_assertion: Some(
+                ReturnDataAssertion::try_from_matter_labs(Some(33), Some("0x2a".to_owned()))
+                    .expect("Always valid"),
+            ),
+            ..Output::default()
+        };
+        for (words, size, is_equal) in [
+            (
+                vec![
+                    "2a00000000000000000000000000000000000000000000000000000000000000",
+                    "0",
+                ],
+                Some(33),
+                true,
+            ),
+            (
+                vec![
+                    "2a00000000000000000000000000000000000000000000000000000000000000",
+                    "0",
+                ],
+                Some(64),
+                false,
+            ),
+            (
+                vec![
+                    "2b00000000000000000000000000000000000000000000000000000000000000",
+                    "0",
+                ],
+                Some(33),
+                false,
+            ),
+        ] {
+            let found = found(words.as_slice(), size);
+            assert_eq!(expected == found, is_equal, "{words:?} {size:?}");
+            assert_eq!(found == expected, is_equal, "{words:?} {size:?}");
+        }
+    }
+}
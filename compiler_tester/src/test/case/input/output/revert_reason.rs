@@ -0,0 +1,438 @@
+//!
+//! The compiler test outcome revert reason.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::directories::matter_labs::test::metadata::case::input::expected::variant::extended::revert_reason::RevertReason as MatterLabsTestExpectedRevertReason;
+use crate::test::case::input::value::Value;
+use crate::test::instance::Instance;
+
+///
+/// The compiler test outcome revert reason.
+///
+/// The panics are represented as custom errors with the `Panic(uint256)` selector.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub enum RevertReason {
+    /// The `Error(string)` message.
+    Error(String),
+    /// The custom error with static arguments.
+    Custom {
+        /// The hexadecimal error selector.
+        selector: String,
+        /// The error signature, if known from the test metadata or the contract ABIs.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        signature: Option<String>,
+        /// The error arguments.
+        args: Vec<Value>,
+    },
+}
+
+impl RevertReason {
+    /// The `Error(string)` selector.
+    pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+    /// The `Panic(uint256)` signature.
+    pub const PANIC_SIGNATURE: &'static str = "Panic(uint256)";
+
+    ///
+    /// Try convert from Matter Labs compiler test metadata expected revert reason.
+    ///
+    /// The custom error may be specified by its signature, its selector, or only its name,
+    /// which is resolved through the `custom_errors` declared in the contract ABIs.
+    ///
+    pub fn try_from_matter_labs(
+        reason: MatterLabsTestExpectedRevertReason,
+        instances: &BTreeMap<String, Instance>,
+        custom_errors: &BTreeMap<String, BTreeSet<String>>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<Self> {
+        match reason {
+            MatterLabsTestExpectedRevertReason::Message(message) => Ok(Self::Error(message)),
+            MatterLabsTestExpectedRevertReason::Error { error, args } => {
+                let signature = match error.strip_prefix("0x") {
+                    Some(selector) if selector.len() == 8 => {
+                        hex::decode(selector).map_err(|error| {
+                            anyhow::anyhow!("Invalid error selector `{selector}`: {error}")
+                        })?;
+                        None
+                    }
+                    Some(_) => anyhow::bail!("Error selector `{error}` must be 4 bytes long"),
+                    None if error.contains('(') => Some(error.clone()),
+                    None => Some(Self::resolve_signature(error.as_str(), custom_errors)?),
+                };
+                let selector = match signature.as_ref() {
+                    Some(signature) => hex::encode(crate::utils::selector(signature.as_str())),
+                    None => error[2..].to_lowercase(),
+                };
+                let args = Value::try_from_vec_matter_labs(args, instances, target)
+                    .map_err(|error| anyhow::anyhow!("Invalid error arguments: {error}"))?;
+                Ok(Self::Custom {
+                    selector,
+                    signature,
+                    args,
+                })
+            }
+        }
+    }
+
+    ///
+    /// Returns the signatures of the custom errors declared in the contract `abis` by their names.
+    ///
+    /// The names are not unique, as the errors may be overloaded or declared by several contracts.
+    ///
+    pub fn custom_errors(
+        abis: Option<&BTreeMap<String, serde_json::Value>>,
+    ) -> BTreeMap<String, BTreeSet<String>> {
+        let mut custom_errors: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for entry in abis
+            .into_iter()
+            .flat_map(|abis| abis.values())
+            .filter_map(serde_json::Value::as_array)
+            .flatten()
+        {
+            if entry.get("type").and_then(serde_json::Value::as_str) != Some("error") {
+                continue;
+            }
+            let name = match entry.get("name").and_then(serde_json::Value::as_str) {
+                Some(name) => name,
+                None => continue,
+            };
+            let types = match Self::abi_types(entry.get("inputs")) {
+                Some(types) => types,
+                None => continue,
+            };
+            custom_errors
+                .entry(name.to_owned())
+                .or_default()
+                .insert(format!("{name}({types})"));
+        }
+        custom_errors
+    }
+
+    ///
+    /// Returns the signature of the custom error `name` declared in the contract ABIs.
+    ///
+    fn resolve_signature(
+        name: &str,
+        custom_errors: &BTreeMap<String, BTreeSet<String>>,
+    ) -> anyhow::Result<String> {
+        if Some(name) == Self::PANIC_SIGNATURE.split('(').next() {
+            return Ok(Self::PANIC_SIGNATURE.to_owned());
+        }
+        match custom_errors.get(name) {
+            Some(signatures) if signatures.len() == 1 => {
+                Ok(signatures.first().expect("Always exists").to_owned())
+            }
+            Some(signatures) => anyhow::bail!(
+                "Custom error `{name}` is ambiguous, specify one of the signatures: {}",
+                signatures
+                    .iter()
+                    .map(|signature| format!("`{signature}`"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            None => anyhow::bail!("Custom error `{name}` is not declared in the contract ABIs"),
+        }
+    }
+
+    ///
+    /// Returns the canonical comma-separated types of the ABI `parameters`.
+    ///
+    /// The tuples are expanded into their component types, keeping the array suffixes.
+    ///
+    fn abi_types(parameters: Option<&serde_json::Value>) -> Option<String> {
+        let parameters = match parameters {
+            Some(parameters) => parameters.as_array()?,
+            None => return Some(String::new()),
+        };
+        let types = parameters
+            .iter()
+            .map(|parameter| {
+                let r#type = parameter.get("type")?.as_str()?;
+                match r#type.strip_prefix("tuple") {
+                    Some(suffix) => Some(format!(
+                        "({}){suffix}",
+                        Self::abi_types(parameter.get("components"))?
+                    )),
+                    None => Some(r#type.to_owned()),
+                }
+            })
+            .collect::<Option<Vec<String>>>()?;
+        Some(types.join(","))
+    }
+
+    ///
+    /// Takes the signature of the `expected` custom error with the same selector, if unknown.
+    ///
+    /// Used to describe the decoded revert data, which only contains the selector.
+    ///
+    pub fn with_signature_of(mut self, expected: &Self) -> Self {
+        if let (
+            Self::Custom {
+                selector,
+                signature: signature @ None,
+                ..
+            },
+            Self::Custom {
+                selector: expected_selector,
+                signature: Some(expected_signature),
+                ..
+            },
+        ) = (&mut self, expected)
+        {
+            if *selector == *expected_selector {
+                *signature = Some(expected_signature.to_owned());
+            }
+        }
+        self
+    }
+
+    ///
+    /// Decodes the revert reason from the revert data words.
+    ///
+    /// Returns `None` if the data is too short to contain a selector, or the `Error(string)`
+    /// payload is malformed.
+    ///
+    pub fn decode(return_data: &[Value]) -> Option<Self> {
+        let mut bytes =
+            Vec::with_capacity(return_data.len() * era_compiler_common::BYTE_LENGTH_FIELD);
+        for word in return_data.iter() {
+            match word {
+                Value::Certain(value) => {
+                    bytes.extend(crate::utils::u256_to_h256(value).to_fixed_bytes())
+                }
+                Value::Any => return None,
+            }
+        }
+
+        let selector = bytes.get(..4)?;
+        let payload = &bytes[4..];
+        if selector == Self::ERROR_SELECTOR {
+            let offset = Self::word_at(payload, 0)?;
+            let length = Self::word_at(payload, offset)?;
+            let start = offset.checked_add(era_compiler_common::BYTE_LENGTH_FIELD)?;
+            let message = payload.get(start..start.checked_add(length)?)?;
+            return Some(Self::Error(String::from_utf8_lossy(message).to_string()));
+        }
+
+        let args = payload
+            .chunks_exact(era_compiler_common::BYTE_LENGTH_FIELD)
+            .map(|word| Value::Certain(web3::types::U256::from_big_endian(word)))
+            .collect();
+        let signature = (selector == crate::utils::selector(Self::PANIC_SIGNATURE))
+            .then(|| Self::PANIC_SIGNATURE.to_owned());
+        Some(Self::Custom {
+            selector: hex::encode(selector),
+            signature,
+            args,
+        })
+    }
+
+    ///
+    /// Reads the word at the byte `offset` of `payload` as a `usize`.
+    ///
+    fn word_at(payload: &[u8], offset: usize) -> Option<usize> {
+        let word =
+            payload.get(offset..offset.checked_add(era_compiler_common::BYTE_LENGTH_FIELD)?)?;
+        usize::try_from(web3::types::U256::from_big_endian(word)).ok()
+    }
+}
+
+impl PartialEq<Self> for RevertReason {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Error(message_1), Self::Error(message_2)) => message_1 == message_2,
+            (
+                Self::Custom {
+                    selector: selector_1,
+                    args: args_1,
+                    ..
+                },
+                Self::Custom {
+                    selector: selector_2,
+                    args: args_2,
+                    ..
+                },
+            ) => {
+                selector_1 == selector_2
+                    && args_1.len() == args_2.len()
+                    && args_1.iter().zip(args_2.iter()).all(|pair| match pair {
+                        (Value::Certain(value_1), Value::Certain(value_2)) => value_1 == value_2,
+                        _ => true,
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error(message) => write!(f, "Error({message:?})"),
+            Self::Custom {
+                selector,
+                signature,
+                args,
+            } => {
+                match signature {
+                    Some(signature) => write!(f, "{signature}")?,
+                    None => write!(f, "0x{selector}")?,
+                }
+                if !args.is_empty() {
+                    let args = args
+                        .iter()
+                        .map(|arg| match arg {
+                            Value::Certain(value) => format!("{value:#x}"),
+                            Value::Any => "*".to_owned(),
+                        })
+                        .collect::<Vec<String>>();
+                    write!(f, " [{}]", args.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+
+    use crate::directories::matter_labs::test::metadata::case::input::expected::variant::extended::revert_reason::RevertReason as MatterLabsTestExpectedRevertReason;
+    use crate::test::case::input::value::Value;
+
+    use super::RevertReason;
+
+    ///
+    /// Returns the custom errors of a contract declaring an overloaded error and a tuple one.
+    ///
+    fn custom_errors() -> BTreeMap<String, BTreeSet<String>> {
+        let abi = serde_json::json!([
+            { "type": "function", "name": "f", "inputs": [] },
+            { "type": "error", "name": "Unauthorized", "inputs": [] },
+            {
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [{ "type": "uint256" }, { "type": "uint256" }]
+            },
+            {
+                "type": "error",
+                "name": "Invalid",
+                "inputs": [{ "type": "uint8" }]
+            },
+            {
+                "type": "error",
+                "name": "Invalid",
+                "inputs": [{
+                    "type": "tuple[]",
+                    "components": [{ "type": "address" }, { "type": "bytes32" }]
+                }]
+            }
+        ]);
+        RevertReason::custom_errors(Some(&BTreeMap::from([("test.sol:Test".to_owned(), abi)])))
+    }
+
+    #[test]
+    fn custom_errors_signatures() {
+        let custom_errors = custom_errors();
+        let signatures: Vec<&str> = custom_errors
+            .values()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            signatures,
+            vec![
+                "InsufficientBalance(uint256,uint256)",
+                "Invalid((address,bytes32)[])",
+                "Invalid(uint8)",
+                "Unauthorized()",
+            ]
+        );
+    }
+
+    #[test]
+    fn try_from_matter_labs() {
+        let custom_errors = custom_errors();
+        for (error, expected) in [
+            (
+                "InsufficientBalance",
+                Ok("InsufficientBalance(uint256,uint256)"),
+            ),
+            ("Unauthorized()", Ok("Unauthorized()")),
+            ("Panic", Ok("Panic(uint256)")),
+            ("0x82b42900", Ok("0x82b42900")),
+            ("Invalid", Err("is ambiguous")),
+            ("Missing", Err("is not declared")),
+            ("0x82b429", Err("must be 4 bytes long")),
+        ] {
+            let reason = MatterLabsTestExpectedRevertReason::Error {
+                error: error.to_owned(),
+                args: vec![],
+            };
+            let result = RevertReason::try_from_matter_labs(
+                reason,
+                &BTreeMap::new(),
+                &custom_errors,
+                era_compiler_common::Target::EVM,
+            );
+            match (result, expected) {
+                (Ok(reason), Ok(expected)) => {
+                    assert_eq!(reason.to_string(), expected, "Error `{error}`")
+                }
+                (Err(error), Err(expected)) => assert!(
+                    error.to_string().contains(expected),
+                    "Error `{error}` does not contain `{expected}`"
+                ),
+                (result, expected) => {
+                    panic!("Error `{error}`: expected {expected:?}, found {result:?}")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let words = |data: &str| -> Vec<Value> {
+            let mut bytes = hex::decode(data).expect("Always valid");
+            bytes.resize(bytes.len().div_ceil(32) * 32, 0);
+            bytes
+                .chunks(32)
+                .map(|word| Value::Certain(web3::types::U256::from_big_endian(word)))
+                .collect()
+        };
+        let unauthorized = RevertReason::try_from_matter_labs(
+            MatterLabsTestExpectedRevertReason::Error {
+                error: "Unauthorized".to_owned(),
+                args: vec![],
+            },
+            &BTreeMap::new(),
+            &custom_errors(),
+            era_compiler_common::Target::EVM,
+        )
+        .expect("Always valid");
+
+        for (data, expected) in [
+            (
+                format!("08c379a0{:064x}{:064x}{}", 0x20, 2, hex::encode("no")),
+                Some("Error(\"no\")"),
+            ),
+            (
+                format!("4e487b71{:064x}", 0x11),
+                Some("Panic(uint256) [0x11]"),
+            ),
+            ("82b42900".to_owned(), Some("Unauthorized()")),
+            ("deadbeef".to_owned(), Some("0xdeadbeef")),
+            (String::new(), None),
+        ] {
+            let found = RevertReason::decode(words(data.as_str()).as_slice())
+                .map(|found| found.with_signature_of(&unauthorized).to_string());
+            assert_eq!(found.as_deref(), expected, "Revert data `{data}`");
+        }
+    }
+}
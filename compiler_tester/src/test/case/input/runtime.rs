@@ -2,32 +2,41 @@
 //! The contract call input variant.
 //!
 
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use revm::primitives::EVMError;
 use revm::primitives::ExecutionResult;
+use sha3::Digest;
 use solidity_adapter::EVMVersion;
 
+use crate::summary::element::outcome::error_code::ErrorCode;
 use crate::summary::Summary;
+use crate::test::case::input::access_list::AccessList;
+use crate::test::case::input::call_kind::CallKind;
 use crate::test::case::input::calldata::Calldata;
+use crate::test::case::input::context_overrides::ContextOverrides;
+use crate::test::case::input::fixture::Fixture;
 use crate::test::case::input::identifier::InputIdentifier;
 use crate::test::case::input::output::Output;
 use crate::test::case::input::storage::Storage;
+use crate::test::case::input::value::Value;
 use crate::test::context::input::InputContext;
 use crate::test::description::TestDescription;
 use crate::vm::eravm::system_context::SystemContext;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::EVM;
 
-use crate::vm::revm::revm_type_conversions::revm_bytes_to_vec_value;
+use crate::vm::revm::revm_type_conversions::transform_revert_output;
 use crate::vm::revm::revm_type_conversions::transform_success_output;
+use crate::vm::revm::trace::Trace;
 use crate::vm::revm::Revm;
 
 ///
 /// The contract call input variant.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Runtime {
     /// The input name.
     name: String,
@@ -38,11 +47,27 @@ pub struct Runtime {
     /// The caller.
     caller: web3::types::Address,
     /// The value in wei.
-    value: Option<u128>,
+    value: Option<web3::types::U256>,
+    /// The call kind.
+    call_kind: CallKind,
+    /// The gas limit of the call on the EVM interpreter, if overridden.
+    evm_gas_limit: Option<u64>,
+    /// The context overrides of the call on the EVM interpreter.
+    context_overrides: ContextOverrides,
     /// The contracts storage to set before running.
     storage: Storage,
+    /// The addresses and storage keys pre-warmed by the transaction access list on REVM.
+    access_list: AccessList,
     /// The expected output.
     expected: Output,
+    /// The placeholder address of the instance registered with the returned address, if any.
+    register: Option<web3::types::Address>,
+    /// Whether the value is passed via the `msg.value` simulator on EraVM, if overridden.
+    system_mode: Option<bool>,
+    /// The fixture kind, if the call is a setup or teardown fixture.
+    fixture: Option<Fixture>,
+    /// Whether the output is checked against the expected one, or only has to be successful.
+    is_expected_checked: bool,
 }
 
 impl Runtime {
@@ -54,7 +79,9 @@ impl Runtime {
         address: web3::types::Address,
         calldata: Calldata,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
+        call_kind: CallKind,
+        evm_gas_limit: Option<u64>,
         storage: Storage,
         expected: Output,
     ) -> Self {
@@ -64,22 +91,187 @@ impl Runtime {
             calldata,
             caller,
             value,
+            call_kind,
+            evm_gas_limit,
+            context_overrides: ContextOverrides::default(),
             storage,
+            access_list: AccessList::default(),
             expected,
+            register: None,
+            system_mode: None,
+            fixture: None,
+            is_expected_checked: true,
+        }
+    }
+
+    ///
+    /// Marks the call as a setup or teardown fixture, which is excluded from the benchmarks.
+    ///
+    /// The return data is only checked if `is_expected_checked`, as fixtures are usually only
+    /// required to succeed. The name is prefixed with the fixture kind, so that the fixtures
+    /// are told apart from the main inputs with the same indexes.
+    ///
+    pub fn set_fixture(&mut self, fixture: Fixture, is_expected_checked: bool) {
+        self.name = format!("#{fixture}:{}", self.name);
+        self.fixture = Some(fixture);
+        self.is_expected_checked = is_expected_checked;
+    }
+
+    ///
+    /// Overrides whether the value is passed via the `msg.value` simulator on EraVM, e.g. for
+    /// the calls testing the raw value transfers.
+    ///
+    pub fn set_system_mode(&mut self, system_mode: bool) {
+        self.system_mode = Some(system_mode);
+    }
+
+    ///
+    /// Sets the addresses and storage keys pre-warmed by the transaction access list on REVM.
+    ///
+    pub fn set_access_list(&mut self, access_list: AccessList) {
+        self.access_list = access_list;
+    }
+
+    ///
+    /// Sets the context overrides of the call on the EVM interpreter.
+    ///
+    pub fn set_context_overrides(&mut self, context_overrides: ContextOverrides) {
+        self.context_overrides = context_overrides;
+    }
+
+    ///
+    /// Registers the returned address as the address of `instance` for the subsequent inputs.
+    ///
+    pub fn set_register(&mut self, instance: &str) {
+        self.register = Some(Self::registration_placeholder(instance));
+    }
+
+    ///
+    /// Returns the placeholder address of a registered `instance`, which is used while building
+    /// the subsequent inputs and replaced with the registered address before running them.
+    ///
+    pub fn registration_placeholder(instance: &str) -> web3::types::Address {
+        let hash = sha3::Keccak256::digest(format!("#register:{instance}").as_bytes());
+        web3::types::Address::from_slice(
+            &hash[era_compiler_common::BYTE_LENGTH_FIELD
+                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..],
+        )
+    }
+
+    ///
+    /// Replaces the registration placeholders with the registered addresses in the callee address,
+    /// calldata, and expected output.
+    ///
+    fn substitute_addresses(
+        &mut self,
+        addresses: &HashMap<web3::types::Address, web3::types::Address>,
+    ) {
+        for (placeholder, address) in addresses.iter() {
+            if self.address == *placeholder {
+                self.address = *address;
+            }
+            let placeholder = placeholder.as_bytes();
+            let mut offset = 0;
+            while let Some(position) = self.calldata.inner[offset..]
+                .windows(placeholder.len())
+                .position(|window| window == placeholder)
+            {
+                let start = offset + position;
+                self.calldata.inner[start..start + placeholder.len()]
+                    .copy_from_slice(address.as_bytes());
+                offset = start + placeholder.len();
+            }
+        }
+        self.expected.substitute_addresses(addresses);
+    }
+
+    ///
+    /// Whether the call `output` is the expected one.
+    ///
+    /// The fixture calls without the expected output only have to succeed.
+    ///
+    fn is_output_valid(is_expected_checked: bool, expected: &Output, output: &Output) -> bool {
+        if is_expected_checked {
+            output == expected
+        } else {
+            !output.exception
+        }
+    }
+
+    ///
+    /// Reports a passed call.
+    ///
+    /// The fixture calls are reported as special passes, so they are excluded from the benchmarks.
+    ///
+    fn passed(
+        summary: Arc<Mutex<Summary>>,
+        test: TestDescription,
+        is_fixture: bool,
+        cycles: usize,
+        ergs: u64,
+        gas: u64,
+        stack_depth: Option<usize>,
+        pubdata: Option<usize>,
+        cold_accesses: Option<usize>,
+        warm_accesses: Option<usize>,
+    ) {
+        if is_fixture {
+            Summary::passed_special(summary, test);
+        } else {
+            Summary::passed_runtime(
+                summary,
+                test,
+                cycles,
+                ergs,
+                gas,
+                stack_depth,
+                pubdata,
+                cold_accesses,
+                warm_accesses,
+            );
         }
     }
 }
 
 impl Runtime {
+    ///
+    /// Checks that the input does not use the metadata only supported on REVM.
+    ///
+    fn check_revm_metadata(&self) -> Result<(), &'static str> {
+        if self.register.is_some() {
+            return Err("Instance registration is only supported on REVM");
+        }
+        if !self.access_list.is_empty() {
+            return Err("The access lists are only supported on REVM");
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks that the input does not use the metadata only supported on the EVM interpreter.
+    ///
+    fn check_evm_interpreter_metadata(&self) -> Result<(), &'static str> {
+        if !self.context_overrides.is_empty() {
+            return Err("The EVM context overrides are only supported on the EVM interpreter");
+        }
+        Ok(())
+    }
+
     ///
     /// Runs the call on EraVM.
     ///
     pub fn run_eravm<const M: bool>(
-        self,
+        mut self,
         summary: Arc<Mutex<Summary>>,
         vm: &mut EraVM,
         context: InputContext<'_>,
     ) {
+        match self.system_mode.take() {
+            Some(true) if !M => return self.run_eravm::<true>(summary, vm, context),
+            Some(false) if M => return self.run_eravm::<false>(summary, vm, context),
+            _ => {}
+        }
+
         let group = context.case_context.group.clone();
         let input_index = context.selector;
         let test = TestDescription::from_context(
@@ -89,39 +281,79 @@ impl Runtime {
                 name: self.name,
             },
         );
+        if let Err(error) = self
+            .check_revm_metadata()
+            .and_then(|()| self.check_evm_interpreter_metadata())
+        {
+            Summary::invalid(summary, test, ErrorCode::InvalidMetadata, error);
+            return;
+        }
         let name = test.selector.to_string();
         vm.populate_storage(self.storage.inner);
-        let vm_function = match group.as_deref() {
-            Some(benchmark_analyzer::Benchmark::EVM_INTERPRETER_GROUP_NAME) => {
-                EraVM::execute_evm_interpreter::<M>
-            }
-            _ => EraVM::execute::<M>,
+        let result = match self.call_kind {
+            CallKind::Call => match group.as_deref() {
+                Some(benchmark_analyzer::Benchmark::EVM_INTERPRETER_GROUP_NAME) => vm
+                    .execute_evm_interpreter::<M>(
+                        name,
+                        self.address,
+                        self.caller,
+                        self.value,
+                        self.calldata.inner.clone(),
+                        None,
+                        self.evm_gas_limit,
+                    ),
+                _ => vm.execute::<M>(
+                    name,
+                    self.address,
+                    self.caller,
+                    self.value,
+                    self.calldata.inner.clone(),
+                    None,
+                ),
+            },
+            CallKind::DelegateCall => vm.execute_delegatecall(
+                name,
+                self.address,
+                self.call_kind.proxy_address().expect("Always exists"),
+                self.caller,
+                self.calldata.inner.clone(),
+            ),
+            CallKind::StaticCall => vm.execute_staticcall::<M>(
+                name,
+                self.address,
+                self.call_kind.proxy_address().expect("Always exists"),
+                self.calldata.inner.clone(),
+            ),
         };
-        let result = match vm_function(
-            vm,
-            name,
-            self.address,
-            self.caller,
-            self.value,
-            self.calldata.inner.clone(),
-            None,
-        ) {
+        let result = match result {
             Ok(result) => result,
             Err(error) => {
-                Summary::invalid(summary, test, error);
+                Summary::invalid(summary, test, ErrorCode::ExecutionFailed, error);
                 return;
             }
         };
 
-        if result.output == self.expected {
-            Summary::passed_runtime(summary, test, result.cycles, result.ergs, result.gas);
+        if Self::is_output_valid(self.is_expected_checked, &self.expected, &result.output) {
+            Self::passed(
+                summary,
+                test,
+                self.fixture.is_some(),
+                result.cycles,
+                result.ergs,
+                result.gas,
+                result.stack_depth,
+                result.pubdata,
+                None,
+                None,
+            );
         } else {
-            Summary::failed(
+            Summary::failed_with_storage(
                 summary,
                 test,
                 self.expected,
                 result.output,
                 self.calldata.inner,
+                vm.storage_excerpt(&[self.address]),
             );
         }
     }
@@ -143,6 +375,13 @@ impl Runtime {
                 name: self.name,
             },
         );
+        if let Err(error) = self
+            .check_revm_metadata()
+            .and_then(|()| self.check_evm_interpreter_metadata())
+        {
+            Summary::invalid(summary, test, ErrorCode::InvalidMetadata, error);
+            return;
+        }
         let name = test.selector.to_string();
         vm.populate_storage(self.storage.inner);
         let result = match vm.execute_runtime_code(
@@ -154,12 +393,23 @@ impl Runtime {
         ) {
             Ok(execution_result) => execution_result,
             Err(error) => {
-                Summary::invalid(summary, test, error);
+                Summary::invalid(summary, test, ErrorCode::ExecutionFailed, error);
                 return;
             }
         };
-        if result.output == self.expected {
-            Summary::passed_runtime(summary, test, result.cycles, result.ergs, result.gas);
+        if Self::is_output_valid(self.is_expected_checked, &self.expected, &result.output) {
+            Self::passed(
+                summary,
+                test,
+                self.fixture.is_some(),
+                result.cycles,
+                result.ergs,
+                result.gas,
+                result.stack_depth,
+                result.pubdata,
+                None,
+                None,
+            );
         } else {
             Summary::failed(
                 summary,
@@ -175,12 +425,16 @@ impl Runtime {
     /// Runs the call on REVM.
     ///
     pub fn run_revm<'b>(
-        self,
+        mut self,
         summary: Arc<Mutex<Summary>>,
         vm: Revm<'b>,
         evm_version: Option<EVMVersion>,
         context: InputContext<'_>,
+        registered_addresses: &mut HashMap<web3::types::Address, web3::types::Address>,
     ) -> Revm<'b> {
+        self.substitute_addresses(registered_addresses);
+
+        let trace_directory = context.case_context.revm_trace_directory;
         let input_index = context.selector;
         let test = TestDescription::from_context(
             context,
@@ -189,6 +443,10 @@ impl Runtime {
                 name: self.name,
             },
         );
+        if let Err(error) = self.check_evm_interpreter_metadata() {
+            Summary::invalid(summary, test, ErrorCode::InvalidMetadata, error);
+            return vm;
+        }
 
         // On revm we can't send a tx with a tx_origin different from the tx_sender,
         // this specific test expects tx_origin to be that value, so we change the sender
@@ -199,22 +457,36 @@ impl Runtime {
         }
 
         let rich_addresses = SystemContext::get_rich_addresses();
-        let vm = if rich_addresses.contains(&caller) {
+        let mut vm = if rich_addresses.contains(&caller) {
             vm.update_runtime_balance(caller)
         } else {
             vm
         };
+        vm.populate_storage(self.storage.inner);
 
-        let mut vm = vm.fill_runtime_new_transaction(
-            self.address,
-            caller,
-            self.calldata.clone(),
-            self.value,
-            evm_version,
-        );
+        let (address, calldata) = match (
+            self.call_kind.proxy_address(),
+            self.call_kind.evm_proxy_bytecode(),
+        ) {
+            (Some(proxy_address), Some(proxy_bytecode)) => {
+                vm.install_call_proxy(&proxy_address, proxy_bytecode);
+                let mut calldata = crate::utils::address_to_h256(&self.address)
+                    .to_fixed_bytes()
+                    .to_vec();
+                calldata.extend_from_slice(self.calldata.inner.as_slice());
+                (proxy_address, Calldata { inner: calldata })
+            }
+            _ => (self.address, self.calldata.clone()),
+        };
+
+        let mut vm =
+            vm.fill_runtime_new_transaction(address, caller, calldata, self.value, evm_version);
         vm = vm.update_balance_if_lack_of_funds(caller);
+        vm.set_access_list(&self.access_list);
 
-        let result = match vm.state.transact_commit() {
+        let mut trace = trace_directory.map(|_| Trace::new(&vm));
+        let (mut vm, result, accesses) = vm.transact_commit_with_accesses(trace.as_mut());
+        let result = match result {
             Ok(result) => result,
             Err(error) => {
                 let error_msg = match error {
@@ -225,7 +497,7 @@ impl Runtime {
                     EVMError::Precompile(error) => format!("Error on Precompile: {error:?}"),
                 };
 
-                Summary::invalid(summary.clone(), test, error_msg);
+                Summary::invalid(summary.clone(), test, ErrorCode::ExecutionFailed, error_msg);
                 return vm;
             }
         };
@@ -245,20 +517,80 @@ impl Runtime {
                 (transform_success_output(output, logs), gas_used, None)
             }
             ExecutionResult::Revert { gas_used, output } => {
-                let return_data_value = revm_bytes_to_vec_value(output);
-                (Output::new(return_data_value, true, vec![]), gas_used, None)
+                (transform_revert_output(output), gas_used, None)
             }
             ExecutionResult::Halt { reason, gas_used } => {
                 (Output::new(vec![], true, vec![]), gas_used, Some(reason))
             }
         };
 
-        if output == self.expected {
-            Summary::passed_runtime(summary, test, 0, 0, gas);
+        if let (Some(placeholder), false, Some(Value::Certain(address))) =
+            (self.register, output.exception, output.return_data.first())
+        {
+            registered_addresses.insert(placeholder, crate::utils::u256_to_address(address));
+        }
+
+        let is_output_valid =
+            Self::is_output_valid(self.is_expected_checked, &self.expected, &output);
+        if !is_output_valid {
+            if let (Some(trace), Some(trace_directory)) = (trace, trace_directory) {
+                if let Err(error) =
+                    trace.write(&vm, trace_directory, Trace::test_name(&test).as_str())
+                {
+                    eprintln!("REVM trace writing error: {error}");
+                }
+            }
+        }
+
+        Summary::record_expectation(
+            summary.clone(),
+            test.selector.path.as_str(),
+            input_index,
+            solidity_adapter::Expectation::new(
+                output
+                    .return_data
+                    .iter()
+                    .map(|value| *value.unwrap_certain_as_ref())
+                    .collect(),
+                output.exception,
+                gas,
+                output == self.expected,
+            ),
+        );
+
+        if is_output_valid {
+            Self::passed(
+                summary,
+                test,
+                self.fixture.is_some(),
+                0,
+                0,
+                gas,
+                None,
+                None,
+                None,
+                accesses.as_ref().map(|accesses| accesses.cold),
+                accesses.as_ref().map(|accesses| accesses.warm),
+            );
         } else if let Some(error) = error {
-            Summary::invalid(summary, test, format!("{error:?}"));
+            Summary::invalid(
+                summary,
+                test,
+                ErrorCode::ExecutionFailed,
+                format!("{error:?}"),
+            );
         } else {
-            Summary::failed(summary, test, self.expected, output, self.calldata.inner);
+            let storage = accesses
+                .map(|accesses| vm.storage_accesses(&accesses.storage))
+                .unwrap_or_default();
+            Summary::failed_with_storage(
+                summary,
+                test,
+                self.expected,
+                output,
+                self.calldata.inner,
+                storage,
+            );
         };
         vm
     }
@@ -267,11 +599,17 @@ impl Runtime {
     /// Runs the call on EVM interpreter.
     ///
     pub fn run_evm_interpreter<const M: bool>(
-        self,
+        mut self,
         summary: Arc<Mutex<Summary>>,
         vm: &mut EraVM,
         context: InputContext<'_>,
     ) {
+        match self.system_mode.take() {
+            Some(true) if !M => return self.run_evm_interpreter::<true>(summary, vm, context),
+            Some(false) if M => return self.run_evm_interpreter::<false>(summary, vm, context),
+            _ => {}
+        }
+
         let input_index = context.selector;
         let test = TestDescription::from_context(
             context,
@@ -280,32 +618,78 @@ impl Runtime {
                 name: self.name,
             },
         );
+        if let Err(error) = self.check_revm_metadata() {
+            Summary::invalid(summary, test, ErrorCode::InvalidMetadata, error);
+            return;
+        }
         let name = test.selector.to_string();
         vm.populate_storage(self.storage.inner);
-        let result = match vm.execute_evm_interpreter::<M>(
+        let overridden_context =
+            vm.override_system_context(self.context_overrides.system_context_values());
+        let result = vm.execute_evm_interpreter::<M>(
             name,
             self.address,
             self.caller,
             self.value,
             self.calldata.inner.clone(),
             None,
-        ) {
+            self.evm_gas_limit,
+        );
+        vm.restore_system_context(overridden_context);
+        let result = match result {
             Ok(result) => result,
             Err(error) => {
-                Summary::invalid(summary, test, error);
+                Summary::invalid(summary, test, ErrorCode::ExecutionFailed, error);
                 return;
             }
         };
 
-        if result.output == self.expected {
-            Summary::passed_runtime(summary, test, result.cycles, result.ergs, result.gas);
+        if Self::is_output_valid(self.is_expected_checked, &self.expected, &result.output) {
+            Self::passed(
+                summary,
+                test,
+                self.fixture.is_some(),
+                result.cycles,
+                result.ergs,
+                result.gas,
+                result.stack_depth,
+                result.pubdata,
+                None,
+                None,
+            );
         } else {
-            Summary::failed(
+            Summary::failed_with_storage(
                 summary,
                 test,
                 self.expected,
                 result.output,
                 self.calldata.inner,
+                vm.storage_excerpt(&[self.address]),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::output::Output;
+
+    use super::Runtime;
+
+    #[test]
+    fn is_output_valid() {
+        let expected = Output::from(true);
+        for (is_expected_checked, output, is_valid) in [
+            (true, Output::from(true), true),
+            (true, Output::from(false), false),
+            (true, Output::new(vec![], true, vec![]), false),
+            (false, Output::from(false), true),
+            (false, Output::new(vec![], true, vec![]), false),
+        ] {
+            assert_eq!(
+                Runtime::is_output_valid(is_expected_checked, &expected, &output),
+                is_valid,
+                "{is_expected_checked} {output:?}"
             );
         }
     }
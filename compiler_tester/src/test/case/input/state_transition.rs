@@ -0,0 +1,390 @@
+//!
+//! The Ethereum state test transaction input variant.
+//!
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use revm::db::states::plain_account::PlainStorage;
+use revm::primitives::AccountInfo;
+use revm::primitives::Bytecode;
+use revm::primitives::Env;
+use revm::primitives::SpecId;
+use revm::primitives::TxKind;
+use revm::primitives::B256;
+
+use crate::directories::state_tests::test::definition::post::Post;
+use crate::directories::state_tests::test::definition::Definition;
+use crate::summary::element::outcome::error_code::ErrorCode;
+use crate::summary::Summary;
+use crate::test::case::input::identifier::InputIdentifier;
+use crate::test::case::input::output::Output;
+use crate::test::context::input::InputContext;
+use crate::test::description::TestDescription;
+use crate::vm::revm::revm_type_conversions::web3_address_to_revm_address;
+use crate::vm::revm::revm_type_conversions::web3_u256_to_revm_u256;
+use crate::vm::revm::state_root;
+
+///
+/// The Ethereum state test transaction input variant.
+///
+/// Applies a single transaction to a standalone pre-state, and checks the resulting
+/// state trie root and logs hash.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct StateTransition {
+    /// The fork specification.
+    spec_id: SpecId,
+    /// The pre-state accounts.
+    #[serde(serialize_with = "StateTransition::serialize_pre")]
+    pre: Vec<(revm::primitives::Address, AccountInfo, PlainStorage)>,
+    /// The block and transaction environment.
+    env: Box<Env>,
+    /// The expected state trie root.
+    expected_state_root: web3::types::H256,
+    /// The expected logs hash.
+    expected_logs_hash: web3::types::H256,
+    /// The expected transaction exception.
+    expected_exception: Option<String>,
+}
+
+impl StateTransition {
+    /// The chain ID used by the state tests.
+    pub const CHAIN_ID: u64 = 1;
+
+    ///
+    /// Serializes the pre-state accounts with their storage slots ordered by key.
+    ///
+    fn serialize_pre<S>(
+        pre: &[(revm::primitives::Address, AccountInfo, PlainStorage)],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(pre.iter().map(|(address, info, storage)| {
+            (address, info, storage.iter().collect::<BTreeMap<_, _>>())
+        }))
+    }
+
+    ///
+    /// Returns the REVM specification of the state test `fork`, if it is supported.
+    ///
+    pub fn spec_id(fork: &str) -> Option<SpecId> {
+        match fork {
+            "Frontier" => Some(SpecId::FRONTIER),
+            "Homestead" => Some(SpecId::HOMESTEAD),
+            "EIP150" => Some(SpecId::TANGERINE),
+            "EIP158" => Some(SpecId::SPURIOUS_DRAGON),
+            "Byzantium" => Some(SpecId::BYZANTIUM),
+            "Constantinople" => Some(SpecId::CONSTANTINOPLE),
+            "ConstantinopleFix" => Some(SpecId::PETERSBURG),
+            "Istanbul" => Some(SpecId::ISTANBUL),
+            "Berlin" => Some(SpecId::BERLIN),
+            "London" => Some(SpecId::LONDON),
+            "Merge" | "Paris" => Some(SpecId::MERGE),
+            "Shanghai" => Some(SpecId::SHANGHAI),
+            "Cancun" => Some(SpecId::CANCUN),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Creates the input from the state test `definition` and its `post` entry for the `spec_id`.
+    ///
+    /// The values which REVM stores as 64-bit integers, such as the nonces and gas limits,
+    /// are rejected if they do not fit, so that the test is reported as invalid.
+    ///
+    pub fn try_from_definition(
+        definition: &Definition,
+        spec_id: SpecId,
+        post: &Post,
+    ) -> anyhow::Result<Self> {
+        let pre = definition
+            .pre
+            .iter()
+            .map(|(address, account)| -> anyhow::Result<_> {
+                let code = Bytecode::new_raw(account.code.0.clone().into());
+                let info = AccountInfo::new(
+                    web3_u256_to_revm_u256(account.balance),
+                    Self::to_u64(account.nonce, "account nonce")?,
+                    code.hash_slow(),
+                    code,
+                );
+                let storage = account
+                    .storage
+                    .iter()
+                    .map(|(key, value)| {
+                        (web3_u256_to_revm_u256(*key), web3_u256_to_revm_u256(*value))
+                    })
+                    .collect();
+                Ok((web3_address_to_revm_address(address), info, storage))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let transaction = &definition.transaction;
+        let data = transaction
+            .data
+            .get(post.indexes.data)
+            .ok_or_else(|| anyhow::anyhow!("Invalid data index {}", post.indexes.data))?;
+        let gas_limit = transaction
+            .gas_limit
+            .get(post.indexes.gas)
+            .ok_or_else(|| anyhow::anyhow!("Invalid gas index {}", post.indexes.gas))?;
+        let value = transaction
+            .value
+            .get(post.indexes.value)
+            .ok_or_else(|| anyhow::anyhow!("Invalid value index {}", post.indexes.value))?;
+        let gas_price = transaction
+            .gas_price
+            .or(transaction.max_fee_per_gas)
+            .ok_or_else(|| anyhow::anyhow!("The transaction gas price is missing"))?;
+
+        let mut env = Box::<Env>::default();
+        env.cfg.chain_id = Self::CHAIN_ID;
+
+        env.block.number = web3_u256_to_revm_u256(definition.env.current_number);
+        env.block.coinbase = web3_address_to_revm_address(&definition.env.current_coinbase);
+        env.block.timestamp = web3_u256_to_revm_u256(definition.env.current_timestamp);
+        env.block.gas_limit = web3_u256_to_revm_u256(definition.env.current_gas_limit);
+        env.block.basefee =
+            web3_u256_to_revm_u256(definition.env.current_base_fee.unwrap_or_default());
+        env.block.difficulty =
+            web3_u256_to_revm_u256(definition.env.current_difficulty.unwrap_or_default());
+        env.block.prevrandao = definition
+            .env
+            .current_random
+            .map(|random| B256::from_slice(random.as_bytes()));
+        if let Some(excess_blob_gas) = definition.env.current_excess_blob_gas {
+            env.block
+                .set_blob_excess_gas_and_price(Self::to_u64(excess_blob_gas, "excess blob gas")?);
+        }
+
+        env.tx.caller = web3_address_to_revm_address(&transaction.sender()?);
+        env.tx.gas_limit = Self::to_u64(*gas_limit, "transaction gas limit")?;
+        env.tx.gas_price = web3_u256_to_revm_u256(gas_price);
+        env.tx.gas_priority_fee = transaction
+            .max_priority_fee_per_gas
+            .map(web3_u256_to_revm_u256);
+        env.tx.transact_to = match transaction.to()? {
+            Some(address) => TxKind::Call(web3_address_to_revm_address(&address)),
+            None => TxKind::Create,
+        };
+        env.tx.value = web3_u256_to_revm_u256(*value);
+        env.tx.data = revm::primitives::Bytes::from(data.0.clone());
+        env.tx.nonce = Some(Self::to_u64(transaction.nonce, "transaction nonce")?);
+        env.tx.access_list = transaction
+            .access_lists
+            .get(post.indexes.data)
+            .cloned()
+            .flatten()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| {
+                (
+                    web3_address_to_revm_address(&item.address),
+                    item.storage_keys
+                        .into_iter()
+                        .map(web3_u256_to_revm_u256)
+                        .collect(),
+                )
+            })
+            .collect();
+        env.tx.blob_hashes = transaction
+            .blob_versioned_hashes
+            .iter()
+            .map(|hash| B256::from_slice(hash.as_bytes()))
+            .collect();
+        env.tx.max_fee_per_blob_gas = transaction.max_fee_per_blob_gas.map(web3_u256_to_revm_u256);
+
+        Ok(Self {
+            spec_id,
+            pre,
+            env,
+            expected_state_root: post.hash,
+            expected_logs_hash: post.logs,
+            expected_exception: post.expect_exception.clone(),
+        })
+    }
+}
+
+impl StateTransition {
+    ///
+    /// Converts the state test `value` to a 64-bit integer, naming the value as `name` if it
+    /// does not fit.
+    ///
+    fn to_u64(value: web3::types::U256, name: &str) -> anyhow::Result<u64> {
+        u64::try_from(value)
+            .map_err(|_| anyhow::anyhow!("The {name} {value} does not fit into 64 bits"))
+    }
+
+    ///
+    /// Runs the state transition on REVM.
+    ///
+    /// Only the presence of the expected exception is checked, not its kind.
+    ///
+    pub fn run_revm(self, summary: Arc<Mutex<Summary>>, context: InputContext<'_>) {
+        let input_index = context.selector;
+        let test = TestDescription::from_context(
+            context,
+            InputIdentifier::StateTransition { input_index },
+        );
+
+        let mut cache = revm::CacheState::new(false);
+        for (address, info, storage) in self.pre.into_iter() {
+            cache.insert_account_with_storage(address, info, storage);
+        }
+        cache.set_state_clear_flag(SpecId::enabled(self.spec_id, SpecId::SPURIOUS_DRAGON));
+        let mut state = revm::db::State::builder()
+            .with_cached_prestate(cache)
+            .with_bundle_update()
+            .build();
+
+        let mut vm = revm::Evm::builder()
+            .with_db(&mut state)
+            .with_spec_id(self.spec_id)
+            .modify_env(|env| env.clone_from(&self.env))
+            .build();
+        let result = vm.transact_commit();
+        drop(vm);
+
+        let (logs, gas) = match (result, self.expected_exception) {
+            (Ok(result), None) => (result.logs().to_vec(), result.gas_used()),
+            (Ok(_), Some(_)) => {
+                Summary::failed(
+                    summary,
+                    test,
+                    Output::new(vec![], true, vec![]),
+                    Output::new(vec![], false, vec![]),
+                    self.env.tx.data.to_vec(),
+                );
+                return;
+            }
+            (Err(_), Some(_)) => (vec![], 0),
+            (Err(error), None) => {
+                Summary::invalid(
+                    summary,
+                    test,
+                    ErrorCode::ExecutionFailed,
+                    format!("Transaction error: {error:?}"),
+                );
+                return;
+            }
+        };
+
+        let state_root = state_root::state_root(state.cache.trie_account());
+        if state_root != self.expected_state_root {
+            Summary::failed(
+                summary,
+                test,
+                crate::utils::h256_to_u256(&self.expected_state_root).into(),
+                crate::utils::h256_to_u256(&state_root).into(),
+                self.env.tx.data.to_vec(),
+            );
+            return;
+        }
+
+        let logs_hash = state_root::logs_hash(logs.as_slice());
+        if logs_hash != self.expected_logs_hash {
+            Summary::failed(
+                summary,
+                test,
+                crate::utils::h256_to_u256(&self.expected_logs_hash).into(),
+                crate::utils::h256_to_u256(&logs_hash).into(),
+                self.env.tx.data.to_vec(),
+            );
+            return;
+        }
+
+        Summary::passed_runtime(summary, test, 0, 0, gas, None, None, None, None, None);
+    }
+
+    ///
+    /// Skips the state transition on the VMs which cannot be seeded with an arbitrary pre-state.
+    ///
+    pub fn run_unsupported(self, summary: Arc<Mutex<Summary>>, context: InputContext<'_>) {
+        let input_index = context.selector;
+        let test = TestDescription::from_context(
+            context,
+            InputIdentifier::StateTransition { input_index },
+        );
+        Summary::ignored(summary, test);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revm::primitives::SpecId;
+
+    use crate::directories::state_tests::test::definition::Definition;
+
+    use super::StateTransition;
+
+    ///
+    /// Returns a state test definition with the transaction `gas_limit` and sender `nonce`.
+    ///
+    fn definition(gas_limit: &str, nonce: &str) -> Definition {
+        serde_json::from_value(serde_json::json!({
+            "env": {
+                "currentCoinbase": "0x2adc25665018aa1fe0e6bc666dac8fc2697ff9ba",
+                "currentGasLimit": "0x05f5e100",
+                "currentNumber": "0x01",
+                "currentTimestamp": "0x03e8",
+                "currentBaseFee": "0x0a"
+            },
+            "pre": {
+                "0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b": {
+                    "balance": "0x0de0b6b3a7640000",
+                    "code": "0x",
+                    "nonce": nonce,
+                    "storage": {}
+                }
+            },
+            "transaction": {
+                "data": ["0x"],
+                "gasLimit": [gas_limit],
+                "value": ["0x00"],
+                "gasPrice": "0x0a",
+                "nonce": "0x00",
+                "secretKey": "0x45a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8",
+                "to": "0x1000000000000000000000000000000000000000"
+            },
+            "post": {
+                "Cancun": [{
+                    "hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "logs": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "indexes": { "data": 0, "gas": 0, "value": 0 }
+                }]
+            }
+        }))
+        .expect("Always valid")
+    }
+
+    #[test]
+    fn try_from_definition() {
+        for (gas_limit, nonce, expected) in [
+            ("0x0f4240", "0x00", None),
+            ("0xffffffffffffffff", "0xffffffffffffffff", None),
+            (
+                "0x010000000000000000",
+                "0x00",
+                Some("The transaction gas limit 18446744073709551616 does not fit into 64 bits"),
+            ),
+            (
+                "0x0f4240",
+                "0x010000000000000000",
+                Some("The account nonce 18446744073709551616 does not fit into 64 bits"),
+            ),
+        ] {
+            let definition = definition(gas_limit, nonce);
+            let post = &definition.post["Cancun"][0];
+            let result = StateTransition::try_from_definition(&definition, SpecId::CANCUN, post);
+            assert_eq!(
+                result.err().map(|error| error.to_string()),
+                expected.map(str::to_owned),
+                "gas limit {gas_limit}, nonce {nonce}"
+            );
+        }
+    }
+}
@@ -13,9 +13,10 @@ use crate::test::instance::Instance;
 ///
 /// The test input storage data.
 ///
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct Storage {
     /// The inner storage hashmap data.
+    #[serde(serialize_with = "crate::utils::serialize_sorted")]
     pub inner: HashMap<(web3::types::Address, web3::types::U256), web3::types::H256>,
 }
 
@@ -31,30 +32,18 @@ impl Storage {
         let mut result = HashMap::new();
 
         for (address, contract_storage) in storage.into_iter() {
-            let address = if let Some(instance) = address.strip_suffix(".address") {
-                instances
-                    .get(instance)
-                    .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", instance))?
-                    .address()
-                    .copied()
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("Instance `{}` is not successfully deployed", instance)
-                    })
+            let address = if let Some(instance) = Value::instance_name(address.as_str()) {
+                Value::instance_address(instance, instances)
             } else {
-                web3::types::Address::from_str(address.as_str())
-                    .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
+                let literal = address.strip_prefix("0x").unwrap_or(address.as_str());
+                crate::utils::validate_address_checksum(literal).and_then(|()| {
+                    web3::types::Address::from_str(literal)
+                        .map_err(|error| anyhow::anyhow!("Invalid address literal: {}", error))
+                })
             }
             .map_err(|error| anyhow::anyhow!("Invalid storage address: {}", error))?;
 
-            let contract_storage = match contract_storage {
-                MatterLabsTestContractStorage::List(list) => list
-                    .into_iter()
-                    .enumerate()
-                    .map(|(key, value)| (key.to_string(), value))
-                    .collect(),
-                MatterLabsTestContractStorage::Map(map) => map.clone(),
-            };
-            for (key, value) in contract_storage.into_iter() {
+            for (key, value) in contract_storage.to_map().into_iter() {
                 let key = match Value::try_from_matter_labs(key, instances, target)
                     .map_err(|error| anyhow::anyhow!("Invalid storage key: {}", error))?
                 {
@@ -16,7 +16,7 @@ use crate::vm::revm::Revm;
 ///
 /// The storage emptiness check input variant.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct StorageEmpty {
     /// Whether storage is empty.
     is_empty: bool,
@@ -66,18 +66,7 @@ impl StorageEmpty {
         let input_index = context.selector;
         let test =
             TestDescription::from_context(context, InputIdentifier::StorageEmpty { input_index });
-        let mut is_empty = true;
-        for cache_account in vm.state.db().cache.accounts.values() {
-            let plain_account = cache_account.clone().account;
-            if let Some(plain_account) = plain_account {
-                for (_, value) in plain_account.storage.iter() {
-                    if !value.is_zero() {
-                        is_empty = false;
-                    }
-                }
-            }
-        }
-
+        let is_empty = vm.is_storage_empty();
         if is_empty == self.is_empty {
             Summary::passed_special(summary, test);
         } else {
@@ -3,13 +3,17 @@
 //!
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use serde::Serialize;
 use serde::Serializer;
 
 use crate::test::instance::Instance;
+use crate::vm::address_iterator::AddressIterator;
+use crate::vm::eravm::address_iterator::EraVMAddressIterator;
 use crate::vm::eravm::system_context::SystemContext;
+use crate::vm::evm::address_iterator::EVMAddressIterator;
 
 ///
 /// The compiler test value.
@@ -49,16 +53,13 @@ impl Value {
             return Ok(Self::Any);
         }
 
-        let value = if let Some(instance) = value.strip_suffix(".address") {
+        let value = if let Some(arguments) = Self::create2_arguments(value.as_str()) {
             web3::types::U256::from_big_endian(
-                instances
-                    .get(instance)
-                    .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", instance))?
-                    .address()
-                    .ok_or_else(|| {
-                        anyhow::anyhow!("Instance `{}` was not successfully deployed", instance)
-                    })?
-                    .as_bytes(),
+                Self::create2_address(arguments, instances, target)?.as_bytes(),
+            )
+        } else if let Some(instance) = Self::instance_name(value.as_str()) {
+            web3::types::U256::from_big_endian(
+                Self::instance_address(instance, instances)?.as_bytes(),
             )
         } else if let Some(value) = value.strip_prefix('-') {
             let value = web3::types::U256::from_dec_str(value)
@@ -73,6 +74,9 @@ impl Value {
                 .checked_sub(value)
                 .expect("Always valid")
         } else if let Some(value) = value.strip_prefix("0x") {
+            if value.len() == era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 2 {
+                crate::utils::validate_address_checksum(value)?;
+            }
             web3::types::U256::from_str(value)
                 .map_err(|error| anyhow::anyhow!("Invalid hexadecimal literal: {}", error))?
         } else if value == "$CHAIN_ID" {
@@ -145,6 +149,144 @@ impl Value {
         Ok(Self::Certain(value))
     }
 
+    ///
+    /// Returns the instance name if the value is an instance address reference,
+    /// that is `#address(<instance>)` or `<instance>.address`.
+    ///
+    pub fn instance_name(value: &str) -> Option<&str> {
+        value
+            .strip_prefix("#address(")
+            .and_then(|value| value.strip_suffix(')'))
+            .or_else(|| value.strip_suffix(".address"))
+    }
+
+    ///
+    /// Returns the trimmed arguments if the value is a `CREATE2` address reference,
+    /// that is `#create2(<arguments>)`.
+    ///
+    pub fn create2_arguments(value: &str) -> Option<Vec<&str>> {
+        value
+            .strip_prefix("#create2(")
+            .and_then(|value| value.strip_suffix(')'))
+            .map(|arguments| arguments.split(',').map(str::trim).collect())
+    }
+
+    ///
+    /// Returns the instances referenced by the value, that is the instance of an address
+    /// reference, or the deployer, the salt references, and the code hash instance of a
+    /// `CREATE2` address reference.
+    ///
+    pub fn referenced_instances(value: &str) -> Vec<&str> {
+        match Self::create2_arguments(value) {
+            Some(arguments) => {
+                let mut instances = Vec::with_capacity(arguments.len());
+                if let Some(deployer) = arguments.first() {
+                    instances.push(*deployer);
+                }
+                if let Some(salt) = arguments.get(1) {
+                    instances.extend(Self::referenced_instances(salt));
+                }
+                if let Some(code_hash) = arguments.get(2) {
+                    if !code_hash.starts_with("0x") {
+                        instances.push(*code_hash);
+                    }
+                }
+                instances
+            }
+            None => Self::instance_name(value).into_iter().collect(),
+        }
+    }
+
+    ///
+    /// Returns the address of the deployed `instance`.
+    ///
+    pub fn instance_address(
+        instance: &str,
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<web3::types::Address> {
+        instances
+            .get(instance)
+            .ok_or_else(|| anyhow::anyhow!("Instance `{}` not found", instance))?
+            .address()
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("Instance `{}` was not successfully deployed", instance))
+    }
+
+    ///
+    /// Returns the address of a contract created with `CREATE2`, referenced as
+    /// `#create2(<deployer>, <salt>, <code hash>[, <constructor input hash>])`.
+    ///
+    /// The deployer is an instance name. The code hash is either a hexadecimal literal, or an
+    /// instance name, whose bytecode hash on EraVM or init code hash on EVM is used. The
+    /// constructor input hash is only used on EraVM, and defaults to the hash of empty input.
+    ///
+    fn create2_address(
+        arguments: Vec<&str>,
+        instances: &BTreeMap<String, Instance>,
+        target: era_compiler_common::Target,
+    ) -> anyhow::Result<web3::types::Address> {
+        if !(3..=4).contains(&arguments.len()) {
+            anyhow::bail!(
+                "`#create2` expects 3 or 4 arguments: deployer, salt, code hash, and optional constructor input hash"
+            );
+        }
+
+        let deployer = Self::instance_address(arguments[0], instances)?;
+        let salt = match Self::try_from_matter_labs(arguments[1].to_owned(), instances, target)
+            .map_err(|error| anyhow::anyhow!("Invalid `#create2` salt: {}", error))?
+        {
+            Self::Certain(salt) => crate::utils::u256_to_h256(&salt),
+            Self::Any => anyhow::bail!("`#create2` salt cannot be `*`"),
+        };
+        let code_hash = match arguments[2].strip_prefix("0x") {
+            Some(code_hash) => web3::types::H256::from_str(code_hash)
+                .map_err(|error| anyhow::anyhow!("Invalid `#create2` code hash: {}", error))?,
+            None => match instances.get(arguments[2]) {
+                Some(Instance::EraVM(instance)) => crate::utils::u256_to_h256(&instance.code_hash),
+                Some(Instance::EVM(instance)) => web3::types::H256::from(web3::signing::keccak256(
+                    instance.deploy_code.as_slice(),
+                )),
+                None => anyhow::bail!("Instance `{}` not found", arguments[2]),
+            },
+        };
+        let input_hash = match arguments.get(3) {
+            Some(input_hash) => {
+                web3::types::H256::from_str(input_hash.strip_prefix("0x").unwrap_or(input_hash))
+                    .map_err(|error| {
+                        anyhow::anyhow!("Invalid `#create2` constructor input hash: {}", error)
+                    })?
+            }
+            None => web3::types::H256::from(web3::signing::keccak256(&[])),
+        };
+
+        let address = match target {
+            era_compiler_common::Target::EraVM => {
+                EraVMAddressIterator::create2(&deployer, &salt, &code_hash, &input_hash)
+            }
+            era_compiler_common::Target::EVM => {
+                EVMAddressIterator::create2(&deployer, &salt, &code_hash, &input_hash)
+            }
+        };
+        Ok(address)
+    }
+
+    ///
+    /// Replaces the value with the registered address if it is a registration placeholder.
+    ///
+    pub fn substitute_address(
+        &mut self,
+        addresses: &HashMap<web3::types::Address, web3::types::Address>,
+    ) {
+        if let Self::Certain(value) = self {
+            if value.bits() > era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 8 {
+                return;
+            }
+            if let Some(address) = addresses.get(&crate::utils::u256_to_address(value)) {
+                *value = web3::types::U256::from_big_endian(address.as_bytes());
+            }
+        }
+    }
+
     ///
     /// Try convert into vec of self from vec of Matter Labs compiler test metadata values.
     ///
@@ -176,3 +318,26 @@ impl Serialize for Value {
         serializer.serialize_str(&value_str)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn referenced_instances() {
+        for (value, expected) in [
+            ("42", vec![]),
+            ("0x0b0b", vec![]),
+            ("Test.address", vec!["Test"]),
+            ("#address(Test)", vec!["Test"]),
+            ("#create2(Factory, 0x01, 0xabcd)", vec!["Factory"]),
+            ("#create2(Factory, 0x01, Child)", vec!["Factory", "Child"]),
+            (
+                "#create2(Factory, Salt.address, Child, 0x00)",
+                vec!["Factory", "Salt", "Child"],
+            ),
+        ] {
+            assert_eq!(Value::referenced_instances(value), expected, "{value}");
+        }
+    }
+}
@@ -3,21 +3,34 @@
 //!
 
 pub mod input;
+pub mod vm_state;
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::Mutex;
 
 use crate::compilers::mode::Mode;
+use crate::directories::matter_labs::test::metadata::case::input::Input as MatterLabsTestInput;
 use crate::directories::matter_labs::test::metadata::case::Case as MatterLabsTestCase;
 use crate::summary::Summary;
 use crate::test::instance::Instance;
+use crate::vm::eravm::deployers::dummy_deployer::DummyDeployer;
+use crate::vm::eravm::deployers::system_contract_deployer::SystemContractDeployer;
 use crate::vm::eravm::deployers::EraVMDeployer;
+use crate::vm::eravm::runner_settings::overrides::Overrides as RunnerOverrides;
+use crate::vm::eravm::runner_settings::RunnerSettings;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::EVM;
 use crate::vm::revm::Revm;
 
+use self::input::balance::Balance;
+use self::input::immutables::Immutables;
+use self::input::runtime::Runtime;
 use self::input::Input;
+use self::vm_state::VMState;
 
 use super::CaseContext;
 use super::InputContext;
@@ -25,12 +38,20 @@ use super::InputContext;
 ///
 /// The test case.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Case {
     /// The case name.
     name: Option<String>,
-    /// The case inputs.
-    inputs: Vec<Input>,
+    /// The case inputs with the indexes they are reported with.
+    ///
+    /// The checks derived from an input, such as the expected immutables and balances, share
+    /// its index, so that they do not shift the indexes of the subsequent inputs. The setup and
+    /// teardown fixtures are numbered separately for the same reason.
+    inputs: Vec<(usize, Input)>,
+    /// The EraVM runner settings overrides.
+    runner_overrides: RunnerOverrides,
+    /// The VM state the case starts from.
+    vm_state: VMState,
 }
 
 impl Case {
@@ -38,29 +59,152 @@ impl Case {
     /// A shortcut constructor.
     ///
     pub fn new(name: Option<String>, inputs: Vec<Input>) -> Self {
-        Self { name, inputs }
+        Self {
+            name,
+            inputs: inputs.into_iter().enumerate().collect(),
+            runner_overrides: RunnerOverrides::default(),
+            vm_state: VMState::default(),
+        }
+    }
+
+    ///
+    /// Sets the EraVM runner settings overrides required by the case.
+    ///
+    pub fn with_runner_overrides(mut self, runner_overrides: RunnerOverrides) -> Self {
+        self.runner_overrides = runner_overrides;
+        self
+    }
+
+    ///
+    /// Returns the VM state the case starts from.
+    ///
+    pub fn vm_state(&self) -> VMState {
+        self.vm_state
+    }
+
+    ///
+    /// Returns the case name.
+    ///
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
     }
 
     ///
     /// Try convert from Matter Labs compiler test metadata case.
     ///
+    /// The `registered_instances` of the previous cases are kept for the case sharing their
+    /// VM state, and are extended with the ones registered by the case.
+    ///
     pub fn try_from_matter_labs(
         case: MatterLabsTestCase,
         mode: &Mode,
         instances: &BTreeMap<String, Instance>,
+        registered_instances: &mut BTreeSet<String>,
         method_identifiers: &Option<BTreeMap<String, BTreeMap<String, u32>>>,
+        custom_errors: &BTreeMap<String, BTreeSet<String>>,
         target: era_compiler_common::Target,
     ) -> anyhow::Result<Self> {
+        let vm_state = case.vm_state;
         let mut inputs = Vec::with_capacity(case.inputs.len());
+        let mut instances = Cow::Borrowed(instances);
+
+        if vm_state == VMState::Fresh {
+            registered_instances.clear();
+        }
+        for register in registered_instances.iter() {
+            if let Some(instance) = instances.to_mut().get_mut(register.as_str()) {
+                instance.set_address(Runtime::registration_placeholder(register.as_str()));
+            }
+        }
 
-        for (index, input) in case.inputs.into_iter().enumerate() {
-            let input =
-                Input::try_from_matter_labs(input, mode, instances, method_identifiers, target)
-                    .map_err(|error| anyhow::anyhow!("Input #{} is invalid: {}", index, error))?;
-            inputs.push(input);
+        Self::validate_registrations(case.inputs.as_slice(), &instances)?;
+
+        let mut next_indexes = BTreeMap::new();
+        for input in case.inputs.into_iter() {
+            let next_index = next_indexes.entry(input.fixture).or_insert(0);
+            let index = *next_index;
+            *next_index += 1;
+            let name = match input.fixture {
+                Some(fixture) => format!("The {fixture} input #{index}"),
+                None => format!("Input #{index}"),
+            };
+
+            let register = input.register.clone();
+            let immutables = Immutables::try_from_matter_labs(&input, &instances, target)
+                .map_err(|error| anyhow::anyhow!("{name} is invalid: {error}"))?;
+            let balances = Balance::try_from_matter_labs(&input, &instances, target)
+                .map_err(|error| anyhow::anyhow!("{name} is invalid: {error}"))?;
+            let input = Input::try_from_matter_labs(
+                input,
+                mode,
+                &instances,
+                method_identifiers,
+                custom_errors,
+                target,
+            )
+            .map_err(|error| anyhow::anyhow!("{name} is invalid: {error}"))?;
+            inputs.push((index, input));
+            if let Some(immutables) = immutables {
+                inputs.push((index, Input::Immutables(immutables)));
+            }
+            inputs.extend(
+                balances
+                    .into_iter()
+                    .map(|balance| (index, Input::Balance(balance))),
+            );
+            if let Some(register) = register {
+                instances
+                    .to_mut()
+                    .get_mut(register.as_str())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "{name} is invalid: Registered instance `{register}` not found"
+                        )
+                    })?
+                    .set_address(Runtime::registration_placeholder(register.as_str()));
+                registered_instances.insert(register);
+            }
         }
 
-        Ok(Self::new(Some(case.name), inputs))
+        Ok(Self {
+            name: Some(case.name),
+            inputs,
+            runner_overrides: RunnerOverrides::default(),
+            vm_state,
+        })
+    }
+
+    ///
+    /// Checks that the instances registered by the case `inputs` are declared in the contracts
+    /// of the test, since their ABI is required to call them by the method names.
+    ///
+    /// A registered instance must not be a library, nor be deployed by a `#deployer` input
+    /// of the case, as its address would be ambiguous.
+    ///
+    fn validate_registrations(
+        inputs: &[MatterLabsTestInput],
+        instances: &BTreeMap<String, Instance>,
+    ) -> anyhow::Result<()> {
+        for register in inputs.iter().filter_map(|input| input.register.as_deref()) {
+            match instances.get(register) {
+                Some(instance) if instance.is_library() => anyhow::bail!(
+                    "Registered instance `{register}` is a library, and cannot be registered"
+                ),
+                Some(_) => {}
+                None => anyhow::bail!(
+                    "Registered instance `{register}` must be declared in the `contracts` of the test"
+                ),
+            }
+            if inputs
+                .iter()
+                .any(|input| input.method.as_str() == "#deployer" && input.instance == register)
+            {
+                anyhow::bail!(
+                    "Registered instance `{register}` is also deployed by a `#deployer` input"
+                );
+            }
+        }
+        Ok(())
     }
 
     ///
@@ -70,6 +214,7 @@ impl Case {
         case: &[solidity_adapter::FunctionCall],
         instances: BTreeMap<String, Instance>,
         last_source: &str,
+        events_unordered: bool,
         target: era_compiler_common::Target,
     ) -> anyhow::Result<Self> {
         let mut inputs = Vec::with_capacity(case.len());
@@ -81,12 +226,17 @@ impl Case {
                     caller = solidity_adapter::account_address(*input);
                 }
                 input => {
-                    if let Some(input) =
-                        Input::try_from_ethereum(input, &instances, last_source, &caller, target)
-                            .map_err(|error| {
-                                anyhow::anyhow!("Failed to proccess input #{index}: {error}")
-                            })?
-                    {
+                    if let Some(input) = Input::try_from_ethereum(
+                        input,
+                        &instances,
+                        last_source,
+                        &caller,
+                        events_unordered,
+                        target,
+                    )
+                    .map_err(|error| {
+                        anyhow::anyhow!("Failed to proccess input #{index}: {error}")
+                    })? {
                         inputs.push(input);
                     }
                 }
@@ -97,36 +247,32 @@ impl Case {
     }
 
     ///
-    /// Runs the case on EraVM.
+    /// Runs the case on EraVM with the `runner_settings` of the run and the case overrides.
+    ///
+    /// Returns the VM with the state left by the case.
     ///
-    pub fn run_eravm<D, const M: bool>(
+    pub fn run_eravm(
         self,
         summary: Arc<Mutex<Summary>>,
-        mut vm: EraVM,
+        vm: EraVM,
         context: &CaseContext,
-    ) where
-        D: EraVMDeployer,
-    {
-        for (index, input) in self.inputs.into_iter().enumerate() {
-            let context = InputContext {
-                case_context: context,
-                case_name: &self.name,
-                selector: index,
-            };
-            input.run_eravm::<_, M>(summary.clone(), &mut vm, &mut D::new(), context)
-        }
+        runner_settings: RunnerSettings,
+    ) -> EraVM {
+        self.run_on_eravm(summary, vm, context, runner_settings, false)
     }
 
     ///
     /// Runs the case on EVM emulator.
     ///
+    /// Returns the VM with the state left by the case.
+    ///
     pub fn run_evm_emulator(
         self,
         summary: Arc<Mutex<Summary>>,
         mut vm: EVM,
         context: &CaseContext,
-    ) {
-        for (index, input) in self.inputs.into_iter().enumerate() {
+    ) -> EVM {
+        for (index, input) in self.inputs.into_iter() {
             let context = InputContext {
                 case_context: context,
                 case_name: &self.name,
@@ -134,48 +280,508 @@ impl Case {
             };
             input.run_evm_emulator(summary.clone(), &mut vm, context)
         }
+        vm
     }
 
     ///
     /// Runs the case on REVM.
     ///
+    /// The `registered_addresses` of the instances are kept with the VM state, so that the
+    /// subsequent cases sharing it can call them.
+    ///
+    /// Returns the VM with the state left by the case.
+    ///
     pub fn run_revm(
         self,
         summary: Arc<Mutex<Summary>>,
+        mut vm: Revm<'static>,
         evm_version: Option<solidity_adapter::EVMVersion>,
         context: &CaseContext,
-    ) {
-        let mut vm = Revm::new();
-        for (index, input) in self.inputs.into_iter().enumerate() {
+        registered_addresses: &mut HashMap<web3::types::Address, web3::types::Address>,
+    ) -> Revm<'static> {
+        for (index, input) in self.inputs.into_iter() {
             let context = InputContext {
                 case_context: context,
                 case_name: &self.name,
                 selector: index,
             };
-            vm = input.run_revm(summary.clone(), vm, evm_version, context)
+            vm = input.run_revm(
+                summary.clone(),
+                vm,
+                evm_version,
+                context,
+                registered_addresses,
+            )
         }
+        vm
     }
 
     ///
-    /// Runs the case on EVM interpreter.
+    /// Runs the case on EVM interpreter with the `runner_settings` of the run and the case overrides.
     ///
-    pub fn run_evm_interpreter<D, const M: bool>(
+    /// Returns the VM with the state left by the case.
+    ///
+    pub fn run_evm_interpreter(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        vm: EraVM,
+        context: &CaseContext<'_>,
+        runner_settings: RunnerSettings,
+    ) -> EraVM {
+        self.run_on_eravm(summary, vm, context, runner_settings, true)
+    }
+
+    ///
+    /// Runs the case on EraVM or, if `is_evm_interpreter` is set, on EVM interpreter, with the
+    /// deployer and value simulator mode selected by the `runner_settings` and the case overrides.
+    ///
+    fn run_on_eravm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        vm: EraVM,
+        context: &CaseContext<'_>,
+        runner_settings: RunnerSettings,
+        is_evm_interpreter: bool,
+    ) -> EraVM {
+        let runner_settings = runner_settings.with_overrides(self.runner_overrides);
+        match (
+            runner_settings.disable_deployer,
+            runner_settings.disable_value_simulator,
+        ) {
+            (true, true) => self.run_on_eravm_with::<DummyDeployer, false>(
+                summary,
+                vm,
+                context,
+                is_evm_interpreter,
+            ),
+            (true, false) => self.run_on_eravm_with::<DummyDeployer, true>(
+                summary,
+                vm,
+                context,
+                is_evm_interpreter,
+            ),
+            (false, true) => self.run_on_eravm_with::<SystemContractDeployer, false>(
+                summary,
+                vm,
+                context,
+                is_evm_interpreter,
+            ),
+            (false, false) => self.run_on_eravm_with::<SystemContractDeployer, true>(
+                summary,
+                vm,
+                context,
+                is_evm_interpreter,
+            ),
+        }
+    }
+
+    ///
+    /// Runs the case on EraVM or EVM interpreter with the deployer `D` and value simulator mode `M`.
+    ///
+    fn run_on_eravm_with<D, const M: bool>(
         self,
         summary: Arc<Mutex<Summary>>,
         mut vm: EraVM,
         context: &CaseContext<'_>,
-    ) where
+        is_evm_interpreter: bool,
+    ) -> EraVM
+    where
         D: EraVMDeployer,
     {
-        for (index, input) in self.inputs.into_iter().enumerate() {
-            vm.increment_evm_block_number_and_timestamp();
-
+        for (index, input) in self.inputs.into_iter() {
             let context = InputContext {
                 case_context: context,
                 case_name: &self.name,
                 selector: index,
             };
-            input.run_evm_interpreter::<_, M>(summary.clone(), &mut vm, &mut D::new(), context)
+            if is_evm_interpreter {
+                vm.increment_evm_block_number_and_timestamp();
+                input.run_evm_interpreter::<_, M>(summary.clone(), &mut vm, &mut D::new(), context)
+            } else {
+                input.run_eravm::<_, M>(summary.clone(), &mut vm, &mut D::new(), context)
+            }
+        }
+        vm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+
+    use crate::compilers::mode::Mode;
+    use crate::compilers::yul::mode_upstream::Mode as YulUpstreamMode;
+    use crate::directories::matter_labs::test::metadata::case::Case as MatterLabsTestCase;
+    use crate::summary::element::outcome::Outcome;
+    use crate::summary::Summary;
+    use crate::test::case::input::call_kind::CallKind;
+    use crate::test::case::input::calldata::Calldata;
+    use crate::test::case::input::output::Output;
+    use crate::test::case::input::runtime::Runtime;
+    use crate::test::case::input::storage::Storage;
+    use crate::test::case::input::storage_empty::StorageEmpty;
+    use crate::test::case::input::Input;
+    use crate::test::case::vm_state::VMState;
+    use crate::test::instance::Instance;
+    use crate::test::Test;
+
+    use super::Case;
+
+    fn call(address: web3::types::Address, expected: u64) -> Runtime {
+        Runtime::new(
+            format!("call_{expected}"),
+            address,
+            Calldata::default(),
+            web3::types::Address::from_low_u64_be(0xca11),
+            None,
+            CallKind::Call,
+            None,
+            Storage::default(),
+            web3::types::U256::from(expected).into(),
+        )
+    }
+
+    #[test]
+    fn shared_registered_addresses() {
+        let factory = web3::types::Address::from_low_u64_be(0xfac7);
+        let deployed = web3::types::Address::from_low_u64_be(0x0b0b);
+        let address_overrides = vec![
+            // Returns the address of the deployed instance.
+            (
+                factory,
+                vec![
+                    0x61, 0x0b, 0x0b, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3,
+                ],
+            ),
+            // Returns `42`.
+            (
+                deployed,
+                vec![0x60, 0x2a, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3],
+            ),
+        ];
+
+        let mut register = call(factory, 0x0b0b);
+        register.set_register("deployed");
+        let mut registered_call = Case::new(
+            Some("registered_call".to_owned()),
+            vec![Input::Runtime(call(
+                Runtime::registration_placeholder("deployed"),
+                42,
+            ))],
+        );
+        registered_call.vm_state = VMState::Shared;
+        let cases = vec![
+            Case::new(Some("register".to_owned()), vec![Input::Runtime(register)]),
+            registered_call,
+        ];
+
+        let test = Test::new(
+            "shared_registered_addresses".to_owned(),
+            cases,
+            Mode::YulUpstream(YulUpstreamMode::new(
+                semver::Version::new(0, 8, 28),
+                false,
+                true,
+            )),
+            None,
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::new(),
+            None,
+            false,
+        );
+        let summary = Summary::new(false, true).wrap();
+        test.run_revm(summary.clone(), None, address_overrides.as_slice());
+
+        let summary = Summary::unwrap_arc(summary);
+        assert_eq!(summary.elements().len(), 2);
+        for element in summary.elements() {
+            assert!(
+                matches!(element.outcome, Outcome::Passed { .. }),
+                "{:?}",
+                element.outcome
+            );
+        }
+    }
+
+    #[test]
+    fn storage_empty_after_selfdestruct() {
+        let address = web3::types::Address::from_low_u64_be(0xde57);
+        let address_overrides = vec![
+            // Writes to the storage if called without calldata, and selfdestructs otherwise.
+            (
+                address,
+                vec![
+                    0x36, 0x60, 0x0a, 0x57, 0x60, 0x01, 0x60, 0x00, 0x55, 0x00, 0x5b, 0x33, 0xff,
+                ],
+            ),
+        ];
+
+        for (evm_version, is_empty) in [
+            (None, false),
+            (
+                Some(solidity_adapter::EVMVersion::Lesser(
+                    solidity_adapter::EVM::Cancun,
+                )),
+                true,
+            ),
+        ] {
+            let input = |name: &str, calldata: Vec<u8>| {
+                Input::Runtime(Runtime::new(
+                    name.to_owned(),
+                    address,
+                    Calldata { inner: calldata },
+                    web3::types::Address::from_low_u64_be(0xca11),
+                    None,
+                    CallKind::Call,
+                    None,
+                    Storage::default(),
+                    Output::new(vec![], false, vec![]),
+                ))
+            };
+            let case = Case::new(
+                Some("selfdestruct".to_owned()),
+                vec![
+                    input("store", vec![]),
+                    Input::StorageEmpty(StorageEmpty::new(false)),
+                    input("selfdestruct", vec![0x01]),
+                    Input::StorageEmpty(StorageEmpty::new(is_empty)),
+                ],
+            );
+
+            let test = Test::new(
+                "storage_empty_after_selfdestruct".to_owned(),
+                vec![case],
+                Mode::YulUpstream(YulUpstreamMode::new(
+                    semver::Version::new(0, 8, 28),
+                    false,
+                    true,
+                )),
+                None,
+                HashMap::new(),
+                HashMap::new(),
+                HashMap::new(),
+                evm_version,
+                false,
+            );
+            let summary = Summary::new(false, true).wrap();
+            test.run_revm(summary.clone(), None, address_overrides.as_slice());
+
+            let summary = Summary::unwrap_arc(summary);
+            assert_eq!(summary.elements().len(), 4, "{evm_version:?}");
+            for element in summary.elements() {
+                assert!(
+                    matches!(element.outcome, Outcome::Passed { .. }),
+                    "{evm_version:?}: {:?}",
+                    element.outcome
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn input_indexes() {
+        let case: MatterLabsTestCase = serde_json::from_str(
+            r##"{
+                "name": "balances",
+                "inputs": [
+                    {
+                        "method": "#fallback",
+                        "calldata": [],
+                        "expected_balances": { "Test.address": "0", "0x01": "0" }
+                    },
+                    { "method": "#fallback", "calldata": [] }
+                ]
+            }"##,
+        )
+        .expect("Always valid");
+        let instances = BTreeMap::from([(
+            "Test".to_owned(),
+            Instance::evm(
+                "test.sol:Test".to_owned(),
+                Some(web3::types::Address::from_low_u64_be(0x1234)),
+                true,
+                false,
+                vec![],
+            ),
+        )]);
+        let mode = Mode::YulUpstream(YulUpstreamMode::new(
+            semver::Version::new(0, 8, 28),
+            false,
+            true,
+        ));
+
+        let case = Case::try_from_matter_labs(
+            case,
+            &mode,
+            &instances,
+            &mut BTreeSet::new(),
+            &None,
+            &BTreeMap::new(),
+            era_compiler_common::Target::EVM,
+        )
+        .expect("Always valid");
+        let indexes: Vec<(usize, bool)> = case
+            .inputs
+            .iter()
+            .map(|(index, input)| (*index, matches!(input, Input::Balance(_))))
+            .collect();
+        assert_eq!(indexes, vec![(0, false), (0, true), (0, true), (1, false)]);
+    }
+
+    #[test]
+    fn fixture_indexes() {
+        let mut case: MatterLabsTestCase = serde_json::from_str(
+            r##"{
+                "name": "fixtures",
+                "setup": [
+                    { "method": "8129fc1c", "calldata": [] },
+                    { "method": "8129fc1c", "calldata": [] }
+                ],
+                "inputs": [
+                    { "method": "#fallback", "calldata": [] },
+                    { "method": "6d4ce63c", "calldata": [] }
+                ],
+                "teardown": [{ "method": "d826f88f", "calldata": [] }]
+            }"##,
+        )
+        .expect("Always valid");
+        case.normalize_fixtures().expect("Always valid");
+        let instances = BTreeMap::from([(
+            "Test".to_owned(),
+            Instance::evm(
+                "test.sol:Test".to_owned(),
+                Some(web3::types::Address::from_low_u64_be(0x1234)),
+                true,
+                false,
+                vec![],
+            ),
+        )]);
+        let mode = Mode::YulUpstream(YulUpstreamMode::new(
+            semver::Version::new(0, 8, 28),
+            false,
+            true,
+        ));
+
+        let case = Case::try_from_matter_labs(
+            case,
+            &mode,
+            &instances,
+            &mut BTreeSet::new(),
+            &None,
+            &BTreeMap::new(),
+            era_compiler_common::Target::EVM,
+        )
+        .expect("Always valid");
+        let indexes: Vec<(usize, String)> = case
+            .inputs
+            .iter()
+            .map(|(index, input)| {
+                let input = serde_json::to_value(input).expect("Always valid");
+                (
+                    *index,
+                    input["Runtime"]["name"]
+                        .as_str()
+                        .expect("Always exists")
+                        .to_owned(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            indexes,
+            vec![
+                (0, "#setup:8129fc1c".to_owned()),
+                (1, "#setup:8129fc1c".to_owned()),
+                (0, "#fallback".to_owned()),
+                (1, "6d4ce63c".to_owned()),
+                (0, "#teardown:d826f88f".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn registrations() {
+        let instances = BTreeMap::from([
+            (
+                "Test".to_owned(),
+                Instance::evm(
+                    "test.sol:Test".to_owned(),
+                    Some(web3::types::Address::from_low_u64_be(0x1234)),
+                    true,
+                    false,
+                    vec![],
+                ),
+            ),
+            (
+                "Created".to_owned(),
+                Instance::evm("test.sol:Created".to_owned(), None, false, false, vec![]),
+            ),
+            (
+                "Library".to_owned(),
+                Instance::evm(
+                    "test.sol:Library".to_owned(),
+                    Some(web3::types::Address::from_low_u64_be(0x5678)),
+                    false,
+                    true,
+                    vec![],
+                ),
+            ),
+        ]);
+        let mode = Mode::YulUpstream(YulUpstreamMode::new(
+            semver::Version::new(0, 8, 28),
+            false,
+            true,
+        ));
+
+        for (inputs, error) in [
+            (
+                r##"[
+                    { "method": "#fallback", "calldata": [], "register": "Created" },
+                    { "instance": "Created", "method": "#fallback", "calldata": [] }
+                ]"##,
+                None,
+            ),
+            (
+                r##"[{ "method": "#fallback", "calldata": [], "register": "Undeclared" }]"##,
+                Some("Registered instance `Undeclared` must be declared in the `contracts` of the test"),
+            ),
+            (
+                r##"[{ "method": "#fallback", "calldata": [], "register": "Library" }]"##,
+                Some("Registered instance `Library` is a library, and cannot be registered"),
+            ),
+            (
+                r##"[
+                    { "instance": "Created", "method": "#deployer", "calldata": [] },
+                    { "method": "#fallback", "calldata": [], "register": "Created" }
+                ]"##,
+                Some("Registered instance `Created` is also deployed by a `#deployer` input"),
+            ),
+        ] {
+            let case: MatterLabsTestCase = serde_json::from_str(
+                format!(r#"{{ "name": "registrations", "inputs": {inputs} }}"#).as_str(),
+            )
+            .expect("Always valid");
+
+            let result = Case::try_from_matter_labs(
+                case,
+                &mode,
+                &instances,
+                &mut BTreeSet::new(),
+                &None,
+                &BTreeMap::new(),
+                era_compiler_common::Target::EVM,
+            );
+            match error {
+                Some(error) => assert_eq!(
+                    result.err().map(|error| error.to_string()).as_deref(),
+                    Some(error),
+                    "Invalid validation of {inputs}"
+                ),
+                None => assert!(result.is_ok(), "Invalid validation of {inputs}"),
+            }
         }
     }
 }
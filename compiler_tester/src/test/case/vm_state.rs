@@ -0,0 +1,16 @@
+//!
+//! The VM state a test case starts from.
+//!
+
+///
+/// The VM state a test case starts from.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VMState {
+    /// The VM of the test with no state left by the other cases.
+    #[default]
+    Fresh,
+    /// The VM state left by the previous case of the test, or the fresh one for the first case.
+    Shared,
+}
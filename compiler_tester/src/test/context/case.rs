@@ -2,6 +2,10 @@
 //! Context used to process test cases, consisting of a number of inputs.
 //!
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+
 use crate::Mode;
 
 ///
@@ -12,4 +16,9 @@ pub struct CaseContext<'a> {
     pub mode: &'a Mode,
     pub group: &'a Option<String>,
     pub name: &'a str,
+    pub eravm_function_sizes: &'a HashMap<web3::types::U256, BTreeMap<String, usize>>,
+    /// The directory to write the REVM traces of failed inputs to, if enabled.
+    pub revm_trace_directory: Option<&'a Path>,
+    /// The REVM address override stub bytecodes.
+    pub revm_address_overrides: &'a [(web3::types::Address, Vec<u8>)],
 }
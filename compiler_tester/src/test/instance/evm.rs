@@ -2,6 +2,8 @@
 //! The EVM test contract instance used for building.
 //!
 
+use std::collections::BTreeMap;
+
 ///
 /// The EVM test contract instance used for building.
 ///
@@ -17,6 +19,8 @@ pub struct Instance {
     pub is_library: bool,
     /// The deploy bytecode.
     pub deploy_code: Vec<u8>,
+    /// The byte offsets of the immutables in the deployed code by the immutable names.
+    pub immutables: BTreeMap<String, Vec<usize>>,
 }
 
 impl Instance {
@@ -36,6 +40,7 @@ impl Instance {
             is_main,
             is_library,
             deploy_code,
+            immutables: BTreeMap::new(),
         }
     }
 }
@@ -5,6 +5,8 @@
 pub mod eravm;
 pub mod evm;
 
+use std::collections::BTreeMap;
+
 use self::eravm::Instance as EraVMInstance;
 use self::evm::Instance as EVMInstance;
 
@@ -55,6 +57,16 @@ impl Instance {
         ))
     }
 
+    ///
+    /// Sets the byte offsets of the immutables in the deployed code of the EVM instance.
+    ///
+    pub fn with_immutables(mut self, immutables: BTreeMap<String, Vec<usize>>) -> Self {
+        if let Self::EVM(instance) = &mut self {
+            instance.immutables = immutables;
+        }
+        self
+    }
+
     ///
     /// Sets the address of the instance.
     ///
@@ -95,6 +107,17 @@ impl Instance {
         }
     }
 
+    ///
+    /// Returns the byte offsets of the immutables in the deployed code by the immutable names,
+    /// if it is an EVM instance.
+    ///
+    pub fn immutables(&self) -> Option<&BTreeMap<String, Vec<usize>>> {
+        match self {
+            Self::EraVM(_) => None,
+            Self::EVM(instance) => Some(&instance.immutables),
+        }
+    }
+
     ///
     /// Returns the instance address if applicable.
     ///
@@ -9,21 +9,30 @@ pub mod instance;
 pub mod selector;
 
 use solidity_adapter::EVMVersion;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use sha3::Digest;
+
 use crate::compilers::mode::Mode;
+use crate::environment::Environment;
 use crate::summary::Summary;
+use crate::test::case::vm_state::VMState;
 use crate::test::case::Case;
 use crate::test::context::case::CaseContext;
 use crate::test::context::input::InputContext;
-use crate::vm::eravm::deployers::EraVMDeployer;
+use crate::test::description::TestDescription;
+use crate::test::selector::TestSelector;
+use crate::vm::eravm::runner_settings::RunnerSettings;
 use crate::vm::eravm::EraVM;
 use crate::vm::evm::input::build::Build as EVMBuild;
 use crate::vm::evm::invoker::Invoker as EVMInvoker;
 use crate::vm::evm::runtime::Runtime as EVMRuntime;
 use crate::vm::evm::EVM;
+use crate::vm::revm::Revm;
 
 ///
 /// The test.
@@ -40,10 +49,14 @@ pub struct Test {
     group: Option<String>,
     /// The EraVM contract builds.
     eravm_builds: HashMap<web3::types::U256, Vec<u8>>,
-    /// The EVM contract builds.
-    evm_builds: HashMap<String, EVMBuild>,
+    /// The EraVM contract function sizes, if the assembly is available.
+    eravm_function_sizes: HashMap<web3::types::U256, BTreeMap<String, usize>>,
+    /// The EVM contract builds, shared with the failure handlers outliving the test runs.
+    evm_builds: Arc<HashMap<String, EVMBuild>>,
     /// The EVM version.
     evm_version: Option<EVMVersion>,
+    /// Whether the EVM bytecodes are published before running the cases on EVM interpreter.
+    is_evm_bytecode_prepublication_enabled: bool,
 }
 
 impl Test {
@@ -56,8 +69,10 @@ impl Test {
         mode: Mode,
         group: Option<String>,
         eravm_builds: HashMap<web3::types::U256, Vec<u8>>,
+        eravm_function_sizes: HashMap<web3::types::U256, BTreeMap<String, usize>>,
         evm_builds: HashMap<String, EVMBuild>,
         evm_version: Option<EVMVersion>,
+        is_evm_bytecode_prepublication_enabled: bool,
     ) -> Self {
         Self {
             name,
@@ -65,85 +80,252 @@ impl Test {
             mode,
             group,
             eravm_builds,
-            evm_builds,
+            eravm_function_sizes,
+            evm_builds: Arc::new(evm_builds),
             evm_version,
+            is_evm_bytecode_prepublication_enabled,
+        }
+    }
+
+    ///
+    /// Returns the description of the whole test.
+    ///
+    pub fn description(&self) -> TestDescription {
+        TestDescription {
+            group: self.group.clone(),
+            mode: Some(self.mode.clone()),
+            selector: TestSelector {
+                path: self.name.clone(),
+                case: None,
+                input: None,
+            },
+        }
+    }
+
+    ///
+    /// Returns the test cases.
+    ///
+    pub fn cases(&self) -> &[Case] {
+        self.cases.as_slice()
+    }
+
+    ///
+    /// Returns the EraVM contract builds by their bytecode hashes.
+    ///
+    pub fn eravm_builds(&self) -> &HashMap<web3::types::U256, Vec<u8>> {
+        &self.eravm_builds
+    }
+
+    ///
+    /// Returns the EVM contract builds by their contract identifiers.
+    ///
+    pub fn evm_builds(&self) -> &Arc<HashMap<String, EVMBuild>> {
+        &self.evm_builds
+    }
+
+    ///
+    /// Returns the result cache key of the test.
+    ///
+    /// The key is the hash of the contract builds, case inputs, `environment`, and the
+    /// `vm_configuration_hash`, e.g. of the system contracts or REVM address overrides.
+    /// The cases are hashed in their serialized form, with the hash maps ordered by key,
+    /// so that the key is stable across runs and toolchains.
+    ///
+    pub fn result_cache_key(
+        &self,
+        environment: Environment,
+        vm_configuration_hash: Option<&str>,
+    ) -> String {
+        let mut hasher = sha3::Keccak256::new();
+        for (bytecode_hash, bytecode) in self.eravm_builds.iter().collect::<BTreeMap<_, _>>() {
+            hasher.update(crate::utils::u256_to_h256(bytecode_hash).as_bytes());
+            hasher.update(bytecode.as_slice());
+        }
+        for (name, build) in self.evm_builds.iter().collect::<BTreeMap<_, _>>() {
+            hasher.update(name.as_bytes());
+            hasher.update(build.deploy_build.as_slice());
+            hasher.update(build.runtime_build.as_slice());
         }
+        hasher.update(
+            bincode::serialize(&self.cases)
+                .expect("Always valid")
+                .as_slice(),
+        );
+        if let Some(evm_version) = self.evm_version {
+            hasher.update(evm_version.to_string().as_bytes());
+        }
+        hasher.update(environment.to_string().as_bytes());
+        if let Some(vm_configuration_hash) = vm_configuration_hash {
+            hasher.update(vm_configuration_hash.as_bytes());
+        }
+        hex::encode(hasher.finalize())
     }
 
     ///
     /// Runs the test on EraVM.
     ///
-    pub fn run_eravm<D, const M: bool>(self, summary: Arc<Mutex<Summary>>, vm: Arc<EraVM>)
-    where
-        D: EraVMDeployer,
-    {
+    /// Each case starts from a fresh VM, unless it shares the state left by the previous case.
+    ///
+    pub fn run_eravm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        vm: Arc<EraVM>,
+        runner_settings: RunnerSettings,
+    ) {
         let context = CaseContext {
             name: &self.name,
             mode: &self.mode,
             group: &self.group,
+            eravm_function_sizes: &self.eravm_function_sizes,
+            revm_trace_directory: None,
+            revm_address_overrides: &[],
         };
+        let mut previous_vm = None;
         for case in self.cases {
-            let vm = EraVM::clone_with_contracts(vm.clone(), self.eravm_builds.clone(), None);
-            case.run_eravm::<D, M>(summary.clone(), vm.clone(), &context);
+            let case_vm = match (case.vm_state(), previous_vm.take()) {
+                (VMState::Shared, Some(previous_vm)) => previous_vm,
+                _ => {
+                    let mut vm =
+                        EraVM::clone_with_contracts(vm.clone(), self.eravm_builds.clone(), None);
+                    vm.deploy_group_system_contracts(self.group.as_deref());
+                    vm
+                }
+            };
+            previous_vm = Some(case.run_eravm(summary.clone(), case_vm, &context, runner_settings));
         }
     }
 
     ///
     /// Runs the test on EVM emulator.
     ///
+    /// Each case starts from a fresh VM, unless it shares the state left by the previous case.
+    ///
     pub fn run_evm_emulator(self, summary: Arc<Mutex<Summary>>) {
-        for case in self.cases {
-            let config = evm::standard::Config::shanghai();
-            let etable =
-                evm::Etable::<evm::standard::State, EVMRuntime, evm::trap::CallCreateTrap>::runtime(
-                );
-            let resolver = evm::standard::EtableResolver::new(&config, &(), &etable);
-            let invoker = EVMInvoker::new(&config, &resolver);
+        let config = evm::standard::Config::shanghai();
+        let etable =
+            evm::Etable::<evm::standard::State, EVMRuntime, evm::trap::CallCreateTrap>::runtime();
+        let resolver = evm::standard::EtableResolver::new(&config, &(), &etable);
 
-            let vm = EVM::new(self.evm_builds.clone(), invoker);
+        let mut previous_vm = None;
+        for case in self.cases {
+            let vm = match (case.vm_state(), previous_vm.take()) {
+                (VMState::Shared, Some(previous_vm)) => previous_vm,
+                _ => EVM::new(
+                    self.evm_builds.as_ref().clone(),
+                    EVMInvoker::new(&config, &resolver),
+                ),
+            };
 
             let context = CaseContext {
                 name: &self.name,
                 mode: &self.mode,
                 group: &self.group,
+                eravm_function_sizes: &self.eravm_function_sizes,
+                revm_trace_directory: None,
+                revm_address_overrides: &[],
             };
-            case.run_evm_emulator(summary.clone(), vm, &context);
+            previous_vm = Some(case.run_evm_emulator(summary.clone(), vm, &context));
         }
     }
 
     ///
     /// Runs the test on REVM.
     ///
-    pub fn run_revm(self, summary: Arc<Mutex<Summary>>) {
+    /// If `trace_directory` is set, the traces of failed inputs are written there.
+    /// The `address_overrides` stubs are installed into every fresh VM.
+    ///
+    /// Each case starts from a fresh VM, unless it shares the state left by the previous case.
+    ///
+    pub fn run_revm(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        trace_directory: Option<&Path>,
+        address_overrides: &[(web3::types::Address, Vec<u8>)],
+    ) {
+        let mut previous_vm = None;
+        let mut registered_addresses = HashMap::new();
         for case in self.cases {
             let context = CaseContext {
                 name: &self.name,
                 mode: &self.mode,
                 group: &self.group,
+                eravm_function_sizes: &self.eravm_function_sizes,
+                revm_trace_directory: trace_directory,
+                revm_address_overrides: address_overrides,
+            };
+            let vm = match (case.vm_state(), previous_vm.take()) {
+                (VMState::Shared, Some(previous_vm)) => previous_vm,
+                (_, previous_vm) => {
+                    if let Some(previous_vm) = previous_vm {
+                        previous_vm.release();
+                    }
+                    registered_addresses.clear();
+                    let mut vm = Revm::acquire();
+                    vm.install_address_overrides(address_overrides);
+                    vm
+                }
             };
-            case.run_revm(summary.clone(), self.evm_version, &context);
+            previous_vm = Some(case.run_revm(
+                summary.clone(),
+                vm,
+                self.evm_version,
+                &context,
+                &mut registered_addresses,
+            ));
+        }
+        if let Some(previous_vm) = previous_vm {
+            previous_vm.release();
         }
     }
 
     ///
     /// Runs the test on EVM interpreter.
     ///
-    pub fn run_evm_interpreter<D, const M: bool>(self, summary: Arc<Mutex<Summary>>, vm: Arc<EraVM>)
-    where
-        D: EraVMDeployer,
-    {
+    /// Unless disabled, the EVM bytecodes of the test are published in advance, so that the
+    /// deploy inputs only measure the deployment itself.
+    ///
+    /// Each case starts from a fresh VM, unless it shares the state left by the previous case.
+    ///
+    pub fn run_evm_interpreter(
+        self,
+        summary: Arc<Mutex<Summary>>,
+        vm: Arc<EraVM>,
+        runner_settings: RunnerSettings,
+    ) {
+        let vm = if self.is_evm_bytecode_prepublication_enabled && !self.evm_builds.is_empty() {
+            let mut vm =
+                EraVM::clone_with_contracts(vm, self.eravm_builds.clone(), self.evm_version);
+            let runtime_codes = self
+                .evm_builds
+                .values()
+                .map(|build| build.runtime_build.to_owned())
+                .collect();
+            vm.prepublish_evm_bytecodes(runtime_codes);
+            Arc::new(vm)
+        } else {
+            vm
+        };
+
+        let mut previous_vm = None;
         for case in self.cases {
-            let vm = EraVM::clone_with_contracts(
-                vm.clone(),
-                self.eravm_builds.clone(),
-                self.evm_version,
-            );
+            let case_vm = match (case.vm_state(), previous_vm.take()) {
+                (VMState::Shared, Some(previous_vm)) => previous_vm,
+                _ => EraVM::clone_with_contracts(
+                    vm.clone(),
+                    self.eravm_builds.clone(),
+                    self.evm_version,
+                ),
+            };
             let context = CaseContext {
                 name: &self.name,
                 mode: &self.mode,
                 group: &self.group,
+                eravm_function_sizes: &self.eravm_function_sizes,
+                revm_trace_directory: None,
+                revm_address_overrides: &[],
             };
-            case.run_evm_interpreter::<D, M>(summary.clone(), vm, &context);
+            previous_vm =
+                Some(case.run_evm_interpreter(summary.clone(), case_vm, &context, runner_settings));
         }
     }
 }
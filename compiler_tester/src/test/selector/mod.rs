@@ -2,6 +2,8 @@
 //! Test selector, unambiously locating a test suite, or a specific input.
 //!
 
+pub mod registry;
+
 use crate::test::case::input::identifier::InputIdentifier;
 
 ///
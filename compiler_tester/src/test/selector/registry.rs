@@ -0,0 +1,40 @@
+//!
+//! The test selector registry.
+//!
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use crate::test::selector::TestSelector;
+
+///
+/// The test selector registry.
+///
+/// Ensures that each selector is discovered only once per run across all test directories,
+/// since the summary and benchmark entries are keyed by the selector string representation.
+///
+#[derive(Debug, Default)]
+pub struct Registry {
+    /// The registered selectors.
+    selectors: Mutex<HashSet<String>>,
+}
+
+impl Registry {
+    ///
+    /// Registers the `selector`.
+    ///
+    /// Returns an error if the selector has already been registered.
+    ///
+    pub fn register(&self, selector: &TestSelector) -> anyhow::Result<()> {
+        let selector = selector.to_string();
+        if !self
+            .selectors
+            .lock()
+            .expect("Sync")
+            .insert(selector.clone())
+        {
+            anyhow::bail!("Duplicate test selector `{selector}`");
+        }
+        Ok(())
+    }
+}
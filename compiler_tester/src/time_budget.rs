@@ -0,0 +1,348 @@
+//!
+//! The compiler tester time budget.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use colored::Colorize;
+
+use crate::compilers::mode::Mode;
+use crate::filters::Filters;
+use crate::summary::report::Report;
+use crate::GenericTest;
+
+///
+/// The compiler tester time budget.
+///
+/// Selects the tests fitting the budget using their wall times recorded in the summary
+/// of a previous run. The tests failed in that run are selected first, then the ones changed
+/// since then, and then the rest. Within each of these groups, the fastest tests are
+/// selected first, so the number of selected tests is maximized.
+///
+/// As the tests are run by several workers concurrently, each selected test is assigned to
+/// the least loaded worker, and the budget limits the wall time of every worker.
+///
+#[derive(Debug)]
+pub struct TimeBudget {
+    /// The budget in milliseconds.
+    budget_ms: u64,
+    /// The wall times of the previous run in milliseconds, by the test target, mode, and path.
+    durations: BTreeMap<String, u64>,
+    /// The tests failed in the previous run, by the test target, mode, and path.
+    failing: BTreeSet<String>,
+    /// The hashes of the test files in the previous run, by their paths.
+    file_hashes: BTreeMap<String, String>,
+    /// The estimated wall time in milliseconds of the tests without a recorded one.
+    estimate_ms: u64,
+    /// The selection statistics, shared by the runs of all targets.
+    statistics: Mutex<Statistics>,
+}
+
+///
+/// The time budget selection statistics.
+///
+#[derive(Debug)]
+struct Statistics {
+    /// The estimated wall times of the workers in milliseconds.
+    loads: Vec<u64>,
+    /// The number of selected tests.
+    selected: usize,
+    /// The total number of tests.
+    total: usize,
+}
+
+///
+/// The time budget selection priority, from the highest to the lowest.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Priority {
+    /// The test has failed in the previous run.
+    Failed,
+    /// The test file has been changed since the previous run.
+    Changed,
+    /// The rest of the tests.
+    Other,
+}
+
+impl TimeBudget {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Reads the wall times from the summary at `path`, written by a previous run with
+    /// `--summary-output`. The tests are run by the number of `workers`.
+    ///
+    pub fn new(budget: Duration, path: PathBuf, workers: usize) -> anyhow::Result<Self> {
+        let report = Report::try_from(path.clone())?;
+        if report.durations.is_empty() {
+            anyhow::bail!("Summary file {path:?} does not contain the test wall times");
+        }
+
+        Ok(Self::from_report(budget, report, workers))
+    }
+
+    ///
+    /// Creates the budget from the `report` of a previous run.
+    ///
+    fn from_report(budget: Duration, report: Report, workers: usize) -> Self {
+        let estimate_ms = report.durations.values().sum::<u64>() / report.durations.len() as u64;
+        Self {
+            budget_ms: budget.as_millis() as u64,
+            durations: report.durations,
+            failing: report.failing,
+            file_hashes: report.file_hashes,
+            estimate_ms,
+            statistics: Mutex::new(Statistics {
+                loads: vec![0; workers.max(1)],
+                selected: 0,
+                total: 0,
+            }),
+        }
+    }
+
+    ///
+    /// Selects the tests fitting the remaining budget.
+    ///
+    /// The tests whose modes are filtered out are kept, as they are skipped before being built
+    /// and do not spend the budget.
+    ///
+    pub fn select(
+        &self,
+        target: era_compiler_common::Target,
+        tests: Vec<GenericTest>,
+        filters: &Filters,
+    ) -> Vec<GenericTest> {
+        let mut changed = BTreeMap::new();
+        let mut candidates = Vec::with_capacity(tests.len());
+        for (index, (test, _, mode)) in tests.iter().enumerate() {
+            if !filters.check_mode(mode) {
+                continue;
+            }
+
+            let path = test.selector().path.as_str();
+            let key = self.key(target, mode, path);
+            let duration_ms = self
+                .durations
+                .get(key.as_str())
+                .copied()
+                .unwrap_or(self.estimate_ms);
+            let priority = if self.failing.contains(key.as_str()) {
+                Priority::Failed
+            } else if *changed
+                .entry(path.to_owned())
+                .or_insert_with(|| self.is_changed(path))
+            {
+                Priority::Changed
+            } else {
+                Priority::Other
+            };
+            candidates.push((priority, duration_ms, index));
+        }
+        let is_selected = self.allocate(candidates, tests.len());
+
+        tests
+            .into_iter()
+            .zip(is_selected)
+            .filter_map(|(test, is_selected)| is_selected.then_some(test))
+            .collect()
+    }
+
+    ///
+    /// Assigns the `candidates` of the `count` tests to the least loaded workers in the order
+    /// of their priority and duration, and returns whether each test has been selected.
+    ///
+    /// The tests without candidates are always selected.
+    ///
+    fn allocate(&self, mut candidates: Vec<(Priority, u64, usize)>, count: usize) -> Vec<bool> {
+        candidates.sort();
+
+        let mut statistics = self.statistics.lock().expect("Sync");
+        statistics.total += candidates.len();
+        let mut is_selected = vec![true; count];
+        for (_, duration_ms, index) in candidates.into_iter() {
+            let load = statistics.loads.iter_mut().min().expect("Always exists");
+            if *load + duration_ms <= self.budget_ms {
+                *load += duration_ms;
+                statistics.selected += 1;
+            } else {
+                is_selected[index] = false;
+            }
+        }
+        is_selected
+    }
+
+    ///
+    /// Adds the wall times and file hashes of the tests that have not been selected to
+    /// the `report`, so its summary can be used by the subsequent budgeted runs.
+    ///
+    /// The previous hashes of the files left out are kept, so they are still considered
+    /// changed by the next run.
+    ///
+    pub fn complete_report(&self, report: &mut Report) {
+        for (name, wall_time_ms) in self.durations.iter() {
+            report
+                .durations
+                .entry(name.to_owned())
+                .or_insert(*wall_time_ms);
+        }
+        for (path, hash) in self.file_hashes.iter() {
+            report
+                .file_hashes
+                .entry(path.to_owned())
+                .or_insert_with(|| hash.to_owned());
+        }
+    }
+
+    ///
+    /// Returns the key of the test in the previous run.
+    ///
+    /// The key starts with the target only if several targets were run, so the key with
+    /// the target is preferred if it is known.
+    ///
+    fn key(&self, target: era_compiler_common::Target, mode: &Mode, path: &str) -> String {
+        let key = format!("{target} {mode} {path}");
+        if self.durations.contains_key(key.as_str()) || self.failing.contains(key.as_str()) {
+            key
+        } else {
+            format!("{mode} {path}")
+        }
+    }
+
+    ///
+    /// Whether the test file at `path` has been changed since the previous run.
+    ///
+    /// The file is compared by its contents hash, so a checkout or a touch does not make it
+    /// changed. The files missing in the previous run are changed, unless the summary does not
+    /// contain the hashes at all.
+    ///
+    fn is_changed(&self, path: &str) -> bool {
+        if self.file_hashes.is_empty() {
+            return false;
+        }
+
+        let path = crate::utils::test_file_path(path);
+        match self.file_hashes.get(path) {
+            Some(hash) => crate::utils::file_hash(Path::new(path)).as_ref() != Some(hash),
+            None => true,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeBudget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let statistics = self.statistics.lock().expect("Sync");
+        let spent_ms = statistics.loads.iter().max().copied().unwrap_or_default();
+        writeln!(
+            f,
+            "    {} {} of {} tests with the estimated time of {}m{:02}s on {} workers within the {}m{:02}s budget",
+            "Selected".bright_green().bold(),
+            statistics.selected,
+            statistics.total,
+            spent_ms / 60_000,
+            spent_ms / 1000 % 60,
+            statistics.loads.len(),
+            self.budget_ms / 60_000,
+            self.budget_ms / 1000 % 60,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use crate::summary::report::Report;
+
+    use super::Priority;
+    use super::TimeBudget;
+
+    ///
+    /// Creates a budget of `budget_ms` for `workers` from a report with the `file_hashes`.
+    ///
+    fn time_budget(
+        budget_ms: u64,
+        workers: usize,
+        file_hashes: BTreeMap<String, String>,
+    ) -> TimeBudget {
+        let report = Report {
+            durations: BTreeMap::from([("M3B3 tests/test.sol".to_owned(), 1000)]),
+            file_hashes,
+            ..Default::default()
+        };
+        TimeBudget::from_report(Duration::from_millis(budget_ms), report, workers)
+    }
+
+    #[test]
+    fn allocate() {
+        let candidates = vec![
+            (Priority::Other, 400, 0),
+            (Priority::Other, 300, 1),
+            (Priority::Changed, 500, 2),
+            (Priority::Failed, 600, 3),
+            (Priority::Other, 100, 4),
+        ];
+        for (workers, expected, loads) in [
+            (1, vec![false, false, false, true, true], vec![700]),
+            (2, vec![false, true, true, true, true], vec![900, 600]),
+            (3, vec![true, true, true, true, true], vec![600, 500, 800]),
+        ] {
+            let budget = time_budget(900, workers, BTreeMap::new());
+
+            let is_selected = budget.allocate(candidates.clone(), 6);
+
+            let mut expected = expected;
+            expected.push(true);
+            assert_eq!(is_selected, expected, "{workers} workers");
+            let statistics = budget.statistics.lock().expect("Sync");
+            assert_eq!(statistics.loads, loads, "{workers} workers");
+            assert_eq!(statistics.total, candidates.len());
+        }
+    }
+
+    #[test]
+    fn is_changed() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-time-budget-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let unchanged = directory.join("unchanged.sol");
+        let changed = directory.join("changed.sol");
+        let added = directory.join("added.sol");
+        for path in [&unchanged, &changed, &added] {
+            std::fs::write(path, "contract Test {}").expect("Always valid");
+        }
+        let path = |path: &std::path::PathBuf| path.to_string_lossy().to_string();
+        let hash = crate::utils::file_hash(unchanged.as_path()).expect("Always valid");
+        let file_hashes = BTreeMap::from([
+            (path(&unchanged), hash.clone()),
+            (path(&changed), hash.clone()),
+        ]);
+        std::fs::write(changed.as_path(), "contract Test { uint x; }").expect("Always valid");
+
+        let budget = time_budget(1000, 1, file_hashes.clone());
+        for (test, expected) in [
+            (path(&unchanged), false),
+            (format!("{}::test", path(&unchanged)), false),
+            (path(&changed), true),
+            (path(&added), true),
+        ] {
+            assert_eq!(budget.is_changed(test.as_str()), expected, "{test}");
+        }
+
+        let mut report = Report::default();
+        report.file_hashes.insert(path(&unchanged), hash.clone());
+        budget.complete_report(&mut report);
+        assert_eq!(report.file_hashes, file_hashes);
+
+        let budget = time_budget(1000, 1, BTreeMap::new());
+        assert!(!budget.is_changed(path(&changed).as_str()));
+
+        std::fs::remove_dir_all(directory).expect("Always valid");
+    }
+}
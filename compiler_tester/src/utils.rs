@@ -2,8 +2,100 @@
 //! The compiler tester utils.
 //!
 
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
 use sha3::Digest;
 
+/// The sequences of characters that are not allowed in file names.
+static FILE_NAME_FORBIDDEN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new("[^A-Za-z0-9+.-]+").expect("Always valid"));
+
+///
+/// Replaces the characters that are not allowed in file names.
+///
+pub fn sanitize_file_name(name: &str) -> String {
+    FILE_NAME_FORBIDDEN.replace_all(name, "_").to_string()
+}
+
+/// The number of hexadecimal digits of the test selector hash in the debug subdirectory names.
+const DEBUG_SELECTOR_HASH_LENGTH: usize = 16;
+
+/// The debug artifact with the test selector, written to each test subdirectory.
+const DEBUG_SELECTOR_FILE_NAME: &str = "selector.txt";
+
+///
+/// Returns the debug configuration of the mode subdirectory.
+///
+/// The subdirectory is not created here, but together with the test subdirectories inside it,
+/// so that modes without any built tests do not leave empty directories behind.
+///
+pub fn debug_config_for_mode(
+    debug_config: &era_compiler_llvm_context::DebugConfig,
+    mode: &str,
+) -> era_compiler_llvm_context::DebugConfig {
+    let mut debug_config = debug_config.clone();
+    debug_config.output_directory = debug_config.output_directory.join(sanitize_file_name(mode));
+    debug_config
+}
+
+///
+/// Returns the debug configuration of the test subdirectory, if debugging is enabled.
+///
+/// The subdirectory is named after the test selector hash, so sanitized selectors never collide,
+/// and contains the full selector in `selector.txt`. The directory creation is safe to race.
+///
+pub fn debug_config_for_test(
+    debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+    test_path: &str,
+) -> anyhow::Result<Option<era_compiler_llvm_context::DebugConfig>> {
+    let mut debug_config = match debug_config {
+        Some(debug_config) => debug_config.clone(),
+        None => return Ok(None),
+    };
+    let hash = hex::encode(sha3::Keccak256::digest(test_path.as_bytes()));
+    debug_config
+        .output_directory
+        .push(&hash[..DEBUG_SELECTOR_HASH_LENGTH]);
+    std::fs::create_dir_all(debug_config.output_directory.as_path()).map_err(|error| {
+        anyhow::anyhow!(
+            "Debug directory {:?} creating: {error}",
+            debug_config.output_directory
+        )
+    })?;
+    write_debug_artifact(&debug_config, DEBUG_SELECTOR_FILE_NAME, test_path)?;
+    Ok(Some(debug_config))
+}
+
+///
+/// Writes a debug artifact to the debug output directory.
+///
+pub fn write_debug_artifact(
+    debug_config: &era_compiler_llvm_context::DebugConfig,
+    file_name: &str,
+    contents: impl AsRef<[u8]>,
+) -> anyhow::Result<()> {
+    let path = debug_config
+        .output_directory
+        .join(sanitize_file_name(file_name));
+    std::fs::write(path.as_path(), contents)
+        .map_err(|error| anyhow::anyhow!("Debug artifact {path:?} writing: {error}"))
+}
+
+///
+/// Serializes the hash `map` ordered by its keys, so that the serialized data does not depend
+/// on the iteration order.
+///
+pub fn serialize_sorted<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Ord + serde::Serialize + 'a,
+    V: serde::Serialize + 'a,
+    S: serde::Serializer,
+{
+    serializer.collect_map(map.into_iter().collect::<BTreeMap<&K, &V>>())
+}
+
 ///
 /// Returns a `keccak256` selector of the specified contract method.
 ///
@@ -12,6 +104,26 @@ pub fn selector(signature: &str) -> [u8; 4] {
     hash_bytes[0..4].try_into().expect("Always valid")
 }
 
+///
+/// Returns the path of the test file of the test at `path`.
+///
+/// The state tests are identified by their file path and the test name after `::`.
+///
+pub fn test_file_path(path: &str) -> &str {
+    path.split("::").next().expect("Always exists")
+}
+
+///
+/// Returns the hexadecimal SHA-256 hash of the file at `path`, if it can be read.
+///
+pub fn file_hash(path: &std::path::Path) -> Option<String> {
+    let contents = match std::fs::read(path) {
+        Ok(contents) => contents,
+        Err(_) => return None,
+    };
+    Some(hex::encode(sha2::Sha256::digest(contents.as_slice())))
+}
+
 ///
 /// Overrides the default formatting for `Address`, which replaces the middle with an ellipsis.
 ///
@@ -19,6 +131,41 @@ pub fn address_as_string(value: &web3::types::Address) -> String {
     hex::encode(value.as_bytes())
 }
 
+///
+/// Validates the EIP-55 checksum of a hexadecimal address literal without the `0x` prefix.
+///
+/// Literals in a single case are not checksummed and are always accepted.
+///
+pub fn validate_address_checksum(address: &str) -> anyhow::Result<()> {
+    let has_lowercase = address
+        .chars()
+        .any(|character| character.is_ascii_lowercase());
+    let has_uppercase = address
+        .chars()
+        .any(|character| character.is_ascii_uppercase());
+    if !has_lowercase || !has_uppercase {
+        return Ok(());
+    }
+
+    let lowercase = address.to_ascii_lowercase();
+    let hash = hex::encode(sha3::Keccak256::digest(lowercase.as_bytes()));
+    let checksummed: String = lowercase
+        .chars()
+        .zip(hash.chars())
+        .map(|(character, nibble)| {
+            if nibble >= '8' {
+                character.to_ascii_uppercase()
+            } else {
+                character
+            }
+        })
+        .collect();
+    if checksummed != address {
+        anyhow::bail!("Invalid address checksum, expected `0x{checksummed}`");
+    }
+    Ok(())
+}
+
 ///
 /// Overrides the default formatting for `U256`, which replaces the middle with an ellipsis.
 ///
@@ -75,3 +222,104 @@ pub fn u256_to_h256(value: &web3::types::U256) -> web3::types::H256 {
 pub fn h256_to_u256(value: &web3::types::H256) -> web3::types::U256 {
     web3::types::U256::from_big_endian(value.as_bytes())
 }
+
+///
+/// Shuffles the `items` with the Fisher-Yates algorithm driven by the SplitMix64 generator,
+/// so the same `seed` always yields the same order.
+///
+pub fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed;
+    for index in (1..items.len()).rev() {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut random = state;
+        random = (random ^ (random >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        random = (random ^ (random >> 27)).wrapping_mul(0x94d049bb133111eb);
+        random ^= random >> 31;
+        items.swap(index, (random % (index as u64 + 1)) as usize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    ///
+    /// The orders are pinned, so the seeds printed by the earlier runs keep reproducing them.
+    ///
+    #[test]
+    fn shuffle() {
+        for (seed, expected) in [
+            (0, [6, 3, 2, 9, 8, 1, 4, 7, 0, 5]),
+            (42, [0, 9, 5, 8, 6, 4, 7, 2, 1, 3]),
+            (43, [4, 2, 5, 6, 1, 3, 9, 8, 7, 0]),
+        ] {
+            for _ in 0..2 {
+                let mut items: Vec<usize> = (0..10).collect();
+                super::shuffle(items.as_mut_slice(), seed);
+                assert_eq!(items, expected, "seed {seed}");
+            }
+        }
+    }
+
+    ///
+    /// The test vectors of EIP-55.
+    ///
+    #[test]
+    fn validate_address_checksum() {
+        for address in [
+            "52908400098527886E0F7030069857D2E4169EE7",
+            "8617E340B3D01FA5F11F306F4090FD50E238070D",
+            "de709f2102306220921060314715629080e2fb77",
+            "27b1fdb04752bbc536007a920d24acb045561c26",
+            "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "D1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            assert!(
+                super::validate_address_checksum(address).is_ok(),
+                "Address `0x{address}` must be valid"
+            );
+        }
+
+        for (address, expected) in [
+            (
+                "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            ),
+            (
+                "fb6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+                "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            ),
+        ] {
+            let error = super::validate_address_checksum(address)
+                .expect_err("Always invalid")
+                .to_string();
+            assert!(
+                error.contains(expected),
+                "Address `0x{address}`: unexpected error `{error}`"
+            );
+        }
+    }
+
+    #[test]
+    fn serialize_sorted() {
+        let serialize = |map: &std::collections::HashMap<u64, u64>| {
+            let mut serializer = serde_json::Serializer::new(Vec::new());
+            super::serialize_sorted(map, &mut serializer).expect("Always valid");
+            String::from_utf8(serializer.into_inner()).expect("Always valid")
+        };
+
+        for _ in 0..8 {
+            let forward = (0..16).map(|key| (key, key * 2)).collect();
+            let backward = (0..16).rev().map(|key| (key, key * 2)).collect();
+            let expected = format!(
+                "{{{}}}",
+                (0..16)
+                    .map(|key| format!("\"{key}\":{}", key * 2))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            );
+            assert_eq!(serialize(&forward), expected);
+            assert_eq!(serialize(&backward), expected);
+        }
+    }
+}
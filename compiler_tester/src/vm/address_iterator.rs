@@ -26,4 +26,20 @@ pub trait AddressIterator {
     /// If the nonce for the `caller` does not exist, it will be created.
     ///
     fn nonce(&mut self, caller: &web3::types::Address) -> usize;
+
+    ///
+    /// Returns the address of a contract created by `deployer` with `CREATE2`.
+    ///
+    /// The `code_hash` is the init code hash on EVM, and the bytecode hash on EraVM.
+    /// The `input_hash` is the constructor input hash, which is only used on EraVM, as the
+    /// constructor input is a part of the init code on EVM.
+    ///
+    fn create2(
+        deployer: &web3::types::Address,
+        salt: &web3::types::H256,
+        code_hash: &web3::types::H256,
+        input_hash: &web3::types::H256,
+    ) -> web3::types::Address
+    where
+        Self: Sized;
 }
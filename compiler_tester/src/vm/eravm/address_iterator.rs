@@ -20,6 +20,10 @@ impl EraVMAddressIterator {
     /// The create prefix, `keccak256("zksyncCreate")`.
     const CREATE_PREFIX: &'static str =
         "63bae3a9951d38e8a3fbb7b70909afc1200610fc5bc55ade242f815974674f23";
+
+    /// The create2 prefix, `keccak256("zksyncCreate2")`.
+    const CREATE2_PREFIX: &'static str =
+        "2020dba91b30cc0006188af794c2fb30dd8520db7e2c088b7fc7c103c00ca494";
 }
 
 impl Default for EraVMAddressIterator {
@@ -81,4 +85,100 @@ impl AddressIterator for EraVMAddressIterator {
     fn nonce(&mut self, caller: &web3::types::Address) -> usize {
         *self.nonces.entry(*caller).or_default()
     }
+
+    fn create2(
+        deployer: &web3::types::Address,
+        salt: &web3::types::H256,
+        code_hash: &web3::types::H256,
+        input_hash: &web3::types::H256,
+    ) -> web3::types::Address {
+        let mut bytes = web3::types::H256::from_str(Self::CREATE2_PREFIX)
+            .expect("Invalid constant create2 prefix")
+            .as_bytes()
+            .to_vec();
+        bytes.extend(crate::utils::address_to_h256(deployer).as_bytes());
+        bytes.extend(salt.as_bytes());
+        bytes.extend(code_hash.as_bytes());
+        bytes.extend(input_hash.as_bytes());
+
+        web3::types::Address::from_slice(
+            &web3::signing::keccak256(bytes.as_slice())[era_compiler_common::BYTE_LENGTH_FIELD
+                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..],
+        )
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::vm::address_iterator::AddressIterator;
+
+    use super::EraVMAddressIterator;
+
+    ///
+    /// The prefixes are the `CREATE_PREFIX` and `CREATE2_PREFIX` constants of the zkSync system
+    /// contracts, which must be the hashes of their seeds.
+    ///
+    #[test]
+    fn prefixes() {
+        for (seed, prefix) in [
+            ("zksyncCreate", EraVMAddressIterator::CREATE_PREFIX),
+            ("zksyncCreate2", EraVMAddressIterator::CREATE2_PREFIX),
+        ] {
+            assert_eq!(
+                hex::encode(web3::signing::keccak256(seed.as_bytes())),
+                prefix,
+                "{seed}"
+            );
+        }
+    }
+
+    ///
+    /// The address is `keccak256(CREATE2_PREFIX ++ deployer ++ salt ++ bytecode hash ++ input hash)`
+    /// truncated to 20 bytes, as described in the zkSync docs.
+    ///
+    /// The vectors are computed with a separate Keccak-256 implementation, so they guard the
+    /// order of the preimage parts and the deployer padding.
+    ///
+    #[test]
+    fn create2() {
+        let bytecode_hash = "010001cb6a6e8d5f6829522f19fa9568660e0a9cd53b2e8be4deb0a679452e41";
+        for (deployer, salt, input, expected) in [
+            (
+                "36615Cf349d7F6344891B1e7CA7C72883F5dc049",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                Some(vec![]),
+                "3cfd6d969361fd475d81ba3e3306263ec88e1c61",
+            ),
+            (
+                "36615Cf349d7F6344891B1e7CA7C72883F5dc049",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                None,
+                "9526ff5498e41f26493668912838f48eed627872",
+            ),
+            (
+                "00000000000000000000000000000000deadbeef",
+                "00000000000000000000000000000000000000000000000000000000cafebabe",
+                Some(vec![0xde, 0xad, 0xbe, 0xef]),
+                "f0d3ae7e6a01c23df62a2d738626e1390de8fb48",
+            ),
+        ] {
+            let input_hash = match input {
+                Some(input) => web3::types::H256::from(web3::signing::keccak256(input.as_slice())),
+                None => web3::types::H256::zero(),
+            };
+            let address = EraVMAddressIterator::create2(
+                &web3::types::Address::from_str(deployer).expect("Always valid"),
+                &web3::types::H256::from_str(salt).expect("Always valid"),
+                &web3::types::H256::from_str(bytecode_hash).expect("Always valid"),
+                &input_hash,
+            );
+
+            assert_eq!(
+                address,
+                web3::types::Address::from_str(expected).expect("Always valid"),
+                "{deployer} {salt}"
+            );
+        }
+    }
 }
@@ -23,11 +23,6 @@ pub struct DummyDeployer {
     address_iterator: EraVMAddressIterator,
 }
 
-impl DummyDeployer {
-    /// The immutables mapping position in contract.
-    const IMMUTABLES_MAPPING_POSITION: web3::types::U256 = web3::types::U256::zero();
-}
-
 impl EraVMDeployer for DummyDeployer {
     fn new() -> Self {
         Self {
@@ -41,25 +36,25 @@ impl EraVMDeployer for DummyDeployer {
         caller: web3::types::Address,
         bytecode_hash: web3::types::U256,
         constructor_calldata: Vec<u8>,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         vm: &mut EraVM,
     ) -> anyhow::Result<ExecutionResult> {
         let address = self.address_iterator.next(&caller, false);
 
         vm.add_deployed_contract(address, bytecode_hash, None);
 
-        let context_u128_value = if let Some(value) = value {
-            vm.mint_ether(address, web3::types::U256::from(value));
+        let context_value = if let Some(value) = value {
+            vm.mint_ether(address, value);
             value
         } else {
-            0
+            web3::types::U256::zero()
         };
 
         let result = vm.execute::<M>(
             test_name,
             address,
             caller,
-            Some(context_u128_value),
+            Some(context_value),
             constructor_calldata,
             Some(zkevm_tester::compiler_tests::VmLaunchOption::ManualCallABI(
                 zkevm_tester::compiler_tests::FullABIParams {
@@ -74,7 +69,7 @@ impl EraVMDeployer for DummyDeployer {
 
         if result.output.exception {
             if let Some(value) = value {
-                vm.burn_ether(address, web3::types::U256::from(value));
+                vm.burn_ether(address, value);
             }
             vm.remove_deployed_contract(address);
             return Ok(result);
@@ -93,6 +88,8 @@ impl EraVMDeployer for DummyDeployer {
             result.cycles,
             result.ergs,
             result.gas,
+            result.stack_depth,
+            result.pubdata,
         ))
     }
 
@@ -102,7 +99,7 @@ impl EraVMDeployer for DummyDeployer {
         _caller: web3::types::Address,
         _deploy_code: Vec<u8>,
         _constructor_calldata: Vec<u8>,
-        _value: Option<u128>,
+        _value: Option<web3::types::U256>,
         _vm: &mut EraVM,
     ) -> anyhow::Result<ExecutionResult> {
         todo!()
@@ -160,7 +157,7 @@ impl DummyDeployer {
             let immutable_value =
                 web3::types::H256::from_token(immutable_value).expect("Always valid");
 
-            let immutable_position = Self::get_position_of_immutable(address, immutable_index);
+            let immutable_position = EraVM::get_position_of_immutable(address, immutable_index);
 
             let address = web3::types::Address::from_low_u64_be(
                 zkevm_opcode_defs::ADDRESS_IMMUTABLE_SIMULATOR.into(),
@@ -174,25 +171,4 @@ impl DummyDeployer {
 
         Ok(())
     }
-
-    ///
-    /// Returns the immutable position in the contract storage.
-    ///
-    fn get_position_of_immutable(
-        address: web3::types::Address,
-        index: web3::types::U256,
-    ) -> web3::types::U256 {
-        let mut key = web3::types::H256::from(address).to_fixed_bytes().to_vec();
-        key.extend([0u8; era_compiler_common::BYTE_LENGTH_FIELD]);
-        Self::IMMUTABLES_MAPPING_POSITION
-            .to_big_endian(&mut key[era_compiler_common::BYTE_LENGTH_FIELD..]);
-        let key = web3::signing::keccak256(key.as_slice()).to_vec();
-
-        let mut nested_key = vec![0u8; era_compiler_common::BYTE_LENGTH_FIELD];
-        index.to_big_endian(&mut nested_key[..]);
-        nested_key.extend(key);
-        let nested_key = web3::signing::keccak256(nested_key.as_slice());
-
-        web3::types::U256::from(nested_key)
-    }
 }
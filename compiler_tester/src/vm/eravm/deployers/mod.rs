@@ -26,7 +26,7 @@ pub trait EraVMDeployer {
         caller: web3::types::Address,
         bytecode_hash: web3::types::U256,
         constructor_calldata: Vec<u8>,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         vm: &mut EraVM,
     ) -> anyhow::Result<ExecutionResult>;
 
@@ -39,7 +39,7 @@ pub trait EraVMDeployer {
         caller: web3::types::Address,
         deploy_code: Vec<u8>,
         constructor_calldata: Vec<u8>,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         vm: &mut EraVM,
     ) -> anyhow::Result<ExecutionResult>;
 }
@@ -31,19 +31,18 @@ impl EraVMDeployer for SystemContractDeployer {
         caller: web3::types::Address,
         bytecode_hash: web3::types::U256,
         constructor_calldata: Vec<u8>,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         vm: &mut EraVM,
     ) -> anyhow::Result<ExecutionResult> {
         let mut entry_address = web3::types::Address::from_low_u64_be(
             zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
         );
 
-        let (vm_launch_option, context_u128_value) = if M {
+        let (vm_launch_option, context_value) = if M {
             let mut r3 = None;
             let mut r4 = None;
             let mut r5 = None;
             if let Some(value) = value {
-                let value = web3::types::U256::from(value);
                 vm.mint_ether(caller, value);
 
                 r3 = Some(value);
@@ -68,18 +67,18 @@ impl EraVMDeployer for SystemContractDeployer {
                     r5_value: r5,
                 },
             );
-            (vm_launch_option, 0)
+            (vm_launch_option, web3::types::U256::zero())
         } else {
-            let context_u128_value = if let Some(value) = value {
+            let context_value = if let Some(value) = value {
                 vm.mint_ether(
                     web3::types::Address::from_low_u64_be(
                         zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
                     ),
-                    web3::types::U256::from(value),
+                    value,
                 );
                 value
             } else {
-                0
+                web3::types::U256::zero()
             };
             let vm_launch_option = zkevm_tester::compiler_tests::VmLaunchOption::ManualCallABI(
                 zkevm_tester::compiler_tests::FullABIParams {
@@ -90,7 +89,7 @@ impl EraVMDeployer for SystemContractDeployer {
                     r5_value: None,
                 },
             );
-            (vm_launch_option, context_u128_value)
+            (vm_launch_option, context_value)
         };
 
         let mut calldata = Vec::with_capacity(
@@ -112,7 +111,7 @@ impl EraVMDeployer for SystemContractDeployer {
             test_name,
             entry_address,
             caller,
-            Some(context_u128_value),
+            Some(context_value),
             calldata,
             Some(vm_launch_option),
         )
@@ -124,23 +123,22 @@ impl EraVMDeployer for SystemContractDeployer {
         caller: web3::types::Address,
         deploy_code: Vec<u8>,
         constructor_calldata: Vec<u8>,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         vm: &mut EraVM,
     ) -> anyhow::Result<ExecutionResult> {
-        let context_u128_value;
+        let context_value;
         let vm_launch_option;
         let mut entry_address = web3::types::Address::from_low_u64_be(
             zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
         );
 
         if M {
-            context_u128_value = 0;
+            context_value = web3::types::U256::zero();
 
             let mut r3 = None;
             let mut r4 = None;
             let mut r5 = None;
             if let Some(value) = value {
-                let value = web3::types::U256::from(value);
                 vm.mint_ether(caller, value);
 
                 r3 = Some(value);
@@ -167,15 +165,15 @@ impl EraVMDeployer for SystemContractDeployer {
             );
         } else {
             if let Some(value) = value {
-                context_u128_value = value;
+                context_value = value;
                 vm.mint_ether(
                     web3::types::Address::from_low_u64_be(
                         zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
                     ),
-                    web3::types::U256::from(value),
+                    value,
                 );
             } else {
-                context_u128_value = 0;
+                context_value = web3::types::U256::zero();
             }
 
             vm_launch_option = zkevm_tester::compiler_tests::VmLaunchOption::ManualCallABI(
@@ -213,7 +211,7 @@ impl EraVMDeployer for SystemContractDeployer {
             test_name,
             entry_address,
             caller,
-            Some(context_u128_value),
+            Some(context_value),
             calldata,
             Some(vm_launch_option),
         )
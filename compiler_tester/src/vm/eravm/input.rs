@@ -6,6 +6,7 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use crate::test::instance::Instance;
+use crate::vm::eravm::EraVM;
 
 ///
 /// The EraVM compiler input.
@@ -16,8 +17,12 @@ pub struct Input {
     pub builds: HashMap<String, era_compiler_llvm_context::EraVMBuild>,
     /// The contracts method identifiers.
     pub method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
+    /// The contracts ABI.
+    pub abis: Option<BTreeMap<String, serde_json::Value>>,
     /// The last contract name.
     pub last_contract: String,
+    /// The compiler warnings.
+    pub warnings: Vec<String>,
 }
 
 impl Input {
@@ -27,15 +32,74 @@ impl Input {
     pub fn new(
         builds: HashMap<String, era_compiler_llvm_context::EraVMBuild>,
         method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
+        abis: Option<BTreeMap<String, serde_json::Value>>,
         last_contract: String,
     ) -> Self {
         Self {
             builds,
             method_identifiers,
+            abis,
             last_contract,
+            warnings: vec![],
         }
     }
 
+    ///
+    /// Sets the compiler warnings.
+    ///
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    ///
+    /// Writes the method identifiers and the final assembly listings to the debug directory.
+    ///
+    pub fn write_debug_artifacts(
+        &self,
+        debug_config: &era_compiler_llvm_context::DebugConfig,
+    ) -> anyhow::Result<()> {
+        if let Some(method_identifiers) = self.method_identifiers.as_ref() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                "method_identifiers.json",
+                serde_json::to_vec_pretty(method_identifiers)?,
+            )?;
+        }
+        for (path, build) in self.builds.iter() {
+            if let Some(assembly) = build.assembly.as_ref() {
+                crate::utils::write_debug_artifact(
+                    debug_config,
+                    format!(
+                        "{path}.final.{}",
+                        era_compiler_common::EXTENSION_ERAVM_ASSEMBLY
+                    )
+                    .as_str(),
+                    assembly,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the function code sizes of the builds with assembly, keyed by the bytecode hash.
+    ///
+    pub fn function_sizes(&self) -> HashMap<web3::types::U256, BTreeMap<String, usize>> {
+        self.builds
+            .values()
+            .filter_map(|build| {
+                let assembly = build.assembly.as_deref()?;
+                Some((
+                    web3::types::U256::from_big_endian(
+                        build.bytecode_hash.expect("Always exists").as_slice(),
+                    ),
+                    EraVM::function_sizes(assembly),
+                ))
+            })
+            .collect()
+    }
+
     ///
     /// Returns all contract instances.
     ///
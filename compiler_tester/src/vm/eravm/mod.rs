@@ -5,14 +5,18 @@
 pub mod address_iterator;
 pub mod deployers;
 pub mod input;
+pub mod runner_settings;
 pub mod system_context;
 pub mod system_contracts;
+pub mod system_contracts_profile;
 
 #[cfg(feature = "vm2")]
 mod vm2_adapter;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::ops::Add;
+use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -20,13 +24,17 @@ use std::time::Duration;
 use std::time::Instant;
 
 use colored::Colorize;
+use sha3::Digest;
 use solidity_adapter::EVMVersion;
 
+use crate::download_verifier::DownloadVerifier;
+use crate::test::case::input::output::Output;
 use crate::vm::execution_result::ExecutionResult;
+use crate::vm::storage_access::StorageAccess;
 
 use self::system_context::SystemContext;
 use self::system_contracts::SystemContracts;
-use self::system_contracts::ADDRESS_EVM_GAS_MANAGER;
+use self::system_contracts_profile::SystemContractsProfile;
 
 ///
 /// The EraVM interface.
@@ -45,10 +53,21 @@ pub struct EraVM {
     published_evm_bytecodes: HashMap<web3::types::U256, Vec<web3::types::U256>>,
     /// The storage state.
     storage: HashMap<zkevm_tester::compiler_tests::StorageKey, web3::types::H256>,
+    /// The storage state before the last execution, or only the previous values of the slots
+    /// changed by it with the `vm2` feature, which are zero for the slots written for the first time.
+    previous_storage: HashMap<zkevm_tester::compiler_tests::StorageKey, web3::types::H256>,
     /// The transient storage state.
     storage_transient: HashMap<zkevm_tester::compiler_tests::StorageKey, web3::types::H256>,
     /// The current EVM block number.
     current_evm_block_number: u128,
+    /// The default gas limit of calls on the EVM interpreter.
+    evm_gas_limit: u64,
+    /// The system contracts deployed on first access, as `address -> (bytecode hash, bytecode)`.
+    lazy_system_contracts: HashMap<web3::types::Address, (web3::types::U256, Vec<u8>)>,
+    /// The lazy system contracts required by test groups.
+    system_contracts_groups: Arc<BTreeMap<String, Vec<web3::types::Address>>>,
+    /// The system contracts profile, which resolves the system contract addresses.
+    system_contracts_profile: Arc<SystemContractsProfile>,
 }
 
 impl EraVM {
@@ -65,15 +84,33 @@ impl EraVM {
     /// The `auxData` variable transient storage slot in the `EvmGasManager` contract.
     pub const EVM_GAS_MANAGER_AUX_DATA_TRANSIENT_SLOT: u64 = 5;
 
-    /// The EVM call gas limit.
+    /// The default EVM call gas limit.
     pub const EVM_CALL_GAS_LIMIT: u64 = u32::MAX as u64;
 
+    /// The EraVM instruction size in bytes.
+    pub const INSTRUCTION_SIZE: usize = era_compiler_common::BYTE_LENGTH_X64;
+
+    /// The name of the constants pseudo-function in the function sizes.
+    pub const FUNCTION_SIZES_CONSTANTS: &'static str = "<constants>";
+
+    /// The immutables mapping position in the `ImmutableSimulator` contract.
+    pub const IMMUTABLES_MAPPING_POSITION: web3::types::U256 = web3::types::U256::zero();
+
+    /// The versioned hash prefix of the EVM bytecodes.
+    pub const EVM_BYTECODE_HASH_VERSION: u8 = 2;
+
+    /// The pubdata size of an initial storage write: the 32-byte derived key and the value.
+    pub const PUBDATA_INITIAL_WRITE_SIZE: usize = 64;
+
+    /// The pubdata size of a repeated storage write: the 8-byte enumeration index and the value.
+    pub const PUBDATA_REPEATED_WRITE_SIZE: usize = 40;
+
     ///
     /// Creates and initializes a new EraVM instance.
     ///
     pub fn new(
         executable_download_config_paths: Vec<PathBuf>,
-        system_contracts_solc_downloader_config_path: PathBuf,
+        system_contracts_profile: SystemContractsProfile,
         system_contracts_debug_config: Option<era_compiler_llvm_context::DebugConfig>,
         system_contracts_load_path: Option<PathBuf>,
         system_contracts_save_path: Option<PathBuf>,
@@ -92,10 +129,13 @@ impl EraVM {
         );
         let system_contracts_solc_downloader_config =
             era_compiler_downloader::Downloader::new(http_client.clone())
-                .download(system_contracts_solc_downloader_config_path.as_path())?;
+                .download(system_contracts_profile.solc_bin_config_path.as_path())?;
+        DownloadVerifier::new(http_client.clone())
+            .verify(system_contracts_profile.solc_bin_config_path.as_path())?;
         for config_path in executable_download_config_paths.into_iter() {
             era_compiler_downloader::Downloader::new(http_client.clone())
                 .download(config_path.as_path())?;
+            DownloadVerifier::new(http_client.clone()).verify(config_path.as_path())?;
         }
         println!(
             "    {} downloading compiler executables in {}m{:02}s",
@@ -117,11 +157,24 @@ impl EraVM {
 
         let system_contracts = SystemContracts::load_or_build(
             solc_version,
+            &system_contracts_profile,
             system_contracts_debug_config,
             system_contracts_load_path,
             system_contracts_save_path,
         )?;
 
+        let mut vm = Self::from_system_contracts(system_contracts, target);
+        vm.system_contracts_profile = Arc::new(system_contracts_profile);
+        Ok(vm)
+    }
+
+    ///
+    /// Initializes a new EraVM instance with the already built `system_contracts`.
+    ///
+    pub fn from_system_contracts(
+        system_contracts: SystemContracts,
+        target: era_compiler_common::Target,
+    ) -> Self {
         let storage = SystemContext::create_storage(target);
         let storage_transient = HashMap::new();
 
@@ -143,9 +196,14 @@ impl EraVM {
             ),
             deployed_contracts: HashMap::new(),
             storage,
+            previous_storage: HashMap::new(),
             storage_transient,
             published_evm_bytecodes: HashMap::new(),
             current_evm_block_number: SystemContext::INITIAL_BLOCK_NUMBER,
+            evm_gas_limit: Self::EVM_CALL_GAS_LIMIT,
+            lazy_system_contracts: HashMap::new(),
+            system_contracts_groups: Arc::new(BTreeMap::new()),
+            system_contracts_profile: Arc::new(SystemContractsProfile::default()),
         };
 
         vm.add_known_contract(
@@ -188,13 +246,140 @@ impl EraVM {
             );
         }
 
-        Ok(vm)
+        vm
+    }
+
+    ///
+    /// Returns the hash of the system contracts and other contracts known to the VM.
+    ///
+    pub fn system_contracts_hash(&self) -> String {
+        let mut hasher = sha3::Keccak256::new();
+        let mut known_contracts: Vec<&web3::types::U256> = self.known_contracts.keys().collect();
+        known_contracts.sort();
+        for bytecode_hash in known_contracts.into_iter() {
+            hasher.update(crate::utils::u256_to_h256(bytecode_hash).as_bytes());
+        }
+        let mut deployed_contracts: Vec<(&web3::types::Address, &Vec<u8>)> =
+            self.deployed_contracts.iter().collect();
+        deployed_contracts.sort();
+        for (address, bytecode) in deployed_contracts.into_iter() {
+            hasher.update(address.as_bytes());
+            hasher.update(bytecode.as_slice());
+        }
+        hasher.update(crate::utils::u256_to_h256(&self.default_aa_code_hash).as_bytes());
+        hasher.update(crate::utils::u256_to_h256(&self.evm_interpreter_code_hash).as_bytes());
+        for (group, addresses) in self.system_contracts_groups.iter() {
+            hasher.update(group.as_bytes());
+            for address in addresses.iter() {
+                hasher.update(address.as_bytes());
+            }
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    ///
+    /// Keeps only the minimal system contracts deployed, and deploys the others on first access.
+    ///
+    /// The other system contracts are moved to the known contracts and keep their code hashes in
+    /// the `AccountCodeStorage`, so the calls from the contract code are resolved by the VM code
+    /// lookup. They are also deployed explicitly when a test input calls them, or a test group
+    /// requires them.
+    ///
+    /// The optional `groups_path` YAML file maps test groups to the names of the system contracts
+    /// they require, e.g. `events: [event_writer, keccak256]`, where `*` stands for all of them.
+    ///
+    pub fn enable_minimal_system_contracts(
+        &mut self,
+        groups_path: Option<&Path>,
+    ) -> anyhow::Result<()> {
+        let minimal_addresses = SystemContracts::minimal_addresses();
+        let lazy_addresses: Vec<web3::types::Address> = self
+            .deployed_contracts
+            .keys()
+            .filter(|address| !minimal_addresses.contains(address))
+            .copied()
+            .collect();
+        for address in lazy_addresses.into_iter() {
+            let bytecode_hash = self
+                .storage
+                .get(&zkevm_tester::compiler_tests::StorageKey {
+                    address: web3::types::Address::from_low_u64_be(
+                        zkevm_opcode_defs::ADDRESS_ACCOUNT_CODE_STORAGE.into(),
+                    ),
+                    key: web3::types::U256::from_big_endian(address.as_bytes()),
+                })
+                .map(crate::utils::h256_to_u256)
+                .expect("Always exists");
+            let bytecode = self
+                .deployed_contracts
+                .remove(&address)
+                .expect("Always exists");
+            self.add_known_contract(bytecode.clone(), bytecode_hash);
+            self.lazy_system_contracts
+                .insert(address, (bytecode_hash, bytecode));
+        }
+
+        let groups_path = match groups_path {
+            Some(groups_path) => groups_path,
+            None => return Ok(()),
+        };
+        let text = std::fs::read_to_string(groups_path).map_err(|error| {
+            anyhow::anyhow!("System contracts groups file {groups_path:?} reading: {error}")
+        })?;
+        let groups: BTreeMap<String, Vec<String>> =
+            serde_yaml::from_str(text.as_str()).map_err(|error| {
+                anyhow::anyhow!("System contracts groups file {groups_path:?} parsing: {error}")
+            })?;
+        let mut system_contracts_groups = BTreeMap::new();
+        for (group, names) in groups.into_iter() {
+            let mut addresses = Vec::with_capacity(names.len());
+            for name in names.iter() {
+                if name == "*" {
+                    addresses.extend(self.lazy_system_contracts.keys().copied());
+                } else {
+                    addresses.push(self.system_contracts_profile.address(name).map_err(
+                        |error| anyhow::anyhow!("System contracts group `{group}`: {error}"),
+                    )?);
+                }
+            }
+            addresses.sort();
+            addresses.dedup();
+            system_contracts_groups.insert(group, addresses);
+        }
+        self.system_contracts_groups = Arc::new(system_contracts_groups);
+        Ok(())
+    }
+
+    ///
+    /// Deploys the lazy system contracts required by the test `group`.
+    ///
+    pub fn deploy_group_system_contracts(&mut self, group: Option<&str>) {
+        if let Some(addresses) = group.and_then(|group| self.system_contracts_groups.get(group)) {
+            for address in addresses.clone().into_iter() {
+                self.deploy_lazy_system_contract(address);
+            }
+        }
+    }
+
+    ///
+    /// Deploys the system contract at `address` if it has not been deployed yet, either
+    /// explicitly or by a call from the contract code.
+    ///
+    fn deploy_lazy_system_contract(&mut self, address: web3::types::Address) {
+        if let Some((bytecode_hash, bytecode)) = self.lazy_system_contracts.remove(&address) {
+            if self.deployed_contracts.contains_key(&address) {
+                return;
+            }
+            self.add_deployed_contract(address, bytecode_hash, Some(bytecode));
+        }
     }
 
     ///
     /// Clones the VM instance from and adds known contracts for a single test run.
     ///
-    /// TODO: make copyless when the VM supports it.
+    /// TODO: make copyless when the VM supports it. Blocked, as `run_vm_multi_contracts` takes the
+    /// contracts and storage as standard maps by value, so persistent maps would have to be
+    /// converted on every execution, which costs more than the clone saves.
     ///
     pub fn clone_with_contracts(
         vm: Arc<Self>,
@@ -215,6 +400,75 @@ impl EraVM {
         vm_clone
     }
 
+    ///
+    /// Publishes the EVM `runtime_codes` in advance, so that the deploy inputs of the test do not
+    /// pay for the publication.
+    ///
+    /// The bytecodes are marked as known in the `KnownCodesStorage` under their versioned hashes,
+    /// and their preimages are recorded the same way as the ones published by an execution.
+    /// Nothing is deployed, so the deploy inputs remain the only deployments of the test.
+    /// If the deployed bytecode differs from the runtime code, e.g. it contains immutables,
+    /// it is published by the deploy input as usual.
+    ///
+    pub fn prepublish_evm_bytecodes(&mut self, runtime_codes: Vec<Vec<u8>>) {
+        let known_codes_storage_address = web3::types::Address::from_low_u64_be(
+            zkevm_opcode_defs::ADDRESS_KNOWN_CODES_STORAGE.into(),
+        );
+        for runtime_code in runtime_codes.into_iter() {
+            let (bytecode_hash, preimage) = match Self::evm_bytecode_hash(runtime_code.as_slice()) {
+                Some(hash_and_preimage) => hash_and_preimage,
+                None => continue,
+            };
+            self.storage.insert(
+                zkevm_tester::compiler_tests::StorageKey {
+                    address: known_codes_storage_address,
+                    key: bytecode_hash,
+                },
+                web3::types::H256::from_low_u64_be(1),
+            );
+            self.published_evm_bytecodes
+                .entry(bytecode_hash)
+                .or_insert(preimage);
+        }
+    }
+
+    ///
+    /// Returns the versioned hash of the EVM `bytecode` and its preimage padded to 32-byte words,
+    /// or `None` if the bytecode is empty or too long to be encoded in the hash.
+    ///
+    /// The hash is the SHA-256 of the padded bytecode, with its first bytes replaced by the
+    /// EVM version marker and the unpadded length in bytes.
+    ///
+    fn evm_bytecode_hash(bytecode: &[u8]) -> Option<(web3::types::U256, Vec<web3::types::U256>)> {
+        if bytecode.is_empty() {
+            return None;
+        }
+        let length = u16::try_from(bytecode.len()).ok()?;
+
+        let mut padded = bytecode.to_vec();
+        padded.resize(
+            bytecode
+                .len()
+                .next_multiple_of(era_compiler_common::BYTE_LENGTH_FIELD),
+            0,
+        );
+
+        let mut hash: [u8; era_compiler_common::BYTE_LENGTH_FIELD] =
+            sha2::Sha256::digest(padded.as_slice()).into();
+        hash[0] = Self::EVM_BYTECODE_HASH_VERSION;
+        hash[1] = 0;
+        hash[2..4].copy_from_slice(length.to_be_bytes().as_slice());
+
+        let preimage = padded
+            .chunks(era_compiler_common::BYTE_LENGTH_FIELD)
+            .map(web3::types::U256::from_big_endian)
+            .collect();
+        Some((
+            web3::types::U256::from_big_endian(hash.as_slice()),
+            preimage,
+        ))
+    }
+
     ///
     /// Sets the given block number as the new current block number in storage.
     ///
@@ -282,49 +536,136 @@ impl EraVM {
         test_name: String,
         mut entry_address: web3::types::Address,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         calldata: Vec<u8>,
         vm_launch_option: Option<zkevm_tester::compiler_tests::VmLaunchOption>,
     ) -> anyhow::Result<ExecutionResult> {
-        let (vm_launch_option, context_u128_value) =
-            if let Some(vm_launch_option) = vm_launch_option {
-                (vm_launch_option, value)
-            } else if M {
-                match value {
-                    Some(value) => {
-                        self.mint_ether(caller, web3::types::U256::from(value));
-
-                        let r3 = Some(web3::types::U256::from(value));
-                        let r4 = Some(web3::types::U256::from_big_endian(entry_address.as_bytes()));
-                        let r5 = Some(web3::types::U256::from(u8::from(
-                            era_compiler_llvm_context::eravm_const::SYSTEM_CALL_BIT,
-                        )));
-
-                        entry_address = web3::types::Address::from_low_u64_be(
-                            zkevm_opcode_defs::ADDRESS_MSG_VALUE.into(),
+        let (vm_launch_option, context_value) = if let Some(vm_launch_option) = vm_launch_option {
+            (vm_launch_option, value)
+        } else if M {
+            match value {
+                Some(value) => {
+                    self.mint_ether(caller, value);
+
+                    let r3 = Some(value);
+                    let r4 = Some(web3::types::U256::from_big_endian(entry_address.as_bytes()));
+                    let r5 = Some(web3::types::U256::from(u8::from(
+                        era_compiler_llvm_context::eravm_const::SYSTEM_CALL_BIT,
+                    )));
+
+                    entry_address = web3::types::Address::from_low_u64_be(
+                        zkevm_opcode_defs::ADDRESS_MSG_VALUE.into(),
+                    );
+
+                    let vm_launch_option =
+                        zkevm_tester::compiler_tests::VmLaunchOption::ManualCallABI(
+                            zkevm_tester::compiler_tests::FullABIParams {
+                                is_constructor: false,
+                                is_system_call: true,
+                                r3_value: r3,
+                                r4_value: r4,
+                                r5_value: r5,
+                            },
                         );
-
-                        let vm_launch_option =
-                            zkevm_tester::compiler_tests::VmLaunchOption::ManualCallABI(
-                                zkevm_tester::compiler_tests::FullABIParams {
-                                    is_constructor: false,
-                                    is_system_call: true,
-                                    r3_value: r3,
-                                    r4_value: r4,
-                                    r5_value: r5,
-                                },
-                            );
-                        (vm_launch_option, None)
-                    }
-                    None => (zkevm_tester::compiler_tests::VmLaunchOption::Default, None),
-                }
-            } else {
-                if let Some(value) = value {
-                    self.mint_ether(entry_address, web3::types::U256::from(value));
+                    (vm_launch_option, None)
                 }
+                None => (zkevm_tester::compiler_tests::VmLaunchOption::Default, None),
+            }
+        } else {
+            if let Some(value) = value {
+                self.mint_ether(entry_address, value);
+            }
 
-                (zkevm_tester::compiler_tests::VmLaunchOption::Default, value)
-            };
+            (zkevm_tester::compiler_tests::VmLaunchOption::Default, value)
+        };
+
+        let context = zkevm_tester::compiler_tests::VmExecutionContext::new(
+            entry_address,
+            caller,
+            Self::context_u128_value(context_value.unwrap_or_default())?,
+            0,
+        );
+
+        self.run(
+            test_name,
+            entry_address,
+            calldata,
+            context,
+            vm_launch_option,
+        )
+    }
+
+    ///
+    /// Runs a test transaction which executes the code at `entry_address` in the context of
+    /// `this_address`, simulating a delegate call from the latter.
+    ///
+    pub fn execute_delegatecall(
+        &mut self,
+        test_name: String,
+        entry_address: web3::types::Address,
+        this_address: web3::types::Address,
+        caller: web3::types::Address,
+        calldata: Vec<u8>,
+    ) -> anyhow::Result<ExecutionResult> {
+        let context =
+            zkevm_tester::compiler_tests::VmExecutionContext::new(this_address, caller, 0, 0);
+
+        self.run(
+            test_name,
+            entry_address,
+            calldata,
+            context,
+            zkevm_tester::compiler_tests::VmLaunchOption::Default,
+        )
+    }
+
+    ///
+    /// Runs a test transaction which calls `entry_address` from `caller`, simulating a static call.
+    ///
+    /// The static call is emulated by reverting the transaction if it modifies the storage
+    /// of the non-system contracts or emits events. The storage is reverted with the previous
+    /// values of the changed slots, so it is not copied before the execution.
+    ///
+    pub fn execute_staticcall<const M: bool>(
+        &mut self,
+        test_name: String,
+        entry_address: web3::types::Address,
+        caller: web3::types::Address,
+        calldata: Vec<u8>,
+    ) -> anyhow::Result<ExecutionResult> {
+        let mut result =
+            self.execute::<M>(test_name, entry_address, caller, None, calldata, None)?;
+
+        let changes = Self::storage_changes(&self.storage, self.previous_storage_values());
+        let is_state_modified = !result.output.events.is_empty()
+            || changes.iter().any(|(key, _)| {
+                key.address
+                    >= web3::types::Address::from_low_u64_be(
+                        zkevm_opcode_defs::ADDRESS_UNRESTRICTED_SPACE,
+                    )
+            });
+        if is_state_modified {
+            Self::revert_storage(&mut self.storage, changes);
+            #[cfg(feature = "vm2")]
+            self.previous_storage.clear();
+            result.output = Output::new(vec![], true, vec![]);
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Runs the VM with the specified execution `context` and updates the state.
+    ///
+    fn run(
+        &mut self,
+        test_name: String,
+        entry_address: web3::types::Address,
+        calldata: Vec<u8>,
+        context: zkevm_tester::compiler_tests::VmExecutionContext,
+        vm_launch_option: zkevm_tester::compiler_tests::VmLaunchOption,
+    ) -> anyhow::Result<ExecutionResult> {
+        self.deploy_lazy_system_contract(entry_address);
 
         let mut trace_file_path = PathBuf::from_str("./trace/").expect("Always valid");
         let trace_file_name = regex::Regex::new("[^A-Za-z0-9]+")
@@ -333,13 +674,6 @@ impl EraVM {
             .to_string();
         trace_file_path.push(trace_file_name);
 
-        let context = zkevm_tester::compiler_tests::VmExecutionContext::new(
-            entry_address,
-            caller,
-            context_u128_value.unwrap_or_default(),
-            0,
-        );
-
         #[cfg(not(feature = "vm2"))]
         {
             let snapshot = zkevm_tester::compiler_tests::run_vm_multi_contracts(
@@ -367,21 +701,25 @@ impl EraVM {
                     .insert(*address, assembly.to_owned());
             }
 
+            let mut pubdata = self.state_diff_pubdata(snapshot.storage.iter());
             for (hash, preimage) in snapshot.published_sha256_blobs.iter() {
                 if self.published_evm_bytecodes.contains_key(hash) {
                     continue;
                 }
 
+                pubdata += Self::evm_bytecode_length(hash);
                 self.published_evm_bytecodes.insert(*hash, preimage.clone());
             }
 
-            self.storage.clone_from(&snapshot.storage);
+            self.previous_storage = std::mem::replace(&mut self.storage, snapshot.storage.clone());
 
-            Ok(snapshot.into())
+            let mut result = ExecutionResult::from(snapshot);
+            result.pubdata = Some(pubdata);
+            Ok(result)
         }
         #[cfg(feature = "vm2")]
         {
-            let (result, storage_changes, deployed_contracts) = vm2_adapter::run_vm(
+            let (mut result, storage_changes, deployed_contracts) = vm2_adapter::run_vm(
                 self.deployed_contracts.clone(),
                 &calldata,
                 self.storage.clone(),
@@ -394,8 +732,14 @@ impl EraVM {
             )
             .map_err(|error| anyhow::anyhow!("EraVM failure: {}", error))?;
 
+            result.pubdata = Some(
+                self.state_diff_pubdata(storage_changes.iter())
+                    + self.published_evm_bytecodes_pubdata(storage_changes.iter()),
+            );
+            self.previous_storage.clear();
             for (key, value) in storage_changes.into_iter() {
-                self.storage.insert(key, value);
+                let previous = self.storage.insert(key, value).unwrap_or_default();
+                self.previous_storage.entry(key).or_insert(previous);
             }
             for (address, assembly) in deployed_contracts.into_iter() {
                 if self.deployed_contracts.contains_key(&address) {
@@ -409,32 +753,240 @@ impl EraVM {
         }
     }
 
+    ///
+    /// Returns the estimated size of the state diffs published as pubdata for the storage values
+    /// written by an execution, compared to the storage before it.
+    ///
+    /// The storage writes of the system contracts, e.g. the nonces and balances, are published
+    /// as well. The slots left with their previous values are not published. The diffs are
+    /// counted uncompressed.
+    ///
+    fn state_diff_pubdata<'a>(
+        &self,
+        storage: impl Iterator<
+            Item = (
+                &'a zkevm_tester::compiler_tests::StorageKey,
+                &'a web3::types::H256,
+            ),
+        >,
+    ) -> usize {
+        storage
+            .filter_map(|(key, value)| match self.storage.get(key) {
+                Some(previous) if previous == value => None,
+                Some(_) => Some(Self::PUBDATA_REPEATED_WRITE_SIZE),
+                None if value.is_zero() => None,
+                None => Some(Self::PUBDATA_INITIAL_WRITE_SIZE),
+            })
+            .sum()
+    }
+
+    ///
+    /// Returns the size of the EVM bytecodes published by an execution, which are the ones newly
+    /// marked as known in the `KnownCodesStorage`, compared to the storage before it.
+    ///
+    /// The bytecodes are published padded to 32-byte words, and their lengths in bytes are
+    /// encoded in the versioned hashes.
+    ///
+    #[cfg(feature = "vm2")]
+    fn published_evm_bytecodes_pubdata<'a>(
+        &self,
+        storage: impl Iterator<
+            Item = (
+                &'a zkevm_tester::compiler_tests::StorageKey,
+                &'a web3::types::H256,
+            ),
+        >,
+    ) -> usize {
+        let known_codes_storage_address = web3::types::Address::from_low_u64_be(
+            zkevm_opcode_defs::ADDRESS_KNOWN_CODES_STORAGE.into(),
+        );
+        storage
+            .filter(|(key, value)| {
+                key.address == known_codes_storage_address
+                    && !value.is_zero()
+                    && !self.storage.contains_key(key)
+            })
+            .map(|(key, _)| Self::evm_bytecode_length(&key.key))
+            .sum()
+    }
+
+    ///
+    /// Returns the padded length of the EVM bytecode with the versioned `bytecode_hash`, or zero
+    /// for the EraVM bytecode hashes.
+    ///
+    fn evm_bytecode_length(bytecode_hash: &web3::types::U256) -> usize {
+        let bytecode_hash = crate::utils::u256_to_h256(bytecode_hash);
+        match bytecode_hash.as_bytes() {
+            [Self::EVM_BYTECODE_HASH_VERSION, _, high, low, ..] => {
+                usize::from(u16::from_be_bytes([*high, *low]))
+                    .next_multiple_of(era_compiler_common::BYTE_LENGTH_FIELD)
+            }
+            _ => 0,
+        }
+    }
+
+    ///
+    /// Returns the storage excerpt of the last execution for the failure details, that is
+    /// the slots of the user contracts changed by it, and all the slots of the `addresses`,
+    /// ordered by address and key.
+    ///
+    /// The storage reads are not exposed by the VM, so the slots of the other contracts that
+    /// are only read are left out. The excerpt is only computed on demand, as the changed slots
+    /// are found by comparing the whole storage with the one before the execution.
+    ///
+    pub fn storage_excerpt(&self, addresses: &[web3::types::Address]) -> Vec<StorageAccess> {
+        Self::storage_excerpt_from(&self.storage, self.previous_storage_values(), addresses)
+    }
+
+    ///
+    /// Returns the values before the last execution of the slots possibly changed by it,
+    /// which are zero for the slots written for the first time.
+    ///
+    fn previous_storage_values(
+        &self,
+    ) -> impl Iterator<Item = (&zkevm_tester::compiler_tests::StorageKey, web3::types::H256)> {
+        #[cfg(not(feature = "vm2"))]
+        {
+            self.previous_storage
+                .iter()
+                .map(|(key, value)| (key, *value))
+                .chain(
+                    self.storage
+                        .keys()
+                        .filter(|key| !self.previous_storage.contains_key(key))
+                        .map(|key| (key, web3::types::H256::zero())),
+                )
+        }
+        #[cfg(feature = "vm2")]
+        {
+            self.previous_storage
+                .iter()
+                .map(|(key, value)| (key, *value))
+        }
+    }
+
+    ///
+    /// Returns the slots of the `storage` changed by the last execution with their `previous` values.
+    ///
+    fn storage_changes<'a>(
+        storage: &HashMap<zkevm_tester::compiler_tests::StorageKey, web3::types::H256>,
+        previous: impl Iterator<
+            Item = (
+                &'a zkevm_tester::compiler_tests::StorageKey,
+                web3::types::H256,
+            ),
+        >,
+    ) -> Vec<(zkevm_tester::compiler_tests::StorageKey, web3::types::H256)> {
+        previous
+            .filter(|(key, before)| storage.get(key).copied().unwrap_or_default() != *before)
+            .map(|(key, before)| (*key, before))
+            .collect()
+    }
+
+    ///
+    /// Reverts the `changes` of the `storage`, removing the slots which were zero before.
+    ///
+    fn revert_storage(
+        storage: &mut HashMap<zkevm_tester::compiler_tests::StorageKey, web3::types::H256>,
+        changes: Vec<(zkevm_tester::compiler_tests::StorageKey, web3::types::H256)>,
+    ) {
+        for (key, before) in changes.into_iter() {
+            if before.is_zero() {
+                storage.remove(&key);
+            } else {
+                storage.insert(key, before);
+            }
+        }
+    }
+
+    ///
+    /// Returns the storage excerpt of the `storage` with the `previous` values of the slots
+    /// possibly changed by an execution, and all the slots of the `addresses`.
+    ///
+    fn storage_excerpt_from<'a>(
+        storage: &HashMap<zkevm_tester::compiler_tests::StorageKey, web3::types::H256>,
+        previous: impl Iterator<
+            Item = (
+                &'a zkevm_tester::compiler_tests::StorageKey,
+                web3::types::H256,
+            ),
+        >,
+        addresses: &[web3::types::Address],
+    ) -> Vec<StorageAccess> {
+        let user_space =
+            web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_UNRESTRICTED_SPACE);
+
+        let mut excerpt = BTreeMap::new();
+        for (key, before) in previous.filter(|(key, _)| key.address >= user_space) {
+            let after = storage.get(key).copied().unwrap_or_default();
+            if before != after {
+                excerpt.insert(
+                    (key.address, key.key),
+                    StorageAccess::new(
+                        key.address,
+                        key.key,
+                        crate::utils::h256_to_u256(&before),
+                        crate::utils::h256_to_u256(&after),
+                    ),
+                );
+            }
+        }
+        for (key, value) in storage
+            .iter()
+            .filter(|(key, _)| addresses.contains(&key.address))
+        {
+            let value = crate::utils::h256_to_u256(value);
+            excerpt
+                .entry((key.address, key.key))
+                .or_insert_with(|| StorageAccess::new(key.address, key.key, value, value));
+        }
+        excerpt.into_values().collect()
+    }
+
+    ///
+    /// Sets the default gas limit of calls on the EVM interpreter.
+    ///
+    pub fn set_evm_gas_limit(&mut self, gas_limit: u64) {
+        self.evm_gas_limit = gas_limit;
+    }
+
     ///
     /// Executes a contract simulating EVM to EVM call, which gives the ability to measure the amount of gas used.
     ///
+    /// The call is given `gas_limit`, or the default EVM gas limit if it is not specified.
+    /// If the call runs out of gas, the interpreter does not report the gas left, so the exception is
+    /// returned with empty return data, and the entire gas limit is considered used.
+    ///
     pub fn execute_evm_interpreter<const M: bool>(
         &mut self,
         test_name: String,
         entry_address: web3::types::Address,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         calldata: Vec<u8>,
         vm_launch_option: Option<zkevm_tester::compiler_tests::VmLaunchOption>,
+        gas_limit: Option<u64>,
     ) -> anyhow::Result<ExecutionResult> {
+        let gas_limit = gas_limit.unwrap_or(self.evm_gas_limit);
+        let evm_gas_manager_address = self
+            .system_contracts_profile
+            .address("evm_gas_manager")
+            .expect("Always valid");
+
         // add initial frame data in EvmGasManager
-        // set `passGas` to `EVM_CALL_GAS_LIMIT`
+        // set `passGas` to the gas limit
         self.storage_transient.insert(
             zkevm_tester::compiler_tests::StorageKey {
-                address: web3::types::Address::from_low_u64_be(ADDRESS_EVM_GAS_MANAGER.into()),
+                address: evm_gas_manager_address,
                 key: web3::types::U256::from(Self::EVM_GAS_MANAGER_GAS_TRANSIENT_SLOT),
             },
-            web3::types::H256::from_low_u64_be(Self::EVM_CALL_GAS_LIMIT),
+            web3::types::H256::from_low_u64_be(gas_limit),
         );
 
         // set `isActiveFrame` to true
         self.storage_transient.insert(
             zkevm_tester::compiler_tests::StorageKey {
-                address: web3::types::Address::from_low_u64_be(ADDRESS_EVM_GAS_MANAGER.into()),
+                address: evm_gas_manager_address,
                 key: web3::types::U256::from(Self::EVM_GAS_MANAGER_AUX_DATA_TRANSIENT_SLOT),
             },
             web3::types::H256::from_low_u64_be(2), // "activeFrame flag"
@@ -448,16 +1000,19 @@ impl EraVM {
             calldata,
             vm_launch_option,
         )?;
-        if result.output.return_data.is_empty() {
+        let gas_left = if !result.output.return_data.is_empty() {
+            result
+                .output
+                .return_data
+                .remove(0)
+                .unwrap_certain_as_ref()
+                .as_u64()
+        } else if result.output.exception {
+            0
+        } else {
             anyhow::bail!("Return data is empty");
-        }
-        let gas_left = result
-            .output
-            .return_data
-            .remove(0)
-            .unwrap_certain_as_ref()
-            .as_u64();
-        result.gas = Self::EVM_CALL_GAS_LIMIT - gas_left;
+        };
+        result.gas = gas_limit.saturating_sub(gas_left);
 
         Ok(result)
     }
@@ -486,6 +1041,18 @@ impl EraVM {
         true
     }
 
+    ///
+    /// Narrows the `value` to the EraVM execution context value, which is limited to 128 bits.
+    ///
+    /// Values beyond the limit can only be passed via the system call simulating `msg.value`.
+    ///
+    fn context_u128_value(value: web3::types::U256) -> anyhow::Result<u128> {
+        if value > web3::types::U256::from(u128::MAX) {
+            anyhow::bail!("Value {value} exceeds the EraVM context value limit of 128 bits");
+        }
+        Ok(value.low_u128())
+    }
+
     ///
     /// Mints some Ether value at the specified address.
     /// Is needed for payable calls simulation.
@@ -624,6 +1191,89 @@ impl EraVM {
         );
     }
 
+    ///
+    /// Sets the `SystemContext` storage `values` by their positions, and returns the previous ones,
+    /// `None` for the absent slots, so they can be restored after a call.
+    ///
+    pub fn override_system_context(
+        &mut self,
+        values: Vec<(web3::types::U256, web3::types::H256)>,
+    ) -> Vec<(web3::types::U256, Option<web3::types::H256>)> {
+        let address =
+            web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_SYSTEM_CONTEXT.into());
+        values
+            .into_iter()
+            .map(|(key, value)| {
+                let previous = self.storage.insert(
+                    zkevm_tester::compiler_tests::StorageKey { address, key },
+                    value,
+                );
+                (key, previous)
+            })
+            .collect()
+    }
+
+    ///
+    /// Restores the `SystemContext` storage `values` returned by `override_system_context`,
+    /// removing the slots which have been absent.
+    ///
+    pub fn restore_system_context(
+        &mut self,
+        values: Vec<(web3::types::U256, Option<web3::types::H256>)>,
+    ) {
+        let address =
+            web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_SYSTEM_CONTEXT.into());
+        for (key, value) in values.into_iter() {
+            let key = zkevm_tester::compiler_tests::StorageKey { address, key };
+            match value {
+                Some(value) => {
+                    self.storage.insert(key, value);
+                }
+                None => {
+                    self.storage.remove(&key);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the immutable value at `index` of the contract deployed at `address`.
+    ///
+    pub fn get_immutable(
+        &self,
+        address: web3::types::Address,
+        index: web3::types::U256,
+    ) -> web3::types::H256 {
+        let key = zkevm_tester::compiler_tests::StorageKey {
+            address: web3::types::Address::from_low_u64_be(
+                zkevm_opcode_defs::ADDRESS_IMMUTABLE_SIMULATOR.into(),
+            ),
+            key: Self::get_position_of_immutable(address, index),
+        };
+        self.storage.get(&key).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Returns the immutable position in the contract storage.
+    ///
+    pub fn get_position_of_immutable(
+        address: web3::types::Address,
+        index: web3::types::U256,
+    ) -> web3::types::U256 {
+        let mut key = web3::types::H256::from(address).to_fixed_bytes().to_vec();
+        key.extend([0u8; era_compiler_common::BYTE_LENGTH_FIELD]);
+        Self::IMMUTABLES_MAPPING_POSITION
+            .to_big_endian(&mut key[era_compiler_common::BYTE_LENGTH_FIELD..]);
+        let key = web3::signing::keccak256(key.as_slice()).to_vec();
+
+        let mut nested_key = vec![0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+        index.to_big_endian(&mut nested_key[..]);
+        nested_key.extend(key);
+        let nested_key = web3::signing::keccak256(nested_key.as_slice());
+
+        web3::types::U256::from(nested_key)
+    }
+
     ///
     /// Returns known contract size by code_hash, None if not found.
     ///
@@ -634,6 +1284,103 @@ impl EraVM {
             .len()
     }
 
+    ///
+    /// Returns the runtime code size of the EVM contract deployed at `address`, which is encoded
+    /// in its versioned bytecode hash, or `None` if there is no EVM contract at the address.
+    ///
+    pub fn get_evm_code_size(&self, address: &web3::types::Address) -> Option<usize> {
+        match self.evm_code_hash(address)?.as_bytes() {
+            [Self::EVM_BYTECODE_HASH_VERSION, _, high, low, ..] => {
+                Some(usize::from(u16::from_be_bytes([*high, *low])))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the runtime code of the EVM contract deployed at `address`, if its bytecode has
+    /// been published by an execution of the VM.
+    ///
+    pub fn get_evm_code(&self, address: &web3::types::Address) -> Option<Vec<u8>> {
+        let size = self.get_evm_code_size(address)?;
+        let code_hash = self.evm_code_hash(address)?;
+        let preimage = self
+            .published_evm_bytecodes
+            .get(&web3::types::U256::from_big_endian(code_hash.as_bytes()))?;
+
+        let mut code = Vec::with_capacity(preimage.len() * era_compiler_common::BYTE_LENGTH_FIELD);
+        for word in preimage.iter() {
+            let mut bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+            word.to_big_endian(&mut bytes);
+            code.extend_from_slice(&bytes);
+        }
+        code.truncate(size);
+        Some(code)
+    }
+
+    ///
+    /// Returns the bytecode hash of the contract deployed at `address` from the account code storage.
+    ///
+    fn evm_code_hash(&self, address: &web3::types::Address) -> Option<&web3::types::H256> {
+        self.storage
+            .get(&zkevm_tester::compiler_tests::StorageKey {
+                address: web3::types::Address::from_low_u64_be(
+                    zkevm_opcode_defs::ADDRESS_ACCOUNT_CODE_STORAGE.into(),
+                ),
+                key: web3::types::U256::from_big_endian(address.as_bytes()),
+            })
+            .filter(|code_hash| !code_hash.is_zero())
+    }
+
+    ///
+    /// Returns the code size of each function in bytes, parsed from the EraVM `assembly`.
+    ///
+    /// The functions are the global labels, and each instruction is attributed to the last one.
+    /// The constant cells are attributed to the `<constants>` pseudo-function.
+    ///
+    pub fn function_sizes(assembly: &str) -> BTreeMap<String, usize> {
+        let mut sizes = BTreeMap::new();
+        let mut function = None;
+        for line in assembly.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with(';') {
+                continue;
+            }
+
+            if !line.starts_with(char::is_whitespace) {
+                if let Some(label) = trimmed.strip_suffix(':') {
+                    if !label.starts_with('.') {
+                        function = Some(label.to_owned());
+                    }
+                }
+                continue;
+            }
+
+            if trimmed.starts_with(".cell") {
+                *sizes
+                    .entry(Self::FUNCTION_SIZES_CONSTANTS.to_owned())
+                    .or_default() += era_compiler_common::BYTE_LENGTH_FIELD;
+            } else if !trimmed.starts_with('.') {
+                if let Some(function) = function.as_ref() {
+                    *sizes.entry(function.to_owned()).or_default() += Self::INSTRUCTION_SIZE;
+                }
+            }
+        }
+        sizes
+    }
+
+    ///
+    /// Returns the number of instructions of the functions in the `function_sizes` computed from
+    /// the assembly, leaving out the constants.
+    ///
+    pub fn instructions(function_sizes: &BTreeMap<String, usize>) -> usize {
+        function_sizes
+            .iter()
+            .filter(|(name, _)| name.as_str() != Self::FUNCTION_SIZES_CONSTANTS)
+            .map(|(_, size)| size / Self::INSTRUCTION_SIZE)
+            .sum()
+    }
+
     ///
     /// Gets the balance storage key for the specified address.
     ///
@@ -660,3 +1407,334 @@ impl EraVM {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use sha3::Digest;
+
+    use crate::directories::matter_labs::test::DEFAULT_CALLER_ADDRESS;
+    use crate::vm::execution_result::ExecutionResult;
+
+    use super::system_contracts::SystemContracts;
+    use super::system_contracts_profile::SystemContractsProfile;
+    use super::EraVM;
+
+    ///
+    /// Creates a VM with the tracked stable build of the system contracts.
+    ///
+    fn vm() -> EraVM {
+        let system_contracts = SystemContracts::load_or_build(
+            semver::Version::new(0, 8, 28),
+            &SystemContractsProfile::default(),
+            None,
+            Some(PathBuf::from(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../system-contracts-stable-build"
+            ))),
+            None,
+        )
+        .expect("Always valid");
+        EraVM::from_system_contracts(system_contracts, era_compiler_common::Target::EraVM)
+    }
+
+    ///
+    /// Calls the system contract at `address` from the default caller.
+    ///
+    fn call(vm: &mut EraVM, address: u64, calldata: Vec<u8>) -> ExecutionResult {
+        vm.execute::<true>(
+            format!("system_contract_{address:x}"),
+            web3::types::Address::from_low_u64_be(address),
+            web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid"),
+            None,
+            calldata,
+            None,
+        )
+        .expect("Always valid")
+    }
+
+    ///
+    /// Returns the `L1Messenger.sendToL1(bytes)` calldata.
+    ///
+    fn send_to_l1(message: &[u8]) -> Vec<u8> {
+        let mut calldata = crate::utils::selector("sendToL1(bytes)").to_vec();
+        calldata.extend(
+            web3::types::H256::from_low_u64_be(era_compiler_common::BYTE_LENGTH_FIELD as u64)
+                .as_bytes(),
+        );
+        calldata.extend(web3::types::H256::from_low_u64_be(message.len() as u64).as_bytes());
+        calldata.extend(message);
+        calldata.extend(vec![
+            0;
+            message.len().next_multiple_of(
+                era_compiler_common::BYTE_LENGTH_FIELD
+            ) - message.len()
+        ]);
+        calldata
+    }
+
+    ///
+    /// The `L1Messenger` is deployed by the test input, while the `Keccak256` and `EventWriter`
+    /// it calls are resolved by the VM code lookup.
+    ///
+    #[test]
+    fn minimal_system_contracts_nested_calls() {
+        let mut vm = vm();
+        vm.enable_minimal_system_contracts(None)
+            .expect("Always valid");
+
+        let message = vec![0xab; 100];
+        let result = call(
+            &mut vm,
+            zkevm_opcode_defs::ADDRESS_L1_MESSENGER.into(),
+            send_to_l1(&message),
+        );
+        assert!(!result.output.exception);
+        assert_eq!(result.output.return_data.len(), 1);
+        assert_eq!(
+            result.output.return_data[0].unwrap_certain_as_ref(),
+            &web3::types::U256::from_big_endian(
+                sha3::Keccak256::digest(message.as_slice()).as_slice()
+            )
+        );
+        assert!(!result.output.events.is_empty());
+    }
+
+    ///
+    /// The `L1Messenger` calls the `Keccak256` and `EventWriter`, so its call stack is deeper
+    /// than the one of the `Keccak256` called directly.
+    ///
+    #[test]
+    fn stack_depth() {
+        let mut vm = vm();
+        let keccak256 = call(
+            &mut vm,
+            zkevm_opcode_defs::ADDRESS_KECCAK256.into(),
+            vec![0xab; 100],
+        );
+        let l1_messenger = call(
+            &mut vm,
+            zkevm_opcode_defs::ADDRESS_L1_MESSENGER.into(),
+            send_to_l1(&[0xab; 100]),
+        );
+        assert!(!keccak256.output.exception);
+        assert!(!l1_messenger.output.exception);
+        assert!(keccak256.stack_depth.expect("Always exists") >= 1);
+        assert!(l1_messenger.stack_depth > keccak256.stack_depth);
+    }
+
+    ///
+    /// The calldata of a call with value, e.g. to the `#fallback`, must reach the callee exactly
+    /// as specified, without a selector or padding, with and without the `msg.value` simulator.
+    ///
+    #[test]
+    fn msg_value_simulator_calldata() {
+        let mut vm = vm();
+        let message = vec![0xab; 37];
+        let expected = web3::types::U256::from_big_endian(
+            sha3::Keccak256::digest(message.as_slice()).as_slice(),
+        );
+
+        let address =
+            web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_KECCAK256.into());
+        let caller = web3::types::Address::from_str(DEFAULT_CALLER_ADDRESS).expect("Always valid");
+        let value = Some(web3::types::U256::from(1));
+        for is_system_mode in [true, false] {
+            let name = format!("msg_value_{is_system_mode}");
+            let result = if is_system_mode {
+                vm.execute::<true>(name, address, caller, value, message.clone(), None)
+            } else {
+                vm.execute::<false>(name, address, caller, value, message.clone(), None)
+            };
+            let result = result.expect("Always valid");
+            assert!(!result.output.exception, "System mode: {is_system_mode}");
+            assert_eq!(
+                result.output.return_data.len(),
+                1,
+                "System mode: {is_system_mode}"
+            );
+            assert_eq!(
+                result.output.return_data[0].unwrap_certain_as_ref(),
+                &expected,
+                "System mode: {is_system_mode}"
+            );
+        }
+    }
+
+    #[test]
+    fn state_diff_pubdata() {
+        let mut vm = vm();
+        let key = |key: u64| zkevm_tester::compiler_tests::StorageKey {
+            address: web3::types::Address::from_low_u64_be(0x10000),
+            key: web3::types::U256::from(key),
+        };
+        vm.storage
+            .insert(key(1), web3::types::H256::from_low_u64_be(1));
+        vm.storage
+            .insert(key(2), web3::types::H256::from_low_u64_be(1));
+
+        let storage = [
+            (key(1), web3::types::H256::from_low_u64_be(1)),
+            (key(2), web3::types::H256::from_low_u64_be(2)),
+            (key(3), web3::types::H256::from_low_u64_be(3)),
+            (key(4), web3::types::H256::zero()),
+        ];
+        assert_eq!(
+            vm.state_diff_pubdata(storage.iter().map(|(key, value)| (key, value))),
+            EraVM::PUBDATA_REPEATED_WRITE_SIZE + EraVM::PUBDATA_INITIAL_WRITE_SIZE
+        );
+    }
+
+    #[test]
+    fn evm_bytecode_length() {
+        for (bytecode_hash, expected) in [
+            (
+                "0200004100000000000000000000000000000000000000000000000000000000",
+                96,
+            ),
+            (
+                "0200004000000000000000000000000000000000000000000000000000000000",
+                64,
+            ),
+            (
+                "0100004100000000000000000000000000000000000000000000000000000000",
+                0,
+            ),
+        ] {
+            let bytecode_hash = web3::types::U256::from_str(bytecode_hash).expect("Always valid");
+            assert_eq!(EraVM::evm_bytecode_length(&bytecode_hash), expected);
+        }
+    }
+    #[test]
+    fn revert_storage() {
+        let key = |key: u64| zkevm_tester::compiler_tests::StorageKey {
+            address: web3::types::Address::from_low_u64_be(0x10000),
+            key: web3::types::U256::from(key),
+        };
+        let value = |value: u64| web3::types::H256::from_low_u64_be(value);
+
+        let original = HashMap::from([(key(0), value(1)), (key(1), value(2))]);
+        let mut storage =
+            HashMap::from([(key(0), value(1)), (key(1), value(3)), (key(2), value(4))]);
+        let previous = [(key(0), value(1)), (key(1), value(2)), (key(2), value(0))];
+
+        let changes =
+            EraVM::storage_changes(&storage, previous.iter().map(|(key, value)| (key, *value)));
+        assert_eq!(changes.len(), 2, "Only the written slots must be changed");
+        EraVM::revert_storage(&mut storage, changes);
+        assert!(storage == original, "The storage must be reverted");
+    }
+
+    #[test]
+    fn storage_excerpt() {
+        let key = |address: u64, key: u64| zkevm_tester::compiler_tests::StorageKey {
+            address: web3::types::Address::from_low_u64_be(address),
+            key: web3::types::U256::from(key),
+        };
+        let value = |value: u64| web3::types::H256::from_low_u64_be(value);
+
+        let called = 0x10000;
+        let other = 0x10001;
+        let system = 0x8003;
+        let storage = HashMap::from([
+            (key(called, 0), value(1)),
+            (key(called, 1), value(2)),
+            (key(other, 0), value(3)),
+            (key(other, 1), value(4)),
+            (key(system, 0), value(5)),
+        ]);
+        let previous = [
+            (key(called, 1), value(0)),
+            (key(other, 0), value(0)),
+            (key(other, 1), value(4)),
+            (key(other, 2), value(6)),
+            (key(system, 0), value(0)),
+        ];
+
+        let excerpt = EraVM::storage_excerpt_from(
+            &storage,
+            previous.iter().map(|(key, value)| (key, *value)),
+            &[web3::types::Address::from_low_u64_be(called)],
+        );
+        let excerpt: Vec<(u64, u64, u64, u64)> = excerpt
+            .into_iter()
+            .map(|access| {
+                (
+                    access.address.to_low_u64_be(),
+                    access.key.as_u64(),
+                    access.before.as_u64(),
+                    access.after.as_u64(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            excerpt,
+            vec![
+                (called, 0, 1, 1),
+                (called, 1, 0, 2),
+                (other, 0, 0, 3),
+                (other, 2, 6, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn override_system_context() {
+        let mut vm = vm();
+        let storage = vm.storage.clone();
+        let present = web3::types::U256::from(
+            super::system_context::SystemContext::SYSTEM_CONTEXT_GAS_PRICE_POSITION,
+        );
+        let absent = web3::types::U256::from(0x1234_5678);
+
+        let previous = vm.override_system_context(vec![
+            (present, web3::types::H256::from_low_u64_be(100)),
+            (absent, web3::types::H256::from_low_u64_be(200)),
+        ]);
+        assert_eq!(previous.len(), 2);
+        assert!(previous[0].1.is_some());
+        assert_eq!(previous[1], (absent, None));
+        assert_eq!(vm.storage.len(), storage.len() + 1);
+
+        vm.restore_system_context(previous);
+        assert_eq!(vm.storage, storage);
+    }
+
+    #[test]
+    fn prepublish_evm_bytecodes() {
+        let mut vm = vm();
+        let storage_length = vm.storage.len();
+        let runtime_code = hex::decode("6080604052").expect("Always valid");
+        let bytecode_hash = web3::types::U256::from_str(
+            "0200000511ed8b89cbf1cab63d86aa02c85cf349d7f64f02262bbe7164e8c394",
+        )
+        .expect("Always valid");
+
+        vm.prepublish_evm_bytecodes(vec![runtime_code.clone(), vec![], runtime_code.clone()]);
+        assert_eq!(vm.storage.len(), storage_length + 1);
+        assert_eq!(
+            vm.storage.get(&zkevm_tester::compiler_tests::StorageKey {
+                address: web3::types::Address::from_low_u64_be(
+                    zkevm_opcode_defs::ADDRESS_KNOWN_CODES_STORAGE.into(),
+                ),
+                key: bytecode_hash,
+            }),
+            Some(&web3::types::H256::from_low_u64_be(1)),
+        );
+        assert_eq!(
+            vm.published_evm_bytecodes.get(&bytecode_hash),
+            Some(&vec![web3::types::U256::from_big_endian(
+                [runtime_code.as_slice(), [0u8; 27].as_slice()]
+                    .concat()
+                    .as_slice(),
+            )]),
+        );
+        assert_eq!(
+            EraVM::evm_bytecode_length(&bytecode_hash),
+            era_compiler_common::BYTE_LENGTH_FIELD,
+        );
+    }
+}
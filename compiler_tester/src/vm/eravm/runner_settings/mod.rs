@@ -0,0 +1,45 @@
+//!
+//! The EraVM runner settings.
+//!
+
+pub mod overrides;
+
+use self::overrides::Overrides;
+
+///
+/// The EraVM runner settings.
+///
+/// The defaults are set for the whole run, and can be overridden by the test or case metadata,
+/// as some tests only make sense with a specific deployer or value passing mode.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RunnerSettings {
+    /// Whether the contracts are deployed natively, instead of calling the system contract deployer.
+    pub disable_deployer: bool,
+    /// Whether the value is passed directly, instead of the `msg.value` simulator system call.
+    pub disable_value_simulator: bool,
+}
+
+impl RunnerSettings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(disable_deployer: bool, disable_value_simulator: bool) -> Self {
+        Self {
+            disable_deployer,
+            disable_value_simulator,
+        }
+    }
+
+    ///
+    /// Returns the settings with the metadata `overrides` applied.
+    ///
+    pub fn with_overrides(self, overrides: Overrides) -> Self {
+        Self {
+            disable_deployer: overrides.disable_deployer.unwrap_or(self.disable_deployer),
+            disable_value_simulator: overrides
+                .disable_value_simulator
+                .unwrap_or(self.disable_value_simulator),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//!
+//! The EraVM runner settings overrides.
+//!
+
+///
+/// The EraVM runner settings overrides.
+///
+/// The unset fields keep the values of the run defaults.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct Overrides {
+    /// Whether the contracts are deployed natively, if required.
+    pub disable_deployer: Option<bool>,
+    /// Whether the value is passed directly, if required.
+    pub disable_value_simulator: Option<bool>,
+}
+
+impl Overrides {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(disable_deployer: Option<bool>, disable_value_simulator: Option<bool>) -> Self {
+        Self {
+            disable_deployer,
+            disable_value_simulator,
+        }
+    }
+
+    ///
+    /// Returns the overrides with the unset fields taken from `defaults`, e.g. the case overrides
+    /// with the defaults of the whole test.
+    ///
+    pub fn or(self, defaults: Self) -> Self {
+        Self {
+            disable_deployer: self.disable_deployer.or(defaults.disable_deployer),
+            disable_value_simulator: self
+                .disable_value_simulator
+                .or(defaults.disable_value_simulator),
+        }
+    }
+}
@@ -183,13 +183,6 @@ impl SystemContext {
         ));
 
         for index in 0..block_number {
-            let padded_index = [[0u8; 16], index.to_be_bytes()].concat();
-            let padded_slot =
-                web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_BLOCK_HASH_POSITION)
-                    .to_fixed_bytes()
-                    .to_vec();
-            let key = web3::signing::keccak256([padded_index, padded_slot].concat().as_slice());
-
             let mut hash =
                 web3::types::U256::from_str(Self::ZERO_BLOCK_HASH).expect("Always valid");
             hash = hash.add(web3::types::U256::from(index));
@@ -197,7 +190,7 @@ impl SystemContext {
             hash.to_big_endian(&mut hash_bytes);
 
             system_context_values.push((
-                web3::types::H256::from(key),
+                crate::utils::u256_to_h256(&Self::block_hash_position(index)),
                 web3::types::H256::from_slice(hash_bytes.as_slice()),
             ));
         }
@@ -270,6 +263,19 @@ impl SystemContext {
         storage
     }
 
+    ///
+    /// Returns the storage position of the hash of the block `number`.
+    ///
+    pub fn block_hash_position(number: u128) -> web3::types::U256 {
+        let padded_index = [[0u8; 16], number.to_be_bytes()].concat();
+        let padded_slot =
+            web3::types::H256::from_low_u64_be(Self::SYSTEM_CONTEXT_BLOCK_HASH_POSITION)
+                .to_fixed_bytes()
+                .to_vec();
+        let key = web3::signing::keccak256([padded_index, padded_slot].concat().as_slice());
+        web3::types::U256::from_big_endian(key.as_slice())
+    }
+
     ///
     /// Returns constants for the specified EVM version.
     ///
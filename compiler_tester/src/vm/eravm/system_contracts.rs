@@ -2,6 +2,7 @@
 //! The EraVM system contracts.
 //!
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::fs::File;
 use std::path::PathBuf;
@@ -16,11 +17,14 @@ use crate::compilers::solidity::SolidityCompiler;
 use crate::compilers::yul::mode::Mode as YulMode;
 use crate::compilers::yul::YulCompiler;
 use crate::compilers::Compiler;
-use crate::toolchain::Toolchain;
+use crate::vm::eravm::system_contracts_profile::SystemContractsProfile;
 
 /// The EVMGasManager system contract address.
 pub const ADDRESS_EVM_GAS_MANAGER: u16 = 0x8013;
 
+/// The CodeOracle system contract address.
+pub const ADDRESS_CODE_ORACLE: u16 = 0x8012;
+
 ///
 /// The EraVM system contracts.
 ///
@@ -119,25 +123,75 @@ impl SystemContracts {
         "era-contracts/system-contracts/contracts/EvmGasManager.yul";
 
     ///
-    /// Loads or builds the system contracts.
+    /// Returns the system contracts deployed in the minimal system contracts mode.
+    ///
+    /// Besides the contract deployer, known codes storage, and base token, the deployer calls
+    /// the account code storage, nonce holder, and immutable simulator on every deployment.
+    ///
+    pub fn minimal_addresses() -> [web3::types::Address; 6] {
+        let addresses: [u64; 6] = [
+            zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
+            zkevm_opcode_defs::ADDRESS_KNOWN_CODES_STORAGE.into(),
+            zkevm_opcode_defs::ADDRESS_ETH_TOKEN.into(),
+            zkevm_opcode_defs::ADDRESS_ACCOUNT_CODE_STORAGE.into(),
+            zkevm_opcode_defs::ADDRESS_NONCE_HOLDER.into(),
+            zkevm_opcode_defs::ADDRESS_IMMUTABLE_SIMULATOR.into(),
+        ];
+        addresses.map(web3::types::Address::from_low_u64_be)
+    }
+
+    ///
+    /// Returns the address of the system contract with the specified name.
+    ///
+    pub fn address_by_name(name: &str) -> anyhow::Result<web3::types::Address> {
+        let address: u64 = match name {
+            "empty_contract" => return Ok(web3::types::Address::zero()),
+            "keccak256" => zkevm_opcode_defs::ADDRESS_KECCAK256.into(),
+            "ecrecover" => zkevm_opcode_defs::ADDRESS_ECRECOVER.into(),
+            "sha256" => zkevm_opcode_defs::ADDRESS_SHA256.into(),
+            "identity" => zkevm_opcode_defs::ADDRESS_IDENTITY.into(),
+            "ecadd" => zkevm_opcode_defs::system_params::ADDRESS_ECADD.into(),
+            "ecmul" => zkevm_opcode_defs::system_params::ADDRESS_ECMUL.into(),
+            "account_code_storage" => zkevm_opcode_defs::ADDRESS_ACCOUNT_CODE_STORAGE.into(),
+            "nonce_holder" => zkevm_opcode_defs::ADDRESS_NONCE_HOLDER.into(),
+            "known_codes_storage" => zkevm_opcode_defs::ADDRESS_KNOWN_CODES_STORAGE.into(),
+            "immutable_simulator" => zkevm_opcode_defs::ADDRESS_IMMUTABLE_SIMULATOR.into(),
+            "contract_deployer" => zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
+            "l1_messenger" => zkevm_opcode_defs::ADDRESS_L1_MESSENGER.into(),
+            "msg_value_simulator" => zkevm_opcode_defs::ADDRESS_MSG_VALUE.into(),
+            "system_context" => zkevm_opcode_defs::ADDRESS_SYSTEM_CONTEXT.into(),
+            "base_token" => zkevm_opcode_defs::ADDRESS_ETH_TOKEN.into(),
+            "event_writer" => zkevm_opcode_defs::ADDRESS_EVENT_WRITER.into(),
+            "code_oracle" => ADDRESS_CODE_ORACLE.into(),
+            "evm_gas_manager" => ADDRESS_EVM_GAS_MANAGER.into(),
+            name => anyhow::bail!("Unknown system contract `{name}`"),
+        };
+        Ok(web3::types::Address::from_low_u64_be(address))
+    }
+
+    ///
+    /// Loads or builds the system contracts of the `profile`.
+    ///
+    /// The load and save paths are resolved to the build cache paths of the profile.
     ///
     pub fn load_or_build(
         solc_version: semver::Version,
+        profile: &SystemContractsProfile,
         system_contracts_debug_config: Option<era_compiler_llvm_context::DebugConfig>,
         system_contracts_load_path: Option<PathBuf>,
         system_contracts_save_path: Option<PathBuf>,
     ) -> anyhow::Result<Self> {
         let system_contracts = if let Some(system_contracts_path) = system_contracts_load_path {
-            Self::load(system_contracts_path)
+            Self::load(profile.cache_path(system_contracts_path))
                 .map_err(|error| anyhow::anyhow!("System contracts loading: {}", error))?
         } else {
-            Self::build(solc_version, system_contracts_debug_config)
+            Self::build(solc_version, profile, system_contracts_debug_config)
                 .map_err(|error| anyhow::anyhow!("System contracts building: {}", error))?
         };
 
         if let Some(system_contracts_save_path) = system_contracts_save_path {
             system_contracts
-                .save(system_contracts_save_path)
+                .save(profile.cache_path(system_contracts_save_path))
                 .map_err(|error| anyhow::anyhow!("System contracts saving: {}", error))?;
         }
 
@@ -145,119 +199,46 @@ impl SystemContracts {
     }
 
     ///
-    /// Builds the system contracts.
+    /// Builds the system contracts from the `profile` checkout.
     ///
     fn build(
         solc_version: semver::Version,
+        profile: &SystemContractsProfile,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
         let build_time_start = Instant::now();
         println!("    {} system contracts", "Building".bright_green().bold());
 
         let yul_system_contracts = [
-            (
-                web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_KECCAK256.into()),
-                Self::PATH_KECCAK256,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_ECRECOVER.into()),
-                Self::PATH_ECRECOVER,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_SHA256.into()),
-                Self::PATH_SHA256,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::system_params::ADDRESS_ECADD.into(),
-                ),
-                Self::PATH_ECADD,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::system_params::ADDRESS_ECMUL.into(),
-                ),
-                Self::PATH_ECMUL,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_EVENT_WRITER.into(),
-                ),
-                Self::PATH_EVENT_WRITER,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(0x8012),
-                Self::PATH_CODE_ORACLE,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(ADDRESS_EVM_GAS_MANAGER.into()),
-                Self::PATH_EVM_GAS_MANAGER,
-            ),
+            ("keccak256", Self::PATH_KECCAK256),
+            ("ecrecover", Self::PATH_ECRECOVER),
+            ("sha256", Self::PATH_SHA256),
+            ("ecadd", Self::PATH_ECADD),
+            ("ecmul", Self::PATH_ECMUL),
+            ("event_writer", Self::PATH_EVENT_WRITER),
+            ("code_oracle", Self::PATH_CODE_ORACLE),
+            ("evm_gas_manager", Self::PATH_EVM_GAS_MANAGER),
         ];
 
         let solidity_system_contracts = vec![
-            (web3::types::Address::zero(), Self::PATH_EMPTY_CONTRACT),
-            (
-                web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_IDENTITY.into()),
-                Self::PATH_IDENTITY,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_ACCOUNT_CODE_STORAGE.into(),
-                ),
-                Self::PATH_ACCOUNT_CODE_STORAGE,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_NONCE_HOLDER.into(),
-                ),
-                Self::PATH_NONCE_HOLDER,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_KNOWN_CODES_STORAGE.into(),
-                ),
-                Self::PATH_KNOWN_CODES_STORAGE,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_IMMUTABLE_SIMULATOR.into(),
-                ),
-                Self::PATH_IMMUTABLE_SIMULATOR,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_CONTRACT_DEPLOYER.into(),
-                ),
-                Self::PATH_CONTRACT_DEPLOYER,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_L1_MESSENGER.into(),
-                ),
-                Self::PATH_L1_MESSENGER,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_MSG_VALUE.into()),
-                Self::PATH_MSG_VALUE_SIMULATOR,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(
-                    zkevm_opcode_defs::ADDRESS_SYSTEM_CONTEXT.into(),
-                ),
-                Self::PATH_SYSTEM_CONTEXT,
-            ),
-            (
-                web3::types::Address::from_low_u64_be(zkevm_opcode_defs::ADDRESS_ETH_TOKEN.into()),
-                Self::PATH_BASE_TOKEN,
-            ),
+            ("empty_contract", Self::PATH_EMPTY_CONTRACT),
+            ("identity", Self::PATH_IDENTITY),
+            ("account_code_storage", Self::PATH_ACCOUNT_CODE_STORAGE),
+            ("nonce_holder", Self::PATH_NONCE_HOLDER),
+            ("known_codes_storage", Self::PATH_KNOWN_CODES_STORAGE),
+            ("immutable_simulator", Self::PATH_IMMUTABLE_SIMULATOR),
+            ("contract_deployer", Self::PATH_CONTRACT_DEPLOYER),
+            ("l1_messenger", Self::PATH_L1_MESSENGER),
+            ("msg_value_simulator", Self::PATH_MSG_VALUE_SIMULATOR),
+            ("system_context", Self::PATH_SYSTEM_CONTEXT),
+            ("base_token", Self::PATH_BASE_TOKEN),
         ];
 
         let mut yul_file_paths = Vec::with_capacity(yul_system_contracts.len() + 1);
         for (_, path) in yul_system_contracts.into_iter() {
-            yul_file_paths.push(path.to_owned());
+            yul_file_paths.push(profile.resolve(path));
         }
-        yul_file_paths.push(Self::PATH_EVM_EMULATOR.to_owned());
+        yul_file_paths.push(profile.resolve(Self::PATH_EVM_EMULATOR));
         let yul_optimizer_settings = era_compiler_llvm_context::OptimizerSettings::cycles();
         let yul_mode = YulMode::new(yul_optimizer_settings, true).into();
         let yul_llvm_options = vec![
@@ -274,7 +255,7 @@ impl SystemContracts {
         .map(|option| option.to_owned())
         .collect();
         let mut builds = Self::compile(
-            YulCompiler::new(Toolchain::IrLLVM),
+            YulCompiler::new(),
             yul_file_paths,
             &yul_mode,
             yul_llvm_options,
@@ -290,7 +271,7 @@ impl SystemContracts {
             "era-contracts/system-contracts/contracts/openzeppelin/**/*.sol",
             "tests/solidity/complex/interpreter/*.sol",
         ] {
-            for path in glob::glob(pattern)?.filter_map(Result::ok) {
+            for path in glob::glob(profile.resolve(pattern).as_str())?.filter_map(Result::ok) {
                 let path = path.to_string_lossy().to_string();
                 if !solidity_file_paths.contains(&path) {
                     solidity_file_paths.push(path);
@@ -317,12 +298,16 @@ impl SystemContracts {
             debug_config,
         )?);
 
-        let default_aa = builds.remove(Self::PATH_DEFAULT_AA).ok_or_else(|| {
-            anyhow::anyhow!("The default AA code not found in the compiler build artifacts")
-        })?;
-        let evm_emulator = builds.remove(Self::PATH_EVM_EMULATOR).ok_or_else(|| {
-            anyhow::anyhow!("The EVM emulator code not found in the compiler build artifacts")
-        })?;
+        let default_aa = builds
+            .remove(profile.resolve(Self::PATH_DEFAULT_AA).as_str())
+            .ok_or_else(|| {
+                anyhow::anyhow!("The default AA code not found in the compiler build artifacts")
+            })?;
+        let evm_emulator = builds
+            .remove(profile.resolve(Self::PATH_EVM_EMULATOR).as_str())
+            .ok_or_else(|| {
+                anyhow::anyhow!("The EVM emulator code not found in the compiler build artifacts")
+            })?;
 
         let mut system_contracts =
             Vec::with_capacity(solidity_system_contracts.len() + yul_system_contracts.len());
@@ -330,9 +315,11 @@ impl SystemContracts {
         system_contracts.extend(yul_system_contracts);
 
         let mut deployed_contracts = Vec::with_capacity(system_contracts.len());
-        for (address, path) in system_contracts.into_iter() {
+        for (name, path) in system_contracts.into_iter() {
+            let address = profile.address(name)?;
+            let path = profile.resolve(path);
             let build = builds
-                .remove(path)
+                .remove(path.as_str())
                 .unwrap_or_else(|| panic!("System contract `{path}` not found in the builds"));
             deployed_contracts.push((address, build));
         }
@@ -427,7 +414,7 @@ impl SystemContracts {
                 )
             })?;
 
-            if file_path == "era-contracts/system-contracts/contracts/Constants.sol" {
+            if file_path.ends_with("/system-contracts/contracts/Constants.sol") {
                 source = source.replace("{{SYSTEM_CONTRACTS_OFFSET}}", "0x8000");
             }
 
@@ -439,6 +426,7 @@ impl SystemContracts {
                 "system-contracts".to_owned(),
                 sources,
                 era_solc::StandardJsonInputLibraries::default(),
+                BTreeSet::new(),
                 mode,
                 llvm_options,
                 debug_config,
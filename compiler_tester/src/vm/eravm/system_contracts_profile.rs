@@ -0,0 +1,273 @@
+//!
+//! The EraVM system contracts profile.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::vm::eravm::system_contracts::SystemContracts;
+
+///
+/// The EraVM system contracts profile.
+///
+/// Selects the `era-contracts` checkout the system contracts are built from, e.g. before and after
+/// a protocol upgrade, together with the `solc` version they are built with. The profiles are
+/// listed by name in the profiles file, and the default one is the `era-contracts` submodule.
+/// The system contracts moved by the protocol version may be deployed at other addresses.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct SystemContractsProfile {
+    /// The profile name, `None` for the default profile.
+    #[serde(skip)]
+    pub name: Option<String>,
+    /// The `era-contracts` checkout directory.
+    pub contracts_directory: PathBuf,
+    /// The system contracts `solc` executable download configuration file.
+    pub solc_bin_config_path: PathBuf,
+    /// The system contract addresses overriding the default ones, by the system contract names.
+    #[serde(default)]
+    pub addresses: BTreeMap<String, web3::types::Address>,
+}
+
+impl Default for SystemContractsProfile {
+    fn default() -> Self {
+        Self {
+            name: None,
+            contracts_directory: PathBuf::from(Self::DEFAULT_CONTRACTS_DIRECTORY),
+            solc_bin_config_path: PathBuf::from(Self::DEFAULT_SOLC_BIN_CONFIG_PATH),
+            addresses: BTreeMap::new(),
+        }
+    }
+}
+
+impl SystemContractsProfile {
+    /// The default `era-contracts` checkout directory.
+    pub const DEFAULT_CONTRACTS_DIRECTORY: &'static str = "era-contracts";
+
+    /// The default system contracts `solc` executable download configuration file.
+    pub const DEFAULT_SOLC_BIN_CONFIG_PATH: &'static str =
+        "./configs/solc-bin-system-contracts.json";
+
+    /// The default profiles file.
+    pub const DEFAULT_PROFILES_PATH: &'static str = "./configs/system-contracts-profiles.yaml";
+
+    ///
+    /// Reads the profile `name` from the profiles file at `path`.
+    ///
+    pub fn try_from_name(path: &Path, name: &str) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path).map_err(|error| {
+            anyhow::anyhow!("System contracts profiles file {path:?} reading: {error}")
+        })?;
+        let mut profiles: BTreeMap<String, Self> =
+            serde_yaml::from_str(text.as_str()).map_err(|error| {
+                anyhow::anyhow!("System contracts profiles file {path:?} parsing: {error}")
+            })?;
+        let mut profile = profiles.remove(name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "System contracts profile `{name}` not found in {path:?}, expected one of: {}",
+                profiles
+                    .keys()
+                    .map(|name| format!("`{name}`"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        })?;
+        for contract in profile.addresses.keys() {
+            SystemContracts::address_by_name(contract).map_err(|error| {
+                anyhow::anyhow!("System contracts profile `{name}` in {path:?}: {error}")
+            })?;
+        }
+        profile.name = Some(name.to_owned());
+        Ok(profile)
+    }
+
+    ///
+    /// Whether the profile builds the same system contracts as the default one.
+    ///
+    pub fn is_default(&self) -> bool {
+        let default = Self::default();
+        self.contracts_directory == default.contracts_directory
+            && self.solc_bin_config_path == default.solc_bin_config_path
+            && self.addresses.is_empty()
+    }
+
+    ///
+    /// Returns the address of the system contract `name`, overridden by the profile if specified.
+    ///
+    pub fn address(&self, name: &str) -> anyhow::Result<web3::types::Address> {
+        let address = SystemContracts::address_by_name(name)?;
+        Ok(self.addresses.get(name).copied().unwrap_or(address))
+    }
+
+    ///
+    /// Returns the system contracts build cache path of the profile.
+    ///
+    /// The profile name is appended to the `path` as an extension, so the builds of different
+    /// profiles are cached separately. The path is left intact for the profiles building the same
+    /// system contracts as the default one, e.g. the current mainnet version.
+    ///
+    pub fn cache_path(&self, path: PathBuf) -> PathBuf {
+        match self.name.as_deref() {
+            Some(name) if !self.is_default() => {
+                let mut path = path.into_os_string();
+                path.push(".");
+                path.push(name);
+                PathBuf::from(path)
+            }
+            _ => path,
+        }
+    }
+
+    ///
+    /// Resolves the system contract `path` given in the default checkout against the profile one.
+    ///
+    /// The paths outside of the default checkout, e.g. in the tests directory, are left intact.
+    ///
+    pub fn resolve(&self, path: &str) -> String {
+        match path.strip_prefix(Self::DEFAULT_CONTRACTS_DIRECTORY) {
+            Some(suffix) if suffix.starts_with('/') => {
+                format!("{}{suffix}", self.contracts_directory.to_string_lossy())
+            }
+            _ => path.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use super::SystemContractsProfile;
+
+    const PROFILES: &str = r#"
+stable:
+  contracts_directory: era-contracts
+  solc_bin_config_path: ./configs/solc-bin-system-contracts.json
+upcoming:
+  contracts_directory: era-contracts-upcoming
+  solc_bin_config_path: ./configs/solc-bin-system-contracts.json
+  addresses:
+    evm_gas_manager: "0x0000000000000000000000000000000000008014"
+invalid:
+  contracts_directory: era-contracts
+  solc_bin_config_path: ./configs/solc-bin-system-contracts.json
+  addresses:
+    unknown: "0x0000000000000000000000000000000000008014"
+"#;
+
+    fn profile(name: &str) -> anyhow::Result<SystemContractsProfile> {
+        let path = std::env::temp_dir().join(format!(
+            "era-compiler-tester-system-contracts-profiles-{name}-{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(path.as_path(), PROFILES).expect("Always valid");
+        let profile = SystemContractsProfile::try_from_name(path.as_path(), name);
+        std::fs::remove_file(path.as_path()).expect("Always valid");
+        profile
+    }
+
+    #[test]
+    fn try_from_name() {
+        let stable = profile("stable").expect("Always valid");
+        assert_eq!(stable.name.as_deref(), Some("stable"));
+        assert!(stable.is_default());
+
+        let upcoming = profile("upcoming").expect("Always valid");
+        assert_eq!(
+            upcoming.contracts_directory,
+            PathBuf::from("era-contracts-upcoming")
+        );
+        assert!(!upcoming.is_default());
+
+        let error = profile("invalid").expect_err("Always invalid").to_string();
+        assert!(
+            error.contains("Unknown system contract `unknown`"),
+            "Invalid error: {error}"
+        );
+        let error = profile("missing").expect_err("Always invalid").to_string();
+        assert!(
+            error.contains("expected one of: `invalid`, `stable`, `upcoming`"),
+            "Invalid error: {error}"
+        );
+    }
+
+    #[test]
+    fn cache_path() {
+        let path = PathBuf::from("system-contracts-stable-build");
+        let upcoming = SystemContractsProfile {
+            name: Some("upcoming".to_owned()),
+            contracts_directory: PathBuf::from("era-contracts-upcoming"),
+            ..SystemContractsProfile::default()
+        };
+        let stable = SystemContractsProfile {
+            name: Some("stable".to_owned()),
+            ..SystemContractsProfile::default()
+        };
+
+        for (profile, expected) in [
+            (
+                SystemContractsProfile::default(),
+                "system-contracts-stable-build",
+            ),
+            (stable, "system-contracts-stable-build"),
+            (upcoming, "system-contracts-stable-build.upcoming"),
+        ] {
+            assert_eq!(
+                profile.cache_path(path.clone()),
+                PathBuf::from(expected),
+                "Invalid cache path of {:?}",
+                profile.name
+            );
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let profile = SystemContractsProfile {
+            contracts_directory: PathBuf::from("era-contracts-upcoming"),
+            ..SystemContractsProfile::default()
+        };
+
+        for (path, expected) in [
+            (
+                "era-contracts/system-contracts/contracts/EvmEmulator.yul",
+                "era-contracts-upcoming/system-contracts/contracts/EvmEmulator.yul",
+            ),
+            (
+                "tests/solidity/simple/system/identity.sol",
+                "tests/solidity/simple/system/identity.sol",
+            ),
+            (
+                "era-contracts-other/Contract.sol",
+                "era-contracts-other/Contract.sol",
+            ),
+        ] {
+            assert_eq!(
+                profile.resolve(path),
+                expected,
+                "Invalid resolution of {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn address() {
+        let overridden = web3::types::Address::from_low_u64_be(0x8014);
+        let profile = SystemContractsProfile {
+            addresses: BTreeMap::from([("evm_gas_manager".to_owned(), overridden)]),
+            ..SystemContractsProfile::default()
+        };
+
+        assert_eq!(
+            profile.address("evm_gas_manager").expect("Always valid"),
+            overridden
+        );
+        assert_eq!(
+            profile.address("code_oracle").expect("Always valid"),
+            web3::types::Address::from_low_u64_be(0x8012)
+        );
+        assert!(profile.address("unknown").is_err());
+    }
+}
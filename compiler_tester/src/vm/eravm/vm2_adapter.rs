@@ -138,17 +138,29 @@ pub fn run_vm(
                 return_data: chunk_return_data(&return_value),
                 exception: false,
                 events: merge_events(vm.world_diff.events()),
+                revert_reason: None,
+                return_data_assertion: None,
+                return_data_size: Some(return_value.len()),
+                events_unordered: false,
             }
         }
         ExecutionEnd::Reverted(return_value) => Output {
             return_data: chunk_return_data(&return_value),
             exception: true,
             events: vec![],
+            revert_reason: None,
+            return_data_assertion: None,
+            return_data_size: Some(return_value.len()),
+            events_unordered: false,
         },
         ExecutionEnd::Panicked => Output {
             return_data: vec![],
             exception: true,
             events: vec![],
+            revert_reason: None,
+            return_data_assertion: None,
+            return_data_size: None,
+            events_unordered: false,
         },
         ExecutionEnd::SuspendedOnHook { .. } => unreachable!(),
     };
@@ -159,6 +171,8 @@ pub fn run_vm(
             cycles: 0,
             ergs: 0,
             gas: 0,
+            stack_depth: None,
+            pubdata: None,
         },
         storage_changes,
         deployed_contracts,
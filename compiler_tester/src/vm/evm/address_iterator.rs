@@ -49,4 +49,93 @@ impl AddressIterator for EVMAddressIterator {
     fn nonce(&mut self, caller: &web3::types::Address) -> usize {
         *self.nonces.entry(*caller).or_insert(1)
     }
+
+    fn create2(
+        deployer: &web3::types::Address,
+        salt: &web3::types::H256,
+        code_hash: &web3::types::H256,
+        _input_hash: &web3::types::H256,
+    ) -> web3::types::Address {
+        let mut bytes = vec![0xff];
+        bytes.extend(deployer.as_bytes());
+        bytes.extend(salt.as_bytes());
+        bytes.extend(code_hash.as_bytes());
+
+        web3::types::Address::from_slice(
+            &web3::signing::keccak256(bytes.as_slice())[era_compiler_common::BYTE_LENGTH_FIELD
+                - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..],
+        )
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::vm::address_iterator::AddressIterator;
+
+    use super::EVMAddressIterator;
+
+    ///
+    /// The deployer, salt, init code, and address vectors of EIP-1014.
+    ///
+    #[test]
+    fn create2_eip_1014() {
+        for (deployer, salt, init_code, expected) in [
+            (
+                "0000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "00",
+                "4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38",
+            ),
+            (
+                "deadbeef00000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "00",
+                "B928f69Bb1D91Cd65274e3c79d8986362984fDA3",
+            ),
+            (
+                "deadbeef00000000000000000000000000000000",
+                "000000000000000000000000feed000000000000000000000000000000000000",
+                "00",
+                "D04116cDd17beBE565EB2422F2497E06cC1C9833",
+            ),
+            (
+                "0000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "deadbeef",
+                "70f2b2914A2a4b783FaEFb75f459A580616Fcb5e",
+            ),
+            (
+                "00000000000000000000000000000000deadbeef",
+                "00000000000000000000000000000000000000000000000000000000cafebabe",
+                "deadbeef",
+                "60f3f640a8508fC6a86d45DF051962668E1e8AC7",
+            ),
+            (
+                "00000000000000000000000000000000deadbeef",
+                "00000000000000000000000000000000000000000000000000000000cafebabe",
+                "deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                "1d8bfDC5D46DC4f61D6b6115972536eBE6A8854C",
+            ),
+            (
+                "0000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "",
+                "E33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0",
+            ),
+        ] {
+            let init_code = hex::decode(init_code).expect("Always valid");
+            let address = EVMAddressIterator::create2(
+                &web3::types::Address::from_str(deployer).expect("Always valid"),
+                &web3::types::H256::from_str(salt).expect("Always valid"),
+                &web3::types::H256::from(web3::signing::keccak256(init_code.as_slice())),
+                &web3::types::H256::zero(),
+            );
+
+            assert_eq!(
+                address,
+                web3::types::Address::from_str(expected).expect("Always valid")
+            );
+        }
+    }
 }
@@ -2,6 +2,8 @@
 //! The EVM contract build.
 //!
 
+use std::collections::BTreeMap;
+
 ///
 /// The EVM contract build.
 ///
@@ -11,6 +13,9 @@ pub struct Build {
     pub deploy_build: Vec<u8>,
     /// The contract runtime build.
     pub runtime_build: Vec<u8>,
+    /// The byte offsets of the immutables in the deployed code by the immutable names.
+    /// Only reported by the upstream `solc`.
+    pub immutables: BTreeMap<String, Vec<usize>>,
 }
 
 impl Build {
@@ -21,6 +26,15 @@ impl Build {
         Self {
             deploy_build,
             runtime_build,
+            immutables: BTreeMap::new(),
         }
     }
+
+    ///
+    /// Sets the byte offsets of the immutables in the deployed code.
+    ///
+    pub fn with_immutables(mut self, immutables: BTreeMap<String, Vec<usize>>) -> Self {
+        self.immutables = immutables;
+        self
+    }
 }
@@ -20,8 +20,12 @@ pub struct Input {
     pub builds: HashMap<String, Build>,
     /// The contracts method identifiers.
     pub method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
+    /// The contracts ABI.
+    pub abis: Option<BTreeMap<String, serde_json::Value>>,
     /// The last contract name.
     pub last_contract: String,
+    /// The compiler warnings.
+    pub warnings: Vec<String>,
 }
 
 impl Input {
@@ -31,15 +35,55 @@ impl Input {
     pub fn new(
         builds: HashMap<String, Build>,
         method_identifiers: Option<BTreeMap<String, BTreeMap<String, u32>>>,
+        abis: Option<BTreeMap<String, serde_json::Value>>,
         last_contract: String,
     ) -> Self {
         Self {
             builds,
             method_identifiers,
+            abis,
             last_contract,
+            warnings: vec![],
         }
     }
 
+    ///
+    /// Sets the compiler warnings.
+    ///
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    ///
+    /// Writes the method identifiers and the final bytecode to the debug directory.
+    ///
+    pub fn write_debug_artifacts(
+        &self,
+        debug_config: &era_compiler_llvm_context::DebugConfig,
+    ) -> anyhow::Result<()> {
+        if let Some(method_identifiers) = self.method_identifiers.as_ref() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                "method_identifiers.json",
+                serde_json::to_vec_pretty(method_identifiers)?,
+            )?;
+        }
+        for (path, build) in self.builds.iter() {
+            crate::utils::write_debug_artifact(
+                debug_config,
+                format!("{path}.deploy.hex").as_str(),
+                hex::encode(build.deploy_build.as_slice()),
+            )?;
+            crate::utils::write_debug_artifact(
+                debug_config,
+                format!("{path}.runtime.hex").as_str(),
+                hex::encode(build.runtime_build.as_slice()),
+            )?;
+        }
+        Ok(())
+    }
+
     ///
     /// Returns all contract instances.
     ///
@@ -61,7 +105,8 @@ impl Input {
 
             instances.insert(
                 name.clone(),
-                Instance::evm(name, Some(address), false, true, deploy_code),
+                Instance::evm(name, Some(address), false, true, deploy_code)
+                    .with_immutables(build.immutables.clone()),
             );
         }
 
@@ -84,7 +129,8 @@ impl Input {
                     true,
                     false,
                     deploy_code,
-                ),
+                )
+                .with_immutables(main_contract_build.immutables.clone()),
             );
         } else {
             for (instance, path) in contracts.iter() {
@@ -98,7 +144,8 @@ impl Input {
 
                 instances.insert(
                     instance.to_owned(),
-                    Instance::evm(path.to_owned(), None, is_main, false, deploy_code),
+                    Instance::evm(path.to_owned(), None, is_main, false, deploy_code)
+                        .with_immutables(build.immutables.clone()),
                 );
             }
         }
@@ -15,6 +15,7 @@ use std::time::Instant;
 
 use colored::Colorize;
 
+use crate::download_verifier::DownloadVerifier;
 use crate::vm::execution_result::ExecutionResult;
 
 use self::input::build::Build as EVMBuild;
@@ -66,6 +67,7 @@ impl<'evm> EVM<'evm> {
         for config_path in executable_download_config_paths.into_iter() {
             era_compiler_downloader::Downloader::new(http_client.clone())
                 .download(config_path.as_path())?;
+            DownloadVerifier::new(http_client.clone()).verify(config_path.as_path())?;
         }
         println!(
             "    {} downloading compiler executables in {}m{:02}s",
@@ -85,7 +87,7 @@ impl<'evm> EVM<'evm> {
         _test_name: String,
         path: &str,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         constructor_args: Vec<u8>,
     ) -> anyhow::Result<ExecutionResult> {
         let build = self.builds.get(path).expect("Always valid");
@@ -99,7 +101,7 @@ impl<'evm> EVM<'evm> {
         let (address, exception) = match evm::transact(
             evm::standard::TransactArgs::Create {
                 caller,
-                value: value.unwrap_or_default().into(),
+                value: value.unwrap_or_default(),
                 init_code: deploy_code,
                 salt: None,
                 gas_limit: web3::types::U256::from_str_radix(
@@ -149,7 +151,7 @@ impl<'evm> EVM<'evm> {
         _test_name: String,
         address: web3::types::Address,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         calldata: Vec<u8>,
     ) -> anyhow::Result<ExecutionResult> {
         self.runtime
@@ -160,7 +162,7 @@ impl<'evm> EVM<'evm> {
             evm::standard::TransactArgs::Call {
                 caller,
                 address,
-                value: value.unwrap_or_default().into(),
+                value: value.unwrap_or_default(),
                 data: calldata,
                 gas_limit: web3::types::U256::from_str_radix(
                     "ffffffff",
@@ -209,4 +211,11 @@ impl<'evm> EVM<'evm> {
                 .insert(crate::utils::u256_to_h256(&key), value);
         }
     }
+
+    ///
+    /// Returns the balance of `address`.
+    ///
+    pub fn get_balance(&self, address: web3::types::Address) -> web3::types::U256 {
+        evm::RuntimeBaseBackend::balance(&self.runtime, address)
+    }
 }
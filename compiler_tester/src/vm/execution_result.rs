@@ -18,32 +18,55 @@ pub struct ExecutionResult {
     pub ergs: u64,
     /// The number of gas used.
     pub gas: u64,
+    /// The maximum call stack depth reached by the execution, `Some` for EraVM.
+    pub stack_depth: Option<usize>,
+    /// The estimated number of pubdata bytes published by the execution, `Some` for EraVM.
+    pub pubdata: Option<usize>,
 }
 
 impl ExecutionResult {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(output: Output, cycles: usize, ergs: u64, gas: u64) -> Self {
+    pub fn new(
+        output: Output,
+        cycles: usize,
+        ergs: u64,
+        gas: u64,
+        stack_depth: Option<usize>,
+        pubdata: Option<usize>,
+    ) -> Self {
         Self {
             output,
             cycles,
             ergs,
             gas,
+            stack_depth,
+            pubdata,
         }
     }
+
+    ///
+    /// A shortcut constructor for the EVM results, which only measure the gas used.
+    ///
+    pub fn new_evm(output: Output, gas: u64) -> Self {
+        Self::new(output, 0, 0, gas, None, None)
+    }
 }
 
 impl From<zkevm_tester::compiler_tests::VmSnapshot> for ExecutionResult {
     fn from(snapshot: zkevm_tester::compiler_tests::VmSnapshot) -> Self {
         let cycles = snapshot.num_cycles_used;
         let ergs = snapshot.num_ergs_used as u64;
+        let stack_depth = snapshot.max_callstack_depth;
 
         Self {
             output: Output::from(snapshot),
             cycles,
             ergs,
             gas: 0,
+            stack_depth: Some(stack_depth),
+            pubdata: None,
         }
     }
 }
@@ -55,6 +78,8 @@ impl From<EVMOutput> for ExecutionResult {
             cycles: 0,
             ergs: 0,
             gas: 0,
+            stack_depth: None,
+            pubdata: None,
         }
     }
 }
@@ -7,3 +7,4 @@ pub mod eravm;
 pub mod evm;
 pub mod execution_result;
 pub mod revm;
+pub mod storage_access;
@@ -0,0 +1,253 @@
+//!
+//! The REVM storage slot and account access counter.
+//!
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::rc::Rc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use revm::db::EmptyDBTyped;
+use revm::interpreter::opcode;
+use revm::interpreter::Interpreter;
+use revm::primitives::AccountStatus;
+use revm::primitives::Address;
+use revm::primitives::EVMError;
+use revm::primitives::ExecutionResult;
+use revm::primitives::B256;
+use revm::primitives::U256;
+use revm::Context;
+
+use super::trace::Trace;
+use super::Revm;
+
+/// The REVM host the access counter instructions are executed with.
+type Host = Context<(), revm::State<EmptyDBTyped<Infallible>>>;
+
+/// Whether the accesses are counted, which is only required by the benchmarks and the storage
+/// excerpts of the failures.
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+///
+/// The REVM storage slot and account access counter.
+///
+/// Counts the EIP-2929 cold and warm accesses of a transaction. A storage slot or an account
+/// is cold on its first access in the transaction, unless it is pre-warmed, e.g. by the
+/// transaction access list.
+///
+/// The storage slots accessed by the transaction are recorded with their values before it.
+///
+#[derive(Debug, Default, Clone)]
+pub struct AccessCounter {
+    /// The number of cold accesses.
+    pub cold: usize,
+    /// The number of warm accesses.
+    pub warm: usize,
+    /// The values of the accessed storage slots before the transaction, by address and key.
+    pub storage: BTreeMap<(Address, U256), U256>,
+}
+
+impl AccessCounter {
+    ///
+    /// Counts the access made by the `opcode` instruction about to be executed by `interpreter`.
+    ///
+    fn count(&mut self, opcode: u8, interpreter: &Interpreter, host: &Host) {
+        let journaled_state = &host.evm.journaled_state;
+        let is_cold = match opcode {
+            opcode::SLOAD | opcode::SSTORE => {
+                let key = match interpreter.stack().peek(0) {
+                    Ok(key) => key,
+                    Err(_) => return,
+                };
+                journaled_state
+                    .state
+                    .get(&interpreter.contract.target_address)
+                    .and_then(|account| account.storage.get(&key))
+                    .map(|slot| slot.is_cold)
+                    .unwrap_or(true)
+            }
+            opcode::BALANCE
+            | opcode::EXTCODESIZE
+            | opcode::EXTCODECOPY
+            | opcode::EXTCODEHASH
+            | opcode::SELFDESTRUCT => match interpreter.stack().peek(0) {
+                Ok(address) => Self::is_account_cold(host, address),
+                Err(_) => return,
+            },
+            opcode::CALL | opcode::CALLCODE | opcode::DELEGATECALL | opcode::STATICCALL => {
+                match interpreter.stack().peek(1) {
+                    Ok(address) => Self::is_account_cold(host, address),
+                    Err(_) => return,
+                }
+            }
+            _ => return,
+        };
+        if is_cold {
+            self.cold += 1;
+        } else {
+            self.warm += 1;
+        }
+    }
+
+    ///
+    /// Records the storage slot `key` of the `address` account accessed by the instruction
+    /// just executed, unless it has already been recorded.
+    ///
+    fn record_slot(&mut self, address: Address, key: U256, host: &Host) {
+        if self.storage.contains_key(&(address, key)) {
+            return;
+        }
+        if let Some(slot) = host
+            .evm
+            .journaled_state
+            .state
+            .get(&address)
+            .and_then(|account| account.storage.get(&key))
+        {
+            self.storage.insert((address, key), slot.original_value);
+        }
+    }
+
+    ///
+    /// Whether the account at the stack word `address` is cold.
+    ///
+    fn is_account_cold(host: &Host, address: U256) -> bool {
+        let address = Address::from_word(B256::from(address));
+        let journaled_state = &host.evm.journaled_state;
+        match journaled_state.state.get(&address) {
+            Some(account) => account.status.contains(AccountStatus::Cold),
+            None => !journaled_state.warm_preloaded_addresses.contains(&address),
+        }
+    }
+}
+
+impl<'a> Revm<'a> {
+    ///
+    /// Enables the access counting for the transactions of the subsequent tests.
+    ///
+    pub fn enable_access_counting() {
+        IS_ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    ///
+    /// Executes and commits the transaction filled into the VM, counting its cold and warm
+    /// storage slot and account accesses, and recording the accessed storage slots, if enabled.
+    ///
+    /// The accessing instructions are wrapped by a temporary handler register, which is removed
+    /// after the transaction, so the hardfork semantics registers are kept. Without the access
+    /// counting, the transaction is executed with the original instruction table.
+    ///
+    /// If the `trace` is given, the accounts changed by the transaction are saved into it.
+    ///
+    pub fn transact_commit_with_accesses(
+        mut self,
+        trace: Option<&mut Trace>,
+    ) -> (
+        Self,
+        Result<ExecutionResult, EVMError<Infallible>>,
+        Option<AccessCounter>,
+    ) {
+        if !IS_ENABLED.load(Ordering::Relaxed) {
+            let result = Trace::transact_commit(&mut self, trace);
+            return (self, result, None);
+        }
+
+        let counter = Rc::new(RefCell::new(AccessCounter::default()));
+        let register_counter = counter.clone();
+        self.state
+            .handler
+            .append_handler_register_box(Box::new(move |handler| {
+                let mut table = handler.take_instruction_table();
+                let boxed = table.to_boxed();
+                for opcode in [
+                    opcode::SLOAD,
+                    opcode::SSTORE,
+                    opcode::BALANCE,
+                    opcode::EXTCODESIZE,
+                    opcode::EXTCODECOPY,
+                    opcode::EXTCODEHASH,
+                    opcode::SELFDESTRUCT,
+                    opcode::CALL,
+                    opcode::CALLCODE,
+                    opcode::DELEGATECALL,
+                    opcode::STATICCALL,
+                ] {
+                    let instruction = std::mem::replace(
+                        &mut boxed[opcode as usize],
+                        Box::new(|_: &mut Interpreter, _: &mut Host| {}),
+                    );
+                    let counter = register_counter.clone();
+                    boxed[opcode as usize] =
+                        Box::new(move |interpreter: &mut Interpreter, host: &mut Host| {
+                            let key = match opcode {
+                                opcode::SLOAD | opcode::SSTORE => interpreter.stack().peek(0).ok(),
+                                _ => None,
+                            };
+                            counter.borrow_mut().count(opcode, interpreter, host);
+                            instruction(interpreter, host);
+                            if let Some(key) = key {
+                                counter.borrow_mut().record_slot(
+                                    interpreter.contract.target_address,
+                                    key,
+                                    host,
+                                );
+                            }
+                        });
+                }
+                handler.set_instruction_table(table);
+            }));
+
+        let result = Trace::transact_commit(&mut self, trace);
+        self.state.handler.pop_handle_register();
+        let accesses = counter.take();
+        (self, result, Some(accesses))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::case::input::access_list::AccessList;
+    use crate::test::case::input::calldata::Calldata;
+    use crate::vm::revm::Revm;
+
+    /// `SLOAD(0)`, `SLOAD(0)`, `SLOAD(1)`, `STOP`.
+    const BYTECODE: [u8; 13] = [
+        0x60, 0x00, 0x54, 0x50, 0x60, 0x00, 0x54, 0x50, 0x60, 0x01, 0x54, 0x50, 0x00,
+    ];
+
+    fn accesses(access_list: AccessList) -> (usize, usize) {
+        let address = web3::types::Address::from_low_u64_be(0xc0de);
+        let caller = web3::types::Address::from_low_u64_be(0xca11);
+
+        let mut vm = Revm::new();
+        vm.install_call_proxy(&address, BYTECODE.to_vec());
+        let mut vm =
+            vm.fill_runtime_new_transaction(address, caller, Calldata::default(), None, None);
+        vm = vm.update_balance_if_lack_of_funds(caller);
+        vm.set_access_list(&access_list);
+
+        Revm::enable_access_counting();
+        let (_vm, result, accesses) = vm.transact_commit_with_accesses(None);
+        assert!(result.expect("Always valid").is_success());
+        let accesses = accesses.expect("Always enabled");
+        (accesses.cold, accesses.warm)
+    }
+
+    #[test]
+    fn cold_and_warm() {
+        assert_eq!(accesses(AccessList::default()), (2, 1));
+    }
+
+    #[test]
+    fn pre_warmed_by_access_list() {
+        let access_list = AccessList {
+            inner: vec![(
+                web3::types::Address::from_low_u64_be(0xc0de),
+                vec![web3::types::U256::one()],
+            )],
+        };
+        assert_eq!(accesses(access_list), (1, 2));
+    }
+}
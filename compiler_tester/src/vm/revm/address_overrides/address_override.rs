@@ -0,0 +1,65 @@
+//!
+//! The REVM address override.
+//!
+
+///
+/// The REVM address override.
+///
+/// The override is installed as a stub contract, so the calls to the address are handled
+/// deterministically instead of hitting an empty account.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressOverride {
+    /// Returns the specified hexadecimal data to every call.
+    Return(String),
+    /// Forwards every call with its value to the specified address, e.g. a precompile.
+    Remap(web3::types::Address),
+}
+
+impl AddressOverride {
+    /// The size of the code preceding the returned data in the `Return` stub.
+    const RETURN_STUB_HEADER_SIZE: u8 = 14;
+
+    ///
+    /// Returns the bytecode of the stub contract.
+    ///
+    pub fn bytecode(&self) -> anyhow::Result<Vec<u8>> {
+        match self {
+            Self::Return(data) => {
+                let data = hex::decode(data.strip_prefix("0x").unwrap_or(data.as_str()))
+                    .map_err(|error| anyhow::anyhow!("Invalid return data `{data}`: {error}"))?;
+                let length = u16::try_from(data.len())
+                    .map_err(|_| anyhow::anyhow!("Return data exceeds {} bytes", u16::MAX))?
+                    .to_be_bytes();
+
+                let mut bytecode =
+                    Vec::with_capacity(Self::RETURN_STUB_HEADER_SIZE as usize + data.len());
+                // CODECOPY(0, RETURN_STUB_HEADER_SIZE, length)
+                bytecode.extend([0x61, length[0], length[1]]);
+                bytecode.extend([0x60, Self::RETURN_STUB_HEADER_SIZE, 0x60, 0x00, 0x39]);
+                // RETURN(0, length)
+                bytecode.extend([0x61, length[0], length[1]]);
+                bytecode.extend([0x60, 0x00, 0xf3]);
+                bytecode.extend(data);
+                Ok(bytecode)
+            }
+            Self::Remap(address) => {
+                let mut bytecode = Vec::with_capacity(55);
+                // CALLDATACOPY(0, 0, CALLDATASIZE)
+                bytecode.extend([0x36, 0x60, 0x00, 0x60, 0x00, 0x37]);
+                // CALL(GAS, address, CALLVALUE, 0, CALLDATASIZE, 0, 0)
+                bytecode.extend([0x60, 0x00, 0x60, 0x00, 0x36, 0x60, 0x00, 0x34, 0x73]);
+                bytecode.extend(address.as_bytes());
+                bytecode.extend([0x5a, 0xf1]);
+                // RETURNDATACOPY(0, 0, RETURNDATASIZE)
+                bytecode.extend([0x3d, 0x60, 0x00, 0x60, 0x00, 0x3e]);
+                // JUMPI(success, 0x32), REVERT(0, RETURNDATASIZE)
+                bytecode.extend([0x60, 0x32, 0x57, 0x3d, 0x60, 0x00, 0xfd]);
+                // JUMPDEST, RETURN(0, RETURNDATASIZE)
+                bytecode.extend([0x5b, 0x3d, 0x60, 0x00, 0xf3]);
+                Ok(bytecode)
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//!
+//! The REVM address overrides.
+//!
+
+pub mod address_override;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use sha3::Digest;
+
+use self::address_override::AddressOverride;
+
+///
+/// The REVM address overrides.
+///
+/// Maps addresses, e.g. the EraVM system contract ones, to the stubs installed there, so the
+/// tests referencing them degrade gracefully on the EVM target.
+///
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+#[serde(transparent)]
+pub struct AddressOverrides {
+    /// The overrides by address.
+    pub inner: BTreeMap<web3::types::Address, AddressOverride>,
+}
+
+impl AddressOverrides {
+    ///
+    /// Returns the stub bytecodes by address.
+    ///
+    pub fn bytecodes(&self) -> anyhow::Result<Vec<(web3::types::Address, Vec<u8>)>> {
+        self.inner
+            .iter()
+            .map(|(address, address_override)| {
+                let bytecode = address_override
+                    .bytecode()
+                    .map_err(|error| anyhow::anyhow!("Address {address:?} override: {error}"))?;
+                Ok((*address, bytecode))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the hash of the overrides, which is a part of the result cache key.
+    ///
+    pub fn hash(&self) -> String {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(format!("{:?}", self.inner).as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+impl TryFrom<PathBuf> for AddressOverrides {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path()).map_err(|error| {
+            anyhow::anyhow!("REVM address overrides file {path:?} reading: {error}")
+        })?;
+        let address_overrides: Self = serde_yaml::from_str(text.as_str()).map_err(|error| {
+            anyhow::anyhow!("REVM address overrides file {path:?} parsing: {error}")
+        })?;
+        Ok(address_overrides)
+    }
+}
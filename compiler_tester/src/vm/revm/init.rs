@@ -1,20 +1,47 @@
-use std::{convert::Infallible, str::FromStr};
+use std::{convert::Infallible, str::FromStr, sync::Arc};
 
 use revm::{
     db::{states::plain_account::PlainStorage, EmptyDBTyped},
-    primitives::{Address, FixedBytes, TxKind, B256, U256},
-    Evm,
+    primitives::{Address, FixedBytes, SpecId, TxKind, B256, U256},
+    Database, Evm,
 };
 use solidity_adapter::EVMVersion;
+use solidity_adapter::EVM;
 
-use crate::{test::case::input::calldata::Calldata, vm::eravm::system_context::SystemContext};
+use crate::{
+    test::case::input::{access_list::AccessList, calldata::Calldata},
+    vm::eravm::system_context::SystemContext,
+};
 
 use super::revm_type_conversions::{
     web3_address_to_revm_address, web3_u256_to_revm_address, web3_u256_to_revm_u256,
 };
 
+/// The hardforks with their REVM specs, from the oldest to the latest.
+const HARDFORKS: [(EVM, SpecId); 12] = [
+    (EVM::Homestead, SpecId::HOMESTEAD),
+    (EVM::TangerineWhistle, SpecId::TANGERINE),
+    (EVM::SpuriousDragon, SpecId::SPURIOUS_DRAGON),
+    (EVM::Byzantium, SpecId::BYZANTIUM),
+    (EVM::Constantinople, SpecId::CONSTANTINOPLE),
+    (EVM::Petersburg, SpecId::PETERSBURG),
+    (EVM::Istanbul, SpecId::ISTANBUL),
+    (EVM::Berlin, SpecId::BERLIN),
+    (EVM::London, SpecId::LONDON),
+    (EVM::Paris, SpecId::MERGE),
+    (EVM::Shanghai, SpecId::SHANGHAI),
+    (EVM::Cancun, SpecId::CANCUN),
+];
+
+/// The hardfork the tests are run with, if selected.
+static SPEC: once_cell::sync::OnceCell<EVM> = once_cell::sync::OnceCell::new();
+
 #[derive(Debug)]
 pub struct Revm<'a> {
+    /// The VM state, always backed by the empty database, as there is no forking environment.
+    ///
+    /// TODO: when a forking environment with an RPC-backed database is added, put an on-disk
+    /// cache of the fetched accounts and storage slots, keyed by the block number, in front of it.
     pub state: Evm<'a, (), revm::State<EmptyDBTyped<Infallible>>>,
 }
 
@@ -29,7 +56,39 @@ impl<'a> Revm<'a> {
     /// A shortcut constructor.
     ///
     pub fn new() -> Self {
-        let mut cache = revm::CacheState::new(false);
+        let mut state = revm::db::State::builder()
+            .with_cached_prestate(revm::CacheState::new(false))
+            .with_bundle_update()
+            .build();
+        Self::insert_prestate(&mut state);
+
+        Self {
+            state: revm::Evm::builder().with_db(state).build(),
+        }
+    }
+
+    ///
+    /// Selects the hardfork the tests are run with.
+    ///
+    /// The contracts are compiled for the hardfork, and the tests whose EVM version excludes
+    /// it are skipped. Must be called before the tests are read.
+    ///
+    pub fn select_spec(evm: EVM) -> anyhow::Result<()> {
+        SPEC.set(evm)
+            .map_err(|_| anyhow::anyhow!("The REVM spec is already selected"))
+    }
+
+    ///
+    /// Returns the hardfork the tests are run with, if selected.
+    ///
+    pub fn selected_spec() -> Option<EVM> {
+        SPEC.get().copied()
+    }
+
+    ///
+    /// Inserts the accounts and block hashes every test starts with.
+    ///
+    pub(crate) fn insert_prestate(state: &mut revm::State<EmptyDBTyped<Infallible>>) {
         // Precompile 0x01 needs to have its code hash
         let acc_info = revm::primitives::AccountInfo {
             balance: U256::from(1_u64),
@@ -41,7 +100,7 @@ impl<'a> Revm<'a> {
             nonce: 1,
         };
 
-        cache.insert_account_with_storage(
+        state.cache.insert_account_with_storage(
             Address::from_word(FixedBytes::from(U256::from(1_u64))),
             acc_info,
             PlainStorage::default(),
@@ -55,17 +114,12 @@ impl<'a> Revm<'a> {
             nonce: 1,
         };
 
-        cache.insert_account_with_storage(
+        state.cache.insert_account_with_storage(
             Address::from_word(FixedBytes::from(U256::ZERO)),
             acc_info_zero,
             PlainStorage::default(),
         );
 
-        let mut state = revm::db::State::builder()
-            .with_cached_prestate(cache)
-            .with_bundle_update()
-            .build();
-
         // Blocks 0 and 1 need to have their hashes set (revm by default just uses the keccak of the number)
         state.block_hashes.insert(
             1,
@@ -77,10 +131,6 @@ impl<'a> Revm<'a> {
             B256::from_str("0x3737373737373737373737373737373737373737373737373737373737373737")
                 .unwrap(),
         );
-
-        Self {
-            state: revm::Evm::builder().with_db(state).build(),
-        }
     }
 
     pub fn fill_runtime_new_transaction(
@@ -88,17 +138,16 @@ impl<'a> Revm<'a> {
         address: web3::types::Address,
         caller: web3::types::Address,
         calldata: Calldata,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         evm_version: Option<EVMVersion>,
     ) -> Self {
-        let vm = self
-            .state
+        let vm = Self::with_semantics(self.state, evm_version)
             .modify()
             .modify_env(|env| {
                 let evm_context = SystemContext::get_constants_evm(evm_version);
                 env.tx.caller = web3_address_to_revm_address(&caller);
                 env.tx.data = revm::primitives::Bytes::from(calldata.inner.clone());
-                env.tx.value = revm::primitives::U256::from(value.unwrap_or_default());
+                env.tx.value = web3_u256_to_revm_u256(value.unwrap_or_default());
                 env.tx.transact_to = TxKind::Call(web3_address_to_revm_address(&address));
                 env.cfg.chain_id = evm_context.chain_id;
                 env.block.number = U256::from(evm_context.block_number);
@@ -119,15 +168,65 @@ impl<'a> Revm<'a> {
         Self { state: vm }
     }
 
+    ///
+    /// Sets the EIP-2930 access list of the transaction filled into the VM.
+    ///
+    pub fn set_access_list(&mut self, access_list: &AccessList) {
+        self.state.tx_mut().access_list = access_list
+            .inner
+            .iter()
+            .map(|(address, storage_keys)| {
+                (
+                    web3_address_to_revm_address(address),
+                    storage_keys
+                        .iter()
+                        .map(|key| web3_u256_to_revm_u256(*key))
+                        .collect(),
+                )
+            })
+            .collect();
+    }
+
+    ///
+    /// Installs the call proxy `bytecode` at `address`, unless it is already installed.
+    ///
+    pub fn install_call_proxy(&mut self, address: &web3::types::Address, bytecode: Vec<u8>) {
+        let address = web3_address_to_revm_address(address);
+        let is_installed = matches!(
+            self.state.db_mut().basic(address),
+            Ok(Some(info)) if info.code_hash != revm::primitives::KECCAK_EMPTY
+        );
+        if is_installed {
+            return;
+        }
+
+        let bytecode = revm::primitives::Bytecode::new_raw(bytecode.into());
+        let info =
+            revm::primitives::AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode);
+        self.state.db_mut().insert_account(address, info);
+    }
+
+    ///
+    /// Installs the address override stubs, replacing the accounts at their addresses.
+    ///
+    pub fn install_address_overrides(&mut self, overrides: &[(web3::types::Address, Vec<u8>)]) {
+        for (address, bytecode) in overrides.iter() {
+            let address = web3_address_to_revm_address(address);
+            let bytecode = revm::primitives::Bytecode::new_raw(bytecode.to_owned().into());
+            let info =
+                revm::primitives::AccountInfo::new(U256::ZERO, 1, bytecode.hash_slow(), bytecode);
+            self.state.db_mut().insert_account(address, info);
+        }
+    }
+
     pub fn fill_deploy_new_transaction(
         self,
         caller: web3::types::Address,
-        value: Option<u128>,
+        value: Option<web3::types::U256>,
         evm_version: Option<EVMVersion>,
         code: Vec<u8>,
     ) -> Self {
-        let vm = self
-            .state
+        let vm = Self::with_semantics(self.state, evm_version)
             .modify()
             .modify_env(|env| {
                 let evm_context = SystemContext::get_constants_evm(evm_version);
@@ -147,10 +246,131 @@ impl<'a> Revm<'a> {
                 env.tx.access_list = vec![];
                 env.tx.caller = web3_address_to_revm_address(&caller);
                 env.tx.data = revm::primitives::Bytes::from(code);
-                env.tx.value = revm::primitives::U256::from(value.unwrap_or_default());
+                env.tx.value = web3_u256_to_revm_u256(value.unwrap_or_default());
                 env.tx.transact_to = TxKind::Create;
             })
             .build();
         Self { state: vm }
     }
+
+    ///
+    /// Checks whether the storage of all existing accounts is empty.
+    ///
+    /// The destroyed accounts, including the ones selfdestructed before Cancun and the touched
+    /// empty ones cleared by EIP-161, are removed from the state and do not count.
+    /// The accounts recreated after their destruction only keep the storage written since then.
+    ///
+    pub fn is_storage_empty(&self) -> bool {
+        self.state
+            .db()
+            .cache
+            .accounts
+            .values()
+            .filter_map(|cache_account| cache_account.account.as_ref())
+            .all(|account| account.storage.values().all(|value| value.is_zero()))
+    }
+
+    ///
+    /// Applies the state semantics of the latest hardfork allowed by the test EVM version.
+    ///
+    /// The bytecode is always executed with the instruction set of the default hardfork,
+    /// as the contracts are compiled for it. However, the tests restricted to the older
+    /// hardforks expect their state semantics, e.g. `SELFDESTRUCT` clearing the account
+    /// before Cancun (EIP-6780), or the touched empty accounts being kept before
+    /// Spurious Dragon (EIP-161). Therefore, only the journal and the state cache are switched
+    /// to the older hardfork.
+    ///
+    /// If the hardfork is selected, the contracts are compiled for it, so it is applied entirely.
+    ///
+    fn with_semantics(
+        vm: Evm<'a, (), revm::State<EmptyDBTyped<Infallible>>>,
+        evm_version: Option<EVMVersion>,
+    ) -> Evm<'a, (), revm::State<EmptyDBTyped<Infallible>>> {
+        let selected_spec_id = Self::selected_spec().map(Self::spec_id);
+        let spec_id = selected_spec_id.or_else(|| evm_version.and_then(Self::semantics_spec_id));
+        let has_state_clear = spec_id
+            .map(|spec_id| SpecId::enabled(spec_id, SpecId::SPURIOUS_DRAGON))
+            .unwrap_or(true);
+        let builder = vm
+            .modify()
+            .reset_handler()
+            .modify_db(|state| state.set_state_clear_flag(has_state_clear));
+        match (selected_spec_id, spec_id) {
+            (Some(selected_spec_id), _) => builder.with_spec_id(selected_spec_id).build(),
+            (None, Some(spec_id)) => builder
+                .append_handler_register_box(Box::new(move |handler| {
+                    let load_accounts = handler.pre_execution.load_accounts.clone();
+                    handler.pre_execution.load_accounts = Arc::new(move |context| {
+                        load_accounts(context)?;
+                        context.evm.journaled_state.set_spec_id(spec_id);
+                        Ok(())
+                    });
+                }))
+                .build(),
+            (None, None) => builder.build(),
+        }
+    }
+
+    ///
+    /// Returns the REVM spec of the hardfork.
+    ///
+    fn spec_id(evm: EVM) -> SpecId {
+        HARDFORKS
+            .iter()
+            .find(|(version, _)| *version == evm)
+            .map(|(_, spec_id)| *spec_id)
+            .expect("Always exists")
+    }
+
+    ///
+    /// Returns the hardfork whose state semantics are expected by the test EVM version.
+    ///
+    /// Returns `None` if the EVM version does not restrict the latest hardfork,
+    /// so the default semantics apply.
+    ///
+    fn semantics_spec_id(evm_version: EVMVersion) -> Option<SpecId> {
+        HARDFORKS
+            .iter()
+            .rev()
+            .find(|(version, _)| match evm_version {
+                EVMVersion::Equals(_) | EVMVersion::LesserEquals(_) | EVMVersion::Lesser(_) => {
+                    evm_version.matches(version)
+                }
+                _ => false,
+            })
+            .map(|(_, spec_id)| *spec_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revm::primitives::SpecId;
+    use solidity_adapter::EVMVersion;
+    use solidity_adapter::EVM;
+
+    use super::Revm;
+
+    #[test]
+    fn semantics_spec_id() {
+        for (evm_version, expected) in [
+            (EVMVersion::Lesser(EVM::Cancun), Some(SpecId::SHANGHAI)),
+            (EVMVersion::LesserEquals(EVM::Cancun), Some(SpecId::CANCUN)),
+            (EVMVersion::Equals(EVM::Berlin), Some(SpecId::BERLIN)),
+            (EVMVersion::Lesser(EVM::Paris), Some(SpecId::LONDON)),
+            (
+                EVMVersion::Lesser(EVM::SpuriousDragon),
+                Some(SpecId::TANGERINE),
+            ),
+            (EVMVersion::Lesser(EVM::Homestead), None),
+            (EVMVersion::Greater(EVM::Paris), None),
+            (EVMVersion::GreaterEquals(EVM::Shanghai), None),
+            (EVMVersion::Default, None),
+        ] {
+            assert_eq!(
+                Revm::semantics_spec_id(evm_version),
+                expected,
+                "{evm_version}"
+            );
+        }
+    }
 }
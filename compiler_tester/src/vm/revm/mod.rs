@@ -1,5 +1,11 @@
+pub mod access_counter;
+pub mod address_overrides;
 pub mod balance;
 pub mod init;
+pub mod pool;
 pub mod revm_type_conversions;
+pub mod state_root;
+pub mod storage;
+pub mod trace;
 
 pub use init::Revm;
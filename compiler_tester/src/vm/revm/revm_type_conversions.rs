@@ -64,6 +64,7 @@ pub fn transform_success_output(
             Bytes::from(addr_slice.into_word())
         }
     };
+    let return_data_size = bytes.len();
     let return_data_value = revm_bytes_to_vec_value(bytes);
 
     let events = logs
@@ -78,5 +79,14 @@ pub fn transform_success_output(
             )
         })
         .collect();
-    Output::new(return_data_value, false, events)
+    let mut output = Output::new(return_data_value, false, events);
+    output.return_data_size = Some(return_data_size);
+    output
+}
+
+pub fn transform_revert_output(output: Bytes) -> Output {
+    let return_data_size = output.len();
+    let mut output = Output::new(revm_bytes_to_vec_value(output), true, vec![]);
+    output.return_data_size = Some(return_data_size);
+    output
 }
@@ -0,0 +1,258 @@
+//!
+//! The Ethereum state and logs commitments used by the state tests.
+//!
+
+use revm::db::states::plain_account::PlainAccount;
+use revm::primitives::Address;
+use revm::primitives::Log;
+
+///
+/// Computes the Merkle-Patricia state trie root of the `accounts`.
+///
+pub fn state_root<'a>(
+    accounts: impl IntoIterator<Item = (Address, &'a PlainAccount)>,
+) -> web3::types::H256 {
+    let entries = accounts
+        .into_iter()
+        .map(|(address, account)| {
+            let storage = account
+                .storage
+                .iter()
+                .filter(|(_, value)| !value.is_zero())
+                .map(|(key, value)| {
+                    (
+                        web3::signing::keccak256(key.to_be_bytes::<32>().as_slice()).to_vec(),
+                        rlp::encode(&revm_u256_to_web3(value)).to_vec(),
+                    )
+                })
+                .collect();
+
+            let mut stream = rlp::RlpStream::new_list(4);
+            stream.append(&account.info.nonce);
+            stream.append(&revm_u256_to_web3(&account.info.balance));
+            stream.append(&trie_root(storage));
+            stream.append(&web3::types::H256::from_slice(
+                account.info.code_hash.as_slice(),
+            ));
+            (
+                web3::signing::keccak256(address.as_slice()).to_vec(),
+                stream.out().to_vec(),
+            )
+        })
+        .collect();
+    trie_root(entries)
+}
+
+///
+/// Computes the hash of the RLP-encoded list of `logs`.
+///
+pub fn logs_hash(logs: &[Log]) -> web3::types::H256 {
+    let mut stream = rlp::RlpStream::new_list(logs.len());
+    for log in logs.iter() {
+        stream.begin_list(3);
+        stream.append(&web3::types::Address::from_slice(log.address.as_slice()));
+        stream.append_list(
+            log.data
+                .topics()
+                .iter()
+                .map(|topic| web3::types::H256::from_slice(topic.as_slice()))
+                .collect::<Vec<web3::types::H256>>()
+                .as_slice(),
+        );
+        stream.append(&log.data.data.to_vec());
+    }
+    web3::types::H256::from(web3::signing::keccak256(&stream.out()))
+}
+
+///
+/// Computes the root of the trie built from the `entries` of hashed keys and encoded values.
+///
+fn trie_root(entries: Vec<(Vec<u8>, Vec<u8>)>) -> web3::types::H256 {
+    let mut entries: Vec<(Vec<u8>, Vec<u8>)> = entries
+        .into_iter()
+        .map(|(key, value)| (to_nibbles(key.as_slice()), value))
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let node = if entries.is_empty() {
+        rlp::encode(&Vec::<u8>::new()).to_vec()
+    } else {
+        encode_node(entries.as_slice(), 0)
+    };
+    web3::types::H256::from(web3::signing::keccak256(node.as_slice()))
+}
+
+///
+/// Encodes the trie node holding the `entries` sorted by key, starting from the nibble at `depth`.
+///
+/// The hashed keys of the state trie have the same length, but a key may also be a prefix of
+/// another one, in which case its value is kept in the branch node.
+///
+fn encode_node(entries: &[(Vec<u8>, Vec<u8>)], depth: usize) -> Vec<u8> {
+    if let [(key, value)] = entries {
+        let mut stream = rlp::RlpStream::new_list(2);
+        stream.append(&hex_prefix(&key[depth..], true));
+        stream.append(value);
+        return stream.out().to_vec();
+    }
+
+    let first = &entries[0].0;
+    let last = &entries[entries.len() - 1].0;
+    let common_prefix_length = first[depth..]
+        .iter()
+        .zip(last[depth..].iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common_prefix_length > 0 {
+        let mut stream = rlp::RlpStream::new_list(2);
+        stream.append(&hex_prefix(
+            &first[depth..depth + common_prefix_length],
+            false,
+        ));
+        append_reference(
+            &mut stream,
+            encode_node(entries, depth + common_prefix_length),
+        );
+        return stream.out().to_vec();
+    }
+
+    let (value, entries) = match entries.split_first() {
+        Some(((key, value), rest)) if key.len() == depth => (Some(value), rest),
+        _ => (None, entries),
+    };
+    let mut stream = rlp::RlpStream::new_list(17);
+    let mut start = 0;
+    for nibble in 0..16u8 {
+        let end = start
+            + entries[start..]
+                .iter()
+                .take_while(|(key, _)| key[depth] == nibble)
+                .count();
+        if start == end {
+            stream.append_empty_data();
+        } else {
+            append_reference(&mut stream, encode_node(&entries[start..end], depth + 1));
+        }
+        start = end;
+    }
+    match value {
+        Some(value) => {
+            stream.append(value);
+        }
+        None => {
+            stream.append_empty_data();
+        }
+    }
+    stream.out().to_vec()
+}
+
+///
+/// Appends a child node, which is inlined if shorter than a hash, and hashed otherwise.
+///
+fn append_reference(stream: &mut rlp::RlpStream, node: Vec<u8>) {
+    if node.len() < era_compiler_common::BYTE_LENGTH_FIELD {
+        stream.append_raw(node.as_slice(), 1);
+    } else {
+        stream.append(&web3::types::H256::from(web3::signing::keccak256(
+            node.as_slice(),
+        )));
+    }
+}
+
+///
+/// Encodes the `nibbles` path with the hex-prefix encoding.
+///
+fn hex_prefix(nibbles: &[u8], is_leaf: bool) -> Vec<u8> {
+    let flag = if is_leaf { 2 } else { 0 };
+    let mut result = Vec::with_capacity(nibbles.len() / 2 + 1);
+    let mut nibbles = nibbles.iter();
+    if nibbles.len() % 2 == 1 {
+        result.push(((flag + 1) << 4) | nibbles.next().expect("Always exists"));
+    } else {
+        result.push(flag << 4);
+    }
+    while let (Some(high), Some(low)) = (nibbles.next(), nibbles.next()) {
+        result.push((high << 4) | low);
+    }
+    result
+}
+
+///
+/// Splits the `bytes` into nibbles.
+///
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+///
+/// Converts the REVM integer to the web3 one.
+///
+fn revm_u256_to_web3(value: &revm::primitives::U256) -> web3::types::U256 {
+    web3::types::U256::from_big_endian(value.to_be_bytes::<32>().as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    /// The root of the empty trie.
+    const EMPTY_TRIE_ROOT: &str =
+        "56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421";
+
+    fn trie_root(entries: &[(&str, &str)]) -> web3::types::H256 {
+        super::trie_root(
+            entries
+                .iter()
+                .map(|(key, value)| (key.as_bytes().to_vec(), value.as_bytes().to_vec()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn empty() {
+        let root = super::state_root(std::iter::empty());
+
+        assert_eq!(
+            root,
+            web3::types::H256::from_str(EMPTY_TRIE_ROOT).expect("Always valid")
+        );
+    }
+
+    ///
+    /// The `foo`, `dogs`, and `puppy` vectors of `TrieTests/trieanyorder.json` in ethereum/tests.
+    ///
+    #[test]
+    fn ethereum_tests_trie_any_order() {
+        for (entries, expected) in [
+            (
+                vec![("foo", "bar"), ("food", "bass")],
+                "17beaa1648bafa633cda809c90c04af50fc8aed3cb40d16efbddee6fdf63c4c3",
+            ),
+            (
+                vec![
+                    ("doe", "reindeer"),
+                    ("dog", "puppy"),
+                    ("dogglesworth", "cat"),
+                ],
+                "8aad789dff2f538bca5d8ea56e8abe10f4c7ba3a5dea95fea4cd6e7c3a1168d3",
+            ),
+            (
+                vec![
+                    ("do", "verb"),
+                    ("horse", "stallion"),
+                    ("doge", "coin"),
+                    ("dog", "puppy"),
+                ],
+                "5991bb8c6514148a29db676a14ac506cd2cd5775ace63c30a4fe457715e9ac84",
+            ),
+        ] {
+            assert_eq!(
+                trie_root(entries.as_slice()),
+                web3::types::H256::from_str(expected).expect("Always valid")
+            );
+        }
+    }
+}
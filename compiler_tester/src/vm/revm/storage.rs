@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use revm::{
+    primitives::{Account, EvmStorageSlot, U256},
+    Database, DatabaseCommit,
+};
+
+use crate::vm::storage_access::StorageAccess;
+
+use super::{
+    revm_type_conversions::{web3_address_to_revm_address, web3_u256_to_revm_u256},
+    Revm,
+};
+
+impl<'a> Revm<'a> {
+    ///
+    /// Sets the storage values of the accounts, keeping their other slots intact.
+    ///
+    pub fn populate_storage(
+        &mut self,
+        values: HashMap<(web3::types::Address, web3::types::U256), web3::types::H256>,
+    ) {
+        if values.is_empty() {
+            return;
+        }
+
+        let db = self.state.db_mut();
+        let mut changes =
+            revm::primitives::HashMap::<revm::primitives::Address, Account>::default();
+        for ((address, key), value) in values.into_iter() {
+            let address = web3_address_to_revm_address(&address);
+            let key = web3_u256_to_revm_u256(key);
+            let value = U256::from_be_bytes(value.to_fixed_bytes());
+
+            let original_value = db.storage(address, key).unwrap_or_default();
+            let account = changes.entry(address).or_insert_with(|| {
+                let mut account =
+                    Account::from(db.basic(address).ok().flatten().unwrap_or_default());
+                account.mark_touch();
+                account
+            });
+            account
+                .storage
+                .insert(key, EvmStorageSlot::new_changed(original_value, value));
+        }
+        db.commit(changes);
+    }
+
+    ///
+    /// Returns the storage slots accessed by a committed transaction, given with their values
+    /// before it, along with their current values.
+    ///
+    pub fn storage_accesses(
+        &mut self,
+        slots: &BTreeMap<(revm::primitives::Address, U256), U256>,
+    ) -> Vec<StorageAccess> {
+        let db = self.state.db_mut();
+        slots
+            .iter()
+            .map(|((address, key), before)| {
+                let after = db.storage(*address, *key).unwrap_or_default();
+                StorageAccess::new(
+                    web3::types::Address::from_slice(address.as_slice()),
+                    web3::types::U256::from_big_endian(key.to_be_bytes::<32>().as_slice()),
+                    web3::types::U256::from_big_endian(before.to_be_bytes::<32>().as_slice()),
+                    web3::types::U256::from_big_endian(after.to_be_bytes::<32>().as_slice()),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,128 @@
+//!
+//! The REVM execution trace of a failed input.
+//!
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::io::Write;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use revm::db::states::CacheAccount;
+use revm::primitives::Address;
+use revm::primitives::EVMError;
+use revm::primitives::ExecutionResult;
+use revm::primitives::ResultAndState;
+use revm::primitives::SpecId;
+use revm::DatabaseCommit;
+
+use crate::test::description::TestDescription;
+use crate::vm::revm::Revm;
+
+/// The characters replaced in the trace file names.
+static FILE_NAME_FORBIDDEN: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new("[^A-Za-z0-9]+").expect("Always valid"));
+
+///
+/// The REVM execution trace of a failed input.
+///
+/// The accounts changed by the transaction are saved before it is committed, so the transaction
+/// can be replayed on the state restored from the committed one with the EIP-3155 struct log
+/// tracer if the input fails.
+///
+pub struct Trace {
+    /// The accounts changed by the transaction as they were before it, or `None` if absent.
+    accounts: HashMap<Address, Option<CacheAccount>>,
+    /// The transaction, block, and config environment.
+    env: Box<revm::primitives::Env>,
+    /// The EVM specification.
+    spec_id: SpecId,
+}
+
+impl Trace {
+    ///
+    /// Saves the environment of the transaction filled into the `vm`.
+    ///
+    pub fn new(vm: &Revm<'_>) -> Self {
+        Self {
+            accounts: HashMap::new(),
+            env: vm.state.context.evm.env.clone(),
+            spec_id: vm.state.spec_id(),
+        }
+    }
+
+    ///
+    /// Executes and commits the transaction filled into the `vm`.
+    ///
+    /// If the `trace` is given, the accounts changed by the transaction are saved into it
+    /// before committing, so that only they are copied for each transaction.
+    ///
+    pub fn transact_commit(
+        vm: &mut Revm<'_>,
+        trace: Option<&mut Self>,
+    ) -> Result<ExecutionResult, EVMError<Infallible>> {
+        let trace = match trace {
+            Some(trace) => trace,
+            None => return vm.state.transact_commit(),
+        };
+
+        let ResultAndState { result, state } = vm.state.transact()?;
+        let accounts = &vm.state.db().cache.accounts;
+        trace.accounts.extend(
+            state
+                .keys()
+                .map(|address| (*address, accounts.get(address).cloned())),
+        );
+        vm.state.db_mut().commit(state);
+        Ok(result)
+    }
+
+    ///
+    /// Returns the trace file name of the `test`, including its mode, so that the traces of
+    /// different modes do not overwrite each other.
+    ///
+    pub fn test_name(test: &TestDescription) -> String {
+        match test.mode.as_ref() {
+            Some(mode) => format!("{} {mode}", test.selector),
+            None => test.selector.to_string(),
+        }
+    }
+
+    ///
+    /// Replays the transaction on the state of the `vm` before it, and writes its struct logs
+    /// to the file named after the `test_name` in the `directory`.
+    ///
+    /// Each line is a JSON object with the opcode, program counter, gas, stack, and call depth.
+    ///
+    pub fn write(self, vm: &Revm<'_>, directory: &Path, test_name: &str) -> anyhow::Result<()> {
+        let file_name = FILE_NAME_FORBIDDEN.replace_all(test_name, "_");
+        let path = directory.join(format!("{file_name}.jsonl"));
+        let file = std::fs::File::create(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Trace file {path:?} creating: {error}"))?;
+        let writer: Box<dyn Write> = Box::new(std::io::BufWriter::new(file));
+
+        let mut cache = vm.state.db().cache.clone();
+        for (address, account) in self.accounts.into_iter() {
+            match account {
+                Some(account) => cache.accounts.insert(address, account),
+                None => cache.accounts.remove(&address),
+            };
+        }
+        let mut state = revm::db::State::builder()
+            .with_cached_prestate(cache)
+            .with_bundle_update()
+            .build();
+        state.block_hashes = vm.state.db().block_hashes.clone();
+
+        let mut evm = revm::Evm::builder()
+            .with_db(state)
+            .with_external_context(revm::inspectors::TracerEip3155::new(writer))
+            .with_env(self.env)
+            .with_spec_id(self.spec_id)
+            .append_handler_register(revm::inspector_handle_register)
+            .build();
+        evm.transact()
+            .map_err(|error| anyhow::anyhow!("Trace {path:?} replaying: {error:?}"))?;
+        Ok(())
+    }
+}
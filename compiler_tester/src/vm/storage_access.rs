@@ -0,0 +1,99 @@
+//!
+//! The storage slot accessed by an execution.
+//!
+
+///
+/// The storage slot accessed by an execution.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageAccess {
+    /// The contract address.
+    pub address: web3::types::Address,
+    /// The storage key.
+    pub key: web3::types::U256,
+    /// The value before the execution.
+    pub before: web3::types::U256,
+    /// The value after the execution.
+    pub after: web3::types::U256,
+}
+
+impl StorageAccess {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        address: web3::types::Address,
+        key: web3::types::U256,
+        before: web3::types::U256,
+        after: web3::types::U256,
+    ) -> Self {
+        Self {
+            address,
+            key,
+            before,
+            after,
+        }
+    }
+
+    ///
+    /// Formats the storage excerpt of a failed input, one slot per line, as it is printed to
+    /// the console and written to the JUnit report.
+    ///
+    pub fn format_excerpt(accesses: &[Self]) -> String {
+        let mut excerpt = "storage:".to_owned();
+        for access in accesses.iter() {
+            excerpt.push_str(format!("\n    {access}").as_str());
+        }
+        excerpt
+    }
+}
+
+impl std::fmt::Display for StorageAccess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "0x{}[0x{}]: 0x{}",
+            crate::utils::address_as_string(&self.address),
+            crate::utils::u256_as_string(&self.key),
+            crate::utils::u256_as_string(&self.before),
+        )?;
+        if self.after != self.before {
+            write!(f, " -> 0x{}", crate::utils::u256_as_string(&self.after))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StorageAccess;
+
+    #[test]
+    fn format_excerpt() {
+        let address = web3::types::Address::from_low_u64_be(0x10000);
+        let accesses = vec![
+            StorageAccess::new(
+                address,
+                web3::types::U256::zero(),
+                web3::types::U256::from(1),
+                web3::types::U256::from(1),
+            ),
+            StorageAccess::new(
+                address,
+                web3::types::U256::one(),
+                web3::types::U256::zero(),
+                web3::types::U256::from(0x2a),
+            ),
+        ];
+        let address = "0000000000000000000000000000000000010000";
+        let zero = "0".repeat(64);
+        let one = format!("{}1", "0".repeat(63));
+        let value = format!("{}2a", "0".repeat(62));
+        assert_eq!(
+            StorageAccess::format_excerpt(accesses.as_slice()),
+            format!(
+                "storage:\n    0x{address}[0x{zero}]: 0x{one}\n    0x{address}[0x{one}]: 0x{zero} -> 0x{value}"
+            )
+        );
+    }
+}
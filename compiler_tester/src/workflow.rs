@@ -13,6 +13,8 @@ pub enum Workflow {
     BuildOnly,
     /// Build and execute tests.
     BuildAndRun,
+    /// Only discover tests and count the compilations that would be performed.
+    DryRun,
 }
 
 impl FromStr for Workflow {
@@ -22,10 +24,11 @@ impl FromStr for Workflow {
         match day {
             "build" => Ok(Workflow::BuildOnly),
             "run" => Ok(Workflow::BuildAndRun),
+            "dry-run" => Ok(Workflow::DryRun),
             string => anyhow::bail!(
                 "Unknown workflow `{}`. Supported workflows: {}",
                 string,
-                vec![Self::BuildOnly, Self::BuildAndRun]
+                vec![Self::BuildOnly, Self::BuildAndRun, Self::DryRun]
                     .into_iter()
                     .map(|element| element.to_string())
                     .collect::<Vec<String>>()
@@ -40,6 +43,7 @@ impl std::fmt::Display for Workflow {
         match self {
             Workflow::BuildOnly => write!(f, "build"),
             Workflow::BuildAndRun => write!(f, "run"),
+            Workflow::DryRun => write!(f, "dry-run"),
         }
     }
 }
@@ -134,7 +134,7 @@ pub fn gen_fuzzing_test(case: FuzzingCase) -> anyhow::Result<EthereumTest> {
     let test_path = PathBuf::from(case.contract_path.as_str());
 
     // Generate Test objects for the fuzzing contract
-    let enabled_test = EnabledTest::new(test_path.to_path_buf(), None, None, None);
+    let enabled_test = EnabledTest::new(test_path.to_path_buf(), None, None, None, vec![], vec![]);
     let mut test = solidity_adapter::Test::try_from(test_path.as_path())?;
     let fcall = build_function_call(case)?;
     test.calls.push(fcall);
@@ -175,12 +175,13 @@ pub fn build_and_run(test: EthereumTest) -> anyhow::Result<Summary> {
             era_compiler_solidity::DEFAULT_EXECUTABLE_NAME,
         ))
         .expect("Always valid");
-    compiler_tester::LLVMOptions::initialize(false, false)?;
+    compiler_tester::LLVMOptions::initialize(false, false, vec![])?;
     let compiler_tester = compiler_tester::CompilerTester::new(
         compiler_tester::Summary::new(true, false).wrap(),
-        compiler_tester::Filters::new(vec![], vec![], vec![]),
+        compiler_tester::Filters::new(vec![], vec![], vec![], vec![], vec![], None, vec![]),
         None,
         Workflow::BuildAndRun,
+        None,
     )?;
 
     // Compile and run test
@@ -192,19 +193,20 @@ pub fn build_and_run(test: EthereumTest) -> anyhow::Result<Summary> {
         &compiler_tester.filters,
         compiler_tester.debug_config.clone(),
     ) {
-        test.run_eravm::<compiler_tester::EraVMSystemContractDeployer, true>(
+        test.run_eravm(
             compiler_tester.summary.clone(),
             Arc::new(compiler_tester::EraVM::new(
                 vec![
                     PathBuf::from("./configs/solc-bin-default.json"),
                     PathBuf::from("./configs/vyper-bin-default.json"),
                 ],
-                PathBuf::from("./configs/solc-bin-system-contracts.json"),
+                compiler_tester::EraVMSystemContractsProfile::default(),
                 None,
                 Some(PathBuf::from("system-contracts-stable-build")),
                 Some(PathBuf::from("system-contracts-stable-build")),
                 era_compiler_common::Target::EraVM,
             )?),
+            compiler_tester::EraVMRunnerSettings::default(),
         );
     }
 
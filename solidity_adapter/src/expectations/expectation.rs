@@ -0,0 +1,106 @@
+//!
+//! The actual result of an Ethereum test call.
+//!
+
+///
+/// The actual result of an Ethereum test call.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Expectation {
+    /// The return data words.
+    pub return_data: Vec<web3::types::U256>,
+    /// Whether the call has failed.
+    pub failure: bool,
+    /// The amount of gas used by the call.
+    pub gas: u64,
+    /// Whether the call has matched its expectation.
+    pub passed: bool,
+}
+
+impl Expectation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(return_data: Vec<web3::types::U256>, failure: bool, gas: u64, passed: bool) -> Self {
+        Self {
+            return_data,
+            failure,
+            gas,
+            passed,
+        }
+    }
+
+    ///
+    /// Returns the expectation in the `-> ...` function call syntax.
+    ///
+    pub fn to_call_syntax(&self) -> String {
+        if self.failure {
+            if self.return_data.is_empty() {
+                return "FAILURE".to_owned();
+            }
+            let bytes = self
+                .return_data
+                .iter()
+                .flat_map(|word| {
+                    let mut bytes = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+                    word.to_big_endian(&mut bytes);
+                    bytes
+                })
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>();
+            return format!("FAILURE, hex\"{bytes}\"");
+        }
+
+        self.return_data
+            .iter()
+            .map(Self::word_to_call_syntax)
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    ///
+    /// Returns the word as a decimal literal if it is small enough, and as a hexadecimal one otherwise.
+    ///
+    fn word_to_call_syntax(word: &web3::types::U256) -> String {
+        let negated = (!*word).overflowing_add(web3::types::U256::one()).0;
+        if *word <= web3::types::U256::from(u64::MAX) {
+            word.to_string()
+        } else if negated <= web3::types::U256::from(u64::MAX) {
+            format!("-{negated}")
+        } else {
+            format!("0x{word:x}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expectation;
+
+    #[test]
+    fn to_call_syntax() {
+        let minus_one = web3::types::U256::MAX;
+        let large = web3::types::U256::from(u64::MAX) + web3::types::U256::one();
+        for (return_data, failure, expected) in [
+            (vec![], false, ""),
+            (
+                vec![web3::types::U256::from(42), minus_one],
+                false,
+                "42, -1",
+            ),
+            (vec![large], false, "0x10000000000000000"),
+            (vec![], true, "FAILURE"),
+            (
+                vec![web3::types::U256::from(0xff)],
+                true,
+                "FAILURE, hex\"00000000000000000000000000000000000000000000000000000000000000ff\"",
+            ),
+        ] {
+            assert_eq!(
+                Expectation::new(return_data, failure, 0, false).to_call_syntax(),
+                expected,
+                "{expected}"
+            );
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//!
+//! The actual results of the Ethereum tests calls.
+//!
+
+pub mod expectation;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::test::function_call::FunctionCall;
+use crate::test::Test;
+
+use self::expectation::Expectation;
+
+///
+/// The actual results of the Ethereum tests calls.
+///
+/// Are written by the compiler tester, and used to rewrite the expectations of the failed tests.
+///
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct Expectations {
+    /// The call results, keyed by the test path and the input index.
+    pub tests: BTreeMap<String, BTreeMap<usize, Expectation>>,
+}
+
+impl Expectations {
+    /// The function calls section separator.
+    const CALLS_SEPARATOR: &'static str = "// ----";
+
+    /// The function call line prefix.
+    const CALL_PREFIX: &'static str = "// ";
+
+    /// The expected output separator of a function call.
+    const OUTPUT_SEPARATOR: &'static str = "->";
+
+    /// The event line prefix.
+    const EVENT_PREFIX: &'static str = "~ ";
+
+    /// The gas option line prefix.
+    const GAS_PREFIX: &'static str = "gas ";
+
+    ///
+    /// Returns the gas option variant of the upstream tests, which corresponds to the `solc` `mode`.
+    ///
+    pub fn gas_variant(mode: &str) -> anyhow::Result<&'static str> {
+        let prefix: String = mode.chars().take(2).collect();
+        match prefix.as_str() {
+            "Y+" => Ok("irOptimized"),
+            "Y-" => Ok("ir"),
+            "E+" => Ok("legacyOptimized"),
+            "E-" => Ok("legacy"),
+            _ => anyhow::bail!(
+                "Unsupported mode `{mode}`: expected one starting with `Y+`, `Y-`, `E+`, or `E-`"
+            ),
+        }
+    }
+
+    ///
+    /// Adds the actual result of the call at `input_index` of the test at `path`.
+    ///
+    pub fn insert(&mut self, path: String, input_index: usize, expectation: Expectation) {
+        self.tests
+            .entry(path)
+            .or_default()
+            .insert(input_index, expectation);
+    }
+
+    ///
+    /// Writes the expectations to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let contents = serde_json::to_string_pretty(self).expect("Always valid");
+        std::fs::write(path, contents)
+            .map_err(|error| anyhow::anyhow!("Expectations file {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Returns the paths of the tests with at least one failed call.
+    ///
+    pub fn failed(&self) -> Vec<&str> {
+        self.tests
+            .iter()
+            .filter(|(_, calls)| calls.values().any(|expectation| !expectation.passed))
+            .map(|(path, _)| path.as_str())
+            .collect()
+    }
+
+    ///
+    /// Rewrites the expected output of the failed calls, and the `gas_variant` options of all
+    /// calls of the test at `path`.
+    ///
+    pub fn update(&self, path: &str, gas_variant: &str) -> anyhow::Result<()> {
+        let calls = self
+            .tests
+            .get(path)
+            .ok_or_else(|| anyhow::anyhow!("No call results found"))?;
+        let test = Test::try_from(Path::new(path))?;
+        let data = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Test file reading: {error}"))?;
+
+        let mut lines: Vec<String> = data.lines().map(|line| line.to_owned()).collect();
+        let separator = lines
+            .iter()
+            .position(|line| line == Self::CALLS_SEPARATOR)
+            .ok_or_else(|| anyhow::anyhow!("Function calls section not found"))?;
+
+        let mut blocks: Vec<Vec<usize>> = Vec::new();
+        for (index, line) in lines.iter().enumerate().skip(separator + 1) {
+            let Some(line) = line.strip_prefix(Self::CALL_PREFIX) else {
+                continue;
+            };
+            let line = line.trim_start();
+            if line.is_empty() {
+                continue;
+            }
+            match blocks.last_mut() {
+                Some(block)
+                    if line.starts_with(Self::EVENT_PREFIX)
+                        || line.starts_with(Self::GAS_PREFIX) =>
+                {
+                    block.push(index)
+                }
+                _ => blocks.push(vec![index]),
+            }
+        }
+        if blocks.len() != test.calls.len() {
+            anyhow::bail!(
+                "Found {} function call lines, but {} function calls",
+                blocks.len(),
+                test.calls.len()
+            );
+        }
+
+        let mut results = calls.values();
+        let runtime_calls = test
+            .calls
+            .iter()
+            .zip(blocks)
+            .filter(|(call, _)| matches!(call, FunctionCall::Call { .. }))
+            .map(|(_, block)| block)
+            .collect::<Vec<Vec<usize>>>();
+        if runtime_calls.len() != calls.len() {
+            anyhow::bail!(
+                "Found {} function calls, but {} call results",
+                runtime_calls.len(),
+                calls.len()
+            );
+        }
+        for block in runtime_calls.into_iter() {
+            let expectation = results.next().expect("Always exists");
+
+            let (header, options) = block.split_first().expect("Always exists");
+            if !expectation.passed {
+                let line = lines[*header].as_str();
+                let call = match line.find(Self::OUTPUT_SEPARATOR) {
+                    Some(position) => line[..position].trim_end(),
+                    None => line.trim_end(),
+                };
+                let output = expectation.to_call_syntax();
+                lines[*header] = if output.is_empty() {
+                    format!("{call} {}", Self::OUTPUT_SEPARATOR)
+                } else {
+                    format!("{call} {} {output}", Self::OUTPUT_SEPARATOR)
+                };
+            }
+
+            let gas_prefix = format!("{}{}{gas_variant}:", Self::CALL_PREFIX, Self::GAS_PREFIX);
+            for option in options.iter() {
+                if lines[*option].starts_with(gas_prefix.as_str()) {
+                    lines[*option] = format!("{gas_prefix} {}", expectation.gas);
+                }
+            }
+        }
+
+        let mut data = lines.join("\n");
+        data.push('\n');
+        std::fs::write(path, data).map_err(|error| anyhow::anyhow!("Test file writing: {error}"))
+    }
+}
+
+impl TryFrom<PathBuf> for Expectations {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let text = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Expectations file {path:?} reading: {error}"))?;
+        let json: Self = serde_json::from_str(text.as_str())
+            .map_err(|error| anyhow::anyhow!("Expectations file {path:?} parsing: {error}"))?;
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expectation::Expectation;
+    use super::Expectations;
+
+    #[test]
+    fn gas_variant() {
+        for (mode, expected) in [
+            ("Y+M3B3 0.8.28", Some("irOptimized")),
+            ("Y-", Some("ir")),
+            ("E+M0B0", Some("legacyOptimized")),
+            ("E-M3B3 0.4.26", Some("legacy")),
+            ("Y", None),
+            ("", None),
+            ("é+", None),
+            ("Yé", None),
+            ("I+M3B3", None),
+        ] {
+            assert_eq!(Expectations::gas_variant(mode).ok(), expected, "{mode}");
+        }
+    }
+
+    #[test]
+    fn update() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-expectations-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let path = directory.join("test.sol");
+        std::fs::write(
+            path.as_path(),
+            r#"contract C {
+    function f() public returns (uint) { return 2; }
+    function g() public returns (uint) { return 3; }
+    function h() public { revert(); }
+}
+// ----
+// f() -> 1
+// gas irOptimized: 100
+// gas legacy: 200
+// g() -> 3
+// gas irOptimized: 300
+// h() -> 4
+"#,
+        )
+        .expect("Always valid");
+        let path_string = path.to_string_lossy().to_string();
+
+        let mut expectations = Expectations::default();
+        for (index, expectation) in [
+            Expectation::new(vec![web3::types::U256::from(2)], false, 150, false),
+            Expectation::new(vec![web3::types::U256::from(3)], false, 250, true),
+            Expectation::new(vec![], true, 350, false),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            expectations.insert(path_string.clone(), index, expectation);
+        }
+        assert_eq!(expectations.failed(), vec![path_string.as_str()]);
+
+        let result = expectations.update(path_string.as_str(), "irOptimized");
+        let data = std::fs::read_to_string(path.as_path());
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+        result.expect("Always valid");
+
+        assert_eq!(
+            data.expect("Always valid"),
+            r#"contract C {
+    function f() public returns (uint) { return 2; }
+    function g() public returns (uint) { return 3; }
+    function h() public { revert(); }
+}
+// ----
+// f() -> 2
+// gas irOptimized: 150
+// gas legacy: 200
+// g() -> 3
+// gas irOptimized: 250
+// h() -> FAILURE
+"#
+        );
+    }
+
+    #[test]
+    fn update_mismatch() {
+        let mut expectations = Expectations::default();
+        expectations.insert(
+            "missing.sol".to_owned(),
+            0,
+            Expectation::new(vec![], false, 0, false),
+        );
+        assert!(expectations.update("other.sol", "ir").is_err());
+        assert!(expectations.update("missing.sol", "ir").is_err());
+    }
+}
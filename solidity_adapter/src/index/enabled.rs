@@ -17,6 +17,10 @@ pub struct EnabledTest {
     pub version: Option<semver::VersionReq>,
     /// The test group.
     pub group: Option<String>,
+    /// The test tags.
+    pub tags: Vec<String>,
+    /// The compiler features covered by the test.
+    pub features: Vec<String>,
 }
 
 impl EnabledTest {
@@ -28,12 +32,16 @@ impl EnabledTest {
         modes: Option<Vec<String>>,
         version: Option<semver::VersionReq>,
         group: Option<String>,
+        tags: Vec<String>,
+        features: Vec<String>,
     ) -> Self {
         Self {
             path,
             modes,
             version,
             group,
+            tags,
+            features,
         }
     }
 }
@@ -133,11 +133,42 @@ impl FSEntity {
                     file.modes,
                     file.version,
                     file.group,
+                    file.tags,
+                    file.features,
                 ))
             }
         }
     }
 
+    ///
+    /// Disables the test at `path` relative to the index root with the `comment`.
+    ///
+    /// Returns whether the test has been found.
+    ///
+    pub fn disable(&mut self, path: &Path, comment: String) -> bool {
+        let mut current_entity = self;
+        for path_part in path.iter() {
+            current_entity = match current_entity {
+                FSEntity::Directory(directory) => match directory
+                    .entries
+                    .get_mut(path_part.to_string_lossy().as_ref())
+                {
+                    Some(entity) => entity,
+                    None => return false,
+                },
+                FSEntity::File(_) => return false,
+            };
+        }
+        match current_entity {
+            FSEntity::Directory(_) => false,
+            FSEntity::File(file) => {
+                file.enabled = false;
+                file.comment = Some(comment);
+                true
+            }
+        }
+    }
+
     ///
     /// Updates new index, tests and lists changes.
     ///
@@ -155,6 +186,8 @@ impl FSEntity {
                 new_file.comment = old_file.comment.clone();
                 new_file.modes = old_file.modes.clone();
                 new_file.version = old_file.version.clone();
+                new_file.tags = old_file.tags.clone();
+                new_file.features = old_file.features.clone();
 
                 let new_hash = new_file
                     .hash
@@ -253,6 +286,8 @@ impl FSEntity {
                     file.modes,
                     file.version,
                     file.group,
+                    file.tags,
+                    file.features,
                 ));
                 return;
             }
@@ -337,3 +372,85 @@ impl FSEntity {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
+    use super::directory::Directory;
+    use super::test_file::TestFile;
+    use super::FSEntity;
+
+    fn file() -> FSEntity {
+        FSEntity::File(TestFile {
+            data: None,
+            hash: None,
+            enabled: true,
+            group: None,
+            comment: None,
+            modes: None,
+            version: None,
+            tags: vec![],
+            features: vec![],
+        })
+    }
+
+    fn index() -> FSEntity {
+        FSEntity::Directory(Directory::new(BTreeMap::from([
+            (
+                "a".to_owned(),
+                FSEntity::Directory(Directory::new(BTreeMap::from([(
+                    "test.sol".to_owned(),
+                    file(),
+                )]))),
+            ),
+            ("test.sol".to_owned(), file()),
+        ])))
+    }
+
+    fn file_state(index: &FSEntity, path: &str) -> (bool, Option<String>) {
+        let mut current_entity = index;
+        for path_part in Path::new(path).iter() {
+            current_entity = match current_entity {
+                FSEntity::Directory(directory) => directory
+                    .entries
+                    .get(path_part.to_string_lossy().as_ref())
+                    .expect("Always exists"),
+                FSEntity::File(_) => panic!("Not a directory"),
+            };
+        }
+        match current_entity {
+            FSEntity::File(file) => (file.enabled, file.comment.clone()),
+            FSEntity::Directory(_) => panic!("Not a file"),
+        }
+    }
+
+    #[test]
+    fn disable() {
+        let mut index = index();
+
+        assert!(index.disable(Path::new("a/test.sol"), "Disabled".to_owned()));
+
+        assert_eq!(
+            file_state(&index, "a/test.sol"),
+            (false, Some("Disabled".to_owned()))
+        );
+        assert_eq!(file_state(&index, "test.sol"), (true, None));
+    }
+
+    #[test]
+    fn disable_not_found() {
+        for path in ["a/missing.sol", "a", "test.sol/test.sol", "b/test.sol"] {
+            let mut index = index();
+
+            assert!(
+                !index.disable(Path::new(path), "Disabled".to_owned()),
+                "{path}"
+            );
+
+            assert_eq!(file_state(&index, "a/test.sol"), (true, None));
+            assert_eq!(file_state(&index, "test.sol"), (true, None));
+        }
+    }
+}
@@ -35,6 +35,12 @@ pub struct TestFile {
     /// The compiler version the test must be run with.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<semver::VersionReq>,
+    /// The test tags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The compiler features covered by the test.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
 }
 
 impl TryFrom<&Path> for TestFile {
@@ -58,6 +64,8 @@ impl TryFrom<&Path> for TestFile {
             comment: None,
             modes: None,
             version: None,
+            tags: vec![],
+            features: vec![],
         })
     }
 }
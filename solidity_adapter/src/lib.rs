@@ -4,20 +4,25 @@
 
 #![allow(clippy::assigning_clones)]
 
+pub mod expectations;
 pub mod index;
 pub mod test;
 
 use std::ops::Add;
 use std::str::FromStr;
 
+pub use self::expectations::expectation::Expectation;
+pub use self::expectations::Expectations;
 pub use self::index::enabled::EnabledTest;
 pub use self::index::FSEntity;
 pub use self::test::function_call::event::Event;
 pub use self::test::function_call::gas_option::GasOption;
 pub use self::test::function_call::FunctionCall;
+pub use self::test::imports;
 pub use self::test::params::abi_encoder_v1_only::ABIEncoderV1Only;
 pub use self::test::params::compile_to_ewasm::CompileToEwasm;
 pub use self::test::params::compile_via_yul::CompileViaYul;
+pub use self::test::params::events_unordered::EventsUnordered;
 pub use self::test::params::evm_version::EVMVersion;
 pub use self::test::params::evm_version::EVM;
 pub use self::test::params::revert_strings::RevertStrings;
@@ -5,16 +5,32 @@
 pub mod function_call;
 pub mod params;
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::fs;
 use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::LazyLock;
 
 use regex::Regex;
 
 use self::function_call::FunctionCall;
 use self::params::Params;
 
+///
+/// The import directive regex.
+///
+/// The comments and string literals are matched as well, so the imports inside them are skipped,
+/// and only the import matches capture the imported path.
+///
+static IMPORT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"//[^\n]*|/\*(?s:.*?)\*/|"(?:[^"\\\n]|\\.)*"|'(?:[^'\\\n]|\\.)*'|\bimport\s+(?:[^;"']*\bfrom\s+)?["']([^"']+)["']"#,
+    )
+    .expect("Always valid")
+});
+
 ///
 /// The semantic test instance.
 ///
@@ -22,6 +38,8 @@ use self::params::Params;
 pub struct Test {
     /// The source code files.
     pub sources: Vec<(String, String)>,
+    /// Whether the sources import one another or the source files missing from the test.
+    pub has_imports: bool,
     /// The test params.
     pub params: Params,
     /// The function calls.
@@ -49,7 +67,7 @@ impl TryFrom<&Path> for Test {
             "// ".to_owned()
         };
 
-        let sources = process_sources(&data, path)?;
+        let (sources, has_imports) = process_sources(&data, path)?;
 
         let (data, function_calls) = data
             .split_once(&format!("{comment_start}----\n"))
@@ -90,6 +108,7 @@ impl TryFrom<&Path> for Test {
 
         Ok(Self {
             sources,
+            has_imports,
             params,
             calls,
         })
@@ -97,10 +116,14 @@ impl TryFrom<&Path> for Test {
 }
 
 ///
-/// Returns sources.
+/// Returns sources, and whether they import one another or other source files.
 ///
-fn process_sources(data: &str, path: &Path) -> anyhow::Result<Vec<(String, String)>> {
+/// The sources imported by the test sources, but not defined in the test, e.g. the ones imported
+/// by external sources, are read from the files relative to their importing sources.
+///
+fn process_sources(data: &str, path: &Path) -> anyhow::Result<(Vec<(String, String)>, bool)> {
     let mut sources = Vec::new();
+    let mut source_paths = BTreeMap::new();
 
     let mut source_name = None;
     let mut source = String::new();
@@ -134,13 +157,14 @@ fn process_sources(data: &str, path: &Path) -> anyhow::Result<Vec<(String, Strin
                     .ok_or_else(|| anyhow::anyhow!("Failed to get parent directory of file"))?
                     .join(relative_path);
 
-                let mut file = fs::File::open(path)?;
+                let mut file = fs::File::open(path.as_path())?;
 
                 let mut data = String::new();
                 file.read_to_string(&mut data).map_err(|error| {
                     anyhow::anyhow!("Failed to read source code file: {}", error)
                 })?;
 
+                source_paths.insert(name.clone(), path);
                 sources.push((name, data));
             }
             "Source" => {
@@ -152,10 +176,10 @@ fn process_sources(data: &str, path: &Path) -> anyhow::Result<Vec<(String, Strin
                 }
 
                 // For sources without names
-                if !source.is_empty() {
+                if !source.trim().is_empty() {
                     sources.push((String::new(), source));
-                    source = String::new();
                 }
+                source = String::new();
 
                 source_name = Some(name);
             }
@@ -170,10 +194,214 @@ fn process_sources(data: &str, path: &Path) -> anyhow::Result<Vec<(String, Strin
     if !source.is_empty() {
         let name = match source_name {
             Some(source_name) => source_name,
-            None => path.to_string_lossy().to_string(),
+            None => {
+                let name = path.to_string_lossy().to_string();
+                source_paths.insert(name.clone(), path.to_path_buf());
+                name
+            }
         };
         sources.push((name, source));
     }
 
-    Ok(sources)
+    let directory = path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Failed to get parent directory of file"))?;
+    let has_imports = resolve_imports(&mut sources, source_paths, directory)?;
+
+    Ok((sources, has_imports))
+}
+
+///
+/// Returns the paths imported by the Solidity `source`, skipping the imports in comments and string literals.
+///
+pub fn imports(source: &str) -> Vec<&str> {
+    IMPORT_REGEX
+        .captures_iter(source)
+        .filter_map(|captures| captures.get(1))
+        .map(|import| import.as_str())
+        .collect()
+}
+
+///
+/// Adds the sources imported by the test sources, but not defined in the test.
+///
+/// The imported source is read from the file relative to the file of its importing source,
+/// if the latter is an external source, and relative to the test `directory` otherwise.
+/// The imported sources are inserted before the test sources, so the last source is kept.
+/// The imports that cannot be found are left to be reported by the compiler.
+///
+/// Returns whether any import has been resolved to a test source or a source file.
+///
+fn resolve_imports(
+    sources: &mut Vec<(String, String)>,
+    mut source_paths: BTreeMap<String, PathBuf>,
+    directory: &Path,
+) -> anyhow::Result<bool> {
+    let mut has_imports = false;
+    let mut names: BTreeSet<String> = sources.iter().map(|(name, _)| name.to_owned()).collect();
+    let mut queue: Vec<(String, String)> = sources.clone();
+    let mut imported = Vec::new();
+    while let Some((name, source)) = queue.pop() {
+        for import in imports(source.as_str()) {
+            let import_name = import_source_name(name.as_str(), import);
+            if names.contains(&import_name) {
+                has_imports = true;
+                continue;
+            }
+
+            let import_path = match source_paths.get(name.as_str()) {
+                Some(path) if import.starts_with("./") || import.starts_with("../") => path
+                    .parent()
+                    .ok_or_else(|| anyhow::anyhow!("Failed to get parent directory of file"))?
+                    .join(import),
+                _ => directory.join(import_name.trim_start_matches('/')),
+            };
+            if !import_path.is_file() {
+                continue;
+            }
+            let data = fs::read_to_string(import_path.as_path()).map_err(|error| {
+                anyhow::anyhow!(
+                    "Failed to read imported source code file {import_path:?}: {}",
+                    error
+                )
+            })?;
+
+            has_imports = true;
+            names.insert(import_name.clone());
+            source_paths.insert(import_name.clone(), import_path);
+            queue.push((import_name.clone(), data.clone()));
+            imported.push((import_name, data));
+        }
+    }
+
+    sources.splice(0..0, imported);
+    Ok(has_imports)
+}
+
+///
+/// Returns the source unit name of the `import` from the source unit `name`.
+///
+/// As in `solc`, only the imports starting with `./` or `../` are relative to the importing
+/// source unit, and the others are source unit names as is.
+///
+fn import_source_name(name: &str, import: &str) -> String {
+    if !import.starts_with("./") && !import.starts_with("../") {
+        return import.to_owned();
+    }
+
+    let mut components: Vec<&str> = name.split('/').collect();
+    components.pop();
+    for component in import.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+    components.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Test;
+
+    #[test]
+    fn import_source_name() {
+        for (name, import, expected) in [
+            ("a.sol", "b.sol", "b.sol"),
+            ("dir/a.sol", "./b.sol", "dir/b.sol"),
+            ("dir/sub/a.sol", "../b.sol", "dir/b.sol"),
+            ("dir/a.sol", "lib/b.sol", "lib/b.sol"),
+            ("a.sol", "./dir/../b.sol", "b.sol"),
+        ] {
+            assert_eq!(
+                super::import_source_name(name, import),
+                expected,
+                "{name} {import}"
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_imports() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-resolve-imports-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.join("lib")).expect("Always valid");
+        std::fs::write(
+            directory.join("lib/b.sol"),
+            "import \"./c.sol\";\ncontract B {}\n",
+        )
+        .expect("Always valid");
+        std::fs::write(directory.join("lib/c.sol"), "contract C {}\n").expect("Always valid");
+        std::fs::write(directory.join("d.sol"), "contract D {}\n").expect("Always valid");
+
+        let mut sources = vec![(
+            "a.sol".to_owned(),
+            r#"
+// import "d.sol";
+/* import "d.sol";
+*/
+string constant S = "import 'd.sol';";
+import {B} from "lib/b.sol";
+import "missing.sol";
+contract A {}
+"#
+            .to_owned(),
+        )];
+        let has_imports =
+            super::resolve_imports(&mut sources, BTreeMap::new(), directory.as_path())
+                .expect("Always valid");
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+
+        assert!(has_imports);
+        assert_eq!(
+            sources
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["lib/b.sol", "lib/c.sol", "a.sol"]
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let directory = std::env::temp_dir().join(format!(
+            "era-compiler-tester-multi-source-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(directory.as_path()).expect("Always valid");
+        let path = directory.join("test.sol");
+        std::fs::write(
+            path.as_path(),
+            r#"==== Source: a.sol ====
+library L { function f() public pure returns (uint) { return 1; } }
+==== Source: b.sol ====
+import "a.sol";
+contract C { function g() public pure returns (uint) { return L.f(); } }
+// ----
+// g() -> 1
+"#,
+        )
+        .expect("Always valid");
+
+        let test = Test::try_from(path.as_path());
+        std::fs::remove_dir_all(directory.as_path()).expect("Always valid");
+        let test = test.expect("Always valid");
+
+        assert!(test.has_imports);
+        assert_eq!(
+            test.sources
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["a.sol", "b.sol"]
+        );
+        assert_eq!(test.calls.len(), 1);
+    }
 }
@@ -0,0 +1,28 @@
+//!
+//! eventsUnordered param values.
+//!
+
+///
+/// eventsUnordered param values.
+///
+#[derive(Debug, PartialEq, Eq)]
+pub enum EventsUnordered {
+    /// `true` in the metadata.
+    True,
+    /// not specified in the metadata.
+    Default,
+}
+
+impl TryFrom<&str> for EventsUnordered {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "true" => EventsUnordered::True,
+            word => anyhow::bail!(
+                r#"Expected "true" as eventsUnordered value, found: {}"#,
+                word
+            ),
+        })
+    }
+}
@@ -78,6 +78,19 @@ impl TryFrom<&str> for EVMVersion {
     }
 }
 
+impl std::fmt::Display for EVMVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EVMVersion::Equals(version) => write!(f, "={version}"),
+            EVMVersion::Greater(version) => write!(f, ">{version}"),
+            EVMVersion::Lesser(version) => write!(f, "<{version}"),
+            EVMVersion::GreaterEquals(version) => write!(f, ">={version}"),
+            EVMVersion::LesserEquals(version) => write!(f, "<={version}"),
+            EVMVersion::Default => write!(f, "default"),
+        }
+    }
+}
+
 ///
 /// EVM version.
 ///
@@ -130,3 +143,22 @@ impl TryFrom<&str> for EVM {
         })
     }
 }
+
+impl std::fmt::Display for EVM {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            EVM::Homestead => write!(f, "homestead"),
+            EVM::TangerineWhistle => write!(f, "tangerineWhistle"),
+            EVM::SpuriousDragon => write!(f, "spuriousDragon"),
+            EVM::Byzantium => write!(f, "byzantium"),
+            EVM::Constantinople => write!(f, "constantinople"),
+            EVM::Petersburg => write!(f, "petersburg"),
+            EVM::Istanbul => write!(f, "istanbul"),
+            EVM::Berlin => write!(f, "berlin"),
+            EVM::London => write!(f, "london"),
+            EVM::Paris => write!(f, "paris"),
+            EVM::Shanghai => write!(f, "shanghai"),
+            EVM::Cancun => write!(f, "cancun"),
+        }
+    }
+}
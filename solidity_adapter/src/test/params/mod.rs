@@ -6,6 +6,7 @@ pub mod abi_encoder_v1_only;
 pub mod allow_non_existing_functions;
 pub mod compile_to_ewasm;
 pub mod compile_via_yul;
+pub mod events_unordered;
 pub mod evm_version;
 pub mod revert_strings;
 
@@ -15,6 +16,7 @@ use self::abi_encoder_v1_only::ABIEncoderV1Only;
 use self::allow_non_existing_functions::AllowNonExistingFunctions;
 use self::compile_to_ewasm::CompileToEwasm;
 use self::compile_via_yul::CompileViaYul;
+use self::events_unordered::EventsUnordered;
 use self::evm_version::EVMVersion;
 use self::revert_strings::RevertStrings;
 
@@ -35,6 +37,8 @@ pub struct Params {
     pub revert_strings: RevertStrings,
     /// allowNonExistingFunctions param value.
     pub allow_non_existing_functions: AllowNonExistingFunctions,
+    /// eventsUnordered param value.
+    pub events_unordered: EventsUnordered,
 }
 
 impl TryFrom<&str> for Params {
@@ -47,6 +51,7 @@ impl TryFrom<&str> for Params {
         let mut evm_version = EVMVersion::Default;
         let mut revert_strings = RevertStrings::Default;
         let mut allow_non_existing_functions = AllowNonExistingFunctions::Default;
+        let mut events_unordered = EventsUnordered::Default;
 
         for (index, line) in value.lines().enumerate() {
             let regex = Regex::new("^(.*): (.*)$").expect("Always valid");
@@ -91,6 +96,11 @@ impl TryFrom<&str> for Params {
                         .try_into()
                         .map_err(|error| anyhow::anyhow!("{} on line {}", error, index + 1))?;
                 }
+                "eventsUnordered" => {
+                    events_unordered = value
+                        .try_into()
+                        .map_err(|error| anyhow::anyhow!("{} on line {}", error, index + 1))?;
+                }
                 word => anyhow::bail!(
                     r#"Expected "compileViaYul", "compileToEwasm", "ABIEncoderV1Only", "revertStrings", or "EVMVersion" on line {}, found: {}"#,
                     index + 1,
@@ -106,6 +116,7 @@ impl TryFrom<&str> for Params {
             evm_version,
             revert_strings,
             allow_non_existing_functions,
+            events_unordered,
         })
     }
 }
@@ -0,0 +1,44 @@
+//!
+//! The tests updater subcommand.
+//!
+
+use std::path::PathBuf;
+
+///
+/// The tests updater subcommand.
+///
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Runs the tests with the compiler tester on REVM under the specified solc mode, and rewrites
+    /// the expected output of the failed calls, and the gas options of the mode, to the actual results.
+    UpdateExpectations {
+        /// The solc mode to run the tests under, e.g. `Y+ 0.8.28`.
+        #[arg(short, long)]
+        mode: String,
+        /// The test paths to run, passed to the compiler tester as path filters.
+        #[arg(short, long, default_value = "solidity/test/libsolidity/semanticTests")]
+        path: Vec<String>,
+        /// Path to the compiler tester executable.
+        #[arg(long, default_value = "compiler-tester")]
+        compiler_tester: PathBuf,
+    },
+    /// Adds the upstream tests missing in the index, runs them with the compiler tester on REVM
+    /// under the specified solc mode, and disables the failed ones and the ones needing adaptation.
+    ImportUpstream {
+        /// The upstream tests directory.
+        #[arg(short, long, default_value = "solidity/test/libsolidity/semanticTests")]
+        source: PathBuf,
+        /// Path of the tests' index, which the compiler tester reads the upstream tests from.
+        #[arg(short, long, default_value = "ethereum.yaml")]
+        index: PathBuf,
+        /// The solc mode to run the new tests under, e.g. `Y+ 0.8.28`.
+        #[arg(short, long)]
+        mode: String,
+        /// Path to the compiler tester executable.
+        #[arg(long, default_value = "compiler-tester")]
+        compiler_tester: PathBuf,
+        /// Path to write the JSON triage report to.
+        #[arg(long)]
+        report: Option<PathBuf>,
+    },
+}
@@ -2,27 +2,35 @@
 //! The tests updater's arguments.
 //!
 
+pub mod command;
+
 use std::path::PathBuf;
 
 use clap::Parser;
 
+use self::command::Command;
+
 ///
 /// The tests updater's arguments.
 ///
 #[derive(Debug, Parser)]
-#[command(about, long_about = None)]
+#[command(about, long_about = None, subcommand_negates_reqs = true)]
 pub struct Arguments {
+    /// The subcommand to run instead of the update.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Source directory of changed tests.
     #[arg(short, long, default_value = "solidity/test/libsolidity/semanticTests")]
     pub source: PathBuf,
 
     /// Path of the tests' index.
-    #[arg(short, long)]
-    pub index: PathBuf,
+    #[arg(short, long, required = true)]
+    pub index: Option<PathBuf>,
 
     /// Destination directory for tests to be updated.
-    #[arg(short, long)]
-    pub destination: PathBuf,
+    #[arg(short, long, required = true)]
+    pub destination: Option<PathBuf>,
 
     /// Whether to only update the index, and do not touch the files.
     #[arg(long)]
@@ -3,15 +3,27 @@
 //!
 
 pub(crate) mod arguments;
+pub(crate) mod summary;
+pub(crate) mod triage;
 
+use std::fs::File;
 use std::fs::OpenOptions;
 use std::io::BufReader;
 use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
 
 use clap::Parser;
 use colored::Colorize;
 
+use self::arguments::command::Command;
 use self::arguments::Arguments;
+use self::summary::Summary;
+use self::triage::Triage;
+
+/// The environment variable overriding the path of the index the compiler tester reads the
+/// upstream tests from.
+const ETHEREUM_INDEX_ENVIRONMENT_VARIABLE: &str = "ERA_COMPILER_TESTER_ETHEREUM_INDEX";
 
 ///
 /// Run updating
@@ -19,22 +31,35 @@ use self::arguments::Arguments;
 fn main() {
     let arguments = Arguments::parse();
 
-    let file = OpenOptions::new()
-        .read(true)
-        .open(arguments.index.clone())
-        .expect("Failed to open file");
-    let reader = BufReader::new(file);
-    let old_index: solidity_adapter::FSEntity =
-        serde_yaml::from_reader(reader).expect("Failed to read index");
+    if let Some(Command::UpdateExpectations {
+        mode,
+        path,
+        compiler_tester,
+    }) = arguments.command
+    {
+        update_expectations(mode, path, compiler_tester);
+        return;
+    }
+    if let Some(Command::ImportUpstream {
+        source,
+        index,
+        mode,
+        compiler_tester,
+        report,
+    }) = arguments.command
+    {
+        import_upstream(source, index, mode, compiler_tester, report);
+        return;
+    }
+    let index = arguments.index.expect("Always exists");
+    let destination = arguments.destination.expect("Always exists");
+
+    let old_index = read_index(index.as_path());
 
     let mut new_index =
         solidity_adapter::FSEntity::index(&arguments.source).expect("Failed to update index");
     let changes = old_index
-        .update(
-            &mut new_index,
-            arguments.destination.as_path(),
-            arguments.index_only,
-        )
+        .update(&mut new_index, destination.as_path(), arguments.index_only)
         .expect("Failed to update tests");
 
     println!("{} files created:\n", changes.created.len());
@@ -64,15 +89,180 @@ fn main() {
     }
     println!();
 
-    let new_index = serde_yaml::to_string(&new_index).expect("Serialization");
+    write_index(index.as_path(), &new_index);
+
+    println!("Test files successfully updated");
+}
+
+///
+/// Reads the tests' index at `path`.
+///
+fn read_index(path: &Path) -> solidity_adapter::FSEntity {
+    let file = OpenOptions::new()
+        .read(true)
+        .open(path)
+        .expect("Failed to open file");
+    let reader = BufReader::new(file);
+    serde_yaml::from_reader(reader).expect("Failed to read index")
+}
+
+///
+/// Writes the tests' `index` to `path`.
+///
+fn write_index(path: &Path, index: &solidity_adapter::FSEntity) {
+    let index = serde_yaml::to_string(index).expect("Serialization");
     let mut file_to_write = OpenOptions::new()
         .write(true)
         .truncate(true)
-        .open(arguments.index)
+        .open(path)
         .expect("Failed to open file");
     file_to_write
-        .write_all(new_index.as_bytes())
+        .write_all(index.as_bytes())
         .expect("Failed to write to the output file");
+}
 
-    println!("Test files successfully updated");
+///
+/// Runs the tests on REVM under `mode`, and rewrites the expectations of the failed ones.
+///
+fn update_expectations(mode: String, paths: Vec<String>, compiler_tester: PathBuf) {
+    let gas_variant = match solidity_adapter::Expectations::gas_variant(mode.as_str()) {
+        Ok(gas_variant) => gas_variant,
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(1);
+        }
+    };
+
+    let expectations_path = std::env::temp_dir().join(format!(
+        "tests-updater-expectations-{}.json",
+        std::process::id()
+    ));
+    let mut command = std::process::Command::new(compiler_tester.as_path());
+    command
+        .args(["--target", "evm"])
+        .args(["--environment", "REVM"])
+        .args(["--toolchain", "solc"])
+        .args(["--mode", mode.as_str()])
+        .arg("--expectations-output")
+        .arg(expectations_path.as_path());
+    for path in paths.iter() {
+        command.args(["--path", path.as_str()]);
+    }
+    let status = command.status().expect("Failed to run the compiler tester");
+    if !expectations_path.exists() {
+        panic!("The compiler tester has not produced the call results: {status}");
+    }
+
+    let expectations = solidity_adapter::Expectations::try_from(expectations_path.clone())
+        .expect("Failed to read the call results");
+    let _ = std::fs::remove_file(expectations_path.as_path());
+
+    let failed = expectations.failed();
+    println!("{} failed tests:\n", failed.len());
+    let mut updated = 0;
+    for path in failed.into_iter() {
+        match expectations.update(path, gas_variant) {
+            Ok(()) => {
+                updated += 1;
+                println!("{}", path.green());
+            }
+            Err(error) => println!("{} {}", path.bright_red(), error),
+        }
+    }
+    println!();
+
+    println!("Test expectations successfully updated in {updated} files");
+}
+
+///
+/// Adds the upstream tests at `source` missing in the `index`, runs them on REVM under `mode`,
+/// and disables the failed ones and the ones needing adaptation with a triage comment.
+///
+/// The existing tests are reindexed as with `--index-only`, so their files are not touched.
+///
+/// The compiler tester reads the new tests from a temporary copy of the index, so the `index` is
+/// only written once after the triage, and is left intact if the run fails.
+///
+fn import_upstream(
+    source: PathBuf,
+    index: PathBuf,
+    mode: String,
+    compiler_tester: PathBuf,
+    report: Option<PathBuf>,
+) {
+    let old_index = read_index(index.as_path());
+    let mut new_index =
+        solidity_adapter::FSEntity::index(&source).expect("Failed to index the upstream tests");
+    let changes = old_index
+        .update(&mut new_index, source.as_path(), true)
+        .expect("Failed to compare the index");
+    if changes.created.is_empty() {
+        println!("No new upstream tests found");
+        return;
+    }
+
+    let untriaged_index_path = std::env::temp_dir().join(format!(
+        "tests-updater-import-upstream-{}.yaml",
+        std::process::id()
+    ));
+    File::create(untriaged_index_path.as_path()).expect("Failed to create the temporary index");
+    write_index(untriaged_index_path.as_path(), &new_index);
+
+    let summary_path = std::env::temp_dir().join(format!(
+        "tests-updater-import-upstream-{}.json",
+        std::process::id()
+    ));
+    let mut command = std::process::Command::new(compiler_tester.as_path());
+    command
+        .env(
+            ETHEREUM_INDEX_ENVIRONMENT_VARIABLE,
+            untriaged_index_path.as_path(),
+        )
+        .args(["--target", "evm"])
+        .args(["--environment", "REVM"])
+        .args(["--toolchain", "solc"])
+        .args(["--mode", mode.as_str()])
+        .arg("--summary-output")
+        .arg(summary_path.as_path());
+    for path in changes.created.iter() {
+        command.arg("--path").arg(path.as_path());
+    }
+    let status = command.status();
+    let _ = std::fs::remove_file(untriaged_index_path.as_path());
+    let status = status.expect("Failed to run the compiler tester");
+    if !summary_path.exists() {
+        panic!("The compiler tester has not produced the summary: {status}");
+    }
+
+    let summary =
+        std::fs::read_to_string(summary_path.as_path()).expect("Failed to read the summary");
+    let _ = std::fs::remove_file(summary_path.as_path());
+    let summary = Summary::from_json(summary.as_str()).expect("Failed to parse the summary");
+    if summary.incomplete {
+        panic!("The compiler tester run has been interrupted");
+    }
+
+    let triage = Triage::new(changes.created, &summary.tests);
+    for (tests, comment) in [
+        (&triage.failed, Triage::COMMENT_FAILED),
+        (&triage.needs_adaptation, Triage::COMMENT_NEEDS_ADAPTATION),
+    ] {
+        for test in tests.iter() {
+            let path = test.strip_prefix(source.as_path()).expect("Always valid");
+            new_index.disable(path, comment.to_owned());
+        }
+    }
+    write_index(index.as_path(), &new_index);
+
+    print!("{triage}");
+    if let Some(report) = report {
+        let contents = serde_json::to_string_pretty(&triage).expect("Serialization");
+        std::fs::write(report.as_path(), contents).expect("Failed to write the triage report");
+    }
+
+    println!(
+        "Upstream tests successfully imported: {} enabled, {} disabled",
+        triage.passed.len(),
+        triage.failed.len() + triage.needs_adaptation.len()
+    );
 }
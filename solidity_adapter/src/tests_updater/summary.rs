@@ -0,0 +1,104 @@
+//!
+//! The compiler tester summary report.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The compiler tester summary report.
+///
+/// Only the fields read by the updater are declared, so the other fields may be added freely.
+///
+#[derive(Debug, serde::Deserialize)]
+pub struct Summary {
+    /// The version of the report JSON layout, missing in the reports written before versioning.
+    #[serde(default)]
+    pub schema_version: u32,
+    /// Whether the test run has been interrupted before completion.
+    #[serde(default)]
+    pub incomplete: bool,
+    /// The test statuses, keyed by the test target, mode, and selector.
+    pub tests: BTreeMap<String, Status>,
+}
+
+impl Summary {
+    /// The supported version of the report JSON layout.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    ///
+    /// Parses the summary from the JSON `text`.
+    ///
+    /// The reports of newer versions are rejected, as their fields may have changed their meaning.
+    ///
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        let summary: Self = serde_json::from_str(text)?;
+        if summary.schema_version > Self::SCHEMA_VERSION {
+            anyhow::bail!(
+                "Schema version {} is newer than the supported version {}",
+                summary.schema_version,
+                Self::SCHEMA_VERSION
+            );
+        }
+        Ok(summary)
+    }
+}
+
+///
+/// The compiler tester summary report test status.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// The test has passed.
+    Passed,
+    /// The test has failed.
+    Failed,
+    /// The test is invalid.
+    Invalid,
+    /// The test has failed due to a known compiler limitation.
+    ExpectedFailure,
+    /// The test has been expected to fail, but has passed.
+    UnexpectedPass,
+    /// The test has been ignored.
+    Ignored,
+    /// The test has been skipped, as it is not applicable to the run configuration.
+    Skipped,
+    /// The test has been skipped, as it has passed with the same builds in a previous run.
+    CachedPass,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+    use super::Summary;
+
+    #[test]
+    fn from_json() {
+        let summary = Summary::from_json(
+            r#"{"schema_version":1,"incomplete":true,"tests":{"Y+ 0.8.28 a.sol":"cached_pass"},"error_codes":{}}"#,
+        )
+        .expect("Always valid");
+        assert!(summary.incomplete);
+        assert_eq!(
+            summary.tests.get("Y+ 0.8.28 a.sol"),
+            Some(&Status::CachedPass)
+        );
+    }
+
+    #[test]
+    fn from_json_unversioned() {
+        let summary =
+            Summary::from_json(r#"{"tests":{"Y+ 0.8.28 a.sol":"passed"}}"#).expect("Always valid");
+        assert_eq!(summary.schema_version, 0);
+        assert!(!summary.incomplete);
+    }
+
+    #[test]
+    fn from_json_newer_version() {
+        let text = format!(
+            r#"{{"schema_version":{},"tests":{{}}}}"#,
+            Summary::SCHEMA_VERSION + 1
+        );
+        assert!(Summary::from_json(text.as_str()).is_err());
+    }
+}
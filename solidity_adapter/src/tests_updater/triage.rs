@@ -0,0 +1,169 @@
+//!
+//! The upstream tests import triage.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use colored::Colorize;
+
+use crate::summary::Status;
+
+///
+/// The upstream tests import triage.
+///
+/// Classifies the newly imported tests by their outcomes in the quick compiler tester run.
+///
+#[derive(Debug, Default, serde::Serialize)]
+pub struct Triage {
+    /// The tests passed in the quick run, which are left enabled.
+    pub passed: Vec<PathBuf>,
+    /// The tests failed in the quick run.
+    pub failed: Vec<PathBuf>,
+    /// The tests invalid or not run in the quick run, e.g. due to unsupported metadata.
+    pub needs_adaptation: Vec<PathBuf>,
+}
+
+impl Triage {
+    /// The index comment of the failed tests.
+    pub const COMMENT_FAILED: &'static str = "Imported from upstream: fails";
+
+    /// The index comment of the tests needing adaptation.
+    pub const COMMENT_NEEDS_ADAPTATION: &'static str = "Imported from upstream: needs adaptation";
+
+    ///
+    /// Classifies the `tests` by the compiler tester summary `statuses`, keyed by the test names,
+    /// which contain the test paths.
+    ///
+    pub fn new(tests: Vec<PathBuf>, statuses: &BTreeMap<String, Status>) -> Self {
+        let mut triage = Self::default();
+        for test in tests.into_iter() {
+            let path = test.to_string_lossy().to_string();
+            let test_statuses: Vec<Status> = statuses
+                .iter()
+                .filter(|(name, _)| Self::is_test_name(name.as_str(), path.as_str()))
+                .map(|(_, status)| *status)
+                .collect();
+            if test_statuses.contains(&Status::Invalid) {
+                triage.needs_adaptation.push(test);
+            } else if test_statuses.contains(&Status::Failed) {
+                triage.failed.push(test);
+            } else if test_statuses.iter().any(|status| {
+                matches!(
+                    status,
+                    Status::Passed | Status::CachedPass | Status::UnexpectedPass
+                )
+            }) {
+                triage.passed.push(test);
+            } else {
+                triage.needs_adaptation.push(test);
+            }
+        }
+        triage
+    }
+
+    ///
+    /// Whether the summary test `name`, consisting of the optional target, the mode, and the
+    /// selector, belongs to the test at `path`.
+    ///
+    /// The selector must start with the exact path, optionally followed by the case and input,
+    /// so the tests whose paths contain `path` are not matched.
+    ///
+    fn is_test_name(name: &str, path: &str) -> bool {
+        name.match_indices(path).any(|(position, _)| {
+            let is_start = name[..position].is_empty() || name[..position].ends_with(' ');
+            let suffix = &name[position + path.len()..];
+            is_start && (suffix.is_empty() || suffix.starts_with("::") || suffix.starts_with('['))
+        })
+    }
+}
+
+impl std::fmt::Display for Triage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} passed tests:\n", self.passed.len())?;
+        for test in self.passed.iter() {
+            writeln!(f, "{}", test.to_string_lossy().green())?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "{} failed tests:\n", self.failed.len())?;
+        for test in self.failed.iter() {
+            writeln!(f, "{}", test.to_string_lossy().bright_red())?;
+        }
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "{} tests needing adaptation:\n",
+            self.needs_adaptation.len()
+        )?;
+        for test in self.needs_adaptation.iter() {
+            writeln!(f, "{}", test.to_string_lossy().yellow())?;
+        }
+        writeln!(f)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+
+    use crate::summary::Status;
+
+    use super::Triage;
+
+    #[test]
+    fn new() {
+        let statuses = BTreeMap::from([
+            ("Y+ 0.8.28 a/passed.sol".to_owned(), Status::Passed),
+            ("Y- 0.8.28 a/passed.sol".to_owned(), Status::CachedPass),
+            ("Y+ 0.8.28 a/failed.sol[f():1]".to_owned(), Status::Failed),
+            ("Y+ 0.8.28 a/failed.sol[g():2]".to_owned(), Status::Passed),
+            ("Y+ 0.8.28 a/invalid.sol".to_owned(), Status::Invalid),
+            ("Y+ 0.8.28 a/skipped.sol".to_owned(), Status::Skipped),
+            ("Y+ 0.8.28 b/a/missing.sol".to_owned(), Status::Passed),
+            ("Y+ 0.8.28 a/missing.sol.bak".to_owned(), Status::Passed),
+        ]);
+        let triage = Triage::new(
+            [
+                "a/passed.sol",
+                "a/failed.sol",
+                "a/invalid.sol",
+                "a/skipped.sol",
+                "a/missing.sol",
+            ]
+            .into_iter()
+            .map(PathBuf::from)
+            .collect(),
+            &statuses,
+        );
+
+        assert_eq!(triage.passed, vec![PathBuf::from("a/passed.sol")]);
+        assert_eq!(triage.failed, vec![PathBuf::from("a/failed.sol")]);
+        assert_eq!(
+            triage.needs_adaptation,
+            vec![
+                PathBuf::from("a/invalid.sol"),
+                PathBuf::from("a/skipped.sol"),
+                PathBuf::from("a/missing.sol"),
+            ]
+        );
+    }
+
+    #[test]
+    fn is_test_name() {
+        for (name, expected) in [
+            ("a/test.sol", true),
+            ("Y+ 0.8.28 a/test.sol", true),
+            ("EVM Y+ 0.8.28 a/test.sol::case", true),
+            ("Y+ 0.8.28 a/test.sol[#deployer:Test]", true),
+            ("Y+ 0.8.28 b/a/test.sol", false),
+            ("Y+ 0.8.28 a/test.sol.bak", false),
+            ("Y+ 0.8.28 a/test.solx", false),
+        ] {
+            assert_eq!(Triage::is_test_name(name, "a/test.sol"), expected, "{name}");
+        }
+    }
+}